@@ -0,0 +1,59 @@
+use criterion::{criterion_group, criterion_main};
+
+#[cfg(not(feature = "experimental"))]
+mod benchmark {
+    use criterion::Criterion;
+    pub fn criterion_benchmark(_c: &mut Criterion) {
+        panic!("This benchmark requires the 'experimental' feature to work; try again with `--features experimental`");
+    }
+}
+
+#[cfg(feature = "experimental")]
+mod benchmark {
+    use criterion::{black_box, Criterion};
+    use ion_rs::{v1_0, Reader};
+
+    // The number of top-level annotated values in each test stream.
+    const NUM_VALUES: usize = 1_000_000;
+
+    /// Produces a text Ion 1.0 stream of `NUM_VALUES` ints, each of which has three annotations.
+    fn text_1_0_data(annotations: &str) -> String {
+        (0..NUM_VALUES)
+            .map(|i| format!("{annotations}::{i}\n"))
+            .collect()
+    }
+
+    pub fn criterion_benchmark(c: &mut Criterion) {
+        println!("# Values: {NUM_VALUES}");
+        let mut group = c.benchmark_group("text 1.0 annotations");
+        group.sample_size(10);
+        for (name, annotations) in [
+            ("identifiers", "foo::bar::baz"),
+            ("quoted without escapes", "'foo bar'::'bar baz'::'baz quux'"),
+            (
+                "quoted with escapes",
+                r"'foo\tbar'::'bar\x20baz'::'baz quux'",
+            ),
+        ] {
+            let data = text_1_0_data(annotations);
+            // Visits each annotation of each value, resolving its text.
+            group.bench_function(name, |b| {
+                b.iter(|| {
+                    let mut reader = Reader::new(v1_0::Text, data.as_bytes()).unwrap();
+                    let mut num_annotations = 0usize;
+                    while let Some(value) = reader.next().unwrap() {
+                        for annotation in value.annotations() {
+                            black_box(annotation.unwrap().text());
+                            num_annotations += 1;
+                        }
+                    }
+                    assert_eq!(num_annotations, NUM_VALUES * 3);
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, benchmark::criterion_benchmark);
+criterion_main!(benches);
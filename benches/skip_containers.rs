@@ -0,0 +1,77 @@
+use criterion::{criterion_group, criterion_main};
+
+#[cfg(not(feature = "experimental"))]
+mod benchmark {
+    use criterion::Criterion;
+    pub fn criterion_benchmark(_c: &mut Criterion) {
+        panic!("This benchmark requires the 'experimental' feature to work; try again with `--features experimental`");
+    }
+}
+
+#[cfg(feature = "experimental")]
+mod benchmark {
+    use criterion::{black_box, Criterion};
+    use ion_rs::{v1_0, Element, Reader, Sequence};
+
+    // The number of top-level structs in the test stream.
+    const NUM_VALUES: usize = 100;
+    // The number of fields in each top-level struct.
+    const NUM_FIELDS: usize = 5_000;
+
+    /// Produces a binary Ion 1.0 stream of `NUM_VALUES` structs. Each struct begins with a nested
+    /// container that is a few levels deep, followed by `NUM_FIELDS` scalar fields.
+    fn binary_1_0_data() -> Vec<u8> {
+        let fields: Vec<String> = (0..NUM_FIELDS)
+            .map(|i| format!("field_{}: {i}", i % 16))
+            .collect();
+        let text = format!(
+            "{{header: {{id: 1, tags: [a, b, {{c: d}}]}}, {}}}",
+            fields.join(", ")
+        )
+        .repeat(NUM_VALUES);
+        let elements: Sequence = Element::read_all(text).unwrap();
+        elements.encode_as(v1_0::Binary).unwrap()
+    }
+
+    pub fn criterion_benchmark(c: &mut Criterion) {
+        println!("# Values: {NUM_VALUES}, # Fields: {NUM_FIELDS}");
+        let data = binary_1_0_data();
+
+        let mut group = c.benchmark_group("binary 1.0 skip containers");
+        // Reads the first field of each struct and then advances to the next top-level value.
+        // The reader uses each struct's encoded length to jump to its end.
+        group.bench_function("read first field, skip remaining fields", |b| {
+            b.iter(|| {
+                let mut reader = Reader::new(v1_0::Binary, data.as_slice()).unwrap();
+                let mut num_values = 0usize;
+                while let Some(value) = reader.next().unwrap() {
+                    let strukt = value.read().unwrap().expect_struct().unwrap();
+                    let header = strukt.iter().next().unwrap().unwrap();
+                    black_box(header.name().unwrap());
+                    num_values += 1;
+                }
+                assert_eq!(num_values, NUM_VALUES);
+            })
+        });
+        // Visits every field of each struct before advancing to the next top-level value, parsing
+        // each remaining field's header along the way.
+        group.bench_function("read first field, visit remaining fields", |b| {
+            b.iter(|| {
+                let mut reader = Reader::new(v1_0::Binary, data.as_slice()).unwrap();
+                let mut num_fields = 0usize;
+                while let Some(value) = reader.next().unwrap() {
+                    let strukt = value.read().unwrap().expect_struct().unwrap();
+                    for field in &strukt {
+                        black_box(field.unwrap().name().unwrap());
+                        num_fields += 1;
+                    }
+                }
+                assert_eq!(num_fields, NUM_VALUES * (NUM_FIELDS + 1));
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, benchmark::criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,298 @@
+//! Reads one or more Ion files (text or binary, detected automatically) and writes their
+//! contents to STDOUT in the requested format.
+//!
+//! USAGE:
+//!
+//!     ion_cat [--pretty | --compact | --binary | --validate | --count] FILE...
+//!
+//! `--pretty` (the default), `--compact`, and `--binary` transcode each file's values.
+//! `--validate` reads every value in each file without writing it, reporting the location of the
+//! first error found. `--count` prints the number of top-level values in each file.
+//!
+//! The functions that implement each mode operate on in-memory input and output so they can be
+//! tested independently of the command line handling.
+
+#[cfg(not(feature = "experimental"))]
+fn main() {
+    println!("This example requires the 'experimental' feature to work; try again with `--features experimental`");
+}
+
+#[cfg(feature = "experimental")]
+fn main() {
+    ion_cat::main()
+}
+
+#[cfg(feature = "experimental")]
+mod ion_cat {
+    use std::io::Write;
+    use std::path::Path;
+    use std::process::exit;
+
+    use ion_rs::v1_0::{Binary, Text};
+    use ion_rs::*;
+
+    const USAGE: &str =
+        "USAGE:\n\n    ion_cat [--pretty | --compact | --binary | --validate | --count] FILE...\n";
+
+    /// The operation to perform on each input file.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum Mode {
+        Pretty,
+        Compact,
+        Binary,
+        Validate,
+        Count,
+    }
+
+    impl Mode {
+        fn from_flag(flag: &str) -> Option<Mode> {
+            let mode = match flag {
+                "--pretty" => Mode::Pretty,
+                "--compact" => Mode::Compact,
+                "--binary" => Mode::Binary,
+                "--validate" => Mode::Validate,
+                "--count" => Mode::Count,
+                _ => return None,
+            };
+            Some(mode)
+        }
+    }
+
+    pub fn main() {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let (mode, paths) = match args.first().map(|arg| arg.as_str()) {
+            Some(flag) if flag.starts_with("--") => match Mode::from_flag(flag) {
+                Some(mode) => (mode, &args[1..]),
+                None => {
+                    eprintln!("{USAGE}\nUnrecognized option: {flag}");
+                    exit(1);
+                }
+            },
+            _ => (Mode::Pretty, &args[..]),
+        };
+        if paths.is_empty() {
+            eprintln!("{USAGE}\nNo input files were specified.");
+            exit(1);
+        }
+
+        let mut stdout = std::io::stdout().lock();
+        for path in paths {
+            match cat_file(path, mode) {
+                Ok(output) => stdout
+                    .write_all(&output)
+                    .expect("failed to write to STDOUT"),
+                Err(message) => {
+                    eprintln!("{path}: {message}");
+                    exit(1);
+                }
+            }
+        }
+    }
+
+    /// Reads the file at `path` and applies `mode` to its contents, returning the bytes that
+    /// should be written to STDOUT. If the file cannot be read or contains invalid Ion, returns
+    /// a description of the problem.
+    pub fn cat_file(path: impl AsRef<Path>, mode: Mode) -> Result<Vec<u8>, String> {
+        let input = std::fs::read(path).map_err(|e| format!("could not read file: {e}"))?;
+        let result = match mode {
+            Mode::Pretty => transcode(&input, Text.with_format(TextFormat::Pretty)),
+            Mode::Compact => transcode(&input, Text.with_format(TextFormat::Compact)),
+            Mode::Binary => transcode(&input, Binary),
+            Mode::Validate => validate(&input).map(|summary| {
+                format!(
+                    "valid {} with {} top-level values\n",
+                    summary.encoding.name(),
+                    summary.count
+                )
+                .into_bytes()
+            }),
+            Mode::Count => count(&input).map(|count| format!("{count}\n").into_bytes()),
+        };
+        result.map_err(|error| describe_error(&input, &error))
+    }
+
+    /// Reads the Ion values in `input` and re-encodes them using the provided configuration.
+    pub fn transcode<E: Encoding>(
+        input: &[u8],
+        config: impl Into<WriteConfig<E>>,
+    ) -> IonResult<Vec<u8>> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let mut writer = Writer::new(config, Vec::new())?;
+        while let Some(value) = reader.next()? {
+            writer.write(value)?;
+        }
+        writer.close()
+    }
+
+    /// The outcome of successfully validating a stream.
+    #[derive(Debug)]
+    pub struct Summary {
+        pub encoding: IonEncoding,
+        pub count: usize,
+    }
+
+    /// Fully reads every value in `input`, including all nested values, returning the first
+    /// error encountered.
+    pub fn validate(input: &[u8]) -> IonResult<Summary> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let mut count = 0;
+        while let Some(value) = reader.next()? {
+            // Materializing the value visits each of its children.
+            let _element = Element::try_from(value)?;
+            count += 1;
+        }
+        Ok(Summary {
+            encoding: reader.detected_encoding(),
+            count,
+        })
+    }
+
+    /// Returns the number of top-level values in `input`. Container values are skipped rather
+    /// than read.
+    pub fn count(input: &[u8]) -> IonResult<usize> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let mut count = 0;
+        while reader.next()?.is_some() {
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Formats `error` along with its location in `input`, if known. Binary locations are
+    /// reported as a byte offset; text locations also include the line and column.
+    pub fn describe_error(input: &[u8], error: &IonError) -> String {
+        let Some(position) = error.position() else {
+            return error.to_string();
+        };
+        let offset = position.byte_offset();
+        let line_and_column = position.line_and_column().or_else(|| {
+            // The reader does not always track lines and columns, so we compute them ourselves.
+            detect_encoding(input)
+                .is_text()
+                .then(|| line_and_column_at(input, offset))
+        });
+        match line_and_column {
+            Some((line, column)) => {
+                format!("{error} (offset {offset}, line {line}, column {column})")
+            }
+            None => format!("{error} (offset {offset})"),
+        }
+    }
+
+    /// Returns the encoding of `input`. The reader detects a binary stream's encoding when it
+    /// reads the stream's leading IVM, so this reads (and discards) the first value.
+    fn detect_encoding(input: &[u8]) -> IonEncoding {
+        let Ok(mut reader) = Reader::new(AnyEncoding, input) else {
+            return IonEncoding::default();
+        };
+        let _ = reader.next();
+        reader.detected_encoding()
+    }
+
+    /// Returns the 1-based line and column of the byte at `offset`.
+    fn line_and_column_at(input: &[u8], offset: usize) -> (usize, usize) {
+        let preceding = &input[..offset.min(input.len())];
+        let line = preceding.iter().filter(|byte| **byte == b'\n').count() + 1;
+        let line_start = preceding
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        (line, offset - line_start + 1)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::path::PathBuf;
+        use tempfile::TempDir;
+
+        const TEXT_FIXTURE: &str = r#"
+            $ion_1_0
+            {name: "widget", tags: [blue, round], price: 12.50}
+            annotated::(a b c)
+            2024-01-01T null.int {{aGVsbG8=}}
+        "#;
+
+        // Writes `contents` to a file called `name` in `dir` and returns its path.
+        fn fixture(dir: &TempDir, name: &str, contents: &[u8]) -> PathBuf {
+            let path = dir.path().join(name);
+            std::fs::write(&path, contents).unwrap();
+            path
+        }
+
+        #[test]
+        fn binary_to_pretty_text() -> IonResult<()> {
+            let dir = TempDir::new()?;
+            let binary = transcode(TEXT_FIXTURE.as_bytes(), Binary)?;
+            let path = fixture(&dir, "fixture.10n", &binary);
+
+            let pretty = cat_file(&path, Mode::Pretty).unwrap();
+            let pretty = String::from_utf8(pretty).unwrap();
+            // Pretty text puts each struct field on its own line.
+            assert!(pretty.contains("\n  name: \"widget\",\n"), "{pretty}");
+            let expected = Element::read_all(TEXT_FIXTURE)?;
+            assert!(IonData::eq(&Element::read_all(pretty)?, &expected));
+            Ok(())
+        }
+
+        #[test]
+        fn text_to_binary_to_text_round_trip() -> IonResult<()> {
+            let dir = TempDir::new()?;
+            let text_path = fixture(&dir, "fixture.ion", TEXT_FIXTURE.as_bytes());
+            let binary = cat_file(&text_path, Mode::Binary).unwrap();
+            let binary_path = fixture(&dir, "fixture.10n", &binary);
+            let compact = cat_file(&binary_path, Mode::Compact).unwrap();
+
+            let original = Element::read_all(TEXT_FIXTURE)?;
+            assert!(IonData::eq(&Element::read_all(binary)?, &original));
+            assert!(IonData::eq(&Element::read_all(compact)?, &original));
+            Ok(())
+        }
+
+        #[test]
+        fn corrupt_file_reports_location() -> IonResult<()> {
+            let dir = TempDir::new()?;
+            let text_path = fixture(&dir, "corrupt.ion", b"1\n2\n{foo: bar,, baz: 3}\n4");
+            let message = cat_file(&text_path, Mode::Validate).unwrap_err();
+            assert!(message.contains("line 3"), "{message}");
+
+            // Truncate a binary stream partway through its last value.
+            let mut binary = transcode(TEXT_FIXTURE.as_bytes(), Binary)?;
+            binary.truncate(binary.len() - 2);
+            let binary_path = fixture(&dir, "corrupt.10n", &binary);
+            let message = cat_file(&binary_path, Mode::Validate).unwrap_err();
+            assert!(message.contains("offset"), "{message}");
+            assert!(!message.contains("line"), "{message}");
+            Ok(())
+        }
+
+        #[test]
+        fn validate_reports_encoding_and_count() -> IonResult<()> {
+            let dir = TempDir::new()?;
+            let binary = transcode(TEXT_FIXTURE.as_bytes(), Binary)?;
+            let path = fixture(&dir, "fixture.10n", &binary);
+            let output = cat_file(&path, Mode::Validate).unwrap();
+            assert_eq!(
+                String::from_utf8(output).unwrap(),
+                "valid binary Ion v1.0 with 5 top-level values\n"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn count_large_stream() -> IonResult<()> {
+            let dir = TempDir::new()?;
+            let text: String = (0..5_000)
+                .map(|i| format!("{{id: {i}, tags: [a, b]}}\n"))
+                .collect();
+            let text_path = fixture(&dir, "large.ion", text.as_bytes());
+            let binary_path = fixture(&dir, "large.10n", &transcode(text.as_bytes(), Binary)?);
+            for path in [text_path, binary_path] {
+                let output = cat_file(&path, Mode::Count).unwrap();
+                assert_eq!(String::from_utf8(output).unwrap(), "5000\n");
+            }
+            Ok(())
+        }
+    }
+}
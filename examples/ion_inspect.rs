@@ -0,0 +1,242 @@
+//! Reads a binary Ion 1.0 file and prints a report describing how each value in the stream was
+//! encoded. This is useful when debugging an encoder.
+//!
+//! USAGE:
+//!
+//!     ion_inspect FILE
+//!
+//! The report has one line per item in the stream, including system values like Ion version
+//! markers and symbol tables. Each line shows the item's byte offset, its header bytes in hex
+//! (the annotations wrapper, if any, followed by the type descriptor and trailing length), the
+//! length of its body as declared in its header, and a description of the value. Nested values
+//! are indented beneath their container. Field names and annotations are shown with their text
+//! when it is known, and as `$<symbol ID>` otherwise.
+
+#[cfg(not(feature = "experimental"))]
+fn main() {
+    println!("This example requires the 'experimental' feature to work; try again with `--features experimental`");
+}
+
+#[cfg(feature = "experimental")]
+fn main() {
+    ion_inspect::main()
+}
+
+#[cfg(feature = "experimental")]
+mod ion_inspect {
+    use std::fmt::Write;
+    use std::io::Write as _;
+    use std::process::exit;
+
+    use ion_rs::v1_0::Binary;
+    use ion_rs::*;
+
+    const USAGE: &str = "USAGE:\n\n    ion_inspect FILE\n";
+
+    const COLUMN_HEADINGS: &str =
+        "  offset | header                   | length | value\n---------+--------------------------+--------+------\n";
+
+    pub fn main() {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let [path] = args.as_slice() else {
+            eprintln!("{USAGE}\nExpected exactly one input file.");
+            exit(1);
+        };
+        let input = match std::fs::read(path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{path}: could not read file: {e}");
+                exit(1);
+            }
+        };
+        match inspect(&input) {
+            Ok(report) => std::io::stdout()
+                .lock()
+                .write_all(report.as_bytes())
+                .expect("failed to write to STDOUT"),
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                exit(1);
+            }
+        }
+    }
+
+    /// Returns a report describing the encoding of each item in `input`, which must be a binary
+    /// Ion 1.0 stream.
+    pub fn inspect(input: &[u8]) -> IonResult<String> {
+        let mut reader = SystemReader::new(Binary, input);
+        let mut report = String::from(COLUMN_HEADINGS);
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::VersionMarker(marker) => {
+                    let (major, minor) = marker.major_minor();
+                    let description = format!("$ion_{major}_{minor}");
+                    let span = marker.span();
+                    write_line(
+                        &mut report,
+                        Some(span.range().start),
+                        span.bytes(),
+                        None,
+                        &description,
+                    );
+                }
+                SystemStreamItem::SymbolTable(symbol_table) => {
+                    inspect_value(&mut report, 0, None, symbol_table.as_value())?
+                }
+                SystemStreamItem::Value(value) => inspect_value(&mut report, 0, None, value)?,
+                SystemStreamItem::EndOfStream(_) => return Ok(report),
+                // Encoding directives only appear in Ion 1.1 streams.
+                _ => unreachable!("found a system stream item that binary Ion 1.0 cannot contain"),
+            }
+        }
+    }
+
+    /// Adds a line describing `value` to the report, followed by a line for each of its child
+    /// values (if any).
+    fn inspect_value(
+        report: &mut String,
+        depth: usize,
+        field_name: Option<SymbolRef>,
+        value: LazyValue<Binary>,
+    ) -> IonResult<()> {
+        let raw_value = value
+            .raw()
+            .expect("binary Ion 1.0 values are always encoded in the stream");
+        let encoded = raw_value.encoded_data();
+        // The annotations wrapper immediately precedes the value's own header.
+        let mut header = Vec::new();
+        if let Some(annotations) = raw_value.encoded_annotations() {
+            header.extend_from_slice(annotations.span().bytes());
+        }
+        header.extend_from_slice(encoded.header_span().bytes());
+
+        let mut description = "  ".repeat(depth);
+        if let Some(name) = field_name {
+            write!(description, "{}: ", symbol_text(name)).unwrap();
+        }
+        for annotation in value.annotations() {
+            write!(description, "{}::", symbol_text(annotation?)).unwrap();
+        }
+        if value.is_null() {
+            write!(description, "null.{}", value.ion_type()).unwrap();
+        } else if value.is_container() {
+            write!(description, "{}", value.ion_type()).unwrap();
+        } else {
+            let element = Element::try_from(value)?;
+            write!(description, "{} {}", value.ion_type(), element.value()).unwrap();
+        }
+        write_line(
+            report,
+            // Values read from the stream always have a range; only values constructed while
+            // expanding a macro do not, and binary Ion 1.0 has no macros.
+            value.range().map(|range| range.start),
+            &header,
+            Some(encoded.body_range().len()),
+            &description,
+        );
+
+        if value.is_null() {
+            return Ok(());
+        }
+        match value.read()? {
+            ValueRef::List(list) => {
+                for child in list.iter() {
+                    inspect_value(report, depth + 1, None, child?)?;
+                }
+            }
+            ValueRef::SExp(sexp) => {
+                for child in sexp.iter() {
+                    inspect_value(report, depth + 1, None, child?)?;
+                }
+            }
+            ValueRef::Struct(strukt) => {
+                for field in strukt.iter() {
+                    let field = field?;
+                    inspect_value(report, depth + 1, Some(field.name()?), field.value())?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Returns the symbol's text if it is known. Otherwise, returns its symbol ID as `$<ID>`.
+    fn symbol_text(symbol: SymbolRef) -> String {
+        match symbol.text() {
+            Some(text) => text.to_owned(),
+            None => format!("${}", symbol.unknown_text_sid().unwrap_or(0)),
+        }
+    }
+
+    fn write_line(
+        report: &mut String,
+        offset: Option<usize>,
+        header: &[u8],
+        length: Option<usize>,
+        description: &str,
+    ) {
+        let hex: Vec<String> = header.iter().map(|byte| format!("{byte:02x}")).collect();
+        let offset = offset.map(|o| o.to_string()).unwrap_or_default();
+        let length = length.map(|l| l.to_string()).unwrap_or_default();
+        writeln!(
+            report,
+            "{offset:>8} | {:<24} | {length:>6} | {description}",
+            hex.join(" ")
+        )
+        .unwrap();
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn inspect_golden_report() -> IonResult<()> {
+            let text = r#"
+                {name: "widget", tags: [blue, round], price: 12.50}
+                annotated::(a 1)
+                null.int
+                $0
+            "#;
+            let mut writer = Writer::new(Binary, Vec::new())?;
+            for element in Element::read_all(text)? {
+                writer.write(element)?;
+            }
+            let binary = writer.close()?;
+
+            // The leading newline keeps the first line of the report aligned with the rest.
+            let expected = r#"
+  offset | header                   | length | value
+---------+--------------------------+--------+------
+       0 | e0 01 00 ea              |        | $ion_1_0
+       4 | ee ac 81 83 de a8        |     40 | $ion_symbol_table::struct
+      11 | 71                       |      1 |   imports: symbol $ion_symbol_table
+      14 | be a2                    |     34 |   symbols: list
+      16 | 84                       |      4 |     string "tags"
+      21 | 84                       |      4 |     string "blue"
+      26 | 85                       |      5 |     string "round"
+      32 | 85                       |      5 |     string "price"
+      38 | 89                       |      9 |     string "annotated"
+      48 | 81                       |      1 |     string "a"
+      50 | de 93                    |     19 | struct
+      53 | 86                       |      6 |   name: string "widget"
+      61 | b4                       |      4 |   tags: list
+      62 | 71                       |      1 |     symbol blue
+      64 | 71                       |      1 |     symbol round
+      67 | 53                       |      3 |   price: decimal 12.50
+      71 | e7 81 8e c4              |      4 | annotated::sexp
+      75 | 71                       |      1 |   symbol a
+      77 | 21                       |      1 |   int 1
+      79 | 2f                       |      0 | null.int
+      80 | 70                       |      0 | symbol $0
+"#;
+            assert_eq!(inspect(&binary)?, expected.trim_start_matches('\n'));
+            Ok(())
+        }
+
+        #[test]
+        fn inspect_rejects_text() {
+            assert!(inspect(b"{foo: bar}").is_err());
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#![no_main]
+
+use ion_rs::v1_0::ImmutableBuffer;
+use ion_rs::v1_1::{FlexInt, FlexUInt};
+use libfuzzer_sys::fuzz_target;
+
+// Decodes the input as each of the variable-length integer encodings. Decoding may fail, but must
+// not panic.
+fuzz_target!(|data: &[u8]| {
+    let buffer = ImmutableBuffer::new(data);
+    let _ = buffer.read_var_uint();
+    let _ = buffer.read_var_int();
+    let _ = FlexUInt::read(data, 0);
+    let _ = FlexInt::read(data, 0);
+});
@@ -0,0 +1,24 @@
+#![no_main]
+
+use ion_rs::v1_0::Binary;
+use ion_rs::{LazyRawBinaryReader_1_0, RawStreamItem};
+use ion_rs_fuzz::visit_raw_value;
+use libfuzzer_sys::fuzz_target;
+
+// Visits every raw value in a binary Ion 1.0 stream. Reading may fail, but must not panic.
+fuzz_target!(|data: &[u8]| {
+    let mut input = vec![0xE0, 0x01, 0x00, 0xEA];
+    input.extend_from_slice(data);
+    let mut reader = LazyRawBinaryReader_1_0::new(&input);
+    loop {
+        match reader.next() {
+            Ok(RawStreamItem::Value(value)) => {
+                if visit_raw_value::<Binary>(value).is_err() {
+                    break;
+                }
+            }
+            Ok(RawStreamItem::EndOfStream(_)) | Err(_) => break,
+            Ok(_) => {}
+        }
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use ion_rs::v1_0::{LazyRawTextReader, Text};
+use ion_rs::{EncodingContext, RawStreamItem};
+use ion_rs_fuzz::visit_raw_value;
+use libfuzzer_sys::fuzz_target;
+
+// Visits every raw value in a text Ion 1.0 stream. Reading may fail, but must not panic.
+fuzz_target!(|data: &[u8]| {
+    let context = EncodingContext::empty();
+    let mut reader = LazyRawTextReader::new(data);
+    loop {
+        match reader.next(context.get_ref()) {
+            Ok(RawStreamItem::Value(value)) => {
+                if visit_raw_value::<Text>(value).is_err() {
+                    break;
+                }
+            }
+            Ok(RawStreamItem::EndOfStream(_)) | Err(_) => break,
+            Ok(_) => {}
+        }
+    }
+});
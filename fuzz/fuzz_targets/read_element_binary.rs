@@ -0,0 +1,14 @@
+#![no_main]
+
+use ion_rs::v1_0::Binary;
+use ion_rs::{ElementReader, Reader};
+use libfuzzer_sys::fuzz_target;
+
+// Materializes every value in a binary Ion 1.0 stream. Reading may fail, but must not panic.
+fuzz_target!(|data: &[u8]| {
+    let mut input = vec![0xE0, 0x01, 0x00, 0xEA];
+    input.extend_from_slice(data);
+    if let Ok(mut reader) = Reader::new(Binary, input) {
+        let _ = reader.read_all_elements();
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use ion_rs::v1_0::Text;
+use ion_rs::{ElementReader, Reader};
+use libfuzzer_sys::fuzz_target;
+
+// Materializes every value in a text Ion 1.0 stream. Reading may fail, but must not panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut reader) = Reader::new(Text, data) {
+        let _ = reader.read_all_elements();
+    }
+});
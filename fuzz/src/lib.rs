@@ -0,0 +1,53 @@
+//! Helpers shared by the fuzz targets in `fuzz_targets/`.
+
+use ion_rs::v1_0::RawValueRef;
+use ion_rs::{
+    Decoder, IonResult, LazyRawFieldExpr, LazyRawFieldName, LazyRawSequence, LazyRawStruct,
+    LazyRawValue, RawValueExpr,
+};
+
+/// Containers nested more deeply than this are not visited, keeping stack usage bounded.
+const MAX_DEPTH: usize = 64;
+
+/// Reads `value`, its annotations, and (recursively) all of its child values.
+pub fn visit_raw_value<'top, D: Decoder>(value: D::Value<'top>) -> IonResult<()> {
+    visit_raw_value_at_depth::<D>(value, 0)
+}
+
+fn visit_raw_value_at_depth<'top, D: Decoder>(
+    value: D::Value<'top>,
+    depth: usize,
+) -> IonResult<()> {
+    if depth > MAX_DEPTH {
+        return Ok(());
+    }
+    for annotation in value.annotations() {
+        annotation?;
+    }
+    match value.read()? {
+        RawValueRef::List(list) => {
+            for child in list.iter() {
+                if let RawValueExpr::ValueLiteral(child) = child? {
+                    visit_raw_value_at_depth::<D>(child, depth + 1)?;
+                }
+            }
+        }
+        RawValueRef::SExp(sexp) => {
+            for child in sexp.iter() {
+                if let RawValueExpr::ValueLiteral(child) = child? {
+                    visit_raw_value_at_depth::<D>(child, depth + 1)?;
+                }
+            }
+        }
+        RawValueRef::Struct(strukt) => {
+            for field in strukt.iter() {
+                if let LazyRawFieldExpr::NameValue(name, child) = field? {
+                    name.read()?;
+                    visit_raw_value_at_depth::<D>(child, depth + 1)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -160,4 +160,32 @@ mod binary_decimal_tests {
         assert_eq!(value, after_round_trip);
         Ok(())
     }
+
+    // `Decimal`'s coefficient is backed by an i128, so the widest coefficient it can hold has
+    // 39 digits; the cases below use the widest 38-digit values that fit in either direction.
+    #[rstest]
+    #[case::zero(Decimal::new(0, 0))]
+    #[case::negative_zero(Decimal::negative_zero())]
+    #[case::zero_large_exponent(Decimal::new(0, 10_000))]
+    #[case::negative_zero_small_exponent(Decimal::negative_zero_with_exponent(-10_000))]
+    #[case::two_to_the_63(Decimal::new(1i128 << 63, 0))]
+    #[case::negative_two_to_the_63(Decimal::new(-(1i128 << 63), 0))]
+    #[case::wide_coefficient(Decimal::new(99999999999999999999999999999999999999i128, 10_000))]
+    #[case::negative_wide_coefficient(Decimal::new(-99999999999999999999999999999999999999i128, -10_000))]
+    #[case::i64_max_exponent(Decimal::new(1, i64::MAX))]
+    #[case::i64_min_exponent(Decimal::new(-1, i64::MIN))]
+    #[case::negative_zero_i64_min_exponent(Decimal::negative_zero_with_exponent(i64::MIN))]
+    #[case::i128_min_i64_min(Decimal::new(i128::MIN, i64::MIN))]
+    fn roundtrip_decimals_preserves_ion_equality(#[case] value: Decimal) -> IonResult<()> {
+        let mut writer = Writer::new(BinaryEncoding_1_0::default_write_config(), Vec::new())?;
+        writer.write(value)?;
+        let output = writer.close()?;
+        let mut reader = Reader::new(AnyEncoding, output)?;
+        let after_round_trip = reader.expect_next()?.read()?.expect_decimal()?;
+        assert!(
+            value.ion_eq(&after_round_trip),
+            "expected {value:?}, found {after_round_trip:?}"
+        );
+        Ok(())
+    }
 }
@@ -2,7 +2,8 @@ use std::mem;
 
 use crate::decimal::coefficient::Coefficient;
 use crate::result::IonResult;
-use crate::Int;
+use crate::{Int, IonError};
+use num_bigint::{BigInt, Sign};
 use num_traits::Zero;
 use std::io::Write;
 
@@ -38,7 +39,10 @@ impl DecodedInt {
     /// Encodes the provided `value` as an Int and writes it to the provided `sink`.
     /// Returns the number of bytes written.
     pub fn write<W: Write>(sink: &mut W, value: impl Into<Int>) -> IonResult<usize> {
-        let value = value.into().data;
+        let value = value.into();
+        let Some(value) = value.as_i128() else {
+            return Self::write_big_int(sink, value.into());
+        };
         let magnitude = value.unsigned_abs();
         // Using leading_zeros() to determine how many empty bytes we can ignore.
         // We subtract one from the number of leading bits to leave space for a sign bit
@@ -70,6 +74,22 @@ impl DecodedInt {
         Ok(bytes_written)
     }
 
+    /// Encodes a value outside the range of an `i128` as an Int and writes it to the provided
+    /// `sink`. Returns the number of bytes written.
+    #[inline(never)]
+    fn write_big_int<W: Write>(sink: &mut W, value: BigInt) -> IonResult<usize> {
+        let (sign, mut bytes) = value.to_bytes_be();
+        // If the magnitude's highest bit is set, it needs an additional byte for the sign bit.
+        if bytes[0] & 0b1000_0000 != 0 {
+            bytes.insert(0, 0);
+        }
+        if sign == Sign::Minus {
+            bytes[0] |= 0b1000_0000;
+        }
+        sink.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+
     /// Encodes a negative zero as an `Int` and writes it to the provided `sink`.
     /// Returns the number of bytes written.
     ///
@@ -125,12 +145,14 @@ impl From<DecodedInt> for Int {
     }
 }
 
-impl From<DecodedInt> for Coefficient {
-    fn from(decoded_int: DecodedInt) -> Self {
+impl TryFrom<DecodedInt> for Coefficient {
+    type Error = IonError;
+
+    fn try_from(decoded_int: DecodedInt) -> Result<Self, Self::Error> {
         if decoded_int.is_negative_zero() {
-            return Coefficient::negative_zero();
+            return Ok(Coefficient::negative_zero());
         }
-        Coefficient::new(decoded_int)
+        Coefficient::try_from(decoded_int.value)
     }
 }
 
@@ -1,8 +1,9 @@
 use std::io::Write;
 use std::mem;
 
-use crate::result::{IonFailure, IonResult};
-use crate::{Int, IonError, UInt};
+use crate::result::IonResult;
+use crate::types::integer::UIntData;
+use crate::{Int, UInt};
 
 /// Represents a fixed-length unsigned integer. See the
 /// [UInt and Int Fields](https://amazon-ion.github.io/ion-docs/docs/binary.html#uint-and-int-fields)
@@ -21,19 +22,6 @@ impl DecodedUInt {
         }
     }
 
-    /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
-    /// If the length of `uint_bytes` is greater than the size of a `u128`, returns `Err`.
-    #[inline]
-    pub(crate) fn uint_from_slice(uint_bytes: &[u8]) -> IonResult<u128> {
-        if uint_bytes.len() > mem::size_of::<u128>() {
-            return IonResult::decoding_error(
-                "integer size is currently limited to the range of an i128",
-            );
-        }
-
-        Ok(Self::uint_from_slice_unchecked(uint_bytes))
-    }
-
     /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
     /// Panics if the length of `uint_bytes` is greater than the size of a `u128`.
     #[inline]
@@ -68,27 +56,35 @@ impl DecodedUInt {
     }
 }
 
-impl TryFrom<DecodedUInt> for Int {
-    type Error = IonError;
-
-    fn try_from(uint: DecodedUInt) -> Result<Self, Self::Error> {
+impl From<DecodedUInt> for Int {
+    fn from(uint: DecodedUInt) -> Self {
         let DecodedUInt {
             value,
             .. // Ignore 'size_in_bytes'
         } = uint;
-        value.try_into()
+        value.into()
     }
 }
 
 /// A buffer for storing a UInt's Big Endian bytes.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct UIntBeBytes {
-    bytes: [u8; mem::size_of::<u128>()],
+pub enum UIntBeBytes {
+    /// The bytes of a magnitude that fits in a `u128`, including any leading zero bytes.
+    Inline([u8; mem::size_of::<u128>()]),
+    /// The bytes of a magnitude that is too large to fit in a `u128`.
+    Heap(Vec<u8>),
 }
 
 impl UIntBeBytes {
     pub fn new(bytes: [u8; mem::size_of::<u128>()]) -> Self {
-        Self { bytes }
+        Self::Inline(bytes)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            UIntBeBytes::Inline(bytes) => bytes.as_slice(),
+            UIntBeBytes::Heap(bytes) => bytes.as_slice(),
+        }
     }
 }
 
@@ -106,7 +102,7 @@ pub struct EncodedUInt {
 impl EncodedUInt {
     /// Returns the slice view of the encoded UInt.
     pub fn as_bytes(&self) -> &[u8] {
-        &self.be_bytes.bytes[self.first_occupied_byte..]
+        &self.be_bytes.as_bytes()[self.first_occupied_byte..]
     }
 }
 
@@ -131,6 +127,18 @@ pub fn encode(magnitude: impl Into<u128>) -> EncodedUInt {
     }
 }
 
+/// Returns the magnitude as big-endian bytes. Unlike [`encode`], this supports magnitudes that
+/// are too large to fit in a `u128`.
+pub fn encode_uint(magnitude: &UInt) -> EncodedUInt {
+    match &magnitude.data {
+        UIntData::U128(magnitude) => encode(*magnitude),
+        UIntData::BigUInt(magnitude) => EncodedUInt {
+            be_bytes: UIntBeBytes::Heap(magnitude.to_bytes_be()),
+            first_occupied_byte: 0,
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
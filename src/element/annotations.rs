@@ -16,7 +16,7 @@ use std::cmp::Ordering;
 ///     assert_eq!(annotation.text().map(|s| s.len()), Some(3));
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Annotations {
     symbols: Vec<Symbol>,
 }
@@ -58,6 +58,13 @@ impl SequenceBuilder {
         Self { values: Vec::new() }
     }
 
+    /// Crate visible; users should call [`Sequence::builder_with_capacity()`] instead.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
     /// Helper method for [`Sequence::clone_builder()`].
     pub(crate) fn with_initial_elements(elements: &[Element]) -> Self {
         let mut new_elements = Vec::with_capacity(elements.len());
@@ -73,6 +80,33 @@ impl SequenceBuilder {
         self
     }
 
+    /// Adds each of the provided elements to the end of the [`Sequence`] being constructed.
+    ///
+    /// ```
+    /// use ion_rs::{Element, Sequence};
+    /// let actual: Element = Sequence::builder_with_capacity(100)
+    ///     .push("start")
+    ///     .extend(0..100)
+    ///     .build_list()
+    ///     .into();
+    /// assert_eq!(actual.as_sequence().unwrap().len(), 101);
+    /// ```
+    pub fn extend<E, I>(mut self, elements: I) -> Self
+    where
+        E: Into<Element>,
+        I: IntoIterator<Item = E>,
+    {
+        self.values.extend(elements.into_iter().map(Into::into));
+        self
+    }
+
+    /// Reserves capacity for at least `additional` more elements in the [`Sequence`] being
+    /// constructed.
+    pub fn reserve(mut self, additional: usize) -> Self {
+        self.values.reserve(additional);
+        self
+    }
+
     /// Removes the element at the specified position from the [`Sequence`] being constructed.
     /// If the index is out of bounds, this method will panic.
     pub fn remove(mut self, index: usize) -> Self {
@@ -140,6 +174,13 @@ impl StructBuilder {
         StructBuilder { fields: Vec::new() }
     }
 
+    /// Crate visible; users should call [`Struct::builder_with_capacity()`] instead.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        StructBuilder {
+            fields: Vec::with_capacity(capacity),
+        }
+    }
+
     /// Helper method for [`Struct::clone_builder()`].
     pub(crate) fn with_initial_fields(elements: &[(Symbol, Element)]) -> Self {
         let mut new_elements = Vec::with_capacity(elements.len());
@@ -197,6 +238,13 @@ impl StructBuilder {
         self
     }
 
+    /// Reserves capacity for at least `additional` more fields in the [`Struct`] being
+    /// constructed.
+    pub fn reserve(mut self, additional: usize) -> Self {
+        self.fields.reserve(additional);
+        self
+    }
+
     /// Removes the first field with the specified name from the [`Struct`] being constructed.
     pub fn remove_field<A: AsRef<str>>(mut self, field_to_remove: A) -> Self {
         // TODO: This removes the first field with a matching name.
@@ -313,7 +361,7 @@ macro_rules! ion_sexp {
 /// let prefix = "abc";
 /// let suffix = "def";
 /// // Construct an s-expression Element from Rust values
-/// let actual = ion_struct! {
+/// let actual: Element = ion_struct! {
 ///     "w": "foo",
 /// //   ^--- Quoted strings are field name literals
 /// //   v--- Unquoted field names are interpreted as variables
@@ -377,7 +425,7 @@ use crate::{List, SExp};
 mod tests {
     use crate::element::builders::{SequenceBuilder, StructBuilder};
     use crate::element::Element;
-    use crate::{ion_list, ion_sexp, ion_struct, Symbol};
+    use crate::{ion_list, ion_sexp, ion_struct, IntoAnnotatedElement, Sequence, Struct, Symbol};
 
     #[test]
     fn make_seq_with_macro() {
@@ -542,4 +590,70 @@ mod tests {
         let expected = Element::read_one(r#"{a: 1, c: "foo", d: baz}"#).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn make_large_list_with_builder() {
+        let mut builder = Sequence::builder_with_capacity(10_000);
+        for i in 0..10_000 {
+            builder = builder.push(i);
+        }
+        let actual: Element = builder.build_list().into();
+        let expected_text = format!(
+            "[{}]",
+            (0..10_000)
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let expected = Element::read_one(expected_text).unwrap();
+        assert_eq!(actual, expected);
+
+        let extended: Element = Sequence::builder().extend(0..10_000).build_list().into();
+        assert_eq!(extended, expected);
+    }
+
+    #[test]
+    fn extend_sequence_builder() {
+        let actual = Sequence::builder_with_capacity(4)
+            .push(1)
+            .extend([true, false])
+            .extend(vec![Element::string("foo")])
+            .reserve(10)
+            .push(Symbol::owned("bar"))
+            .build_sexp();
+        assert_eq!(actual, ion_sexp!(1 true false "foo" Symbol::owned("bar")));
+    }
+
+    #[test]
+    fn make_struct_with_duplicate_fields_using_capacity() {
+        let actual: Element = Struct::builder_with_capacity(4)
+            .with_field("a", 1)
+            .with_field("b", 2)
+            .reserve(2)
+            .with_field("a", 3)
+            .with_fields([("b", 4)])
+            .build()
+            .into();
+        let expected: Element = ion_struct! {"a": 1, "b": 2, "a": 3, "b": 4}.into();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual,
+            Element::read_one("{a: 1, b: 2, a: 3, b: 4}").unwrap()
+        );
+    }
+
+    #[test]
+    fn annotate_built_containers() {
+        let list = Sequence::builder_with_capacity(2)
+            .extend([1, 2])
+            .build_list()
+            .with_annotations(["foo", "bar"]);
+        let strukt = Struct::builder_with_capacity(1)
+            .with_field("a", 1)
+            .build()
+            .with_annotations(["baz"]);
+        let expected = Element::read_all("foo::bar::[1, 2] baz::{a: 1}").unwrap();
+        assert_eq!(list, expected.get(0).unwrap().clone());
+        assert_eq!(strukt, expected.get(1).unwrap().clone());
+    }
 }
@@ -34,7 +34,14 @@ macro_rules! create_new_slice_iterator_type {
             fn next(&mut self) -> Option<Self::Item> {
                 self.values.as_mut().and_then(|iter| iter.next())
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.values.as_ref().map(|iter| iter.len()).unwrap_or(0);
+                (remaining, Some(remaining))
+            }
         }
+
+        impl<'a> ExactSizeIterator for $iterator_name<'a> {}
     )*)
 }
 create_new_slice_iterator_type!(
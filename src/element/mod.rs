@@ -13,25 +13,32 @@
 //! [serde-json-value]: https://docs.serde.rs/serde_json/value/enum.Value.html
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::io;
+use std::hash::{Hash, Hasher};
+use std::{io, mem};
 
 pub use annotations::{Annotations, IntoAnnotations};
 pub use sequence::Sequence;
 
-use crate::{ion_data, Decimal, Int, IonError, IonResult, IonType, Str, Symbol, Timestamp};
-use crate::{Blob, Bytes, Clob, List, SExp, Struct};
+use crate::{
+    ion_data, Decimal, Int, IonError, IonResult, IonType, Str, Symbol, Timestamp,
+    TimestampPrecision,
+};
+use crate::{Blob, Bytes, Clob, DuplicateFieldPolicy, List, SExp, Struct};
 // Re-export the Value variant types and traits so they can be accessed directly from this module.
 use crate::element::builders::{SequenceBuilder, StructBuilder};
-use crate::element::reader::ElementReader;
+use crate::element::reader::{ElementReader, OwnedElementIterator};
 use crate::ion_data::{IonEq, IonOrd};
 use crate::lazy::any_encoding::AnyEncoding;
 use crate::lazy::encoding::Encoding;
 use crate::lazy::reader::Reader;
-use crate::lazy::streaming_raw_reader::{IonInput, IonSlice};
-use crate::result::IonFailure;
+use crate::lazy::streaming_raw_reader::{IonInput, IonSlice, IonStream};
+use crate::lazy::system_reader::ReaderCheckpoint;
+use crate::result::{type_mismatch_error, IonFailure};
 use crate::text::text_formatter::FmtValueFormatter;
 use crate::write_config::WriteConfig;
+use crate::{v1_0, Format, TextFormat};
 
 mod annotations;
 pub(crate) mod iterators;
@@ -108,7 +115,7 @@ impl IonOrd for Value {
 }
 
 /// Variants for all _values_ within an [`Element`].
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub enum Value {
     Null(IonType),
     Bool(bool),
@@ -125,6 +132,75 @@ pub enum Value {
     Struct(Struct),
 }
 
+/// Values are compared using Ion equivalence (see [`IonEq`](crate::IonData)), which makes `==`
+/// reflexive and consistent with [`Value`]'s [`Hash`] implementation:
+///
+/// * Any `nan` is equal to any other `nan`, while `0e0` and `-0e0` are not equal.
+/// * Decimals must have the same precision to be equal; `1d0` and `1.0` are not equal, nor are
+///   `0d0` and `-0d0`.
+/// * Timestamps must have the same precision and offset to be equal, even if they represent the
+///   same instant.
+/// * Structs are equal if they have the same fields, regardless of order. Repeated fields must
+///   appear the same number of times.
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.ion_eq(other)
+    }
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        use Value::*;
+        // Each value type's hash must be consistent with its Ion equivalence.
+        mem::discriminant(self).hash(state);
+        match self {
+            Null(ion_type) => ion_type.hash(state),
+            Bool(b) => b.hash(state),
+            Int(i) => i.hash(state),
+            // All `nan`s are equivalent. All other floats are equivalent if and only if they have
+            // the same bits.
+            Float(f) if f.is_nan() => f64::NAN.to_bits().hash(state),
+            Float(f) => f.to_bits().hash(state),
+            Decimal(d) => {
+                d.coefficient.hash(state);
+                d.exponent.hash(state);
+            }
+            Timestamp(t) => hash_timestamp(t, state),
+            Symbol(s) => s.hash(state),
+            String(s) => s.hash(state),
+            Clob(b) | Blob(b) => b.hash(state),
+            List(s) | SExp(s) => s.hash(state),
+            Struct(s) => s.hash(state),
+        }
+    }
+}
+
+/// Hashes the fields that [`Timestamp`]'s Ion equivalence compares. Fractional seconds are
+/// omitted because equivalent fractional seconds can be stored in more than one representation.
+fn hash_timestamp<H: Hasher>(timestamp: &Timestamp, state: &mut H) {
+    use chrono::{Datelike, Timelike};
+    let precision = timestamp.precision();
+    let date_time = timestamp.date_time;
+    precision.hash(state);
+    timestamp.offset.hash(state);
+    date_time.year().hash(state);
+    if precision >= TimestampPrecision::Month {
+        date_time.month().hash(state);
+    }
+    if precision >= TimestampPrecision::Day {
+        date_time.day().hash(state);
+    }
+    if precision >= TimestampPrecision::HourAndMinute {
+        date_time.hour().hash(state);
+        date_time.minute().hash(state);
+    }
+    if precision >= TimestampPrecision::Second {
+        date_time.second().hash(state);
+    }
+}
+
 impl Value {
     pub fn ion_type(&self) -> IonType {
         use Value::*;
@@ -145,6 +221,121 @@ impl Value {
             Struct(_) => IonType::Struct,
         }
     }
+
+    /// If this value is an Ion bool, returns it. Otherwise, returns the original value.
+    pub fn try_into_bool(self) -> Result<bool, Value> {
+        match self {
+            Value::Bool(b) => Ok(b),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion int, returns it. Otherwise, returns the original value.
+    pub fn try_into_int(self) -> Result<Int, Value> {
+        match self {
+            Value::Int(i) => Ok(i),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion float, returns it. Otherwise, returns the original value.
+    pub fn try_into_float(self) -> Result<f64, Value> {
+        match self {
+            Value::Float(f) => Ok(f),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion decimal, returns it. Otherwise, returns the original value.
+    pub fn try_into_decimal(self) -> Result<Decimal, Value> {
+        match self {
+            Value::Decimal(d) => Ok(d),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion timestamp, returns it. Otherwise, returns the original value.
+    pub fn try_into_timestamp(self) -> Result<Timestamp, Value> {
+        match self {
+            Value::Timestamp(t) => Ok(t),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion string, returns it. Otherwise, returns the original value.
+    pub fn try_into_string(self) -> Result<Str, Value> {
+        match self {
+            Value::String(text) => Ok(text),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion symbol, returns it. Otherwise, returns the original value.
+    pub fn try_into_symbol(self) -> Result<Symbol, Value> {
+        match self {
+            Value::Symbol(symbol) => Ok(symbol),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion blob or clob, returns its bytes. Otherwise, returns the original
+    /// value.
+    pub fn try_into_lob(self) -> Result<Bytes, Value> {
+        match self {
+            Value::Blob(bytes) | Value::Clob(bytes) => Ok(bytes),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion blob, returns its bytes. Otherwise, returns the original value.
+    pub fn try_into_blob(self) -> Result<Bytes, Value> {
+        match self {
+            Value::Blob(bytes) => Ok(bytes),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion clob, returns its bytes. Otherwise, returns the original value.
+    pub fn try_into_clob(self) -> Result<Bytes, Value> {
+        match self {
+            Value::Clob(bytes) => Ok(bytes),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion list or s-expression, returns its [`Sequence`]. Otherwise, returns
+    /// the original value.
+    pub fn try_into_sequence(self) -> Result<Sequence, Value> {
+        match self {
+            Value::List(sequence) | Value::SExp(sequence) => Ok(sequence),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion list, returns its [`Sequence`]. Otherwise, returns the original value.
+    pub fn try_into_list(self) -> Result<Sequence, Value> {
+        match self {
+            Value::List(sequence) => Ok(sequence),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion s-expression, returns its [`Sequence`]. Otherwise, returns the
+    /// original value.
+    pub fn try_into_sexp(self) -> Result<Sequence, Value> {
+        match self {
+            Value::SExp(sequence) => Ok(sequence),
+            other => Err(other),
+        }
+    }
+
+    /// If this value is an Ion struct, returns it. Otherwise, returns the original value.
+    pub fn try_into_struct(self) -> Result<Struct, Value> {
+        match self {
+            Value::Struct(structure) => Ok(structure),
+            other => Err(other),
+        }
+    }
 }
 
 impl Display for Value {
@@ -311,27 +502,16 @@ impl IonEq for Element {
 }
 
 // Ordering is done as follows:
-// 1. Ion type -- It is a logical way to group Ion values, and it is the cheapest comparison
-// 2. Annotations -- the vast majority of Ion values have few annotations, so this should usually be cheap
-// 3. Value -- compared using IonOrd
+// 1. Value -- compared using IonOrd, which orders by Ion type first
+// 2. Annotations -- only consulted when the values are equivalent, so that values sort together
+//    regardless of how they are annotated
 impl IonOrd for Element {
     fn ion_cmp(&self, other: &Self) -> Ordering {
-        let ord = self.ion_type().ion_cmp(&other.ion_type());
+        let ord = self.value().ion_cmp(other.value());
         if !ord.is_eq() {
             return ord;
         }
-
-        let a1 = self.annotations();
-        let a2 = other.annotations();
-
-        let ord = a1.ion_cmp(a2);
-        if !ord.is_eq() {
-            return ord;
-        }
-
-        let v1 = self.value();
-        let v2 = other.value();
-        v1.ion_cmp(v2)
+        self.annotations().ion_cmp(other.annotations())
     }
 }
 
@@ -356,12 +536,8 @@ impl Element {
         }
     }
 
-    fn expected(&self, expected: IonType) -> IonError {
-        IonError::decoding_error(format!(
-            "expected a(n) {}, found a(n) {}",
-            expected,
-            self.ion_type()
-        ))
+    fn expected(&self, expected: &str) -> IonError {
+        type_mismatch_error(expected, self.ion_type(), self.is_null(), None)
     }
 
     /// Returns a reference to this [Element]'s [Value].
@@ -440,6 +616,59 @@ impl Element {
         &self.annotations
     }
 
+    /// Returns `true` if any of this element's annotations has the text `text`.
+    /// ```
+    /// use ion_rs::{Element, IonResult};
+    ///# fn main() -> IonResult<()> {
+    /// let element = Element::read_one("foo::bar::99")?;
+    /// assert!(element.has_annotation("bar"));
+    /// assert!(!element.has_annotation("baz"));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn has_annotation(&self, text: &str) -> bool {
+        self.annotations.contains(text)
+    }
+
+    /// Returns `true` if this element's annotations are exactly the provided sequence of texts.
+    /// ```
+    /// use ion_rs::{Element, IonResult};
+    ///# fn main() -> IonResult<()> {
+    /// let element = Element::read_one("foo::bar::99")?;
+    /// assert!(element.annotations_eq(["foo", "bar"]));
+    /// assert!(!element.annotations_eq(["foo"]));
+    /// assert!(!element.annotations_eq([]));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn annotations_eq<'a>(&self, texts: impl IntoIterator<Item = &'a str>) -> bool {
+        let mut annotations = self.annotations.iter();
+        for text in texts {
+            match annotations.next() {
+                Some(annotation) if annotation.text() == Some(text) => {}
+                _ => return false,
+            }
+        }
+        annotations.next().is_none()
+    }
+
+    /// Like [`Self::has_annotation`], but returns an [`IonError::Decoding`] describing this
+    /// element's actual annotations if none of them has the text `text`.
+    pub fn expect_annotation(&self, text: &str) -> IonResult<()> {
+        if self.has_annotation(text) {
+            return Ok(());
+        }
+        let found: Vec<_> = self
+            .annotations
+            .iter()
+            .map(|symbol| symbol.text().unwrap_or("$0"))
+            .collect();
+        IonResult::decoding_error(format!(
+            "expected a value with the annotation '{text}'; found annotations [{}]",
+            found.join(", ")
+        ))
+    }
+
     pub fn with_annotations<I: IntoAnnotations>(self, annotations: I) -> Self {
         Element::new(annotations.into_annotations(), self.value)
     }
@@ -448,6 +677,28 @@ impl Element {
         matches!(&self.value, Value::Null(_))
     }
 
+    /// Consumes this [Element], returning its [Value]. The element's annotations are discarded.
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+
+    /// Consumes this [Element], returning its [Annotations] and [Value].
+    ///
+    /// ```
+    /// use ion_rs::{Element, IntoAnnotatedElement};
+    /// # fn main() -> ion_rs::IonResult<()> {
+    /// let element = Element::read_one("foo::bar::[1, 2, 3]")?;
+    /// let (annotations, value) = element.clone().into_parts();
+    /// assert_eq!(annotations.len(), 2);
+    /// assert_eq!(annotations.first(), Some("foo"));
+    /// assert_eq!(value.with_annotations(annotations), element);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_parts(self) -> (Annotations, Value) {
+        (self.annotations, self.value)
+    }
+
     pub fn as_int(&self) -> Option<&Int> {
         match &self.value {
             Value::Int(i) => Some(i),
@@ -456,7 +707,7 @@ impl Element {
     }
 
     pub fn expect_int(&self) -> IonResult<&Int> {
-        self.as_int().ok_or_else(|| self.expected(IonType::Int))
+        self.as_int().ok_or_else(|| self.expected("an int"))
     }
 
     pub fn as_i64(&self) -> Option<i64> {
@@ -469,7 +720,7 @@ impl Element {
     pub fn expect_i64(&self) -> IonResult<i64> {
         match &self.value {
             Value::Int(i) => i.expect_i64(),
-            _ => Err(self.expected(IonType::Int)),
+            _ => Err(self.expected("an int")),
         }
     }
 
@@ -481,7 +732,7 @@ impl Element {
     }
 
     pub fn expect_float(&self) -> IonResult<f64> {
-        self.as_float().ok_or_else(|| self.expected(IonType::Float))
+        self.as_float().ok_or_else(|| self.expected("a float"))
     }
 
     pub fn as_decimal(&self) -> Option<Decimal> {
@@ -492,8 +743,7 @@ impl Element {
     }
 
     pub fn expect_decimal(&self) -> IonResult<Decimal> {
-        self.as_decimal()
-            .ok_or_else(|| self.expected(IonType::Decimal))
+        self.as_decimal().ok_or_else(|| self.expected("a decimal"))
     }
 
     pub fn as_timestamp(&self) -> Option<Timestamp> {
@@ -505,7 +755,7 @@ impl Element {
 
     pub fn expect_timestamp(&self) -> IonResult<Timestamp> {
         self.as_timestamp()
-            .ok_or_else(|| self.expected(IonType::Timestamp))
+            .ok_or_else(|| self.expected("a timestamp"))
     }
 
     pub fn as_text(&self) -> Option<&str> {
@@ -517,12 +767,8 @@ impl Element {
     }
 
     pub fn expect_text(&self) -> IonResult<&str> {
-        self.as_text().ok_or_else(|| {
-            IonError::decoding_error(format!(
-                "expected a text value, found a(n) {}",
-                self.ion_type()
-            ))
-        })
+        self.as_text()
+            .ok_or_else(|| self.expected("a string or a symbol with known text"))
     }
 
     pub fn as_string(&self) -> Option<&str> {
@@ -533,8 +779,7 @@ impl Element {
     }
 
     pub fn expect_string(&self) -> IonResult<&str> {
-        self.as_string()
-            .ok_or_else(|| self.expected(IonType::String))
+        self.as_string().ok_or_else(|| self.expected("a string"))
     }
 
     pub fn as_symbol(&self) -> Option<&Symbol> {
@@ -545,8 +790,7 @@ impl Element {
     }
 
     pub fn expect_symbol(&self) -> IonResult<&Symbol> {
-        self.as_symbol()
-            .ok_or_else(|| self.expected(IonType::Symbol))
+        self.as_symbol().ok_or_else(|| self.expected("a symbol"))
     }
 
     pub fn as_bool(&self) -> Option<bool> {
@@ -557,7 +801,7 @@ impl Element {
     }
 
     pub fn expect_bool(&self) -> IonResult<bool> {
-        self.as_bool().ok_or_else(|| self.expected(IonType::Bool))
+        self.as_bool().ok_or_else(|| self.expected("a bool"))
     }
 
     pub fn as_lob(&self) -> Option<&[u8]> {
@@ -568,12 +812,7 @@ impl Element {
     }
 
     pub fn expect_lob(&self) -> IonResult<&[u8]> {
-        self.as_lob().ok_or_else(|| {
-            IonError::decoding_error(format!(
-                "expected a lob value, found a(n) {}",
-                self.ion_type()
-            ))
-        })
+        self.as_lob().ok_or_else(|| self.expected("a blob or clob"))
     }
 
     pub fn as_blob(&self) -> Option<&[u8]> {
@@ -584,7 +823,7 @@ impl Element {
     }
 
     pub fn expect_blob(&self) -> IonResult<&[u8]> {
-        self.as_blob().ok_or_else(|| self.expected(IonType::Blob))
+        self.as_blob().ok_or_else(|| self.expected("a blob"))
     }
 
     pub fn as_clob(&self) -> Option<&[u8]> {
@@ -595,7 +834,7 @@ impl Element {
     }
 
     pub fn expect_clob(&self) -> IonResult<&[u8]> {
-        self.as_clob().ok_or_else(|| self.expected(IonType::Clob))
+        self.as_clob().ok_or_else(|| self.expected("a clob"))
     }
 
     pub fn as_sequence(&self) -> Option<&Sequence> {
@@ -605,13 +844,18 @@ impl Element {
         }
     }
 
+    /// Like [`as_sequence`](Self::as_sequence), but returns a mutable reference so that the
+    /// sequence's child elements can be modified in place.
+    pub fn as_sequence_mut(&mut self) -> Option<&mut Sequence> {
+        match &mut self.value {
+            Value::SExp(s) | Value::List(s) => Some(s),
+            _ => None,
+        }
+    }
+
     pub fn expect_sequence(&self) -> IonResult<&Sequence> {
-        self.as_sequence().ok_or_else(|| {
-            IonError::decoding_error(format!(
-                "expected a sequence value, found a(n) {}",
-                self.ion_type()
-            ))
-        })
+        self.as_sequence()
+            .ok_or_else(|| self.expected("a list or sexp"))
     }
 
     pub fn as_list(&self) -> Option<&Sequence> {
@@ -622,7 +866,7 @@ impl Element {
     }
 
     pub fn expect_list(&self) -> IonResult<&Sequence> {
-        self.as_list().ok_or_else(|| self.expected(IonType::List))
+        self.as_list().ok_or_else(|| self.expected("a list"))
     }
 
     pub fn as_sexp(&self) -> Option<&Sequence> {
@@ -633,7 +877,7 @@ impl Element {
     }
 
     pub fn expect_sexp(&self) -> IonResult<&Sequence> {
-        self.as_sexp().ok_or_else(|| self.expected(IonType::SExp))
+        self.as_sexp().ok_or_else(|| self.expected("a sexp"))
     }
 
     pub fn as_struct(&self) -> Option<&Struct> {
@@ -643,9 +887,28 @@ impl Element {
         }
     }
 
+    /// Like [`as_struct`](Self::as_struct), but returns a mutable reference so that the struct's
+    /// field values can be modified in place.
+    ///
+    /// ```
+    /// use ion_rs::{ion_list, Element};
+    /// # fn main() -> ion_rs::IonResult<()> {
+    /// let mut element = Element::read_one("{name: \"widget\", tags: [a, b]}")?;
+    /// let tags = element.as_struct_mut().unwrap().get_mut("tags").unwrap();
+    /// *tags.as_sequence_mut().unwrap().get_mut(1).unwrap() = Element::symbol("c");
+    /// assert_eq!(element, Element::read_one("{name: \"widget\", tags: [a, c]}")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_struct_mut(&mut self) -> Option<&mut Struct> {
+        match &mut self.value {
+            Value::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
+
     pub fn expect_struct(&self) -> IonResult<&Struct> {
-        self.as_struct()
-            .ok_or_else(|| self.expected(IonType::Struct))
+        self.as_struct().ok_or_else(|| self.expected("a struct"))
     }
 
     /// Reads a single Ion [`Element`] from the provided data source.
@@ -676,6 +939,128 @@ impl Element {
             .into())
     }
 
+    /// Like [`Element::read_first`], but reads from an [`io::Read`] implementation. Only as much
+    /// of `source` as is needed to materialize the first value is read into memory.
+    pub fn read_first_from<R: io::Read>(source: R) -> IonResult<Option<Element>> {
+        let mut reader = Reader::new(AnyEncoding, IonStream::new(source))?;
+        reader.read_next_element()
+    }
+
+    /// Like [`Element::read_one`], but reads from an [`io::Read`] implementation.
+    pub fn read_one_from<R: io::Read>(source: R) -> IonResult<Element> {
+        let mut reader = Reader::new(AnyEncoding, IonStream::new(source))?;
+        reader.read_one_element()
+    }
+
+    /// Like [`Element::read_all`], but reads from an [`io::Read`] implementation. The input is
+    /// pulled from `source` incrementally as each value is materialized rather than being
+    /// buffered in its entirety up front.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    /// use std::io::BufReader;
+    ///
+    /// let source = BufReader::new("1 two \"three\"".as_bytes());
+    /// let elements = Element::read_all_from(source)?;
+    /// assert_eq!(elements, Element::read_all("1 two \"three\"")?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn read_all_from<R: io::Read>(source: R) -> IonResult<Sequence> {
+        Self::iter_from(source)?.collect()
+    }
+
+    /// Reads all available [`Element`]s from the provided binary Ion data, dividing the work of
+    /// materializing them among `num_threads` threads. The resulting [`Sequence`] has the same
+    /// values in the same order as the one returned by [`Element::read_all`].
+    ///
+    /// The stream is first scanned once on the current thread to divide it into `num_threads`
+    /// parts of similar size, each ending at a top-level value boundary. Scanning skips over
+    /// each value without materializing it, which is cheap for binary Ion. Each thread then
+    /// materializes the values in its own part of the stream, starting with the symbol table in
+    /// effect where that part begins. `num_threads` is capped at the available parallelism
+    /// reported by [`std::thread::available_parallelism`]. If `data` is not binary Ion or fewer
+    /// than two threads would be used, the values are read on the current thread.
+    ///
+    /// If the input has valid data, returns `Ok(Sequence)`.
+    /// If the input has invalid data, returns `Err(IonError)`.
+    pub fn read_all_parallel(data: &[u8], num_threads: usize) -> IonResult<Sequence> {
+        // Every binary Ion stream begins with an IVM, whose first byte is 0xE0.
+        let is_binary = data.first() == Some(&0xE0);
+        // Threads beyond the number that can run at once would not finish any sooner.
+        let max_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let num_threads = num_threads.min(max_threads);
+        if num_threads < 2 || !is_binary {
+            return Self::read_all(data);
+        }
+        Self::read_all_partitioned(data, num_threads)
+    }
+
+    /// Reads all of the values in the binary Ion stream `data` by dividing it into as many as
+    /// `num_partitions` parts and materializing each part's values on its own thread.
+    fn read_all_partitioned(data: &[u8], num_partitions: usize) -> IonResult<Sequence> {
+        let starts = Self::partition_starts(data, num_partitions)?;
+        let ends = starts
+            .iter()
+            .skip(1)
+            .map(ReaderCheckpoint::offset)
+            .chain([data.len()]);
+        let partitions = std::thread::scope(|scope| {
+            let workers: Vec<_> = starts
+                .iter()
+                .zip(ends)
+                .map(|(start, end)| scope.spawn(move || Self::read_partition(&data[..end], start)))
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect::<IonResult<Vec<_>>>()
+        })?;
+        let elements: Vec<Element> = partitions.into_iter().flatten().collect();
+        Ok(elements.into())
+    }
+
+    /// Scans the top-level values in `data` without materializing them, returning a checkpoint
+    /// for the start of each of (at most) `num_partitions` parts of the stream. The parts are
+    /// of similar size in bytes, and each one begins at a top-level value boundary.
+    fn partition_starts(data: &[u8], num_partitions: usize) -> IonResult<Vec<ReaderCheckpoint>> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut starts = vec![reader.checkpoint()?];
+        let target_len = data.len().div_ceil(num_partitions);
+        let mut next_start = target_len;
+        while let Some(value) = reader.next()? {
+            let Some(range) = value.range() else {
+                continue;
+            };
+            if range.end < next_start || starts.len() == num_partitions {
+                continue;
+            }
+            // A checkpoint can't be taken while the reader is expanding an e-expression. If this
+            // value came from one, the part will begin after a later value instead.
+            if let Ok(checkpoint) = reader.checkpoint() {
+                if checkpoint.offset() == data.len() {
+                    break;
+                }
+                next_start = checkpoint.offset() + target_len;
+                starts.push(checkpoint);
+            }
+        }
+        Ok(starts)
+    }
+
+    /// Materializes each of the values in `data` that follow `start`.
+    fn read_partition(data: &[u8], start: &ReaderCheckpoint) -> IonResult<Vec<Element>> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        reader.seek_to_checkpoint(start)?;
+        reader.into_elements().collect()
+    }
+
     /// Returns an iterator over the Elements in the provided Ion data source.
     /// If the data source cannot be read or contains invalid Ion data, this method
     /// will return an `Err`.
@@ -685,6 +1070,15 @@ impl Element {
         Ok(Reader::new(AnyEncoding, source)?.into_elements())
     }
 
+    /// Returns an iterator over the Elements read from the provided [`io::Read`] implementation.
+    /// The iterator owns its reader, so it can be returned or stored and consumed later.
+    /// If the data source cannot be read, this method will return an `Err`.
+    pub fn iter_from<R: io::Read>(
+        source: R,
+    ) -> IonResult<OwnedElementIterator<Reader<AnyEncoding, IonStream<R>>>> {
+        Ok(Reader::new(AnyEncoding, IonStream::new(source))?.into_elements())
+    }
+
     /// Encodes this element as an Ion stream with itself as the only top-level value.
     /// If the stream's encoding is binary Ion, returns a `Vec<u8>` containing the encoded bytes.
     /// If the stream's encoding is text Ion, returns a `String` containing the UTF-8 encoded text.
@@ -747,6 +1141,154 @@ impl Element {
     ) -> IonResult<W> {
         config.into().encode_to(self, output)
     }
+
+    /// Encodes this element as a binary Ion 1.0 stream with itself as the only top-level value.
+    ///
+    /// This is shorthand for `element.encode_as(v1_0::Binary)`.
+    pub fn to_binary(&self) -> IonResult<Vec<u8>> {
+        self.encode_as(v1_0::Binary)
+    }
+
+    /// Encodes this element as a text Ion 1.0 stream with itself as the only top-level value,
+    /// using the specified [`TextFormat`].
+    ///
+    /// This is shorthand for `element.encode_as(v1_0::Text.with_format(format))`.
+    pub fn to_text(&self, format: TextFormat) -> IonResult<String> {
+        self.encode_as(v1_0::Text.with_format(format))
+    }
+
+    /// Encodes this element as an Ion 1.0 stream in the specified [`Format`], writing the encoded
+    /// bytes to `output`.
+    pub fn write_to<W: io::Write>(&self, output: &mut W, format: Format) -> IonResult<()> {
+        match format {
+            Format::Text(text_format) => {
+                self.encode_to(output, v1_0::Text.with_format(text_format))?;
+            }
+            Format::Binary => {
+                self.encode_to(output, v1_0::Binary)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// The `try_into_*` methods hand the original element back to the caller when the conversion fails
+// so that it isn't lost; the large `Err` variant is intentional.
+#[allow(clippy::result_large_err)]
+impl Element {
+    /// Applies a `Value::try_into_*` conversion to this element's value. If the conversion fails,
+    /// the original element (including its annotations) is returned.
+    fn try_into_value_with<T>(
+        self,
+        conversion: impl FnOnce(Value) -> Result<T, Value>,
+    ) -> Result<T, Element> {
+        let Element { annotations, value } = self;
+        conversion(value).map_err(|value| Element::new(annotations, value))
+    }
+
+    /// If this element is an Ion bool, returns it. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion; use
+    /// [`into_parts`](Self::into_parts) to keep them.
+    pub fn try_into_bool(self) -> Result<bool, Element> {
+        self.try_into_value_with(Value::try_into_bool)
+    }
+
+    /// If this element is an Ion int, returns it. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_int(self) -> Result<Int, Element> {
+        self.try_into_value_with(Value::try_into_int)
+    }
+
+    /// If this element is an Ion float, returns it. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_float(self) -> Result<f64, Element> {
+        self.try_into_value_with(Value::try_into_float)
+    }
+
+    /// If this element is an Ion decimal, returns it. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_decimal(self) -> Result<Decimal, Element> {
+        self.try_into_value_with(Value::try_into_decimal)
+    }
+
+    /// If this element is an Ion timestamp, returns it. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_timestamp(self) -> Result<Timestamp, Element> {
+        self.try_into_value_with(Value::try_into_timestamp)
+    }
+
+    /// If this element is an Ion string, returns it. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_string(self) -> Result<Str, Element> {
+        self.try_into_value_with(Value::try_into_string)
+    }
+
+    /// If this element is an Ion symbol, returns it. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_symbol(self) -> Result<Symbol, Element> {
+        self.try_into_value_with(Value::try_into_symbol)
+    }
+
+    /// If this element is an Ion blob or clob, returns its bytes. Otherwise, returns the original
+    /// element. The element's annotations are discarded by a successful conversion.
+    pub fn try_into_lob(self) -> Result<Bytes, Element> {
+        self.try_into_value_with(Value::try_into_lob)
+    }
+
+    /// If this element is an Ion blob, returns its bytes. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_blob(self) -> Result<Bytes, Element> {
+        self.try_into_value_with(Value::try_into_blob)
+    }
+
+    /// If this element is an Ion clob, returns its bytes. Otherwise, returns the original element.
+    /// The element's annotations are discarded by a successful conversion.
+    pub fn try_into_clob(self) -> Result<Bytes, Element> {
+        self.try_into_value_with(Value::try_into_clob)
+    }
+
+    /// If this element is an Ion list or s-expression, returns its [`Sequence`] without cloning
+    /// it. Otherwise, returns the original element. The element's annotations are discarded by a
+    /// successful conversion; use [`into_parts`](Self::into_parts) to keep them.
+    ///
+    /// ```
+    /// use ion_rs::Element;
+    /// # fn main() -> ion_rs::IonResult<()> {
+    /// let element = Element::read_one("(1 2 3)")?;
+    /// let sequence = element.try_into_sequence().expect("it's an s-expression");
+    /// assert_eq!(sequence.len(), 3);
+    ///
+    /// let element = Element::read_one("greeting::\"hello\"")?;
+    /// // The original element is returned, annotations and all.
+    /// let element = element.try_into_sequence().unwrap_err();
+    /// assert!(element.annotations().contains("greeting"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_into_sequence(self) -> Result<Sequence, Element> {
+        self.try_into_value_with(Value::try_into_sequence)
+    }
+
+    /// If this element is an Ion list, returns its [`Sequence`] without cloning it. Otherwise,
+    /// returns the original element. The element's annotations are discarded by a successful
+    /// conversion.
+    pub fn try_into_list(self) -> Result<Sequence, Element> {
+        self.try_into_value_with(Value::try_into_list)
+    }
+
+    /// If this element is an Ion s-expression, returns its [`Sequence`] without cloning it.
+    /// Otherwise, returns the original element. The element's annotations are discarded by a
+    /// successful conversion.
+    pub fn try_into_sexp(self) -> Result<Sequence, Element> {
+        self.try_into_value_with(Value::try_into_sexp)
+    }
+
+    /// If this element is an Ion struct, returns it without cloning it. Otherwise, returns the
+    /// original element. The element's annotations are discarded by a successful conversion;
+    /// use [`into_parts`](Self::into_parts) to keep them.
+    pub fn try_into_struct(self) -> Result<Struct, Element> {
+        self.try_into_value_with(Value::try_into_struct)
+    }
 }
 
 impl Display for Element {
@@ -761,14 +1303,126 @@ impl Display for Element {
     }
 }
 
+/// Elements are equal if they have the same annotations (in the same order) and their values are
+/// equal. See [`Value`]'s `PartialEq` implementation for details.
 impl PartialEq for Element {
     fn eq(&self, other: &Self) -> bool {
-        self.value == other.value && self.annotations == other.annotations
+        self.ion_eq(other)
     }
 }
 
 impl Eq for Element {}
 
+impl Hash for Element {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.annotations.hash(state);
+        self.value.hash(state);
+    }
+}
+
+// The impls below allow an `Element` to be compared directly to a Rust value; for example,
+// `assert_eq!(element, 5)`. An `Element` is only equal to a Rust value if it has no annotations
+// and its value is a non-null instance of the corresponding Ion type.
+
+impl Element {
+    /// Returns this element's value if the element has no annotations. Used to compare elements
+    /// to Rust values, which cannot carry annotations.
+    fn unannotated_value(&self) -> Option<&Value> {
+        if self.annotations.is_empty() {
+            Some(&self.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// An `Element` is equal to an `i64` if it is an unannotated int with the same value.
+///
+/// ```
+/// use ion_rs::{Element, IonResult};
+/// # fn main() -> IonResult<()> {
+/// let elements: Vec<Element> = Element::read_all(r#"5 12.5e0 true "widget" quantity::5"#)?
+///     .into_iter()
+///     .collect();
+/// assert_eq!(elements[0], 5);
+/// assert_ne!(elements[0], 6);
+/// assert_eq!(elements[1], 12.5);
+/// assert_eq!(elements[2], true);
+/// assert_eq!(elements[3], "widget");
+/// // Annotated elements are never equal to a bare Rust value.
+/// assert_ne!(elements[4], 5);
+/// # Ok(())
+/// # }
+/// ```
+impl PartialEq<i64> for Element {
+    fn eq(&self, other: &i64) -> bool {
+        matches!(self.unannotated_value(), Some(Value::Int(int)) if *int == Int::from(*other))
+    }
+}
+
+/// An `Element` is equal to an `f64` if it is an unannotated float with the same value.
+/// As with `f64` itself, a `nan` element is not equal to `f64::NAN`.
+impl PartialEq<f64> for Element {
+    fn eq(&self, other: &f64) -> bool {
+        matches!(self.unannotated_value(), Some(Value::Float(float)) if float == other)
+    }
+}
+
+/// An `Element` is equal to a `bool` if it is an unannotated bool with the same value.
+impl PartialEq<bool> for Element {
+    fn eq(&self, other: &bool) -> bool {
+        matches!(self.unannotated_value(), Some(Value::Bool(b)) if b == other)
+    }
+}
+
+/// An `Element` is equal to a `&str` if it is an unannotated _string_ with the same text.
+/// Symbols are never equal to a `&str`; this mirrors `Element::from("text")`, which produces
+/// a string.
+impl PartialEq<&str> for Element {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self.unannotated_value(), Some(Value::String(text)) if text.text() == *other)
+    }
+}
+
+/// An `Element` is equal to a `Decimal` if it is an unannotated decimal that is Ion-equivalent to
+/// it. As with comparisons between elements, `1.5` is not equal to `1.50`.
+impl PartialEq<Decimal> for Element {
+    fn eq(&self, other: &Decimal) -> bool {
+        matches!(self.unannotated_value(), Some(Value::Decimal(decimal)) if decimal.ion_eq(other))
+    }
+}
+
+/// An `Element` is equal to a `Timestamp` if it is an unannotated timestamp equal to it.
+impl PartialEq<Timestamp> for Element {
+    fn eq(&self, other: &Timestamp) -> bool {
+        matches!(self.unannotated_value(), Some(Value::Timestamp(timestamp)) if timestamp == other)
+    }
+}
+
+/// An `Element` is equal to a `&[u8]` if it is an unannotated blob or clob with the same bytes.
+impl PartialEq<&[u8]> for Element {
+    fn eq(&self, other: &&[u8]) -> bool {
+        match self.unannotated_value() {
+            Some(Value::Blob(bytes) | Value::Clob(bytes)) => bytes.as_ref() == *other,
+            _ => false,
+        }
+    }
+}
+
+// Implements `PartialEq<Element>` for each of the provided types using the corresponding
+// `PartialEq<$type> for Element` impl.
+macro_rules! impl_partial_eq_element_for {
+    ($($type:ty),+ $(,)?) => {$(
+        impl PartialEq<Element> for $type {
+            fn eq(&self, other: &Element) -> bool {
+                other == self
+            }
+        }
+    )+};
+}
+
+impl_partial_eq_element_for!(i64, f64, bool, &str, Decimal, Timestamp, &[u8]);
+
 // This implementation allows APIs that require an Into<Element> to accept references to an existing
 // Element.
 impl<'a> From<&'a Element> for Element {
@@ -788,6 +1442,112 @@ where
     }
 }
 
+// Converting an `Element` to a Rust type ignores its annotations. Null values of any type are
+// conversion errors.
+
+impl TryFrom<&Element> for i64 {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_i64()
+    }
+}
+
+impl TryFrom<&Element> for f64 {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_float()
+    }
+}
+
+impl TryFrom<&Element> for bool {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_bool()
+    }
+}
+
+impl TryFrom<&Element> for String {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_string().map(str::to_owned)
+    }
+}
+
+impl TryFrom<&Element> for Decimal {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_decimal()
+    }
+}
+
+impl TryFrom<&Element> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_timestamp()
+    }
+}
+
+/// Converts a blob or clob to its bytes.
+impl TryFrom<&Element> for Vec<u8> {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_lob().map(<[u8]>::to_vec)
+    }
+}
+
+/// Converts a list or s-expression to a `Vec` of its child values.
+impl TryFrom<&Element> for Vec<Element> {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        Ok(element.expect_sequence()?.elements().cloned().collect())
+    }
+}
+
+/// Converts a struct to a map of its fields. Returns an error if the struct has a field name with
+/// unknown text or a repeated field name; use [`Struct::to_map`] to choose how repeated field
+/// names are handled.
+impl TryFrom<&Element> for HashMap<String, Element> {
+    type Error = IonError;
+
+    fn try_from(element: &Element) -> IonResult<Self> {
+        element.expect_struct()?.to_map(DuplicateFieldPolicy::Error)
+    }
+}
+
+// Implements `TryFrom<Element>` for each of the provided types using the corresponding
+// `TryFrom<&Element>` impl.
+macro_rules! impl_try_from_owned_element_for {
+    ($($type:ty),+ $(,)?) => {$(
+        impl TryFrom<Element> for $type {
+            type Error = IonError;
+
+            fn try_from(element: Element) -> IonResult<Self> {
+                <$type>::try_from(&element)
+            }
+        }
+    )+};
+}
+
+impl_try_from_owned_element_for!(
+    i64,
+    f64,
+    bool,
+    String,
+    Decimal,
+    Timestamp,
+    Vec<u8>,
+    Vec<Element>,
+    HashMap<String, Element>,
+);
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -799,8 +1559,11 @@ mod tests {
     use ElemOp::*;
 
     use crate::element::annotations::IntoAnnotations;
-    use crate::{ion_list, ion_sexp, ion_struct, Decimal, Int, IonType, Symbol, Timestamp};
-    use crate::{Annotations, Element, IntoAnnotatedElement, Struct};
+    use crate::{
+        ion_list, ion_sexp, ion_struct, Decimal, Format, Int, IonData, IonError, IonResult,
+        IonType, Symbol, TextFormat, Timestamp,
+    };
+    use crate::{Annotations, Element, IntoAnnotatedElement, List, Sequence, Struct};
 
     /// Makes a timestamp from an RFC-3339 string and panics if it can't
     fn make_timestamp<T: AsRef<str>>(text: T) -> Timestamp {
@@ -835,6 +1598,20 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn annotation_helpers() -> IonResult<()> {
+        let element = Element::read_one("$0::foo::1")?;
+        assert!(element.has_annotation("foo"));
+        assert!(!element.has_annotation("$0"));
+        // Annotations with unknown text never match
+        assert!(!element.annotations_eq(["$0", "foo"]));
+        assert!(Element::read_one("foo::bar::1")?.annotations_eq(["foo", "bar"]));
+        element.expect_annotation("foo")?;
+        let error = element.expect_annotation("bar").unwrap_err();
+        assert!(error.to_string().contains("[$0, foo]"), "{error}");
+        Ok(())
+    }
+
     struct CaseSym {
         eq_annotations: Vec<Symbol>,
         ne_annotations: Vec<Symbol>,
@@ -1303,6 +2080,374 @@ mod tests {
         let itself = &input_case.elem;
         assert_eq!(&input_case.elem, itself);
     }
+
+    #[test]
+    fn try_into_sequence_moves_without_cloning() {
+        let element: Element = List(Sequence::new(0..10_000)).into();
+        let first_child = element.as_sequence().unwrap().get(0).unwrap() as *const Element;
+        let sequence = element.try_into_sequence().unwrap();
+        assert_eq!(sequence.len(), 10_000);
+        // The children were not copied to a new allocation.
+        assert_eq!(sequence.get(0).unwrap() as *const Element, first_child);
+    }
+
+    #[test]
+    fn try_into_struct_discards_annotations() {
+        let element = ion_struct! {"a": 1}.with_annotations(["foo"]);
+        let structure = element.try_into_struct().unwrap();
+        assert_eq!(structure, ion_struct! {"a": 1});
+    }
+
+    #[test]
+    fn try_into_mismatch_returns_original_element() {
+        let element = Element::string("hello").with_annotations(["foo", "bar"]);
+        let returned = element.clone().try_into_struct().unwrap_err();
+        assert_eq!(returned, element);
+        let returned = returned.try_into_sequence().unwrap_err();
+        assert_eq!(returned, element);
+        let returned = returned.try_into_symbol().unwrap_err();
+        assert_eq!(returned, element);
+        assert_eq!(returned.try_into_string().unwrap(), "hello");
+    }
+
+    #[rstest]
+    #[case::bool(Element::from(true), |e: Element| e.try_into_bool().is_ok())]
+    #[case::int(Element::int(5), |e: Element| e.try_into_int().is_ok())]
+    #[case::float(Element::float(2.5), |e: Element| e.try_into_float().is_ok())]
+    #[case::decimal(Element::decimal(Decimal::new(5, -1)), |e: Element| e.try_into_decimal().is_ok())]
+    #[case::symbol(Element::symbol("foo"), |e: Element| e.try_into_symbol().is_ok())]
+    #[case::blob(Element::blob([1, 2]), |e: Element| e.try_into_blob().is_ok())]
+    #[case::clob(Element::clob([1, 2]), |e: Element| e.try_into_clob().is_ok())]
+    #[case::blob_as_lob(Element::blob([1, 2]), |e: Element| e.try_into_lob().is_ok())]
+    #[case::clob_as_lob(Element::clob([1, 2]), |e: Element| e.try_into_lob().is_ok())]
+    #[case::list(ion_list![1, 2].into(), |e: Element| e.try_into_list().is_ok())]
+    #[case::sexp(ion_sexp!(1 2).into(), |e: Element| e.try_into_sexp().is_ok())]
+    #[case::list_is_not_sexp(ion_list![1, 2].into(), |e: Element| e.try_into_sexp().is_err())]
+    #[case::blob_is_not_clob(Element::blob([1, 2]), |e: Element| e.try_into_clob().is_err())]
+    #[case::int_is_not_float(Element::int(5), |e: Element| e.try_into_float().is_err())]
+    #[case::null_bool_is_not_bool(Element::null(IonType::Bool), |e: Element| e.try_into_bool().is_err())]
+    fn try_into_conversions(#[case] element: Element, #[case] conversion: fn(Element) -> bool) {
+        assert!(conversion(element));
+    }
+
+    #[test]
+    fn modify_containers_in_place() {
+        let mut element = ion_struct! {"a": ion_list![1, 2], "b": 3}.with_annotations(["foo"]);
+        let list = element.as_struct_mut().unwrap().get_mut("a").unwrap();
+        *list.as_sequence_mut().unwrap().get_mut(0).unwrap() = Element::int(10);
+        assert_eq!(
+            element,
+            ion_struct! {"a": ion_list![10, 2], "b": 3}.with_annotations(["foo"])
+        );
+        assert!(element.as_sequence_mut().is_none());
+        let b = element.as_struct_mut().unwrap().get_mut("b").unwrap();
+        assert!(b.as_struct_mut().is_none());
+        assert!(b.as_sequence_mut().is_none());
+    }
+
+    #[test]
+    fn into_parts_round_trip() {
+        let element = ion_list![1, 2, 3].with_annotations(["foo", "bar"]);
+        let (annotations, value) = element.clone().into_parts();
+        assert_eq!(annotations, ["foo", "bar"].into_annotations());
+        assert_eq!(Element::new(annotations, value), element);
+        assert_eq!(element.clone().into_value(), *element.value());
+    }
+
+    // Each of these inputs was once able to cause a panic instead of an `Err`.
+    #[rstest]
+    #[case::long_string_with_invalid_utf8(b"'''l\xBBong'''".as_slice())]
+    #[case::short_string_with_escapes_and_invalid_utf8(b"\"\x9B\\n\"".as_slice())]
+    #[case::quoted_symbol_with_invalid_utf8(b"'ba\xFC'".as_slice())]
+    #[case::quoted_symbol_with_escapes_and_invalid_utf8(b"'\xFC\\n'".as_slice())]
+    #[case::unpaired_low_surrogate(b"\"\\uDC00\"".as_slice())]
+    #[case::escape_beyond_unicode_range(b"\"\\U00110000\"".as_slice())]
+    #[case::symbol_id_larger_than_usize(b"$123456789012345678901234567890".as_slice())]
+    #[case::timestamp_offset_out_of_range(
+        &[0xE0, 0x01, 0x00, 0xEA, 0x6A, 0x13, 0x09, 0x34, 0x80, 0x0F, 0xE8, 0x81, 0x81, 0x80, 0x80]
+    )]
+    #[case::binary_1_1_eexp_with_trailing_address(&[0xE0, 0x01, 0x01, 0xEA, 0x50])]
+    fn read_malformed_input_returns_err(#[case] input: &[u8]) {
+        assert!(Element::read_all(input).is_err());
+    }
+
+    #[test]
+    fn read_all_parallel() -> IonResult<()> {
+        use crate::{v1_0, Writer};
+
+        // Write the values in batches, each of which introduces new field names and symbol values.
+        // Flushing between batches causes the writer to append to the symbol table, so values
+        // throughout the stream depend on symbol tables that precede them.
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        for batch in 0..20 {
+            for i in 0..500 {
+                let field_name = format!("field_{batch}");
+                let element: Element = ion_struct! {
+                    field_name: i,
+                    "tag": Symbol::from(format!("tag_{}", i % (batch + 1))),
+                    "items": ion_list![batch, "text", Symbol::from(format!("item_{batch}"))],
+                }
+                .with_annotations([format!("batch_{batch}")]);
+                writer.write(&element)?;
+            }
+            writer.flush()?;
+        }
+        let data = writer.close()?;
+
+        let expected = Element::read_all(&data)?;
+        assert_eq!(expected.len(), 10_000);
+        for num_threads in [0, 1, 2, 3, 8] {
+            let actual = Element::read_all_parallel(&data, num_threads)?;
+            assert_eq!(actual, expected, "{num_threads} threads");
+        }
+        // `read_all_parallel` limits the thread count to the available parallelism, so test the
+        // partitioned reader directly to cover several threads on any machine.
+        for num_threads in [2, 3, 8] {
+            let actual = Element::read_all_partitioned(&data, num_threads)?;
+            assert_eq!(actual, expected, "{num_threads} partitions");
+            // Each thread gets its own part of the stream.
+            let offsets: Vec<_> = Element::partition_starts(&data, num_threads)?
+                .iter()
+                .map(|start| start.offset())
+                .collect();
+            assert_eq!(offsets.len(), num_threads);
+            assert!(
+                offsets.windows(2).all(|pair| pair[0] < pair[1]),
+                "{offsets:?}"
+            );
+        }
+
+        // A stream that begins again with a new IVM and a new symbol table partway through
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        for i in 0..1_000 {
+            let element: Element = ion_struct! {
+                "other_field": Symbol::from(format!("other_{i}")),
+            }
+            .into();
+            writer.write(&element)?;
+        }
+        let concatenated = [data.as_slice(), writer.close()?.as_slice()].concat();
+        let expected = Element::read_all(&concatenated)?;
+        assert_eq!(expected.len(), 11_000);
+        for num_threads in [2, 3, 8] {
+            let actual = Element::read_all_partitioned(&concatenated, num_threads)?;
+            assert_eq!(actual, expected, "{num_threads} partitions");
+        }
+
+        // A stream with fewer values than threads
+        let short = Element::read_all("1 2")?.encode_as(v1_0::Binary)?;
+        assert_eq!(
+            Element::read_all_partitioned(&short, 8)?,
+            Element::read_all(&short)?
+        );
+
+        // Text is read on the current thread.
+        let text = "foo::1 {a: b} [c, d] 2024T";
+        assert_eq!(
+            Element::read_all_parallel(text.as_bytes(), 4)?,
+            Element::read_all(text)?
+        );
+
+        // An error in any part of the stream is reported.
+        let mut truncated = data.clone();
+        truncated.truncate(data.len() - 3);
+        assert!(Element::read_all_parallel(&truncated, 4).is_err());
+        assert!(Element::read_all_partitioned(&truncated, 4).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_from_file() -> IonResult<()> {
+        use crate::v1_0::{Binary, Text};
+        use std::fs::File;
+        use std::io::{BufReader, Seek, Write};
+
+        let elements = Element::read_all(
+            r#"foo::1 {a: b, c: [2.5, 3e0, "four"]} 2024T null.struct (five $0 '''six''')"#,
+        )?;
+        let single = Element::read_one("{a: [1, 2, 3]}")?;
+        let read_file = |mut file: &File| -> IonResult<BufReader<File>> {
+            file.rewind()?;
+            Ok(BufReader::new(file.try_clone()?))
+        };
+
+        let encodings = [
+            (
+                elements.encode_as(Text)?.into_bytes(),
+                single.encode_as(Text)?.into_bytes(),
+            ),
+            (elements.encode_as(Binary)?, single.encode_as(Binary)?),
+        ];
+        for (data, single_data) in encodings {
+            let mut file = tempfile::tempfile()?;
+            file.write_all(&data)?;
+
+            assert_eq!(Element::read_all_from(read_file(&file)?)?, elements);
+            assert_eq!(
+                Element::read_first_from(read_file(&file)?)?,
+                Element::read_first(&data)?
+            );
+            // The iterator owns its reader, so it can outlive the function that created it.
+            let iter = Element::iter_from(read_file(&file)?)?;
+            assert_eq!(iter.collect::<IonResult<Sequence>>()?, elements);
+            // There is more than one value in the stream.
+            assert!(Element::read_one_from(read_file(&file)?).is_err());
+
+            assert_eq!(Element::read_one_from(single_data.as_slice())?, single);
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::annotations("foo::bar::'baz quux'::5")]
+    #[case::unknown_symbol_text("$0")]
+    #[case::annotated_unknown_symbol_text("$0::{$0: $0}")]
+    #[case::negative_zero_float("-0e0")]
+    #[case::special_floats("[nan, +inf, -inf]")]
+    #[case::timestamp_precision("[2024T, 2024-06T, 2024-06-15, 2024-06-15T12:30Z]")]
+    #[case::timestamp_offsets("[2024-06-15T12:30:45.123-07:00, 2024-06-15T12:30:45-00:00]")]
+    #[case::nested_containers(
+        r#"a::{foo: [1, b::(2 three "four"), {five: 5e0}], 'bar baz': {{aGVsbG8=}}, quux: {{"hi"}}}"#
+    )]
+    #[case::nulls("[null, null.int, a::null.struct, (null.symbol)]")]
+    fn to_binary_and_to_text_round_trip(#[case] ion_text: &str) -> IonResult<()> {
+        let element = Element::read_one(ion_text)?;
+        let encodings = [
+            element.to_binary()?,
+            element.to_text(TextFormat::Compact)?.into_bytes(),
+            element.to_text(TextFormat::Lines)?.into_bytes(),
+            element.to_text(TextFormat::Pretty)?.into_bytes(),
+        ];
+        for encoded in encodings {
+            let actual = Element::read_one(encoded)?;
+            assert!(IonData::eq(&element, &actual), "{element} != {actual}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_to_selects_the_writer() -> IonResult<()> {
+        let element = Element::read_one("foo::{bar: [1, 2.5, -0e0, $0]}")?;
+
+        let mut binary = Vec::new();
+        element.write_to(&mut binary, Format::Binary)?;
+        assert_eq!(binary, element.to_binary()?);
+
+        let mut text = Vec::new();
+        element.write_to(&mut text, Format::Text(TextFormat::Compact))?;
+        assert_eq!(text, element.to_text(TextFormat::Compact)?.into_bytes());
+
+        assert!(IonData::eq(&element, &Element::read_one(binary)?));
+        assert!(IonData::eq(&element, &Element::read_one(text)?));
+        Ok(())
+    }
+
+    /// Converts `ion` to `T` from both a borrowed and an owned `Element`, asserting that both
+    /// produce the same result.
+    fn convert<T>(ion: &str) -> IonResult<T>
+    where
+        T: for<'a> TryFrom<&'a Element, Error = IonError>
+            + TryFrom<Element, Error = IonError>
+            + PartialEq
+            + std::fmt::Debug,
+    {
+        let element = Element::read_one(ion)?;
+        let from_ref = T::try_from(&element);
+        let from_owned = T::try_from(element);
+        match (from_ref, from_owned) {
+            (Ok(a), Ok(b)) => {
+                assert_eq!(a, b);
+                Ok(a)
+            }
+            (Err(a), Err(b)) => {
+                assert_eq!(a.to_string(), b.to_string());
+                Err(a)
+            }
+            (a, b) => panic!("borrowed and owned conversions disagree: {a:?} vs {b:?}"),
+        }
+    }
+
+    /// Asserts that converting `ion` to `T` fails with a message naming the expected and actual
+    /// types.
+    fn assert_conversion_error<T>(ion: &str, expected: &str, found: &str)
+    where
+        T: for<'a> TryFrom<&'a Element, Error = IonError>
+            + TryFrom<Element, Error = IonError>
+            + PartialEq
+            + std::fmt::Debug,
+    {
+        let message = convert::<T>(ion).unwrap_err().to_string();
+        assert!(
+            message.contains(&format!("expected {expected}, found {found}")),
+            "{ion}: {message}"
+        );
+    }
+
+    #[test]
+    fn try_from_element_for_rust_types() -> IonResult<()> {
+        use std::collections::HashMap;
+
+        assert_eq!(convert::<i64>("annotated::-17")?, -17);
+        assert_eq!(convert::<f64>("2.5e0")?, 2.5);
+        assert!(convert::<bool>("true")?);
+        assert_eq!(convert::<String>("\"hello\"")?, "hello");
+        assert_eq!(convert::<Decimal>("1.50")?, Decimal::new(150, -2));
+        assert_eq!(
+            convert::<Timestamp>("2024-06-01T")?,
+            Timestamp::with_ymd(2024, 6, 1).build()?
+        );
+        assert_eq!(convert::<Vec<u8>>("{{aGVsbG8=}}")?, b"hello".to_vec());
+        assert_eq!(convert::<Vec<u8>>("{{\"hello\"}}")?, b"hello".to_vec());
+        assert_eq!(
+            convert::<Vec<Element>>("[1, a::2]")?,
+            vec![Element::int(1), Element::read_one("a::2")?]
+        );
+        assert_eq!(
+            convert::<Vec<Element>>("(1 2)")?,
+            vec![Element::int(1), Element::int(2)]
+        );
+        let map = convert::<HashMap<String, Element>>("{a: 1, b: \"two\"}")?;
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], Element::int(1));
+        assert_eq!(map["b"], Element::string("two"));
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_element_rejects_nulls() {
+        use std::collections::HashMap;
+
+        assert_conversion_error::<i64>("null.int", "an int", "a null.int");
+        assert_conversion_error::<f64>("null.float", "a float", "a null.float");
+        assert_conversion_error::<bool>("null", "a bool", "a null.null");
+        assert_conversion_error::<String>("null.string", "a string", "a null.string");
+        assert_conversion_error::<Decimal>("null.decimal", "a decimal", "a null.decimal");
+        assert_conversion_error::<Timestamp>("null.timestamp", "a timestamp", "a null.timestamp");
+        assert_conversion_error::<Vec<u8>>("null.blob", "a blob or clob", "a null.blob");
+        assert_conversion_error::<Vec<Element>>("null.list", "a list or sexp", "a null.list");
+        assert_conversion_error::<HashMap<String, Element>>(
+            "null.struct",
+            "a struct",
+            "a null.struct",
+        );
+    }
+
+    #[test]
+    fn try_from_element_rejects_other_types() {
+        use std::collections::HashMap;
+
+        assert_conversion_error::<i64>("1.5", "an int", "a(n) decimal");
+        assert_conversion_error::<f64>("1", "a float", "a(n) int");
+        assert_conversion_error::<bool>("\"true\"", "a bool", "a(n) string");
+        assert_conversion_error::<String>("hello", "a string", "a(n) symbol");
+        assert_conversion_error::<Decimal>("1e0", "a decimal", "a(n) float");
+        assert_conversion_error::<Timestamp>("\"2024T\"", "a timestamp", "a(n) string");
+        assert_conversion_error::<Vec<u8>>("[1]", "a blob or clob", "a(n) list");
+        assert_conversion_error::<Vec<Element>>("{}", "a list or sexp", "a(n) struct");
+        assert_conversion_error::<HashMap<String, Element>>("[]", "a struct", "a(n) list");
+        // Repeated field names cannot be represented in a `HashMap`.
+        assert!(convert::<HashMap<String, Element>>("{a: 1, a: 2}").is_err());
+    }
 }
 
 #[cfg(test)]
@@ -1367,6 +2512,80 @@ mod value_tests {
         assert_eq!(e1.into(), e2.into());
     }
 
+    // Asserts that `element == value` and `value == element` both produce `expected`.
+    fn assert_primitive_eq<T>(element: &Element, value: T, expected: bool)
+    where
+        T: PartialEq<Element> + Debug,
+        Element: PartialEq<T>,
+    {
+        assert_eq!(*element == value, expected, "{element} == {value:?}");
+        assert_eq!(value == *element, expected, "{value:?} == {element}");
+    }
+
+    #[test]
+    fn element_eq_primitives() {
+        let timestamp = Timestamp::with_ymd(2024, 1, 1).build().unwrap();
+        assert_primitive_eq(&Element::int(5), 5i64, true);
+        assert_primitive_eq(&Element::int(5), 6i64, false);
+        assert_primitive_eq(&Element::float(5.0), 5i64, false);
+        assert_primitive_eq(&Element::float(1.5), 1.5f64, true);
+        assert_primitive_eq(&Element::float(1.5), 2.5f64, false);
+        assert_primitive_eq(&Element::float(f64::NAN), f64::NAN, false);
+        assert_primitive_eq(&Element::from(true), true, true);
+        assert_primitive_eq(&Element::from(true), false, false);
+        assert_primitive_eq(&Element::string("foo"), "foo", true);
+        assert_primitive_eq(&Element::string("foo"), "bar", false);
+        let decimal = Decimal::new(15, -1);
+        assert_primitive_eq(&Element::decimal(decimal), decimal, true);
+        assert_primitive_eq(&Element::decimal(decimal), Decimal::new(150, -2), false);
+        assert_primitive_eq(
+            &Element::timestamp(timestamp.clone()),
+            timestamp.clone(),
+            true,
+        );
+        let other_timestamp = Timestamp::with_ymd(2024, 1, 2).build().unwrap();
+        assert_primitive_eq(&Element::timestamp(timestamp), other_timestamp, false);
+        assert_primitive_eq(&Element::blob(b"foo"), b"foo".as_slice(), true);
+        assert_primitive_eq(&Element::clob(b"foo"), b"foo".as_slice(), true);
+        assert_primitive_eq(&Element::blob(b"foo"), b"bar".as_slice(), false);
+        assert_primitive_eq(&Element::string("foo"), b"foo".as_slice(), false);
+    }
+
+    #[test]
+    fn symbol_element_does_not_eq_str() {
+        // `Element::from(&str)` produces a string, so only strings are equal to a `&str`.
+        assert_primitive_eq(&Element::symbol("foo"), "foo", false);
+        assert_primitive_eq(&Element::from("foo"), "foo", true);
+    }
+
+    #[test]
+    fn annotated_element_does_not_eq_primitive() {
+        let timestamp = Timestamp::with_ymd(2024, 1, 1).build().unwrap();
+        let annotate = |element: Element| element.with_annotations(["foo"]);
+        assert_primitive_eq(&annotate(Element::int(5)), 5i64, false);
+        assert_primitive_eq(&annotate(Element::float(1.5)), 1.5f64, false);
+        assert_primitive_eq(&annotate(Element::from(true)), true, false);
+        assert_primitive_eq(&annotate(Element::string("foo")), "foo", false);
+        let decimal = Decimal::new(15, -1);
+        assert_primitive_eq(&annotate(Element::decimal(decimal)), decimal, false);
+        let element = annotate(Element::timestamp(timestamp.clone()));
+        assert_primitive_eq(&element, timestamp, false);
+        assert_primitive_eq(&annotate(Element::blob(b"foo")), b"foo".as_slice(), false);
+    }
+
+    #[test]
+    fn null_element_does_not_eq_primitive() {
+        let timestamp = Timestamp::with_ymd(2024, 1, 1).build().unwrap();
+        assert_primitive_eq(&Element::null(IonType::Int), 0i64, false);
+        assert_primitive_eq(&Element::null(IonType::Float), 0f64, false);
+        assert_primitive_eq(&Element::null(IonType::Bool), false, false);
+        assert_primitive_eq(&Element::null(IonType::String), "", false);
+        assert_primitive_eq(&Element::null(IonType::Decimal), Decimal::new(0, 0), false);
+        assert_primitive_eq(&Element::null(IonType::Timestamp), timestamp, false);
+        assert_primitive_eq(&Element::null(IonType::Blob), b"".as_slice(), false);
+        assert_primitive_eq(&Element::null(IonType::Clob), b"".as_slice(), false);
+    }
+
     #[rstest]
     #[case::struct_(ion_struct!{"greetings": "hello", "name": "Ion"}, 2)]
     #[case::list(ion_list!["greetings", 5, true], 3)]
@@ -1478,7 +2697,7 @@ mod value_tests {
         I: TryInto<Int, Error = E>,
     {
         let int: Int = source_int.try_into().unwrap();
-        let element: Element = int.into();
+        let element: Element = int.clone().into();
         assert_eq!(element.expect_i64(), int.expect_i64())
     }
 
@@ -1503,4 +2722,67 @@ mod value_tests {
         assert_eq!(actual_text, input);
         Ok(())
     }
+
+    fn hash_of(element: &Element) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let mut hasher = DefaultHasher::new();
+        element.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[rstest]
+    #[case::nan("nan", "nan", true)]
+    #[case::signed_zero("0e0", "-0e0", false)]
+    #[case::decimal_precision("1d0", "1.0", false)]
+    #[case::decimal_negative_zero("0.0", "-0.0", false)]
+    #[case::timestamp_precision("2024T", "2024-01T", false)]
+    #[case::timestamp_offset("2024-01-01T00:00Z", "2024-01-01T00:00-00:00", false)]
+    #[case::timestamp_fraction("2024-01-01T00:00:00.0Z", "2024-01-01T00:00:00.00Z", false)]
+    #[case::annotations("foo::1", "1", false)]
+    #[case::annotation_order("a::b::1", "b::a::1", false)]
+    #[case::field_order("{a: 1, b: 2}", "{b: 2, a: 1}", true)]
+    #[case::repeated_field("{a: 1, a: 1}", "{a: 1}", false)]
+    #[case::nested_nan("[{a: nan}]", "[{a: nan}]", true)]
+    #[case::null_type("null.int", "null.string", false)]
+    #[case::int_and_float("1", "1e0", false)]
+    fn element_eq_and_hash_follow_ion_equivalence(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] expected: bool,
+    ) -> IonResult<()> {
+        let a = Element::read_one(a)?;
+        let b = Element::read_one(b)?;
+        assert_eq!(a == b, expected, "{a} == {b}");
+        assert_eq!(a.ion_eq(&b), expected, "{a} ion_eq {b}");
+        if expected {
+            assert_eq!(hash_of(&a), hash_of(&b), "hash({a}) != hash({b})");
+        }
+        let set: std::collections::HashSet<Element> = [a, b].into_iter().collect();
+        assert_eq!(set.len(), if expected { 1 } else { 2 });
+        Ok(())
+    }
+
+    #[test]
+    fn equivalent_elements_have_equal_hashes() -> IonResult<()> {
+        let elements = Element::read_all(
+            r#"
+            null null.int 0 -0 1 -1 0e0 -0e0 nan +inf -inf 1.5e0
+            0. 0.0 -0.0 1d0 1.0 10d-1 "" "a" a 'a' {{}} {{"a"}}
+            2024T 2024-01T 2024-01-01 2024-01-01T00:00Z 2024-01-01T00:00:00.000Z
+            2024-01-01T00:00:00.000000001+01:00 2024-01-01T00:00:00.1234Z
+            [] () {} [1, nan] (a b) {a: 1} {a: 1, a: 1} {a: 1, b: 2} {b: 2, a: 1}
+            foo::1 bar::1 foo::bar::1 bar::foo::1 foo::nan foo::{a: nan}
+            "#,
+        )?;
+        for a in elements.iter() {
+            for b in elements.iter() {
+                assert_eq!(a == b, a.ion_eq(b), "{a} == {b}");
+                if a == b {
+                    assert_eq!(hash_of(a), hash_of(b), "hash({a}) != hash({b})");
+                }
+            }
+        }
+        Ok(())
+    }
 }
@@ -87,6 +87,9 @@ impl<'a, R: ElementReader + ?Sized> Iterator for ElementIterator<'a, R> {
     }
 }
 
+/// Takes ownership of a given [ElementReader] implementation and yields one [Element] at a time
+/// until the stream is exhausted or invalid data is encountered. Because it does not borrow its
+/// reader, it can outlive the scope in which the reader was constructed.
 pub struct OwnedElementIterator<R: ElementReader> {
     reader: R,
 }
@@ -3,8 +3,11 @@ use crate::element::iterators::SequenceIterator;
 use crate::element::Element;
 use crate::ion_data::{IonEq, IonOrd};
 use crate::lazy::encoding::Encoding;
+use crate::result::DecodingError;
 use crate::write_config::WriteConfig;
-use crate::IonResult;
+use crate::{DecodingErrorKind, IonError, IonResult};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator};
 use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 use std::io;
@@ -13,7 +16,7 @@ use std::io;
 ///
 /// A `Sequence` is not itself an Ion value type, but can represent a series of Ion values appearing
 /// in a [`List`](crate::List), a [`SExp`](crate::SExp), or at the top level.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Sequence {
     elements: Vec<Element>,
 }
@@ -44,10 +47,17 @@ impl Sequence {
         SequenceBuilder::new()
     }
 
+    /// Returns a [`SequenceBuilder`] with space preallocated for `capacity` elements.
+    pub fn builder_with_capacity(capacity: usize) -> SequenceBuilder {
+        SequenceBuilder::with_capacity(capacity)
+    }
+
     pub fn clone_builder(&self) -> SequenceBuilder {
         SequenceBuilder::with_initial_elements(&self.elements)
     }
 
+    /// Returns an iterator over the elements in this sequence. This is equivalent to
+    /// [`iter`](Self::iter) and to iterating over `&sequence`.
     pub fn elements(&self) -> SequenceIterator<'_> {
         SequenceIterator::new(&self.elements)
     }
@@ -56,6 +66,12 @@ impl Sequence {
         self.elements.get(index)
     }
 
+    /// Returns a mutable reference to the element at `index`, or `None` if `index` is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Element> {
+        self.elements.get_mut(index)
+    }
+
     pub fn len(&self) -> usize {
         self.elements.len()
     }
@@ -64,10 +80,122 @@ impl Sequence {
         self.len() == 0
     }
 
+    /// Returns an iterator over the elements in this sequence.
     pub fn iter(&self) -> SequenceIterator<'_> {
         self.elements()
     }
 
+    /// Returns `true` if this sequence contains an element that is Ion equivalent to `element`.
+    ///
+    /// Ion equivalence considers every `nan` to be equivalent to any other `nan` and distinguishes
+    /// `0e0` from `-0e0`. Decimals are only equivalent when they have the same coefficient and
+    /// exponent, so `1d0` is not equivalent to `1.0`. This is the same comparison performed by
+    /// [`Element`]'s `==` operator.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, Sequence};
+    ///
+    /// let sequence = Element::read_all("nan 0e0 1d0")?;
+    /// assert!(sequence.contains_ion_eq(&Element::read_one("nan")?));
+    /// assert!(!sequence.contains_ion_eq(&Element::read_one("-0e0")?));
+    /// assert!(!sequence.contains_ion_eq(&Element::read_one("1.0")?));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn contains_ion_eq(&self, element: &Element) -> bool {
+        self.elements.iter().any(|e| e.ion_eq(element))
+    }
+
+    /// Returns a new sequence containing the elements of this sequence with any element that is
+    /// Ion equivalent to an earlier element removed. The remaining elements keep their order.
+    ///
+    /// See [`contains_ion_eq`](Self::contains_ion_eq) for how Ion equivalence compares values.
+    pub fn dedup_ion_eq(&self) -> Sequence {
+        let mut elements: Vec<Element> = Vec::with_capacity(self.len());
+        for element in &self.elements {
+            if !elements.iter().any(|e| e.ion_eq(element)) {
+                elements.push(element.clone());
+            }
+        }
+        elements.into()
+    }
+
+    /// Returns a new sequence containing the elements of this sequence that are Ion equivalent to
+    /// an element of `other`, in their original order.
+    ///
+    /// Duplicate elements in this sequence are all kept; use [`dedup_ion_eq`](Self::dedup_ion_eq)
+    /// on the result to get a set.
+    pub fn intersection_ion_eq(&self, other: &Sequence) -> Sequence {
+        self.elements
+            .iter()
+            .filter(|element| other.contains_ion_eq(element))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns a new sequence containing the elements of this sequence that are not Ion equivalent
+    /// to any element of `other`, in their original order.
+    ///
+    /// Duplicate elements in this sequence are all kept; use [`dedup_ion_eq`](Self::dedup_ion_eq)
+    /// on the result to get a set.
+    pub fn difference_ion_eq(&self, other: &Sequence) -> Sequence {
+        self.elements
+            .iter()
+            .filter(|element| !other.contains_ion_eq(element))
+            .cloned()
+            .collect()
+    }
+
+    /// Converts each element in the sequence to a `T`, returning the results as a `Vec`.
+    ///
+    /// If any element cannot be converted, returns a decoding error naming that element's index
+    /// and Ion type. The error has the same [`DecodingErrorKind`] as the element's conversion
+    /// error, or [`DecodingErrorKind::TypeMismatch`] if the conversion did not fail with a
+    /// decoding error.
+    ///
+    /// ```
+    /// use ion_rs::{Element, IonError, IonResult, Sequence};
+    /// # fn main() -> IonResult<()> {
+    /// struct Flag(bool);
+    ///
+    /// impl TryFrom<&Element> for Flag {
+    ///     type Error = IonError;
+    ///     fn try_from(element: &Element) -> IonResult<Self> {
+    ///         element.expect_bool().map(Flag)
+    ///     }
+    /// }
+    ///
+    /// let sequence = Sequence::new([true, false, true]);
+    /// let flags: Vec<Flag> = sequence.try_into_vec()?;
+    /// assert_eq!(flags.iter().filter(|flag| flag.0).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_into_vec<T>(&self) -> IonResult<Vec<T>>
+    where
+        T: for<'a> TryFrom<&'a Element, Error = IonError>,
+    {
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| {
+                T::try_from(element).map_err(|e| {
+                    let kind = e
+                        .decoding_error_kind()
+                        .unwrap_or(DecodingErrorKind::TypeMismatch);
+                    DecodingError::new(format!(
+                        "could not convert the {} at index {index}: {e}",
+                        element.ion_type()
+                    ))
+                    .with_kind(kind)
+                    .into()
+                })
+            })
+            .collect()
+    }
+
     /// ```
     ///# use ion_rs::IonResult;
     ///# fn main() -> IonResult<()> {
@@ -150,6 +278,17 @@ impl<'a> IntoIterator for &'a Sequence {
     }
 }
 
+/// Allows `sequence.par_iter()` to visit the sequence's elements in parallel.
+#[cfg(feature = "rayon")]
+impl<'a> IntoParallelIterator for &'a Sequence {
+    type Iter = rayon::slice::Iter<'a, Element>;
+    type Item = &'a Element;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.elements.par_iter()
+    }
+}
+
 // TODO: This currently clones elements. We should change `Sequence` to wrap a VecDeque so we can
 //       pop from the front.
 impl IntoIterator for Sequence {
@@ -201,3 +340,122 @@ impl Iterator for OwnedSequenceIterator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ion_list, DecodingErrorKind, Element, IonData, IonError, IonResult, Sequence};
+
+    #[derive(Debug, PartialEq)]
+    struct TestInt(i64);
+
+    impl TryFrom<&Element> for TestInt {
+        type Error = IonError;
+
+        fn try_from(element: &Element) -> IonResult<Self> {
+            element.expect_i64().map(TestInt)
+        }
+    }
+
+    #[test]
+    fn try_into_vec_homogeneous() -> IonResult<()> {
+        let list = ion_list![1, 2, 3];
+        let values: Vec<TestInt> = list.try_into_vec()?;
+        assert_eq!(values, vec![TestInt(1), TestInt(2), TestInt(3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_by_reference_and_by_value() {
+        let sequence = Sequence::new([1, 2, 3]);
+        let mut iter = (&sequence).into_iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(sequence.iter().len(), sequence.elements().len());
+
+        let mut sum = 0;
+        for element in &sequence {
+            sum += element.expect_i64().unwrap();
+        }
+        // Iterating by reference leaves the sequence available to be consumed.
+        let owned: Vec<Element> = sequence.into_iter().collect();
+        assert_eq!(sum, 6);
+        assert_eq!(
+            owned,
+            vec![Element::int(1), Element::int(2), Element::int(3)]
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_matches_sequential_iteration() {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+        let sequence: Sequence = (0..1_000_000i64).map(Element::int).collect();
+        let sequential: i64 = sequence.iter().map(|e| e.expect_i64().unwrap()).sum();
+        let parallel: i64 = sequence.par_iter().map(|e| e.expect_i64().unwrap()).sum();
+        assert_eq!(parallel, sequential);
+        assert_eq!(sequence.par_iter().count(), sequence.len());
+    }
+
+    #[test]
+    fn try_into_vec_heterogeneous() {
+        let list = ion_list![1, 2, "three", 4];
+        let error = list.try_into_vec::<TestInt>().unwrap_err();
+        assert_eq!(
+            error.decoding_error_kind(),
+            Some(DecodingErrorKind::TypeMismatch)
+        );
+        let message = error.to_string();
+        assert!(message.contains("index 2"), "{message}");
+        assert!(message.contains("string"), "{message}");
+    }
+
+    /// Asserts that two sequences are Ion equivalent.
+    fn assert_ion_eq(actual: Sequence, expected: &str) -> IonResult<()> {
+        let expected = Element::read_all(expected)?;
+        assert_eq!(
+            IonData::from(&actual),
+            IonData::from(&expected),
+            "{actual:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn contains_ion_eq() -> IonResult<()> {
+        let sequence = Element::read_all("0e0 nan 1d0")?;
+        let nan = Element::read_one("nan")?;
+        // `nan` is Ion equivalent to `nan`, and `==` agrees...
+        assert!(sequence.contains_ion_eq(&nan));
+        assert!(sequence.iter().any(|element| element == &nan));
+        // ...while `-0e0` is not Ion equivalent to `0e0`.
+        let negative_zero = Element::read_one("-0e0")?;
+        assert!(!sequence.contains_ion_eq(&negative_zero));
+        assert!(!sequence.iter().any(|element| element == &negative_zero));
+
+        assert!(sequence.contains_ion_eq(&Element::read_one("0e0")?));
+        assert!(sequence.contains_ion_eq(&Element::read_one("1d0")?));
+        assert!(!sequence.contains_ion_eq(&Element::read_one("1.0")?));
+        assert!(!sequence.contains_ion_eq(&Element::read_one("1")?));
+        Ok(())
+    }
+
+    #[test]
+    fn dedup_ion_eq() -> IonResult<()> {
+        let sequence = Element::read_all("0e0 nan -0e0 1d0 nan 1.0 0e0 1d0 foo::1d0")?;
+        assert_ion_eq(sequence.dedup_ion_eq(), "0e0 nan -0e0 1d0 1.0 foo::1d0")?;
+        assert_ion_eq(Sequence::new::<Element, _>([]).dedup_ion_eq(), "")
+    }
+
+    #[test]
+    fn intersection_and_difference_ion_eq() -> IonResult<()> {
+        let left = Element::read_all("0e0 nan 1d0 2 nan")?;
+        let right = Element::read_all("-0e0 nan 1.0 2")?;
+        assert_ion_eq(left.intersection_ion_eq(&right), "nan 2 nan")?;
+        assert_ion_eq(left.difference_ion_eq(&right), "0e0 1d0")?;
+        assert_ion_eq(right.intersection_ion_eq(&left), "nan 2")?;
+        assert_ion_eq(right.difference_ion_eq(&left), "-0e0 1.0")?;
+        assert_ion_eq(left.intersection_ion_eq(&right).dedup_ion_eq(), "nan 2")
+    }
+}
@@ -5,8 +5,8 @@ use std::ops::Deref;
 /// Implementations of [IonOrd] must be consistent with [IonEq](crate::ion_data::IonEq).
 /// Since there is no total ordering in the Ion specification, do not write any code that depends on
 /// a specific order being preserved. Only depend on the fact that a total ordering does exist.
-pub(crate) trait IonOrd {
-    // Intentionally not public—this trait is exposed via `impl Ord for IonData`.
+pub trait IonOrd {
+    // Intentionally not exported—this trait is exposed via `impl Ord for IonData` and `IonData::cmp`.
     // Called ion_cmp to avoid shadowing with Ord::cmp
     fn ion_cmp(&self, other: &Self) -> Ordering;
 }
@@ -46,8 +46,16 @@ impl<T: IonOrd> IonOrd for [T] {
 /// [`f64`], then _any_ blanket impl of [`IonOrd`] for a standard library trait will cause
 /// `error[E0119]: conflicting implementations of trait` because [`f64`] is an external type (and
 /// "upstream crates may add a new impl of trait `std::ops::Deref` for type `f64` in future versions").
+///
+/// All `NaN` values are equivalent (see [`ion_eq_f64`](crate::ion_data::ion_eq_f64)) and are
+/// ordered after every other value, including positive infinity.
 pub(crate) fn ion_cmp_f64(this: &f64, that: &f64) -> Ordering {
-    this.total_cmp(that)
+    match (this.is_nan(), that.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => this.total_cmp(that),
+    }
 }
 
 /// Checks Ion ordering for [`bool`].
@@ -61,6 +69,7 @@ pub(crate) fn ion_cmp_bool(this: &bool, that: &bool) -> Ordering {
 #[cfg(test)]
 mod ord_tests {
     use super::*;
+    use crate::ion_data::IonEq;
     use crate::{Element, IonData, List, Sequence};
     use rstest::*;
 
@@ -70,7 +79,8 @@ mod ord_tests {
     )]
     #[case::annotations_are_sorted("A $0::A bar::A foo::A foo::$0::A foo::bar::A")]
     #[case::annotations_have_lower_precedence_than_type("foo::bar::null foo::true 3")]
-    #[case::annotations_have_higher_precedence_than_value("3 foo::2 foo::bar::1")]
+    #[case::annotations_have_lower_precedence_than_value("foo::bar::1 foo::2 3")]
+    #[case::annotations_order_equivalent_values("1 a::1 b::1 b::a::1 2")]
     #[case::nulls(
         r"
         null.null
@@ -271,4 +281,128 @@ mod ord_tests {
             previous_element = element;
         }
     }
+
+    /// Values of every Ion type, in the order that `IonData::cmp` should sort them.
+    const GOLDEN_ORDER: &str = r#"
+        null
+        annotated::null
+        null.bool
+        false
+        true
+        null.int
+        -5
+        0
+        7
+        null.float
+        -inf
+        -1e0
+        -0e0
+        0e0
+        1e0
+        +inf
+        nan
+        null.decimal
+        -1.0
+        -0.0
+        0.0
+        1.0
+        1.00
+        null.timestamp
+        2020T
+        2020-01-01T
+        2020-01-01T00:00-00:00
+        2020-01-01T00:00Z
+        2021T
+        null.symbol
+        $0
+        ''
+        a
+        b
+        null.string
+        ""
+        "a"
+        "ab"
+        "b"
+        "z"
+        "é"
+        null.clob
+        {{ "a" }}
+        null.blob
+        {{ AQ== }}
+        null.list
+        []
+        [1]
+        [1, 2]
+        [2]
+        a::[2]
+        null.sexp
+        ()
+        (a)
+        null.struct
+        {}
+        {a: 1}
+        {a: 1, a: 1}
+        {a: 2}
+        {b: 1}
+    "#;
+
+    #[test]
+    fn sorting_shuffled_values_produces_golden_order() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let expected: Vec<Element> = Element::read_all(GOLDEN_ORDER)
+            .unwrap()
+            .into_iter()
+            .collect();
+        for seed in 0..10 {
+            let mut elements = expected.clone();
+            elements.shuffle(&mut StdRng::seed_from_u64(seed));
+            elements.sort_by(IonData::cmp);
+            assert!(
+                IonData::eq(&elements, &expected),
+                "seed {seed} sorted to:\n{}",
+                List::from(Sequence::new(elements))
+            );
+        }
+    }
+
+    #[test]
+    fn ordering_is_consistent_with_ion_eq() {
+        let mut elements: Vec<Element> = Element::read_all(GOLDEN_ORDER)
+            .unwrap()
+            .into_iter()
+            .collect();
+        // Values that are equivalent to (or differ only subtly from) other values in the list.
+        elements.extend(
+            Element::read_all(
+                r#"
+                null.null 1.000 10d-1 1d0 -0d1
+                2020-01-01T00:00:00.000Z 2020-01-01T00:00:00.000+00:00 2020-01-01T01:00+01:00
+                $0::a a::$0::a a::b::c a::c
+                {b: 2, a: 1} {a: 1, b: 2} {a: 1, b: 2, a: 1} {a: {b: [c, nan]}} {a: {b: [c, nan]}}
+                [nan, -0e0] [nan, 0e0] (a b)
+                "#,
+            )
+            .unwrap(),
+        );
+        elements.extend([
+            Element::from(-f64::NAN),
+            Element::from(f64::from_bits(0x7FF0_0000_0000_0001)),
+        ]);
+
+        for a in &elements {
+            for b in &elements {
+                let ord = IonData::cmp(a, b);
+                assert_eq!(ord.is_eq(), a.ion_eq(b), "\na: {a}\nb: {b}");
+                assert_eq!(ord, IonData::cmp(b, a).reverse(), "\na: {a}\nb: {b}");
+                for c in &elements {
+                    if ord.is_le() && IonData::cmp(b, c).is_le() {
+                        assert!(IonData::cmp(a, c).is_le(), "\na: {a}\nb: {b}\nc: {c}");
+                    }
+                }
+            }
+        }
+    }
 }
@@ -15,9 +15,8 @@ pub(crate) use ion_ord::{ion_cmp_bool, ion_cmp_f64, IonOrd};
 /// Equivalence with respect to Ion values means that if two Ion values, `X` and `Y`, are equivalent,
 /// they represent the same data and can be substituted for the other without loss of information.
 ///
-/// Some types, such as [`Element`](crate::Element) and [`Value`](crate::element::Value) cannot be
-/// used as the key of a map because they adhere to Rust value semantics—these types cannot implement
-/// [`Eq`] because they include `NaN` as a possible value.
+/// [`Element`](crate::Element) and [`Value`](crate::element::Value) already compare using Ion
+/// equivalence and implement [`Eq`] and [`Hash`], so they can be used as map keys directly.
 ///
 /// For use cases that are concerned with preserving the original Ion data, it is necessary to use
 /// Ion value equivalence. Many common use cases, such as writing unit tests for code that produces
@@ -30,11 +29,42 @@ pub(crate) use ion_ord::{ion_cmp_bool, ion_cmp_f64, IonOrd};
 /// [`Value`](crate::element::Value)), can be converted to [`IonData`].
 ///
 /// [`Hash`] and [`Ord`] are not guaranteed to be implemented for all [`IonData`], but when they are,
-/// they are required to be consistent with Ion structural equality (and [`Eq`]).
+/// they are required to be consistent with Ion structural equality (and [`Eq`]): two values are
+/// equivalent if and only if they compare as [`Ordering::Equal`].
 ///
-/// __WARNING__—The Ion specification does _not_ define a total ordering over all Ion values. Do
-/// not depend on getting any particular result from [Ord]. Use it only as an opaque total ordering
-/// over all [IonData]. _The algorithm used for [Ord] may change between versions._
+/// ## Ordering
+///
+/// The Ion specification does _not_ define an ordering over Ion values; the total ordering
+/// implemented here is specific to this library. It is useful for producing a canonical order
+/// (for example, to sort values before serializing or diffing them), but other Ion
+/// implementations may order the same values differently. Elements are ordered as follows:
+///
+/// 1. By Ion type, in the order `null`, `bool`, `int`, `float`, `decimal`, `timestamp`, `symbol`,
+///    `string`, `clob`, `blob`, `list`, `sexp`, `struct`. A typed null (e.g. `null.int`) has the
+///    type it names.
+/// 2. Within a type, a null sorts before all non-null values. Non-null values are ordered by
+///    type-specific rules:
+///     * `bool`: `false` before `true`.
+///     * `int`: by numeric value.
+///     * `float`: by numeric value, with `-0e0` before `0e0` and `nan` after `+inf`.
+///     * `decimal`: by numeric value, then negative zero before positive zero, then by precision
+///       (`1.0` before `1.00`).
+///     * `timestamp`: by the point in time represented, then by precision (`2020T` before
+///       `2020-01-01T`), then by offset (an unknown offset before any known offset).
+///     * `symbol`: a symbol with unknown text before all others, then by text.
+///     * `string`: by text.
+///     * `clob`, `blob`: by their bytes.
+///     * `list`, `sexp`: by their child values, compared pairwise; a sequence that is a prefix of
+///       another sorts first.
+///     * `struct`: each struct's fields are sorted by name and then by value, and the resulting
+///       sequences of fields are compared pairwise like a list's child values.
+/// 3. Values that are equivalent in all of the above are ordered by their annotations, which are
+///    compared pairwise like a list's child values. Unannotated values sort first.
+///
+/// Text (including field names and annotations) is compared lexicographically by its UTF-8 bytes.
+///
+/// The ordering can be used by wrapping values in [`IonData`], or by passing [`IonData::cmp`] to
+/// methods like [`slice::sort_by`].
 #[derive(Debug, Clone)]
 pub struct IonData<T>(T);
 
@@ -50,6 +80,28 @@ impl<T: IonEq> IonData<T> {
     }
 }
 
+impl<T: IonEq + IonOrd> IonData<T> {
+    /// Compares two values using the total ordering described in the [type-level
+    /// documentation](IonData#ordering).
+    ///
+    /// ```
+    /// use ion_rs::{Element, IonData, IonResult};
+    ///
+    /// # fn main() -> IonResult<()> {
+    /// let mut elements: Vec<Element> = Element::read_all("foo::2 1.0 1 b::1 a::1 1.00")?
+    ///     .into_iter()
+    ///     .collect();
+    /// elements.sort_by(IonData::cmp);
+    /// let expected = Element::read_all("1 a::1 b::1 foo::2 1.0 1.00")?;
+    /// assert!(IonData::eq(&elements, &expected.into_iter().collect()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cmp(a: &T, b: &T) -> Ordering {
+        T::ion_cmp(a, b)
+    }
+}
+
 impl<T: IonEq> PartialEq for IonData<T> {
     fn eq(&self, other: &Self) -> bool {
         self.0.ion_eq(&other.0)
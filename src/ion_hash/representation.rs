@@ -15,6 +15,7 @@ use crate::result::IonResult;
 use crate::{Decimal, Int, IonType, Struct, Symbol, Timestamp};
 use crate::{Element, Sequence};
 use digest::{FixedOutput, Output, Reset, Update};
+use num_traits::Zero;
 
 pub(crate) trait RepresentationEncoder {
     fn update_with_representation(&mut self, elem: &Element) -> IonResult<()> {
@@ -53,13 +54,9 @@ where
 {
     fn write_repr_integer(&mut self, value: Option<&Int>) -> IonResult<()> {
         if let Some(int) = value {
-            match int.expect_i128()? {
-                0 => {}
-                i => {
-                    let magnitude = i.unsigned_abs();
-                    let encoded = binary::uint::encode(magnitude);
-                    self.update_escaping(encoded.as_bytes());
-                }
+            if !int.is_zero() {
+                let encoded = binary::uint::encode_uint(&int.unsigned_abs());
+                self.update_escaping(encoded.as_bytes());
             }
         }
 
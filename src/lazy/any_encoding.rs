@@ -421,7 +421,45 @@ impl<'top> Iterator for AnyEExpArgsIterator<'top> {
 
 // ===== Readers ======
 
-/// A lazy raw reader that can decode both text and binary Ion.
+/// A lazy raw reader that can decode both text and binary Ion, in either Ion 1.0 or Ion 1.1.
+///
+/// When the reader is constructed, it inspects the first bytes of its input. If they are the
+/// binary Ion 1.0 (`E0 01 00 EA`) or binary Ion 1.1 (`E0 01 01 EA`) version marker, the reader
+/// expects binary data in that version. Otherwise, the reader expects Ion 1.0 text.
+///
+/// Each version marker encountered at the top level can switch the stream to the other Ion
+/// version; [`encoding`](LazyRawReader::encoding) reports the encoding currently in effect.
+/// A version marker is always read using the format (text or binary) of the data around it, so a
+/// stream cannot switch between text and binary. In a binary stream, the bytes of a text version
+/// marker like `$ion_1_1` are decoded as binary values; in a text stream, a binary version marker
+/// is not valid UTF-8 and causes an error.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(all(feature = "experimental-tooling-apis", feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{EncodingContext, IonEncoding, LazyRawAnyReader, LazyRawReader, LazyRawValue};
+///
+/// let context = EncodingContext::empty();
+/// let data: &[u8] = &[
+///     0xE0, 0x01, 0x00, 0xEA, // $ion_1_0
+///     0x21, 0x01,             // 1
+///     0xE0, 0x01, 0x01, 0xEA, // $ion_1_1
+///     0x61, 0x02,             // 2
+/// ];
+/// let mut reader = LazyRawAnyReader::new(data);
+/// assert_eq!(reader.encoding(), IonEncoding::Binary_1_0);
+/// reader.next(context.get_ref())?.expect_ivm()?;
+/// assert_eq!(reader.next(context.get_ref())?.expect_value()?.read()?.expect_i64()?, 1);
+/// reader.next(context.get_ref())?.expect_ivm()?;
+/// assert_eq!(reader.next(context.get_ref())?.expect_value()?.read()?.expect_i64()?, 2);
+/// assert_eq!(reader.encoding(), IonEncoding::Binary_1_1);
+///# Ok(())
+///# }
+///# #[cfg(not(all(feature = "experimental-tooling-apis", feature = "experimental-reader-writer")))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone)]
 pub struct LazyRawAnyReader<'data> {
     // If the reader encounters an IVM that changes the encoding, the new encoding will be stored
     // here until `next()` is called again, at which point the reader will be swapped out for one
@@ -432,8 +470,6 @@ pub struct LazyRawAnyReader<'data> {
 
 impl<'data> LazyRawAnyReader<'data> {
     fn detect_encoding(data: &[u8]) -> IonEncoding {
-        const BINARY_1_0_IVM: &[u8] = &[0xEA, 0x01, 0x00, 0xE0];
-
         match *data {
             [0xE0, 0x01, 0x00, 0xEA, ..] => IonEncoding::Binary_1_0,
             [0xE0, 0x01, 0x01, 0xEA, ..] => IonEncoding::Binary_1_1,
@@ -451,6 +487,7 @@ impl<'data> From<RawReaderKind<'data>> for LazyRawAnyReader<'data> {
     }
 }
 
+#[derive(Clone)]
 pub enum RawReaderKind<'data> {
     Text_1_0(LazyRawTextReader_1_0<'data>),
     Binary_1_0(LazyRawBinaryReader_1_0<'data>),
@@ -1056,6 +1093,16 @@ impl<'top> LazyRawValue<'top, AnyEncoding> for LazyRawAnyValue<'top> {
         }
     }
 
+    fn float_size(&self) -> Option<usize> {
+        use LazyRawValueKind::*;
+        match &self.encoding {
+            Text_1_0(v) => v.float_size(),
+            Binary_1_0(v) => v.float_size(),
+            Text_1_1(v) => v.float_size(),
+            Binary_1_1(v) => v.float_size(),
+        }
+    }
+
     fn annotations_span(&self) -> Span<'top> {
         match &self.encoding {
             LazyRawValueKind::Text_1_0(v) => v.annotations_span(),
@@ -1713,7 +1760,8 @@ mod tests {
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_stream_item::LazyRawStreamItem;
     use crate::lazy::raw_value_ref::RawValueRef;
-    use crate::{IonResult, RawSymbolRef, Timestamp};
+    use crate::{Element, IonResult, RawSymbolRef, Timestamp, WriteConfig};
+    use rstest::rstest;
 
     use super::*;
 
@@ -1948,4 +1996,93 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case::text_1_0_without_ivm("1".as_bytes(), IonEncoding::Text_1_0)]
+    #[case::text_1_0("$ion_1_0 1".as_bytes(), IonEncoding::Text_1_0)]
+    #[case::text_1_1("$ion_1_1 1".as_bytes(), IonEncoding::Text_1_1)]
+    #[case::binary_1_0(&[0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01], IonEncoding::Binary_1_0)]
+    #[case::binary_1_1(&[0xE0, 0x01, 0x01, 0xEA, 0x61, 0x01], IonEncoding::Binary_1_1)]
+    fn detect_initial_encoding(
+        #[case] data: &[u8],
+        #[case] expected_encoding: IonEncoding,
+    ) -> IonResult<()> {
+        let encoding_context = EncodingContext::empty();
+        let context_ref = encoding_context.get_ref();
+        let mut reader = LazyRawAnyReader::new(data);
+        // Binary streams are detected before anything has been read.
+        if expected_encoding.is_binary() {
+            assert_eq!(reader.encoding(), expected_encoding);
+        }
+        let mut item = reader.next(context_ref)?;
+        if item.version_marker().is_some() {
+            item = reader.next(context_ref)?;
+        }
+        assert_eq!(item.expect_value()?.read()?.expect_i64()?, 1);
+        assert_eq!(reader.encoding(), expected_encoding);
+        Ok(())
+    }
+
+    #[test]
+    fn text_ivm_in_binary_stream_is_not_an_ivm() -> IonResult<()> {
+        // Version markers are read in the format of the surrounding stream. In binary Ion 1.0,
+        // the leading `$` of `$ion_1_1` is the type descriptor of a 4-byte positive int.
+        let mut data = vec![0xE0, 0x01, 0x00, 0xEA];
+        data.extend_from_slice(b"$ion_1_1 ");
+        let encoding_context = EncodingContext::empty();
+        let context_ref = encoding_context.get_ref();
+        let mut reader = LazyRawAnyReader::new(data.as_slice());
+        reader.next(context_ref)?.expect_ivm()?;
+        let value = reader.next(context_ref)?.expect_value()?;
+        assert_eq!(
+            value.read()?.expect_i64()?,
+            i64::from_be_bytes(*b"\0\0\0\0ion_")
+        );
+        assert_eq!(reader.encoding(), IonEncoding::Binary_1_0);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_ivm_in_text_stream_is_an_error() {
+        let data: &[u8] = &[b'1', b' ', 0xE0, 0x01, 0x01, 0xEA, b' ', b'2'];
+        let encoding_context = EncodingContext::empty();
+        let context_ref = encoding_context.get_ref();
+        let mut reader = LazyRawAnyReader::new(data);
+        let first = reader.next(context_ref).unwrap().expect_value().unwrap();
+        assert_eq!(first.read().unwrap().expect_i64().unwrap(), 1);
+        assert!(reader.next(context_ref).is_err());
+        assert_eq!(reader.encoding(), IonEncoding::Text_1_0);
+    }
+
+    #[rstest]
+    #[case::text_1_0(crate::v1_0::Text)]
+    #[case::binary_1_0(crate::v1_0::Binary)]
+    #[case::text_1_1(crate::v1_1::Text)]
+    #[case::binary_1_1(crate::v1_1::Binary)]
+    fn clone_reader_to_read_ahead<E: Encoding>(#[case] encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let data = Element::read_all("0 1 2 3 4")?.encode_as(encoding)?;
+        let encoding_context = EncodingContext::empty();
+        let context = encoding_context.get_ref();
+        let mut reader = LazyRawAnyReader::new(data.as_ref());
+        let mut next_int = |reader: &mut LazyRawAnyReader| loop {
+            if let LazyRawStreamItem::<AnyEncoding>::Value(value) = reader.next(context)? {
+                return value.read()?.expect_i64();
+            }
+        };
+        assert_eq!(next_int(&mut reader)?, 0);
+
+        // Read ahead in a clone of the reader; the original is unaffected.
+        let mut speculative_reader = reader.clone();
+        for expected in 1..=3 {
+            assert_eq!(next_int(&mut speculative_reader)?, expected);
+        }
+        for expected in 1..=4 {
+            assert_eq!(next_int(&mut reader)?, expected);
+        }
+        assert_eq!(next_int(&mut speculative_reader)?, 4);
+        Ok(())
+    }
 }
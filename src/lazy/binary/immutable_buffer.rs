@@ -17,7 +17,7 @@ use crate::lazy::encoder::binary::v1_1::flex_int::FlexInt;
 use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
 use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::lazy::expanded::template::ParameterEncoding;
-use crate::result::IonFailure;
+use crate::result::{DecodingError, DecodingErrorKind, IncompleteError, IonFailure};
 use crate::{Int, IonError, IonResult, IonType};
 
 const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<i128>();
@@ -134,7 +134,7 @@ impl<'a> ImmutableBuffer<'a> {
     #[inline]
     pub(crate) fn peek_type_descriptor(&self) -> IonResult<TypeDescriptor> {
         if self.is_empty() {
-            return IonResult::incomplete("a type descriptor", self.offset());
+            return self.incomplete("a type descriptor", 1);
         }
         let next_byte = self.data[0];
         Ok(ION_1_0_TYPE_DESCRIPTORS[next_byte as usize])
@@ -145,9 +145,9 @@ impl<'a> ImmutableBuffer<'a> {
     ///
     /// See: <https://amazon-ion.github.io/ion-docs/docs/binary.html#value-streams>
     pub fn read_ivm(self) -> ParseResult<'a, LazyRawBinaryVersionMarker_1_0<'a>> {
-        let bytes = self
-            .peek_n_bytes(IVM.len())
-            .ok_or_else(|| IonError::incomplete("an IVM", self.offset()))?;
+        let Some(bytes) = self.peek_n_bytes(IVM.len()) else {
+            return self.incomplete("an IVM", IVM.len());
+        };
 
         match bytes {
             [0xE0, major, minor, 0xEA] => {
@@ -239,7 +239,7 @@ impl<'a> ImmutableBuffer<'a> {
                 // This is the final byte.
                 // Make sure we haven't exceeded the configured maximum size
                 if encoded_size_in_bytes > MAX_ENCODED_SIZE_IN_BYTES {
-                    return Self::value_too_large(
+                    return self.value_too_large(
                         "a VarUInt",
                         encoded_size_in_bytes,
                         MAX_ENCODED_SIZE_IN_BYTES,
@@ -252,7 +252,7 @@ impl<'a> ImmutableBuffer<'a> {
             }
         }
 
-        IonResult::incomplete("a VarUInt", self.offset() + encoded_size_in_bytes)
+        self.incomplete("a VarUInt", encoded_size_in_bytes + 1)
     }
 
     /// Reads a `VarInt` encoding primitive from the beginning of the buffer. If it is successful,
@@ -262,7 +262,10 @@ impl<'a> ImmutableBuffer<'a> {
     pub fn read_var_int(self) -> ParseResult<'a, VarInt> {
         const BITS_PER_ENCODED_BYTE: usize = 7;
         const STORAGE_SIZE_IN_BITS: usize = mem::size_of::<i64>() * 8;
-        const MAX_ENCODED_SIZE_IN_BYTES: usize = STORAGE_SIZE_IN_BITS / BITS_PER_ENCODED_BYTE;
+        // The first byte holds 6 magnitude bits; each byte after it holds 7. Representing the
+        // magnitude of i64::MIN (2^63) takes 64 bits, so the longest VarInt we accept is 10 bytes.
+        const MAX_ENCODED_SIZE_IN_BYTES: usize =
+            1 + (STORAGE_SIZE_IN_BITS - 6).div_ceil(BITS_PER_ENCODED_BYTE);
 
         const LOWER_6_BITMASK: u8 = 0b0011_1111;
         const LOWER_7_BITMASK: u8 = 0b0111_1111;
@@ -276,13 +279,13 @@ impl<'a> ImmutableBuffer<'a> {
         // negative (1).
 
         if self.is_empty() {
-            return IonResult::incomplete("a VarInt", self.offset());
+            return self.incomplete("a VarInt", 1);
         }
         let first_byte: u8 = self.peek_next_byte().unwrap();
         let no_more_bytes: bool = first_byte >= 0b1000_0000; // If the first bit is 1, we're done.
         let is_negative: bool = (first_byte & 0b0100_0000) == 0b0100_0000;
         let sign: i64 = if is_negative { -1 } else { 1 };
-        let mut magnitude = (first_byte & 0b0011_1111) as i64;
+        let magnitude = (first_byte & 0b0011_1111) as i64;
 
         if no_more_bytes {
             return Ok((
@@ -291,12 +294,15 @@ impl<'a> ImmutableBuffer<'a> {
             ));
         }
 
+        // Widen the accumulator so that a 10-byte VarInt (up to 69 bits of magnitude) can be
+        // range checked after it has been read instead of silently overflowing.
+        let mut magnitude = magnitude as u128;
         let mut encoded_size_in_bytes = 1;
         // Whether we found the terminating byte in this buffer.
         let mut terminated = false;
 
         for byte in self.bytes()[1..].iter().copied() {
-            let lower_seven = (0b0111_1111 & byte) as i64;
+            let lower_seven = (0b0111_1111 & byte) as u128;
             magnitude <<= 7;
             magnitude |= lower_seven;
             encoded_size_in_bytes += 1;
@@ -307,30 +313,61 @@ impl<'a> ImmutableBuffer<'a> {
         }
 
         if !terminated {
-            return IonResult::incomplete("a VarInt", self.offset() + encoded_size_in_bytes);
+            return self.incomplete("a VarInt", encoded_size_in_bytes + 1);
         }
 
         if encoded_size_in_bytes > MAX_ENCODED_SIZE_IN_BYTES {
-            return IonResult::decoding_error(format!(
+            return self.numeric_overflow(format!(
                 "Found a {encoded_size_in_bytes}-byte VarInt. Max supported size is {MAX_ENCODED_SIZE_IN_BYTES} bytes."
             ));
         }
 
+        let value = if is_negative {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        };
+        let Ok(value) = i64::try_from(value) else {
+            return self.numeric_overflow(format!(
+                "Found a VarInt whose value ({value}) does not fit in an i64."
+            ));
+        };
+
         Ok((
-            VarInt::new(magnitude * sign, is_negative, encoded_size_in_bytes),
+            VarInt::new(value, is_negative, encoded_size_in_bytes),
             self.consume(encoded_size_in_bytes),
         ))
     }
 
+    #[inline(never)]
+    // Like `value_too_large` below, this method is inline(never) because it is rarely invoked.
+    /// Returns an `Err` indicating that reading `label` from the beginning of this buffer requires
+    /// at least `bytes_needed` bytes, but fewer are available.
+    pub(crate) fn incomplete<T>(&self, label: &'static str, bytes_needed: usize) -> IonResult<T> {
+        Err(IncompleteError::new(label, self.offset())
+            .with_byte_counts(bytes_needed, self.len())
+            .into())
+    }
+
     #[inline(never)]
     // This method is inline(never) because it is rarely invoked and its allocations/formatting
     // compile to a non-trivial number of instructions.
-    fn value_too_large<T>(label: &str, length: usize, max_length: usize) -> IonResult<T> {
-        IonResult::decoding_error(format!(
+    fn value_too_large<T>(&self, label: &str, length: usize, max_length: usize) -> IonResult<T> {
+        self.numeric_overflow(format!(
             "found {label} that was too large; size = {length}, max size = {max_length}"
         ))
     }
 
+    #[inline(never)]
+    /// Returns an `Err` indicating that the encoding primitive at the beginning of this buffer is
+    /// too large to be represented.
+    fn numeric_overflow<T>(&self, description: String) -> IonResult<T> {
+        Err(DecodingError::new(description)
+            .with_kind(DecodingErrorKind::NumericOverflow)
+            .with_position(self.offset())
+            .into())
+    }
+
     /// Reads the first `length` bytes from the buffer as an `Int` encoding primitive. If it is
     /// successful, returns an `Ok(_)` containing its `DecodedInt` representation and consumes the
     /// source bytes.
@@ -346,21 +383,21 @@ impl<'a> ImmutableBuffer<'a> {
             // 18 bytes or larger here; later on, we reject 17-byte encodings of values other than
             // i128::MIN.
             return cold_path! {
-            IonResult::decoding_error(format!(
-                "Found a {length}-byte Int. Max supported size is {MAX_INT_SIZE_IN_BYTES} bytes."
-            ))};
+            self.numeric_overflow(
+                format!("Found a {length}-byte Int. Max supported size is {MAX_INT_SIZE_IN_BYTES} bytes."),
+            )};
         }
 
-        let int_bytes = self
-            .peek_n_bytes(length)
-            .ok_or_else(|| IonError::incomplete("an Int encoding primitive", self.offset()))?;
+        let Some(int_bytes) = self.peek_n_bytes(length) else {
+            return self.incomplete("an Int encoding primitive", length);
+        };
 
         // i128::MIN is a special case; it's the only 17-byte encoding we accept.
         const INT_MIN_ENCODING: &[u8] = &[0x80, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
         if length == INT_MIN_ENCODING.len() {
             return cold_path! {{
                 if int_bytes != INT_MIN_ENCODING {
-                    IonResult::decoding_error("Found an int encoding primitive outside the supported range")
+                    self.numeric_overflow("Found an int encoding primitive outside the supported range".to_owned())
                 } else {
                     Ok((
                         DecodedInt::new(Int::from(i128::MIN), true, length),
@@ -411,6 +448,15 @@ impl<'a> ImmutableBuffer<'a> {
                 length => (length as usize, input_after_type_descriptor),
             };
 
+        // Make sure the input contains the entire wrapper before looking inside it.
+        if input_after_combined_length.len() < annotations_and_value_length {
+            let header_length = self.len() - input_after_combined_length.len();
+            return self.incomplete(
+                "an annotations wrapper",
+                header_length + annotations_and_value_length,
+            );
+        }
+
         // Read the length of the annotations sequence
         let (annotations_length, input_after_annotations_length) =
             input_after_combined_length.read_var_uint()?;
@@ -420,10 +466,16 @@ impl<'a> ImmutableBuffer<'a> {
             return IonResult::decoding_error("found an annotations wrapper with no annotations");
         }
 
-        // Validate that the annotated value is not missing.
-        let expected_value_length = annotations_and_value_length
-            - annotations_length.size_in_bytes()
-            - annotations_length.value();
+        // Validate that the annotations sequence fits in the wrapper and that the annotated value
+        // is not missing.
+        let Some(expected_value_length) = annotations_and_value_length
+            .checked_sub(annotations_length.size_in_bytes() + annotations_length.value())
+        else {
+            return IonResult::decoding_error(format!(
+                "found an annotations wrapper with length {annotations_and_value_length} whose \
+                 annotations sequence does not fit inside it"
+            ));
+        };
 
         if expected_value_length == 0 {
             return IonResult::decoding_error("found an annotation wrapper with no value");
@@ -432,10 +484,8 @@ impl<'a> ImmutableBuffer<'a> {
         // Skip over the annotations sequence itself; the reader will return to it if/when the
         // reader asks to iterate over those symbol IDs.
         if input_after_annotations_length.len() < annotations_length.value() {
-            return IonResult::incomplete(
-                "an annotations sequence",
-                input_after_annotations_length.offset(),
-            );
+            return input_after_annotations_length
+                .incomplete("an annotations sequence", annotations_length.value());
         }
         // Here, `self` is the (immutable) buffer we started with. Comparing it with `input_after_annotations_length`
         // gets us the before-and-after comparison we need to calculate the size of the header.
@@ -479,7 +529,7 @@ impl<'a> ImmutableBuffer<'a> {
         // If the type descriptor says we should skip more bytes, skip them.
         let (length, remaining) = remaining.read_length(type_descriptor.length_code)?;
         if remaining.len() < length.value() {
-            return IonResult::incomplete("a NOP", remaining.offset());
+            return self.incomplete("a NOP", self.len() - remaining.len() + length.value());
         }
         let remaining = remaining.consume(length.value());
         let total_nop_pad_size = 1 + length.size_in_bytes() + length.value();
@@ -579,10 +629,7 @@ impl<'a> ImmutableBuffer<'a> {
         // Read the field ID
         let (mut field_id_var_uint, mut input_after_field_id) = input.read_var_uint()?;
         if input_after_field_id.is_empty() {
-            return IonResult::incomplete(
-                "found field name but no value",
-                input_after_field_id.offset(),
-            );
+            return input_after_field_id.incomplete("a field value", 1);
         }
 
         let mut type_descriptor = input_after_field_id.peek_type_descriptor()?;
@@ -627,10 +674,7 @@ impl<'a> ImmutableBuffer<'a> {
                 input_before_field_id.read_var_uint()?;
             // If we're out of data (i.e. there's no field value) the struct is incomplete.
             if input_after_field_id.is_empty() {
-                return IonResult::incomplete(
-                    "found a field name but no value",
-                    input_after_field_id.offset(),
-                );
+                return input_after_field_id.incomplete("a field value", 1);
             }
             // Peek at the next value header. If it's a NOP, we need to repeat the process.
             if input_after_field_id.peek_type_descriptor()?.is_nop() {
@@ -684,9 +728,11 @@ impl<'a> ImmutableBuffer<'a> {
         type_descriptor: TypeDescriptor,
     ) -> IonResult<LazyRawBinaryValue_1_0<'a>> {
         let input = self;
-        let header = type_descriptor
-            .to_header()
-            .ok_or_else(|| IonError::decoding_error("found a non-value in value position"))?;
+        let header = type_descriptor.to_header().ok_or_else(|| {
+            DecodingError::new("found a non-value in value position")
+                .with_kind(DecodingErrorKind::InvalidTypeCode)
+                .with_position(input.offset())
+        })?;
 
         let header_offset = input.offset();
         let (length, _) = input.consume(1).read_value_length(header)?;
@@ -697,10 +743,7 @@ impl<'a> ImmutableBuffer<'a> {
                 + value_length;
 
         if total_length > input.len() {
-            return IonResult::incomplete(
-                "the stream ended unexpectedly in the middle of a value",
-                header_offset,
-            );
+            return input.incomplete("a value", total_length);
         }
 
         let encoded_value = EncodedValue {
@@ -772,6 +815,7 @@ pub struct AnnotationsWrapper {
 #[cfg(test)]
 mod tests {
     use crate::{Int, IonError};
+    use rstest::rstest;
 
     use super::*;
 
@@ -907,6 +951,20 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::i64_max(i64::MAX)]
+    #[case::i64_min(i64::MIN)]
+    #[case::i64_min_plus_one(i64::MIN + 1)]
+    fn read_ten_byte_var_int(#[case] value: i64) -> IonResult<()> {
+        let mut encoded = vec![];
+        VarInt::write_i64(&mut encoded, value)?;
+        assert_eq!(encoded.len(), 10);
+        let var_int = ImmutableBuffer::new(&encoded).read_var_int()?.0;
+        assert_eq!(var_int.size_in_bytes(), 10);
+        assert_eq!(var_int.value(), value);
+        Ok(())
+    }
+
     #[test]
     fn read_var_int_overflow_detection() -> IonResult<()> {
         let buffer = ImmutableBuffer::new(&[
@@ -927,6 +985,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn read_var_int_below_i64_min_overflow_detection() {
+        // -(2^63 + 1), which is one less than i64::MIN
+        let buffer = ImmutableBuffer::new(&[
+            0b0100_0001,
+            0b0000_0000,
+            0b0000_0000,
+            0b0000_0000,
+            0b0000_0000,
+            0b0000_0000,
+            0b0000_0000,
+            0b0000_0000,
+            0b0000_0000,
+            0b1000_0001,
+        ]);
+        buffer
+            .read_var_int()
+            .expect_err("This should have failed due to overflow.");
+    }
+
     #[test]
     fn read_int_negative_zero() -> IonResult<()> {
         let buffer = ImmutableBuffer::new(&[0b1000_0000]); // Negative zero
@@ -5,7 +5,6 @@ use crate::lazy::binary::raw::value::LazyRawBinaryValue_1_0;
 use crate::lazy::decoder::{HasRange, LazyRawFieldExpr, LazyRawReader};
 use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
-use crate::result::IonFailure;
 use crate::{Encoding, IonResult};
 
 use crate::lazy::any_encoding::IonEncoding;
@@ -14,6 +13,7 @@ use crate::lazy::streaming_raw_reader::RawReaderState;
 
 /// A binary Ion 1.0 reader that yields [`LazyRawBinaryValue_1_0`]s representing the top level values found
 /// in the provided input stream.
+#[derive(Clone)]
 pub struct LazyRawBinaryReader_1_0<'data> {
     data: DataSource<'data>,
 }
@@ -172,10 +172,7 @@ impl<'data> DataSource<'data> {
 
     fn advance_to_next_item(&mut self) -> IonResult<ImmutableBuffer<'data>> {
         if self.buffer.len() < self.bytes_to_skip {
-            return IonResult::incomplete(
-                "cannot advance to next item, insufficient data in buffer",
-                self.buffer.offset(),
-            );
+            return self.buffer.incomplete("the next item", self.bytes_to_skip);
         }
 
         if self.bytes_to_skip > 0 {
@@ -241,12 +238,17 @@ impl<'data> DataSource<'data> {
 
 #[cfg(test)]
 mod tests {
+    use crate::element::reader::ElementReader;
     use crate::lazy::binary::raw::reader::LazyRawBinaryReader_1_0;
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::lazy::decoder::{LazyRawFieldName, RawVersionMarker};
+    use crate::lazy::decoder::{
+        HasRange, LazyRawFieldName, LazyRawReader, LazyRawSequence, RawVersionMarker,
+    };
     use crate::lazy::raw_stream_item::RawStreamItem;
+    use crate::lazy::raw_value_ref::RawValueRef;
     use crate::raw_symbol_ref::AsRawSymbolRef;
-    use crate::{IonResult, IonType, RawSymbolRef};
+    use crate::{v1_0, Element, IonError, IonResult, IonType, RawSymbolRef, Reader};
+    use rstest::rstest;
 
     #[test]
     fn test_struct() -> IonResult<()> {
@@ -409,4 +411,335 @@ mod tests {
 
         Ok(())
     }
+
+    /// A 14-byte NOP pad, the largest whose length fits in its type descriptor.
+    const MAX_HEADER_LENGTH_NOP: [u8; 14] = [0x0d, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    /// An 18-byte NOP pad whose length (16) is encoded as a VarUInt.
+    const VAR_UINT_LENGTH_NOP: [u8; 18] =
+        [0x0e, 0x90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    #[test]
+    fn nop_padding_at_top_level() -> IonResult<()> {
+        let data: Vec<u8> = [
+            &[0xe0, 0x01, 0x00, 0xea][..], // IVM
+            &MAX_HEADER_LENGTH_NOP,
+            &[0x21, 0x01], // 1
+            &VAR_UINT_LENGTH_NOP,
+            &[0x21, 0x02], // 2
+            &[0x00],       // 1-byte NOP as the last byte in the stream
+        ]
+        .concat();
+        let mut reader = LazyRawBinaryReader_1_0::new(&data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let one = reader.next()?.expect_value()?;
+        assert_eq!(one.range(), 18..20);
+        assert_eq!(one.read()?.expect_i64()?, 1);
+        let two = reader.next()?.expect_value()?;
+        assert_eq!(two.range(), 38..40);
+        assert_eq!(two.read()?.expect_i64()?, 2);
+        let end = reader.next()?;
+        assert!(matches!(end, RawStreamItem::EndOfStream(_)), "{end:?}");
+        assert_eq!(end.range(), 41..41);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::list(0xbe)]
+    #[case::sexp(0xce)]
+    fn nop_padding_in_sequences(#[case] type_descriptor: u8) -> IonResult<()> {
+        let body: Vec<u8> = [
+            &[0x00][..],   // 1-byte NOP as the first byte in the container
+            &[0x21, 0x01], // 1
+            &MAX_HEADER_LENGTH_NOP,
+            &VAR_UINT_LENGTH_NOP,
+            &[0x21, 0x02],          // 2
+            &MAX_HEADER_LENGTH_NOP, // NOP as the last bytes in the container
+        ]
+        .concat();
+        let data: Vec<u8> = [
+            &[0xe0, 0x01, 0x00, 0xea][..], // IVM
+            &[type_descriptor, 0x80 | body.len() as u8],
+            &body,
+            &[0x21, 0x03], // 3
+        ]
+        .concat();
+        let mut reader = LazyRawBinaryReader_1_0::new(&data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let sequence = reader.next()?.expect_value()?;
+        let values = match sequence.read()? {
+            RawValueRef::List(list) => list.iter().collect::<IonResult<Vec<_>>>()?,
+            RawValueRef::SExp(sexp) => sexp.iter().collect::<IonResult<Vec<_>>>()?,
+            other => panic!("expected a list or sexp, found {other:?}"),
+        };
+        let values = values
+            .iter()
+            .map(|value| value.expect_value()?.read()?.expect_i64())
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(values, [1, 2]);
+        assert_eq!(reader.next()?.expect_value()?.read()?.expect_i64()?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn nop_padding_in_structs() -> IonResult<()> {
+        // In a struct, each NOP pad is preceded by a field ID that is ignored.
+        let body: Vec<u8> = [
+            &[0x8a, 0x00][..],   // $10: 1-byte NOP as the first field
+            &[0x8b, 0x21, 0x01], // $11: 1
+            &[0x8c],             // $12:
+            &MAX_HEADER_LENGTH_NOP,
+            &[0x8d], // $13:
+            &VAR_UINT_LENGTH_NOP,
+            &[0x8e, 0x21, 0x02], // $14: 2
+            &[0x8f],             // $15: NOP as the last field in the struct
+            &MAX_HEADER_LENGTH_NOP,
+        ]
+        .concat();
+        let data: Vec<u8> = [
+            &[0xe0, 0x01, 0x00, 0xea][..], // IVM
+            &[0xde, 0x80 | body.len() as u8],
+            &body,
+            &[0xd2, 0x8a, 0x00], // A struct containing only a NOP field
+            &[0x21, 0x03],       // 3
+        ]
+        .concat();
+        let mut reader = LazyRawBinaryReader_1_0::new(&data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let lazy_struct = reader.next()?.expect_value()?.read()?.expect_struct()?;
+        let mut fields = Vec::new();
+        for field in &lazy_struct {
+            let (name, value) = field?.expect_name_value()?;
+            fields.push((name.read()?, value.read()?.expect_i64()?));
+        }
+        assert_eq!(
+            fields,
+            [
+                (RawSymbolRef::SymbolId(11), 1),
+                (RawSymbolRef::SymbolId(14), 2)
+            ]
+        );
+        let empty_struct = reader.next()?.expect_value()?.read()?.expect_struct()?;
+        assert!(empty_struct.iter().next().is_none());
+        assert_eq!(reader.next()?.expect_value()?.read()?.expect_i64()?, 3);
+        Ok(())
+    }
+
+    /// Reads the next item from `reader`. If it is a container, also reads each of its children.
+    fn read_next_item_fully(reader: &mut LazyRawBinaryReader_1_0<'_>) -> IonResult<()> {
+        let RawStreamItem::Value(value) = reader.next()? else {
+            return Ok(());
+        };
+        match value.read()? {
+            RawValueRef::List(list) => list.iter().try_for_each(|value| value.map(|_| ())),
+            RawValueRef::SExp(sexp) => sexp.iter().try_for_each(|value| value.map(|_| ())),
+            RawValueRef::Struct(s) => s.iter().try_for_each(|field| field.map(|_| ())),
+            _ => Ok(()),
+        }
+    }
+
+    // In each case, the last NOP pad declares a length that extends beyond the end of its
+    // container (or the stream). The bytes that follow a container are not part of its NOP pad.
+    #[rstest]
+    #[case::top_level(&[0x0e, 0x90, 0x00, 0x00])]
+    #[case::list(&[0xb4, 0x21, 0x01, 0x02, 0x00, 0x21, 0x02])]
+    #[case::sexp(&[0xc4, 0x21, 0x01, 0x0e, 0x83, 0x21, 0x02, 0x21, 0x03])]
+    #[case::structure(&[0xd4, 0x8a, 0x21, 0x01, 0x8b, 0x03, 0x21, 0x02, 0x21, 0x03])]
+    fn nop_padding_overrunning_its_container_is_incomplete(#[case] value: &[u8]) {
+        let data: Vec<u8> = [&[0xe0, 0x01, 0x00, 0xea][..], value].concat();
+        let mut reader = LazyRawBinaryReader_1_0::new(&data);
+        let _ivm = reader.next().unwrap().expect_ivm().unwrap();
+        let result = read_next_item_fully(&mut reader);
+        assert!(
+            matches!(result, Err(IonError::Incomplete(_))),
+            "expected an incomplete error, found {result:?}"
+        );
+    }
+
+    #[rstest]
+    // A wrapper declaring 16 bytes of annotations and value with only 4 available
+    #[case::annotations_wrapper(&[0xee, 0x90, 0x81, 0x84, 0x21, 0x01], 18, 6)]
+    // A NOP pad declaring a 16-byte body with only 1 available
+    #[case::nop_pad(&[0x0e, 0x90, 0x00], 18, 3)]
+    // An int declaring a 16-byte body with only 1 available
+    #[case::value(&[0x2e, 0x90, 0x01], 18, 3)]
+    // A struct whose field name has no value
+    #[case::field_value(&[0xde, 0x81, 0x84], 1, 0)]
+    // A VarUInt length with no terminating byte
+    #[case::var_uint(&[0x2e, 0x01, 0x01], 3, 2)]
+    fn truncated_lengths_are_incomplete(
+        #[case] value: &[u8],
+        #[case] bytes_needed: usize,
+        #[case] bytes_available: usize,
+    ) {
+        let data: Vec<u8> = [&[0xe0, 0x01, 0x00, 0xea][..], value].concat();
+        let mut reader = LazyRawBinaryReader_1_0::new(&data);
+        let _ivm = reader.next().unwrap().expect_ivm().unwrap();
+        match read_next_item_fully(&mut reader) {
+            Err(IonError::Incomplete(error)) => {
+                assert_eq!(error.bytes_needed(), Some(bytes_needed), "{error}");
+                assert_eq!(error.bytes_available(), Some(bytes_available), "{error}");
+            }
+            other => panic!("expected an incomplete error, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn annotations_sequence_longer_than_its_wrapper_is_invalid() {
+        // The wrapper's length is 3, but its annotations sequence claims to be 4 bytes long.
+        let data = &[0xe0, 0x01, 0x00, 0xea, 0xe3, 0x84, 0x84, 0x84];
+        let mut reader = LazyRawBinaryReader_1_0::new(data);
+        let _ivm = reader.next().unwrap().expect_ivm().unwrap();
+        let result = read_next_item_fully(&mut reader);
+        assert!(
+            matches!(result, Err(IonError::Decoding(_))),
+            "expected a decoding error, found {result:?}"
+        );
+    }
+
+    #[test]
+    fn truncating_a_stream_never_panics() -> IonResult<()> {
+        let mut data = to_binary_ion(
+            r#"
+            $ion_symbol_table::{symbols: ["alpha", "beta", "gamma"]}
+            null true 0 -1 12345678901234567890123 1.5e0 2.5 -0d3 1.2345678901234567890
+            2024-01-02T03:04:05.678Z 2024T "hello" "a string long enough to need a VarUInt length"
+            foo 'bar baz' {{aGVsbG8gd29ybGQ=}} {{"clob text"}}
+            [1, 2, [3, [4, [5]]], "six", seven::8]
+            (a b (c d) e::f)
+            {name: "struct", alpha: 1, beta: [1, 2, {gamma: 3}], nested: {deeper: {x: null.int}}}
+            annot1::annot2::{x: 1, y: two::2}
+            null.struct null.list null.sexp null.string
+            "#,
+        )?;
+        let stream_body = data[4..].to_vec();
+        while data.len() < 1024 {
+            data.extend_from_slice(&stream_body);
+        }
+        let expected = Element::read_all(&data)?;
+
+        for end in 0..=data.len() {
+            let result = Reader::new(v1_0::Binary, &data[..end])
+                .and_then(|mut reader| reader.read_all_elements());
+            match result {
+                Ok(elements) => assert!(expected.iter().take(elements.len()).eq(elements.iter())),
+                Err(IonError::Incomplete(error)) => {
+                    if let (Some(needed), Some(available)) =
+                        (error.bytes_needed(), error.bytes_available())
+                    {
+                        assert!(needed > available, "at {end}: {error}");
+                    }
+                }
+                Err(IonError::Decoding(_)) => {}
+                Err(other) => panic!("unexpected error when truncated at {end}: {other:?}"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the first field of the struct at the head of `reader` and then abandons it.
+    fn read_first_field_of_struct(reader: &mut LazyRawBinaryReader_1_0<'_>) -> IonResult<usize> {
+        let value = reader.next()?.expect_value()?;
+        let end = value.range().end;
+        let lazy_struct = value.read()?.expect_struct()?;
+        let (name, _value) = lazy_struct
+            .iter()
+            .next()
+            .expect("first field")?
+            .expect_name_value()?;
+        assert_eq!(name.read()?, 4.as_raw_symbol_token_ref()); // 'name'
+        Ok(end)
+    }
+
+    #[test]
+    fn skip_rest_of_large_struct() -> IonResult<()> {
+        let fields: Vec<String> = (0..5_000).map(|i| format!("name: {i}")).collect();
+        let data = &to_binary_ion(&format!("{{{}}} 42", fields.join(", ")))?;
+        let mut reader = LazyRawBinaryReader_1_0::new(data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let struct_end = read_first_field_of_struct(&mut reader)?;
+        // Advancing past the struct resumes reading at the end offset recorded in its header.
+        let value = reader.next()?.expect_value()?;
+        assert_eq!(value.range().start, struct_end);
+        assert_eq!(value.read()?.expect_i64()?, 42);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_rest_of_container_without_parsing_its_fields() -> IonResult<()> {
+        let data: Vec<u8> = vec![
+            0xe0, 0x01, 0x00, 0xea, // IVM
+            0xd5, // 5-byte struct
+            0x84, 0x21, 0x01, // name: 1
+            0xff, 0xff, // Illegal type descriptors that would fail to parse
+            0x21, 0x02, // 2
+        ];
+        let mut reader = LazyRawBinaryReader_1_0::new(&data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let _ = read_first_field_of_struct(&mut reader)?;
+        assert_eq!(reader.next()?.expect_value()?.read()?.expect_i64()?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_rest_of_nested_containers() -> IonResult<()> {
+        let data = &to_binary_ion(
+            r#"
+            {name: {name: [1, [2, 3], {version: 4}], imports: 5}, symbols: 6}
+            7
+        "#,
+        )?;
+        let mut reader = LazyRawBinaryReader_1_0::new(data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let outer = reader.next()?.expect_value()?.read()?.expect_struct()?;
+        let mut outer_fields = outer.iter();
+        let (_name, inner) = outer_fields.next().expect("field 1")?.expect_name_value()?;
+        let inner = inner.read()?.expect_struct()?;
+        let (_name, list) = inner.iter().next().expect("field 1")?.expect_name_value()?;
+        let list = list.read()?.expect_list()?;
+        let mut list_values = list.sequence.iter();
+        let _one = list_values.next().expect("value 1")?.expect_value()?;
+        let nested = list_values.next().expect("value 2")?.expect_value()?;
+        let _two = nested.read()?.expect_list()?.sequence.iter().next();
+        // Abandoning the nested containers resumes at the next field of the outermost struct...
+        let (name, value) = outer_fields.next().expect("field 2")?.expect_name_value()?;
+        assert_eq!(name.read()?, 7.as_raw_symbol_token_ref()); // 'symbols'
+        assert_eq!(value.read()?.expect_i64()?, 6);
+        assert!(outer_fields.next().is_none());
+        // ...and then at the next top-level value.
+        assert_eq!(reader.next()?.expect_value()?.read()?.expect_i64()?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_rest_of_annotated_container() -> IonResult<()> {
+        let data = &to_binary_ion(
+            r#"
+            name::version::{name: 1, imports: [2, 3], symbols: 4}
+            5
+        "#,
+        )?;
+        let mut reader = LazyRawBinaryReader_1_0::new(data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let value = reader.next()?.expect_value()?;
+        assert_eq!(value.annotations().count(), 2);
+        let struct_end = value.range().end;
+        let lazy_struct = value.read()?.expect_struct()?;
+        let _field = lazy_struct.iter().next().expect("field 1")?;
+        let value = reader.next()?.expect_value()?;
+        assert_eq!(value.range().start, struct_end);
+        assert_eq!(value.read()?.expect_i64()?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_rest_of_container_at_end_of_stream() -> IonResult<()> {
+        let data = &to_binary_ion(r#"{name: [1, 2, 3], imports: 4}"#)?;
+        let mut reader = LazyRawBinaryReader_1_0::new(data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let struct_end = read_first_field_of_struct(&mut reader)?;
+        assert_eq!(struct_end, data.len());
+        assert!(matches!(reader.next()?, RawStreamItem::EndOfStream(_)));
+        assert_eq!(reader.position(), data.len());
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 #![allow(non_camel_case_types)]
 use crate::lazy::binary::raw::v1_1::immutable_buffer::{AnnotationsEncoding, ImmutableBuffer};
 use crate::lazy::encoder::binary::v1_1::flex_sym::FlexSymValue;
+use crate::result::IonFailure;
 use crate::{IonResult, RawSymbolRef, SymbolId};
 
 /// Iterates over a slice of bytes, lazily reading them as a sequence of FlexUInt- or
@@ -42,8 +43,11 @@ impl<'a> Iterator for RawBinaryAnnotationsIterator_1_1<'a> {
                 };
                 let raw_symbol = match flex_sym.value() {
                     FlexSymValue::SymbolRef(raw_symbol) => raw_symbol,
-                    FlexSymValue::Opcode(_) => {
-                        todo!("FlexSym escapes in annotation sequences")
+                    FlexSymValue::Opcode(opcode) => {
+                        return Some(IonResult::decoding_error(format!(
+                            "found an illegal FlexSym escape in an annotations sequence: {:#04X}",
+                            opcode.byte
+                        )))
                     }
                 };
                 (raw_symbol, remaining_input)
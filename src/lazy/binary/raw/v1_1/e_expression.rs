@@ -16,6 +16,7 @@ use crate::lazy::expanded::template::{MacroSignature, Parameter, ParameterEncodi
 use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::text::raw::v1_1::arg_group::{EExpArg, EExpArgExpr};
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::result::IonFailure;
 use crate::{try_or_some_err, v1_1, Environment, HasRange, HasSpan, IonResult, Span};
 
 #[derive(Copy, Clone)]
@@ -144,7 +145,7 @@ impl<'top> RawEExpression<'top, v1_1::Binary> for &'top BinaryEExpression_1_1<'t
         // If we've already parsed and resolved the e-expression's arguments, use our cache as the
         // new environment.
         if let Some(cache) = self.cache {
-            return Ok(Environment::new(cache));
+            return Ok(Environment::new(cache).with_invocation(Some(self.span())));
         }
         // Otherwise, we parse the arguments and add them to a new environment as we go.
         // Note that (as currently designed) we cannot then populate the cache as we do not have
@@ -315,7 +316,11 @@ impl<'top> Iterator for BinaryEExpArgsInputIter<'top> {
                 let (group_header_flex_uint, _remaining_args_input) =
                     try_or_some_err!(self.remaining_args_buffer.read_flex_uint());
                 let bytes_to_read = match group_header_flex_uint.value() {
-                    0 => todo!("delimited argument groups"),
+                    0 => {
+                        return Some(IonResult::decoding_error(
+                            "delimited argument groups are not yet supported",
+                        ))
+                    }
                     n_bytes => n_bytes as usize,
                 };
                 // If it's length-prefixed, we don't need to inspect its contents. We can build an
@@ -24,7 +24,7 @@ use crate::lazy::expanded::macro_table::MacroRef;
 use crate::lazy::expanded::template::ParameterEncoding;
 use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::text::raw::v1_1::arg_group::EExpArgExpr;
-use crate::result::IonFailure;
+use crate::result::{DecodingError, IonFailure};
 use crate::{v1_1, IonError, IonResult};
 
 /// A buffer of unsigned bytes that can be cheaply copied and which defines methods for parsing
@@ -46,6 +46,10 @@ pub struct ImmutableBuffer<'a> {
     data: &'a [u8],
     offset: usize,
     context: EncodingContextRef<'a>,
+    // The number of delimited containers that were being read when this buffer was created.
+    // Delimited containers are read eagerly and recursively, so this is checked against the
+    // reader's maximum depth to keep deeply nested input from exhausting the stack.
+    depth: usize,
 }
 
 impl<'a> Debug for ImmutableBuffer<'a> {
@@ -93,6 +97,7 @@ impl<'a> ImmutableBuffer<'a> {
             data,
             offset,
             context,
+            depth: 0,
         }
     }
 
@@ -119,6 +124,7 @@ impl<'a> ImmutableBuffer<'a> {
             data: self.bytes_range(offset, length),
             offset: self.offset + offset,
             context: self.context,
+            depth: self.depth,
         }
     }
 
@@ -164,6 +170,7 @@ impl<'a> ImmutableBuffer<'a> {
             data: &self.data[num_bytes_to_consume..],
             offset: self.offset + num_bytes_to_consume,
             context: self.context,
+            depth: self.depth,
         }
     }
 
@@ -215,14 +222,20 @@ impl<'a> ImmutableBuffer<'a> {
         }
     }
 
-    /// Reads a [`FlexInt`] from the buffer.
+    /// Reads a [`FlexInt`] from the buffer, returning it along with the input that follows it.
+    ///
+    /// Encodings of up to 10 bytes are supported. A 10-byte encoding whose bits beyond the 64th
+    /// are not a sign extension of the value is a decoding error, as is any longer encoding.
     pub fn read_flex_int(self) -> ParseResult<'a, FlexInt> {
         let flex_int = FlexInt::read(self.bytes(), self.offset())?;
         let remaining = self.consume(flex_int.size_in_bytes());
         Ok((flex_int, remaining))
     }
 
-    /// Reads a [`FlexUInt`] from the buffer.
+    /// Reads a [`FlexUInt`] from the buffer, returning it along with the input that follows it.
+    ///
+    /// Encodings of up to 10 bytes are supported. A 10-byte encoding with any bits set beyond
+    /// the 64th is a decoding error, as is any longer encoding.
     #[inline]
     pub fn read_flex_uint(self) -> ParseResult<'a, FlexUInt> {
         let flex_uint = FlexUInt::read(self.bytes(), self.offset())?;
@@ -236,9 +249,9 @@ impl<'a> ImmutableBuffer<'a> {
         };
         let size_in_bytes = match first_byte {
             // If the first byte is zero, this flex_uint is encoded using 9+ bytes. That's pretty
-            // uncommon, so we'll just use the existing logic in the `read` method and discard the
+            // uncommon, so we'll just use the existing logic in `read_flex_uint` and discard the
             // value. If this shows up in profiles, it can be optimized further.
-            0 => FlexUInt::read(self.bytes(), self.offset())?.size_in_bytes(),
+            0 => self.read_flex_uint()?.0.size_in_bytes(),
             _ => first_byte.trailing_zeros() as usize + 1,
         };
 
@@ -259,6 +272,7 @@ impl<'a> ImmutableBuffer<'a> {
             // stream offset + local offset
             offset: self.offset + offset,
             context: self.context,
+            depth: self.depth,
         }
     }
 
@@ -333,6 +347,11 @@ impl<'a> ImmutableBuffer<'a> {
                 let (flex, after) = self.read_flex_uint()?;
                 Ok((Some(flex), after))
             }
+            LengthType::FlexUIntBody => {
+                // The FlexUInt is the value's body, so it is not consumed here.
+                let (flex, _after) = self.read_flex_uint()?;
+                Ok((Some(FlexUInt::new(0, flex.size_in_bytes() as u64)), self))
+            }
         }
     }
 
@@ -399,7 +418,9 @@ impl<'a> ImmutableBuffer<'a> {
             EExpressionWithAddress => {
                 ParseValueExprResult::EExp(self.read_eexp_with_address_in_opcode(opcode))
             }
-            EExpressionAddressFollows => todo!("eexp address follows"),
+            EExpressionAddressFollows => ParseValueExprResult::EExp(IonResult::decoding_error(
+                "e-expressions with a trailing macro address are not yet supported",
+            )),
             EExpressionWithLengthPrefix => {
                 ParseValueExprResult::EExp(self.read_eexp_with_length_prefix(opcode))
             }
@@ -461,11 +482,30 @@ impl<'a> ImmutableBuffer<'a> {
     ) -> IonResult<(DelimitedContents<'a>, ImmutableBuffer<'a>)> {
         use crate::IonType;
 
-        if let Some(IonType::Struct) = opcode.ion_type {
-            self.peek_delimited_struct()
-        } else {
-            self.peek_delimited_sequence()
+        let max_depth = self.context.read_options.max_depth;
+        if self.depth >= max_depth {
+            return Err(DecodingError::new(format!(
+                "found a container that exceeds the maximum nesting depth of {max_depth}"
+            ))
+            .with_position(self.offset())
+            .into());
         }
+        let body = ImmutableBuffer {
+            depth: self.depth + 1,
+            ..self
+        };
+        let (contents, after) = if let Some(IonType::Struct) = opcode.ion_type {
+            body.peek_delimited_struct()?
+        } else {
+            body.peek_delimited_sequence()?
+        };
+        Ok((
+            contents,
+            ImmutableBuffer {
+                depth: self.depth,
+                ..after
+            },
+        ))
     }
 
     pub(crate) fn peek_delimited_sequence(
@@ -522,7 +562,12 @@ impl<'a> ImmutableBuffer<'a> {
         let (sym, after) = match flex_sym.value() {
             FlexSymValue::SymbolRef(sym_ref) => (sym_ref, after),
             FlexSymValue::Opcode(o) if o.is_delimited_end() => return Ok((None, after)),
-            _ => unreachable!(),
+            FlexSymValue::Opcode(o) => {
+                return IonResult::decoding_error(format!(
+                    "found an illegal FlexSym escape in a field name: {:#04X}",
+                    o.byte
+                ))
+            }
         };
 
         let matched_field_id = self.slice(0, after.offset() - self.offset());
@@ -640,6 +685,10 @@ impl<'a> ImmutableBuffer<'a> {
                     // This call to `read_value_length` is not always inlined, so we avoid the method call
                     // if possible.
                     LengthType::FlexUIntFollows => input.consume(1).read_flex_uint()?.0,
+                    LengthType::FlexUIntBody => {
+                        let body_length = input.consume(1).read_flex_uint()?.0.size_in_bytes();
+                        FlexUInt::new(0, body_length as u64)
+                    }
                 };
 
                 let length_length = length.size_in_bytes() as u8;
@@ -789,7 +838,10 @@ impl<'a> ImmutableBuffer<'a> {
         // TODO: As an optimization, see if we can avoid actually reading the flex_sym.
         let (flex_sym, remaining) = self.read_flex_sym()?;
         if let FlexSymValue::Opcode(opcode) = flex_sym.value() {
-            todo!("FlexSym escapes in annotation sequences; opcode: {opcode:?}");
+            return IonResult::decoding_error(format!(
+                "found an illegal FlexSym escape in an annotations sequence: {:#04X}",
+                opcode.byte
+            ));
         }
 
         Ok(remaining)
@@ -874,11 +926,13 @@ impl<'a> ImmutableBuffer<'a> {
     pub fn read_e_expression(self, opcode: Opcode) -> ParseResult<'a, BinaryEExpression_1_1<'a>> {
         use OpcodeType::*;
         match opcode.opcode_type {
-            EExpressionWithAddress => return self.read_eexp_with_address_in_opcode(opcode),
-            EExpressionAddressFollows => todo!("e-expr with trailing address; {opcode:#0x?}",),
-            EExpressionWithLengthPrefix => return self.read_eexp_with_length_prefix(opcode),
+            EExpressionWithAddress => self.read_eexp_with_address_in_opcode(opcode),
+            EExpressionAddressFollows => IonResult::decoding_error(format!(
+                "e-expressions with a trailing macro address are not yet supported; {opcode:#0x?}"
+            )),
+            EExpressionWithLengthPrefix => self.read_eexp_with_length_prefix(opcode),
             _ => unreachable!("read_e_expression called with invalid opcode"),
-        };
+        }
     }
 
     fn read_eexp_with_address_in_opcode(
@@ -1167,6 +1221,120 @@ mod tests {
         input_test(Vec::from("foo bar baz".as_bytes()));
     }
 
+    /// Returns the values on either side of each 7-bit boundary in a `FlexUInt`'s encoding along
+    /// with the number of bytes needed to encode them.
+    fn flex_uint_boundary_values() -> Vec<(u64, usize)> {
+        let mut values = vec![(0, 1), (u64::MAX, 10)];
+        for num_bytes in 1..=9 {
+            let boundary = 1u64 << (7 * num_bytes);
+            values.push((boundary - 1, num_bytes));
+            values.push((boundary, num_bytes + 1));
+        }
+        values
+    }
+
+    /// Returns the values on either side of each 7-bit boundary in a `FlexInt`'s encoding along
+    /// with the number of bytes needed to encode them.
+    fn flex_int_boundary_values() -> Vec<(i64, usize)> {
+        let mut values = vec![(0, 1), (-1, 1), (i64::MAX, 10), (i64::MIN, 10)];
+        for num_bytes in 1..=9 {
+            // Each byte holds 7 bits of the value, the highest of which is the sign bit.
+            let boundary = 1i64 << (7 * num_bytes - 1);
+            values.push((boundary - 1, num_bytes));
+            values.push((boundary, num_bytes + 1));
+            values.push((-boundary, num_bytes));
+            values.push((-boundary - 1, num_bytes + 1));
+        }
+        values
+    }
+
+    #[test]
+    fn read_flex_uint_boundaries() -> IonResult<()> {
+        let context = EncodingContext::empty();
+        for (value, expected_size) in flex_uint_boundary_values() {
+            let mut encoding = Vec::new();
+            FlexUInt::write(&mut encoding, value)?;
+            assert_eq!(encoding.len(), expected_size, "encoded size of {value}");
+            // Read the value both from an exact-length slice and from a slice with trailing data,
+            // which exercises the paths that read the encoding without copying it.
+            for trailing_bytes in [0, 16] {
+                let mut input = encoding.clone();
+                input.resize(encoding.len() + trailing_bytes, 0xFF);
+                let buffer = ImmutableBuffer::new_with_offset(context.get_ref(), &input, 100);
+                let (flex_uint, remaining) = buffer.read_flex_uint()?;
+                assert_eq!(flex_uint.value(), value, "encoding {encoding:x?}");
+                assert_eq!(flex_uint.size_in_bytes(), expected_size);
+                assert_eq!(remaining.offset(), 100 + expected_size);
+                assert_eq!(remaining.len(), trailing_bytes);
+            }
+            for end in 0..encoding.len() {
+                let partial = ImmutableBuffer::new(context.get_ref(), &encoding[..end]);
+                assert!(
+                    matches!(partial.read_flex_uint(), Err(IonError::Incomplete(_))),
+                    "prefix of length {end} of {encoding:x?}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_flex_int_boundaries() -> IonResult<()> {
+        let context = EncodingContext::empty();
+        for (value, expected_size) in flex_int_boundary_values() {
+            let mut encoding = Vec::new();
+            FlexInt::write_i64(&mut encoding, value)?;
+            assert_eq!(encoding.len(), expected_size, "encoded size of {value}");
+            for trailing_bytes in [0, 16] {
+                let mut input = encoding.clone();
+                input.resize(encoding.len() + trailing_bytes, 0xFF);
+                let buffer = ImmutableBuffer::new_with_offset(context.get_ref(), &input, 100);
+                let (flex_int, remaining) = buffer.read_flex_int()?;
+                assert_eq!(flex_int.value(), value, "encoding {encoding:x?}");
+                assert_eq!(flex_int.size_in_bytes(), expected_size);
+                assert_eq!(remaining.offset(), 100 + expected_size);
+                assert_eq!(remaining.len(), trailing_bytes);
+            }
+            for end in 0..encoding.len() {
+                let partial = ImmutableBuffer::new(context.get_ref(), &encoding[..end]);
+                assert!(
+                    matches!(partial.read_flex_int(), Err(IonError::Incomplete(_))),
+                    "prefix of length {end} of {encoding:x?}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    // The continuation bits indicate an encoding longer than 10 bytes.
+    #[case::eleven_bytes(&[0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0, 0, 0], true, true)]
+    // Bits beyond the 64th are set and disagree with the 64th bit, so the value fits in
+    // neither a u64 nor an i64.
+    #[case::ten_bytes_overflow(&[0x00, 0x02, 0, 0, 0, 0, 0, 0, 0, 0x80], true, true)]
+    // A FlexInt may sign-extend into the extra bits, but a FlexUInt may not.
+    #[case::ten_bytes_sign_extended(&[0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF], true, false)]
+    fn read_oversized_flex_primitives(
+        #[case] input: &[u8],
+        #[case] flex_uint_fails: bool,
+        #[case] flex_int_fails: bool,
+    ) {
+        let context = EncodingContext::empty();
+        let buffer = ImmutableBuffer::new(context.get_ref(), input);
+        let uint_result = buffer.read_flex_uint();
+        assert_eq!(
+            matches!(uint_result, Err(IonError::Decoding(_))),
+            flex_uint_fails,
+            "{uint_result:?}"
+        );
+        let int_result = buffer.read_flex_int();
+        assert_eq!(
+            matches!(int_result, Err(IonError::Decoding(_))),
+            flex_int_fails,
+            "{int_result:?}"
+        );
+    }
+
     #[test]
     fn validate_nop_length() {
         // read_nop_pad reads a single NOP value, this test ensures that we're tracking the right
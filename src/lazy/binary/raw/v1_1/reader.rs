@@ -11,6 +11,7 @@ use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem
 use crate::lazy::streaming_raw_reader::RawReaderState;
 use crate::{Encoding, IonResult};
 
+#[derive(Clone)]
 pub struct LazyRawBinaryReader_1_1<'data> {
     input: &'data [u8],
     // The offset from the beginning of the overall stream at which the `input` slice begins
@@ -343,8 +344,8 @@ mod tests {
             // Symbol ID: 257
             0xE2, 0x01, 0x00,
 
-            // Symbol ID: 65,793
-            0xE3, 0x01, 0x00, 0x00,
+            // Symbol ID: 65,793 (a biased FlexUInt follows the opcode)
+            0xE3, 0x03,
         ];
         let empty_context = EncodingContext::empty();
         let context = empty_context.get_ref();
@@ -1062,6 +1063,25 @@ mod tests {
                     (11.into(), IonType::Int)
                 ],
             ),
+            (
+                // {$10: 1, "foo": 2, $11: 3}  - switches to FlexSym mode after the first field
+                &[ 0xDD, 0x15, 0x61, 0x01, 0x01, 0xFB, 0x66, 0x6F, 0x6F, 0x61, 0x02, 0x17, 0x61, 0x03 ],
+                &[
+                    (10usize.into(), IonType::Int),
+                    ("foo".into(), IonType::Int),
+                    (11usize.into(), IonType::Int),
+                ],
+            ),
+            (
+                // {"": 1}  - FlexSym escape for empty text
+                &[ 0xD5, 0x01, 0x01, 0x90, 0x61, 0x01 ],
+                &[ ("".into(), IonType::Int) ],
+            ),
+            (
+                // {$0: 1}  - FlexSym escape for symbol ID 0
+                &[ 0xD6, 0x01, 0x01, 0xE1, 0x00, 0x61, 0x01 ],
+                &[ (0usize.into(), IonType::Int) ],
+            ),
             (
                 // {}
                 &[ 0xFD, 0x01 ],
@@ -1118,6 +1138,11 @@ mod tests {
                 &[ 0xF3, 0xFB, 0x66, 0x6F, 0x6F, 0x61, 0x01, 0x17, 0xE1, 0x02, 0x01, 0xF0],
                 &[ ("foo".into(), IonType::Int), (11usize.into(), IonType::Symbol)],
             ),
+            (
+                // { "": 1, $0: 2 }  - delimited
+                &[ 0xF3, 0x01, 0x90, 0x61, 0x01, 0x01, 0xE1, 0x00, 0x61, 0x02, 0x01, 0xF0],
+                &[ ("".into(), IonType::Int), (0usize.into(), IonType::Int)],
+            ),
         ];
 
         for (ion_data, field_pairs) in tests {
@@ -1143,4 +1168,329 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn struct_field_name_with_multibyte_flex_sym_length() -> IonResult<()> {
+        use crate::lazy::decoder::{HasRange, LazyRawFieldName};
+
+        // A 70-byte field name; its negated length (-70) requires a two-byte FlexInt.
+        let name = "f".repeat(70);
+        let mut field = vec![0xEA, 0xFE];
+        field.extend_from_slice(name.as_bytes());
+        field.extend_from_slice(&[0x61, 0x01]);
+
+        // Length-prefixed, switching into FlexSym mode. The struct length (75) is a FlexUInt.
+        let mut length_prefixed = vec![0xFD, 0x97, 0x01];
+        length_prefixed.extend_from_slice(&field);
+        // Delimited
+        let mut delimited = vec![0xF3];
+        delimited.extend_from_slice(&field);
+        delimited.extend_from_slice(&[0x01, 0xF0]);
+
+        for (ion_data, name_offset) in [(length_prefixed, 3), (delimited, 1)] {
+            let encoding_context = EncodingContext::empty();
+            let context = encoding_context.get_ref();
+            let mut reader = LazyRawBinaryReader_1_1::new(&ion_data);
+            let strukt = reader
+                .next(context)?
+                .expect_value()?
+                .read()?
+                .expect_struct()?;
+            let mut fields = strukt.iter();
+            let (field_name, value) = fields.next().unwrap()?.expect_name_value()?;
+            assert_eq!(field_name.read()?, RawSymbolRef::Text(name.as_str()));
+            assert_eq!(field_name.range(), name_offset..name_offset + 72);
+            assert_eq!(value.read()?.expect_int()?, 1.into());
+            assert!(fields.next().is_none());
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::zero(&[0x00, 0x00], 0.0)]
+    #[case::negative_zero(&[0x00, 0x80], -0.0)]
+    #[case::one(&[0x00, 0x3C], 1.0)]
+    #[case::negative_two(&[0x00, 0xC0], -2.0)]
+    #[case::max(&[0xFF, 0x7B], 65504.0)]
+    #[case::smallest_subnormal(&[0x01, 0x00], 2f64.powi(-24))]
+    #[case::infinity(&[0x00, 0x7C], f64::INFINITY)]
+    #[case::negative_infinity(&[0x00, 0xFC], f64::NEG_INFINITY)]
+    fn half_precision_floats(#[case] body: &[u8], #[case] expected: f64) -> IonResult<()> {
+        let mut data = vec![0xE0, 0x01, 0x01, 0xEA, 0x6B];
+        data.extend_from_slice(body);
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = reader.next(context)?.expect_ivm()?;
+        let actual = reader
+            .next(context)?
+            .expect_value()?
+            .read()?
+            .expect_float()?;
+        assert_eq!(actual, expected);
+        assert_eq!(actual.is_sign_negative(), expected.is_sign_negative());
+        Ok(())
+    }
+
+    #[test]
+    fn half_precision_nan() -> IonResult<()> {
+        let data = [0xE0, 0x01, 0x01, 0xEA, 0x6B, 0x00, 0x7E];
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = reader.next(context)?.expect_ivm()?;
+        let actual = reader
+            .next(context)?
+            .expect_value()?
+            .read()?
+            .expect_float()?;
+        assert!(actual.is_nan());
+        Ok(())
+    }
+
+    mod round_trip {
+        use rstest::rstest;
+
+        use crate::lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1;
+        use crate::lazy::decoder::{HasRange, HasSpan};
+        use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
+        use crate::lazy::encoder::value_writer::{AnnotatableWriter, ValueWriter};
+        use crate::lazy::encoding::BinaryEncoding_1_1;
+        use crate::lazy::expanded::EncodingContext;
+        use crate::lazy::raw_stream_item::RawStreamItem;
+        use crate::lazy::raw_value_ref::RawValueRef;
+        use crate::raw_symbol_ref::RawSymbolRef;
+        use crate::{IonResult, IonType};
+
+        const IVM: &[u8] = &[0xE0, 0x01, 0x01, 0xEA];
+
+        /// Encodes `value` (with `annotations`) using the 1.1 binary writer, returning the
+        /// encoding without the leading IVM.
+        fn encode(
+            value: RawValueRef<'_, BinaryEncoding_1_1>,
+            annotations: &[RawSymbolRef<'_>],
+        ) -> IonResult<Vec<u8>> {
+            let mut buffer = Vec::new();
+            let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+            writer
+                .value_writer()
+                .with_annotations(annotations.to_vec())?
+                .write(value)?;
+            writer.flush()?;
+            drop(writer);
+            Ok(buffer[IVM.len()..].to_vec())
+        }
+
+        fn scalar_values<'a>() -> Vec<RawValueRef<'a, BinaryEncoding_1_1>> {
+            use RawValueRef::*;
+            let mut values = vec![
+                Bool(true),
+                Bool(false),
+                Int(0.into()),
+                Int(17.into()),
+                Int((-944).into()),
+                Int(i64::MAX.into()),
+                Int(i64::MIN.into()),
+                Int(crate::Int::from(147573952589676412929i128)),
+                Int(crate::Int::from(-147573952589676412929i128)),
+                Float(0.0),
+                Float(1.5),
+                Float(-2.5e-8),
+                Float(std::f64::consts::PI),
+                Float(f64::INFINITY),
+                Float(f64::NEG_INFINITY),
+                Decimal(crate::Decimal::new(0, 0)),
+                Decimal(crate::Decimal::new(-12345, -2)),
+                Decimal(crate::Decimal::new(7, 10)),
+                Timestamp(crate::Timestamp::with_year(2024).build().unwrap()),
+                Timestamp(
+                    crate::Timestamp::with_ymd(2024, 6, 15)
+                        .with_hms(12, 30, 45)
+                        .with_milliseconds(123)
+                        .with_offset(-300)
+                        .build()
+                        .unwrap(),
+                ),
+                String("".into()),
+                String("hello".into()),
+                String("a string that is longer than fifteen bytes".into()),
+                Symbol(RawSymbolRef::Text("foo")),
+                Symbol(RawSymbolRef::Text(
+                    "a symbol that is longer than fifteen bytes",
+                )),
+                Symbol(RawSymbolRef::SymbolId(0)),
+                Symbol(RawSymbolRef::SymbolId(4)),
+                Symbol(RawSymbolRef::SymbolId(300)),
+                Symbol(RawSymbolRef::SymbolId(100_000)),
+                Blob((&[]).into()),
+                Blob((&[0xDE, 0xAD, 0xBE, 0xEF]).into()),
+                Clob("hello, clob".into()),
+                Null(IonType::Null),
+            ];
+            use IonType as T;
+            for ion_type in [
+                T::Bool,
+                T::Int,
+                T::Float,
+                T::Decimal,
+                T::Timestamp,
+                T::String,
+                T::Symbol,
+                T::Blob,
+                T::Clob,
+                T::List,
+                T::SExp,
+                T::Struct,
+            ] {
+                values.push(Null(ion_type));
+            }
+            values
+        }
+
+        #[rstest]
+        #[case::none(&[])]
+        #[case::one_sid(&[RawSymbolRef::SymbolId(4)])]
+        #[case::two_sids(&[RawSymbolRef::SymbolId(4), RawSymbolRef::SymbolId(500)])]
+        #[case::three_sids(&[
+            RawSymbolRef::SymbolId(4),
+            RawSymbolRef::SymbolId(5),
+            RawSymbolRef::SymbolId(6),
+        ])]
+        #[case::one_text(&[RawSymbolRef::Text("foo")])]
+        #[case::two_text(&[RawSymbolRef::Text("foo"), RawSymbolRef::Text("bar")])]
+        #[case::three_mixed(&[
+            RawSymbolRef::Text("foo"),
+            RawSymbolRef::SymbolId(5),
+            RawSymbolRef::Text("a long annotation that needs a FlexUInt length"),
+        ])]
+        #[case::special_cases(&[RawSymbolRef::Text(""), RawSymbolRef::SymbolId(0)])]
+        fn scalars_round_trip(#[case] annotations: &[RawSymbolRef<'static>]) -> IonResult<()> {
+            let values = scalar_values();
+            let mut data = IVM.to_vec();
+            let mut expected_ranges = Vec::with_capacity(values.len());
+            for value in &values {
+                let encoding = encode(value.clone(), annotations)?;
+                expected_ranges.push(data.len()..data.len() + encoding.len());
+                data.extend_from_slice(&encoding);
+            }
+
+            let empty_context = EncodingContext::empty();
+            let context = empty_context.get_ref();
+            let mut reader = LazyRawBinaryReader_1_1::new(&data);
+            let _ivm = reader.next(context)?.expect_ivm()?;
+            for (index, expected_range) in expected_ranges.into_iter().enumerate() {
+                let value = reader.next(context)?.expect_value()?;
+                // `RawValueRef` is invariant over its lifetime; construct the expected values
+                // again so they can share the lifetime of the value being read.
+                let expected_value = scalar_values()[index].clone();
+                assert_eq!(value.read()?, expected_value);
+                let actual_annotations = value.annotations().collect::<IonResult<Vec<_>>>()?;
+                assert_eq!(actual_annotations.as_slice(), annotations);
+                assert_eq!(value.range(), expected_range);
+                assert_eq!(value.span().bytes(), &data[expected_range]);
+                assert_eq!(
+                    value.is_null(),
+                    matches!(expected_value, RawValueRef::Null(_))
+                );
+            }
+            assert!(matches!(
+                reader.next(context)?,
+                RawStreamItem::EndOfStream(_)
+            ));
+            Ok(())
+        }
+
+        #[rstest]
+        #[case::sids(&[
+            RawSymbolRef::SymbolId(4),
+            RawSymbolRef::SymbolId(10),
+            RawSymbolRef::SymbolId(300),
+        ])]
+        #[case::text(&[RawSymbolRef::Text("foo"), RawSymbolRef::Text("bar")])]
+        #[case::sids_then_text(&[
+            RawSymbolRef::SymbolId(4),
+            RawSymbolRef::Text("foo"),
+            RawSymbolRef::SymbolId(10),
+            RawSymbolRef::Text("a field name that is long enough to need a two-byte FlexInt length"),
+        ])]
+        #[case::special_cases(&[
+            RawSymbolRef::SymbolId(10),
+            RawSymbolRef::Text(""),
+            RawSymbolRef::SymbolId(0),
+        ])]
+        fn struct_field_names_round_trip(
+            #[case] field_names: &[RawSymbolRef<'static>],
+            #[values(false, true)] delimited: bool,
+        ) -> IonResult<()> {
+            use crate::lazy::decoder::LazyRawFieldName;
+            use crate::lazy::encoder::value_writer::StructWriter;
+
+            let mut data = Vec::new();
+            let mut writer = LazyRawBinaryWriter_1_1::new(&mut data)?;
+            let value_writer = writer.value_writer();
+            let mut struct_writer = if delimited {
+                value_writer.with_delimited_containers().struct_writer()?
+            } else {
+                value_writer.struct_writer()?
+            };
+            for (index, name) in field_names.iter().enumerate() {
+                struct_writer.write(*name, index as i64)?;
+            }
+            struct_writer.close()?;
+            writer.flush()?;
+            drop(writer);
+
+            let empty_context = EncodingContext::empty();
+            let context = empty_context.get_ref();
+            let mut reader = LazyRawBinaryReader_1_1::new(&data);
+            let _ivm = reader.next(context)?.expect_ivm()?;
+            let strukt = reader
+                .next(context)?
+                .expect_value()?
+                .read()?
+                .expect_struct()?;
+            let mut fields = strukt.iter();
+            for (index, expected_name) in field_names.iter().enumerate() {
+                let (name, value) = fields.next().unwrap()?.expect_name_value()?;
+                assert_eq!(name.read()?, *expected_name);
+                assert_eq!(value.read()?.expect_int()?, (index as i64).into());
+            }
+            assert!(fields.next().is_none());
+            assert!(matches!(
+                reader.next(context)?,
+                RawStreamItem::EndOfStream(_)
+            ));
+            Ok(())
+        }
+
+        #[rstest]
+        #[case::reserved_int_length(&[0x69])]
+        #[case::reserved_timestamp_8d(&[0x8D])]
+        #[case::reserved_timestamp_8e(&[0x8E])]
+        #[case::reserved_timestamp_8f(&[0x8F])]
+        #[case::reserved_f4(&[0xF4])]
+        #[case::typed_null_bad_type(&[0xEB, 0x0C])]
+        #[case::annotation_flex_sym_bad_escape(&[0xE7, 0x01, 0x61, 0x60])]
+        #[case::annotation_flex_sym_truncated_escape(&[0xE7, 0x01])]
+        #[case::annotation_without_value(&[0xE7, 0x09])]
+        #[case::truncated_int(&[0x62, 0x01])]
+        #[case::truncated_float(&[0x6C, 0x00, 0x00])]
+        #[case::truncated_string(&[0x93, 0x61])]
+        #[case::truncated_flex_uint_length_string(&[0xF9, 0x21, 0x61])]
+        #[case::invalid_utf8_string(&[0x91, 0xFF])]
+        fn illegal_input_is_an_error(#[case] bytes: &[u8]) {
+            let mut data = IVM.to_vec();
+            data.extend_from_slice(bytes);
+            let empty_context = EncodingContext::empty();
+            let context = empty_context.get_ref();
+            let mut reader = LazyRawBinaryReader_1_1::new(&data);
+            let _ivm = reader.next(context).unwrap().expect_ivm().unwrap();
+            let result = reader.next(context).and_then(|item| {
+                let value = item.expect_value()?;
+                value.annotations().collect::<IonResult<Vec<_>>>()?;
+                value.read().map(|_| ())
+            });
+            assert!(result.is_err(), "expected an error for {bytes:02X?}");
+        }
+    }
 }
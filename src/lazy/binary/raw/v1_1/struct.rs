@@ -193,7 +193,12 @@ impl<'top> RawBinaryStructIterator_1_1<'top> {
         let (sym, after) = match flex_sym.value() {
             FlexSymValue::SymbolRef(sym_ref) => (sym_ref, after),
             FlexSymValue::Opcode(o) if o.is_delimited_end() => return Ok(None),
-            _ => unreachable!(),
+            FlexSymValue::Opcode(o) => {
+                return IonResult::decoding_error(format!(
+                    "found an illegal FlexSym escape in a field name: {:#04X}",
+                    o.byte
+                ))
+            }
         };
 
         let matched_field_id = buffer.slice(0, flex_sym.size_in_bytes());
@@ -158,6 +158,8 @@ impl Opcode {
 pub enum LengthType {
     InOpcode(u8),
     FlexUIntFollows,
+    /// The value's body is a single `FlexUInt`; its length is the size of that `FlexUInt`.
+    FlexUIntBody,
     Unknown,
 }
 
@@ -187,7 +189,8 @@ impl Header {
             (OpcodeType::NullNull, 0xA) => InOpcode(0),
             (OpcodeType::String, 0..=15) => InOpcode(self.low_nibble),
             (OpcodeType::InlineSymbol, n) if n < 16 => InOpcode(n),
-            (OpcodeType::SymbolAddress, n) if n < 4 => InOpcode(n),
+            (OpcodeType::SymbolAddress, n) if n < 3 => InOpcode(n),
+            (OpcodeType::SymbolAddress, 3) => FlexUIntBody,
             (OpcodeType::Decimal, 0..=15) => InOpcode(self.low_nibble),
             (OpcodeType::List, n) if n < 16 => InOpcode(n),
             (OpcodeType::SExpression, n) if n < 16 => InOpcode(n),
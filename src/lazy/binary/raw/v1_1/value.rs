@@ -5,6 +5,7 @@ use std::ops::Range;
 
 use num_traits::PrimInt;
 
+use crate::decimal::coefficient::Coefficient;
 use crate::lazy::binary::raw::v1_1::immutable_buffer::AnnotationsEncoding;
 use crate::lazy::binary::raw::v1_1::r#struct::LazyRawBinaryStruct_1_1;
 use crate::lazy::binary::raw::v1_1::sequence::{LazyRawBinaryList_1_1, LazyRawBinarySExp_1_1};
@@ -15,7 +16,6 @@ use crate::lazy::expanded::template::ParameterEncoding;
 use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::span::Span;
 use crate::lazy::str_ref::StrRef;
-use crate::v1_1::FlexUInt;
 use crate::{
     lazy::{
         binary::{
@@ -34,7 +34,7 @@ use crate::{
         encoding::BinaryEncoding_1_1,
         raw_value_ref::RawValueRef,
     },
-    result::IonFailure,
+    result::{DecodingErrorKind, IonFailure},
     types::{HasMinute, SymbolId, Timestamp, TimestampBuilder},
     Decimal, Int, IonEncoding, IonError, IonResult, IonType, LazyExpandedList, LazyExpandedSExp,
     LazyExpandedStruct, LazyList, LazySExp, LazyStruct, RawSymbolRef, SymbolRef, ValueRef,
@@ -139,7 +139,7 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryValue_1
 
     fn read(&self) -> IonResult<RawValueRef<'top, BinaryEncoding_1_1>> {
         if self.encoded_value.encoding == ParameterEncoding::FlexUInt {
-            let flex_uint = FlexUInt::read(self.input.bytes(), self.input.offset())?;
+            let flex_uint = self.input.read_flex_uint()?.0;
             let int: Int = flex_uint.value().into();
             return Ok(RawValueRef::Int(int));
         }
@@ -147,7 +147,14 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryValue_1
         if self.is_null() {
             let ion_type = if self.encoded_value.header.ion_type_code == OpcodeType::TypedNull {
                 let body = self.value_body();
-                ION_1_1_TYPED_NULL_TYPES[body[0] as usize]
+                *ION_1_1_TYPED_NULL_TYPES
+                    .get(body[0] as usize)
+                    .ok_or_else(|| {
+                        IonError::decoding_error(format!(
+                            "found a typed null with an invalid type byte: {:#04X}",
+                            body[0]
+                        ))
+                    })?
             } else {
                 IonType::Null
             };
@@ -189,7 +196,7 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryValue_1
         context: EncodingContextRef<'top>,
     ) -> IonResult<ValueRef<'top, BinaryEncoding_1_1>> {
         if self.encoded_value.encoding == ParameterEncoding::FlexUInt {
-            let flex_uint = FlexUInt::read(self.input.bytes(), self.input.offset())?;
+            let flex_uint = self.input.read_flex_uint()?.0;
             let int: Int = flex_uint.value().into();
             return Ok(ValueRef::Int(int));
         }
@@ -212,7 +219,7 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryValue_1
             context: EncodingContextRef<'a>,
         ) -> IonResult<ValueRef<'a, BinaryEncoding_1_1>> {
             if value.encoded_value.encoding == ParameterEncoding::FlexUInt {
-                let flex_uint = FlexUInt::read(value.input.bytes(), value.input.offset())?;
+                let flex_uint = value.input.read_flex_uint()?.0;
                 let int: Int = flex_uint.value().into();
                 return Ok(ValueRef::Int(int));
             }
@@ -231,7 +238,8 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryValue_1
                     IonType::String => ValueRef::String(value.read_string()?),
                     IonType::Symbol => {
                         let raw_symbol: RawSymbolRef = value.read_symbol()?;
-                        let symbol: SymbolRef = raw_symbol.resolve(context)?;
+                        let offset = value.value_span().range().start;
+                        let symbol: SymbolRef = raw_symbol.resolve_at(context, offset)?;
                         ValueRef::Symbol(symbol)
                     }
                     IonType::Blob => ValueRef::Blob(value.read_blob()?),
@@ -251,6 +259,11 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryValue_1
         }
     }
 
+    fn float_size(&self) -> Option<usize> {
+        (self.ion_type() == IonType::Float && !self.is_null())
+            .then_some(self.encoded_value.value_body_length)
+    }
+
     fn annotations_span(&self) -> Span<'top> {
         let Some(range) = self.encoded_value.annotations_range() else {
             // If there are no annotations, return an empty slice positioned at the opcode
@@ -397,7 +410,7 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         debug_assert!(self.encoded_value.ion_type() == IonType::Int);
         debug_assert!(!self.is_null());
         let body_bytes = self.value_body();
-        Ok(*FixedInt::read(body_bytes, body_bytes.len(), self.input.offset())?.value())
+        Ok(FixedInt::read(body_bytes, body_bytes.len(), self.input.offset())?.into())
     }
 
     /// Helper method called by [`Self::read`]. Reads the current value as a float.
@@ -419,7 +432,13 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
 
                 f32::from_le_bytes(buffer).into()
             }
-            2 => todo!("implement half-precision floats"),
+            2 => {
+                let mut buffer = [0; 2];
+                let val_bytes = self.value_body_buffer().bytes_range(0, 2);
+                buffer[..2].copy_from_slice(val_bytes);
+
+                f16_bits_to_f64(u16::from_le_bytes(buffer))
+            }
             0 => 0.0f64,
             _ => unreachable!("found a float value with illegal byte size"),
         };
@@ -449,7 +468,7 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
             if coefficient_size > 0 && coefficient.value().as_i64() == Some(0) {
                 Decimal::negative_zero_with_exponent(exponent.value())
             } else {
-                Decimal::new(coefficient, exponent.value())
+                Decimal::new(Coefficient::try_from(coefficient)?, exponent.value())
             }
         };
 
@@ -771,8 +790,12 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         debug_assert!(self.encoded_value.ion_type() == IonType::String);
         debug_assert!(!self.is_null());
         let raw_bytes = self.value_body();
-        let text = std::str::from_utf8(raw_bytes)
-            .map_err(|_| IonError::decoding_error("found string with invalid UTF-8 data"))?;
+        let text = std::str::from_utf8(raw_bytes).map_err(|_| {
+            IonError::decoding_error_of_kind(
+                DecodingErrorKind::InvalidUtf8,
+                "found string with invalid UTF-8 data",
+            )
+        })?;
         Ok(StrRef::from(text))
     }
 
@@ -780,15 +803,25 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
     fn read_symbol_id(&'top self) -> IonResult<SymbolId> {
         let biases: [usize; 3] = [0, 256, 65792];
         let length_code = self.encoded_value.header.low_nibble;
-        if (1..=3).contains(&length_code) {
-            let (id, _) = self
-                .value_body_buffer()
-                .read_fixed_uint(length_code.into())?;
-            let id = usize::try_from(id.value())?;
-            Ok(id + biases[(length_code - 1) as usize])
-        } else {
-            unreachable!("invalid length code for symbol ID");
-        }
+        let id = match length_code {
+            1 | 2 => {
+                let (id, _) = self
+                    .value_body_buffer()
+                    .read_fixed_uint(length_code.into())?;
+                usize::try_from(id.value())?
+            }
+            // Opcode 0xE3 is followed by a FlexUInt instead of a FixedUInt.
+            3 => {
+                let (id, _) = self.value_body_buffer().read_flex_uint()?;
+                id.value() as usize
+            }
+            _ => {
+                return IonResult::decoding_error(format!(
+                    "invalid length code for symbol ID: {length_code}"
+                ))
+            }
+        };
+        Ok(id + biases[(length_code - 1) as usize])
     }
 
     /// Helper method called by [`Self::read`]. Reads the current value as a symbol.
@@ -797,8 +830,12 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         let type_code = self.encoded_value.header.ion_type_code;
         if type_code == OpcodeType::InlineSymbol {
             let raw_bytes = self.value_body();
-            let text = std::str::from_utf8(raw_bytes)
-                .map_err(|_| IonError::decoding_error("found symbol with invalid UTF-8 data"))?;
+            let text = std::str::from_utf8(raw_bytes).map_err(|_| {
+                IonError::decoding_error_of_kind(
+                    DecodingErrorKind::InvalidUtf8,
+                    "found symbol with invalid UTF-8 data",
+                )
+            })?;
             Ok(RawSymbolRef::from(text))
         } else if type_code == OpcodeType::SymbolAddress {
             let symbol_id = self.read_symbol_id()?;
@@ -876,3 +913,20 @@ impl<'top> EncodedBinaryValue<'top, BinaryEncoding_1_1> for &'top LazyRawBinaryV
         Span::with_offset(self.annotations_span().range().start + 1, &[])
     }
 }
+
+/// Converts the bits of an IEEE-754 half-precision (binary16) float to an `f64`.
+fn f16_bits_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1F) as i32;
+    let fraction = (bits & 0x03FF) as f64;
+    let magnitude = match exponent {
+        // Subnormal numbers and zero
+        0 => fraction * 2f64.powi(-24),
+        // Infinity and NaN
+        0x1F if fraction == 0.0 => f64::INFINITY,
+        0x1F => f64::NAN,
+        // Normal numbers
+        _ => (1.0 + fraction / 1024.0) * 2f64.powi(exponent - 15),
+    };
+    sign * magnitude
+}
@@ -4,8 +4,11 @@ use std::fmt::{Debug, Formatter};
 use std::ops::Range;
 use std::{fmt, mem};
 
+use num_traits::Zero;
+
 use crate::binary::int::DecodedInt;
 use crate::binary::uint::DecodedUInt;
+use crate::decimal::coefficient::Coefficient;
 use crate::lazy::binary::encoded_value::EncodedValue;
 use crate::lazy::binary::immutable_buffer::ImmutableBuffer;
 use crate::lazy::binary::raw::annotations_iterator::RawBinaryAnnotationsIterator;
@@ -19,7 +22,7 @@ use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::span::Span;
 use crate::lazy::str_ref::StrRef;
-use crate::result::IonFailure;
+use crate::result::{DecodingError, DecodingErrorKind, IonFailure};
 use crate::types::SymbolId;
 use crate::{
     Decimal, Decoder, Int, IonEncoding, IonError, IonResult, IonType, RawSymbolRef, Timestamp,
@@ -127,6 +130,11 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_0> for LazyRawBinaryValue_1_0<'to
         self.read()
     }
 
+    fn float_size(&self) -> Option<usize> {
+        (self.ion_type() == IonType::Float && !self.is_null())
+            .then(|| self.encoded_value.value_body_length())
+    }
+
     fn annotations_span(&self) -> Span<'top> {
         let Some(range) = self.encoded_value.annotations_range() else {
             // If there are no annotations, return an empty slice positioned at the opcode
@@ -382,6 +390,21 @@ impl<'a, 'top> EncodedBinaryValueData_1_0<'a, 'top> {
         Span::with_offset(stream_range.start, bytes)
     }
 
+    /// Returns the input stream index range that contains the bytes representing the value's
+    /// header; that is, its opcode and (if present) its trailing length.
+    pub fn header_range(&self) -> Range<usize> {
+        let range = self.range();
+        range.start..range.start + self.value.encoded_value.header_length()
+    }
+
+    /// Returns the encoded bytes representing the value's header; that is, its opcode and (if
+    /// present) its trailing length.
+    pub fn header_span(&self) -> Span<'top> {
+        let header_range = self.header_range();
+        let bytes = &self.span().bytes()[..header_range.len()];
+        Span::with_offset(header_range.start, bytes)
+    }
+
     /// Returns the input stream index range that contains the bytes representing the
     /// value's body (that is: the content of the value that follows its opcode and length).
     pub fn body_range(&self) -> Range<usize> {
@@ -495,6 +518,16 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         self.input.bytes_range(value_offset, value_body_length)
     }
 
+    /// Returns an [`ImmutableBuffer`] containing this value's body. Unlike wrapping
+    /// [`value_body`](Self::value_body) in a new buffer, the resulting buffer retains the body's
+    /// offset in the input so that errors raised while reading it report the correct position.
+    fn value_body_buffer(&self) -> ImmutableBuffer<'top> {
+        let value_total_length = self.encoded_value.total_length();
+        let value_body_length = self.encoded_value.value_body_length();
+        let value_offset = value_total_length - value_body_length;
+        self.input.slice(value_offset, value_body_length)
+    }
+
     /// Returns an [`ImmutableBuffer`] containing whatever bytes of this value's body are currently
     /// available. This method is used to construct lazy containers, which are not required to be
     /// fully buffered before reading begins.
@@ -529,21 +562,20 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
     fn read_int(&self) -> ValueParseResult<'top, BinaryEncoding_1_0> {
         debug_assert!(self.encoded_value.ion_type() == IonType::Int);
         // `value_body()` returns a buffer starting at the body of the value.
-        let uint_bytes = self.value_body();
-        let magnitude: Int = DecodedUInt::uint_from_slice(uint_bytes)?.try_into()?;
+        let magnitude_bytes = self.value_body();
 
         use crate::binary::type_code::IonTypeCode::*;
-        use num_traits::Zero;
-        let value = match (self.encoded_value.header.ion_type_code, magnitude) {
-            (PositiveInteger, integer) => integer,
-            (NegativeInteger, integer) if integer.is_zero() => {
-                return IonResult::decoding_error(
-                    "found a negative integer (typecode=3) with a value of 0",
-                );
-            }
-            (NegativeInteger, integer) => -integer,
+        let is_negative = match self.encoded_value.header.ion_type_code {
+            PositiveInteger => false,
+            NegativeInteger => true,
             _itc => return IonResult::decoding_error("unexpected ion type code"),
         };
+        let value = Int::from_sign_and_magnitude_be_bytes(is_negative, magnitude_bytes);
+        if is_negative && value.is_zero() {
+            return IonResult::decoding_error(
+                "found a negative integer (typecode=3) with a value of 0",
+            );
+        }
         Ok(RawValueRef::Int(value))
     }
 
@@ -572,7 +604,7 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         }
 
         // Skip the type descriptor and length bytes
-        let input = ImmutableBuffer::new(self.value_body());
+        let input = self.value_body_buffer();
 
         let (exponent_var_int, remaining) = input.read_var_int()?;
         let coefficient_size_in_bytes =
@@ -587,80 +619,87 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
             )));
         }
 
-        Ok(RawValueRef::Decimal(Decimal::new(coefficient, exponent)))
+        Ok(RawValueRef::Decimal(Decimal::new(
+            Coefficient::try_from(coefficient)?,
+            exponent,
+        )))
     }
 
     /// Helper method called by [`Self::read`]. Reads the current value as a timestamp.
+    ///
+    /// Each component is range checked as it is read so that garbage input produces a decoding
+    /// error rather than a `Timestamp` that violates its own invariants.
     fn read_timestamp(&self) -> ValueParseResult<'top, BinaryEncoding_1_0> {
         debug_assert!(self.encoded_value.ion_type() == IonType::Timestamp);
 
-        let input = ImmutableBuffer::new(self.value_body());
+        let input = self.value_body_buffer();
 
         let (offset, input) = input.read_var_int()?;
         let is_known_offset = !offset.is_negative_zero();
-        let offset_minutes = offset.value() as i32;
+        let offset_minutes = if is_known_offset {
+            self.timestamp_component("offset", offset.value())?
+        } else {
+            0
+        };
         let (year_var_uint, input) = input.read_var_uint()?;
-        let year = year_var_uint.value() as u32;
+        let year = self.timestamp_component("year", year_var_uint.value())?;
 
         // Year precision
 
         let builder = Timestamp::with_year(year);
         if input.is_empty() {
-            let timestamp = builder.build()?;
-            return Ok(RawValueRef::Timestamp(timestamp));
+            return self.build_timestamp(builder.build());
         }
 
         // Month precision
 
         let (month_var_uint, input) = input.read_var_uint()?;
-        let month = month_var_uint.value() as u32;
+        let month = self.timestamp_component("month", month_var_uint.value())?;
         let builder = builder.with_month(month);
         if input.is_empty() {
-            let timestamp = builder.build()?;
-            return Ok(RawValueRef::Timestamp(timestamp));
+            return self.build_timestamp(builder.build());
         }
 
         // Day precision
 
         let (day_var_uint, input) = input.read_var_uint()?;
-        let day = day_var_uint.value() as u32;
+        let day = self.timestamp_component("day", day_var_uint.value())?;
         let builder = builder.with_day(day);
         if input.is_empty() {
-            let timestamp = builder.build()?;
-            return Ok(RawValueRef::Timestamp(timestamp));
+            return self.build_timestamp(builder.build());
         }
 
         // Hour-and-minute precision
 
         let (hour_var_uint, input) = input.read_var_uint()?;
-        let hour = hour_var_uint.value() as u32;
+        let hour = self.timestamp_component("hour", hour_var_uint.value())?;
         if input.is_empty() {
-            return IonResult::decoding_error("timestamps with an hour must also specify a minute");
+            return Err(self.timestamp_error("timestamps with an hour must also specify a minute"));
         }
         let (minute_var_uint, input) = input.read_var_uint()?;
-        let minute = minute_var_uint.value() as u32;
+        let minute = self.timestamp_component("minute", minute_var_uint.value())?;
         let builder = builder.with_hour_and_minute(hour, minute);
         if input.is_empty() {
             let timestamp = if is_known_offset {
                 builder.build_utc_fields_at_offset(offset_minutes)
             } else {
                 builder.build()
-            }?;
-            return Ok(RawValueRef::Timestamp(timestamp));
+            };
+            return self.build_timestamp(timestamp);
         }
 
         // Second precision
 
         let (second_var_uint, input) = input.read_var_uint()?;
-        let second = second_var_uint.value() as u32;
+        let second = self.timestamp_component("second", second_var_uint.value())?;
         let builder = builder.with_second(second);
         if input.is_empty() {
             let timestamp = if is_known_offset {
                 builder.build_utc_fields_at_offset(offset_minutes)
             } else {
                 builder.build()
-            }?;
-            return Ok(RawValueRef::Timestamp(timestamp));
+            };
+            return self.build_timestamp(timestamp);
         }
 
         // Fractional second precision
@@ -668,22 +707,71 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         let (subsecond_exponent_var_uint, input) = input.read_var_int()?;
         let subsecond_exponent = subsecond_exponent_var_uint.value();
         // The remaining bytes represent the coefficient.
-        let coefficient_size_in_bytes = self.encoded_value.value_body_length() - input.offset();
+        let coefficient_size_in_bytes = input.len();
         let (subsecond_coefficient, _input) = if coefficient_size_in_bytes == 0 {
             (DecodedInt::zero(), input)
         } else {
             input.read_int(coefficient_size_in_bytes)?
         };
 
-        let builder = builder
-            .with_fractional_seconds(Decimal::new(subsecond_coefficient, subsecond_exponent));
+        let fractional_seconds = Decimal::new(
+            Coefficient::try_from(subsecond_coefficient)?,
+            subsecond_exponent,
+        );
+        if fractional_seconds.is_less_than_zero()
+            || fractional_seconds.is_greater_than_or_equal_to_one()
+        {
+            return Err(self.timestamp_error(format!(
+                "fractional seconds ({fractional_seconds}) are outside of the range [0, 1)"
+            )));
+        }
+        let builder = builder.with_fractional_seconds(fractional_seconds);
         let timestamp = if is_known_offset {
             builder.build_utc_fields_at_offset(offset_minutes)
         } else {
             builder.build()
-        }?;
+        };
+        self.build_timestamp(timestamp)
+    }
+
+    /// Converts a timestamp `component` to the type expected by the [`TimestampBuilder`](crate::TimestampBuilder),
+    /// returning a decoding error if it cannot be represented. The builder is responsible for
+    /// confirming that each component is within its allowed range so that the text and binary
+    /// readers apply the same rules.
+    fn timestamp_component<I, O>(&self, component: &str, value: I) -> IonResult<O>
+    where
+        I: Copy + fmt::Display + TryInto<O>,
+    {
+        value.try_into().map_err(|_| {
+            self.timestamp_error(format!(
+                "timestamp {component} ({value}) is outside of the supported range"
+            ))
+        })
+    }
+
+    /// Converts the result of building a [`Timestamp`] into a value, reporting any failure as a
+    /// decoding error.
+    fn build_timestamp(
+        &self,
+        timestamp: IonResult<Timestamp>,
+    ) -> ValueParseResult<'top, BinaryEncoding_1_0> {
+        match timestamp {
+            Ok(timestamp) => Ok(RawValueRef::Timestamp(timestamp)),
+            Err(IonError::IllegalOperation(error)) => {
+                Err(self.timestamp_error(error.operation().to_string()))
+            }
+            Err(error) => Err(error),
+        }
+    }
 
-        Ok(RawValueRef::Timestamp(timestamp))
+    /// Constructs a decoding error that includes the offset of the timestamp being read.
+    fn timestamp_error(&self, description: impl Into<String>) -> IonError {
+        DecodingError::new(format!(
+            "found an invalid binary timestamp: {}",
+            description.into()
+        ))
+        .with_position(self.encoded_value.header_offset())
+        .into()
     }
 
     /// Helper method called by [`Self::read_symbol`]. Reads the current value as a symbol ID.
@@ -712,8 +800,11 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
     fn read_string(&self) -> ValueParseResult<'top, BinaryEncoding_1_0> {
         debug_assert!(self.encoded_value.ion_type() == IonType::String);
         let raw_bytes = self.value_body();
-        let text = std::str::from_utf8(raw_bytes)
-            .map_err(|_| IonError::decoding_error("found a string with invalid utf-8 data"))?;
+        let text = std::str::from_utf8(raw_bytes).map_err(|_| {
+            DecodingError::new("found a string with invalid utf-8 data")
+                .with_kind(DecodingErrorKind::InvalidUtf8)
+                .with_position(self.encoded_value.header_offset())
+        })?;
         Ok(RawValueRef::String(StrRef::from(text)))
     }
 
@@ -773,9 +864,12 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
 
 #[cfg(test)]
 mod tests {
+    use chrono::{DateTime, FixedOffset};
+    use rstest::rstest;
+
     use crate::lazy::binary::raw::reader::LazyRawBinaryReader_1_0;
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::IonResult;
+    use crate::{Decimal, IonError, IonResult, Timestamp};
 
     #[test]
     fn annotations_sequence() -> IonResult<()> {
@@ -794,4 +888,93 @@ mod tests {
         assert_eq!(annotations_sequence.bytes()[0], 0x83u8); // 0x83 == $3 == $ion_symbol_table
         Ok(())
     }
+
+    // Reads a stream containing a single timestamp whose body is `body`.
+    fn read_timestamp(body: &[u8]) -> IonResult<Timestamp> {
+        let mut data = vec![0xE0, 0x01, 0x00, 0xEA];
+        if body.len() < 14 {
+            data.push(0x60 | body.len() as u8);
+        } else {
+            data.extend([0x6E, 0x80 | body.len() as u8]);
+        }
+        data.extend_from_slice(body);
+        let mut reader = LazyRawBinaryReader_1_0::new(&data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        let value = reader.next()?.expect_value()?;
+        value.read()?.expect_timestamp()
+    }
+
+    #[rstest]
+    #[case::year_zero(&[0x80, 0x80], "year (0)")]
+    #[case::year_10000(&[0x80, 0x4E, 0x90], "year (10000)")]
+    #[case::month_zero(&[0x80, 0x0F, 0xD0, 0x80], "month (0)")]
+    #[case::month_13(&[0x80, 0x0F, 0xD0, 0x8D], "month (13)")]
+    #[case::month_200(&[0x80, 0x0F, 0xD0, 0x01, 0xC8], "month (200)")]
+    #[case::day_zero(&[0x80, 0x0F, 0xD0, 0x81, 0x80], "day (0)")]
+    #[case::day_32(&[0x80, 0x0F, 0xD0, 0x81, 0xA0], "day (32)")]
+    #[case::february_30(&[0x80, 0x0F, 0xD0, 0x82, 0x9E], "day 30 does not exist in month 2")]
+    #[case::february_29_non_leap(&[0x80, 0x0F, 0xD1, 0x82, 0x9D], "day 29 does not exist in month 2")]
+    #[case::hour_24(&[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x98, 0x80], "hour (24)")]
+    #[case::missing_minute(&[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80], "must also specify a minute")]
+    #[case::minute_60(&[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0xBC], "minute (60)")]
+    #[case::second_60(&[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0xBC], "second (60)")]
+    #[case::offset_1440(&[0x0B, 0xA0, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80], "offset (1440)")]
+    #[case::offset_negative_100000(&[0x46, 0x0D, 0xA0, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80], "offset (-100000)")]
+    #[case::fraction_one(&[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0x80, 0x01], "fractional seconds (1.)")]
+    #[case::fraction_ten_tenths(&[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0xC1, 0x0A], "fractional seconds (1.0)")]
+    #[case::fraction_negative(&[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0xC1, 0x81], "fractional seconds (-0.1)")]
+    fn invalid_binary_timestamp(#[case] body: &[u8], #[case] expected_message: &str) {
+        let error = read_timestamp(body).expect_err("invalid timestamp was accepted");
+        let IonError::Decoding(ref decoding_error) = error else {
+            panic!("expected a decoding error, found {error:?}");
+        };
+        assert!(
+            error.to_string().contains(expected_message),
+            "'{error}' did not contain '{expected_message}'"
+        );
+        // The timestamp's type descriptor follows the 4-byte IVM.
+        assert_eq!(decoding_error.position().map(|p| p.byte_offset()), Some(4));
+    }
+
+    #[rstest]
+    #[case::year_one(&[0xC0, 0x81], Timestamp::with_year(1).build())]
+    #[case::year_9999(&[0xC0, 0x4E, 0x8F], Timestamp::with_year(9999).build())]
+    #[case::leap_day(&[0xC0, 0x0F, 0xD0, 0x82, 0x9D], Timestamp::with_ymd(2000, 2, 29).build())]
+    #[case::minimum_offset(
+        &[0x4B, 0x9F, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80],
+        Timestamp::with_ymd(1999, 12, 31).with_hour_and_minute(0, 1).with_offset(-1439).build()
+    )]
+    #[case::maximum_offset(
+        &[0x0B, 0x9F, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80],
+        Timestamp::with_ymd(2000, 1, 1).with_hour_and_minute(23, 59).with_offset(1439).build()
+    )]
+    #[case::end_of_second(
+        &[0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0xC9, 0x3B, 0x9A, 0xC9, 0xFF],
+        Timestamp::with_ymd(2000, 1, 1)
+            .with_hour_and_minute(0, 0)
+            .with_second(0)
+            .with_fractional_seconds(Decimal::new(999_999_999, -9))
+            .with_offset(0)
+            .build()
+    )]
+    fn valid_binary_timestamp_boundaries(
+        #[case] body: &[u8],
+        #[case] expected: IonResult<Timestamp>,
+    ) -> IonResult<()> {
+        assert_eq!(read_timestamp(body)?, expected?);
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_with_very_precise_fractional_seconds() -> IonResult<()> {
+        // A coefficient of 1_000_000_000 with an exponent of -2_147_483_657
+        let timestamp = read_timestamp(&[
+            0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0x48, 0x00, 0x00, 0x00, 0x89, 0x3B,
+            0x9A, 0xCA, 0x00,
+        ])?;
+        // Converting the fractional seconds to nanoseconds must not overflow.
+        let datetime: DateTime<FixedOffset> = timestamp.try_into()?;
+        assert_eq!(datetime.timestamp_subsec_nanos(), 0);
+        Ok(())
+    }
 }
@@ -25,8 +25,8 @@ impl<'data> BytesRef<'data> {
         Bytes::from(self)
     }
 
-    pub fn data(&self) -> &[u8] {
-        self.as_ref()
+    pub fn data(&self) -> &'data [u8] {
+        self.data
     }
 }
 
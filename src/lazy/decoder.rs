@@ -15,7 +15,7 @@ use crate::lazy::raw_stream_item::LazyRawStreamItem;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::span::Span;
 use crate::lazy::streaming_raw_reader::RawReaderState;
-use crate::read_config::ReadConfig;
+use crate::read_config::{ReadConfig, UnresolvedSymbolIdPolicy};
 use crate::result::IonFailure;
 use crate::{
     v1_0, v1_1, Catalog, Encoding, IonResult, IonType, LazyExpandedFieldName, LazyRawWriter,
@@ -77,6 +77,13 @@ pub trait Decoder: 'static + Sized + Debug + Clone + Copy {
     fn with_catalog(self, catalog: impl Catalog + 'static) -> ReadConfig<Self> {
         ReadConfig::new_with_catalog(self, catalog)
     }
+
+    fn with_unresolved_symbol_id_policy(
+        self,
+        policy: UnresolvedSymbolIdPolicy,
+    ) -> ReadConfig<Self> {
+        ReadConfig::new(self).with_unresolved_symbol_id_policy(policy)
+    }
 }
 
 pub trait RawVersionMarker<'top>: Debug + Copy + Clone + HasSpan<'top> {
@@ -575,7 +582,23 @@ pub trait LazyRawValue<'top, D: Decoder>:
     fn annotations(&self) -> D::AnnotationsIterator<'top>;
     fn read(&self) -> IonResult<RawValueRef<'top, D>>;
     fn read_resolved(&self, context: EncodingContextRef<'top>) -> IonResult<ValueRef<'top, D>> {
-        self.read()?.resolve(context)
+        match self.read()? {
+            // Symbols are resolved here so that an undefined symbol ID can be reported at this
+            // value's offset.
+            RawValueRef::Symbol(symbol) => {
+                let offset = self.value_span().range().start;
+                Ok(ValueRef::Symbol(symbol.resolve_at(context, offset)?))
+            }
+            raw_value_ref => raw_value_ref.resolve(context),
+        }
+    }
+
+    /// If this value is a non-null float, returns the number of bytes its encoding used to
+    /// represent the IEEE-754 value. Binary encodings can use 8, 4, or (in Ion 1.1) 2 bytes, and
+    /// may represent positive zero using 0 bytes. Text floats are always reported as 64-bit (8
+    /// bytes). If this value is not a float or is null, returns `None`.
+    fn float_size(&self) -> Option<usize> {
+        (self.ion_type() == IonType::Float && !self.is_null()).then_some(8)
     }
 
     fn annotations_span(&self) -> Span<'top>;
@@ -1,6 +1,7 @@
 use smallvec::SmallVec;
 
-use crate::{RawSymbolRef, SymbolId};
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::{Annotations, RawSymbolRef, SymbolId, SymbolRef};
 
 /// A sequence of annotations.
 ///
@@ -12,6 +13,12 @@ pub type AnnotationsVec<'a> = SmallVec<[RawSymbolRef<'a>; 2]>;
 /// Examples include `SymbolId`, `&str`, and iterables of those types.
 pub trait AnnotationSeq<'a> {
     fn into_annotations_vec(self) -> AnnotationsVec<'a>;
+
+    /// Returns the result of [`AsRawSymbolRef::unknown_text_sid`] for each annotation in the
+    /// sequence, in order. An empty sequence means that none of the annotations have unknown text.
+    fn unknown_text_sids(&self) -> SmallVec<[Option<SymbolId>; 2]> {
+        SmallVec::new()
+    }
 }
 
 impl<'a> AnnotationSeq<'a> for &'a str {
@@ -112,3 +119,23 @@ where
         annotations
     }
 }
+
+impl<'a> AnnotationSeq<'a> for &'a Annotations {
+    fn into_annotations_vec(self) -> AnnotationsVec<'a> {
+        self.iter().map(RawSymbolRef::from).collect()
+    }
+
+    fn unknown_text_sids(&self) -> SmallVec<[Option<SymbolId>; 2]> {
+        self.iter().map(AsRawSymbolRef::unknown_text_sid).collect()
+    }
+}
+
+impl<'a> AnnotationSeq<'a> for SmallVec<[SymbolRef<'a>; 2]> {
+    fn into_annotations_vec(self) -> AnnotationsVec<'a> {
+        self.into_iter().map(RawSymbolRef::from).collect()
+    }
+
+    fn unknown_text_sids(&self) -> SmallVec<[Option<SymbolId>; 2]> {
+        self.iter().map(AsRawSymbolRef::unknown_text_sid).collect()
+    }
+}
@@ -0,0 +1,447 @@
+//! Computes the number of bytes a value will occupy in a binary Ion 1.0 stream without encoding it.
+
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use crate::binary::decimal::DecimalBinaryEncoder;
+use crate::binary::timestamp::TimestampBinaryEncoder;
+use crate::binary::uint;
+use crate::binary::var_uint::VarUInt;
+use crate::lazy::encoder::binary::v1_0::value_writer::MAX_INLINE_LENGTH;
+use crate::lazy::encoding::BinaryEncoding_1_0;
+use crate::result::EncodingError;
+use crate::{Element, IonError, Sequence, Struct, Symbol, SymbolId, Value};
+
+/// Returned by [`BinaryEncoding_1_0::encoded_len_of`] when one or more of the symbol tokens in the
+/// value being measured had text for which the provided resolver could not supply a symbol ID.
+///
+/// In binary Ion 1.0, every symbol value, field name, and annotation is encoded as a symbol ID.
+/// Because the size of that encoding depends on the ID, a length cannot be computed for text that
+/// has not yet been added to the symbol table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnresolvedSymbolsError {
+    symbols: Vec<String>,
+}
+
+impl UnresolvedSymbolsError {
+    /// Returns the text of each symbol that could not be resolved, in the order in which it was
+    /// first encountered. Each text value appears once regardless of how many times it occurred.
+    pub fn symbols(&self) -> &[String] {
+        self.symbols.as_slice()
+    }
+}
+
+impl Display for UnresolvedSymbolsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no symbol ID was available for the text: ")?;
+        for (index, text) in self.symbols.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "'{text}'")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnresolvedSymbolsError {}
+
+impl From<UnresolvedSymbolsError> for IonError {
+    fn from(error: UnresolvedSymbolsError) -> Self {
+        EncodingError::new(error.to_string()).into()
+    }
+}
+
+impl BinaryEncoding_1_0 {
+    /// Returns the exact number of bytes that `element` will occupy when it is written to a binary
+    /// Ion 1.0 stream, without encoding it.
+    ///
+    /// `resolve_symbol` is used to look up the symbol ID of each symbol value, field name, and
+    /// annotation that has text. Symbols with unknown text are encoded as `$0`. The returned length
+    /// does not include the Ion version marker or any symbol table that would precede the value.
+    ///
+    /// If any symbol text cannot be resolved, returns an [`UnresolvedSymbolsError`] listing all of
+    /// the unresolved text.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Element};
+    ///
+    /// let element = Element::read_one(r#"foo::{name: "Bob", age: 31}"#)?;
+    /// // Resolve each symbol using a fixed mapping of text to symbol ID
+    /// let resolve = |text: &str| match text {
+    ///     "foo" => Some(10),
+    ///     "name" => Some(4),
+    ///     "age" => Some(11),
+    ///     _ => None,
+    /// };
+    /// assert_eq!(v1_0::Binary::encoded_len_of(&element, resolve)?, 12);
+    ///
+    /// // Text that cannot be resolved is reported back to the caller.
+    /// let error = v1_0::Binary::encoded_len_of(&element, |_text| None).unwrap_err();
+    /// assert_eq!(error.symbols(), &["foo", "name", "age"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encoded_len_of<F>(
+        element: &Element,
+        resolve_symbol: F,
+    ) -> Result<usize, UnresolvedSymbolsError>
+    where
+        F: Fn(&str) -> Option<SymbolId>,
+    {
+        let mut calculator = EncodedLengthCalculator {
+            resolve_symbol,
+            unresolved: Vec::new(),
+        };
+        let length = calculator.element_length(element);
+        if calculator.unresolved.is_empty() {
+            Ok(length)
+        } else {
+            Err(UnresolvedSymbolsError {
+                symbols: calculator.unresolved,
+            })
+        }
+    }
+}
+
+/// Mirrors the length decisions made by
+/// [`BinaryValueWriter_1_0`](crate::lazy::encoder::binary::v1_0::value_writer::BinaryValueWriter_1_0)
+/// and its container writers.
+struct EncodedLengthCalculator<F> {
+    resolve_symbol: F,
+    // Text that could not be resolved. Once this is non-empty, the computed length is discarded.
+    unresolved: Vec<String>,
+}
+
+impl<F> EncodedLengthCalculator<F>
+where
+    F: Fn(&str) -> Option<SymbolId>,
+{
+    /// Returns the size of a type descriptor (and its trailing length, if any) for a value whose
+    /// body is `body_length` bytes long.
+    fn header_length(body_length: usize) -> usize {
+        match body_length {
+            0..=MAX_INLINE_LENGTH => 1,
+            length => 1 + VarUInt::encoded_size_of(length as u64),
+        }
+    }
+
+    fn symbol_id(&mut self, symbol: &Symbol) -> SymbolId {
+        let Some(text) = symbol.text() else {
+            // Symbols with unknown text are written as `$0`.
+            return 0;
+        };
+        match (self.resolve_symbol)(text) {
+            Some(sid) => sid,
+            None => {
+                if !self.unresolved.iter().any(|t| t == text) {
+                    self.unresolved.push(text.to_owned());
+                }
+                // The actual ID is unknown, but we keep going to find any other unresolved text.
+                0
+            }
+        }
+    }
+
+    fn element_length(&mut self, element: &Element) -> usize {
+        if element.annotations().is_empty() {
+            return self.value_length(element.value());
+        }
+
+        let annotations_sequence_length: usize = element
+            .annotations()
+            .iter()
+            .map(|annotation| VarUInt::encoded_size_of(self.symbol_id(annotation) as u64))
+            .sum();
+        let value_length = self.value_length(element.value());
+        let envelope_length = VarUInt::encoded_size_of(annotations_sequence_length as u64)
+            + annotations_sequence_length
+            + value_length;
+        Self::header_length(envelope_length) + envelope_length
+    }
+
+    fn value_length(&mut self, value: &Value) -> usize {
+        use Value::*;
+        match value {
+            Null(_) | Bool(_) => 1,
+            Int(i) => {
+                let magnitude_length = uint::encode_uint(&i.unsigned_abs()).as_bytes().len();
+                Self::header_length(magnitude_length) + magnitude_length
+            }
+            Float(f) => {
+                if *f == 0f64 && !f.is_sign_negative() {
+                    1
+                } else if (*f as f32) as f64 == *f {
+                    // The writer will losslessly encode this value as an f32.
+                    5
+                } else {
+                    9
+                }
+            }
+            // Decimals and timestamps are encoded to a stack-allocated buffer and then discarded.
+            // Their largest possible encodings fit comfortably in that buffer, so this cannot fail.
+            Decimal(d) => io::sink()
+                .encode_decimal_value(d)
+                .expect("decimal encoding does not fail when writing to a sink"),
+            Timestamp(t) => io::sink()
+                .encode_timestamp_value(t)
+                .expect("timestamp encoding does not fail when writing to a sink"),
+            Symbol(s) => {
+                let sid_length = uint::encode(self.symbol_id(s) as u64).as_bytes().len();
+                Self::header_length(sid_length) + sid_length
+            }
+            String(s) => Self::header_length(s.text().len()) + s.text().len(),
+            Clob(bytes) | Blob(bytes) => {
+                Self::header_length(bytes.as_ref().len()) + bytes.as_ref().len()
+            }
+            List(sequence) | SExp(sequence) => {
+                let body_length = self.sequence_body_length(sequence);
+                Self::header_length(body_length) + body_length
+            }
+            Struct(strukt) => {
+                let body_length = self.struct_body_length(strukt);
+                Self::header_length(body_length) + body_length
+            }
+        }
+    }
+
+    fn sequence_body_length(&mut self, sequence: &Sequence) -> usize {
+        sequence
+            .elements()
+            .map(|child| self.element_length(child))
+            .sum()
+    }
+
+    fn struct_body_length(&mut self, strukt: &Struct) -> usize {
+        strukt
+            .fields()
+            .map(|(name, value)| {
+                VarUInt::encoded_size_of(self.symbol_id(name) as u64) + self.element_length(value)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+    use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
+    use crate::lazy::encoder::write_as_ion::WriteAsIon;
+    use crate::lazy::encoding::BinaryEncoding_1_0;
+    use crate::{Element, IonResult, Symbol, SymbolId, SymbolTable, Value};
+
+    /// Adds `symbol`'s text (if any) to `symbol_table`.
+    fn intern(symbol: &Symbol, symbol_table: &mut SymbolTable) {
+        if let Some(text) = symbol.text() {
+            symbol_table.intern(text);
+        }
+    }
+
+    /// Adds all of the symbol text in `element` to `symbol_table`.
+    fn intern_all(element: &Element, symbol_table: &mut SymbolTable) {
+        for annotation in element.annotations() {
+            intern(annotation, symbol_table);
+        }
+        match element.value() {
+            Value::Symbol(symbol) => intern(symbol, symbol_table),
+            Value::List(sequence) | Value::SExp(sequence) => sequence
+                .elements()
+                .for_each(|child| intern_all(child, symbol_table)),
+            Value::Struct(strukt) => {
+                for (name, value) in strukt.fields() {
+                    intern(name, symbol_table);
+                    intern_all(value, symbol_table);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn sid(symbol: &Symbol, symbol_table: &SymbolTable) -> SymbolId {
+        symbol
+            .text()
+            .map(|text| symbol_table.sid_for(&text).unwrap())
+            .unwrap_or(0)
+    }
+
+    /// Writes `element` to `writer` using the raw binary writer, replacing each symbol's text
+    /// with its symbol ID from `symbol_table`.
+    fn write_with_sids<V: ValueWriter>(
+        element: &Element,
+        symbol_table: &SymbolTable,
+        writer: V,
+    ) -> IonResult<()> {
+        if element.annotations().is_empty() {
+            return write_value_with_sids(element.value(), symbol_table, writer);
+        }
+        let annotations: Vec<SymbolId> = element
+            .annotations()
+            .iter()
+            .map(|annotation| sid(annotation, symbol_table))
+            .collect();
+        write_value_with_sids(
+            element.value(),
+            symbol_table,
+            writer.with_annotations(annotations)?,
+        )
+    }
+
+    fn write_value_with_sids<V: ValueWriter>(
+        value: &Value,
+        symbol_table: &SymbolTable,
+        writer: V,
+    ) -> IonResult<()> {
+        match value {
+            Value::Symbol(symbol) => writer.write_symbol(sid(symbol, symbol_table)),
+            Value::List(sequence) => {
+                let mut list = writer.list_writer()?;
+                for child in sequence.elements() {
+                    write_with_sids(child, symbol_table, list.value_writer())?;
+                }
+                list.close()
+            }
+            Value::SExp(sequence) => {
+                let mut sexp = writer.sexp_writer()?;
+                for child in sequence.elements() {
+                    write_with_sids(child, symbol_table, sexp.value_writer())?;
+                }
+                sexp.close()
+            }
+            Value::Struct(strukt) => {
+                let mut struct_writer = writer.struct_writer()?;
+                for (name, value) in strukt.fields() {
+                    let field_writer = struct_writer.field_writer(sid(name, symbol_table));
+                    write_with_sids(value, symbol_table, field_writer)?;
+                }
+                struct_writer.close()
+            }
+            other => other.write_as_ion(writer),
+        }
+    }
+
+    fn long_text(length: usize) -> String {
+        "a".repeat(length)
+    }
+
+    #[rstest]
+    #[case::null("null.struct")]
+    #[case::bools("true")]
+    #[case::zero("0")]
+    #[case::negative_int("-1")]
+    #[case::large_int("170141183460469231731687303715884105727")]
+    #[case::positive_zero_float("0e0")]
+    #[case::negative_zero_float("-0e0")]
+    #[case::f32_float("1.5e0")]
+    #[case::f64_float("1.1e0")]
+    #[case::nan("nan")]
+    #[case::decimal_zero("0d0")]
+    #[case::decimal_negative_zero("-0d0")]
+    #[case::decimal("-123.456")]
+    #[case::timestamp_year("2024T")]
+    #[case::timestamp_fractional("2024-06-13T10:30:45.123456789-07:00")]
+    #[case::timestamp_unknown_offset("2024-06-13T10:30-00:00")]
+    #[case::symbol("foo")]
+    #[case::unknown_symbol("$0")]
+    #[case::system_symbol("name")]
+    #[case::string("\"hello\"")]
+    #[case::clob("{{\"hello\"}}")]
+    #[case::blob("{{aGVsbG8=}}")]
+    #[case::empty_containers("[]")]
+    #[case::empty_sexp("()")]
+    #[case::empty_struct("{}")]
+    #[case::list("[1, 2.5, foo, \"bar\"]")]
+    #[case::sexp("(+ 1 2)")]
+    #[case::struct_("{a: 1, b: foo, c: [1, 2], a: 2}")]
+    #[case::annotated_scalar("foo::bar::1")]
+    #[case::annotated_containers("foo::[bar::{quux: baz::qux::()}]")]
+    #[case::unknown_text_annotation("$0::5")]
+    #[case::nested("[[[[[[[[[[[[[[[[{a: [(b), {c: d::e}]}]]]]]]]]]]]]]]]]")]
+    fn encoded_len_matches_raw_writer(#[case] ion: &str) -> IonResult<()> {
+        let element = Element::read_one(ion)?;
+        assert_matches_raw_writer(&element, &SymbolTable::default())
+    }
+
+    #[rstest]
+    // String/clob/blob lengths on either side of the inline length limit
+    #[case::length_13(13)]
+    #[case::length_14(14)]
+    // Lengths on either side of the 1- and 2-byte VarUInt boundaries
+    #[case::length_127(127)]
+    #[case::length_128(128)]
+    #[case::length_16383(16383)]
+    #[case::length_16384(16384)]
+    fn encoded_len_matches_raw_writer_at_length_boundaries(#[case] length: usize) -> IonResult<()> {
+        let symbol_table = SymbolTable::default();
+        let text = long_text(length);
+        let bytes = text.as_bytes();
+        let elements = [
+            Element::string(text.as_str()),
+            Element::clob(bytes),
+            Element::blob(bytes),
+            Element::from(crate::ion_list![text.as_str()]),
+            Element::from(crate::ion_sexp!(text.as_str())),
+            Element::from(crate::ion_struct! {"name": text.as_str()}),
+            Element::from(crate::ion_list![text.as_str()]).with_annotations(["name"]),
+        ];
+        for element in &elements {
+            assert_matches_raw_writer(element, &symbol_table)?;
+        }
+        // Shrink the text by the size of its header so that each container's body length (rather
+        // than the string's length) lands exactly on the boundary.
+        for header_length in 1..=3 {
+            if let Some(shorter_length) = length.checked_sub(header_length) {
+                let text = long_text(shorter_length);
+                let element = Element::from(crate::ion_list![text.as_str()]);
+                assert_matches_raw_writer(&element, &symbol_table)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn encoded_len_with_large_symbol_ids() -> IonResult<()> {
+        // Add enough symbols that some IDs require more than one byte as a UInt or VarUInt.
+        let mut symbol_table = SymbolTable::default();
+        for i in 0..70_000 {
+            symbol_table.add_symbol_for_text(format!("symbol_{i}"));
+        }
+        let element = Element::read_one(
+            "symbol_69999::{symbol_200: symbol_69999, symbol_5: [symbol_300::symbol_1]}",
+        )?;
+        assert_matches_raw_writer(&element, &symbol_table)
+    }
+
+    #[test]
+    fn unresolved_symbols_are_reported() -> IonResult<()> {
+        let element = Element::read_one("foo::{bar: [baz, foo::bar, $0, name], name: quux}")?;
+        let error = BinaryEncoding_1_0::encoded_len_of(&element, |text| {
+            SymbolTable::default().sid_for(&text)
+        })
+        .unwrap_err();
+        assert_eq!(error.symbols(), &["foo", "bar", "baz", "quux"]);
+        Ok(())
+    }
+
+    fn assert_matches_raw_writer(element: &Element, symbol_table: &SymbolTable) -> IonResult<()> {
+        let mut symbol_table = symbol_table.clone();
+        intern_all(element, &mut symbol_table);
+
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        write_with_sids(element, &symbol_table, writer.value_writer())?;
+        let output = writer.close()?;
+        // The raw writer emits a 4-byte IVM before the value.
+        let expected_length = output.len() - 4;
+
+        let actual_length =
+            BinaryEncoding_1_0::encoded_len_of(element, |text| symbol_table.sid_for(&text))?;
+        assert_eq!(
+            actual_length, expected_length,
+            "encoded length mismatch for {element}"
+        );
+        Ok(())
+    }
+}
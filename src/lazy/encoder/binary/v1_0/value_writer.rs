@@ -143,8 +143,7 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
     }
 
     pub fn write_int(mut self, value: &Int) -> IonResult<()> {
-        let magnitude: u128 = value.unsigned_abs().data;
-        let encoded = uint::encode(magnitude);
+        let encoded = uint::encode_uint(&value.unsigned_abs());
         let bytes_to_write = encoded.as_bytes();
 
         let encoded_length = bytes_to_write.len();
@@ -699,4 +698,176 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn write_annotated_values_with_long_wrappers() -> IonResult<()> {
+        let long_text = "x".repeat(20);
+        let expected = format!(
+            r#"
+                name::"{long_text}"
+                name::version::imports::symbols::max_id::[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+            "#
+        );
+        writer_test(&expected, |writer| {
+            writer
+                .write(long_text.as_str().annotated_with(4))?
+                .write([1, 2, 3, 4, 5, 6, 7, 8, 9, 10].annotated_with([4, 5, 6, 7, 8]))?;
+            Ok(())
+        })?;
+
+        // An annotations wrapper longer than 13 bytes encodes its length as a separate VarUInt.
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.write(long_text.as_str().annotated_with(4))?;
+        let buffer = writer.close()?;
+        // IVM, then the wrapper's type descriptor and length. The length includes the annotations
+        // sequence length (1 byte), the annotation (1 byte), and the string (2 + 20 bytes).
+        assert_eq!(&buffer[4..6], &[0xEE, 0x98]);
+        Ok(())
+    }
+
+    #[test]
+    fn text_annotations_are_rejected() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        let error = writer
+            .write(1.annotated_with("foo"))
+            .map(|_| ())
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("binary Ion 1.0 cannot encode text literal annotations"),
+            "{error}"
+        );
+        Ok(())
+    }
+
+    /// Writes the IVM-less bytes produced by `test` and compares them to `expected`.
+    fn writer_bytes_test(
+        expected: &[u8],
+        test: impl FnOnce(&mut LazyRawBinaryWriter_1_0<Vec<u8>>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        test(&mut writer)?;
+        let buffer = writer.close()?;
+        // Skip the IVM
+        assert_eq!(&buffer[4..], expected, "{:x?}", &buffer[4..]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_annotated_container_writers_bytes() -> IonResult<()> {
+        #[rustfmt::skip]
+        writer_bytes_test(
+            &[
+                0xE5, // Annotations wrapper, length 5
+                0x81, // Annotations sequence length 1
+                0x84, // $4
+                0xB2, // List, length 2
+                0x21, 0x01, // 1
+            ],
+            |writer| {
+                let mut list = writer.value_writer().with_annotations(4)?.list_writer()?;
+                list.write(1)?;
+                list.close()
+            },
+        )?;
+
+        #[rustfmt::skip]
+        writer_bytes_test(
+            &[
+                0xE3, // Annotations wrapper, length 3
+                0x81, // Annotations sequence length 1
+                0x85, // $5
+                0xC0, // Empty s-expression
+            ],
+            |writer| {
+                writer
+                    .value_writer()
+                    .with_annotations(5)?
+                    .sexp_writer()?
+                    .close()
+            },
+        )?;
+
+        #[rustfmt::skip]
+        writer_bytes_test(
+            &[
+                0xE7, // Annotations wrapper, length 7
+                0x82, // Annotations sequence length 2
+                0x84, 0x87, // $4::$7
+                0xD3, // Struct, length 3
+                0x84, // Field name $4
+                0x21, 0x01, // 1
+            ],
+            |writer| {
+                let mut struct_ = writer
+                    .value_writer()
+                    .with_annotations([4, 7])?
+                    .struct_writer()?;
+                struct_.write(4, 1)?;
+                struct_.close()
+            },
+        )?;
+
+        // A container whose body is longer than 13 bytes has a VarUInt length in its header,
+        // which the annotations wrapper's length must account for.
+        let mut expected = vec![
+            0xEE, 0x92, // Annotations wrapper, VarUInt length 18
+            0x81, // Annotations sequence length 1
+            0x84, // $4
+            0xBE, 0x8E, // List, VarUInt length 14
+        ];
+        expected.extend_from_slice(&[0x20; 14]); // 0, 0, 0, ...
+        writer_bytes_test(&expected, |writer| {
+            let mut list = writer.value_writer().with_annotations(4)?.list_writer()?;
+            list.write_all([0; 14])?;
+            list.close()
+        })
+    }
+
+    #[test]
+    fn write_nested_annotated_container_writers() -> IonResult<()> {
+        let expected = r#"
+            name::[
+                version::{
+                    imports: symbols::max_id::[1, name::(2 version::{})],
+                    symbols: 3,
+                },
+                4,
+            ]
+            $ion::{imports: name::[]}
+        "#;
+        writer_test(expected, |writer| {
+            let mut list = writer.value_writer().with_annotations(4)?.list_writer()?;
+            let mut struct_ = list.value_writer().with_annotations(5)?.struct_writer()?;
+            let mut inner_list = struct_
+                .field_writer(6)
+                .with_annotations([7, 8])?
+                .list_writer()?;
+            inner_list.write(1)?;
+            let mut sexp = inner_list
+                .value_writer()
+                .with_annotations(4)?
+                .sexp_writer()?;
+            sexp.write(2)?;
+            sexp.value_writer()
+                .with_annotations(5)?
+                .struct_writer()?
+                .close()?;
+            sexp.close()?;
+            inner_list.close()?;
+            struct_.write(7, 3)?;
+            struct_.close()?;
+            list.write(4)?;
+            list.close()?;
+
+            let mut struct_ = writer.value_writer().with_annotations(1)?.struct_writer()?;
+            struct_
+                .field_writer(6)
+                .with_annotations(4)?
+                .list_writer()?
+                .close()?;
+            struct_.close()
+        })
+    }
 }
@@ -140,6 +140,8 @@ impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_0<W> {
     }
 
     fn write_version_marker(&mut self) -> IonResult<()> {
+        // Values are buffered until the next flush; write them out first so they precede the IVM.
+        self.flush()?;
         self.output.write_all(&[0xE0, 0x01, 0x00, 0xEA])?;
         Ok(())
     }
@@ -396,9 +396,16 @@ impl<'value, 'top> StructWriter for BinaryStructWriter_1_1<'value, 'top> {
     }
 }
 
+/// Writes the arguments of an e-expression.
+///
+/// The writer does not know the signature of the macro being invoked; each argument is encoded
+/// as a tagged value in the order it is written, and no argument grouping bitmap is written.
 pub struct BinaryEExpWriter_1_1<'value, 'top> {
     allocator: &'top BumpAllocator,
     buffer: &'value mut BumpVec<'top, u8>,
+    // If the e-expression is length-prefixed, its arguments are encoded here and then copied to
+    // `buffer` (following their length) when the writer is closed.
+    args_buffer: Option<BumpVec<'top, u8>>,
     value_writer_config: ValueWriterConfig,
 }
 
@@ -411,16 +418,42 @@ impl<'value, 'top> BinaryEExpWriter_1_1<'value, 'top> {
         Self {
             allocator,
             buffer,
+            args_buffer: None,
             value_writer_config,
         }
     }
+
+    /// Constructs a writer for the arguments of a length-prefixed e-expression. The caller is
+    /// responsible for having already written the opcode and macro address to `buffer`.
+    pub fn new_length_prefixed(
+        allocator: &'top BumpAllocator,
+        buffer: &'value mut BumpVec<'top, u8>,
+        value_writer_config: ValueWriterConfig,
+    ) -> Self {
+        const DEFAULT_CAPACITY: usize = 512;
+        Self {
+            allocator,
+            buffer,
+            args_buffer: Some(BumpVec::with_capacity_in(DEFAULT_CAPACITY, allocator)),
+            value_writer_config,
+        }
+    }
+
+    /// The buffer to which this writer encodes arguments.
+    fn target_buffer(&mut self) -> &mut BumpVec<'top, u8> {
+        match &mut self.args_buffer {
+            Some(args_buffer) => args_buffer,
+            None => self.buffer,
+        }
+    }
 }
 
 impl<'value, 'top> MakeValueWriter for BinaryEExpWriter_1_1<'value, 'top> {
     type ValueWriter<'a> = BinaryValueWriter_1_1<'a, 'top> where Self: 'a;
 
     fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
-        BinaryValueWriter_1_1::new(self.allocator, self.buffer, self.value_writer_config)
+        let value_writer_config = self.value_writer_config;
+        BinaryValueWriter_1_1::new(self.allocator, self.target_buffer(), value_writer_config)
     }
 }
 
@@ -428,15 +461,17 @@ impl<'value, 'top> SequenceWriter for BinaryEExpWriter_1_1<'value, 'top> {
     type Resources = ();
 
     fn close(self) -> IonResult<Self::Resources> {
-        // Nothing to do
-        // TODO: When we have length-prefixed macro invocations, this will require a step to flush the buffered encoding.
+        if let Some(args_buffer) = self.args_buffer {
+            FlexUInt::write(self.buffer, args_buffer.len())?;
+            self.buffer.extend_from_slice_copy(args_buffer.as_slice());
+        }
         Ok(())
     }
 }
 
 impl<'value, 'top> EExpWriter for BinaryEExpWriter_1_1<'value, 'top> {
     fn write_flex_uint(&mut self, value: impl Into<UInt>) -> IonResult<()> {
-        FlexUInt::write(self.buffer, value)?;
+        FlexUInt::write(self.target_buffer(), value)?;
         Ok(())
     }
 }
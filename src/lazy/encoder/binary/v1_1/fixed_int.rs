@@ -2,7 +2,9 @@ use std::io::Write;
 
 use crate::decimal::coefficient::Coefficient;
 use crate::result::IonFailure;
-use crate::{Int, IonResult};
+use crate::types::integer::IntData;
+use crate::{Int, IonError, IonResult};
+use num_bigint::BigInt;
 
 /// An Ion 1.1 encoding primitive that represents a fixed-length signed integer.
 #[derive(Debug)]
@@ -45,11 +47,7 @@ impl FixedInt {
             1 => Self::read_const::<1>(input.try_into().unwrap()),
             2 => Self::read_const::<2>(input.try_into().unwrap()),
             n if n <= MAX_INT_SIZE_IN_BYTES => Self::read_general_case(input, n),
-            _ => {
-                return IonResult::decoding_error(
-                    "found a FixedInt that was larger than the supported maximum",
-                )
-            }
+            n => Self::read_big(&input[..n]),
         };
         Ok(fixed_int)
     }
@@ -80,6 +78,12 @@ impl FixedInt {
         FixedInt::new(size_in_bytes, value)
     }
 
+    /// Reads a FixedInt that is too wide to be read into an `i128`.
+    #[inline(never)]
+    fn read_big(input: &[u8]) -> FixedInt {
+        FixedInt::new(input.len(), BigInt::from_signed_bytes_le(input))
+    }
+
     #[inline]
     fn write_i128<W: Write>(output: &mut W, value: i128) -> IonResult<usize> {
         let num_encoded_bytes = Self::encoded_size(value);
@@ -92,16 +96,26 @@ impl FixedInt {
     }
 
     pub fn write(output: &mut impl Write, value: &Int) -> IonResult<usize> {
-        Self::write_i128(output, value.data)
+        match &value.data {
+            IntData::I128(value) => Self::write_i128(output, *value),
+            IntData::BigInt(value) => {
+                let encoded_bytes = value.to_signed_bytes_le();
+                output.write_all(&encoded_bytes)?;
+                Ok(encoded_bytes.len())
+            }
+        }
     }
 
     #[inline]
     pub fn encoded_size(value: impl Into<Int>) -> usize {
-        let value = value.into();
+        let value = match value.into().data {
+            IntData::I128(value) => value,
+            IntData::BigInt(value) => return value.to_signed_bytes_le().len(),
+        };
         let num_sign_bits = if value.is_negative() {
-            value.data.leading_ones()
+            value.leading_ones()
         } else {
-            value.data.leading_zeros()
+            value.leading_zeros()
         };
         let num_magnitude_bits = 128 - num_sign_bits;
         (num_magnitude_bits as usize / 8) + 1
@@ -122,9 +136,11 @@ impl From<FixedInt> for Int {
     }
 }
 
-impl From<FixedInt> for Coefficient {
-    fn from(other: FixedInt) -> Self {
-        other.value.into()
+impl TryFrom<FixedInt> for Coefficient {
+    type Error = IonError;
+
+    fn try_from(other: FixedInt) -> Result<Self, Self::Error> {
+        other.value.try_into()
     }
 }
 
@@ -139,6 +155,7 @@ impl From<i64> for FixedInt {
 mod tests {
     use crate::lazy::encoder::binary::v1_1::fixed_int::FixedInt;
     use crate::{Int, IonResult};
+    use num_bigint::BigInt;
 
     const FIXED_INT_TEST_CASES: &[(i64, &[u8])] = &[
         (0, &[0b00000000]),
@@ -325,4 +342,16 @@ mod tests {
         }
         Ok(())
     }
+    #[test]
+    fn round_trip_fixed_int_beyond_i128() -> IonResult<()> {
+        // 2^135, which needs 18 bytes in two's complement.
+        let value = Int::from(BigInt::from(1) << 135);
+        let mut buffer = Vec::new();
+        let bytes_written = FixedInt::write(&mut buffer, &value)?;
+        assert_eq!(bytes_written, 18);
+        assert_eq!(FixedInt::encoded_size(&value), 18);
+        let fixed_int = FixedInt::read(&buffer, buffer.len(), 0)?;
+        assert_eq!(fixed_int.value(), &value);
+        Ok(())
+    }
 }
@@ -3,11 +3,11 @@ use std::io::Write;
 use ice_code::ice as cold_path;
 
 use crate::decimal::coefficient::Coefficient;
-use crate::lazy::encoder::binary::v1_1::fixed_int::{
-    MAX_INT_SIZE_IN_BYTES, MAX_UINT_SIZE_IN_BYTES,
-};
+use crate::lazy::encoder::binary::v1_1::fixed_int::MAX_UINT_SIZE_IN_BYTES;
 use crate::result::IonFailure;
+use crate::types::integer::UIntData;
 use crate::{IonError, IonResult, UInt};
+use num_bigint::BigUint;
 
 /// An Ion 1.1 encoding primitive that represents a fixed-length unsigned integer.
 #[derive(Debug)]
@@ -36,8 +36,11 @@ impl FixedUInt {
             return IonResult::incomplete("reading a FixedUInt", offset);
         }
 
-        if size_in_bytes > MAX_INT_SIZE_IN_BYTES {
-            return cold_path! {{IonResult::decoding_error("found a FixedUInt that was larger than the supported maximum")}};
+        if size_in_bytes > MAX_UINT_SIZE_IN_BYTES {
+            return cold_path! {{
+                let value = BigUint::from_bytes_le(&input[..size_in_bytes]);
+                Ok(FixedUInt::new(size_in_bytes, value))
+            }};
         }
 
         const BUFFER_SIZE: usize = MAX_UINT_SIZE_IN_BYTES;
@@ -49,7 +52,14 @@ impl FixedUInt {
 
     #[inline]
     pub(crate) fn write<W: Write>(output: &mut W, value: impl Into<UInt>) -> IonResult<usize> {
-        let value = value.into().data;
+        let value = match value.into().data {
+            UIntData::U128(value) => value,
+            UIntData::BigUInt(value) => {
+                let encoded_bytes = value.to_bytes_le();
+                output.write_all(&encoded_bytes)?;
+                return Ok(encoded_bytes.len());
+            }
+        };
         let encoded_bytes = value.to_le_bytes();
         let leading_zeros = value.leading_zeros();
         let num_encoded_bytes = (16 - (leading_zeros as usize / 8)).max(1);
@@ -72,8 +82,8 @@ impl TryFrom<FixedUInt> for Coefficient {
 
     fn try_from(other: FixedUInt) -> Result<Self, Self::Error> {
         use crate::types::integer::Int;
-        let as_int: Int = other.value.try_into()?;
-        Ok(as_int.into())
+        let as_int: Int = other.value.into();
+        as_int.try_into()
     }
 }
 
@@ -202,7 +212,7 @@ mod tests {
 
         for (value, expected_encoding) in test_cases {
             let mut buffer = Vec::new();
-            FixedUInt::write(&mut buffer, value)?;
+            FixedUInt::write(&mut buffer, &value)?;
             let encoding = buffer.as_slice();
             assert_eq!(encoding, expected_encoding, "actual encoding {encoding:x?} was != expected encoding {expected_encoding:x?} for value {value}");
         }
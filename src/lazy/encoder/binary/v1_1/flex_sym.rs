@@ -61,7 +61,10 @@ impl<'top> FlexSym<'top> {
     /// `offset` is the position of the slice in some larger input stream. It is only used to
     ///          populate an appropriate error message if reading fails.
     pub fn read(input: &'top [u8], offset: usize) -> IonResult<FlexSym<'top>> {
-        use crate::{result::IonFailure, IonError};
+        use crate::{
+            result::{DecodingErrorKind, IonFailure},
+            IonError,
+        };
 
         let value = FlexInt::read(input, offset)?;
         let sym_value = value.value();
@@ -79,15 +82,38 @@ impl<'top> FlexSym<'top> {
                 }
                 let text =
                     std::str::from_utf8(&input[flex_int_len..flex_sym_end]).map_err(|_| {
-                        IonError::decoding_error("found FlexSym with invalid UTF-8 data")
+                        IonError::decoding_error_of_kind(
+                            DecodingErrorKind::InvalidUtf8,
+                            "found FlexSym with invalid UTF-8 data",
+                        )
                     })?;
                 let symbol_ref = Text(text);
                 (FlexSymValue::SymbolRef(symbol_ref), flex_int_len + len)
             }
-            Ordering::Equal => (
-                FlexSymValue::Opcode(Opcode::from_byte(input[value.size_in_bytes()])),
-                value.size_in_bytes() + 1,
-            ),
+            Ordering::Equal => {
+                let escape_offset = value.size_in_bytes();
+                let Some(&escape_byte) = input.get(escape_offset) else {
+                    return IonResult::incomplete("reading a FlexSym escape", offset);
+                };
+                match escape_byte {
+                    // The empty string; see `encode_special_case`.
+                    0x90 => (FlexSymValue::SymbolRef(Text("")), escape_offset + 1),
+                    // A symbol ID value opcode followed by a one-byte FixedUInt, used to encode $0.
+                    0xE1 => {
+                        let Some(&sid) = input.get(escape_offset + 1) else {
+                            return IonResult::incomplete("reading a FlexSym escape", offset);
+                        };
+                        (
+                            FlexSymValue::SymbolRef(SymbolId(sid as usize)),
+                            escape_offset + 2,
+                        )
+                    }
+                    _ => (
+                        FlexSymValue::Opcode(Opcode::from_byte(escape_byte)),
+                        escape_offset + 1,
+                    ),
+                }
+            }
         };
 
         Ok(Self {
@@ -298,7 +298,11 @@ impl FlexUInt {
 
     #[inline]
     pub fn write<W: Write>(output: &mut W, value: impl Into<UInt>) -> IonResult<usize> {
-        let value = value.into().data;
+        let Some(value) = value.into().as_u128() else {
+            return IonResult::encoding_error(
+                "found a FlexUInt that was larger than the current limit",
+            );
+        };
         let leading_zeros = value.leading_zeros();
         let num_encoded_bytes = BYTES_NEEDED_CACHE[leading_zeros as usize] as usize;
         if num_encoded_bytes <= Self::MAX_FLEX_UINT_ENCODED_SIZE_IN_BYTES {
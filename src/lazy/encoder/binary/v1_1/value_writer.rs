@@ -173,23 +173,14 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
             return self.write_i64(small_value);
         }
         cold_path! {{
-            let value: i128 = value.data;
             // Because we've ruled out numbers small enough to fit in an i64, its encoded length
             // must be greater than 8. Write the opcode for an integer with a FlexUInt length.
             self.push_byte(0xF6);
-            let num_sign_bits = if value < 0 {
-                value.leading_ones()
-            } else {
-                value.leading_zeros()
-            };
-            let num_magnitude_bits = 128 - num_sign_bits;
-            let num_encoded_bytes = (num_magnitude_bits as usize / 8) + 1;
-            let le_bytes = value.to_le_bytes();
-            let encoded_bytes = &le_bytes[..num_encoded_bytes];
+            let num_encoded_bytes = FixedInt::encoded_size(value);
             // Write the length as a FlexUInt.
             FlexUInt::write(self.encoding_buffer, num_encoded_bytes as u64)?;
             // Write the little endian bytes of the integer.
-            self.push_bytes(encoded_bytes);
+            FixedInt::write(self.encoding_buffer, value)?;
             Ok(())
         }}
     }
@@ -673,23 +664,34 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         macro_id: impl Into<MacroIdRef<'a>>,
     ) -> IonResult<<Self as ValueWriter>::EExpWriter> {
         match macro_id.into() {
-            MacroIdRef::LocalName(_name) => {
-                // This would be handled by the system writer
-                todo!("macro invocation by name");
+            MacroIdRef::LocalName(name) => {
+                // The binary encoding can only refer to a macro by its address. Resolving a name to
+                // an address requires the encoding context, which the raw writer does not have.
+                IonResult::encoding_error(format!(
+                    "binary Ion 1.1 e-expressions must invoke macros by address; found name '{name}'"
+                ))
             }
             MacroIdRef::LocalAddress(address) if address < 64 => {
                 // Invoke this ID with a one-byte opcode
                 self.encoding_buffer.push(address as u8);
+                Ok(BinaryEExpWriter_1_1::new(
+                    self.allocator,
+                    self.encoding_buffer,
+                    self.config(),
+                ))
             }
-            MacroIdRef::LocalAddress(_address) => {
-                todo!("macros with addresses higher than 64");
+            MacroIdRef::LocalAddress(address) => {
+                // Invoke this ID using a length-prefixed e-expression. The length of the arguments
+                // is written when the e-expression writer is closed.
+                self.encoding_buffer.push(0xF5);
+                FlexUInt::write(self.encoding_buffer, address)?;
+                Ok(BinaryEExpWriter_1_1::new_length_prefixed(
+                    self.allocator,
+                    self.encoding_buffer,
+                    self.config(),
+                ))
             }
-        };
-        Ok(BinaryEExpWriter_1_1::new(
-            self.allocator,
-            self.encoding_buffer,
-            self.config(),
-        ))
+        }
     }
 }
 
@@ -899,17 +901,26 @@ mod tests {
     use rstest::rstest;
 
     use crate::ion_data::IonEq;
+    use crate::lazy::any_encoding::IonVersion;
+    use crate::lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1;
+    use crate::lazy::decoder::{HasRange, HasSpan};
     use crate::lazy::encoder::annotate::{Annotatable, Annotated};
     use crate::lazy::encoder::annotation_seq::AnnotationSeq;
     use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
     use crate::lazy::encoder::value_writer::ValueWriter;
     use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter};
     use crate::lazy::encoder::write_as_ion::{WriteAsIon, WriteAsSExp};
+    use crate::lazy::encoder::LazyRawWriter;
+    use crate::lazy::expanded::compiler::TemplateCompiler;
+    use crate::lazy::expanded::macro_evaluator::RawEExpression;
+    use crate::lazy::expanded::EncodingContext;
+    use crate::lazy::raw_stream_item::RawStreamItem;
+    use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
     use crate::raw_symbol_ref::AsRawSymbolRef;
     use crate::types::float::{FloatRepr, SmallestFloatRepr};
     use crate::{
-        v1_1, Decimal, Element, Int, IonResult, IonType, Null, RawSymbolRef, SymbolId, Timestamp,
-        Writer,
+        v1_1, ContainerEncoding, Decimal, Element, IonResult, IonType, Null, RawSymbolRef,
+        SymbolId, Timestamp, Writer,
     };
 
     fn encoding_test(
@@ -1177,7 +1188,7 @@ mod tests {
             ),
             (
                 // ~e
-                Decimal::new(Int::from(27182818284590452353602874713526624i128), -40),
+                Decimal::new(27182818284590452353602874713526624i128, -40),
                 &[
                     0xF7, 0x21, 0xB1, 0x60, 0x51, 0x2B, 0xF8, 0xFE, 0x2B, 0xA4, 0x11, 0xAF, 0x90,
                     0xF7, 0x66, 0x37, 0x3C, 0x05,
@@ -2677,6 +2688,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn delimited_containers_can_be_read() -> IonResult<()> {
+        // Nested delimited containers written with the raw writer, using inline text for all
+        // symbols so that no symbol table is needed.
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        let mut list = writer
+            .value_writer()
+            .with_delimited_containers()
+            .list_writer()?;
+        list.write("foo")?.write(1)?;
+        let mut sexp = list.sexp_writer()?;
+        sexp.write(RawSymbolRef::Text("bar"))?.write(2.5f64)?;
+        let mut strukt = sexp.struct_writer()?;
+        strukt
+            .write(RawSymbolRef::Text("baz"), true)?
+            .write(RawSymbolRef::Text("quux"), RawSymbolRef::Text("quuz"))?;
+        strukt
+            .field_writer(RawSymbolRef::Text("empty"))
+            .list_writer()?
+            .close()?;
+        strukt.close()?;
+        sexp.close()?;
+        list.close()?;
+        writer.flush()?;
+        let expected =
+            Element::read_one(r#"["foo", 1, (bar 2.5e0 {baz: true, quux: quuz, empty: []})]"#)?;
+        assert_eq!(Element::read_one(&buffer)?, expected);
+
+        // The same data written by the application writer, which manages the symbol table.
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        writer
+            .value_writer()
+            .with_container_encoding(ContainerEncoding::Delimited)
+            .write(&expected)?;
+        let encoded = writer.close()?;
+        // The stream ends with the delimited list's END opcode.
+        assert_eq!(encoded.last(), Some(&0xF0));
+        assert_eq!(Element::read_one(encoded)?, expected);
+        Ok(())
+    }
+
     #[test]
     fn write_annotated() -> IonResult<()> {
         fn case<'a, ValueType: WriteAsIon, SymbolType: 'a>(
@@ -2865,6 +2918,96 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_eexps_with_varying_numbers_of_args() -> IonResult<()> {
+        let mut context = EncodingContext::for_ion_version(IonVersion::v1_1);
+        let mut add_macro = |source: &str| -> IonResult<usize> {
+            let template = TemplateCompiler::compile_from_text(context.get_ref(), source)?;
+            context.macro_table.add_macro(template)
+        };
+        let none_address = add_macro("(macro none () null)")?;
+        let one_address = add_macro("(macro one (a) a)")?;
+        // Push the next macro's address beyond those that can be written in an opcode.
+        for i in one_address + 1..64 {
+            add_macro(&format!("(macro filler_{i} () null)"))?;
+        }
+        let three_address = add_macro("(macro three (a b c) [a, b, c])")?;
+        assert!(one_address < 64 && (64..128).contains(&three_address));
+
+        let mut writer = LazyRawBinaryWriter_1_1::new(Vec::new())?;
+        writer.eexp_writer(none_address)?.close()?;
+        let mut args = writer.eexp_writer(one_address)?;
+        args.write(1)?;
+        args.close()?;
+        let mut args = writer.eexp_writer(three_address)?;
+        args.write("a")?;
+        let mut nested_args = args.eexp_writer(one_address)?;
+        nested_args.write(2)?;
+        nested_args.close()?;
+        args.write_symbol("c")?;
+        args.close()?;
+        // The binary encoding cannot refer to a macro by name.
+        assert!(writer.eexp_writer("one").is_err());
+        let encoded = writer.close()?;
+
+        let one_opcode = one_address as u8;
+        #[rustfmt::skip]
+        let expected_three = [
+            0xF5,                              // Length-prefixed e-expression
+            (three_address as u8) << 1 | 1,    // FlexUInt macro address
+            0x0F,                              // FlexUInt length 7
+            0x91, 0x61,                        // "a"
+            one_opcode, 0x61, 0x02,            // (:one 2)
+            0xA1, 0x63,                        // c
+        ];
+        let mut expected = vec![0xE0, 0x01, 0x01, 0xEA]; // IVM
+        expected.extend_from_slice(&[none_address as u8]); // (:none)
+        expected.extend_from_slice(&[one_opcode, 0x61, 0x01]); // (:one 1)
+        expected.extend_from_slice(&expected_three);
+        assert_eq!(encoded, expected);
+
+        let context = context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&encoded);
+        let _marker = reader.next(context)?.expect_ivm()?;
+
+        let eexp = reader.next(context)?.expect_eexp()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(none_address));
+        assert_eq!(eexp.range(), 4..5);
+        assert!(eexp.raw_arguments().next().is_none());
+
+        let eexp = reader.next(context)?.expect_eexp()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(one_address));
+        assert_eq!(eexp.range(), 5..8);
+        let mut args = eexp.raw_arguments();
+        let arg = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_i64()?, 1);
+        assert_eq!(arg.range(), 6..8);
+        assert!(args.next().is_none());
+
+        let eexp = reader.next(context)?.expect_eexp()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(three_address));
+        assert_eq!(eexp.span().bytes(), expected_three);
+        let mut args = eexp.raw_arguments();
+        let arg = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_string()?.text(), "a");
+        let nested = args.next().unwrap()?.expr().expect_eexp()?;
+        assert_eq!(nested.id(), MacroIdRef::LocalAddress(one_address));
+        assert_eq!(nested.range(), 13..16);
+        let mut nested_args = nested.raw_arguments();
+        let arg = nested_args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_i64()?, 2);
+        assert!(nested_args.next().is_none());
+        let arg = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_symbol()?, RawSymbolRef::Text("c"));
+        assert!(args.next().is_none());
+
+        assert!(matches!(
+            reader.next(context)?,
+            RawStreamItem::EndOfStream(_)
+        ));
+        Ok(())
+    }
+
     #[rstest]
     #[case::boolean("true false")]
     #[case::int("1 2 3 4 5")]
@@ -3030,4 +3173,20 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn flush_between_values() -> IonResult<()> {
+        encoding_test(
+            |writer| {
+                writer.write(1)?;
+                writer.flush()?;
+                // Values written before the IVM are flushed ahead of it.
+                writer.write(2)?;
+                LazyRawWriter::write_version_marker(writer)?;
+                writer.write(3)?;
+                Ok(())
+            },
+            &[0x61, 0x01, 0x61, 0x02, 0xE0, 0x01, 0x01, 0xEA, 0x61, 0x03],
+        )
+    }
 }
@@ -82,6 +82,8 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
         output.write_all(encoding_buffer)?;
         // Flush the output sink, which may have its own buffers.
         output.flush()?;
+        // Now that we've written the encoding buffer's contents to output, clear it.
+        self.encoding_buffer_ptr = None;
         // Clear the allocator. A new encoding buffer will be allocated on the next write.
         allocator.reset();
         Ok(())
@@ -150,6 +152,8 @@ impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_1<W> {
     }
 
     fn write_version_marker(&mut self) -> IonResult<()> {
+        // Values are buffered until the next flush; write them out first so they precede the IVM.
+        self.flush()?;
         self.output.write_all(&[0xE0, 0x01, 0x01, 0xEA])?;
         Ok(())
     }
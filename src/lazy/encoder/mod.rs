@@ -45,7 +45,8 @@ pub enum SymbolCreationPolicy {
     // TODO: Other potential policies, such as:
     //         * Require text (if a SID doesn't map to text, it's an error)
     //         * Wait until the next `flush()` operation to add new symbol definitions in bulk.
-    //         * Using a symbol detection mechanism to intern recurring symbols after `N` usages.
+    //       Ion 1.1 application writers can intern recurring symbols after `N` usages; see
+    //       `SymbolInterningPolicy`.
 }
 
 pub(crate) mod private {
@@ -68,6 +69,7 @@ pub trait LazyRawWriter<W: Write>: SequenceWriter<Resources = W> {
 
     fn output_mut(&mut self) -> &mut W;
 
+    /// Writes an Ion version marker to the output after any values that have already been written.
     fn write_version_marker(&mut self) -> IonResult<()>;
 
     fn encoding(&self) -> IonEncoding;
@@ -76,20 +78,24 @@ pub trait LazyRawWriter<W: Write>: SequenceWriter<Resources = W> {
 #[cfg(test)]
 mod tests {
     use crate::lazy::encoder::annotate::Annotatable;
+    use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
     use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
     use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter};
+    use crate::lazy::encoder::LazyRawWriter;
     use crate::symbol_ref::AsSymbolRef;
-    use crate::{Element, IonData, IonResult, Timestamp};
+    use crate::{
+        v1_0, v1_1, Element, Encoding, IonData, IonEncoding, IonResult, Timestamp, WriteConfig,
+        Writer,
+    };
 
     fn writer_test(
         expected: &str,
-        mut test: impl FnMut(&mut LazyRawTextWriter_1_0<&mut Vec<u8>>) -> IonResult<()>,
+        mut test: impl FnMut(&mut LazyRawTextWriter_1_0<Vec<u8>>) -> IonResult<()>,
     ) -> IonResult<()> {
         let expected = Element::read_all(expected)?;
-        let mut buffer = Vec::new();
-        let mut writer = LazyRawTextWriter_1_0::new(&mut buffer)?;
+        let mut writer = LazyRawTextWriter_1_0::new(Vec::new())?;
         test(&mut writer)?;
-        writer.flush()?;
+        let buffer = writer.close()?;
         println!("{}", String::from_utf8_lossy(buffer.as_slice()));
         let actual = Element::read_all(buffer)?;
         assert!(
@@ -111,7 +117,7 @@ mod tests {
             {{4AEA6g==}}
             [1, 2, 3]
         "#;
-        let test = |writer: &mut LazyRawTextWriter_1_0<&mut Vec<u8>>| {
+        let test = |writer: &mut LazyRawTextWriter_1_0<Vec<u8>>| {
             writer
                 .write(1)?
                 .write(false)?
@@ -137,7 +143,7 @@ mod tests {
             Saturn::2023-11-09T
             Uranus::{{4AEA6g==}}
         "#;
-        let test = |writer: &mut LazyRawTextWriter_1_0<&mut Vec<u8>>| {
+        let test = |writer: &mut LazyRawTextWriter_1_0<Vec<u8>>| {
             writer
                 .write(1.annotated_with(["foo", "bar"]))?
                 .write(false.annotated_with(["quux", "quuz", "gary"]))?
@@ -168,7 +174,7 @@ mod tests {
               {{4AEA6g==}},
             ]
         "#;
-        let test = |writer: &mut LazyRawTextWriter_1_0<&mut Vec<u8>>| {
+        let test = |writer: &mut LazyRawTextWriter_1_0<Vec<u8>>| {
             let mut list = writer.list_writer()?;
             list.write(1)?
                 .write(false)?
@@ -197,7 +203,7 @@ mod tests {
               [1, 2, 3]
             )
         "#;
-        let test = |writer: &mut LazyRawTextWriter_1_0<&mut Vec<u8>>| {
+        let test = |writer: &mut LazyRawTextWriter_1_0<Vec<u8>>| {
             let mut sexp = writer.sexp_writer()?;
             sexp.write(1)?
                 .write(false)?
@@ -225,7 +231,7 @@ mod tests {
               g: {{4AEA6g==}},
             }
         "#;
-        let test = |writer: &mut LazyRawTextWriter_1_0<&mut Vec<u8>>| {
+        let test = |writer: &mut LazyRawTextWriter_1_0<Vec<u8>>| {
             let mut struct_ = writer.struct_writer()?;
             struct_
                 .write("a", 1)?
@@ -239,4 +245,106 @@ mod tests {
         };
         writer_test(expected, test)
     }
+
+    /// Writes a container and a scalar, then confirms that `close()` flushes them and returns
+    /// the complete output.
+    fn close_returns_complete_output<E: Encoding>(encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let mut writer = E::Writer::build(WriteConfig::from(encoding), Vec::new())?;
+        let mut list = writer.list_writer()?;
+        list.write(1)?.write("two")?;
+        list.close()?;
+        writer.write(3)?;
+        let output = writer.close()?;
+        assert_eq!(
+            Element::read_all(output)?,
+            Element::read_all(r#"[1, "two"] 3"#)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn close_returns_complete_output_for_each_encoding() -> IonResult<()> {
+        close_returns_complete_output(v1_0::Text)?;
+        close_returns_complete_output(v1_0::Binary)?;
+        close_returns_complete_output(v1_1::Text)?;
+        close_returns_complete_output(v1_1::Binary)
+    }
+
+    const ELEMENT_TREE: &str = r#"
+        foo::bar::{
+            name: "widget",
+            'quoted field': baz,
+            nested: [1, 2.5, 3e0, null.int, annotated::(a b [c, $0])],
+            ts: 2024-05-06T07:08:09.123Z,
+            blob: {{aGVsbG8=}},
+            clob: {{"hi"}},
+            empty: {},
+            dup: 1,
+            dup: 2,
+        }
+        $0
+        null
+        -0d0
+    "#;
+
+    /// Writes each element of `ELEMENT_TREE` using both the raw and application-level writers
+    /// for `encoding`, then confirms the output is Ion-equal to the original.
+    fn write_elements_round_trip<E: Encoding>(encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let elements = Element::read_all(ELEMENT_TREE)?;
+
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        writer.write_all(&elements)?;
+        let output = writer.close()?;
+        assert!(IonData::eq(&elements, &Element::read_all(output)?));
+
+        // Owned elements can be written too.
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        for element in elements.clone() {
+            writer.write(element)?;
+        }
+        let output = writer.close()?;
+        assert!(IonData::eq(&elements, &Element::read_all(output)?));
+
+        // Ion 1.0's raw binary writer can only write symbol IDs; it is tested separately below.
+        if encoding.encoding() != IonEncoding::Binary_1_0 {
+            let mut writer = E::Writer::build(WriteConfig::from(encoding), Vec::new())?;
+            writer.write_all(&elements)?;
+            let output = writer.close()?;
+            assert!(IonData::eq(&elements, &Element::read_all(output)?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_elements_round_trip_for_each_encoding() -> IonResult<()> {
+        write_elements_round_trip(v1_0::Text)?;
+        write_elements_round_trip(v1_0::Binary)?;
+        write_elements_round_trip(v1_1::Text)?;
+        write_elements_round_trip(v1_1::Binary)
+    }
+
+    #[test]
+    fn raw_binary_1_0_writer_writes_elements_without_symbol_text() -> IonResult<()> {
+        let elements = Element::read_all(r#"[1, "two", ($0 {{Mw==}})] 4.5 null.symbol"#)?;
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        writer.write_all(&elements)?;
+        let output = writer.close()?;
+        assert!(IonData::eq(&elements, &Element::read_all(output)?));
+
+        // Symbols with text can't be written without a symbol table.
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        let Err(error) = writer.write(&Element::read_one("[1, foo]")?) else {
+            panic!("raw binary writer wrote a text symbol");
+        };
+        assert!(error
+            .to_string()
+            .contains("cannot write text symbols (here: 'foo')"));
+        Ok(())
+    }
 }
@@ -16,7 +16,6 @@ use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
 use crate::text::text_formatter::{FmtValueFormatter, IoValueFormatter};
-use crate::text::whitespace_config::WhitespaceConfig;
 use crate::types::{ContainerType, ParentType};
 use crate::{
     v1_0, Decimal, Encoding, Int, IonResult, IonType, RawSymbolRef, Timestamp, ValueWriterConfig,
@@ -32,6 +31,9 @@ pub struct TextValueWriter_1_0<'value, W: Write + 'value> {
     pub(crate) parent_type: ParentType,
     // If `true`, this value had annotations and so should not write its own indentation.
     pub(crate) has_annotations: bool,
+    // If `true`, this value follows another value in the same container and so must write the
+    // container's value delimiter and whitespace before its own text.
+    pub(crate) follows_value: bool,
 }
 
 pub(crate) fn write_symbol_token<O: Write, A: AsRawSymbolRef>(
@@ -67,12 +69,17 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
             value_delimiter: delimiter,
             parent_type,
             has_annotations: false,
+            follows_value: false,
         }
     }
 
-    /// Writes the `indentation` string set in the whitespace config to output `depth` times.
-    fn write_indentation(&mut self) -> IonResult<()> {
-        let indentation = self.whitespace_config().indentation;
+    /// Writes the delimiter separating this value from the one before it (if any), followed by
+    /// the `indentation` string set in the whitespace config `depth` times.
+    pub(crate) fn write_indentation(&mut self) -> IonResult<()> {
+        if self.follows_value {
+            self.follows_value = false;
+            self.writer.write_value_separator(self.value_delimiter)?;
+        }
         if self.parent_type == ParentType::Struct || self.has_annotations {
             // If this value is part of a struct field, the indentation was written before the
             // field name.
@@ -80,12 +87,7 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
             // Either way, there's nothing to do here.
             return Ok(());
         }
-        if !indentation.is_empty() {
-            for _ in 0..self.depth {
-                write!(self.output(), "{indentation}")?;
-            }
-        }
-        Ok(())
+        self.writer.write_indentation(self.depth)
     }
 }
 
@@ -94,22 +96,18 @@ impl<'value, W: Write> TextValueWriter_1_0<'value, W> {
         &mut self.writer.output
     }
 
-    fn whitespace_config(&self) -> &WhitespaceConfig {
-        self.writer.whitespace_config
-    }
-
     pub fn delimiter(&self) -> &'static str {
         self.value_delimiter
     }
 
+    /// Writes the whitespace that follows a top-level value. Nested values are separated by
+    /// the container that holds them; see [`TextContainerWriter_1_0`].
     #[inline]
     fn write_delimiter_text(&mut self) -> IonResult<()> {
-        let space_between = match self.depth {
-            0 => self.whitespace_config().space_between_top_level_values,
-            _ => self.whitespace_config().space_between_nested_values,
-        };
-        let value_delimiter = self.value_delimiter;
-        write!(self.output(), "{value_delimiter}{space_between}")?;
+        self.writer.value_in_progress = false;
+        if self.depth == 0 {
+            self.writer.write_top_level_space()?;
+        }
         Ok(())
     }
 }
@@ -121,12 +119,15 @@ pub struct TextAnnotatedValueWriter_1_0<'value, W: Write> {
 
 impl<'value, W: Write> TextAnnotatedValueWriter_1_0<'value, W> {
     fn encode_annotations(mut self) -> IonResult<TextValueWriter_1_0<'value, W>> {
+        if self.annotations.is_empty() {
+            return Ok(self.value_writer);
+        }
         // The inner ValueWriter knows the indentation depth; we'll have it write the indentation
         // before we write the value.
         self.value_writer.write_indentation()?;
         // After indenting, we set the `has_annotations` flag to `true` so the value won't write
         // indentation a second time.
-        self.value_writer.has_annotations = !self.annotations.is_empty();
+        self.value_writer.has_annotations = true;
         let output = &mut self.value_writer.writer.output;
         for annotation in self.annotations {
             match annotation.as_raw_symbol_token_ref() {
@@ -154,20 +155,26 @@ pub(crate) struct TextContainerWriter_1_0<'a, W: Write> {
     // The depth at which this container's child values appear. This value is used for formatting
     // indentation where applicable.
     depth: usize,
+    // The Ion type of the container using this TextContainerWriter_1_0. Nested containers use
+    // this to determine their parent type.
+    container_type: ContainerType,
+    value_delimiter: &'static str,
     // Tracks whether the `end()` method was called (thereby emitting a closing delimiter) before
     // this value was dropped. This scenario is a contract violation and results in a panic.
     has_been_closed: bool,
-    // The enclosing context in which this container appears. (top level, list, sexp, struct)
-    parent_type: ParentType,
-    // The Ion type of the container using this TextContainerWriter_1_0. This value is only
-    // used for more informative error messages.
-    container_type: ContainerType,
-    value_delimiter: &'static str,
-    trailing_delimiter: &'static str,
+    // Whether any values have been written to this container. Each value after the first is
+    // preceded by the value delimiter; the last value is followed by it if the whitespace config
+    // calls for trailing delimiters.
+    has_values: bool,
 }
 
 impl<'a, W: Write> Drop for TextContainerWriter_1_0<'a, W> {
     fn drop(&mut self) {
+        // If a write inside this container failed, the caller is expected to abandon the
+        // container when the error is propagated; the output is incomplete either way.
+        if self.writer.value_in_progress || std::thread::panicking() {
+            return;
+        }
         // If the user didn't call `end`, the closing delimiter was not written to output.
         // It's too late to call it here because we can't return a `Result`.
         if !self.has_been_closed {
@@ -180,70 +187,67 @@ impl<'a, W: Write> Drop for TextContainerWriter_1_0<'a, W> {
 }
 
 impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
+    /// Writes the opening delimiter of a container. The caller is responsible for writing any
+    /// delimiter, indentation, or annotations that precede it.
     pub fn new(
         writer: &'a mut LazyRawTextWriter_1_0<W>,
         depth: usize,
-        parent_type: ParentType,
         container_type: ContainerType,
         opening_delimiter: &str,
         value_delimiter: &'static str,
-        trailing_delimiter: &'static str,
     ) -> IonResult<Self> {
-        let mut container_writer = Self {
+        let space_after_container_start = &writer.whitespace_config.space_after_container_start;
+        write!(
+            writer.output,
+            "{opening_delimiter}{space_after_container_start}"
+        )?;
+        // Until a value is written, the new container is in a consistent state.
+        writer.value_in_progress = false;
+        Ok(Self {
             writer,
             depth,
-            parent_type,
             container_type,
-            has_been_closed: false,
             value_delimiter,
-            trailing_delimiter,
-        };
-        if parent_type != ParentType::Struct {
-            container_writer.write_indentation(depth)?;
-        }
-        let space_after_container_start = container_writer
-            .writer
-            .whitespace_config
-            .space_after_container_start;
-        write!(
-            container_writer.writer.output,
-            "{opening_delimiter}{space_after_container_start}"
-        )?;
-        Ok(container_writer)
-    }
-
-    /// Writes the `indentation` string set in the whitespace config to output `depth` times.
-    fn write_indentation(&mut self, depth: usize) -> IonResult<()> {
-        let indentation = self.whitespace_config().indentation;
-        if !indentation.is_empty() {
-            for _ in 0..depth {
-                write!(self.output(), "{indentation}")?;
-            }
-        }
-        Ok(())
+            has_been_closed: false,
+            has_values: false,
+        })
     }
 
-    /// Writes the provided value to output using its implementation of `WriteAsIon`, then writes
-    /// the whitespace config's `space_between_nested_values`.
+    /// Writes the provided value to output using its implementation of `WriteAsIon`.
     fn write_value<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
-        self.write_indentation(self.depth)?;
         value.write_as_ion(self.value_writer())?;
         Ok(self)
     }
 
+    /// Writes the value delimiter and whitespace that separate the next value from the previous
+    /// one, if any.
+    fn write_value_separator(&mut self) -> IonResult<()> {
+        if self.has_values {
+            self.writer.write_value_separator(self.value_delimiter)?;
+        }
+        self.has_values = true;
+        Ok(())
+    }
+
     /// Finalizes the container, preventing further values from being written.
     pub fn close(mut self, closing_delimiter: &str) -> IonResult<()> {
-        let space_between = match self.depth {
-            0 => self.whitespace_config().space_between_top_level_values,
-            _ => self.whitespace_config().space_between_nested_values,
-        };
-        let trailing_delimiter = self.trailing_delimiter;
-        self.write_indentation(self.depth)?;
-        write!(
-            self.output(),
-            "{closing_delimiter}{trailing_delimiter}{space_between}"
-        )?;
         self.has_been_closed = true;
+        let writer = &mut *self.writer;
+        if self.has_values {
+            if writer.whitespace_config.trailing_delimiters {
+                writer.write_value_separator(self.value_delimiter)?;
+            } else {
+                let space_before_end = &writer.whitespace_config.space_before_container_end;
+                write!(writer.output, "{space_before_end}")?;
+            }
+        }
+        writer.write_indentation(self.depth)?;
+        write!(writer.output, "{closing_delimiter}")?;
+        if self.depth == 0 {
+            writer.write_top_level_space()?;
+        }
+        // The container is the value that was in progress in its parent.
+        writer.value_in_progress = false;
         Ok(())
     }
 
@@ -251,18 +255,18 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
         &mut self.writer.output
     }
 
-    fn whitespace_config(&self) -> &WhitespaceConfig {
-        self.writer.whitespace_config
-    }
-
     #[inline]
     pub fn value_writer(&mut self) -> TextValueWriter_1_0<'_, W> {
+        let follows_value = self.has_values;
+        self.has_values = true;
+        self.writer.value_in_progress = true;
         TextValueWriter_1_0 {
             writer: self.writer,
             depth: self.depth + 1,
             value_delimiter: self.value_delimiter,
             parent_type: self.container_type.into(),
             has_annotations: false,
+            follows_value,
         }
     }
 }
@@ -273,21 +277,9 @@ pub struct TextListWriter_1_0<'top, W: Write> {
 }
 
 impl<'top, W: Write> TextListWriter_1_0<'top, W> {
-    pub(crate) fn new(
-        writer: &'top mut LazyRawTextWriter_1_0<W>,
-        depth: usize,
-        parent_type: ParentType,
-        trailing_delimiter: &'static str,
-    ) -> IonResult<Self> {
-        let container_writer = TextContainerWriter_1_0::new(
-            writer,
-            depth,
-            parent_type,
-            ContainerType::List,
-            "[",
-            ",",
-            trailing_delimiter,
-        )?;
+    pub(crate) fn new(writer: &'top mut LazyRawTextWriter_1_0<W>, depth: usize) -> IonResult<Self> {
+        let container_writer =
+            TextContainerWriter_1_0::new(writer, depth, ContainerType::List, "[", ",")?;
         Ok(Self { container_writer })
     }
 
@@ -330,21 +322,9 @@ pub struct TextSExpWriter_1_0<'a, W: Write> {
 }
 
 impl<'a, W: Write> TextSExpWriter_1_0<'a, W> {
-    pub(crate) fn new(
-        writer: &'a mut LazyRawTextWriter_1_0<W>,
-        depth: usize,
-        parent_type: ParentType,
-        trailing_delimiter: &'static str,
-    ) -> IonResult<Self> {
-        let container_writer = TextContainerWriter_1_0::new(
-            writer,
-            depth,
-            parent_type,
-            ContainerType::SExp,
-            "(",
-            "",
-            trailing_delimiter,
-        )?;
+    pub(crate) fn new(writer: &'a mut LazyRawTextWriter_1_0<W>, depth: usize) -> IonResult<Self> {
+        let container_writer =
+            TextContainerWriter_1_0::new(writer, depth, ContainerType::SExp, "(", "")?;
         Ok(Self { container_writer })
     }
 
@@ -389,21 +369,9 @@ pub struct TextStructWriter_1_0<'a, W: Write> {
 }
 
 impl<'a, W: Write> TextStructWriter_1_0<'a, W> {
-    pub(crate) fn new(
-        writer: &'a mut LazyRawTextWriter_1_0<W>,
-        depth: usize,
-        parent_type: ParentType,
-        trailing_delimiter: &'static str,
-    ) -> IonResult<Self> {
-        let container_writer = TextContainerWriter_1_0::new(
-            writer,
-            depth,
-            parent_type,
-            ContainerType::Struct,
-            "{",
-            ",",
-            trailing_delimiter,
-        )?;
+    pub(crate) fn new(writer: &'a mut LazyRawTextWriter_1_0<W>, depth: usize) -> IonResult<Self> {
+        let container_writer =
+            TextContainerWriter_1_0::new(writer, depth, ContainerType::Struct, "{", ",")?;
         Ok(Self { container_writer })
     }
 
@@ -415,19 +383,26 @@ impl<'a, W: Write> TextStructWriter_1_0<'a, W> {
 
 impl<'a, W: Write> FieldEncoder for TextStructWriter_1_0<'a, W> {
     fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        // The field is in progress until its value has been written.
+        self.container_writer.writer.value_in_progress = true;
+        // Delimiter following the previous field, if any
+        self.container_writer.write_value_separator()?;
         // Leading indentation for the current depth
         self.container_writer
+            .writer
             .write_indentation(self.container_writer.depth + 1)?;
         // Write the field name
         write_symbol_token(self.container_writer.output(), name)?;
-        let space_after_field_name = self
-            .container_writer
-            .whitespace_config()
-            .space_after_field_name;
         // Write a `:` and configured trailing whitespace
-        write!(self.container_writer.output(), ":{space_after_field_name}",)?;
+        let writer = &mut *self.container_writer.writer;
+        let space_after_field_name = &writer.whitespace_config.space_after_field_name;
+        write!(writer.output, ":{space_after_field_name}",)?;
         Ok(())
     }
+
+    fn abandon(&mut self) {
+        self.container_writer.writer.value_in_progress = true;
+    }
 }
 
 impl<'value, W: Write> MakeValueWriter for TextStructWriter_1_0<'value, W> {
@@ -442,6 +417,8 @@ impl<'value, W: Write> MakeValueWriter for TextStructWriter_1_0<'value, W> {
             value_delimiter: ",",
             parent_type: ParentType::Struct,
             has_annotations: false,
+            // The field name was preceded by the delimiter
+            follows_value: false,
         }
     }
 }
@@ -639,29 +616,17 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
         self.write_delimiter_text()
     }
 
-    fn list_writer(self) -> IonResult<Self::ListWriter> {
-        TextListWriter_1_0::new(
-            self.writer,
-            self.depth,
-            self.parent_type,
-            self.value_delimiter,
-        )
-    }
-    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
-        TextSExpWriter_1_0::new(
-            self.writer,
-            self.depth,
-            self.parent_type,
-            self.value_delimiter,
-        )
-    }
-    fn struct_writer(self) -> IonResult<Self::StructWriter> {
-        TextStructWriter_1_0::new(
-            self.writer,
-            self.depth,
-            self.parent_type,
-            self.value_delimiter,
-        )
+    fn list_writer(mut self) -> IonResult<Self::ListWriter> {
+        self.write_indentation()?;
+        TextListWriter_1_0::new(self.writer, self.depth)
+    }
+    fn sexp_writer(mut self) -> IonResult<Self::SExpWriter> {
+        self.write_indentation()?;
+        TextSExpWriter_1_0::new(self.writer, self.depth)
+    }
+    fn struct_writer(mut self) -> IonResult<Self::StructWriter> {
+        self.write_indentation()?;
+        TextStructWriter_1_0::new(self.writer, self.depth)
     }
     fn eexp_writer<'a>(self, _macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
         IonResult::encoding_error("macros are not supported in Ion 1.0")
@@ -8,9 +8,7 @@ use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::{Encoding, TextEncoding_1_0};
-use crate::text::whitespace_config::{
-    WhitespaceConfig, COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
-};
+use crate::text::whitespace_config::WhitespaceConfig;
 use crate::types::ParentType;
 use crate::write_config::WriteConfigKind;
 use crate::{IonEncoding, IonResult, TextFormat, WriteConfig};
@@ -18,7 +16,12 @@ use crate::{IonEncoding, IonResult, TextFormat, WriteConfig};
 /// A raw text Ion 1.0 writer.
 pub struct LazyRawTextWriter_1_0<W: Write> {
     pub(crate) output: W,
-    pub(crate) whitespace_config: &'static WhitespaceConfig,
+    pub(crate) whitespace_config: WhitespaceConfig,
+    // Whether a value inside a container has been started but not completed, either because it
+    // is still being written or because writing it failed. A container writer that is dropped
+    // while this is set was abandoned after an error rather than forgotten; see
+    // `TextContainerWriter_1_0`.
+    pub(crate) value_in_progress: bool,
 }
 
 impl<W: Write> LazyRawTextWriter_1_0<W> {
@@ -39,6 +42,32 @@ impl<W: Write> LazyRawTextWriter_1_0<W> {
         Ok(())
     }
 
+    /// Writes the `indentation` string set in the whitespace config to output `depth` times.
+    pub(crate) fn write_indentation(&mut self, depth: usize) -> IonResult<()> {
+        let indentation = &self.whitespace_config.indentation;
+        if !indentation.is_empty() {
+            for _ in 0..depth {
+                write!(self.output, "{indentation}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the provided value delimiter followed by the whitespace config's
+    /// `space_between_nested_values`.
+    pub(crate) fn write_value_separator(&mut self, value_delimiter: &str) -> IonResult<()> {
+        let space_between = &self.whitespace_config.space_between_nested_values;
+        write!(self.output, "{value_delimiter}{space_between}")?;
+        Ok(())
+    }
+
+    /// Writes the whitespace config's `space_between_top_level_values`.
+    pub(crate) fn write_top_level_space(&mut self) -> IonResult<()> {
+        let space_between = &self.whitespace_config.space_between_top_level_values;
+        write!(self.output, "{space_between}")?;
+        Ok(())
+    }
+
     /// Helper method to construct this format's `ValueWriter` implementation.
     #[inline]
     fn value_writer(&mut self) -> TextValueWriter_1_0<'_, W> {
@@ -61,7 +90,8 @@ impl<W: Write> SequenceWriter for LazyRawTextWriter_1_0<W> {
 }
 
 impl<W: Write> MakeValueWriter for LazyRawTextWriter_1_0<W> {
-    type ValueWriter<'a> = TextValueWriter_1_0<'a, W>
+    type ValueWriter<'a>
+        = TextValueWriter_1_0<'a, W>
     where
         Self: 'a;
 
@@ -81,17 +111,11 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
     /// Build text writer based on given writer configuration
     fn build<E: Encoding>(config: WriteConfig<E>, output: W) -> IonResult<Self> {
         match &config.kind {
-            WriteConfigKind::Text(text_config) => {
-                let whitespace_config = match text_config.text_kind {
-                    TextFormat::Compact => &COMPACT_WHITESPACE_CONFIG,
-                    TextFormat::Lines => &LINES_WHITESPACE_CONFIG,
-                    TextFormat::Pretty => &PRETTY_WHITESPACE_CONFIG,
-                };
-                Ok(LazyRawTextWriter_1_0 {
-                    output,
-                    whitespace_config,
-                })
-            }
+            WriteConfigKind::Text(text_config) => Ok(LazyRawTextWriter_1_0 {
+                output,
+                whitespace_config: text_config.whitespace_config(),
+                value_in_progress: false,
+            }),
             WriteConfigKind::Binary(_) => {
                 unreachable!("Binary writer can not be created from text encoding")
             }
@@ -115,7 +139,7 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
     }
 
     fn write_version_marker(&mut self) -> IonResult<()> {
-        let space_between = self.whitespace_config.space_between_top_level_values;
+        let space_between = &self.whitespace_config.space_between_top_level_values;
         write!(self.output, "$ion_1_0{space_between}")?;
         Ok(())
     }
@@ -127,8 +151,13 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
-    use crate::{v1_1, Annotatable, ElementReader, IonData, IonResult, Reader, SequenceWriter};
+    use crate::{
+        v1_0, v1_1, Annotatable, Element, ElementReader, IonData, IonError, IonResult, LineEnding,
+        Reader, SequenceWriter, TextFormat, WriteConfig,
+    };
 
     #[test]
     fn write_annotated_values() -> IonResult<()> {
@@ -160,4 +189,69 @@ mod tests {
         assert!(IonData::eq(&expected, &actual));
         Ok(())
     }
+
+    const NESTED_DOCUMENT: &str = r#"a::{x: [1, b::(3 c::[4])], y: {}, z: d::e::{f: g}} 5"#;
+
+    #[rstest]
+    #[case::pretty_defaults(
+        v1_0::Text.with_format(TextFormat::Pretty),
+        "a::{\n  x: [\n    1,\n    b::(\n      3\n      c::[\n        4,\n      ]\n    ),\n  ],\n  y: {\n  },\n  z: d::e::{\n    f: g,\n  },\n}\n5\n"
+    )]
+    #[case::pretty_tabs_without_trailing_commas_or_field_spaces(
+        v1_0::Text
+            .with_format(TextFormat::Pretty)
+            .with_indentation("\t")
+            .unwrap()
+            .with_trailing_commas(false)
+            .with_space_after_field_name(false),
+        "a::{\n\tx:[\n\t\t1,\n\t\tb::(\n\t\t\t3\n\t\t\tc::[\n\t\t\t\t4\n\t\t\t]\n\t\t)\n\t],\n\ty:{\n\t},\n\tz:d::e::{\n\t\tf:g\n\t}\n}\n5\n"
+    )]
+    #[case::pretty_four_spaces_crlf(
+        v1_0::Text
+            .with_format(TextFormat::Pretty)
+            .with_indentation("    ")
+            .unwrap()
+            .with_line_ending(LineEnding::CrLf),
+        "a::{\r\n    x: [\r\n        1,\r\n        b::(\r\n            3\r\n            c::[\r\n                4,\r\n            ]\r\n        ),\r\n    ],\r\n    y: {\r\n    },\r\n    z: d::e::{\r\n        f: g,\r\n    },\r\n}\r\n5\r\n"
+    )]
+    #[case::compact_without_trailing_commas(
+        v1_0::Text
+            .with_format(TextFormat::Compact)
+            .with_trailing_commas(false)
+            // Compact text does not indent nested values
+            .with_indentation("\t")
+            .unwrap(),
+        "a::{x: [1, b::(3 c::[4])], y: {}, z: d::e::{f: g}} 5 "
+    )]
+    #[case::lines_crlf_without_field_spaces(
+        v1_0::Text
+            .with_format(TextFormat::Lines)
+            .with_space_after_field_name(false)
+            .with_line_ending(LineEnding::CrLf),
+        "a::{x:[1, b::(3 c::[4, ] ), ], y:{}, z:d::e::{f:g, }, }\r\n5\r\n"
+    )]
+    fn write_with_text_options(
+        #[case] config: WriteConfig<v1_0::Text>,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let elements = Element::read_all(NESTED_DOCUMENT)?;
+        let text = elements.encode_as(config)?;
+        assert_eq!(text, expected);
+        // Each rendering must be Ion-equal to the original document
+        let actual = Element::read_all(&text)?;
+        assert!(IonData::eq(&elements, &actual));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::letter("x")]
+    #[case::comment("//")]
+    #[case::newline("\n")]
+    #[case::mixed_with_spaces("  x")]
+    fn indentation_must_be_whitespace(#[case] indentation: &str) {
+        let result = v1_0::Text
+            .with_format(TextFormat::Pretty)
+            .with_indentation(indentation);
+        assert!(matches!(result, Err(IonError::IllegalOperation(_))));
+    }
 }
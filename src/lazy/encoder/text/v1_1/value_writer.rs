@@ -11,7 +11,7 @@ use crate::lazy::encoder::value_writer::{
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
-use crate::types::{ContainerType, ParentType};
+use crate::types::ContainerType;
 use crate::{v1_1, Decimal, Encoding, Int, IonResult, IonType, Timestamp, ValueWriterConfig};
 use delegate::delegate;
 use std::io::Write;
@@ -83,19 +83,16 @@ impl<'value, W: Write + 'value> ValueWriter for TextValueWriter_1_1<'value, W> {
     }
 
     fn eexp_writer<'a>(self, macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
+        let mut value_writer_1_0 = self.value_writer_1_0;
+        value_writer_1_0.write_indentation()?;
         TextEExpWriter_1_1::new(
-            self.value_writer_1_0.writer,
-            self.value_writer_1_0.depth,
-            self.value_writer_1_0.parent_type,
+            value_writer_1_0.writer,
+            value_writer_1_0.depth,
             // Pretend we're in a sexp for syntax purposes
             ContainerType::SExp,
             // TODO: Reusable buffer
             format!("(:{}", macro_id.into()).as_str(),
-            " ",
-            match self.value_writer_1_0.parent_type {
-                ParentType::Struct | ParentType::List => ",",
-                _ => "",
-            },
+            "",
         )
     }
 }
@@ -214,6 +211,10 @@ impl<'value, W: Write> FieldEncoder for TextStructWriter_1_1<'value, W> {
     fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
         self.writer_1_0.encode_field_name(name)
     }
+
+    fn abandon(&mut self) {
+        self.writer_1_0.abandon()
+    }
 }
 
 impl<'value, W: Write> MakeValueWriter for TextStructWriter_1_1<'value, W> {
@@ -240,28 +241,28 @@ pub struct TextEExpWriter_1_1<'value, W: Write> {
     // There is no e-exp writer in 1.0 to which we can delegate,
     // but we can re-use the TextContainerWriter_1_0 for a lot of the formatting.
     container_writer: TextContainerWriter_1_0<'value, W>,
+    has_args: bool,
 }
 
 impl<'value, W: Write> TextEExpWriter_1_1<'value, W> {
     pub(crate) fn new(
         writer: &'value mut LazyRawTextWriter_1_0<W>,
         depth: usize,
-        parent_type: ParentType,
         container_type: ContainerType,
         opening_delimiter: &str,
         value_delimiter: &'static str,
-        trailing_delimiter: &'static str,
     ) -> IonResult<Self> {
         let container_writer = TextContainerWriter_1_0::new(
             writer,
             depth,
-            parent_type,
             container_type,
             opening_delimiter,
             value_delimiter,
-            trailing_delimiter,
         )?;
-        Ok(Self { container_writer })
+        Ok(Self {
+            container_writer,
+            has_args: false,
+        })
     }
 }
 
@@ -279,9 +280,21 @@ impl<'value, W: Write + 'value> MakeValueWriter for TextEExpWriter_1_1<'value, W
         Self: 'a,;
 
     fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
-        TextValueWriter_1_1 {
-            value_writer_1_0: self.container_writer.value_writer(),
+        let is_first_arg = !self.has_args;
+        self.has_args = true;
+        let mut value_writer_1_0 = self.container_writer.value_writer();
+        // Unlike an opening delimiter, the macro ID must be separated from the first argument. If
+        // the whitespace config doesn't put any space there, write a value separator instead.
+        if is_first_arg
+            && value_writer_1_0
+                .writer
+                .whitespace_config
+                .space_after_container_start
+                .is_empty()
+        {
+            value_writer_1_0.follows_value = true;
         }
+        TextValueWriter_1_1 { value_writer_1_0 }
     }
 }
 
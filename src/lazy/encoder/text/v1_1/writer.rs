@@ -6,9 +6,6 @@ use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::{Encoding, TextEncoding_1_1};
-use crate::text::whitespace_config::{
-    COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
-};
 use crate::write_config::WriteConfigKind;
 use crate::{IonEncoding, IonResult, TextFormat, WriteConfig};
 
@@ -56,11 +53,7 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
     {
         match &config.kind {
             WriteConfigKind::Text(text_config) => {
-                let whitespace_config = match text_config.text_kind {
-                    TextFormat::Compact => &COMPACT_WHITESPACE_CONFIG,
-                    TextFormat::Lines => &LINES_WHITESPACE_CONFIG,
-                    TextFormat::Pretty => &PRETTY_WHITESPACE_CONFIG,
-                };
+                let whitespace_config = text_config.whitespace_config();
                 write!(
                     output,
                     "$ion_1_1{}",
@@ -70,6 +63,7 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
                     writer_1_0: LazyRawTextWriter_1_0 {
                         output,
                         whitespace_config,
+                        value_in_progress: false,
                     },
                 })
             }
@@ -92,7 +86,7 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
     }
 
     fn write_version_marker(&mut self) -> IonResult<()> {
-        let space_between = self
+        let space_between = &self
             .writer_1_0
             .whitespace_config
             .space_between_top_level_values;
@@ -108,7 +102,9 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
 #[cfg(test)]
 mod tests {
     use crate::lazy::any_encoding::IonVersion;
-    use crate::lazy::decoder::{LazyRawReader, LazyRawSequence, LazyRawValue};
+    use std::ops::Range;
+
+    use crate::lazy::decoder::{HasRange, HasSpan, LazyRawReader, LazyRawSequence, LazyRawValue};
     use crate::lazy::encoder::text::v1_1::writer::LazyRawTextWriter_1_1;
     use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
     use crate::lazy::encoder::write_as_ion::WriteAsSExp;
@@ -116,11 +112,12 @@ mod tests {
     use crate::lazy::expanded::compiler::TemplateCompiler;
     use crate::lazy::expanded::macro_evaluator::RawEExpression;
     use crate::lazy::expanded::EncodingContext;
+    use crate::lazy::raw_stream_item::RawStreamItem;
     use crate::lazy::text::raw::v1_1::reader::{LazyRawTextReader_1_1, MacroIdRef};
     use crate::symbol_ref::AsSymbolRef;
     use crate::{
         v1_1, Annotatable, Decimal, ElementReader, IonData, IonResult, IonType, Null, RawSymbolRef,
-        Reader, Timestamp,
+        Reader, TextFormat, Timestamp, WriteConfig,
     };
 
     #[test]
@@ -323,6 +320,85 @@ mod tests {
         Ok(())
     }
 
+    /// Returns the range of the first occurrence of `text` in `data`.
+    fn range_of(data: &str, text: &str) -> Range<usize> {
+        let start = data.find(text).expect("text not found in data");
+        start..start + text.len()
+    }
+
+    #[test]
+    fn write_eexps_with_varying_numbers_of_args() -> IonResult<()> {
+        let mut context = EncodingContext::for_ion_version(IonVersion::v1_1);
+        for template in [
+            "(macro none () null)",
+            "(macro one (a) a)",
+            "(macro three (a b c) [a, b, c])",
+        ] {
+            let template = TemplateCompiler::compile_from_text(context.get_ref(), template)?;
+            context.macro_table.add_macro(template)?;
+        }
+        let one_address = context.macro_table.address_for_name("one").unwrap();
+
+        let config = WriteConfig::<v1_1::Text>::new(TextFormat::Compact);
+        let mut writer = LazyRawTextWriter_1_1::build(config, vec![])?;
+        writer.eexp_writer("none")?.close()?;
+        let mut args = writer.eexp_writer(one_address)?;
+        args.write(1)?;
+        args.close()?;
+        let mut args = writer.eexp_writer("three")?;
+        args.write("a")?;
+        let mut nested_args = args.eexp_writer("one")?;
+        nested_args.write(2)?;
+        nested_args.close()?;
+        args.write_symbol("c")?;
+        args.close()?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        let context = context.get_ref();
+        let mut reader = LazyRawTextReader_1_1::new(encoded_text.as_bytes());
+        let _marker = reader.next(context)?.expect_ivm()?;
+
+        let eexp = reader.next(context)?.expect_eexp()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalName("none"));
+        assert_eq!(eexp.span().bytes(), b"(:none)");
+        assert!(eexp.raw_arguments().next().is_none());
+
+        let eexp = reader.next(context)?.expect_eexp()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(one_address));
+        let invocation = format!("(:{one_address} 1 )");
+        assert_eq!(eexp.range(), range_of(&encoded_text, &invocation));
+        let mut args = eexp.raw_arguments();
+        let arg = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_i64()?, 1);
+        assert_eq!(arg.span().bytes(), b"1");
+        assert!(args.next().is_none());
+
+        let eexp = reader.next(context)?.expect_eexp()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalName("three"));
+        assert_eq!(
+            eexp.range(),
+            range_of(&encoded_text, r#"(:three "a" (:one 2 ) c )"#)
+        );
+        let mut args = eexp.raw_arguments();
+        let arg = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_string()?.text(), "a");
+        let nested = args.next().unwrap()?.expr().expect_eexp()?;
+        assert_eq!(nested.id(), MacroIdRef::LocalName("one"));
+        assert_eq!(nested.range(), range_of(&encoded_text, "(:one 2 )"));
+        let mut nested_args = nested.raw_arguments();
+        let arg = nested_args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_i64()?, 2);
+        assert!(nested_args.next().is_none());
+        let arg = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(arg.read()?.expect_symbol()?, RawSymbolRef::Text("c"));
+        assert!(args.next().is_none());
+
+        assert!(matches!(
+            reader.next(context)?,
+            RawStreamItem::EndOfStream(_)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn write_annotated_values() -> IonResult<()> {
         const NO_ANNOTATIONS: [&str; 0] = [];
@@ -28,6 +28,12 @@ pub mod internal {
         /// For text implementations, this typically includes indentation, a symbol token representing
         /// the field name itself, and the delimiting `:`.
         fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()>;
+
+        /// Notifies the encoder that the struct will not be completed because of an error that
+        /// was detected before reaching it (for example, a field name that was rejected by a
+        /// higher-level writer). Encoders that check whether their containers were closed use this
+        /// to tell an abandoned struct from one that was never closed.
+        fn abandon(&mut self) {}
     }
 }
 
@@ -394,6 +400,9 @@ pub trait SequenceWriter: MakeValueWriter {
     /// Closes out the sequence being written. Delimited writers can use this opportunity to emit
     /// a sentinel value, and length-prefixed writers can flush any buffered data to the output
     /// buffer.
+    ///
+    /// For top-level writers, `Resources` is the output sink; closing the writer performs a final
+    /// flush and returns it.
     fn close(self) -> IonResult<Self::Resources>;
 
     // Creates functions that delegate to the ValueWriter method of the same name but which then
@@ -12,6 +12,10 @@ pub struct ValueWriterConfig {
     annotations_encoding: AnnotationsEncoding,
     // If this writer emits a struct, the struct will encode its field names according to this setting.
     field_name_encoding: FieldNameEncoding,
+    // How symbol values, field names, and annotations with unknown text should be written.
+    unknown_text_symbol_policy: UnknownTextSymbolPolicy,
+    // Whether fields from unordered collections should be sorted by name before they are written.
+    sorts_fields: bool,
 }
 
 /// Configuration options for encoding containers.
@@ -77,6 +81,77 @@ pub enum FieldNameEncoding {
     WriteNewSymbolsAsInlineText,
 }
 
+/// Configuration options for writing a symbol whose text is unknown (for example, `$0`).
+///
+/// The policy applies to symbol values, field names, and annotations alike.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[non_exhaustive]
+pub enum UnknownTextSymbolPolicy {
+    /// Writing a symbol with unknown text will return an `Err` that names the position
+    /// (symbol value, field name, or annotation) in which it appeared.
+    Error,
+    /// The symbol will be written using its symbol ID: binary writers will encode the symbol ID
+    /// and text writers will emit `$<sid>`. This preserves symbols that were read from symbol IDs
+    /// beyond the end of the source stream's symbol table (see
+    /// [`UnresolvedSymbolIdPolicy::UnknownText`](crate::UnresolvedSymbolIdPolicy::UnknownText)).
+    ///
+    /// A symbol ID beyond the end of the writer's own symbol table is written as-is, and the
+    /// writer will not assign new text to it. If text is later added to the symbol table, any such
+    /// IDs that it reaches are defined without text, making them equivalent to `$0`. A symbol ID
+    /// more than 2^20 past the end of the writer's symbol table cannot be reserved this way;
+    /// writing one will return an `Err`.
+    ///
+    /// The output does not define the text of such symbol IDs, so reading it requires a reader
+    /// whose [`UnresolvedSymbolIdPolicy`](crate::UnresolvedSymbolIdPolicy) tolerates them.
+    WriteSymbolId,
+    /// Binary writers will encode the symbol as symbol ID `0`. Text writers will emit `$0`.
+    #[default]
+    WriteSidZero,
+    /// The symbol will be written as though it had the provided text.
+    WritePlaceholderText(&'static str),
+}
+
+/// Configures how an application writer handles a symbol value, field name, or annotation whose
+/// text is not already in its symbol table.
+///
+/// The policy does not apply to symbols that a value writer has been configured to write as
+/// inline text.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[non_exhaustive]
+pub enum NewSymbolPolicy {
+    /// The text will be added to the symbol table.
+    #[default]
+    AddToSymbolTable,
+    /// Writing text that is not in the symbol table will return an `Err` that names the position
+    /// (symbol value, field name, or annotation) in which it appeared.
+    ErrorOnNewSymbols,
+}
+
+/// Configures when an Ion 1.1 application writer adds the text of a symbol value, field name, or
+/// annotation to its symbol table instead of writing the text inline.
+///
+/// The policy only applies to text that is not already in the symbol table and that a value
+/// writer has been configured to write as a symbol ID, which is the default for binary Ion 1.1.
+/// Binary Ion 1.0 cannot encode symbol text inline, so Ion 1.0 writers ignore this setting.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[non_exhaustive]
+pub enum SymbolInterningPolicy {
+    /// The text will be added to the symbol table the first time it is written.
+    #[default]
+    AlwaysIntern,
+    /// The text will be written inline the first `intern_after` times it is written. After that,
+    /// it will be added to the symbol table. This avoids growing the symbol table with text that
+    /// only appears once, like the field names of mostly-unique records.
+    ///
+    /// To keep memory use bounded, the writer counts the uses of at most 4096 distinct pieces
+    /// of text totalling at most 1 MiB. When more text than that is waiting to be interned, all
+    /// of the counts are discarded and counting starts over, so text that recurs only rarely
+    /// among many unique strings may continue to be written inline.
+    InlineOnce { intern_after: usize },
+    /// The text will always be written inline and will never be added to the symbol table.
+    NeverIntern,
+}
+
 impl ValueWriterConfig {
     /// Constructs a `ValueWriterConfig` that writes all symbol tokens as inline text.
     pub const fn text() -> Self {
@@ -85,6 +160,8 @@ impl ValueWriterConfig {
             symbol_value_encoding: SymbolValueEncoding::WriteAsInlineText,
             annotations_encoding: AnnotationsEncoding::WriteAsInlineText,
             field_name_encoding: FieldNameEncoding::WriteAsInlineText,
+            unknown_text_symbol_policy: UnknownTextSymbolPolicy::WriteSidZero,
+            sorts_fields: false,
         }
     }
 
@@ -95,6 +172,8 @@ impl ValueWriterConfig {
             symbol_value_encoding: SymbolValueEncoding::WriteAsSymbolIds,
             annotations_encoding: AnnotationsEncoding::WriteAsSymbolIds,
             field_name_encoding: FieldNameEncoding::WriteAsSymbolIds,
+            unknown_text_symbol_policy: UnknownTextSymbolPolicy::WriteSidZero,
+            sorts_fields: false,
         }
     }
 
@@ -114,6 +193,16 @@ impl ValueWriterConfig {
         self.annotations_encoding
     }
 
+    pub const fn unknown_text_symbol_policy(&self) -> UnknownTextSymbolPolicy {
+        self.unknown_text_symbol_policy
+    }
+
+    /// Returns `true` if struct fields that come from an unordered collection (like a `HashMap`)
+    /// will be sorted by field name before they are written.
+    pub const fn sorts_fields(&self) -> bool {
+        self.sorts_fields
+    }
+
     /// Returns `true` if this value writer will write nested containers with a delimited encoding.
     pub const fn has_delimited_containers(&self) -> bool {
         matches!(self.container_encoding, ContainerEncoding::Delimited)
@@ -161,4 +250,21 @@ impl ValueWriterConfig {
         self.field_name_encoding = field_name_encoding;
         self
     }
+
+    /// Configures how this value writer will handle symbol values, field names, and annotations
+    /// whose text is unknown.
+    pub const fn with_unknown_text_symbol_policy(
+        mut self,
+        unknown_text_symbol_policy: UnknownTextSymbolPolicy,
+    ) -> Self {
+        self.unknown_text_symbol_policy = unknown_text_symbol_policy;
+        self
+    }
+
+    /// Configures whether struct fields that come from an unordered collection (like a `HashMap`)
+    /// will be sorted by field name before they are written.
+    pub const fn with_sorted_fields(mut self, sorts_fields: bool) -> Self {
+        self.sorts_fields = sorts_fields;
+        self
+    }
 }
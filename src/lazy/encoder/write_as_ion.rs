@@ -14,9 +14,12 @@
 //! that uses an empty annotations sequence. A custom annotations sequence can be set on a per-value
 //! basis by using the [`annotate`](crate::lazy::encoder::annotate::Annotatable::annotated_with) method
 //! provided by the [`Annotate`](crate::lazy::encoder::annotate::Annotatable) trait.
+use std::collections::{BTreeMap, HashMap};
 use std::io;
 use std::marker::PhantomData;
 
+use smallvec::SmallVec;
+
 use crate::lazy::decoder::{Decoder, LazyRawValueExpr, RawValueExpr};
 use crate::lazy::encoder::annotation_seq::AnnotationsVec;
 use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
@@ -25,6 +28,7 @@ use crate::lazy::expanded::macro_evaluator::RawEExpression;
 use crate::lazy::text::raw::v1_1::arg_group::{EExpArg, EExpArgExpr};
 use crate::lazy::value::LazyValue;
 use crate::lazy::value_ref::ValueRef;
+use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::v1_0::RawValueRef;
 use crate::{
     Blob, Clob, Decimal, Element, Int, IonResult, IonType, LazyList, LazyRawFieldExpr,
@@ -77,13 +81,13 @@ pub trait WriteAsIon {
     }
 }
 
-impl WriteAsIon for &Element {
+impl WriteAsIon for Element {
     fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
         if self.annotations().is_empty() {
             self.value().write_as_ion(writer)
         } else {
             self.value()
-                .write_as_ion(writer.with_annotations(self.annotations().as_ref())?)
+                .write_as_ion(writer.with_annotations(self.annotations())?)
         }
     }
 }
@@ -120,6 +124,8 @@ macro_rules! impl_write_as_ion_value {
 //       either as blobs or as lists of integers. We should use the same trick that `SExpTypeHint`
 //       employs to make it possible for users to override the default blob serialization by writing:
 //           writer.write(&[1u8, 2, 3].as_list())
+//       Until then, every sequence of `u8`s (`&[u8]`, `[u8; N]`, and `Vec<u8>`) is written as a
+//       blob. To write a list of byte-sized integers, convert the bytes to a wider integer type.
 impl_write_as_ion_value!(
     Null => write_null with self as self.0,
     bool => write_bool with self as *self,
@@ -140,6 +146,7 @@ impl_write_as_ion_value!(
     &str => write_string,
     String => write_string,
     &[u8] => write_blob,
+    Vec<u8> => write_blob,
     Blob => write_blob,
     Clob => write_clob,
 );
@@ -270,17 +277,59 @@ impl WriteAsIon for Value {
             Blob(b) => value_writer.write_blob(b),
             List(l) => value_writer.write_list(l),
             SExp(s) => value_writer.write_sexp(s),
-            Struct(s) => value_writer.write_struct(s.iter()),
+            Struct(s) => {
+                let mut struct_writer = value_writer.struct_writer()?;
+                if struct_writer.config().sorts_fields() {
+                    let fields = s
+                        .sorted_fields()
+                        .into_iter()
+                        .map(|(name, value)| (name, value));
+                    struct_writer.write_all(fields)?;
+                } else {
+                    struct_writer.write_all(s.iter())?;
+                }
+                struct_writer.close()
+            }
+        }
+    }
+}
+
+/// Writes the map as a struct. A `HashMap`'s iteration order can vary from one process to the
+/// next; if the writer has been configured to sort fields, its entries are sorted by key.
+impl<K: AsRawSymbolRef, V: WriteAsIon, S> WriteAsIon for HashMap<K, V, S> {
+    fn write_as_ion<W: ValueWriter>(&self, value_writer: W) -> IonResult<()> {
+        let mut struct_writer = value_writer.struct_writer()?;
+        if struct_writer.config().sorts_fields() {
+            let mut fields: Vec<_> = self.iter().collect();
+            fields.sort_by(|(name1, _), (name2, _)| {
+                name1
+                    .as_raw_symbol_token_ref()
+                    .cmp(&name2.as_raw_symbol_token_ref())
+            });
+            struct_writer.write_all(fields)?;
+        } else {
+            struct_writer.write_all(self)?;
         }
+        struct_writer.close()
+    }
+}
+
+/// Writes the map as a struct. Its fields are written in the map's iteration order; that is,
+/// sorted by key.
+impl<K: AsRawSymbolRef, V: WriteAsIon> WriteAsIon for BTreeMap<K, V> {
+    fn write_as_ion<W: ValueWriter>(&self, value_writer: W) -> IonResult<()> {
+        let mut struct_writer = value_writer.struct_writer()?;
+        struct_writer.write_all(self)?;
+        struct_writer.close()
     }
 }
 
 impl<'a, D: Decoder> WriteAsIon for LazyValue<'a, D> {
     fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
         if self.has_annotations() {
-            let mut annotations = AnnotationsVec::new();
+            let mut annotations: SmallVec<[SymbolRef; 2]> = SmallVec::new();
             for annotation in self.annotations() {
-                annotations.push(annotation?.into());
+                annotations.push(annotation?);
             }
             self.read()?
                 .write_as_ion(writer.with_annotations(annotations)?)
@@ -529,6 +578,8 @@ impl<'top, D: Decoder> WriteAsIon for LazyStruct<'top, D> {
     }
 }
 
+/// Writes the contained value if there is one. Otherwise, writes an untyped `null`; the Ion type
+/// that `T` would have been written as is not known.
 impl<T: WriteAsIon> WriteAsIon for Option<T> {
     fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
         if let Some(value) = self {
@@ -538,3 +589,65 @@ impl<T: WriteAsIon> WriteAsIon for Option<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use rstest::rstest;
+
+    use crate::{
+        ion_list, ion_struct, v1_0, Element, Encoding, IonResult, IonType, WriteAsIon, WriteConfig,
+    };
+
+    #[rstest]
+    #[case::binary(v1_0::Binary)]
+    #[case::text(v1_0::Text)]
+    fn write_nested_collections<E: Encoding>(#[case] encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let value: Vec<HashMap<String, Option<i64>>> = vec![
+            HashMap::from([("a".to_owned(), Some(1)), ("b".to_owned(), None)]),
+            HashMap::new(),
+            HashMap::from([("c".to_owned(), Some(-3))]),
+        ];
+        let encoded = value.encode_as(WriteConfig::from(encoding))?;
+        let expected = ion_list![
+            ion_struct! { "a": 1, "b": Element::null(IonType::Null) },
+            ion_struct! {},
+            ion_struct! { "c": -3 },
+        ];
+        assert_eq!(Element::read_one(encoded)?, Element::from(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn write_btree_map_in_key_order() -> IonResult<()> {
+        let value = BTreeMap::from([("c", vec![3]), ("a", vec![1, 1]), ("b", vec![])]);
+        let encoded = value.encode_as(v1_0::Text)?;
+        assert_eq!(encoded.trim(), "{a: [1, 1, ], b: [], c: [3, ], }");
+        Ok(())
+    }
+
+    #[test]
+    fn byte_sequences_are_blobs() -> IonResult<()> {
+        let bytes = vec![1u8, 2, 3];
+        let expected = Element::blob([1u8, 2, 3]);
+        for encoded in [
+            bytes.encode_as(v1_0::Binary)?,
+            bytes.as_slice().encode_as(v1_0::Binary)?,
+            [1u8, 2, 3].encode_as(v1_0::Binary)?,
+        ] {
+            assert_eq!(Element::read_one(encoded)?, expected);
+        }
+        // Wider integer types are written as lists.
+        let ints: Vec<u16> = bytes.iter().copied().map(u16::from).collect();
+        let encoded = ints.encode_as(v1_0::Binary)?;
+        assert_eq!(
+            Element::read_one(encoded)?,
+            Element::from(ion_list![1, 2, 3])
+        );
+        Ok(())
+    }
+}
@@ -2,8 +2,10 @@ use std::io::Write;
 
 use delegate::delegate;
 use ice_code::ice as cold_path;
+use rustc_hash::FxHashMap;
 
 use crate::constants::v1_0::system_symbol_ids;
+use crate::lazy::any_encoding::IonVersion;
 use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
 use crate::lazy::encoder::binary::v1_1::value_writer::BinaryValueWriter_1_1;
 use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
@@ -11,8 +13,8 @@ use crate::lazy::encoder::value_writer::{
     AnnotatableWriter, EExpWriter, FieldWriter, SequenceWriter, StructWriter, ValueWriter,
 };
 use crate::lazy::encoder::value_writer_config::{
-    AnnotationsEncoding, ContainerEncoding, FieldNameEncoding, SymbolValueEncoding,
-    ValueWriterConfig,
+    AnnotationsEncoding, ContainerEncoding, FieldNameEncoding, NewSymbolPolicy,
+    SymbolInterningPolicy, SymbolValueEncoding, UnknownTextSymbolPolicy, ValueWriterConfig,
 };
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
@@ -25,13 +27,38 @@ use crate::result::IonFailure;
 use crate::write_config::WriteConfig;
 use crate::{
     Decimal, Element, ElementWriter, Int, IonResult, IonType, MacroTable, RawSymbolRef, Symbol,
-    SymbolTable, Timestamp, UInt, Value,
+    SymbolId, SymbolTable, Timestamp, UInt, Value,
 };
 
+// The maximum distance beyond the end of the symbol table at which a symbol with unknown text may be
+// written using its symbol ID. See `UnknownTextSymbolPolicy::WriteSymbolId`.
+const MAX_RESERVED_UNKNOWN_TEXT_SIDS: usize = 1 << 20;
+
+// The most distinct pieces of text, and the most bytes of text, for which a writer using
+// `SymbolInterningPolicy::InlineOnce` will count inline uses. When either limit would be
+// exceeded, all of the counts are discarded.
+const MAX_INLINE_TEXT_USES_ENTRIES: usize = 4096;
+const MAX_INLINE_TEXT_USES_BYTES: usize = 1 << 20;
+
 pub(crate) struct WriterContext {
     symbol_table: SymbolTable,
     macro_table: MacroTable,
     num_pending_symbols: usize,
+    new_symbol_policy: NewSymbolPolicy,
+    symbol_interning_policy: SymbolInterningPolicy,
+    // When the policy is `InlineOnce`, the number of times each piece of text that has not yet
+    // been interned has been written inline.
+    inline_text_uses: FxHashMap<String, usize>,
+    // The total length of the text used as keys in `inline_text_uses`.
+    inline_text_uses_bytes: usize,
+    // The largest symbol ID beyond the end of the symbol table that has been written as a symbol
+    // with unknown text, or `0` if there is none. New text is never assigned this ID or any
+    // lower one.
+    highest_unknown_text_sid: SymbolId,
+    // The number of symbol IDs occupied by the shared symbol tables that the local symbol table
+    // imports. They follow the system symbols; `symbol_table` holds only the system and local
+    // symbols, so a local symbol's ID in the output is offset by this amount.
+    num_imported_symbols: usize,
 }
 
 impl WriterContext {
@@ -40,17 +67,259 @@ impl WriterContext {
             symbol_table,
             macro_table,
             num_pending_symbols: 0,
+            new_symbol_policy: NewSymbolPolicy::default(),
+            symbol_interning_policy: SymbolInterningPolicy::default(),
+            inline_text_uses: FxHashMap::default(),
+            inline_text_uses_bytes: 0,
+            highest_unknown_text_sid: 0,
+            num_imported_symbols: 0,
+        }
+    }
+
+    /// Returns the number of symbol IDs defined by the system symbols, the imported symbols, and
+    /// the local symbols combined. The next local symbol will be assigned this ID.
+    fn num_sids(&self) -> usize {
+        self.symbol_table.len() + self.num_imported_symbols
+    }
+
+    /// Returns `true` if `sid` belongs to one of the shared symbol tables that the local symbol
+    /// table imports.
+    fn sid_is_imported(&self, sid: SymbolId) -> bool {
+        let system_len = self.symbol_table.system_len();
+        sid >= system_len && sid - system_len < self.num_imported_symbols
+    }
+
+    /// Returns the ID that `text` is written as if it is in the symbol table.
+    fn sid_for(&self, text: &str) -> Option<SymbolId> {
+        let sid = self.symbol_table.sid_for(&text)?;
+        if sid < self.symbol_table.system_len() {
+            return Some(sid);
+        }
+        Some(sid + self.num_imported_symbols)
+    }
+
+    /// Returns the text of `sid` if it is a system or local symbol with known text. The text of
+    /// imported symbols is not known to the writer.
+    fn text_for(&self, sid: SymbolId) -> Option<&str> {
+        if sid < self.symbol_table.system_len() {
+            return self.symbol_table.text_for(sid);
+        }
+        let local_sid = sid.checked_sub(self.num_imported_symbols)?;
+        if local_sid < self.symbol_table.system_len() {
+            // `sid` is imported.
+            return None;
+        }
+        self.symbol_table.text_for(local_sid)
+    }
+
+    /// Returns `true` if `sid` may be written; that is, if it is a system, imported, or local
+    /// symbol or has been written as a symbol with unknown text.
+    fn sid_is_writable(&self, sid: SymbolId) -> bool {
+        sid < self.num_sids() || sid <= self.highest_unknown_text_sid
+    }
+
+    /// Adds `text` to the symbol table and returns its new symbol ID. If the writer's
+    /// [`NewSymbolPolicy`] is [`NewSymbolPolicy::ErrorOnNewSymbols`], returns an `Err` naming the
+    /// `position` (for example, "field name") in which the text appeared instead.
+    fn add_new_symbol(&mut self, position: &str, text: &str) -> IonResult<SymbolId> {
+        if self.new_symbol_policy == NewSymbolPolicy::ErrorOnNewSymbols {
+            return cold_path!(IonResult::encoding_error(format!(
+                "cannot write a {position} with new text ('{text}'); the writer's new symbol policy is `ErrorOnNewSymbols`"
+            )));
+        }
+        // Symbol IDs that have already been written as symbols with unknown text cannot be given
+        // text. If any of them are not yet in the symbol table, fill them with placeholders.
+        while self.num_sids() <= self.highest_unknown_text_sid {
+            self.symbol_table.add_placeholder();
+            self.num_pending_symbols += 1;
+        }
+        self.num_pending_symbols += 1;
+        Ok(self.symbol_table.add_symbol_for_text(text) + self.num_imported_symbols)
+    }
+
+    /// Returns the token to write for `text`, which is not in the symbol table. Depending on the
+    /// writer's [`SymbolInterningPolicy`], the text is either added to the symbol table and its
+    /// new symbol ID is returned, or the text itself is returned so it can be written inline.
+    /// See [`add_new_symbol`](Self::add_new_symbol) for the meaning of `position`.
+    fn intern_or_inline<'a>(
+        &mut self,
+        position: &str,
+        text: &'a str,
+    ) -> IonResult<RawSymbolRef<'a>> {
+        match self.symbol_interning_policy {
+            SymbolInterningPolicy::AlwaysIntern => {}
+            SymbolInterningPolicy::NeverIntern => return Ok(RawSymbolRef::Text(text)),
+            SymbolInterningPolicy::InlineOnce { intern_after } => {
+                let uses = self.inline_text_uses.get(text).copied().unwrap_or(0);
+                if uses < intern_after {
+                    self.count_inline_use(text, uses + 1);
+                    return Ok(RawSymbolRef::Text(text));
+                }
+                if self.inline_text_uses.remove(text).is_some() {
+                    self.inline_text_uses_bytes -= text.len();
+                }
+            }
+        }
+        Ok(RawSymbolRef::SymbolId(self.add_new_symbol(position, text)?))
+    }
+
+    /// Records that `text` has now been written inline `uses` times. If `text` was not already
+    /// being counted and counting it would exceed the limits on how much text is tracked, the
+    /// existing counts are discarded first.
+    fn count_inline_use(&mut self, text: &str, uses: usize) {
+        if let Some(count) = self.inline_text_uses.get_mut(text) {
+            *count = uses;
+            return;
+        }
+        if self.inline_text_uses.len() >= MAX_INLINE_TEXT_USES_ENTRIES
+            || self.inline_text_uses_bytes + text.len() > MAX_INLINE_TEXT_USES_BYTES
+        {
+            self.clear_inline_text_uses();
+        }
+        self.inline_text_uses_bytes += text.len();
+        self.inline_text_uses.insert(text.to_owned(), uses);
+    }
+
+    /// Discards the number of times that each piece of text has been written inline.
+    fn clear_inline_text_uses(&mut self) {
+        self.inline_text_uses.clear();
+        self.inline_text_uses_bytes = 0;
+    }
+
+    /// Returns the symbol ID associated with `text` if it is already in the symbol table.
+    /// Otherwise, applies the writer's [`SymbolInterningPolicy`] as described in
+    /// [`intern_or_inline`](Self::intern_or_inline).
+    fn intern<'a>(&mut self, position: &str, text: &'a str) -> IonResult<RawSymbolRef<'a>> {
+        match self.sid_for(text) {
+            Some(sid) => Ok(RawSymbolRef::SymbolId(sid)),
+            None => self.intern_or_inline(position, text),
+        }
+    }
+
+    /// If `token` is a symbol ID whose text is unknown, applies the provided
+    /// [`UnknownTextSymbolPolicy`] to it. All other tokens are returned unchanged.
+    ///
+    /// If the token was converted from a symbol with unknown text, `unknown_text_sid` is the symbol
+    /// ID that symbol was read from (see [`AsRawSymbolRef::unknown_text_sid`]). The policy is
+    /// applied to that symbol ID even if this writer's symbol table has text for it.
+    ///
+    /// A symbol ID beyond the end of the symbol table also has unknown text. If the policy writes
+    /// it as-is, the symbol ID is reserved so that new text is never assigned to it. Imported
+    /// symbol IDs are always written as-is; their text is known to readers with the shared table.
+    ///
+    /// `position` describes where the token appeared (for example, "field name") and is used in
+    /// the error raised by [`UnknownTextSymbolPolicy::Error`].
+    fn resolve_unknown_text<'a>(
+        &mut self,
+        policy: UnknownTextSymbolPolicy,
+        position: &str,
+        token: RawSymbolRef<'a>,
+        unknown_text_sid: Option<SymbolId>,
+    ) -> IonResult<RawSymbolRef<'a>> {
+        let sid = match (unknown_text_sid, token) {
+            (Some(sid), _) => sid,
+            (None, RawSymbolRef::SymbolId(sid)) => {
+                if self.text_for(sid).is_some() || self.sid_is_imported(sid) {
+                    return Ok(token);
+                }
+                sid
+            }
+            (None, RawSymbolRef::Text(_)) => return Ok(token),
+        };
+        match policy {
+            UnknownTextSymbolPolicy::Error => cold_path!(IonResult::encoding_error(format!(
+                "cannot write a {position} with unknown text (${sid}); the writer's unknown text symbol policy is `Error`"
+            ))),
+            UnknownTextSymbolPolicy::WriteSymbolId => {
+                if sid >= self.num_sids() {
+                    // Each reserved symbol ID becomes a placeholder if text is later added to the
+                    // symbol table, so the number that can be reserved at once is limited.
+                    if sid - self.num_sids() >= MAX_RESERVED_UNKNOWN_TEXT_SIDS {
+                        return cold_path!(IonResult::encoding_error(format!(
+                            "cannot write a {position} with unknown text (${sid}); it is more than {MAX_RESERVED_UNKNOWN_TEXT_SIDS} symbol IDs beyond the end of the symbol table"
+                        )));
+                    }
+                    self.highest_unknown_text_sid = self.highest_unknown_text_sid.max(sid);
+                }
+                Ok(RawSymbolRef::SymbolId(sid))
+            }
+            UnknownTextSymbolPolicy::WriteSidZero => Ok(RawSymbolRef::SymbolId(0)),
+            UnknownTextSymbolPolicy::WritePlaceholderText(text) => Ok(RawSymbolRef::Text(text)),
         }
     }
 }
 
 /// An Ion writer that maintains a symbol table and creates new entries as needed.
+///
+/// Writing a document:
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Element, SequenceWriter, StructWriter, ValueWriter, Writer};
+/// use ion_rs::v1_0::Text;
+///
+/// let mut writer = Writer::new(Text, Vec::new())?;
+/// writer.write(1)?.write("two")?;
+/// let mut record = writer.struct_writer()?;
+/// record.write("item", "apple")?.write("quantity", 3)?;
+/// record.close()?;
+/// let output = writer.close()?;
+///
+/// let expected = Element::read_all(r#"1 "two" {item: "apple", quantity: 3}"#)?;
+/// assert_eq!(Element::read_all(output)?, expected);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+///
+/// Transcoding a stream from one encoding to another:
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Reader, Writer};
+/// use ion_rs::v1_0::Binary;
+///
+/// let text_ion = r#"{name: "widget", tags: [blue, round]} 2024-01-01T"#;
+/// let mut reader = Reader::from_input(text_ion)?;
+/// let mut writer = Writer::new(Binary, Vec::new())?;
+/// while let Some(value) = reader.next()? {
+///     writer.write(value)?;
+/// }
+/// let binary_ion = writer.close()?;
+///
+/// assert_eq!(Element::read_all(binary_ion)?, Element::read_all(text_ion)?);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
 pub struct Writer<E: Encoding, Output: Write> {
     context: WriterContext,
     data_writer: E::Writer<Vec<u8>>,
     directive_writer: E::Writer<Vec<u8>>,
     output: Output,
     value_writer_config: ValueWriterConfig,
+    // Whether nothing has been written since the stream began or the last segment was finished.
+    segment_is_empty: bool,
+    flush_every_n_values: Option<usize>,
+    flush_threshold_bytes: Option<usize>,
+    // The number of top-level values that have been started since the last flush.
+    values_since_flush: usize,
+    // The shared symbol tables imported by the current local symbol table, in declaration order.
+    symbol_table_imports: Vec<SymbolTableImport>,
+    // Whether the next symbol table written to the output must replace the reader's current
+    // symbol table rather than appending to it.
+    symbol_table_is_new: bool,
+}
+
+/// A shared symbol table imported by a [`Writer`]'s local symbol table.
+struct SymbolTableImport {
+    name: String,
+    version: usize,
+    max_id: usize,
 }
 
 pub type TextWriter_1_0<Output> = Writer<TextEncoding_1_0, Output>;
@@ -62,6 +331,18 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
     /// Constructs a writer for the requested encoding using the provided configuration.
     pub fn new(config: impl Into<WriteConfig<E>>, output: Output) -> IonResult<Self> {
         let config = config.into();
+        let value_writer_config = E::default_value_writer_config()
+            .with_unknown_text_symbol_policy(config.unknown_text_symbol_policy)
+            .with_sorted_fields(config.deterministic);
+        let new_symbol_policy = config.new_symbol_policy;
+        let flush_every_n_values = config.flush_every_n_values;
+        let flush_threshold_bytes = config.flush_threshold_bytes;
+        // Only Ion 1.1 can write symbol text inline in every position, so Ion 1.0 writers always
+        // intern new text.
+        let symbol_interning_policy = match E::ion_version() {
+            IonVersion::v1_0 => SymbolInterningPolicy::AlwaysIntern,
+            IonVersion::v1_1 => config.symbol_interning_policy,
+        };
         let directive_writer = E::Writer::build(config.clone(), vec![])?;
         let mut data_writer = E::Writer::build(config, vec![])?;
         // Erase the IVM that's created by default
@@ -70,18 +351,61 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         let ion_version = E::ion_version();
         let symbol_table = SymbolTable::new(ion_version);
         let macro_table = MacroTable::new();
-        let context = WriterContext::new(symbol_table, macro_table);
+        let mut context = WriterContext::new(symbol_table, macro_table);
+        context.new_symbol_policy = new_symbol_policy;
+        context.symbol_interning_policy = symbol_interning_policy;
         let mut writer = Writer {
             context,
             data_writer,
             directive_writer,
             output,
-            value_writer_config: E::default_value_writer_config(),
+            value_writer_config,
+            segment_is_empty: false,
+            flush_every_n_values,
+            flush_threshold_bytes,
+            values_since_flush: 0,
+            symbol_table_imports: Vec::new(),
+            symbol_table_is_new: false,
         };
         writer.flush()?;
+        writer.segment_is_empty = true;
         Ok(writer)
     }
 
+    /// Seeds the writer's symbol table with an exact mapping of symbol IDs to text, typically one
+    /// captured from a source stream using [`SymbolTable::to_exact_seed`]. The first entry in
+    /// `seed` is assigned the first symbol ID after the system symbols, the second entry the
+    /// next ID, and so on. Entries whose text is unknown (`None`) are reproduced as gaps.
+    ///
+    /// The seed is written to the output as a local symbol table ahead of any values that
+    /// follow. Those values use the seeded symbol IDs, so re-encoding a logically unchanged
+    /// document reproduces the source stream's symbol ID assignments. Text that is not in the
+    /// seed is added to the table as usual unless the writer's [`NewSymbolPolicy`] is
+    /// [`NewSymbolPolicy::ErrorOnNewSymbols`].
+    ///
+    /// Returns an `Err` if the writer has already added symbols to its symbol table.
+    pub fn with_exact_symbol_table(mut self, seed: Vec<Option<String>>) -> IonResult<Self> {
+        let symbol_table = &mut self.context.symbol_table;
+        if symbol_table.len() > symbol_table.system_len() || self.context.num_imported_symbols > 0 {
+            return IonResult::illegal_operation(
+                "cannot seed the symbol table of a writer that has already added symbols",
+            );
+        }
+        for text in &seed {
+            symbol_table.add_symbol_or_placeholder(text.as_deref());
+        }
+
+        let mut lst = self
+            .directive_writer
+            .value_writer()
+            .with_annotations(system_symbol_ids::ION_SYMBOL_TABLE)?
+            .struct_writer()?;
+        lst.field_writer(system_symbol_ids::SYMBOLS)
+            .write_list(&seed)?;
+        lst.close()?;
+        Ok(self)
+    }
+
     pub fn output(&self) -> &Output {
         &self.output
     }
@@ -98,10 +422,31 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
     }
 
     /// Writes bytes of previously encoded values to the output stream.
+    ///
+    /// Container writers mutably borrow the writer that created them, so `flush` cannot be called
+    /// while a container is still open. Only complete top-level values are ever flushed.
+    #[cfg_attr(feature = "experimental-reader-writer", doc = "```compile_fail,E0499")]
+    #[cfg_attr(not(feature = "experimental-reader-writer"), doc = "```ignore")]
+    /// use ion_rs::{v1_0, SequenceWriter, Writer};
+    ///
+    /// let mut writer = Writer::new(v1_0::Text, Vec::new()).unwrap();
+    /// let mut list = writer.list_writer().unwrap();
+    /// list.write(1).unwrap();
+    /// // Error: `writer` is still mutably borrowed by `list`
+    /// writer.flush().unwrap();
+    /// list.close().unwrap();
+    /// ```
     pub fn flush(&mut self) -> IonResult<()> {
-        if self.context.num_pending_symbols > 0 {
-            self.write_lst_append()?;
+        // Binary writers buffer encoded values internally; move them to the data writer's output
+        // so we can tell whether any values are pending.
+        self.data_writer.flush()?;
+        let has_pending_values = !self.data_writer.output().is_empty();
+        // A new symbol table only needs to be written if there are values that may depend on it.
+        if self.context.num_pending_symbols > 0 || (self.symbol_table_is_new && has_pending_values)
+        {
+            self.write_lst()?;
             self.context.num_pending_symbols = 0;
+            self.symbol_table_is_new = false;
         }
 
         self.directive_writer.flush()?;
@@ -109,23 +454,179 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
             .write_all(self.directive_writer.output().as_slice())?;
         self.directive_writer.output_mut().clear();
 
-        self.data_writer.flush()?;
         self.output
             .write_all(self.data_writer.output().as_slice())?;
+        if has_pending_values {
+            self.segment_is_empty = false;
+        }
         self.data_writer.output_mut().clear();
+        self.values_since_flush = 0;
+        Ok(())
+    }
+
+    /// Flushes the writer if it has reached either of its configured auto-flush limits. This is
+    /// called after each top-level value is completed.
+    fn flush_if_limit_reached(&mut self) -> IonResult<()> {
+        if self
+            .flush_every_n_values
+            .is_some_and(|n| self.values_since_flush >= n)
+        {
+            return self.flush();
+        }
+        if let Some(threshold) = self.flush_threshold_bytes {
+            // Binary writers buffer encoded values internally; move them to the data writer's
+            // output so they can be measured.
+            self.data_writer.flush()?;
+            if self.data_writer.output().len() >= threshold {
+                return self.flush();
+            }
+        }
+        Ok(())
+    }
+
+    /// Ends the current segment of the stream and begins a new one.
+    ///
+    /// Pending values are flushed, the writer's symbol table (and in Ion 1.1, its macro table) is
+    /// reset to its initial state, and an Ion version marker is written. Values written afterward
+    /// are encoded as though they were the beginning of a new stream; symbol text from the previous
+    /// segment is interned again and may be assigned a different symbol ID.
+    ///
+    /// If nothing has been written since the stream began or the previous segment was finished,
+    /// this only flushes; it does not write an empty segment.
+    ///
+    /// Like [`flush`](Self::flush), this cannot be called while a container is still open.
+    #[cfg_attr(feature = "experimental-reader-writer", doc = "```compile_fail,E0499")]
+    #[cfg_attr(not(feature = "experimental-reader-writer"), doc = "```ignore")]
+    /// use ion_rs::{v1_0, SequenceWriter, Writer};
+    ///
+    /// let mut writer = Writer::new(v1_0::Binary, Vec::new()).unwrap();
+    /// let mut list = writer.list_writer().unwrap();
+    /// list.write(1).unwrap();
+    /// // Error: `writer` is still mutably borrowed by `list`
+    /// writer.finish_segment().unwrap();
+    /// list.close().unwrap();
+    /// ```
+    pub fn finish_segment(&mut self) -> IonResult<()> {
+        self.flush()?;
+        if self.segment_is_empty {
+            return Ok(());
+        }
+        self.context.symbol_table.reset();
+        self.context.macro_table = MacroTable::new();
+        self.context.clear_inline_text_uses();
+        self.context.highest_unknown_text_sid = 0;
+        self.context.num_imported_symbols = 0;
+        // The version marker resets the reader's symbol table, so there is nothing to replace.
+        self.symbol_table_imports.clear();
+        self.symbol_table_is_new = false;
+        self.directive_writer.write_version_marker()?;
+        self.flush()?;
+        self.segment_is_empty = true;
+        Ok(())
+    }
+
+    /// Starts a new local symbol table that does not contain any of the symbols added so far.
+    ///
+    /// Pending values are flushed first, since they may refer to symbols in the current table.
+    /// The next flush writes a symbol table that replaces the current one instead of appending to
+    /// it, and new text is assigned symbol IDs beginning after the system symbols. Imports declared
+    /// using [`add_import`](Self::add_import) are discarded as well.
+    ///
+    /// Unlike [`finish_segment`](Self::finish_segment), this does not write an Ion version marker.
+    pub fn reset_symbol_table(&mut self) -> IonResult<()> {
+        self.flush()?;
+        self.context.symbol_table.reset();
+        self.context.clear_inline_text_uses();
+        self.context.highest_unknown_text_sid = 0;
+        self.context.num_imported_symbols = 0;
+        self.symbol_table_imports.clear();
+        self.symbol_table_is_new = true;
+        Ok(())
+    }
+
+    /// Declares that the local symbol table imports the first `max_id` symbols of version
+    /// `version` of the shared symbol table named `name`.
+    ///
+    /// Imported symbols occupy the symbol IDs after the system symbols and any previously declared
+    /// imports; local symbols are assigned the IDs that follow. The writer does not know the text
+    /// of imported symbols, so they can only be written as symbol IDs (for example, `$10`). Readers
+    /// resolve them using their [`Catalog`](crate::Catalog), so the writer's
+    /// [`UnknownTextSymbolPolicy`] does not apply to them.
+    ///
+    /// Imports must precede a table's local symbols, so this starts a new local symbol table as
+    /// [`reset_symbol_table`](Self::reset_symbol_table) does, but keeps the imports that have
+    /// already been declared.
+    ///
+    /// Returns an `Err` if `name` is empty, `version` is `0`, or the imports together would occupy
+    /// more symbol IDs than a `usize` can represent.
+    pub fn add_import(
+        &mut self,
+        name: impl Into<String>,
+        version: usize,
+        max_id: usize,
+    ) -> IonResult<()> {
+        let name = name.into();
+        if name.is_empty() {
+            return IonResult::illegal_operation(
+                "the name of a symbol table import cannot be empty",
+            );
+        }
+        if version == 0 {
+            return IonResult::illegal_operation(format!(
+                "the version of symbol table import '{name}' must be at least 1"
+            ));
+        }
+        let system_len = self.context.symbol_table.system_len();
+        let Some(num_imported_symbols) = self
+            .context
+            .num_imported_symbols
+            .checked_add(max_id)
+            .filter(|n| n.checked_add(system_len).is_some())
+        else {
+            return IonResult::illegal_operation(format!(
+                "symbol table import '{name}' has a max_id ({max_id}) that would exhaust the available symbol IDs"
+            ));
+        };
+        let mut imports = std::mem::take(&mut self.symbol_table_imports);
+        self.reset_symbol_table()?;
+        imports.push(SymbolTableImport {
+            name,
+            version,
+            max_id,
+        });
+        self.symbol_table_imports = imports;
+        // The imported symbols occupy a range of symbol IDs; they are not added to the symbol table.
+        self.context.num_imported_symbols = num_imported_symbols;
         Ok(())
     }
 
+    /// Flushes any pending values (and the symbol table entries they require) to the output
+    /// stream, then consumes the writer and returns the output.
+    ///
+    /// Because the writer is consumed, it cannot be written to after it has been closed.
+    #[cfg_attr(feature = "experimental-reader-writer", doc = "```compile_fail,E0382")]
+    #[cfg_attr(not(feature = "experimental-reader-writer"), doc = "```ignore")]
+    /// use ion_rs::{v1_0, Writer};
+    ///
+    /// let mut writer = Writer::new(v1_0::Binary, Vec::new()).unwrap();
+    /// writer.write(1).unwrap();
+    /// let output = writer.close().unwrap();
+    /// // Error: `writer` was moved by the call to `close()`
+    /// writer.write(2).unwrap();
+    /// ```
     pub fn close(mut self) -> IonResult<Output> {
         self.flush()?;
         Ok(self.output)
     }
 
-    /// Helper method to encode an LST append containing pending symbols.
-    fn write_lst_append(&mut self) -> IonResult<()> {
+    /// Helper method to encode an LST containing pending symbols. If the symbol table is new, the
+    /// LST lists its imports (if any); otherwise, it is an append to the current symbol table.
+    fn write_lst(&mut self) -> IonResult<()> {
         let Self {
             context,
             directive_writer,
+            symbol_table_imports,
+            symbol_table_is_new,
             ..
         } = self;
 
@@ -134,8 +635,27 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
             .with_annotations(system_symbol_ids::ION_SYMBOL_TABLE)?
             .struct_writer()?;
 
-        lst.field_writer(system_symbol_ids::IMPORTS)
-            .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        // The `imports` field is written before `symbols` so that readers processing the fields
+        // in order see the imported symbols first.
+        if !*symbol_table_is_new {
+            lst.field_writer(system_symbol_ids::IMPORTS)
+                .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        } else if !symbol_table_imports.is_empty() {
+            let mut imports = lst.field_writer(system_symbol_ids::IMPORTS).list_writer()?;
+            for import in symbol_table_imports.iter() {
+                let mut import_struct = imports.struct_writer()?;
+                import_struct
+                    .write(system_symbol_ids::NAME, import.name.as_str())?
+                    .write(system_symbol_ids::VERSION, import.version)?
+                    .write(system_symbol_ids::MAX_ID, import.max_id)?;
+                import_struct.close()?;
+            }
+            imports.close()?;
+        }
+
+        if context.num_pending_symbols == 0 {
+            return lst.close();
+        }
 
         let mut new_symbol_list = lst.field_writer(system_symbol_ids::SYMBOLS).list_writer()?;
 
@@ -158,6 +678,7 @@ impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
         Self: 'a;
 
     fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        self.values_since_flush += 1;
         let raw_value_writer = self.data_writer.make_value_writer();
 
         ApplicationValueWriter {
@@ -168,13 +689,78 @@ impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
     }
 }
 
+/// Takes a series of `TYPE => METHOD` pairs, generating a function for each that calls the
+/// corresponding value writer method, flushes if an auto-flush limit has been reached, and then
+/// returns `Ok(self)` upon success.
+macro_rules! delegate_and_flush_if_limit_reached {
+    ($($value_type:ty => $method:ident),* $(,)?) => {
+        $(
+            fn $method(&mut self, value: $value_type) -> IonResult<&mut Self> {
+                self.value_writer().$method(value)?;
+                self.flush_if_limit_reached()?;
+                Ok(self)
+            }
+        )*
+    };
+}
+
+// Each method that writes a complete top-level value checks the writer's auto-flush limits
+// once the value has been written.
 impl<E: Encoding, Output: Write> SequenceWriter for Writer<E, Output> {
     type Resources = Output;
 
+    fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
+        value.write_as_ion(self.make_value_writer())?;
+        self.flush_if_limit_reached()?;
+        Ok(self)
+    }
+
     fn close(mut self) -> IonResult<Self::Resources> {
         self.flush()?;
         Ok(self.output)
     }
+
+    delegate_and_flush_if_limit_reached!(
+        IonType => write_null,
+        bool => write_bool,
+        i64 => write_i64,
+        &Int => write_int,
+        f32 => write_f32,
+        f64 => write_f64,
+        &Decimal => write_decimal,
+        &Timestamp => write_timestamp,
+        impl AsRef<str> => write_string,
+        impl AsRawSymbolRef => write_symbol,
+        impl AsRef<[u8]> => write_clob,
+        impl AsRef<[u8]> => write_blob,
+    );
+
+    fn write_list<V: WriteAsIon, I: IntoIterator<Item = V>>(
+        &mut self,
+        values: I,
+    ) -> IonResult<&mut Self> {
+        self.value_writer().write_list(values)?;
+        self.flush_if_limit_reached()?;
+        Ok(self)
+    }
+
+    fn write_sexp<V: WriteAsIon, I: IntoIterator<Item = V>>(
+        &mut self,
+        values: I,
+    ) -> IonResult<&mut Self> {
+        self.value_writer().write_sexp(values)?;
+        self.flush_if_limit_reached()?;
+        Ok(self)
+    }
+
+    fn write_struct<K: AsRawSymbolRef, V: WriteAsIon, I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        fields: I,
+    ) -> IonResult<&mut Self> {
+        self.value_writer().write_struct(fields)?;
+        self.flush_if_limit_reached()?;
+        Ok(self)
+    }
 }
 
 pub struct ApplicationValueWriter<'a, V: ValueWriter> {
@@ -195,10 +781,6 @@ impl<'a, V: ValueWriter> ApplicationValueWriter<'a, V> {
             raw_value_writer,
         }
     }
-
-    fn symbol_table(&mut self) -> &mut SymbolTable {
-        &mut self.encoding.symbol_table
-    }
 }
 
 impl<'a, 'value, 'top> ApplicationValueWriter<'a, BinaryValueWriter_1_1<'value, 'top>> {
@@ -206,6 +788,10 @@ impl<'a, 'value, 'top> ApplicationValueWriter<'a, BinaryValueWriter_1_1<'value,
         self.value_writer_config = self
             .value_writer_config
             .with_container_encoding(container_encoding);
+        // Container encoding is handled by the raw writer, so it needs the setting too.
+        self.raw_value_writer = self
+            .raw_value_writer
+            .with_container_encoding(container_encoding);
         self
     }
 
@@ -237,7 +823,18 @@ impl<'value, V: ValueWriter> AnnotatableWriter for ApplicationValueWriter<'value
     where
         Self: 'a,
     {
+        let unknown_text_sids = annotations.unknown_text_sids();
         let mut annotations = annotations.into_annotations_vec();
+        let policy = self.value_writer_config.unknown_text_symbol_policy();
+        for (index, annotation) in annotations.iter_mut().enumerate() {
+            let unknown_text_sid = unknown_text_sids.get(index).copied().flatten();
+            *annotation = self.encoding.resolve_unknown_text(
+                policy,
+                "annotation",
+                *annotation,
+                unknown_text_sid,
+            )?;
+        }
         match self.value_writer_config.annotations_encoding() {
             AnnotationsEncoding::WriteAsSymbolIds => {
                 // Intern all text so everything we write is a symbol ID
@@ -273,29 +870,19 @@ impl<'value, V: ValueWriter> ApplicationValueWriter<'value, V> {
         Self: 'a,
     {
         for annotation in annotations {
-            match annotation.as_raw_symbol_token_ref() {
+            match *annotation {
                 // The token is already a symbol ID.
                 RawSymbolRef::SymbolId(sid) => {
-                    if !self.symbol_table().sid_is_valid(sid) {
+                    if !self.encoding.sid_is_writable(sid) {
                         return IonResult::encoding_error(format!(
                             "annotation symbol ID {sid} is out of range"
                         ));
                     }
                 }
-                // The token is text...
+                // The token is text; map it to its symbol ID, adding it to the symbol table if
+                // needed.
                 RawSymbolRef::Text(text) => {
-                    let sid = match self.symbol_table().sid_for(&text) {
-                        Some(sid) => {
-                            //...that was already in the symbol table.
-                            sid
-                        }
-                        None => {
-                            // ...that we need to add to the symbol table.
-                            self.encoding.num_pending_symbols += 1;
-                            self.symbol_table().add_symbol_for_text(text)
-                        }
-                    };
-                    *annotation = RawSymbolRef::SymbolId(sid);
+                    *annotation = self.encoding.intern("annotation", text)?;
                 }
             };
         }
@@ -312,7 +899,7 @@ impl<'value, V: ValueWriter> ApplicationValueWriter<'value, V> {
     {
         for annotation in annotations {
             if let RawSymbolRef::SymbolId(sid) = annotation.as_raw_symbol_token_ref() {
-                if !self.symbol_table().sid_is_valid(sid) {
+                if !self.encoding.sid_is_writable(sid) {
                     return IonResult::encoding_error(format!(
                         "annotation symbol ID {sid} is not in the symbol table"
                     ));
@@ -336,7 +923,7 @@ impl<'value, V: ValueWriter> ApplicationValueWriter<'value, V> {
             match annotation.as_raw_symbol_token_ref() {
                 // The token is already a symbol ID.
                 RawSymbolRef::SymbolId(sid) => {
-                    if !self.symbol_table().sid_is_valid(sid) {
+                    if !self.encoding.sid_is_writable(sid) {
                         return IonResult::encoding_error(format!(
                             "annotation symbol ID {sid} is out of range"
                         ));
@@ -344,7 +931,7 @@ impl<'value, V: ValueWriter> ApplicationValueWriter<'value, V> {
                 }
                 // The token is text...
                 RawSymbolRef::Text(text) => {
-                    match self.symbol_table().sid_for(&text) {
+                    match self.encoding.sid_for(text) {
                         Some(sid) => {
                             //...that was already in the symbol table.
                             *annotation = RawSymbolRef::SymbolId(sid);
@@ -392,12 +979,19 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
             value_writer_config,
         } = self;
 
+        let token = encoding.resolve_unknown_text(
+            value_writer_config.unknown_text_symbol_policy(),
+            "symbol value",
+            value.as_raw_symbol_token_ref(),
+            value.unknown_text_sid(),
+        )?;
+
         // Depending on the symbol value encoding config option, map the provided symbol reference
         // from text to SID or vice versa, performing any validation needed.
-        let symbol_ref = match value.as_raw_symbol_token_ref() {
+        let symbol_ref = match token {
             SymbolId(symbol_id) => {
                 // We can write the symbol ID as-is. Make sure it's in the symbol table.
-                if !encoding.symbol_table.sid_is_valid(symbol_id) {
+                if !encoding.sid_is_writable(symbol_id) {
                     return cold_path!(IonResult::encoding_error(format!(
                         "symbol value ID ${symbol_id} is not in the symbol table"
                     )));
@@ -407,20 +1001,12 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
             Text(text) => {
                 match value_writer_config.symbol_value_encoding() {
                     WriteAsSymbolIds => {
-                        // Map the text to a symbol ID.
-                        match encoding.symbol_table.sid_for(&text) {
-                            // If it's already in the symbol table, use that SID.
-                            Some(symbol_id) => SymbolId(symbol_id),
-                            // Otherwise, add it to the symbol table.
-                            None => {
-                                encoding.num_pending_symbols += 1;
-                                SymbolId(encoding.symbol_table.add_symbol_for_text(text))
-                            }
-                        }
+                        // Map the text to a symbol ID, adding it to the symbol table if needed.
+                        encoding.intern("symbol value", text)?
                     }
                     WriteNewSymbolsAsInlineText => {
                         // If the text is in the symbol table, use the symbol ID. Otherwise, use the text itself.
-                        match encoding.symbol_table.sid_for(&text) {
+                        match encoding.sid_for(text) {
                             Some(symbol_id) => SymbolId(symbol_id),
                             None => Text(text),
                         }
@@ -472,6 +1058,11 @@ pub struct ApplicationStructWriter<'value, V: ValueWriter> {
     encoding: &'value mut WriterContext,
     raw_struct_writer: V::StructWriter,
     value_writer_config: ValueWriterConfig,
+    // Whether a field name has been encoded that is not yet followed by a value.
+    has_pending_field_name: bool,
+    // Whether a value was written without a preceding field name. Because making a value writer
+    // cannot fail, this is reported when the struct is closed.
+    wrote_value_without_field_name: bool,
 }
 
 impl<'value, V: ValueWriter> ApplicationStructWriter<'value, V> {
@@ -484,6 +1075,8 @@ impl<'value, V: ValueWriter> ApplicationStructWriter<'value, V> {
             encoding: encoding_context,
             raw_struct_writer,
             value_writer_config: config,
+            has_pending_field_name: false,
+            wrote_value_without_field_name: false,
         }
     }
 
@@ -501,6 +1094,9 @@ impl<'value, V: ValueWriter> MakeValueWriter for ApplicationStructWriter<'value,
         Self: 'a;
 
     fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        if !std::mem::take(&mut self.has_pending_field_name) {
+            self.wrote_value_without_field_name = true;
+        }
         ApplicationValueWriter::new(
             self.encoding,
             self.value_writer_config,
@@ -509,14 +1105,28 @@ impl<'value, V: ValueWriter> MakeValueWriter for ApplicationStructWriter<'value,
     }
 }
 
-impl<'value, V: ValueWriter> FieldEncoder for ApplicationStructWriter<'value, V> {
-    fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
-        let text = match name.as_raw_symbol_token_ref() {
+impl<'value, V: ValueWriter> ApplicationStructWriter<'value, V> {
+    /// Resolves the field name according to the writer's configuration and passes it to the raw
+    /// struct writer.
+    fn resolve_and_encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        if self.has_pending_field_name {
+            return cold_path!(IonResult::illegal_operation(
+                "cannot write a field name; the previous field name has not been given a value"
+            ));
+        }
+        self.has_pending_field_name = true;
+        let token = self.encoding.resolve_unknown_text(
+            self.value_writer_config.unknown_text_symbol_policy(),
+            "field name",
+            name.as_raw_symbol_token_ref(),
+            name.unknown_text_sid(),
+        )?;
+        let text = match token {
             // If the user passes in a symbol ID, we range check it and write it as-is no matter what.
             // In the unusual circumstance that the user has a SID and wants to write text, they can
             // resolve the SID in the symbol table before calling this method.
             RawSymbolRef::SymbolId(symbol_id) => {
-                if !self.encoding.symbol_table.sid_is_valid(symbol_id) {
+                if !self.encoding.sid_is_writable(symbol_id) {
                     return cold_path!(IonResult::encoding_error(format!(
                         "symbol ID ${symbol_id} is not in the symbol table"
                     )));
@@ -535,16 +1145,15 @@ impl<'value, V: ValueWriter> FieldEncoder for ApplicationStructWriter<'value, V>
         }
 
         // Otherwise, see if the symbol is already in the symbol table.
-        let token: RawSymbolRef = match self.encoding.symbol_table.sid_for(&text) {
+        let token: RawSymbolRef = match self.encoding.sid_for(text) {
             // If so, use the existing ID.
             Some(sid) => sid.into(),
             // If it's not but the struct writer is configured to intern new text, add it to the
-            // symbol table.
+            // symbol table (or write it inline, if the writer's interning policy says to).
             None if self.value_writer_config.field_name_encoding()
                 == FieldNameEncoding::WriteAsSymbolIds =>
             {
-                self.encoding.num_pending_symbols += 1;
-                self.encoding.symbol_table.add_symbol_for_text(text).into()
+                self.encoding.intern_or_inline("field name", text)?
             }
             // Otherwise, we'll write the text as-is.
             None => text.into(),
@@ -555,12 +1164,38 @@ impl<'value, V: ValueWriter> FieldEncoder for ApplicationStructWriter<'value, V>
     }
 }
 
+impl<'value, V: ValueWriter> FieldEncoder for ApplicationStructWriter<'value, V> {
+    fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        let result = self.resolve_and_encode_field_name(name);
+        if result.is_err() {
+            self.raw_struct_writer.abandon();
+        }
+        result
+    }
+
+    fn abandon(&mut self) {
+        self.raw_struct_writer.abandon()
+    }
+}
+
 impl<'value, V: ValueWriter> StructWriter for ApplicationStructWriter<'value, V> {
     fn field_writer<'a>(&'a mut self, name: impl Into<RawSymbolRef<'a>>) -> FieldWriter<'a, Self> {
         FieldWriter::new(name.into(), self.value_writer_config, self)
     }
 
-    fn close(self) -> IonResult<()> {
+    fn close(mut self) -> IonResult<()> {
+        if self.has_pending_field_name {
+            self.raw_struct_writer.abandon();
+            return IonResult::illegal_operation(
+                "cannot close a struct whose last field name has not been given a value",
+            );
+        }
+        if self.wrote_value_without_field_name {
+            self.raw_struct_writer.abandon();
+            return IonResult::illegal_operation(
+                "a value was written to a struct without a field name",
+            );
+        }
         self.raw_struct_writer.close()
     }
 
@@ -713,12 +1348,22 @@ impl<S: SequenceWriter> ElementWriter for S {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use rstest::rstest;
+
+    use super::{MAX_INLINE_TEXT_USES_BYTES, MAX_INLINE_TEXT_USES_ENTRIES};
+    use crate::element::reader::ElementReader;
     use crate::lazy::encoder::value_writer::AnnotatableWriter;
     use crate::lazy::encoder::value_writer_config::{AnnotationsEncoding, SymbolValueEncoding};
     use crate::raw_symbol_ref::AsRawSymbolRef;
     use crate::{
-        v1_1, FieldNameEncoding, HasSpan, IonResult, LazyRawValue, RawSymbolRef, SequenceWriter,
-        StructWriter, SystemReader, ValueWriter, Writer,
+        ion_list, ion_struct, v1_0, v1_1, AnyEncoding, Decimal, Decoder, Element, ElementWriter,
+        Encoding, FieldNameEncoding, HasSpan, IntoAnnotatedElement, IonData, IonError, IonResult,
+        LazyRawValue, MapCatalog, RawSymbolRef, Reader, Sequence, SequenceWriter,
+        SharedSymbolTable, StructWriter, Symbol, SymbolId, SymbolInterningPolicy, SystemReader,
+        SystemStreamItem, UnknownTextSymbolPolicy, UnresolvedSymbolIdPolicy, ValueWriter,
+        WriteAsIon, WriteConfig, Writer,
     };
 
     fn symbol_value_encoding_test<const N: usize, A: AsRawSymbolRef>(
@@ -976,4 +1621,1125 @@ mod tests {
             ],
         )
     }
+
+    /// Symbols whose text resembles a symbol ID (`$99`), a bare `$`, or an IVM (`$ion_1_0`) must be
+    /// quoted by text writers so they are not misread as something other than a text symbol.
+    fn symbol_id_lookalikes_round_trip<E: Encoding>(encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        const LOOKALIKES: [&str; 4] = ["$99", "$0", "$", "$ion_1_0"];
+        let mut expected = Vec::new();
+        for text in LOOKALIKES {
+            // As a symbol value, a field name, and an annotation
+            expected.push(Element::symbol(text));
+            expected.push(ion_struct! {text: 1}.into());
+            expected.push(Element::from(1).with_annotations([text]));
+        }
+        // All three positions at once, nested in a container
+        expected.push(ion_list![Element::symbol("$99").with_annotations(["$", "$ion_1_0"])].into());
+        expected.push(ion_struct! {"$99": ion_struct! {"$ion_1_0": Element::symbol("$")}}.into());
+
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        writer.write_elements(&expected)?;
+        let output = writer.close()?;
+        let actual: Vec<Element> = Element::read_all(output.as_slice())?.into_iter().collect();
+        assert!(
+            IonData::eq(&expected, &actual),
+            "expected:\n{expected:?}\nwas not Ion equal to actual:\n{actual:?}\n{}",
+            String::from_utf8_lossy(&output)
+        );
+
+        // Element's Display implementation uses the same formatting logic
+        for element in &expected {
+            let displayed = element.to_string();
+            let reread = Element::read_one(displayed.as_str())?;
+            assert!(
+                IonData::eq(element, &reread),
+                "{element:?} was displayed as {displayed}, which was read as {reread:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_id_lookalikes_round_trip_text_1_0() -> IonResult<()> {
+        symbol_id_lookalikes_round_trip(v1_0::Text)
+    }
+
+    #[test]
+    fn symbol_id_lookalikes_round_trip_text_1_1() -> IonResult<()> {
+        symbol_id_lookalikes_round_trip(v1_1::Text)
+    }
+
+    #[test]
+    fn symbol_ids_are_written_unquoted() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        // `$4` is the system symbol `name`
+        writer.write_symbol(4)?;
+        let output = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(output.trim(), "$4");
+        assert_eq!(Element::read_one(output)?, Element::symbol("name"));
+        Ok(())
+    }
+
+    /// Writes the Ion in `data` using the provided unknown text symbol policy, then reads it back.
+    fn write_with_unknown_text_symbol_policy<E: Encoding>(
+        encoding: E,
+        policy: UnknownTextSymbolPolicy,
+        data: &str,
+    ) -> IonResult<Sequence>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let config = WriteConfig::from(encoding).with_unknown_text_symbol_policy(policy);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_elements(&Element::read_all(data)?)?;
+        Element::read_all(writer.close()?)
+    }
+
+    /// Writes symbols with unknown text as symbol values, field names, and annotations under
+    /// each of the available policies.
+    fn unknown_text_symbol_policies<E: Encoding + Copy>(encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        use UnknownTextSymbolPolicy::*;
+        const DATA: &str = "$0 {$0: 1} $0::2 $0::[{$0: $0::$0}]";
+
+        // Symbols with unknown text round trip as symbols with unknown text.
+        for policy in [WriteSymbolId, WriteSidZero] {
+            let actual = write_with_unknown_text_symbol_policy(encoding, policy, DATA)?;
+            assert_eq!(actual, Element::read_all(DATA)?);
+        }
+
+        // Symbols with unknown text are given the placeholder text.
+        let placeholder = WritePlaceholderText("unknown");
+        let expected = Element::read_all(DATA.replace("$0", "unknown").as_str())?;
+        let actual = write_with_unknown_text_symbol_policy(encoding, placeholder, DATA)?;
+        assert_eq!(actual, expected);
+
+        // The policy also applies when transcoding lazy values.
+        let mut reader = Reader::new(v1_0::Text, DATA)?;
+        let config = WriteConfig::from(encoding).with_unknown_text_symbol_policy(placeholder);
+        let mut writer = Writer::new(config, Vec::new())?;
+        while let Some(value) = reader.next()? {
+            writer.write(value)?;
+        }
+        assert_eq!(Element::read_all(writer.close()?)?, expected);
+
+        // Symbols with unknown text are rejected; the error names the position.
+        for (data, position) in [
+            ("$0", "symbol value"),
+            ("{$0: 1}", "field name"),
+            ("$0::2", "annotation"),
+        ] {
+            let error = write_with_unknown_text_symbol_policy(encoding, Error, data).unwrap_err();
+            assert!(
+                error.to_string().contains(position),
+                "expected error for {data} to name the {position}: {error}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_text_symbol_policies_text_1_0() -> IonResult<()> {
+        unknown_text_symbol_policies(v1_0::Text)
+    }
+
+    #[test]
+    fn unknown_text_symbol_policies_binary_1_0() -> IonResult<()> {
+        unknown_text_symbol_policies(v1_0::Binary)
+    }
+
+    #[test]
+    fn unknown_text_symbol_policies_text_1_1() -> IonResult<()> {
+        unknown_text_symbol_policies(v1_1::Text)
+    }
+
+    #[test]
+    fn unknown_text_symbol_policies_binary_1_1() -> IonResult<()> {
+        unknown_text_symbol_policies(v1_1::Binary)
+    }
+
+    /// Writes `data` with the `Error` unknown text symbol policy, expecting it to fail partway
+    /// through a container.
+    fn expect_unknown_text_error<E: Encoding>(encoding: E, data: &str)
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let result =
+            write_with_unknown_text_symbol_policy(encoding, UnknownTextSymbolPolicy::Error, data);
+        assert!(result.is_err(), "expected an error writing {data}");
+    }
+
+    #[rstest]
+    #[case::list("[1, $0]")]
+    #[case::sexp("(1 $0)")]
+    #[case::annotation_in_list("[1, $0::2]")]
+    #[case::field_name("{a: 1, $0: 2}")]
+    #[case::field_value("{a: $0}")]
+    #[case::nested("{a: [1, {b: (2 $0)}]}")]
+    fn unknown_text_error_inside_text_container_does_not_panic(#[case] data: &str) {
+        expect_unknown_text_error(v1_0::Text, data);
+        expect_unknown_text_error(v1_1::Text, data);
+    }
+
+    #[test]
+    fn unknown_text_error_inside_list_returns_err() -> IonResult<()> {
+        let config = WriteConfig::from(v1_0::Text)
+            .with_unknown_text_symbol_policy(UnknownTextSymbolPolicy::Error);
+        let mut writer = Writer::new(config, Vec::new())?;
+        let write_list = |writer: &mut Writer<_, _>| -> IonResult<()> {
+            let mut list = writer.list_writer()?;
+            list.write(1)?.write_symbol(0)?;
+            list.close()
+        };
+        let error = write_list(&mut writer).unwrap_err();
+        assert!(error.to_string().contains("symbol value"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "dropped without calling `end()`")]
+    fn dropping_an_unclosed_text_container_panics() {
+        let mut writer = Writer::new(v1_0::Text, Vec::new()).unwrap();
+        let mut list = writer.list_writer().unwrap();
+        list.write(1).unwrap();
+        drop(list);
+    }
+
+    /// Reads `data`, treating symbol IDs that are not in the symbol table as symbols with
+    /// unknown text.
+    fn read_all_with_unknown_text(data: impl AsRef<[u8]>) -> IonResult<Sequence> {
+        let config =
+            AnyEncoding.with_unresolved_symbol_id_policy(UnresolvedSymbolIdPolicy::UnknownText);
+        Reader::new(config, data.as_ref())?.read_all_elements()
+    }
+
+    #[rstest]
+    #[case::binary_1_0(v1_0::Binary)]
+    #[case::text_1_0(v1_0::Text)]
+    #[case::binary_1_1(v1_1::Binary)]
+    #[case::text_1_1(v1_1::Text)]
+    fn unknown_text_symbol_ids_round_trip<E: Encoding>(#[case] encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        #[rustfmt::skip]
+        let ion_data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA,         // IVM; no local symbol table
+            0x71, 0x0A,                     // $10
+            0x70,                           // $0
+            0xD2, 0x8B, 0x70,               // {$11: $0}
+            0xE4, 0x81, 0x8C, 0x71, 0x0A,   // $12::$10
+        ];
+        let elements = read_all_with_unknown_text(ion_data)?;
+        let unknown = Symbol::unknown_text_with_sid;
+        let expected = Sequence::new([
+            Element::symbol(unknown(10)),
+            Element::symbol(unknown(0)),
+            Element::struct_builder()
+                .with_field(unknown(11), Element::symbol(unknown(0)))
+                .build()
+                .into(),
+            Element::symbol(unknown(10)).with_annotations([unknown(12)]),
+        ]);
+        assert_eq!(elements, expected);
+        // Symbols with unknown text are only equal if they have the same symbol ID.
+        assert_ne!(elements.get(0), elements.get(1));
+
+        let config = WriteConfig::from(encoding)
+            .with_unknown_text_symbol_policy(UnknownTextSymbolPolicy::WriteSymbolId);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_elements(&elements)?;
+        let output = writer.close()?;
+        if !E::is_binary() {
+            let text = String::from_utf8(output.clone()).unwrap();
+            for expected_text in ["$10", "$0", "$11", "$12"] {
+                assert!(
+                    text.contains(expected_text),
+                    "{expected_text} not in {text}"
+                );
+            }
+        }
+        assert_eq!(read_all_with_unknown_text(output)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn new_text_is_not_assigned_unknown_text_symbol_ids() -> IonResult<()> {
+        let elements = Sequence::new([
+            Element::symbol(Symbol::unknown_text_with_sid(11)),
+            Element::symbol("foo"),
+            Element::symbol("bar"),
+            Element::symbol("baz"),
+        ]);
+        let config = WriteConfig::from(v1_0::Binary)
+            .with_unknown_text_symbol_policy(UnknownTextSymbolPolicy::WriteSymbolId);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_elements(&elements)?;
+        let output = writer.close()?;
+        // `foo` is assigned symbol ID 10. Symbol ID 11 was already written with unknown text, so
+        // it is defined without text, leaving `bar` and `baz` with symbol IDs 12 and 13.
+        let expected = Sequence::new([
+            Element::symbol(Symbol::unknown_text()),
+            Element::symbol("foo"),
+            Element::symbol("bar"),
+            Element::symbol("baz"),
+        ]);
+        assert_eq!(Element::read_all(output)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::binary_1_0(v1_0::Binary)]
+    #[case::text_1_0(v1_0::Text)]
+    #[case::binary_1_1(v1_1::Binary)]
+    #[case::text_1_1(v1_1::Text)]
+    fn unknown_text_symbol_ids_are_readable_by_default<E: Encoding>(
+        #[case] encoding: E,
+    ) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let unknown = Symbol::unknown_text_with_sid;
+        let elements = Sequence::new([
+            Element::symbol(unknown(10)),
+            Element::struct_builder()
+                .with_field(unknown(11), Element::symbol("foo"))
+                .build()
+                .into(),
+            Element::symbol(unknown(10)).with_annotations([unknown(12)]),
+        ]);
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        writer.write_elements(&elements)?;
+        let output = writer.close()?;
+        // By default, symbols with unknown text are written as `$0`, which any reader accepts.
+        let expected = Sequence::new([
+            Element::symbol(unknown(0)),
+            Element::struct_builder()
+                .with_field(unknown(0), Element::symbol("foo"))
+                .build()
+                .into(),
+            Element::symbol(unknown(0)).with_annotations([unknown(0)]),
+        ]);
+        assert_eq!(Element::read_all(output)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_text_symbol_ids_far_beyond_the_symbol_table_are_an_error() -> IonResult<()> {
+        let config = WriteConfig::from(v1_0::Binary)
+            .with_unknown_text_symbol_policy(UnknownTextSymbolPolicy::WriteSymbolId);
+        let mut writer = Writer::new(config, Vec::new())?;
+        // Reserving this many symbol IDs would require as many placeholders once text is added.
+        let result = writer
+            .write_symbol(Symbol::unknown_text_with_sid(2_000_000_000))
+            .map(|_| ());
+        assert!(matches!(result, Err(IonError::Encoding(_))), "{result:?}");
+        let result = writer
+            .write_symbol(Symbol::unknown_text_with_sid(usize::MAX))
+            .map(|_| ());
+        assert!(matches!(result, Err(IonError::Encoding(_))), "{result:?}");
+        // Symbol IDs within the limit can still be written, and new text is assigned after them.
+        writer
+            .write_symbol(Symbol::unknown_text_with_sid(1_000))?
+            .write_symbol("foo")?;
+        let output = writer.close()?;
+        assert_eq!(symbol_ids(&output, MapCatalog::new())?, vec![1_000, 1_001]);
+        Ok(())
+    }
+
+    type HashMapHeavyValue = HashMap<String, HashMap<String, Vec<i64>>>;
+
+    // Each call constructs new `HashMap`s, whose iteration orders will (almost certainly) differ
+    // from those returned by previous calls.
+    fn hash_map_heavy_value() -> HashMapHeavyValue {
+        (0..20)
+            .map(|i| {
+                let fields = (0..10).map(|j| (format!("field{j}"), vec![i, j])).collect();
+                (format!("record{i}"), fields)
+            })
+            .collect()
+    }
+
+    fn write_binary(value: impl WriteAsIon, deterministic: bool) -> IonResult<Vec<u8>> {
+        let config = WriteConfig::from(v1_0::Binary).with_deterministic_output(deterministic);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write(value)?;
+        writer.close()
+    }
+
+    #[test]
+    fn deterministic_output_is_byte_identical() -> IonResult<()> {
+        let expected = write_binary(&hash_map_heavy_value(), true)?;
+        for _ in 0..50 {
+            assert_eq!(write_binary(&hash_map_heavy_value(), true)?, expected);
+        }
+
+        // New symbols are added to the symbol table in the order in which they're first written.
+        let mut reader = Reader::new(v1_0::Binary, expected.as_slice())?;
+        let actual = reader.read_one_element()?;
+        let mut record_names: Vec<String> = (0..20).map(|i| format!("record{i}")).collect();
+        record_names.sort();
+        let field_names = (0..10).map(|j| format!("field{j}"));
+        let mut expected_symbols = vec![record_names[0].clone()];
+        expected_symbols.extend(field_names);
+        expected_symbols.extend(record_names[1..].iter().cloned());
+        let symbols = reader.symbol_table().symbols();
+        let new_symbols: Vec<&str> = symbols[symbols.len() - expected_symbols.len()..]
+            .iter()
+            .map(|symbol| symbol.text().unwrap())
+            .collect();
+        assert_eq!(new_symbols, expected_symbols);
+
+        // Without deterministic output, each map is written in its own iteration order.
+        let outputs = (0..50)
+            .map(|_| write_binary(&hash_map_heavy_value(), false))
+            .collect::<IonResult<HashSet<_>>>()?;
+        assert!(outputs.len() > 1);
+        for output in outputs {
+            assert!(IonData::eq(&Element::read_one(output)?, &actual));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn deterministic_output_for_equivalent_elements() -> IonResult<()> {
+        let element1 = Element::read_one("{b: 2, a: [{y: 1, x: 2}], c: 3, a: 1}")?;
+        let element2 = Element::read_one("{c: 3, a: 1, a: [{x: 2, y: 1}], b: 2}")?;
+        assert_ne!(
+            write_binary(&element1, false)?,
+            write_binary(&element2, false)?
+        );
+        assert_eq!(
+            write_binary(&element1, true)?,
+            write_binary(&element2, true)?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::binary_1_0(v1_0::Binary)]
+    #[case::text_1_0(v1_0::Text)]
+    #[case::binary_1_1(v1_1::Binary)]
+    #[case::text_1_1(v1_1::Text)]
+    fn unbalanced_struct_fields<E: Encoding>(#[case] encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
+
+        // A field name followed by another field name
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        let mut strukt = writer.struct_writer()?;
+        strukt.encode_field_name("a")?;
+        let error = strukt.encode_field_name("b").unwrap_err();
+        assert!(
+            error.to_string().contains("has not been given a value"),
+            "{error}"
+        );
+
+        // A field name without a value when the struct is closed
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        let mut strukt = writer.struct_writer()?;
+        strukt.encode_field_name("a")?;
+        let error = strukt.close().unwrap_err();
+        assert!(
+            error.to_string().contains("has not been given a value"),
+            "{error}"
+        );
+
+        // A value without a field name
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        let mut strukt = writer.struct_writer()?;
+        strukt.make_value_writer().write_i64(1)?;
+        let error = strukt.close().unwrap_err();
+        assert!(
+            error.to_string().contains("without a field name"),
+            "{error}"
+        );
+
+        // Balanced calls succeed
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        let mut strukt = writer.struct_writer()?;
+        strukt.encode_field_name("a")?;
+        strukt.make_value_writer().write_i64(1)?;
+        strukt.close()?;
+        assert_eq!(
+            Element::read_one(writer.close()?)?,
+            Element::read_one("{a: 1}")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nested_container_symbols_are_interned() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        let mut outer = writer.struct_writer()?;
+        outer.write("colors", ["red", "green"].map(Symbol::from))?;
+        let mut middle = outer.field_writer("nested").struct_writer()?;
+        let mut inner = middle.field_writer("deeper").list_writer()?;
+        inner.write_sexp([Symbol::from("three"), Symbol::from("levels")])?;
+        inner.write(Symbol::from("down"))?;
+        inner.close()?;
+        middle.close()?;
+        outer.close()?;
+        writer.flush()?;
+
+        // Every new symbol, however deeply nested, was added to the symbol table that was
+        // written ahead of the value.
+        let symbol_table = &writer.context.symbol_table;
+        for text in [
+            "colors", "red", "green", "nested", "deeper", "three", "levels", "down",
+        ] {
+            assert!(symbol_table.sid_for(&text).is_some(), "{text}");
+        }
+        assert_eq!(
+            Element::read_all(writer.output())?,
+            Element::read_all("{colors: [red, green], nested: {deeper: [(three levels), down]}}")?
+        );
+        Ok(())
+    }
+
+    /// Records whose field names, annotations, and symbol values are almost all unique.
+    fn mostly_unique_symbols() -> Vec<Element> {
+        (0..50)
+            .map(|i| {
+                let field_name = format!("field_{i}");
+                let record = ion_struct! {
+                    "id": i,
+                    field_name: Symbol::from(format!("value_{i}")),
+                };
+                record.with_annotations([format!("record_{i}")])
+            })
+            .collect()
+    }
+
+    /// Records that use the same few field names, annotations, and symbol values over and over.
+    fn mostly_repeated_symbols() -> Vec<Element> {
+        (0..50)
+            .map(|i| {
+                let record = ion_struct! {
+                    "name": Symbol::from("widget"),
+                    "color": Symbol::from(["red", "green", "blue"][i % 3]),
+                    "size": Symbol::from("large"),
+                };
+                record.with_annotations(["part"])
+            })
+            .collect()
+    }
+
+    fn write_with_interning_policy(
+        policy: SymbolInterningPolicy,
+        elements: &[Element],
+    ) -> IonResult<Vec<u8>> {
+        let config = WriteConfig::<v1_1::Binary>::new().with_symbol_interning_policy(policy);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_elements(elements)?;
+        let bytes = writer.close()?;
+        assert_eq!(
+            Element::read_all(&bytes)?,
+            elements.to_vec().into(),
+            "{policy:?}"
+        );
+        Ok(bytes)
+    }
+
+    /// Returns the number of symbol tables and encoding directives in a binary Ion 1.1 stream.
+    fn count_symbol_tables(bytes: &[u8]) -> IonResult<usize> {
+        let mut reader = SystemReader::new(v1_1::Binary, bytes);
+        let mut count = 0;
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::SymbolTable(_) | SystemStreamItem::EncodingDirective(_) => {
+                    count += 1
+                }
+                SystemStreamItem::EndOfStream(_) => return Ok(count),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn symbol_interning_policies_unique_symbols() -> IonResult<()> {
+        use SymbolInterningPolicy::*;
+        let elements = mostly_unique_symbols();
+        let always = write_with_interning_policy(AlwaysIntern, &elements)?;
+        let inline_once = write_with_interning_policy(InlineOnce { intern_after: 1 }, &elements)?;
+        let never = write_with_interning_policy(NeverIntern, &elements)?;
+        // Interning text that is only used once costs more than writing it inline.
+        assert!(never.len() < always.len());
+        assert!(inline_once.len() < always.len());
+        // Only `id` is written more than once, so it is the only text `InlineOnce` interns.
+        assert_eq!(count_symbol_tables(&inline_once)?, 1);
+        assert_eq!(count_symbol_tables(&never)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_interning_policies_repeated_symbols() -> IonResult<()> {
+        use SymbolInterningPolicy::*;
+        let elements = mostly_repeated_symbols();
+        let always = write_with_interning_policy(AlwaysIntern, &elements)?;
+        let inline_once = write_with_interning_policy(InlineOnce { intern_after: 1 }, &elements)?;
+        let inline_twice = write_with_interning_policy(InlineOnce { intern_after: 2 }, &elements)?;
+        let never = write_with_interning_policy(NeverIntern, &elements)?;
+        // Interning recurring text is smaller than repeating it inline.
+        assert!(always.len() < never.len());
+        assert!(inline_once.len() < never.len());
+        // Each time text is written inline before it is interned costs a few bytes.
+        assert!(always.len() < inline_once.len());
+        assert!(inline_once.len() < inline_twice.len());
+        assert_eq!(count_symbol_tables(&never)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn inline_once_writes_text_then_symbol_ids() -> IonResult<()> {
+        use RawSymbolRef::*;
+        let config = WriteConfig::<v1_1::Binary>::new()
+            .with_symbol_interning_policy(SymbolInterningPolicy::InlineOnce { intern_after: 2 });
+        let mut writer = Writer::new(config, Vec::new())?;
+        for _ in 0..3 {
+            writer.write_symbol("foo")?;
+        }
+        // Text that's already in the symbol table is always written as a symbol ID.
+        writer.write_symbol("name")?;
+        let bytes = writer.close()?;
+        let mut reader = SystemReader::new(v1_1::Binary, bytes.as_slice());
+        for expected in [Text("foo"), Text("foo"), SymbolId(10), SymbolId(4)] {
+            let value = reader.expect_next_value()?;
+            assert_eq!(value.raw().unwrap().read()?.expect_symbol()?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn inline_once_limits_tracked_text() -> IonResult<()> {
+        let config = WriteConfig::<v1_1::Binary>::new()
+            .with_symbol_interning_policy(SymbolInterningPolicy::InlineOnce { intern_after: 1 });
+        let mut writer = Writer::new(config, Vec::new())?;
+        for i in 0..3 * MAX_INLINE_TEXT_USES_ENTRIES {
+            writer.write_symbol(format!("unique_{i}").as_str())?;
+        }
+        let long_text = "x".repeat(MAX_INLINE_TEXT_USES_BYTES / 2 + 1);
+        for i in 0..3 {
+            writer.write_symbol(format!("{long_text}{i}").as_str())?;
+        }
+        let context = &writer.context;
+        assert!(context.inline_text_uses.len() <= MAX_INLINE_TEXT_USES_ENTRIES);
+        assert!(context.inline_text_uses_bytes <= MAX_INLINE_TEXT_USES_BYTES);
+        // None of the unique text was interned, but text that recurs still is.
+        assert_eq!(
+            context.symbol_table.len(),
+            context.symbol_table.system_len()
+        );
+        writer.write_symbol("foo")?.write_symbol("foo")?;
+        assert!(writer.context.symbol_table.sid_for(&"foo").is_some());
+        writer.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_interning_policy_is_ignored_by_binary_1_0() -> IonResult<()> {
+        let config = WriteConfig::<v1_0::Binary>::new()
+            .with_symbol_interning_policy(SymbolInterningPolicy::NeverIntern);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write_symbol("foo")?;
+        writer.flush()?;
+        assert!(writer.context.symbol_table.sid_for(&"foo").is_some());
+        assert_eq!(
+            Element::read_all(writer.output())?,
+            Element::read_all("foo")?
+        );
+        Ok(())
+    }
+
+    fn special_numeric_values() -> Vec<Element> {
+        vec![
+            Element::float(0.0),
+            Element::float(-0.0),
+            Element::float(f64::NAN),
+            Element::float(f64::INFINITY),
+            Element::float(f64::NEG_INFINITY),
+            Element::decimal(Decimal::negative_zero()),
+            Element::decimal(Decimal::negative_zero_with_exponent(2)),
+            Element::decimal(Decimal::negative_zero_with_exponent(-3)),
+            Element::decimal(Decimal::new(0, -3)),
+        ]
+    }
+
+    fn special_numeric_values_round_trip<E: Encoding>(encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let expected = special_numeric_values();
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        writer.write_elements(&expected)?;
+        let output = writer.close()?;
+        let actual: Vec<Element> = Element::read_all(output.as_slice())?.into_iter().collect();
+        // Unlike `PartialEq`, `IonEq` distinguishes negative zero from positive zero.
+        assert!(
+            IonData::eq(&expected, &actual),
+            "expected:\n{expected:?}\nwas not Ion equal to actual:\n{actual:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn special_numeric_values_round_trip_text_1_0() -> IonResult<()> {
+        special_numeric_values_round_trip(v1_0::Text)
+    }
+
+    #[test]
+    fn special_numeric_values_round_trip_binary_1_0() -> IonResult<()> {
+        special_numeric_values_round_trip(v1_0::Binary)
+    }
+
+    #[test]
+    fn special_numeric_values_round_trip_text_1_1() -> IonResult<()> {
+        special_numeric_values_round_trip(v1_1::Text)
+    }
+
+    #[test]
+    fn special_numeric_values_round_trip_binary_1_1() -> IonResult<()> {
+        special_numeric_values_round_trip(v1_1::Binary)
+    }
+
+    #[test]
+    fn special_numeric_values_text() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        writer.write_elements(&special_numeric_values())?;
+        assert_eq!(
+            String::from_utf8(writer.close()?).unwrap(),
+            "0e0 -0e0 nan +inf -inf -0. -0d2 -0.000 0.000 "
+        );
+        // f32 values take the same path as f64 values
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        writer
+            .write(-0.0f32)?
+            .write(f32::NAN)?
+            .write(f32::NEG_INFINITY)?;
+        assert_eq!(
+            String::from_utf8(writer.close()?).unwrap(),
+            "-0e0 nan -inf "
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn negative_zero_floats_binary_1_0() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer
+            .write(0.0f32)?
+            .write(-0.0f32)?
+            .write(0.0f64)?
+            .write(-0.0f64)?;
+        let encoded = writer.close()?;
+        // Only positive zero may use the zero-length encoding; negative zero needs its sign bit.
+        let body = &encoded[4..];
+        assert_eq!(
+            body,
+            &[0x40, 0x44, 0x80, 0x00, 0x00, 0x00, 0x40, 0x44, 0x80, 0x00, 0x00, 0x00]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nan_payload_is_preserved_binary_1_0() -> IonResult<()> {
+        let nan_with_payload = f64::from_bits(0x7FF0_0000_0000_0001);
+        assert!(nan_with_payload.is_nan());
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write(nan_with_payload)?;
+        let encoded = writer.close()?;
+        let actual = Element::read_one(encoded)?.expect_float()?;
+        assert_eq!(actual.to_bits(), nan_with_payload.to_bits());
+        Ok(())
+    }
+
+    #[test]
+    fn finish_segment_resets_symbol_ids_binary_1_0() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_symbol("foo")?.write_symbol("bar")?;
+        writer.finish_segment()?;
+        writer.write_symbol("bar")?.write_symbol("foo")?;
+        let bytes = writer.close()?;
+
+        // Each segment assigns IDs to its symbols in the order they were first written.
+        let mut reader = Reader::new(v1_0::Binary, bytes.as_slice())?;
+        for (text, sid) in [("foo", 10), ("bar", 11), ("bar", 10), ("foo", 11)] {
+            let value = reader.expect_next()?;
+            assert_eq!(value.read()?.expect_symbol()?, text);
+            assert_eq!(
+                value.raw().unwrap().read()?.expect_symbol()?,
+                RawSymbolRef::SymbolId(sid)
+            );
+        }
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    /// Returns each of the local symbol tables in `bytes`, read using `catalog`.
+    fn symbol_tables(bytes: &[u8], catalog: MapCatalog) -> IonResult<Sequence> {
+        let mut reader = SystemReader::new(AnyEncoding.with_catalog(catalog), bytes);
+        let mut symbol_tables = Vec::new();
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::SymbolTable(symbol_table) => {
+                    symbol_tables.push(Element::try_from(symbol_table.as_value())?)
+                }
+                SystemStreamItem::EndOfStream(_) => return Ok(symbol_tables.into()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads `text` as a series of structs and annotates each of them with `$ion_symbol_table`.
+    /// (Reading annotated structs directly would treat them as symbol tables.)
+    fn expected_symbol_tables(text: &str) -> IonResult<Sequence> {
+        let tables = Element::read_all(text)?;
+        Ok(tables
+            .into_iter()
+            .map(|table| table.with_annotations(["$ion_symbol_table"]))
+            .collect())
+    }
+
+    /// Returns the symbol ID used to encode each of the top-level symbol values in `bytes`.
+    fn symbol_ids(bytes: &[u8], catalog: MapCatalog) -> IonResult<Vec<SymbolId>> {
+        let mut reader = Reader::new(v1_0::Binary.with_catalog(catalog), bytes)?;
+        let mut sids = Vec::new();
+        while let Some(value) = reader.next()? {
+            match value.raw().unwrap().read()?.expect_symbol()? {
+                RawSymbolRef::SymbolId(sid) => sids.push(sid),
+                other => panic!("expected a symbol ID, found {other:?}"),
+            }
+        }
+        Ok(sids)
+    }
+
+    #[rstest]
+    #[case::text_1_0(v1_0::Text)]
+    #[case::binary_1_0(v1_0::Binary)]
+    #[case::text_1_1(v1_1::Text)]
+    #[case::binary_1_1(v1_1::Binary)]
+    fn reset_symbol_table_round_trip<E: Encoding>(#[case] encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        writer.write_symbol("foo")?.write_symbol("bar")?;
+        writer.reset_symbol_table()?;
+        writer
+            .write_symbol("bar")?
+            .write(Element::from(ion_struct! { "baz": "foo" }))?;
+        // Resetting a table with no local symbols is allowed.
+        writer.reset_symbol_table()?;
+        writer.reset_symbol_table()?;
+        writer.write(1)?;
+        let output = writer.close()?;
+        let expected = Element::read_all(r#"foo bar bar {baz: "foo"} 1"#)?;
+        assert_eq!(Element::read_all(output.as_slice())?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn reset_symbol_table_writes_replacement_table() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_symbol("foo")?.write_symbol("bar")?;
+        writer.reset_symbol_table()?;
+        writer.write_symbol("bar")?;
+        writer.flush()?;
+        writer.write_symbol("quux")?;
+        let output = writer.close()?;
+
+        let expected_tables = expected_symbol_tables(
+            r#"
+            {imports: $ion_symbol_table, symbols: ["foo", "bar"]}
+            {symbols: ["bar"]}
+            {imports: $ion_symbol_table, symbols: ["quux"]}
+            "#,
+        )?;
+        assert_eq!(symbol_tables(&output, MapCatalog::new())?, expected_tables);
+        // Symbol IDs restart after the system symbols once the table has been reset.
+        assert_eq!(
+            symbol_ids(&output, MapCatalog::new())?,
+            vec![10, 11, 10, 11]
+        );
+        Ok(())
+    }
+
+    fn shared_table_catalog() -> IonResult<MapCatalog> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(SharedSymbolTable::new("shared", 1, ["alpha", "beta"])?);
+        catalog.insert_table(SharedSymbolTable::new("other", 2, ["x", "y", "z"])?);
+        Ok(catalog)
+    }
+
+    #[rstest]
+    #[case::text(v1_0::Text)]
+    #[case::binary(v1_0::Binary)]
+    fn add_import<E: Encoding>(#[case] encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        writer.write_symbol("first")?;
+        writer.add_import("shared", 1, 2)?;
+        writer.add_import("other", 2, 3)?;
+        writer
+            .write_symbol(RawSymbolRef::SymbolId(10))?
+            .write_symbol(RawSymbolRef::SymbolId(13))?
+            .write_symbol("local")?;
+        writer.flush()?;
+        writer.write_symbol("first")?;
+        let output = writer.close()?;
+        let reader = Reader::new(AnyEncoding.with_catalog(shared_table_catalog()?), output)?;
+        let actual = reader.into_elements().collect::<IonResult<Sequence>>()?;
+        assert_eq!(actual, Element::read_all("first alpha y local first")?);
+        Ok(())
+    }
+
+    #[test]
+    fn add_import_writes_import_list() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_symbol("first")?;
+        writer.add_import("shared", 1, 2)?;
+        writer.add_import("other", 2, 3)?;
+        writer.write_symbol("local")?;
+        writer.flush()?;
+        writer.write_symbol("first")?;
+        let output = writer.close()?;
+
+        let expected_tables = expected_symbol_tables(
+            r#"
+            {imports: $ion_symbol_table, symbols: ["first"]}
+            {
+                imports: [
+                    {name: "shared", version: 1, max_id: 2},
+                    {name: "other", version: 2, max_id: 3},
+                ],
+                symbols: ["local"],
+            }
+            {imports: $ion_symbol_table, symbols: ["first"]}
+            "#,
+        )?;
+        assert_eq!(
+            symbol_tables(&output, shared_table_catalog()?)?,
+            expected_tables
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_import_offsets_local_symbol_ids() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.add_import("shared", 1, 2)?;
+        writer.write_symbol("local")?;
+        writer.add_import("other", 2, 3)?;
+        writer.write_symbol("local")?;
+        writer.reset_symbol_table()?;
+        writer.write_symbol("local")?;
+        let output = writer.close()?;
+        assert_eq!(
+            symbol_ids(&output, shared_table_catalog()?)?,
+            vec![12, 15, 10]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_import_rejects_invalid_imports() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        assert!(matches!(
+            writer.add_import("", 1, 2),
+            Err(IonError::IllegalOperation(_))
+        ));
+        assert!(matches!(
+            writer.add_import("shared", 0, 2),
+            Err(IonError::IllegalOperation(_))
+        ));
+        // Imported symbols are not stored individually, so a large import is cheap...
+        writer.add_import("shared", 1, usize::MAX / 2)?;
+        // ...but the imports cannot occupy more symbol IDs than there are.
+        assert!(matches!(
+            writer.add_import("other", 2, usize::MAX / 2),
+            Err(IonError::IllegalOperation(_))
+        ));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::error(UnknownTextSymbolPolicy::Error)]
+    #[case::write_sid_zero(UnknownTextSymbolPolicy::WriteSidZero)]
+    #[case::write_placeholder_text(UnknownTextSymbolPolicy::WritePlaceholderText("unknown"))]
+    fn imported_symbol_ids_do_not_have_unknown_text(
+        #[case] policy: UnknownTextSymbolPolicy,
+    ) -> IonResult<()> {
+        let config = WriteConfig::from(v1_0::Binary).with_unknown_text_symbol_policy(policy);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.add_import("shared", 1, 2)?;
+        writer
+            .write_symbol(RawSymbolRef::SymbolId(10))?
+            .write_symbol(RawSymbolRef::SymbolId(11))?
+            .write_symbol("local")?;
+        let output = writer.close()?;
+        let reader = Reader::new(AnyEncoding.with_catalog(shared_table_catalog()?), output)?;
+        let actual = reader.into_elements().collect::<IonResult<Sequence>>()?;
+        assert_eq!(actual, Element::read_all("alpha beta local")?);
+        Ok(())
+    }
+
+    fn count_ivms(bytes: &[u8]) -> IonResult<usize> {
+        let mut reader = SystemReader::new(AnyEncoding, bytes);
+        let mut count = 0;
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::VersionMarker(_) => count += 1,
+                SystemStreamItem::EndOfStream(_) => return Ok(count),
+                _ => {}
+            }
+        }
+    }
+
+    fn finish_segment_round_trip<E: Encoding>(encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let first = [Element::symbol("foo"), Element::symbol("bar")];
+        let second = [Element::symbol("bar"), ion_struct! { "baz": "foo" }.into()];
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        // Neither of these writes anything; there is no segment to finish yet.
+        writer.finish_segment()?;
+        writer.finish_segment()?;
+        writer.write_elements(&first)?;
+        writer.finish_segment()?;
+        writer.finish_segment()?;
+        writer.write_elements(&second)?;
+        let output = writer.close()?;
+
+        // Each segment after the first begins with exactly one IVM.
+        let empty_stream = Writer::new(encoding, Vec::new())?.close()?;
+        assert_eq!(count_ivms(&output)?, count_ivms(&empty_stream)? + 1);
+
+        let expected: Sequence = first.into_iter().chain(second).collect();
+        let actual = Element::read_all(output.as_slice())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn finish_segment_round_trip_text_1_0() -> IonResult<()> {
+        finish_segment_round_trip(v1_0::Text)
+    }
+
+    #[test]
+    fn finish_segment_round_trip_binary_1_0() -> IonResult<()> {
+        finish_segment_round_trip(v1_0::Binary)
+    }
+
+    #[test]
+    fn finish_segment_round_trip_text_1_1() -> IonResult<()> {
+        finish_segment_round_trip(v1_1::Text)
+    }
+
+    #[test]
+    fn finish_segment_round_trip_binary_1_1() -> IonResult<()> {
+        finish_segment_round_trip(v1_1::Binary)
+    }
+
+    /// Writes the `i`th value of a stream that uses a variety of top-level `SequenceWriter`
+    /// methods. Many of the values introduce new symbols, so most flushes include an LST append.
+    fn write_numbered_value<E: Encoding>(writer: &mut Writer<E, Vec<u8>>, i: i64) -> IonResult<()> {
+        let kind = format!("kind_{}", i % 37);
+        match i % 4 {
+            0 => writer.write(Element::from(
+                ion_struct! { "id": i, "kind": Symbol::from(kind) },
+            ))?,
+            1 => writer.write_symbol(kind)?,
+            2 => writer.write_i64(i)?,
+            _ => writer.write_list([kind.as_str(), "item"])?,
+        };
+        Ok(())
+    }
+
+    fn flush_every_n_values_matches_manual_flush<E: Encoding>(encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        // Auto-flush is disabled by default, so this writer only flushes when asked to.
+        let mut manual = Writer::new(encoding, Vec::new())?;
+        let mut auto = Writer::new(
+            WriteConfig::<E>::from(encoding).with_flush_every_n_values(10),
+            Vec::new(),
+        )?;
+        for i in 0..1_000 {
+            let manual_len = manual.output().len();
+            let auto_len = auto.output().len();
+            write_numbered_value(&mut manual, i)?;
+            write_numbered_value(&mut auto, i)?;
+            let is_flush_point = (i + 1) % 10 == 0;
+            assert_eq!(manual.output().len(), manual_len);
+            assert_eq!(auto.output().len() > auto_len, is_flush_point, "value {i}");
+            if is_flush_point {
+                manual.flush()?;
+                assert_eq!(manual.output(), auto.output());
+            }
+        }
+        assert_eq!(manual.close()?, auto.close()?);
+        Ok(())
+    }
+
+    #[test]
+    fn flush_every_n_values_matches_manual_flush_text_1_0() -> IonResult<()> {
+        flush_every_n_values_matches_manual_flush(v1_0::Text)
+    }
+
+    #[test]
+    fn flush_every_n_values_matches_manual_flush_binary_1_0() -> IonResult<()> {
+        flush_every_n_values_matches_manual_flush(v1_0::Binary)
+    }
+
+    #[test]
+    fn flush_every_n_values_matches_manual_flush_text_1_1() -> IonResult<()> {
+        flush_every_n_values_matches_manual_flush(v1_1::Text)
+    }
+
+    #[test]
+    fn flush_every_n_values_matches_manual_flush_binary_1_1() -> IonResult<()> {
+        flush_every_n_values_matches_manual_flush(v1_1::Binary)
+    }
+
+    #[test]
+    fn flush_threshold_bytes_does_not_split_values() -> IonResult<()> {
+        let config = WriteConfig::<v1_0::Binary>::new().with_flush_threshold_bytes(100);
+        let mut writer = Writer::new(config, Vec::new())?;
+        let ivm_length = writer.output().len();
+        let large = "x".repeat(250);
+
+        writer.write("small")?;
+        assert_eq!(writer.output().len(), ivm_length);
+        // This value crosses the threshold, so it is flushed in its entirety.
+        writer.write(&large)?;
+        let expected = Element::read_all(format!(r#""small" "{large}""#))?;
+        assert_eq!(Element::read_all(writer.output())?, expected);
+
+        // A container is not flushed while it is being written, nor when it is closed...
+        let flushed_length = writer.output().len();
+        let mut list = writer.list_writer()?;
+        for _ in 0..10 {
+            list.write(&large)?;
+        }
+        list.close()?;
+        assert_eq!(writer.output().len(), flushed_length);
+        // ...but once the next top-level value has been written.
+        writer.write(1)?;
+        let output = writer.close()?;
+        let values = Element::read_all(output)?;
+        assert_eq!(values.len(), 4);
+        assert_eq!(values.get(2).unwrap().expect_list()?.len(), 10);
+        Ok(())
+    }
 }
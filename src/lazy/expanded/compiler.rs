@@ -142,8 +142,8 @@ impl TemplateCompiler {
         context: EncodingContextRef,
         expression: &str,
     ) -> IonResult<TemplateMacro> {
-        // TODO: This is a rudimentary implementation that panics instead of performing thorough
-        //       validation. Where it does surface errors, the messages are too terse.
+        // TODO: This is a rudimentary implementation that does not perform thorough validation.
+        //       Where it does surface errors, the messages are too terse.
         let mut reader = Reader::new(v1_1::Text, expression.as_bytes())?;
         let macro_def_sexp = reader.expect_next()?.read()?.expect_sexp()?;
 
@@ -317,6 +317,15 @@ impl TemplateCompiler {
         while let Some(item) = param_items.next().transpose()? {
             is_final_parameter |= param_items.peek().is_none();
             let name = Self::expect_symbol_text("a parameter name", item)?.to_owned();
+            if compiled_params
+                .iter()
+                .any(|param: &Parameter| param.name() == name)
+            {
+                return IonResult::decoding_error(format!(
+                    "macro '{}' has more than one parameter named '{name}'",
+                    template_name.as_deref().unwrap_or("(anonymous)")
+                ));
+            }
             let parameter_encoding = Self::encoding_for(item)?;
 
             use ParameterCardinality::*;
@@ -366,6 +375,12 @@ impl TemplateCompiler {
         }
         let signature = MacroSignature::new(compiled_params)?;
         let body = Self::expect_next("the template body", &mut values)?;
+        if values.next().is_some() {
+            return IonResult::decoding_error(format!(
+                "macro '{}' has more than one template body expression",
+                template_name.as_deref().unwrap_or("(anonymous)")
+            ));
+        }
         let expansion_analysis = Self::analyze_body_expr(body)?;
         let mut compiled_body = TemplateBody {
             expressions: Vec::new(),
@@ -443,7 +458,9 @@ impl TemplateCompiler {
     /// If the expression could potentially produce an empty stream or a stream with multiple
     /// values, returns `false`.
     fn body_expr_must_produce_exactly_one_value<D: Decoder>(body_expr: LazyValue<D>) -> bool {
-        body_expr.ion_type() != IonType::SExp
+        // An s-expression is a macro invocation, and a symbol is a variable that could be bound to
+        // an expression group or macro invocation. Either could produce any number of values.
+        !matches!(body_expr.ion_type(), IonType::SExp | IonType::Symbol)
     }
 
     /// Recursively visits all of the expressions in `lazy_value` and adds their corresponding
@@ -1093,4 +1110,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn malformed_template_source() {
+        let resources = TestResources::new();
+        let context = resources.context();
+
+        for (expression, expected_message) in [
+            ("(macro)", "expected a macro name but found nothing"),
+            (
+                "(macro foo)",
+                "expected an s-expression defining parameters but found nothing",
+            ),
+            (
+                "(macro foo ())",
+                "expected the template body but found nothing",
+            ),
+            ("(macro foo (1) 1)", "expected a parameter name"),
+            (
+                "(macro foo (x y x) 1)",
+                "macro 'foo' has more than one parameter named 'x'",
+            ),
+            (
+                "(macro foo (x) x x)",
+                "macro 'foo' has more than one template body expression",
+            ),
+            (
+                "(macro foo () (undefined))",
+                "unrecognized macro name: undefined",
+            ),
+            (
+                "(macro foo (x) (values y))",
+                "variable 'y' is not recognized",
+            ),
+            ("(bar foo () 1)", "expected keyword 'macro', but found bar"),
+        ] {
+            let error = TemplateCompiler::compile_from_text(context.get_ref(), expression)
+                .map(|template| template.name().to_owned())
+                .unwrap_err();
+            assert!(
+                error.to_string().contains(expected_message),
+                "{expression}: {error}"
+            );
+        }
+    }
 }
@@ -9,9 +9,9 @@ use crate::lazy::decoder::{Decoder, RawValueExpr};
 use crate::lazy::encoding::TextEncoding_1_1;
 use crate::lazy::expanded::compiler::{ExpansionAnalysis, ExpansionSingleton};
 use crate::lazy::expanded::macro_evaluator::{
-    AnnotateExpansion, EExpArgGroupIterator, EExpressionArgGroup, MacroExpansion,
-    MacroExpansionKind, MacroExpr, MacroExprArgsIterator, MakeStringExpansion, RawEExpression,
-    TemplateExpansion, ValueExpr, ValuesExpansion,
+    AnnotateExpansion, ConditionalExpansion, ConditionalTest, EExpArgGroupIterator,
+    EExpressionArgGroup, MacroExpansion, MacroExpansionKind, MacroExpr, MacroExprArgsIterator,
+    MakeStringExpansion, RawEExpression, TemplateExpansion, ValueExpr, ValuesExpansion,
 };
 use crate::lazy::expanded::macro_table::{MacroKind, MacroRef};
 use crate::lazy::expanded::template::TemplateMacroRef;
@@ -108,15 +108,22 @@ impl<'top, D: Decoder> EExpression<'top, D> {
     }
 
     pub(crate) fn new_evaluation_environment(&self) -> IonResult<Environment<'top, D>> {
-        self.raw_invocation
-            .make_evaluation_environment(self.context)
+        let environment = self
+            .raw_invocation
+            .make_evaluation_environment(self.context)?;
+        self.invoked_macro
+            .signature()
+            .validate_arguments(environment.expressions())?;
+        Ok(environment)
     }
 
     pub(crate) fn expand(&self) -> IonResult<MacroExpansion<'top, D>> {
         let invoked_macro = self.invoked_macro;
         let arguments = MacroExprArgsIterator::from_eexp(self.arguments());
 
-        let mut environment = Environment::empty();
+        // System macros don't have parameters, but the values they produce still report this
+        // e-expression as their source.
+        let mut environment = Environment::empty().with_invocation(Some(self.span()));
         // Initialize a `MacroExpansionKind` with the state necessary to evaluate the requested
         // macro.
         let expansion_kind = match invoked_macro.kind() {
@@ -126,6 +133,18 @@ impl<'top, D: Decoder> EExpression<'top, D> {
                 MacroExpansionKind::MakeString(MakeStringExpansion::new(arguments))
             }
             MacroKind::Annotate => MacroExpansionKind::Annotate(AnnotateExpansion::new(arguments)),
+            MacroKind::IfVoid => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                ConditionalTest::Void,
+                arguments,
+            )),
+            MacroKind::IfSingle => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                ConditionalTest::Single,
+                arguments,
+            )),
+            MacroKind::IfMulti => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                ConditionalTest::Multi,
+                arguments,
+            )),
             MacroKind::Template(template_body) => {
                 let template_ref = TemplateMacroRef::new(invoked_macro, template_body);
                 environment = self.new_evaluation_environment()?;
@@ -111,6 +111,10 @@ where
 pub struct MacroExpr<'top, D: Decoder> {
     kind: MacroExprKind<'top, D>,
     variable: Option<TemplateVariableReference<'top>>,
+    // The environment in which this invocation's arguments must be resolved, if it differs from
+    // the one in which the invocation is eventually expanded. This is the case for invocations
+    // that are passed as arguments from one template to another.
+    environment: Option<Environment<'top, D>>,
 }
 
 impl<'top, D: Decoder> MacroExpr<'top, D> {
@@ -124,6 +128,7 @@ impl<'top, D: Decoder> MacroExpr<'top, D> {
         Self {
             kind: source,
             variable: None,
+            environment: None,
         }
     }
 
@@ -132,7 +137,15 @@ impl<'top, D: Decoder> MacroExpr<'top, D> {
         self
     }
 
+    /// Binds this invocation to the environment in which it appeared. When the invocation is
+    /// expanded, that environment will be used in place of the one provided to [`Self::expand`].
+    pub fn with_environment(mut self, environment: Environment<'top, D>) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
     pub fn expand(&self, environment: Environment<'top, D>) -> IonResult<MacroExpansion<'top, D>> {
+        let environment = self.environment.unwrap_or(environment);
         match self.kind {
             MacroExprKind::TemplateMacro(t) => t.expand(environment),
             MacroExprKind::EExp(e) => e.expand(),
@@ -365,7 +378,7 @@ impl<'top, D: Decoder> ValueExpr<'top, D> {
                     SingletonEExp(_) => todo!(),
                     ValueLiteral(literal) => Some(literal.span()),
                     Template(_, _) => None,
-                    Constructed(_, _) => None,
+                    Constructed(_, _, _) => None,
                 }
             }
             ValueExpr::MacroInvocation(e) => {
@@ -388,6 +401,7 @@ pub enum MacroExpansionKind<'top, D: Decoder> {
     Values(ValuesExpansion<'top, D>),
     MakeString(MakeStringExpansion<'top, D>),
     Annotate(AnnotateExpansion<'top, D>),
+    Conditional(ConditionalExpansion<'top, D>),
     Template(TemplateExpansion<'top>),
 }
 
@@ -425,11 +439,7 @@ impl<'top, D: Decoder> MacroExpansion<'top, D> {
         environment: Environment<'top, D>,
         invocation_to_evaluate: MacroExpr<'top, D>,
     ) -> IonResult<MacroExpansion<'top, D>> {
-        match invocation_to_evaluate.source() {
-            MacroExprKind::TemplateMacro(t) => t.expand(environment),
-            MacroExprKind::EExp(e) => e.expand(),
-            MacroExprKind::EExpArgGroup(g) => g.expand(environment),
-        }
+        invocation_to_evaluate.expand(environment)
     }
 
     pub(crate) fn new(
@@ -460,6 +470,7 @@ impl<'top, D: Decoder> MacroExpansion<'top, D> {
             Values(values_expansion) => values_expansion.next(context, environment),
             MakeString(make_string_expansion) => make_string_expansion.next(context, environment),
             Annotate(annotate_expansion) => annotate_expansion.next(context, environment),
+            Conditional(conditional_expansion) => conditional_expansion.next(context, environment),
             // `void` is trivial and requires no delegation
             Void => Ok(MacroExpansionStep::FinalStep(None)),
         }
@@ -479,6 +490,7 @@ impl<'top, D: Decoder> Debug for MacroExpansion<'top, D> {
             MacroExpansionKind::Values(_) => "values",
             MacroExpansionKind::MakeString(_) => "make_string",
             MacroExpansionKind::Annotate(_) => "annotate",
+            MacroExpansionKind::Conditional(c) => c.test.macro_name(),
             MacroExpansionKind::Template(t) => {
                 return if let Some(name) = t.template.name() {
                     write!(f, "<expansion of template '{}'>", name)
@@ -1017,6 +1029,7 @@ impl<'top, D: Decoder> MakeStringExpansion<'top, D> {
         Ok(MacroExpansionStep::FinalStep(Some(
             ValueExpr::ValueLiteral(LazyExpandedValue::from_constructed(
                 context,
+                environment,
                 EMPTY_ANNOTATIONS,
                 value_ref,
             )),
@@ -1103,14 +1116,118 @@ impl<'top, D: Decoder> AnnotateExpansion<'top, D> {
         let value_ref = context.allocator().alloc_with(|| data);
 
         // Combine our annotations vec and our value_ref to make a 'Constructed' value.
-        let annotated_value =
-            LazyExpandedValue::from_constructed(context, annotations.into_bump_slice(), value_ref);
+        let annotated_value = LazyExpandedValue::from_constructed(
+            context,
+            environment,
+            annotations.into_bump_slice(),
+            value_ref,
+        );
         Ok(MacroExpansionStep::FinalStep(Some(
             ValueExpr::ValueLiteral(annotated_value),
         )))
     }
 }
 
+// ===== Implementation of the conditional macros =====
+
+/// The property of a stream that a conditional macro tests to decide which branch to expand.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConditionalTest {
+    /// `if_void`: the stream contains no values.
+    Void,
+    /// `if_single`: the stream contains exactly one value.
+    Single,
+    /// `if_multi`: the stream contains more than one value.
+    Multi,
+}
+
+impl ConditionalTest {
+    pub fn macro_name(&self) -> &'static str {
+        match self {
+            ConditionalTest::Void => "if_void",
+            ConditionalTest::Single => "if_single",
+            ConditionalTest::Multi => "if_multi",
+        }
+    }
+
+    /// Returns `true` if a stream whose length is (at least) `num_values` passes this test.
+    /// Callers only need to count up to two values.
+    fn is_satisfied_by(&self, num_values: usize) -> bool {
+        match self {
+            ConditionalTest::Void => num_values == 0,
+            ConditionalTest::Single => num_values == 1,
+            ConditionalTest::Multi => num_values > 1,
+        }
+    }
+}
+
+/// The evaluation state of the `if_void`, `if_single`, and `if_multi` macros.
+///
+/// Each of these macros takes three arguments: a stream to test, an expression to expand if the
+/// stream passes the test, and an expression to expand if it does not. The stream is evaluated
+/// only as far as is needed to decide which branch to take; the other branch is never expanded,
+/// so it will not raise errors even if its expansion would be invalid.
+///
+/// Examples:
+///   (:if_void (:void) a b)        => a
+///   (:if_void 1 a b)              => b
+///   (:if_single 1 a b)            => a
+///   (:if_single (: 1 2) a b)      => b
+///   (:if_multi (: 1 2) (: a b) c) => a b
+#[derive(Copy, Clone, Debug)]
+pub struct ConditionalExpansion<'top, D: Decoder> {
+    test: ConditionalTest,
+    arguments: MacroExprArgsIterator<'top, D>,
+}
+
+impl<'top, D: Decoder> ConditionalExpansion<'top, D> {
+    pub fn new(test: ConditionalTest, arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self { test, arguments }
+    }
+
+    /// Yields the expression in the branch selected by testing the first argument. The evaluator
+    /// expands that expression in this expansion's environment.
+    pub fn next(
+        &mut self,
+        _context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<MacroExpansionStep<'top, D>> {
+        // Any argument that was not provided is treated as an empty stream.
+        let stream = self.arguments.next().transpose()?;
+        let true_branch = self.arguments.next().transpose()?;
+        let false_branch = self.arguments.next().transpose()?;
+        if self.arguments.next().is_some() {
+            return IonResult::decoding_error(format!(
+                "`{}` takes three parameters, received four or more",
+                self.test.macro_name()
+            ));
+        }
+
+        let num_values = match stream {
+            None => 0,
+            Some(ValueExpr::ValueLiteral(_)) => 1,
+            Some(ValueExpr::MacroInvocation(invocation)) => {
+                // Tests only distinguish between zero, one, and many values, so we can stop
+                // evaluating the stream after the second value.
+                let mut evaluator = MacroEvaluator::new_with_environment(environment);
+                evaluator.push(invocation.expand(environment)?);
+                let mut num_values = 0;
+                while num_values < 2 && evaluator.next()?.is_some() {
+                    num_values += 1;
+                }
+                num_values
+            }
+        };
+
+        let branch = if self.test.is_satisfied_by(num_values) {
+            true_branch
+        } else {
+            false_branch
+        };
+        Ok(MacroExpansionStep::FinalStep(branch))
+    }
+}
+
 // ===== Implementation of template macro expansion =====
 
 /// The evaluation state of a template expansion.
@@ -1173,7 +1290,7 @@ impl<'top> TemplateExpansion<'top> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{v1_1, ElementReader, Int, IonResult, Reader};
+    use crate::{v1_1, Element, ElementReader, Int, IonResult, Reader};
 
     /// Reads `input` and `expected` using an expanding reader and asserts that their output
     /// is the same.
@@ -1468,9 +1585,9 @@ mod tests {
         let template_definition = "(macro int_pair (flex_uint::$x flex_uint::$y) (values $x $y)))";
         let tests: &[(&[u8], (u64, u64))] = &[
             // invocation+args, expected arg values
-            (&[0x04, 0x01, 0x01], (0, 0)),
-            (&[0x04, 0x09, 0x03], (4, 1)),
-            (&[0x04, 0x0B, 0x0D], (5, 6)), // TODO: non-required cardinalities
+            (&[0x07, 0x01, 0x01], (0, 0)),
+            (&[0x07, 0x09, 0x03], (4, 1)),
+            (&[0x07, 0x0B, 0x0D], (5, 6)), // TODO: non-required cardinalities
         ];
 
         for test in tests {
@@ -1607,6 +1724,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn variables_referenced_many_times() -> IonResult<()> {
+        // Each reference to a variable is resolved to the same argument expression in the
+        // invocation's environment; the argument is not copied or re-expanded per reference.
+        let mut reader = Reader::new(
+            v1_1::Text,
+            r#"
+                (:copies_64 {
+                    name: "big_struct",
+                    fields: [1, 2, 3, {nested: (a b c)}],
+                    tags: (:values foo bar baz),
+                })
+            "#
+            .as_bytes(),
+        )?;
+        reader.register_template_src("(macro copies_8 (x) (values x x x x x x x x))")?;
+        reader.register_template_src(
+            r#"(macro copies_64 (x) (values
+                (copies_8 x) (copies_8 x) (copies_8 x) (copies_8 x)
+                (copies_8 x) (copies_8 x) (copies_8 x) (copies_8 x)))"#,
+        )?;
+        let actual = reader.read_all_elements()?;
+        let expected = Element::read_one(
+            r#"{name: "big_struct", fields: [1, 2, 3, {nested: (a b c)}], tags: foo, tags: bar, tags: baz}"#,
+        )?;
+        assert_eq!(actual.len(), 64);
+        assert!(actual.iter().all(|element| element == &expected));
+        Ok(())
+    }
+
     #[test]
     fn new_yorkers() -> IonResult<()> {
         eval_template_invocation(
@@ -1937,4 +2084,236 @@ mod tests {
         )?;
         Ok(())
     }
+
+    #[test]
+    fn malformed_invocations_are_errors() -> IonResult<()> {
+        for (template_definition, invocation, expected_message) in [
+            ("", "(:undefined)", "could not find macro with id"),
+            ("", "(:values (:undefined))", "could not find macro with id"),
+            (
+                "",
+                "(:annotate 7 8 9)",
+                "e-expression had an extra argument",
+            ),
+            (
+                "",
+                "(:annotate)",
+                "did not include an argument for param 'value_to_annotate'",
+            ),
+            (
+                "",
+                "(:annotate (:values 7) 8)",
+                "expected a string or symbol",
+            ),
+            ("", "(:make_string [a])", "expected a string or symbol"),
+            (
+                "(macro foo (x) (annotate 7 x))",
+                "(:foo 1)",
+                "expected a string or symbol",
+            ),
+        ] {
+            let mut reader = Reader::new(v1_1::Text, invocation.as_bytes())?;
+            if !template_definition.is_empty() {
+                reader.register_template_src(template_definition)?;
+            }
+            let error = reader.read_all_elements().unwrap_err();
+            assert!(
+                error.to_string().contains(expected_message),
+                "{invocation}: {error}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn templates_cannot_recurse() -> IonResult<()> {
+        // A template can only invoke macros that were already in the macro table when it was
+        // compiled, so neither direct nor mutual recursion can be expressed; expansion never
+        // needs to detect a cycle.
+        let mut reader = Reader::new(v1_1::Text, "")?;
+        // Direct recursion by name...
+        let error = reader
+            .register_template_src("(macro foo () (foo))")
+            .unwrap_err();
+        assert!(error.to_string().contains("unrecognized macro name: foo"));
+        // ...and by the address the template would have been assigned.
+        let next_address = reader.register_template_src("(macro bar () 1)")? + 1;
+        let error = reader
+            .register_template_src(&format!("(macro baz () ({next_address}))"))
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invocation of invalid macro address"));
+        // Mutual recursion would require the first template to invoke the second before the
+        // second exists.
+        let error = reader
+            .register_template_src("(macro ping () (pong))")
+            .unwrap_err();
+        assert!(error.to_string().contains("unrecognized macro name: pong"));
+        Ok(())
+    }
+
+    #[test]
+    fn deeply_nested_template_invocations() -> IonResult<()> {
+        // Each template invokes the one defined before it, producing an expansion 200 macros deep.
+        const DEPTH: usize = 200;
+        let mut reader = Reader::new(v1_1::Text, format!("(:m{DEPTH}) (:m{DEPTH})"))?;
+        reader.register_template_src("(macro m0 () (values 0))")?;
+        for depth in 1..=DEPTH {
+            let previous = depth - 1;
+            reader.register_template_src(&format!("(macro m{depth} () (m{previous}))"))?;
+        }
+        let actual = reader.read_all_elements()?;
+        assert_eq!(actual, Element::read_all("0 0")?);
+        Ok(())
+    }
+
+    #[test]
+    fn flex_uint_parameters_accept_non_negative_ints() -> IonResult<()> {
+        eval_template_invocation("(macro foo (flex_uint::x) x)", "(:foo 5) (:foo 0)", "5 0")?;
+        eval_template_invocation(
+            "(macro foo (flex_uint::x*) (values x))",
+            "(:foo) (:foo 1 2 3) (:foo (: 4 5))",
+            "1 2 3 4 5",
+        )?;
+        // A template whose body is a variable produces however many values were bound to it.
+        eval_template_invocation("(macro foo (flex_uint::x*) x)", "(:foo 1 2 3)", "1 2 3")
+    }
+
+    #[test]
+    fn flex_uint_parameters_reject_other_arguments() -> IonResult<()> {
+        let expected_prefix = "parameter 'x' has encoding flex_uint, which requires an \
+                               unannotated, non-negative int, but found";
+        for (template_definitions, invocation, found) in [
+            (
+                &["(macro foo (flex_uint::x) x)"][..],
+                r#"(:foo "hello")"#,
+                "a string",
+            ),
+            (&["(macro foo (flex_uint::x) x)"], "(:foo -1)", "an int"),
+            (
+                &["(macro foo (flex_uint::x) x)"],
+                "(:foo a::5)",
+                "an annotated int",
+            ),
+            (
+                &["(macro foo (flex_uint::x) x)"],
+                "(:foo null.int)",
+                "a null.int",
+            ),
+            (
+                &["(macro foo (flex_uint::x*) x)"],
+                r#"(:foo 1 2 "three")"#,
+                "a string",
+            ),
+            (
+                &["(macro foo (flex_uint::x*) x)"],
+                r#"(:foo (: 1 2.5))"#,
+                "a decimal",
+            ),
+            // Invocations in a template body are validated too.
+            (
+                &[
+                    "(macro foo (flex_uint::x) x)",
+                    r#"(macro bar () (foo "hello"))"#,
+                ],
+                "(:bar)",
+                "a string",
+            ),
+            (
+                &["(macro foo (flex_uint::x) x)", "(macro bar (y) (foo y))"],
+                "(:bar a)",
+                "a symbol",
+            ),
+        ] {
+            let mut reader = Reader::new(v1_1::Text, invocation.as_bytes())?;
+            for template_definition in template_definitions {
+                reader.register_template_src(template_definition)?;
+            }
+            let error = reader.read_all_elements().unwrap_err();
+            let expected_message = format!("{expected_prefix} {found}");
+            assert!(
+                error.to_string().contains(&expected_message),
+                "{invocation}: {error}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn conditional_e_expressions() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+                (:if_void (:void) a b)
+                (:if_void (:) a b)
+                (:if_void 1 a b)
+                (:if_single 1 a b)
+                (:if_single (: 1 2) a b)
+                (:if_single (:void) a b)
+                (:if_multi (: 1 2) (: a b) c)
+                (:if_multi (:values 1) (: a b) c)
+                (:if_multi (:values 1 (:values 2)) (: a b) c)
+            "#,
+            "a a b a b b a b c a b",
+        )?;
+        // A missing branch is an empty stream.
+        eval_enc_expr("(:if_void 1 a) (:if_void (:void) (:void) b)", "")?;
+        // The branch that is not taken is never expanded, so it cannot raise an error.
+        eval_enc_expr(
+            "(:if_void (:void) 1 (:make_string [a])) (:if_single 1 2 (:make_string 3))",
+            "1 2",
+        )
+    }
+
+    #[test]
+    fn conditional_omits_struct_field() -> IonResult<()> {
+        eval_template_invocation(
+            r#"
+            (macro person (name nickname?)
+                {
+                    name: name,
+                    nickname: (if_void nickname (void) (make_string "@" nickname))
+                }
+            )
+            "#,
+            r#"(:person "Alice" "ally") (:person "Bob") (:person "Carol" (:))"#,
+            r#"{name: "Alice", nickname: "@ally"} {name: "Bob"} {name: "Carol"}"#,
+        )
+    }
+
+    #[test]
+    fn nested_conditionals() -> IonResult<()> {
+        eval_template_invocation(
+            r#"(macro size (x*) (if_void x "empty" (if_single x "one" (if_multi x "many" "?"))))"#,
+            "(:size) (:size 1) (:size 1 2 3) (:size (: 4 5)) (:size (:values))",
+            r#""empty" "one" "many" "many" "empty""#,
+        )?;
+        // Conditionals in argument position are expanded with the environment of the template
+        // whose body contains them.
+        let mut reader = Reader::new(
+            v1_1::Text,
+            "(:labeled 1) (:labeled) (:labeled (: 1 2))".as_bytes(),
+        )?;
+        reader.register_template_src(
+            r#"(macro size (x*) (if_void x "empty" (if_single x "one" "many")))"#,
+        )?;
+        reader.register_template_src(
+            "(macro labeled (y*) [(size y), (if_void y (void) (size (values y y)))])",
+        )?;
+        let actual = reader.read_all_elements()?;
+        let expected = Element::read_all(r#"["one", "many"] ["empty"] ["many", "many"]"#)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn template_invocation_argument_uses_host_environment() -> IonResult<()> {
+        // `(values y y)` is expanded by `twice`, but `y` must be resolved in `outer`'s environment.
+        let mut reader = Reader::new(v1_1::Text, "(:outer a)".as_bytes())?;
+        reader.register_template_src("(macro twice (x*) (make_string x x))")?;
+        reader.register_template_src("(macro outer (y*) (twice (values y y)))")?;
+        let actual = reader.read_all_elements()?;
+        assert_eq!(actual, Element::read_all(r#""aaaa""#)?);
+        Ok(())
+    }
 }
@@ -104,6 +104,9 @@ pub enum MacroKind {
     Values,
     MakeString,
     Annotate,
+    IfVoid,
+    IfSingle,
+    IfMulti,
     Template(TemplateBody),
 }
 
@@ -175,6 +178,9 @@ impl MacroTable {
         MacroKind::Values,
         MacroKind::MakeString,
         MacroKind::Annotate,
+        MacroKind::IfVoid,
+        MacroKind::IfSingle,
+        MacroKind::IfMulti,
     ];
     pub const NUM_SYSTEM_MACROS: usize = Self::SYSTEM_MACRO_KINDS.len();
     // When a user defines new macros, this is the first ID that will be assigned. This value
@@ -260,6 +266,9 @@ impl MacroTable {
                     expansion_singleton: None,
                 },
             ),
+            Self::conditional_macro("if_void", MacroKind::IfVoid),
+            Self::conditional_macro("if_single", MacroKind::IfSingle),
+            Self::conditional_macro("if_multi", MacroKind::IfMulti),
         ];
         let mut macros_by_name = HashMap::default();
         for (id, mac) in macros_by_id.iter().enumerate() {
@@ -274,6 +283,36 @@ impl MacroTable {
         }
     }
 
+    /// Constructs one of the conditional system macros, which all share the signature
+    /// `(stream* true_branch* false_branch*)`.
+    fn conditional_macro(name: &str, kind: MacroKind) -> Macro {
+        let parameter = |name: &str| {
+            Parameter::new(
+                name,
+                ParameterEncoding::Tagged,
+                ParameterCardinality::ZeroOrMore,
+                RestSyntaxPolicy::NotAllowed,
+            )
+        };
+        Macro::named(
+            name,
+            MacroSignature::new(vec![
+                parameter("stream"),
+                parameter("true_branch"),
+                parameter("false_branch"),
+            ])
+            .unwrap(),
+            kind,
+            ExpansionAnalysis {
+                // Either branch could expand to anything.
+                could_produce_system_value: true,
+                must_produce_exactly_one_value: false,
+                can_be_lazily_evaluated_at_top_level: false,
+                expansion_singleton: None,
+            },
+        )
+    }
+
     pub fn len(&self) -> usize {
         self.macros_by_address.len()
     }
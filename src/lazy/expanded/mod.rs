@@ -35,6 +35,7 @@
 use std::cell::{Cell, UnsafeCell};
 use std::fmt::{Debug, Formatter};
 use std::ops::{Deref, Range};
+use std::sync::Arc;
 
 use bumpalo::Bump as BumpAllocator;
 
@@ -53,22 +54,27 @@ use crate::lazy::expanded::macro_evaluator::{
 use crate::lazy::expanded::macro_table::{Macro, MacroTable};
 use crate::lazy::expanded::r#struct::LazyExpandedStruct;
 use crate::lazy::expanded::sequence::Environment;
+use crate::lazy::expanded::symbol_cache::SymbolCache;
 use crate::lazy::expanded::template::{TemplateElement, TemplateMacro, TemplateValue};
+use crate::lazy::lob_reader::LobReader;
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem};
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::str_ref::StrRef;
-use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
-use crate::lazy::system_reader::{PendingContextChanges, SystemReader};
+use crate::lazy::streaming_raw_reader::{IonInput, SeekableDataSource, StreamingRawReader};
+use crate::lazy::system_reader::{
+    PendingContextChanges, ReaderCheckpoint, SymbolTableEvent, SystemReader,
+};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
 use crate::raw_symbol_ref::AsRawSymbolRef;
-use crate::result::IonFailure;
+use crate::read_config::{ReadOptions, UnresolvedSymbolIdPolicy};
+use crate::result::{type_mismatch_error, IonFailure};
 use crate::{
-    Catalog, Decimal, HasRange, HasSpan, Int, IonResult, IonType, RawStreamItem, RawSymbolRef,
-    RawVersionMarker, Span, SymbolRef, SymbolTable, Timestamp, ValueRef,
+    Catalog, Decimal, HasSpan, Int, IonResult, IonType, RawStreamItem, RawSymbolRef,
+    RawVersionMarker, Span, SymbolId, SymbolRef, SymbolTable, Timestamp, ValueRef,
 };
 
 // All of these modules (and most of their types) are currently `pub` as the lazy reader is gated
@@ -81,6 +87,7 @@ pub mod macro_evaluator;
 pub mod macro_table;
 pub mod sequence;
 pub mod r#struct;
+pub mod symbol_cache;
 pub mod template;
 
 /// A collection of resources that can be used to encode or decode Ion values.
@@ -95,6 +102,8 @@ pub struct EncodingContext {
     pub(crate) macro_table: MacroTable,
     pub(crate) symbol_table: SymbolTable,
     pub(crate) allocator: BumpAllocator,
+    pub(crate) read_options: ReadOptions,
+    pub(crate) symbol_cache: SymbolCache,
 }
 
 impl EncodingContext {
@@ -107,6 +116,8 @@ impl EncodingContext {
             macro_table,
             symbol_table,
             allocator,
+            read_options: ReadOptions::default(),
+            symbol_cache: SymbolCache::new(),
         }
     }
 
@@ -179,6 +190,21 @@ impl<'top> EncodingContextRef<'top> {
     pub fn macro_table(&self) -> &'top MacroTable {
         &self.context.macro_table
     }
+
+    /// Looks up the provided symbol ID in the active symbol table. If the symbol ID is not
+    /// defined, the reader's [`UnresolvedSymbolIdPolicy`] determines whether this returns a
+    /// symbol with unknown text or `None`.
+    pub(crate) fn resolve_symbol_id(&self, sid: SymbolId) -> Option<SymbolRef<'top>> {
+        match self.symbol_table().symbol_for(sid) {
+            Some(symbol) => Some(symbol.into()),
+            None if self.read_options.unresolved_symbol_id_policy
+                == UnresolvedSymbolIdPolicy::UnknownText =>
+            {
+                Some(SymbolRef::with_unknown_text_sid(sid))
+            }
+            None => None,
+        }
+    }
 }
 
 impl<'top> Deref for EncodingContextRef<'top> {
@@ -246,8 +272,17 @@ pub struct ExpandingReader<Encoding: Decoder, Input: IonInput> {
     pending_context_changes: UnsafeCell<PendingContextChanges>,
     encoding_context: UnsafeCell<EncodingContext>,
     catalog: Box<dyn Catalog>,
+    // An optional user-provided callback that is invoked each time pending changes are applied
+    // to the symbol table.
+    symbol_table_change_handler: UnsafeCell<Option<SymbolTableChangeHandler>>,
+    // The copy of the symbol table stored in the most recent checkpoint. If the symbol table has
+    // not changed since then, the next checkpoint can share it instead of making a new copy.
+    checkpoint_symbol_table: Option<Arc<SymbolTable>>,
 }
 
+/// A callback that is invoked each time the reader's symbol table is reset or appended to.
+pub(crate) type SymbolTableChangeHandler = Box<dyn FnMut(&SymbolTable, SymbolTableEvent)>;
+
 impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     pub(crate) fn new(
         raw_reader: StreamingRawReader<Encoding, Input>,
@@ -259,9 +294,17 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             encoding_context: EncodingContext::empty().into(),
             pending_context_changes: PendingContextChanges::new().into(),
             catalog,
+            symbol_table_change_handler: None.into(),
+            checkpoint_symbol_table: None,
         }
     }
 
+    /// Sets the callback to invoke each time the reader's symbol table changes, replacing any
+    /// callback that was previously set.
+    pub(crate) fn set_symbol_table_change_handler(&mut self, handler: SymbolTableChangeHandler) {
+        *self.symbol_table_change_handler.get_mut() = Some(handler);
+    }
+
     // TODO: This method is temporary. It will be removed when the ability to read 1.1 encoding
     //       directives from the input stream is available. Until then, template creation is manual.
     pub fn register_template_src(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
@@ -341,32 +384,39 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     }
 
     /// Updates the encoding context with the information stored in the `PendingContextChanges`.
+    /// Returns a [`SymbolTableEvent`] describing how the symbol table changed.
     fn apply_pending_context_changes(
         pending_changes: &mut PendingContextChanges,
         symbol_table: &mut SymbolTable,
         macro_table: &mut MacroTable,
-    ) {
+    ) -> SymbolTableEvent {
         if let Some(new_version) = pending_changes.switch_to_version.take() {
             symbol_table.reset_to_version(new_version);
             pending_changes.has_changes = false;
             // If we're switching to a new version, the last stream item was a version marker
             // and there are no other pending changes. The `take()` above clears the `switch_to_version`.
-            return;
+            return SymbolTableEvent::Reset;
         }
 
         if let Some(mut module) = pending_changes.take_new_active_module() {
             std::mem::swap(symbol_table, module.symbol_table_mut());
             std::mem::swap(macro_table, module.macro_table_mut());
-            return;
+            // Encoding directives replace the context wholesale; there are no other changes to
+            // apply. Clearing the flag keeps the next call from resetting the new symbol table.
+            pending_changes.has_changes = false;
+            return SymbolTableEvent::Reset;
         }
 
         // If the symbol table's `imports` field had a value of `$ion_symbol_table`, then we're
         // appending the symbols it defined to the end of our existing local symbol table.
         // Otherwise, we need to clear the existing table before appending the new symbols.
-        if !pending_changes.is_lst_append {
+        let event = if pending_changes.is_lst_append {
+            SymbolTableEvent::Append(symbol_table.len())
+        } else {
             // We're setting the symbols list, not appending to it.
             symbol_table.reset();
-        }
+            SymbolTableEvent::Reset
+        };
         // `drain()` empties the pending `imported_symbols` and `symbols` lists
         for symbol in pending_changes.imported_symbols.drain(..) {
             symbol_table.add_symbol(symbol);
@@ -376,6 +426,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         }
         pending_changes.is_lst_append = false;
         pending_changes.has_changes = false;
+        event
     }
 
     #[inline]
@@ -406,9 +457,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
                 &value,
             )?;
             pending_changes.has_changes = true;
-            let lazy_struct = LazyStruct {
-                expanded_struct: value.read()?.expect_struct().unwrap(),
-            };
+            let lazy_struct = LazyStruct::new(value.read()?.expect_struct().unwrap());
             return Ok(SystemStreamItem::SymbolTable(lazy_struct));
         } else if self.detected_encoding().version() == IonVersion::v1_1
             && SystemReader::<_, Input>::is_encoding_directive_sexp(&value)?
@@ -416,9 +465,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             let pending_changes = unsafe { &mut *self.pending_context_changes.get() };
             SystemReader::<_, Input>::process_encoding_directive(pending_changes, value)?;
             pending_changes.has_changes = true;
-            let lazy_sexp = LazySExp {
-                expanded_sexp: value.read()?.expect_sexp().unwrap(),
-            };
+            let lazy_sexp = LazySExp::new(value.read()?.expect_sexp().unwrap());
             return Ok(SystemStreamItem::EncodingDirective(lazy_sexp));
         }
         // Otherwise, it's an application value.
@@ -431,14 +478,12 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         marker: <Encoding as Decoder>::VersionMarker<'top>,
     ) -> IonResult<SystemStreamItem<'top, Encoding>> {
         let new_version = marker.stream_version_after_marker()?;
-        // If this is the first item in the stream or we're changing versions, we need to ensure
-        // the encoding context is set up for this version.
-        if marker.range().start == 0 || new_version != marker.stream_version_before_marker() {
-            // SAFETY: Version markers do not hold a reference to the symbol table.
-            let pending_changes = unsafe { &mut *self.pending_context_changes.get() };
-            pending_changes.switch_to_version = Some(new_version);
-            pending_changes.has_changes = true;
-        }
+        // Every version marker resets the encoding context to the defaults for its version, even
+        // if the stream's version is not changing.
+        // SAFETY: Version markers do not hold a reference to the symbol table.
+        let pending_changes = unsafe { &mut *self.pending_context_changes.get() };
+        pending_changes.switch_to_version = Some(new_version);
+        pending_changes.has_changes = true;
         Ok(SystemStreamItem::VersionMarker(marker))
     }
 
@@ -469,7 +514,166 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
                 &mut unsafe { &mut *self.encoding_context.get() }.symbol_table;
             let macro_table: &mut MacroTable =
                 &mut unsafe { &mut *self.encoding_context.get() }.macro_table;
-            Self::apply_pending_context_changes(pending_lst, symbol_table, macro_table);
+            let event = Self::apply_pending_context_changes(pending_lst, symbol_table, macro_table);
+            // SAFETY: The handler is only accessed here and in `set_symbol_table_change_handler`,
+            //         which requires a mutable reference to `self`.
+            if let Some(handler) = unsafe { &mut *self.symbol_table_change_handler.get() } {
+                handler(symbol_table, event);
+            }
+        }
+    }
+
+    /// Captures the position of the next top-level expression in the input stream along with the
+    /// encoding context that will be used to read it. Returns an error if the reader is still
+    /// expanding a macro invocation; the values it has not yet produced could not be revisited.
+    pub fn checkpoint(&mut self) -> IonResult<ReaderCheckpoint> {
+        if let Some(ptr) = self.evaluator_ptr.get() {
+            if !Self::ptr_to_evaluator(ptr).is_empty() {
+                return IonResult::illegal_operation(
+                    "cannot create a checkpoint while a macro invocation is being expanded",
+                );
+            }
+        }
+        // If the last expression was a symbol table or encoding directive, apply its changes now
+        // so the checkpoint reflects the context that the next expression will be read with.
+        // Because we have a mutable reference to `self`, nothing can be holding a reference to
+        // the encoding context.
+        self.between_top_level_expressions();
+        let raw_reader = self.raw_reader.get_mut();
+        let (offset, encoding) = (raw_reader.position(), raw_reader.encoding());
+        let context = self.encoding_context.get_mut();
+        let symbol_table = match &self.checkpoint_symbol_table {
+            Some(copy) if context.symbol_table.is_same_state_as(copy) => Arc::clone(copy),
+            _ => {
+                let copy = Arc::new(context.symbol_table.clone());
+                self.checkpoint_symbol_table = Some(Arc::clone(&copy));
+                copy
+            }
+        };
+        Ok(ReaderCheckpoint {
+            offset,
+            encoding,
+            symbol_table,
+            macro_table: context.macro_table.clone(),
+        })
+    }
+
+    /// Repositions the reader at the provided checkpoint and restores the encoding context that
+    /// was active there. Any macro evaluation or encoding context changes in progress are
+    /// discarded.
+    pub fn seek_to_checkpoint(&mut self, checkpoint: &ReaderCheckpoint) -> IonResult<()>
+    where
+        Input::DataSource: SeekableDataSource,
+    {
+        self.raw_reader
+            .get_mut()
+            .seek_to_offset(checkpoint.offset, checkpoint.encoding)?;
+        self.evaluator_ptr.set(None);
+        *self.pending_context_changes.get_mut() = PendingContextChanges::new();
+        let context = self.encoding_context.get_mut();
+        context.allocator.reset();
+        let symbol_table_is_unchanged = context
+            .symbol_table
+            .is_same_state_as(&checkpoint.symbol_table);
+        if !symbol_table_is_unchanged {
+            // Symbols read after the checkpoint (for example, while reading ahead speculatively)
+            // are discarded along with the rest of the table.
+            context.symbol_table = SymbolTable::clone(&checkpoint.symbol_table);
+            context.symbol_table.start_new_generation();
+        }
+        context.macro_table = checkpoint.macro_table.clone();
+        if let Some(handler) = self.symbol_table_change_handler.get_mut() {
+            handler(&context.symbol_table, SymbolTableEvent::Reset);
+        }
+        Ok(())
+    }
+
+    /// If the next application-level value is a non-null blob or clob at the top level of the
+    /// stream, returns a [`LobReader`] over its body. Any system values that precede it are
+    /// processed. If the next value is anything else, returns `Ok(None)` and leaves that value
+    /// to be read by `next_value`.
+    ///
+    /// Unannotated lobs in binary Ion 1.0 are read from the input source as the caller requests
+    /// their bytes. Lobs in any other encoding, and annotated lobs, are buffered in full before
+    /// being returned. See [`Reader::next_lob`](crate::Reader::next_lob).
+    pub fn next_lob(&mut self) -> IonResult<Option<LobReader<'_>>> {
+        if let Some(ptr) = self.evaluator_ptr.get() {
+            if !Self::ptr_to_evaluator(ptr).is_empty() {
+                // The next value will come from a macro invocation that is being expanded.
+                return Ok(None);
+            }
+        }
+        loop {
+            self.between_top_level_expressions();
+            // SAFETY: This is the only reference to the raw reader in this iteration. It is either
+            //         returned inside the `LobReader`, which borrows `self` mutably, or is no longer
+            //         used once `next_system_item` advances the raw reader below.
+            let raw_reader = unsafe { self.raw_reader_mut() };
+            if let Some(ion_type) = raw_reader.start_lob()? {
+                return Ok(Some(LobReader::streaming(ion_type, raw_reader)));
+            }
+
+            let allocator: &BumpAllocator = self.context().allocator();
+            let context_ref = EncodingContextRef::new(allocator.alloc_with(|| self.context()));
+            let is_lob = match raw_reader.peek_next(context_ref)? {
+                RawStreamItem::VersionMarker(_) => false,
+                RawStreamItem::Value(raw_value) => {
+                    let value = LazyExpandedValue::from_literal(context_ref, raw_value);
+                    if matches!(value.ion_type(), IonType::Blob | IonType::Clob) {
+                        if value.is_null() {
+                            return Ok(None);
+                        }
+                        true
+                    } else if self.is_system_value(&value)? {
+                        false
+                    } else {
+                        return Ok(None);
+                    }
+                }
+                // E-expressions and the end of the stream are left for `next_value`.
+                _ => return Ok(None),
+            };
+
+            // Consume the item we peeked at. System values are processed as usual.
+            let SystemStreamItem::Value(value) = self.next_system_item()? else {
+                continue;
+            };
+            debug_assert!(is_lob);
+            let lob_reader = match value.read()? {
+                ValueRef::Blob(bytes) => LobReader::buffered(IonType::Blob, bytes.data()),
+                ValueRef::Clob(bytes) => LobReader::buffered(IonType::Clob, bytes.data()),
+                other => unreachable!("peeked at a lob but read {other:?}"),
+            };
+            return Ok(Some(lob_reader));
+        }
+    }
+
+    /// Returns a mutable reference to the raw reader given only a shared reference to `self`.
+    ///
+    /// `next_lob` peeks at the next raw item using an encoding context borrowed from `self`, and
+    /// may then return a `LobReader` that holds the raw reader; the borrow checker cannot see that
+    /// these uses do not overlap.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not use the returned reference while any other reference to the raw
+    /// reader is in use, including one returned by an earlier call to this method.
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn raw_reader_mut(&self) -> &mut StreamingRawReader<Encoding, Input> {
+        &mut *self.raw_reader.get()
+    }
+
+    /// Returns `true` if `value` is a symbol table or (in Ion 1.1) an encoding directive.
+    fn is_system_value(&self, value: &LazyExpandedValue<'_, Encoding>) -> IonResult<bool> {
+        if !value.has_annotations() {
+            return Ok(false);
+        }
+        match value.ion_type() {
+            IonType::Struct => SystemReader::<_, Input>::is_symbol_table_struct(value),
+            IonType::SExp if self.detected_encoding().version() == IonVersion::v1_1 => {
+                SystemReader::<_, Input>::is_encoding_directive_sexp(value)
+            }
+            _ => Ok(false),
         }
     }
 
@@ -694,6 +898,7 @@ pub enum ExpandedValueSource<'top, D: Decoder> {
     Constructed(
         // TODO: Make this an associated type on the LazyDecoder trait so 1.0 types can set
         //       it to `Never` and the compiler can eliminate this code path where applicable.
+        Environment<'top, D>, // The environment of the macro that constructed the value
         // Constructed data stored in the bump allocator. Holding references instead of the data
         // itself allows this type (and those that contain it) to impl `Copy`.
         &'top [SymbolRef<'top>], // Annotations (if any)
@@ -709,7 +914,7 @@ impl<'top, Encoding: Decoder> Debug for ExpandedValueSource<'top, Encoding> {
             ExpandedValueSource::Template(_, template_element) => {
                 write!(f, "template {:?}", template_element.value())
             }
-            ExpandedValueSource::Constructed(_, value) => write!(f, "constructed {value:?}"),
+            ExpandedValueSource::Constructed(_, _, value) => write!(f, "constructed {value:?}"),
         }
     }
 }
@@ -865,12 +1070,13 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
 
     pub(crate) fn from_constructed(
         context: EncodingContextRef<'top>,
+        environment: Environment<'top, Encoding>,
         annotations: &'top [SymbolRef<'top>],
         value: &'top ValueRef<'top, Encoding>,
     ) -> Self {
         Self {
             context,
-            source: ExpandedValueSource::Constructed(annotations, value),
+            source: ExpandedValueSource::Constructed(environment, annotations, value),
             variable: None,
         }
     }
@@ -885,7 +1091,7 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
         match &self.source {
             ValueLiteral(value) => value.ion_type(),
             Template(_, element) => element.value().ion_type(),
-            Constructed(_annotations, value) => value.ion_type(),
+            Constructed(_, _annotations, value) => value.ion_type(),
             SingletonEExp(eexp) => eexp.require_expansion_singleton().ion_type(),
         }
     }
@@ -895,7 +1101,7 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
         match &self.source {
             ValueLiteral(value) => value.is_null(),
             Template(_, element) => element.value().is_null(),
-            Constructed(_, value) => {
+            Constructed(_, _, value) => {
                 matches!(value, ValueRef::Null(_))
             }
             SingletonEExp(eexp) => eexp.require_expansion_singleton().is_null(),
@@ -907,7 +1113,7 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
         match &self.source {
             ValueLiteral(value) => value.has_annotations(),
             Template(_, element) => !element.annotations().is_empty(),
-            Constructed(annotations, _) => !annotations.is_empty(),
+            Constructed(_, annotations, _) => !annotations.is_empty(),
             SingletonEExp(eexp) => eexp.require_expansion_singleton().has_annotations(),
         }
     }
@@ -921,7 +1127,7 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
             Template(_, element) => ExpandedAnnotationsIterator::new(
                 ExpandedAnnotationsSource::Template(SymbolsIterator::new(element.annotations())),
             ),
-            Constructed(annotations, _value) => {
+            Constructed(_, annotations, _value) => {
                 // For now we return an empty iterator
                 ExpandedAnnotationsIterator::new(ExpandedAnnotationsSource::Constructed(
                     annotations.iter(),
@@ -943,7 +1149,7 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
                 *environment,
                 element,
             )),
-            Constructed(_annotations, value) => Ok((**value).as_expanded()),
+            Constructed(_, _annotations, value) => Ok((**value).as_expanded()),
             SingletonEExp(ref eexp) => eexp.expand_to_single_value()?.read(),
         }
     }
@@ -956,7 +1162,7 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
             Template(environment, element) => {
                 Ok(ValueRef::from_template(self.context, *environment, element))
             }
-            Constructed(_annotations, value) => Ok(**value),
+            Constructed(_, _annotations, value) => Ok((**value).clone()),
             SingletonEExp(ref eexp) => self.read_resolved_singleton_eexp(eexp),
         }
     }
@@ -1003,18 +1209,24 @@ impl<'top, Encoding: Decoder> LazyExpandedValue<'top, Encoding> {
         self.variable
     }
 
+    /// Returns the range of input bytes that produced this value. For a value literal, this is the
+    /// range of the encoded value, including its annotations. For a value produced by evaluating a
+    /// macro, this is the range of the e-expression that was evaluated.
+    ///
+    /// Returns `None` if the value was produced by a macro evaluation that did not begin with an
+    /// e-expression in the input stream.
     pub fn range(&self) -> Option<Range<usize>> {
-        if let ExpandedValueSource::ValueLiteral(value) = &self.source {
-            return Some(value.range());
-        }
-        None
+        self.span().map(|span| span.range())
     }
 
+    /// Like [`range`](Self::range), but returns the input bytes themselves.
     pub fn span(&self) -> Option<Span<'top>> {
-        if let ExpandedValueSource::ValueLiteral(value) = &self.source {
-            return Some(value.span());
+        use ExpandedValueSource::*;
+        match &self.source {
+            ValueLiteral(value) => Some(value.span()),
+            SingletonEExp(eexp) => Some(eexp.span()),
+            Template(environment, _) | Constructed(environment, _, _) => environment.invocation(),
         }
-        None
     }
 }
 
@@ -1022,7 +1234,7 @@ impl<'top, Encoding: Decoder> From<LazyExpandedValue<'top, Encoding>>
     for LazyValue<'top, Encoding>
 {
     fn from(expanded_value: LazyExpandedValue<'top, Encoding>) -> Self {
-        LazyValue { expanded_value }
+        LazyValue::new(expanded_value)
     }
 }
 
@@ -1030,19 +1242,19 @@ impl<'top, Encoding: Decoder> From<LazyExpandedStruct<'top, Encoding>>
     for LazyStruct<'top, Encoding>
 {
     fn from(expanded_struct: LazyExpandedStruct<'top, Encoding>) -> Self {
-        LazyStruct { expanded_struct }
+        LazyStruct::new(expanded_struct)
     }
 }
 
 impl<'top, Encoding: Decoder> From<LazyExpandedSExp<'top, Encoding>> for LazySExp<'top, Encoding> {
     fn from(expanded_sexp: LazyExpandedSExp<'top, Encoding>) -> Self {
-        LazySExp { expanded_sexp }
+        LazySExp::new(expanded_sexp)
     }
 }
 
 impl<'top, Encoding: Decoder> From<LazyExpandedList<'top, Encoding>> for LazyList<'top, Encoding> {
     fn from(expanded_list: LazyExpandedList<'top, Encoding>) -> Self {
-        LazyList { expanded_list }
+        LazyList::new(expanded_list)
     }
 }
 
@@ -1077,7 +1289,7 @@ impl<'top, Encoding: Decoder> Iterator for ExpandedAnnotationsIterator<'top, Enc
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub enum ExpandedValueRef<'top, Encoding: Decoder> {
     Null(IonType),
     Bool(bool),
@@ -1119,10 +1331,37 @@ impl<'top, Encoding: Decoder> PartialEq for ExpandedValueRef<'top, Encoding> {
 }
 
 impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
-    fn expected<T>(self, expected_name: &str) -> IonResult<T> {
-        IonResult::decoding_error(format!(
-            "expected a(n) {} but found a {:?}",
-            expected_name, self
+    /// Returns the range of input bytes that produced this value if it is a container. Scalar
+    /// variants hold only the decoded data, so they return `None`.
+    fn container_range(&self) -> Option<Range<usize>> {
+        use crate::lazy::decoder::LazyRawContainer;
+        use crate::lazy::expanded::r#struct::ExpandedStructSource;
+        use sequence::{ExpandedListSource, ExpandedSExpSource};
+        use ExpandedValueRef::*;
+        match self {
+            SExp(s) => match s.source {
+                ExpandedSExpSource::ValueLiteral(v) => Some(v.as_value().span()),
+                ExpandedSExpSource::Template(environment, _) => environment.invocation(),
+            },
+            List(l) => match l.source {
+                ExpandedListSource::ValueLiteral(v) => Some(v.as_value().span()),
+                ExpandedListSource::Template(environment, _) => environment.invocation(),
+            },
+            Struct(s) => match s.source {
+                ExpandedStructSource::ValueLiteral(v) => Some(v.as_value().span()),
+                ExpandedStructSource::Template(environment, _, _) => environment.invocation(),
+            },
+            _ => None,
+        }
+        .map(|span| span.range())
+    }
+
+    fn expected<T>(self, expected: &str) -> IonResult<T> {
+        Err(type_mismatch_error(
+            expected,
+            self.ion_type(),
+            matches!(self, ExpandedValueRef::Null(_)),
+            self.container_range(),
         ))
     }
 
@@ -1130,7 +1369,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Null(ion_type) = self {
             Ok(ion_type)
         } else {
-            self.expected("null")
+            self.expected("a null")
         }
     }
 
@@ -1138,7 +1377,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Bool(b) = self {
             Ok(b)
         } else {
-            self.expected("bool")
+            self.expected("a bool")
         }
     }
 
@@ -1146,7 +1385,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Int(i) = self {
             Ok(i)
         } else {
-            self.expected("int")
+            self.expected("an int")
         }
     }
 
@@ -1154,7 +1393,27 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Int(i) = self {
             i.expect_i64()
         } else {
-            self.expected("i64 (int)")
+            self.expected("an i64 (int)")
+        }
+    }
+
+    /// Like [`expect_i64`](Self::expect_i64), but returns an `Err` if the int is negative or
+    /// too large to fit in a `u64`.
+    pub fn expect_u64(self) -> IonResult<u64> {
+        if let ExpandedValueRef::Int(i) = self {
+            i.expect_u64()
+        } else {
+            self.expected("a u64 (int)")
+        }
+    }
+
+    /// Like [`expect_i64`](Self::expect_i64), but returns an `Err` if the int is negative or
+    /// too large to fit in a `usize`.
+    pub fn expect_usize(self) -> IonResult<usize> {
+        if let ExpandedValueRef::Int(i) = self {
+            i.expect_usize()
+        } else {
+            self.expected("a usize (int)")
         }
     }
 
@@ -1162,7 +1421,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Float(f) = self {
             Ok(f)
         } else {
-            self.expected("float")
+            self.expected("a float")
         }
     }
 
@@ -1170,7 +1429,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Decimal(d) = self {
             Ok(d)
         } else {
-            self.expected("decimal")
+            self.expected("a decimal")
         }
     }
 
@@ -1178,7 +1437,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Timestamp(t) = self {
             Ok(t)
         } else {
-            self.expected("timestamp")
+            self.expected("a timestamp")
         }
     }
 
@@ -1186,7 +1445,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::String(s) = self {
             Ok(s)
         } else {
-            self.expected("string")
+            self.expected("a string")
         }
     }
 
@@ -1194,7 +1453,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Symbol(s) = self {
             Ok(s)
         } else {
-            self.expected("symbol")
+            self.expected("a symbol")
         }
     }
 
@@ -1202,7 +1461,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Blob(b) = self {
             Ok(b)
         } else {
-            self.expected("blob")
+            self.expected("a blob")
         }
     }
 
@@ -1210,7 +1469,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Clob(c) = self {
             Ok(c)
         } else {
-            self.expected("clob")
+            self.expected("a clob")
         }
     }
 
@@ -1218,7 +1477,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::List(s) = self {
             Ok(s)
         } else {
-            self.expected("list")
+            self.expected("a list")
         }
     }
 
@@ -1226,7 +1485,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::SExp(s) = self {
             Ok(s)
         } else {
-            self.expected("sexp")
+            self.expected("a sexp")
         }
     }
 
@@ -1234,7 +1493,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         if let ExpandedValueRef::Struct(s) = self {
             Ok(s)
         } else {
-            self.expected("struct")
+            self.expected("a struct")
         }
     }
 
@@ -1290,7 +1549,7 @@ impl<'top, Encoding: Decoder> ExpandedValueRef<'top, Encoding> {
         match element.value() {
             Null(ion_type) => ExpandedValueRef::Null(*ion_type),
             Bool(b) => ExpandedValueRef::Bool(*b),
-            Int(i) => ExpandedValueRef::Int(*i),
+            Int(i) => ExpandedValueRef::Int(i.clone()),
             Float(f) => ExpandedValueRef::Float(*f),
             Decimal(d) => ExpandedValueRef::Decimal(*d),
             Timestamp(t) => ExpandedValueRef::Timestamp(*t),
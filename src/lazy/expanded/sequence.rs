@@ -1,10 +1,11 @@
 use crate::element::iterators::SymbolsIterator;
-use crate::lazy::decoder::{Decoder, LazyRawSequence, LazyRawValueExpr, RawValueExpr};
+use crate::lazy::decoder::{Decoder, HasSpan, LazyRawSequence, LazyRawValueExpr, RawValueExpr};
 use crate::lazy::expanded::macro_evaluator::{MacroEvaluator, RawEExpression, ValueExpr};
 use crate::lazy::expanded::template::{TemplateElement, TemplateSequenceIterator};
 use crate::lazy::expanded::{
     EncodingContextRef, ExpandedAnnotationsIterator, ExpandedAnnotationsSource, LazyExpandedValue,
 };
+use crate::lazy::span::Span;
 use crate::{try_or_some_err, IonResult, IonType};
 
 /// A sequence of not-yet-evaluated expressions passed as arguments to a macro invocation.
@@ -22,14 +23,38 @@ use crate::{try_or_some_err, IonResult, IonType};
 /// ```
 /// The `Environment` would contain the expressions `1`, `2` and `(:values 3)`, corresponding to parameters
 /// `x`, `y`, and `z` respectively.
+///
+/// An `Environment` borrows its expressions from the bump allocator and is itself `Copy`. Resolving
+/// a variable returns a copy of the (lazy) argument expression rather than a materialized value, so
+/// a variable that is referenced many times in a template body is never deep-cloned; each reference
+/// simply re-reads or re-expands the same argument.
 #[derive(Copy, Clone, Debug)]
 pub struct Environment<'top, D: Decoder> {
     expressions: &'top [ValueExpr<'top, D>],
+    // The e-expression in the input stream whose evaluation created this environment, if any.
+    // Template macros invoked from the body of another template inherit their parent's invocation.
+    invocation: Option<Span<'top>>,
 }
 
 impl<'top, D: Decoder> Environment<'top, D> {
     pub(crate) fn new(args: &'top [ValueExpr<'top, D>]) -> Self {
-        Environment { expressions: args }
+        Environment {
+            expressions: args,
+            invocation: None,
+        }
+    }
+
+    /// Returns a copy of this environment that is associated with the e-expression found at
+    /// `invocation` in the input stream.
+    pub(crate) fn with_invocation(mut self, invocation: Option<Span<'top>>) -> Self {
+        self.invocation = invocation;
+        self
+    }
+
+    /// Returns the span of the e-expression in the input stream whose evaluation created this
+    /// environment. Values produced by that evaluation report this span as their own.
+    pub fn invocation(&self) -> Option<Span<'top>> {
+        self.invocation
     }
 
     /// Returns the expression for the corresponding signature index -- the variable's offset within
@@ -48,7 +73,10 @@ impl<'top, D: Decoder> Environment<'top, D> {
     /// Returns an empty environment without performing any allocations. This is used for evaluating
     /// e-expressions, which never have named parameters.
     pub const fn empty() -> Environment<'top, D> {
-        Environment { expressions: &[] }
+        Environment {
+            expressions: &[],
+            invocation: None,
+        }
     }
     pub fn expressions(&self) -> &'top [ValueExpr<'top, D>] {
         self.expressions
@@ -64,7 +92,7 @@ impl<'top, D: Decoder> Environment<'top, D> {
             env_exprs.push(expr?.resolve(context)?);
         }
 
-        Ok(Environment::new(env_exprs.into_bump_slice()))
+        Ok(Environment::new(env_exprs.into_bump_slice()).with_invocation(Some(eexp.span())))
     }
 }
 
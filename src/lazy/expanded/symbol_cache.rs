@@ -0,0 +1,180 @@
+use std::cell::{Cell, RefCell};
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+
+use crate::{Symbol, SymbolRef};
+
+/// Caches the text of symbols that have been materialized (converted from a [`SymbolRef`] to an
+/// owned [`Symbol`]) so that text appearing repeatedly in a stream--for example, an annotation
+/// on every top-level value--shares a single allocation.
+///
+/// The cache is keyed by text rather than symbol ID, so its entries remain valid when the symbol
+/// table changes. To bound its memory usage, the cache stops accepting new entries once it holds
+/// [`SymbolCache::MAX_ENTRIES`] texts, and never caches text longer than
+/// [`SymbolCache::MAX_TEXT_LENGTH`] bytes. Text that is not cached is materialized as it would be
+/// without the cache.
+#[derive(Debug, Default)]
+pub struct SymbolCache {
+    texts: RefCell<FxHashSet<Arc<str>>>,
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+}
+
+impl SymbolCache {
+    /// The maximum number of distinct texts the cache will hold.
+    pub const MAX_ENTRIES: usize = 1024;
+    /// The length in bytes of the longest text the cache will hold.
+    pub const MAX_TEXT_LENGTH: usize = 128;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts `symbol` into an owned [`Symbol`], sharing its text with previously materialized
+    /// symbols when possible.
+    pub fn materialize(&self, symbol: SymbolRef<'_>) -> Symbol {
+        let Some(text) = symbol.text() else {
+            return symbol.to_owned();
+        };
+        if text.len() > Self::MAX_TEXT_LENGTH {
+            return symbol.to_owned();
+        }
+        let mut texts = self.texts.borrow_mut();
+        if let Some(shared_text) = texts.get(text) {
+            self.hits.set(self.hits.get() + 1);
+            return Symbol::shared(Arc::clone(shared_text));
+        }
+        self.misses.set(self.misses.get() + 1);
+        let shared_text: Arc<str> = Arc::from(text);
+        if texts.len() < Self::MAX_ENTRIES {
+            texts.insert(Arc::clone(&shared_text));
+        }
+        Symbol::shared(shared_text)
+    }
+
+    /// Returns a summary of the cache's contents and how effective it has been.
+    pub fn stats(&self) -> SymbolCacheStats {
+        SymbolCacheStats {
+            hits: self.hits.get(),
+            misses: self.misses.get(),
+            len: self.texts.borrow().len(),
+        }
+    }
+}
+
+/// Statistics describing a [`SymbolCache`]. See [`SymbolCache::stats`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SymbolCacheStats {
+    hits: usize,
+    misses: usize,
+    len: usize,
+}
+
+impl SymbolCacheStats {
+    /// The number of materialized symbols whose text was found in the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of materialized symbols whose text was not found in the cache.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// The number of distinct texts in the cache.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the cache does not contain any text.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The fraction of materialized symbols whose text was found in the cache, from `0.0` to
+    /// `1.0`. If no symbols have been materialized, returns `0.0`.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::decoder::Decoder;
+    use crate::lazy::streaming_raw_reader::IonInput;
+    use crate::{
+        ion_struct, v1_0, AnyEncoding, Element, IntoAnnotatedElement, IonResult, Reader, Sequence,
+    };
+
+    // Materializes each value in `reader`, confirming that all of their annotations share the
+    // same allocation. Returns the number of values read.
+    fn materialize_all<D: Decoder, I: IonInput>(reader: &mut Reader<D, I>) -> IonResult<usize> {
+        let mut first_annotation_text: Option<*const u8> = None;
+        let mut count = 0;
+        while let Some(value) = reader.next()? {
+            let element = Element::try_from(value)?;
+            let annotation = element.annotations().iter().next().unwrap();
+            let text = annotation.text().unwrap();
+            assert_eq!(text, "event");
+            assert_eq!(
+                *first_annotation_text.get_or_insert(text.as_ptr()),
+                text.as_ptr()
+            );
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    #[test]
+    fn repeated_annotations_share_text() -> IonResult<()> {
+        let record: Element = ion_struct! {"name": "click"}.with_annotations(["event"]);
+        let records: Vec<Element> = vec![record; 100_000];
+        let binary_ion = Sequence::from(records).encode_as(v1_0::Binary)?;
+        // Reading text Ion is considerably slower in unoptimized builds, so we use fewer values.
+        let text_ion = "event::{name: \"click\"} ".repeat(10_000);
+
+        let mut reader = Reader::new(AnyEncoding, binary_ion)?;
+        assert_eq!(materialize_all(&mut reader)?, 100_000);
+        let stats = reader.symbol_cache_stats();
+        // One miss for the annotation `event` and one for the field name `name`.
+        assert_eq!(stats.misses(), 2);
+        assert_eq!(stats.hits(), 199_998);
+        assert!(stats.hit_rate() > 0.99, "{stats:?}");
+
+        let mut reader = Reader::new(AnyEncoding, text_ion)?;
+        assert_eq!(materialize_all(&mut reader)?, 10_000);
+        let stats = reader.symbol_cache_stats();
+        assert_eq!(stats.misses(), 2);
+        assert_eq!(stats.len(), 2);
+        assert!(stats.hit_rate() > 0.99, "{stats:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn cache_is_bounded() {
+        let cache = SymbolCache::new();
+        for i in 0..SymbolCache::MAX_ENTRIES * 2 {
+            let text = format!("symbol{i}");
+            let symbol = cache.materialize(SymbolRef::with_text(&text));
+            assert_eq!(symbol.text(), Some(text.as_str()));
+        }
+        let long_text = "x".repeat(SymbolCache::MAX_TEXT_LENGTH + 1);
+        let symbol = cache.materialize(SymbolRef::with_text(&long_text));
+        assert_eq!(symbol.text(), Some(long_text.as_str()));
+        assert_eq!(
+            cache.materialize(SymbolRef::with_unknown_text()),
+            Symbol::unknown_text()
+        );
+
+        let stats = cache.stats();
+        assert_eq!(stats.len(), SymbolCache::MAX_ENTRIES);
+        assert_eq!(stats.misses(), SymbolCache::MAX_ENTRIES * 2);
+        assert_eq!(stats.hits(), 0);
+    }
+}
@@ -9,10 +9,11 @@ use crate::{Bytes, Decimal, Int, IonResult, IonType, LazyExpandedFieldName, Str,
 use crate::lazy::binary::raw::v1_1::immutable_buffer::ArgGroupingBitmap;
 use crate::lazy::decoder::Decoder;
 use crate::lazy::expanded::{
-    EncodingContextRef, ExpandedValueSource, LazyExpandedValue, TemplateVariableReference,
+    EncodingContextRef, ExpandedValueRef, ExpandedValueSource, LazyExpandedValue,
+    TemplateVariableReference,
 };
 use crate::lazy::expanded::compiler::ExpansionAnalysis;
-use crate::lazy::expanded::macro_evaluator::{AnnotateExpansion, MacroEvaluator, MacroExpansion, MacroExpansionKind, MacroExpr, MacroExprArgsIterator, MakeStringExpansion, TemplateExpansion, ValueExpr, ValuesExpansion};
+use crate::lazy::expanded::macro_evaluator::{AnnotateExpansion, ConditionalExpansion, ConditionalTest, MacroEvaluator, MacroExpansion, MacroExpansionKind, MacroExpr, MacroExprArgsIterator, MacroExprKind, MakeStringExpansion, TemplateExpansion, ValueExpr, ValuesExpansion};
 use crate::lazy::expanded::macro_table::{Macro, MacroKind, MacroRef};
 use crate::lazy::expanded::r#struct::UnexpandedField;
 use crate::lazy::expanded::sequence::Environment;
@@ -56,6 +57,61 @@ impl Parameter {
     pub fn can_be_omitted(&self) -> bool {
         matches!(self.cardinality, ParameterCardinality::ZeroOrOne | ParameterCardinality::ZeroOrMore)
     }
+
+    /// Returns an error if `argument` cannot be bound to this parameter because it is not
+    /// compatible with the parameter's encoding.
+    ///
+    /// Any expression can be bound to a tagged parameter. A `flex_uint` parameter requires an
+    /// unannotated, non-negative int. If the argument is an expression group, each of the
+    /// expressions in the group is validated. Other macro invocations are not expanded here, so
+    /// the values they produce are not checked.
+    pub(crate) fn validate_argument<D: Decoder>(
+        &self,
+        argument: ValueExpr<'_, D>,
+    ) -> IonResult<()> {
+        if self.encoding == ParameterEncoding::Tagged {
+            return Ok(());
+        }
+        match argument {
+            ValueExpr::ValueLiteral(value) => self.validate_value(value),
+            ValueExpr::MacroInvocation(invocation) => match invocation.source() {
+                MacroExprKind::EExpArgGroup(group) => {
+                    for expr in group.expressions() {
+                        self.validate_argument(expr?)?;
+                    }
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+        }
+    }
+
+    fn validate_value<D: Decoder>(&self, value: LazyExpandedValue<'_, D>) -> IonResult<()> {
+        let (is_valid, encoding, expected) = match self.encoding {
+            ParameterEncoding::Tagged => return Ok(()),
+            ParameterEncoding::FlexUInt => (
+                !value.has_annotations()
+                    && matches!(value.read()?, ExpandedValueRef::Int(i) if !i.is_negative()),
+                "flex_uint",
+                "an unannotated, non-negative int",
+            ),
+        };
+        if is_valid {
+            return Ok(());
+        }
+        let ion_type = value.ion_type();
+        let found = match (value.has_annotations(), value.is_null(), ion_type) {
+            (true, true, _) => format!("an annotated null.{ion_type}"),
+            (true, false, _) => format!("an annotated {ion_type}"),
+            (false, true, _) => format!("a null.{ion_type}"),
+            (false, false, IonType::Int) => format!("an {ion_type}"),
+            (false, false, _) => format!("a {ion_type}"),
+        };
+        IonResult::decoding_error(format!(
+            "parameter '{}' has encoding {encoding}, which requires {expected}, but found {found}",
+            self.name,
+        ))
+    }
 }
 
 /// The encoding used to serialize and deserialize the associated parameter.
@@ -137,6 +193,17 @@ impl MacroSignature {
     pub fn num_variadic_params(&self) -> usize {
         self.num_variadic_params
     }
+    /// Returns an error if any of the `arguments` cannot be bound to its corresponding parameter.
+    /// See [`Parameter::validate_argument`] for details.
+    pub(crate) fn validate_arguments<D: Decoder>(
+        &self,
+        arguments: &[ValueExpr<'_, D>],
+    ) -> IonResult<()> {
+        for (parameter, argument) in self.parameters.iter().zip(arguments) {
+            parameter.validate_argument(*argument)?;
+        }
+        Ok(())
+    }
     pub fn bitmap_size_in_bytes(&self) -> usize {
         const BITS_PER_VARIADIC_PARAM: usize = 2;
         const BITS_PER_BYTE: usize = 8;
@@ -893,7 +960,11 @@ impl<'top> TemplateMacroInvocation<'top> {
         for arg in arguments {
             env_exprs.push(arg?);
         }
-        Ok(Environment::new(env_exprs.into_bump_slice()))
+        self.invoked_macro()
+            .signature()
+            .validate_arguments(&env_exprs)?;
+        Ok(Environment::new(env_exprs.into_bump_slice())
+            .with_invocation(parent_environment.invocation()))
     }
 
     pub fn expand<D: Decoder>(&self, mut environment: Environment<'top, D>) -> IonResult<MacroExpansion<'top, D>> {
@@ -908,6 +979,18 @@ impl<'top> TemplateMacroInvocation<'top> {
                 MacroExpansionKind::MakeString(MakeStringExpansion::new(arguments))
             }
             MacroKind::Annotate => MacroExpansionKind::Annotate(AnnotateExpansion::new(arguments)),
+            MacroKind::IfVoid => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                ConditionalTest::Void,
+                arguments,
+            )),
+            MacroKind::IfSingle => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                ConditionalTest::Single,
+                arguments,
+            )),
+            MacroKind::IfMulti => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                ConditionalTest::Multi,
+                arguments,
+            )),
             MacroKind::Template(template_body) => {
                 let template_ref = TemplateMacroRef::new(macro_ref, template_body);
                 environment = self.new_evaluation_environment(environment)?;
@@ -982,7 +1065,9 @@ impl<'top, D: Decoder> Iterator for TemplateMacroInvocationArgsIterator<'top, D>
             TemplateBodyExprKind::MacroInvocation(body_invocation) => {
                 let invocation = body_invocation
                     .resolve(self.context, self.host_template.address(), arg.expr_range());
-                ValueExpr::MacroInvocation(invocation.into())
+                // The invocation's own arguments may refer to the host template's variables, so
+                // it must be expanded in the host's environment rather than the callee's.
+                ValueExpr::MacroInvocation(MacroExpr::from_template_macro(invocation).with_environment(self.environment))
             }
         };
         self.arg_index += arg.num_expressions();
@@ -0,0 +1,78 @@
+use std::fmt::{Debug, Formatter};
+use std::io;
+
+use crate::{IonError, IonResult, IonType};
+
+/// A source of lob body bytes that can be read incrementally.
+pub(crate) trait LobBodySource {
+    /// Copies the next bytes of the lob's body into `buffer`, returning the number of bytes
+    /// copied. Returns `Ok(0)` once the entire body has been read.
+    fn read_lob_bytes(&mut self, buffer: &mut [u8]) -> IonResult<usize>;
+}
+
+enum LobSource<'a> {
+    /// The lob's body is read from the input source as the caller requests it.
+    Streaming(&'a mut dyn LobBodySource),
+    /// The lob's body was already in the reader's buffer; these are its remaining bytes.
+    Buffered(&'a [u8]),
+}
+
+/// Reads the body of a top-level blob or clob incrementally. See
+/// [`Reader::next_lob`](crate::Reader::next_lob) for which lobs are streamed from the input
+/// source and which are buffered in full before the `LobReader` is returned.
+///
+/// Any bytes that have not been read when the reader is advanced are skipped.
+pub struct LobReader<'a> {
+    ion_type: IonType,
+    source: LobSource<'a>,
+}
+
+impl<'a> LobReader<'a> {
+    pub(crate) fn streaming(ion_type: IonType, source: &'a mut dyn LobBodySource) -> Self {
+        Self {
+            ion_type,
+            source: LobSource::Streaming(source),
+        }
+    }
+
+    pub(crate) fn buffered(ion_type: IonType, bytes: &'a [u8]) -> Self {
+        Self {
+            ion_type,
+            source: LobSource::Buffered(bytes),
+        }
+    }
+
+    /// Returns the type of the lob being read: either [`IonType::Blob`] or [`IonType::Clob`].
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+}
+
+impl<'a> io::Read for LobReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.source {
+            LobSource::Streaming(source) => source.read_lob_bytes(buf).map_err(|error| {
+                let kind = match &error {
+                    IonError::Io(io_error) => io_error.source().kind(),
+                    IonError::Incomplete(_) => io::ErrorKind::UnexpectedEof,
+                    _ => io::ErrorKind::InvalidData,
+                };
+                io::Error::new(kind, error)
+            }),
+            LobSource::Buffered(bytes) => bytes.read(buf),
+        }
+    }
+}
+
+impl<'a> Debug for LobReader<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let source = match self.source {
+            LobSource::Streaming(_) => "streaming",
+            LobSource::Buffered(_) => "buffered",
+        };
+        f.debug_struct("LobReader")
+            .field("ion_type", &self.ion_type)
+            .field("source", &source)
+            .finish()
+    }
+}
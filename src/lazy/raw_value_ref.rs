@@ -1,10 +1,11 @@
 use std::fmt::{Debug, Formatter};
+use std::ops::Range;
 
 use crate::lazy::bytes_ref::BytesRef;
-use crate::lazy::decoder::Decoder;
+use crate::lazy::decoder::{Decoder, HasRange, LazyRawContainer};
 use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::str_ref::StrRef;
-use crate::result::IonFailure;
+use crate::result::type_mismatch_error;
 use crate::{
     Decimal, Int, IonResult, IonType, LazyExpandedList, LazyExpandedSExp, LazyExpandedStruct,
     LazyList, LazySExp, LazyStruct, RawSymbolRef, Timestamp, ValueRef,
@@ -15,7 +16,7 @@ use crate::{
 /// or text literal). If it is a symbol ID, a symbol table will be needed to find its associated text.
 ///
 /// For a resolved version of this type, see [crate::lazy::value_ref::ValueRef].
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub enum RawValueRef<'top, D: Decoder> {
     Null(IonType),
     Bool(bool),
@@ -100,11 +101,52 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         Ok(value_ref)
     }
 
+    /// Returns the Ion type of the value, including the type of a typed null.
+    pub fn ion_type(&self) -> IonType {
+        use RawValueRef::*;
+        match self {
+            Null(ion_type) => *ion_type,
+            Bool(_) => IonType::Bool,
+            Int(_) => IonType::Int,
+            Float(_) => IonType::Float,
+            Decimal(_) => IonType::Decimal,
+            Timestamp(_) => IonType::Timestamp,
+            String(_) => IonType::String,
+            Symbol(_) => IonType::Symbol,
+            Blob(_) => IonType::Blob,
+            Clob(_) => IonType::Clob,
+            SExp(_) => IonType::SExp,
+            List(_) => IonType::List,
+            Struct(_) => IonType::Struct,
+        }
+    }
+
+    /// Returns the range of input bytes occupied by this value if it is a container. Scalar
+    /// variants hold only the decoded data, so they return `None`.
+    fn container_range(&self) -> Option<Range<usize>> {
+        use RawValueRef::*;
+        match self {
+            SExp(s) => Some(s.as_value().range()),
+            List(l) => Some(l.as_value().range()),
+            Struct(s) => Some(s.as_value().range()),
+            _ => None,
+        }
+    }
+
+    fn expected<T>(&self, expected: &str) -> IonResult<T> {
+        Err(type_mismatch_error(
+            expected,
+            self.ion_type(),
+            matches!(self, RawValueRef::Null(_)),
+            self.container_range(),
+        ))
+    }
+
     pub fn expect_null(self) -> IonResult<IonType> {
         if let RawValueRef::Null(ion_type) = self {
             Ok(ion_type)
         } else {
-            IonResult::decoding_error("expected a null")
+            self.expected("a null")
         }
     }
 
@@ -112,7 +154,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Bool(b) = self {
             Ok(b)
         } else {
-            IonResult::decoding_error("expected a bool")
+            self.expected("a bool")
         }
     }
 
@@ -120,7 +162,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Int(i) = self {
             Ok(i)
         } else {
-            IonResult::decoding_error("expected an int")
+            self.expected("an int")
         }
     }
 
@@ -128,7 +170,27 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Int(i) = self {
             i.expect_i64()
         } else {
-            IonResult::decoding_error(format!("expected an i64 (int), found: {:?}", self))
+            self.expected("an i64 (int)")
+        }
+    }
+
+    /// Like [`expect_i64`](Self::expect_i64), but returns an `Err` if the int is negative or
+    /// too large to fit in a `u64`.
+    pub fn expect_u64(self) -> IonResult<u64> {
+        if let RawValueRef::Int(i) = self {
+            i.expect_u64()
+        } else {
+            self.expected("a u64 (int)")
+        }
+    }
+
+    /// Like [`expect_i64`](Self::expect_i64), but returns an `Err` if the int is negative or
+    /// too large to fit in a `usize`.
+    pub fn expect_usize(self) -> IonResult<usize> {
+        if let RawValueRef::Int(i) = self {
+            i.expect_usize()
+        } else {
+            self.expected("a usize (int)")
         }
     }
 
@@ -136,7 +198,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Float(f) = self {
             Ok(f)
         } else {
-            IonResult::decoding_error("expected a float")
+            self.expected("a float")
         }
     }
 
@@ -144,7 +206,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Decimal(d) = self {
             Ok(d)
         } else {
-            IonResult::decoding_error("expected a decimal")
+            self.expected("a decimal")
         }
     }
 
@@ -152,7 +214,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Timestamp(t) = self {
             Ok(t)
         } else {
-            IonResult::decoding_error("expected a timestamp")
+            self.expected("a timestamp")
         }
     }
 
@@ -160,7 +222,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::String(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error("expected a string")
+            self.expected("a string")
         }
     }
 
@@ -168,7 +230,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Symbol(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error("expected a symbol")
+            self.expected("a symbol")
         }
     }
 
@@ -176,7 +238,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Blob(b) = self {
             Ok(b)
         } else {
-            IonResult::decoding_error("expected a blob")
+            self.expected("a blob")
         }
     }
 
@@ -184,7 +246,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Clob(c) = self {
             Ok(c)
         } else {
-            IonResult::decoding_error("expected a clob")
+            self.expected("a clob")
         }
     }
 
@@ -192,7 +254,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::List(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error("expected a list")
+            self.expected("a list")
         }
     }
 
@@ -200,7 +262,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::SExp(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error("expected a sexp")
+            self.expected("a sexp")
         }
     }
 
@@ -208,7 +270,7 @@ impl<'top, D: Decoder> RawValueRef<'top, D> {
         if let RawValueRef::Struct(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error(format!("expected a struct, found: {:?}", self))
+            self.expected("a struct")
         }
     }
 }
@@ -2,12 +2,16 @@
 
 use crate::element::reader::ElementReader;
 use crate::element::Element;
+use crate::lazy::any_encoding::{AnyEncoding, IonEncoding};
 use crate::lazy::decoder::Decoder;
-use crate::lazy::streaming_raw_reader::IonInput;
-use crate::lazy::system_reader::SystemReader;
+use crate::lazy::expanded::symbol_cache::SymbolCacheStats;
+use crate::lazy::lob_reader::LobReader;
+use crate::lazy::streaming_raw_reader::{IonInput, SeekableDataSource};
+use crate::lazy::system_reader::{ReaderCheckpoint, SystemReader};
 use crate::lazy::value::LazyValue;
 use crate::read_config::ReadConfig;
 use crate::result::IonFailure;
+use crate::symbol_table::SymbolTable;
 use crate::{IonError, IonResult};
 
 /// A binary reader that only reads each value that it visits upon request (that is: lazily).
@@ -113,6 +117,55 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
         self.next()?
             .ok_or_else(|| IonError::decoding_error("expected another top-level value"))
     }
+
+    /// If the next top-level value is a non-null blob or clob, returns a [`LobReader`] that
+    /// implements [`Read`](std::io::Read) over the lob's body. If the next value is of any other
+    /// type (or is produced by a macro invocation), returns `Ok(None)` and leaves that value to be
+    /// read by [`Self::next`].
+    ///
+    /// Whether the lob's body is streamed depends on the encoding:
+    ///
+    /// * **Binary Ion 1.0**: the body of an unannotated lob is read from the input source as the
+    ///   caller requests it. A lob that is much larger than the reader's input buffer can be
+    ///   processed without holding all of it in memory, and the buffer does not grow to fit it.
+    /// * **Annotated lobs in binary Ion 1.0, binary Ion 1.1, and text Ion (1.0 and 1.1)**: the
+    ///   whole lob is pulled into the input buffer, growing it as needed, before this method
+    ///   returns. Text lobs are also decoded from base64 or clob syntax at that point.
+    ///
+    /// If the input is an in-memory slice rather than an [`io::Read`](std::io::Read)
+    /// implementation, the whole stream is already in memory and nothing is copied into a buffer.
+    ///
+    /// If the `LobReader` is dropped before the body has been read to the end, the rest of the
+    /// body is skipped when the reader is next advanced.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use std::io::Read;
+    /// use ion_rs::{Element, IonType, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let elements = Element::read_all("{{aGVsbG8=}} 5")?;
+    /// let binary_ion = elements.encode_as(Binary)?;
+    /// let mut reader = Reader::new(Binary, binary_ion.as_slice())?;
+    ///
+    /// let mut lob_reader = reader.next_lob()?.expect("the first value is a blob");
+    /// assert_eq!(lob_reader.ion_type(), IonType::Blob);
+    /// let mut blob = Vec::new();
+    /// lob_reader.read_to_end(&mut blob)?;
+    /// assert_eq!(blob, b"hello");
+    ///
+    /// // The next value is not a lob, so it is left for `next()`.
+    /// assert!(reader.next_lob()?.is_none());
+    /// assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 5);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn next_lob(&mut self) -> IonResult<Option<LobReader<'_>>> {
+        self.system_reader.next_lob()
+    }
 }
 
 impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
@@ -123,6 +176,129 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
         let system_reader = SystemReader::new(config, ion_data);
         Ok(Reader { system_reader })
     }
+
+    /// Returns the reader's active symbol table.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        self.system_reader.symbol_table()
+    }
+
+    /// Returns statistics describing how often annotations and field names materialized by this
+    /// reader (for example, when converting a value to an [`Element`]) were able to share text
+    /// with previously materialized symbols.
+    pub fn symbol_cache_stats(&self) -> SymbolCacheStats {
+        self.system_reader.symbol_cache_stats()
+    }
+
+    /// Returns a [`ReaderCheckpoint`] describing the position of the next top-level value and
+    /// the encoding context needed to read it. See [`Self::seek_to_checkpoint`].
+    pub fn checkpoint(&mut self) -> IonResult<ReaderCheckpoint> {
+        self.system_reader.checkpoint()
+    }
+
+    /// Repositions the reader at a [`ReaderCheckpoint`] captured earlier from a reader of the same
+    /// stream. The next call to [`Self::next`] will return the value that followed the checkpoint.
+    ///
+    /// This is available when the input is an in-memory slice or a data source that implements
+    /// [`Seek`](std::io::Seek).
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let elements = Element::read_all("{name: foo} {name: bar} {name: baz}")?;
+    /// let binary_ion = elements.encode_as(Binary)?;
+    /// let mut reader = Reader::new(Binary, binary_ion.as_slice())?;
+    ///
+    /// // Index the stream, remembering where the second value begins.
+    /// reader.expect_next()?;
+    /// let second = reader.checkpoint()?;
+    /// while reader.next()?.is_some() {}
+    ///
+    /// // Jump straight to the second value in a new reader.
+    /// let mut reader = Reader::new(Binary, binary_ion.as_slice())?;
+    /// reader.seek_to_checkpoint(&second)?;
+    /// let value = reader.expect_next()?.read()?.expect_struct()?;
+    /// assert_eq!(value.get_expected("name")?.expect_symbol()?, "bar");
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn seek_to_checkpoint(&mut self, checkpoint: &ReaderCheckpoint) -> IonResult<()>
+    where
+        Input::DataSource: SeekableDataSource,
+    {
+        self.system_reader.seek_to_checkpoint(checkpoint)
+    }
+}
+
+impl<Input: IonInput> Reader<AnyEncoding, Input> {
+    /// Constructs a reader that detects whether `ion_data` is text or binary Ion (1.0 or 1.1)
+    /// and reads it accordingly. This is equivalent to calling `Reader::new(AnyEncoding, ion_data)`.
+    ///
+    /// The reader manages its own encoding context (symbol and macro tables) and the memory used
+    /// to read each top-level value. Each value returned by [`Reader::next`] remains valid
+    /// until the next call to `next()`.
+    ///
+    /// Iterating over the top-level values in a stream:
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{IonType, Reader};
+    ///
+    /// let mut reader = Reader::from_input("1 two \"three\" [4]")?;
+    /// let mut ion_types = vec![];
+    /// while let Some(value) = reader.next()? {
+    ///     ion_types.push(value.ion_type());
+    /// }
+    /// assert_eq!(
+    ///     ion_types,
+    ///     vec![IonType::Int, IonType::Symbol, IonType::String, IonType::List]
+    /// );
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    ///
+    /// Summing a struct field across a series of records:
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    ///
+    /// let records = r#"
+    ///     {item: "apple", quantity: 3}
+    ///     {item: "pear", quantity: 5}
+    ///     {item: "plum"}
+    /// "#;
+    /// let mut reader = Reader::from_input(records)?;
+    /// let mut total = 0;
+    /// while let Some(value) = reader.next()? {
+    ///     let record = value.read()?.expect_struct()?;
+    ///     if let Some(quantity) = record.get("quantity")? {
+    ///         total += quantity.expect_i64()?;
+    ///     }
+    /// }
+    /// assert_eq!(total, 8);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn from_input(ion_data: Input) -> IonResult<Reader<AnyEncoding, Input>> {
+        Reader::new(AnyEncoding, ion_data)
+    }
+
+    /// Returns the encoding that the reader has detected in its input. Before the reader has
+    /// encountered an Ion version marker, this is [`IonEncoding::Text_1_0`].
+    pub fn detected_encoding(&self) -> IonEncoding {
+        self.system_reader.detected_encoding()
+    }
 }
 
 use crate::lazy::{
@@ -131,7 +307,28 @@ use crate::lazy::{
 };
 
 impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
-    // TODO: Remove this when the reader can understand 1.1 encoding directives.
+    // TODO: Remove `register_template_src` when the reader can understand 1.1 encoding directives.
+
+    /// Compiles `template_definition` and adds the resulting macro to the reader's macro table,
+    /// returning the address that it was assigned. E-expressions in an Ion 1.1 stream can invoke
+    /// the macro by name or by address; the reader expands each invocation and yields the values
+    /// it produces from [`Self::next`] as though they had appeared in the stream.
+    ///
+    /// Arguments are evaluated lazily. An argument that the template never uses is not expanded.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, Reader};
+    ///
+    /// let mut reader = Reader::new(AnyEncoding, r#"$ion_1_1 (:greet "Zack")"#)?;
+    /// reader.register_template_src(r#"(macro greet (name) (make_string "Hello, " name "!"))"#)?;
+    /// assert_eq!(reader.expect_next()?.read()?.expect_string()?, "Hello, Zack!");
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
     pub fn register_template_src(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
         self.system_reader
             .expanding_reader
@@ -187,8 +384,17 @@ mod tests {
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::value_ref::ValueRef;
     use crate::write_config::WriteConfig;
-    use crate::{ion_list, ion_sexp, ion_struct, v1_0, AnyEncoding, Int, IonResult, IonType};
+    use crate::{ion_list, ion_sexp, ion_struct, v1_0, v1_1, AnyEncoding, Int, IonResult, IonType};
     use crate::lazy::text::raw::v1_1::reader::MacroAddress;
+    use crate::Sequence;
+    use crate::IonStream;
+    use rstest::rstest;
+    use std::cell::Cell;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+    use std::io::Read;
+    use std::rc::Rc;
+    use std::sync::Arc;
 
     use super::*;
 
@@ -248,6 +454,109 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn from_input_detects_encoding() -> IonResult<()> {
+        let text_ion = r#"{name: "widget", tags: [blue, round]} 2024-01-01T null.int"#;
+        let expected = Element::read_all(text_ion)?;
+        let binary_1_0 = to_binary_ion(text_ion)?;
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        writer.write_elements(&expected)?;
+        let binary_1_1 = writer.close()?;
+
+        for data in [text_ion.as_bytes().to_vec(), binary_1_0, binary_1_1] {
+            let mut reader = Reader::from_input(data)?;
+            let mut actual = Vec::new();
+            while let Some(value) = reader.next()? {
+                actual.push(Element::try_from(value)?);
+            }
+            assert_eq!(actual, expected.iter().cloned().collect::<Vec<_>>());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_fragment_with_initial_symbol_table() -> IonResult<()> {
+        // Write a stream whose second batch of values causes the writer to append to the
+        // symbol table that was defined for the first batch.
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_elements(&Element::read_all("foo::{bar: baz} [quux]")?)?;
+        writer.flush()?;
+        writer.write_elements(&Element::read_all("(foo new_symbol) quux")?)?;
+        let stream = writer.close()?;
+
+        let mut reader = Reader::new(v1_0::Binary, stream.as_slice())?;
+        let first_value = reader.expect_next()?;
+        let fragment_start = first_value.expanded().range().unwrap().start;
+        let mut expected = vec![Element::try_from(first_value)?];
+        // Capture the symbol table that was in effect for the first value.
+        let symbol_table = reader.symbol_table().clone();
+        while let Some(value) = reader.next()? {
+            expected.push(Element::try_from(value)?);
+        }
+        assert_eq!(expected.len(), 4);
+
+        // The fragment begins with a value rather than an IVM and includes the appending LST.
+        let fragment = &stream[fragment_start..];
+        let config = ReadConfig::from(v1_0::Binary).with_initial_symbol_table(symbol_table);
+        let mut reader = Reader::new(config, fragment)?;
+        let mut actual = Vec::new();
+        while let Some(value) = reader.next()? {
+            actual.push(Element::try_from(value)?);
+        }
+        assert_eq!(actual, expected);
+        assert_eq!(reader.symbol_table().sid_for(&"new_symbol"), Some(14));
+
+        // Without the symbol table, the fragment's symbol IDs cannot be resolved.
+        let mut reader = Reader::new(v1_0::Binary, fragment)?;
+        assert!(reader.expect_next().and_then(Element::try_from).is_err());
+        // Readers that detect the encoding still require binary input to begin with an IVM.
+        assert!(Reader::new(AnyEncoding, fragment)?.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn fragment_readers_sharing_an_initial_symbol_table() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write_elements(&Element::read_all("foo::bar")?)?;
+        writer.flush()?;
+        writer.write_elements(&Element::read_all("(foo new_symbol)")?)?;
+        let stream = writer.close()?;
+
+        let mut reader = Reader::new(v1_0::Binary, stream.as_slice())?;
+        let first_value = reader.expect_next()?;
+        let fragment = &stream[first_value.expanded().range().unwrap().start..];
+        let symbol_table = reader.symbol_table().clone();
+
+        let fragment_reader = |symbol_table: &SymbolTable| {
+            let config =
+                ReadConfig::from(v1_0::Binary).with_initial_symbol_table(symbol_table.clone());
+            Reader::new(config, fragment)
+        };
+        let mut reader1 = fragment_reader(&symbol_table)?;
+        let mut reader2 = fragment_reader(&symbol_table)?;
+        // Each reader modifies its own copy of the table, so their states must not be confused
+        // with each other or with the original.
+        assert!(!reader1.symbol_table().is_same_state_as(&symbol_table));
+        assert!(!reader1
+            .symbol_table()
+            .is_same_state_as(reader2.symbol_table()));
+
+        // Interleave the readers; the appending LST read by one does not affect the other.
+        let expected = Element::read_all("foo::bar (foo new_symbol)")?;
+        for expected_value in expected.iter() {
+            assert_eq!(&Element::try_from(reader1.expect_next()?)?, expected_value);
+            assert_eq!(&Element::try_from(reader2.expect_next()?)?, expected_value);
+        }
+        assert!(reader1.next()?.is_none());
+        assert!(reader2.next()?.is_none());
+        assert_eq!(reader1.symbol_table().sid_for(&"new_symbol"), Some(12));
+        assert_eq!(reader2.symbol_table().sid_for(&"new_symbol"), Some(12));
+        assert!(!reader1
+            .symbol_table()
+            .is_same_state_as(reader2.symbol_table()));
+        Ok(())
+    }
+
     #[test]
     fn materialize() -> IonResult<()> {
         let data = to_binary_ion(
@@ -311,6 +620,67 @@ mod tests {
         test_fn(reader)
     }
 
+    /// Reads every value in the Ion 1.1 text `stream` after registering a few templates.
+    fn expand_text_stream(stream: &str) -> IonResult<Sequence> {
+        let mut reader = Reader::new(AnyEncoding, stream)?;
+        reader.register_template_src(r#"(macro greet (name) (make_string "Hello, " name "!"))"#)?;
+        reader.register_template_src("(macro pair (first second) [first, second])")?;
+        reader.register_template_src("(macro second (ignored kept) kept)")?;
+        reader.read_all_elements()
+    }
+
+    #[rstest]
+    #[case::by_name(r#"(:greet "Zack")"#, r#""Hello, Zack!""#)]
+    #[case::several(
+        r#"(:greet "Zack") 1 (:greet "Ed")"#,
+        r#""Hello, Zack!" 1 "Hello, Ed!""#
+    )]
+    #[case::eexp_argument(r#"(:greet (:make_string "Za" "ck"))"#, r#""Hello, Zack!""#)]
+    #[case::template_argument(r#"(:pair (:greet "a") (:pair 1 2))"#, r#"["Hello, a!", [1, 2]]"#)]
+    #[case::values("(:values 1 2 3) (:values)", "1 2 3")]
+    #[case::in_containers(
+        r#"[(:greet "list")] {a: (:greet "field")} ((:pair 1 2))"#,
+        r#"["Hello, list!"] {a: "Hello, field!"} ([1, 2])"#
+    )]
+    fn expand_text_template_macro(
+        #[case] invocations: &str,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let actual = expand_text_stream(&format!("$ion_1_1 {invocations}"))?;
+        assert_eq!(actual, Element::read_all(expected)?);
+        Ok(())
+    }
+
+    #[test]
+    fn expand_text_template_macro_by_address() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "")?;
+        let address = reader.register_template_src("(macro seventeen () 17)")?;
+        let stream = format!("$ion_1_1 (:seventeen) (:{address})");
+        let mut reader = Reader::new(AnyEncoding, stream.as_str())?;
+        reader.register_template_src("(macro seventeen () 17)")?;
+        assert_eq!(reader.read_all_elements()?, Element::read_all("17 17")?);
+        Ok(())
+    }
+
+    #[test]
+    fn unused_template_arguments_are_not_evaluated() -> IonResult<()> {
+        // `make_string` fails if it is given a list, but `second` never evaluates its first
+        // argument.
+        let actual = expand_text_stream("$ion_1_1 (:second (:make_string [1]) 2)")?;
+        assert_eq!(actual, Element::read_all("2")?);
+        assert!(expand_text_stream("$ion_1_1 (:second 1 (:make_string [1]))").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn expand_text_template_macro_errors() {
+        // Unknown macro names and addresses are reported as errors.
+        assert!(expand_text_stream("$ion_1_1 (:no_such_macro 1)").is_err());
+        assert!(expand_text_stream("$ion_1_1 (:1000)").is_err());
+        // So are invocations with the wrong number of arguments.
+        assert!(expand_text_stream(r#"$ion_1_1 (:greet "a" "b")"#).is_err());
+    }
+
     #[test]
     fn expand_binary_template_macro() -> IonResult<()> {
         let macro_source = "(macro seventeen () 17)";
@@ -386,4 +756,332 @@ mod tests {
             Ok(())
         })
     }
+
+    /// Writes three structs to a binary stream, flushing after each one. Each struct's field
+    /// names are defined by a local symbol table that appends to the previous one.
+    fn binary_stream_with_appended_symbols() -> IonResult<Vec<u8>> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        for ion_text in [
+            "{name: \"alice\", id: 1}",
+            "{city: \"boston\", zip: \"02101\"}",
+            "{color: red, size: large}",
+        ] {
+            writer.write_elements(&Element::read_all(ion_text)?)?;
+            writer.flush()?;
+        }
+        writer.close()
+    }
+
+    /// Reads every value in the stream, returning each one with the checkpoint that preceded it.
+    fn index_stream<Input: IonInput>(
+        reader: &mut Reader<AnyEncoding, Input>,
+    ) -> IonResult<Vec<(ReaderCheckpoint, Element)>> {
+        let mut index = Vec::new();
+        loop {
+            let checkpoint = reader.checkpoint()?;
+            let Some(value) = reader.next()? else {
+                return Ok(index);
+            };
+            index.push((checkpoint, Element::try_from(value)?));
+        }
+    }
+
+    #[test]
+    fn seek_to_checkpoint() -> IonResult<()> {
+        let ion = binary_stream_with_appended_symbols()?;
+        let index = index_stream(&mut Reader::new(AnyEncoding, ion.as_slice())?)?;
+        assert_eq!(index.len(), 3);
+        let (middle_checkpoint, middle_element) = &index[1];
+        // The checkpoint's symbol table does not yet include the middle value's field names; the
+        // symbol table that defines them follows the checkpoint.
+        assert!(middle_checkpoint.symbol_table().sid_for(&"city").is_none());
+
+        let mut reader = Reader::new(AnyEncoding, ion.as_slice())?;
+        reader.seek_to_checkpoint(middle_checkpoint)?;
+        assert_eq!(&Element::try_from(reader.expect_next()?)?, middle_element);
+        assert_eq!(Element::try_from(reader.expect_next()?)?, index[2].1);
+        assert!(reader.next()?.is_none());
+
+        // Seek backwards in the same reader.
+        reader.seek_to_checkpoint(&index[0].0)?;
+        assert_eq!(Element::try_from(reader.expect_next()?)?, index[0].1);
+        Ok(())
+    }
+
+    #[test]
+    fn seek_to_checkpoint_in_file() -> IonResult<()> {
+        use std::io::{Seek, Write};
+        let ion = binary_stream_with_appended_symbols()?;
+        let mut file = tempfile::tempfile()?;
+        file.write_all(&ion)?;
+        file.rewind()?;
+        let index = index_stream(&mut Reader::new(AnyEncoding, file.try_clone()?)?)?;
+
+        file.rewind()?;
+        let mut reader = Reader::new(AnyEncoding, file)?;
+        reader.seek_to_checkpoint(&index[1].0)?;
+        let value = reader.expect_next()?;
+        let city = value.read()?.expect_struct()?.get_expected("city")?;
+        assert_eq!(city.expect_string()?, "boston");
+        Ok(())
+    }
+
+    #[test]
+    fn checkpoint_during_macro_expansion() -> IonResult<()> {
+        let mut reader = Reader::new(v1_1::Text, "(:values 1 2) 3")?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        // The e-expression has another value to produce.
+        assert!(reader.checkpoint().is_err());
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 2);
+        let checkpoint = reader.checkpoint()?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 3);
+        reader.seek_to_checkpoint(&checkpoint)?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn restore_checkpoint_after_reading_ahead() -> IonResult<()> {
+        // Each value after the first is preceded by a symbol table that appends its field names.
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        for ion_text in ["{a: 1}", "{b: 2}", "{c: 3}", "{d: 4}"] {
+            writer.write_elements(&Element::read_all(ion_text)?)?;
+            writer.flush()?;
+        }
+        let ion = writer.close()?;
+
+        let mut reader = Reader::new(AnyEncoding, ion.as_slice())?;
+        reader.expect_next()?;
+        let checkpoint = reader.checkpoint()?;
+        let symbol_count = reader.symbol_table().len();
+
+        // Speculatively read the next three values, then rewind.
+        let mut speculative = Vec::new();
+        for _ in 0..3 {
+            speculative.push(Element::try_from(reader.expect_next()?)?);
+        }
+        assert!(reader.symbol_table().sid_for(&"d").is_some());
+        reader.seek_to_checkpoint(&checkpoint)?;
+
+        // The symbols read during the speculative read are gone...
+        assert_eq!(reader.symbol_table().len(), symbol_count);
+        assert!(reader.symbol_table().sid_for(&"b").is_none());
+        // ...and re-reading yields the same values.
+        for expected in &speculative {
+            assert_eq!(&Element::try_from(reader.expect_next()?)?, expected);
+        }
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn checkpoints_share_unchanged_symbol_table() -> IonResult<()> {
+        let ion = Element::read_all("foo bar baz")?.encode_as(v1_0::Binary)?;
+        let mut reader = Reader::new(AnyEncoding, ion.as_slice())?;
+        reader.expect_next()?;
+        let first = reader.checkpoint()?;
+        reader.expect_next()?;
+        let second = reader.checkpoint()?;
+        assert!(Arc::ptr_eq(&first.symbol_table, &second.symbol_table));
+
+        // Restoring a checkpoint while the symbol table is unchanged keeps the reader's table.
+        reader.seek_to_checkpoint(&first)?;
+        assert!(reader.symbol_table().is_same_state_as(&first.symbol_table));
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "bar");
+
+        // A checkpoint taken after the symbol table changes has its own copy.
+        let mut reader = Reader::new(AnyEncoding, "a $ion_symbol_table::{symbols: [\"x\"]} b")?;
+        reader.expect_next()?;
+        let before = reader.checkpoint()?;
+        reader.expect_next()?;
+        let after = reader.checkpoint()?;
+        assert!(!Arc::ptr_eq(&before.symbol_table, &after.symbol_table));
+        assert!(after.symbol_table().sid_for(&"x").is_some());
+        Ok(())
+    }
+
+    /// An `io::Read` that reports how many bytes have been pulled from it so far.
+    struct CountingReader {
+        data: std::io::Cursor<Vec<u8>>,
+        bytes_read: Rc<Cell<usize>>,
+    }
+
+    impl std::io::Read for CountingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let bytes_read = self.data.read(buf)?;
+            self.bytes_read.set(self.bytes_read.get() + bytes_read);
+            Ok(bytes_read)
+        }
+    }
+
+    fn generated_lob(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 31 + i / 251) as u8).collect()
+    }
+
+    fn digest(bytes: &[u8]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn stream_large_blob_in_chunks() -> IonResult<()> {
+        const LOB_SIZE: usize = 10 * 1024 * 1024;
+        const CHUNK_SIZE: usize = 64 * 1024;
+        let lob = generated_lob(LOB_SIZE);
+        let ion = Sequence::new([Element::blob(&lob), Element::int(7)]).encode_as(v1_0::Binary)?;
+        let source_bytes_read = Rc::new(Cell::new(0));
+        let source = CountingReader {
+            data: std::io::Cursor::new(ion),
+            bytes_read: Rc::clone(&source_bytes_read),
+        };
+        let mut reader = Reader::new(AnyEncoding, IonStream::new(source))?;
+
+        let mut lob_reader = reader.next_lob()?.expect("the first value is a blob");
+        assert_eq!(lob_reader.ion_type(), IonType::Blob);
+        let mut hasher = DefaultHasher::new();
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        let mut total_bytes = 0;
+        loop {
+            let chunk_length = lob_reader.read(&mut chunk)?;
+            if chunk_length == 0 {
+                break;
+            }
+            hasher.write(&chunk[..chunk_length]);
+            total_bytes += chunk_length;
+            // The lob's body is pulled from the source as it is read rather than up front.
+            assert!(source_bytes_read.get() < total_bytes + CHUNK_SIZE);
+        }
+        assert_eq!(total_bytes, LOB_SIZE);
+        assert_eq!(hasher.finish(), digest(&lob));
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 7);
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn stream_blob_through_small_buffer() -> IonResult<()> {
+        const BUFFER_SIZE: usize = 64;
+        const LOB_SIZE: usize = 100 * BUFFER_SIZE;
+        const CHUNK_SIZE: usize = 10;
+        let lob = generated_lob(LOB_SIZE);
+        let ion = Sequence::new([Element::blob(&lob), Element::int(7)]).encode_as(v1_0::Binary)?;
+        let source_bytes_read = Rc::new(Cell::new(0));
+        let source = CountingReader {
+            data: std::io::Cursor::new(ion),
+            bytes_read: Rc::clone(&source_bytes_read),
+        };
+        let input = IonStream::with_buffer_size(source, BUFFER_SIZE);
+        let mut reader = Reader::new(AnyEncoding, input)?;
+
+        let mut lob_reader = reader.next_lob()?.expect("the first value is a blob");
+        let mut body = Vec::new();
+        let mut chunk = [0u8; CHUNK_SIZE];
+        loop {
+            let chunk_length = lob_reader.read(&mut chunk)?;
+            if chunk_length == 0 {
+                break;
+            }
+            body.extend_from_slice(&chunk[..chunk_length]);
+            // The reader never holds more than one (ungrown) buffer of input that the caller has
+            // not read yet. The IVM and the lob's header account for the first few bytes.
+            assert!(
+                source_bytes_read.get() <= body.len() + 2 * BUFFER_SIZE,
+                "read {} bytes from the source after {} bytes of the lob",
+                source_bytes_read.get(),
+                body.len()
+            );
+        }
+        assert_eq!(body, lob);
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 7);
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn drop_lob_reader_mid_lob() -> IonResult<()> {
+        let lob = generated_lob(100_000);
+        let elements = Sequence::new([Element::blob(&lob), Element::int(7)]);
+        let binary_ion = elements.encode_as(v1_0::Binary)?;
+        let text_ion = elements.encode_as(v1_0::Text)?;
+
+        let mut reader = Reader::new(AnyEncoding, IonStream::new(binary_ion.as_slice()))?;
+        let mut prefix = [0u8; 1000];
+        reader.next_lob()?.unwrap().read_exact(&mut prefix)?;
+        assert_eq!(prefix.as_slice(), &lob[..1000]);
+        // The rest of the lob is skipped.
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 7);
+
+        // The same is true if the reader is asked for another lob.
+        let mut reader = Reader::new(AnyEncoding, IonStream::new(binary_ion.as_slice()))?;
+        reader.next_lob()?.unwrap().read_exact(&mut prefix)?;
+        assert!(reader.next_lob()?.is_none());
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 7);
+
+        let mut reader = Reader::new(AnyEncoding, text_ion.as_str())?;
+        reader.next_lob()?.unwrap().read_exact(&mut prefix)?;
+        assert_eq!(prefix.as_slice(), &lob[..1000]);
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 7);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text_blob(Element::blob(b"hello"), |e: &Element| Ok(e.encode_as(v1_0::Text)?.into_bytes()))]
+    #[case::text_clob(Element::clob(b"hello"), |e: &Element| Ok(e.encode_as(v1_0::Text)?.into_bytes()))]
+    #[case::binary_1_0_clob(Element::clob(b"hello"), |e: &Element| e.encode_as(v1_0::Binary))]
+    #[case::binary_1_0_annotated(
+        Element::blob(b"hello").with_annotations(["a"]),
+        |e: &Element| e.encode_as(v1_0::Binary)
+    )]
+    #[case::binary_1_1_blob(Element::blob(b"hello"), |e: &Element| e.encode_as(v1_1::Binary))]
+    #[case::binary_1_1_clob(Element::clob(b"hello"), |e: &Element| e.encode_as(v1_1::Binary))]
+    fn read_lob_in_each_encoding(
+        #[case] lob: Element,
+        #[case] encode: fn(&Element) -> IonResult<Vec<u8>>,
+    ) -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, encode(&lob)?)?;
+        let mut lob_reader = reader.next_lob()?.expect("the first value is a lob");
+        assert_eq!(lob_reader.ion_type(), lob.ion_type());
+        let mut bytes = Vec::new();
+        lob_reader.read_to_end(&mut bytes)?;
+        assert_eq!(bytes, b"hello");
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn next_lob_leaves_other_values() -> IonResult<()> {
+        let elements = Element::read_all("null.blob 5 {{}}")?;
+        for ion in [
+            elements.encode_as(v1_0::Text)?.into_bytes(),
+            elements.encode_as(v1_0::Binary)?,
+        ] {
+            let mut reader = Reader::new(AnyEncoding, ion)?;
+            assert!(reader.next_lob()?.is_none());
+            assert!(reader.expect_next()?.is_null());
+            assert!(reader.next_lob()?.is_none());
+            assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 5);
+            let mut bytes = Vec::new();
+            reader.next_lob()?.unwrap().read_to_end(&mut bytes)?;
+            assert!(bytes.is_empty());
+            assert!(reader.next_lob()?.is_none());
+            assert!(reader.next()?.is_none());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_truncated_lob() -> IonResult<()> {
+        let mut ion = Element::blob(generated_lob(10_000)).encode_as(v1_0::Binary)?;
+        ion.truncate(5_000);
+        let mut reader = Reader::new(AnyEncoding, IonStream::new(ion.as_slice()))?;
+        let mut bytes = Vec::new();
+        let error = reader
+            .next_lob()?
+            .unwrap()
+            .read_to_end(&mut bytes)
+            .unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+        Ok(())
+    }
 }
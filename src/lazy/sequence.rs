@@ -6,10 +6,11 @@ use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::lazy::expanded::sequence::{
     ExpandedListIterator, ExpandedSExpIterator, LazyExpandedList, LazyExpandedSExp,
 };
-use crate::lazy::value::{AnnotationsIterator, LazyValue};
+use crate::lazy::value::{AnnotationsIterator, LazyValue, Nesting};
+use crate::lazy::value_ref::ValueRef;
 use crate::{
     try_next, Annotations, Element, ExpandedListSource, ExpandedSExpSource, IntoAnnotatedElement,
-    LazyExpandedValue, LazyRawContainer, Sequence, Value,
+    IonType, LazyExpandedValue, LazyRawContainer, Sequence, Value,
 };
 use crate::{IonError, IonResult};
 
@@ -57,19 +58,60 @@ use crate::{IonError, IonResult};
 #[derive(Copy, Clone)]
 pub struct LazyList<'top, D: Decoder> {
     pub(crate) expanded_list: LazyExpandedList<'top, D>,
+    pub(crate) nesting: Nesting,
 }
 
 pub type LazyBinarySequence<'top, 'data> = LazyList<'top, BinaryEncoding_1_0>;
 
 impl<'top, D: Decoder> LazyList<'top, D> {
     pub(crate) fn new(expanded_list: LazyExpandedList<'top, D>) -> Self {
-        Self { expanded_list }
+        Self {
+            expanded_list,
+            nesting: Nesting::TOP_LEVEL,
+        }
+    }
+
+    /// Returns a copy of this list that reports the provided position in the container hierarchy.
+    pub(crate) fn with_nesting(mut self, nesting: Nesting) -> Self {
+        self.nesting = nesting;
+        self
     }
 
     /// Returns an iterator over the values in this sequence. See: [`LazyValue`].
     pub fn iter(&self) -> ListIterator<'top, D> {
         ListIterator {
             expanded_list_iter: self.expanded_list.iter(),
+            nesting: self.nesting.child_of(IonType::List),
+        }
+    }
+
+    /// Returns an iterator that reads each value in this list, yielding a [`ValueRef`] for each.
+    /// Nested containers are yielded as lazy container references which can be iterated over in
+    /// turn. Dropping the iterator before it is exhausted leaves the remaining values unread.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Reader, ValueRef};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "[1, two, [3]]")?;
+    /// let lazy_list = reader.expect_next()?.read()?.expect_list()?;
+    ///
+    /// let mut elements = lazy_list.elements();
+    /// assert_eq!(elements.next().unwrap()?, ValueRef::Int(1.into()));
+    /// assert_eq!(elements.next().unwrap()?.expect_symbol()?, "two");
+    /// let nested = elements.next().unwrap()?.expect_list()?;
+    /// assert_eq!(nested.elements().next().unwrap()?.expect_i64()?, 3);
+    /// assert!(elements.next().is_none());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn elements(&self) -> ListElementsIterator<'top, D> {
+        ListElementsIterator {
+            values: self.iter(),
         }
     }
 
@@ -92,7 +134,7 @@ impl<'top, D: Decoder> LazyList<'top, D> {
                 LazyExpandedValue::from_template(self.expanded_list.context, env, element)
             }
         };
-        LazyValue::new(expanded_value)
+        LazyValue::new(expanded_value).with_nesting(self.nesting)
     }
 
     /// Returns an iterator over the annotations on this value. If this value has no annotations,
@@ -127,10 +169,7 @@ impl<'top, D: Decoder> LazyList<'top, D> {
     ///# fn main() -> IonResult<()> { Ok(()) }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_list.annotations(),
-            symbol_table: self.expanded_list.context.symbol_table(),
-        }
+        self.as_value().annotations()
     }
 }
 
@@ -178,6 +217,41 @@ impl<'top, 'data: 'top, D: Decoder> IntoIterator for LazyList<'top, D> {
 
 pub struct ListIterator<'top, D: Decoder> {
     expanded_list_iter: ExpandedListIterator<'top, D>,
+    // The position in the container hierarchy of the values this iterator yields.
+    nesting: Nesting,
+}
+
+impl<'top, D: Decoder> ListIterator<'top, D> {
+    /// Returns the depth of the values that this iterator yields; this is one greater than the
+    /// depth of the list being iterated over.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{IonType, Reader};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "[1, [2]]")?;
+    /// let mut outer = reader.expect_next()?.read()?.expect_list()?.iter();
+    /// assert_eq!(outer.depth(), 1);
+    /// assert_eq!(outer.parent_type(), Some(IonType::List));
+    ///
+    /// let _one = outer.next().unwrap()?;
+    /// let inner = outer.next().unwrap()?.read()?.expect_list()?.iter();
+    /// assert_eq!(inner.depth(), 2);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.nesting.depth
+    }
+
+    /// Returns the type of the container being iterated over: always [`IonType::List`].
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.nesting.parent_type
+    }
 }
 
 impl<'top, D: Decoder> Iterator for ListIterator<'top, D> {
@@ -185,11 +259,40 @@ impl<'top, D: Decoder> Iterator for ListIterator<'top, D> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let expanded_value = try_next!(self.expanded_list_iter.next());
-        let lazy_value = LazyValue { expanded_value };
+        let lazy_value = LazyValue {
+            expanded_value,
+            nesting: self.nesting,
+        };
         Some(Ok(lazy_value))
     }
 }
 
+/// An iterator that reads each value in a list. See [`LazyList::elements`].
+pub struct ListElementsIterator<'top, D: Decoder> {
+    values: ListIterator<'top, D>,
+}
+
+impl<'top, D: Decoder> ListElementsIterator<'top, D> {
+    /// See [`ListIterator::depth`].
+    pub fn depth(&self) -> usize {
+        self.values.depth()
+    }
+
+    /// See [`ListIterator::parent_type`].
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.values.parent_type()
+    }
+}
+
+impl<'top, D: Decoder> Iterator for ListElementsIterator<'top, D> {
+    type Item = IonResult<ValueRef<'top, D>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let lazy_value = try_next!(self.values.next());
+        Some(lazy_value.read())
+    }
+}
+
 impl<'top, D: Decoder> Debug for LazyList<'top, D> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "[")?;
@@ -207,6 +310,7 @@ impl<'top, D: Decoder> Debug for LazyList<'top, D> {
 #[derive(Copy, Clone)]
 pub struct LazySExp<'top, D: Decoder> {
     pub(crate) expanded_sexp: LazyExpandedSExp<'top, D>,
+    pub(crate) nesting: Nesting,
 }
 
 impl<'top, D: Decoder> Debug for LazySExp<'top, D> {
@@ -223,7 +327,16 @@ impl<'top, D: Decoder> Debug for LazySExp<'top, D> {
 
 impl<'top, D: Decoder> LazySExp<'top, D> {
     pub(crate) fn new(expanded_sexp: LazyExpandedSExp<'top, D>) -> Self {
-        Self { expanded_sexp }
+        Self {
+            expanded_sexp,
+            nesting: Nesting::TOP_LEVEL,
+        }
+    }
+
+    /// Returns a copy of this sexp that reports the provided position in the container hierarchy.
+    pub(crate) fn with_nesting(mut self, nesting: Nesting) -> Self {
+        self.nesting = nesting;
+        self
     }
 
     #[cfg(feature = "experimental-tooling-apis")]
@@ -245,13 +358,22 @@ impl<'top, D: Decoder> LazySExp<'top, D> {
                 LazyExpandedValue::from_template(self.expanded_sexp.context, env, element)
             }
         };
-        LazyValue::new(expanded_value)
+        LazyValue::new(expanded_value).with_nesting(self.nesting)
     }
 
     /// Returns an iterator over the values in this sequence. See: [`LazyValue`].
     pub fn iter(&self) -> SExpIterator<'top, D> {
         SExpIterator {
             expanded_sexp_iter: self.expanded_sexp.iter(),
+            nesting: self.nesting.child_of(IonType::SExp),
+        }
+    }
+
+    /// Returns an iterator that reads each value in this s-expression, yielding a [`ValueRef`]
+    /// for each. See [`LazyList::elements`].
+    pub fn elements(&self) -> SExpElementsIterator<'top, D> {
+        SExpElementsIterator {
+            values: self.iter(),
         }
     }
 
@@ -287,10 +409,7 @@ impl<'top, D: Decoder> LazySExp<'top, D> {
     ///# fn main() -> IonResult<()> { Ok(()) }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_sexp.annotations(),
-            symbol_table: self.expanded_sexp.context.symbol_table(),
-        }
+        self.as_value().annotations()
     }
 }
 
@@ -338,6 +457,21 @@ impl<'top, 'data: 'top, D: Decoder> IntoIterator for LazySExp<'top, D> {
 
 pub struct SExpIterator<'top, D: Decoder> {
     expanded_sexp_iter: ExpandedSExpIterator<'top, D>,
+    // The position in the container hierarchy of the values this iterator yields.
+    nesting: Nesting,
+}
+
+impl<'top, D: Decoder> SExpIterator<'top, D> {
+    /// Returns the depth of the values that this iterator yields; this is one greater than the
+    /// depth of the s-expression being iterated over.
+    pub fn depth(&self) -> usize {
+        self.nesting.depth
+    }
+
+    /// Returns the type of the container being iterated over: always [`IonType::SExp`].
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.nesting.parent_type
+    }
 }
 
 impl<'top, D: Decoder> Iterator for SExpIterator<'top, D> {
@@ -350,16 +484,45 @@ impl<'top, D: Decoder> Iterator for SExpIterator<'top, D> {
             None => return None,
         };
 
-        let lazy_value = LazyValue { expanded_value };
+        let lazy_value = LazyValue {
+            expanded_value,
+            nesting: self.nesting,
+        };
         Some(Ok(lazy_value))
     }
 }
 
+/// An iterator that reads each value in an s-expression. See [`LazySExp::elements`].
+pub struct SExpElementsIterator<'top, D: Decoder> {
+    values: SExpIterator<'top, D>,
+}
+
+impl<'top, D: Decoder> SExpElementsIterator<'top, D> {
+    /// See [`SExpIterator::depth`].
+    pub fn depth(&self) -> usize {
+        self.values.depth()
+    }
+
+    /// See [`SExpIterator::parent_type`].
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.values.parent_type()
+    }
+}
+
+impl<'top, D: Decoder> Iterator for SExpElementsIterator<'top, D> {
+    type Item = IonResult<ValueRef<'top, D>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let lazy_value = try_next!(self.values.next());
+        Some(lazy_value.read())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::element::Element;
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::{v1_0, IonResult, Reader};
+    use crate::{v1_0, IonResult, Reader, ValueRef};
 
     #[test]
     fn annotations() -> IonResult<()> {
@@ -382,4 +545,28 @@ mod tests {
         assert_eq!(result?, Element::read_one(ion_text)?);
         Ok(())
     }
+
+    #[test]
+    fn elements() -> IonResult<()> {
+        let binary_ion = to_binary_ion("[1, (2 [3]), 4] (5 6) 7")?;
+        let mut reader = Reader::new(v1_0::Binary, binary_ion)?;
+        {
+            let list = reader.expect_next()?.read()?.expect_list()?;
+            let mut elements = list.elements();
+            assert_eq!(elements.next().unwrap()?, ValueRef::Int(1.into()));
+            let sexp = elements.next().unwrap()?.expect_sexp()?;
+            let mut sexp_elements = sexp.elements();
+            assert_eq!(sexp_elements.next().unwrap()?, ValueRef::Int(2.into()));
+            let nested = sexp_elements.next().unwrap()?.expect_list()?;
+            assert_eq!(nested.elements().next().unwrap()?, ValueRef::Int(3.into()));
+            assert!(sexp_elements.next().is_none());
+            // Stop before reading the list's last value.
+        }
+        // The reader can still advance past the partially read list.
+        let sexp = reader.expect_next()?.read()?.expect_sexp()?;
+        assert_eq!(sexp.elements().next().unwrap()?, ValueRef::Int(5.into()));
+        assert_eq!(reader.expect_next()?.read()?, ValueRef::Int(7.into()));
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
 }
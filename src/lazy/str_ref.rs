@@ -51,6 +51,12 @@ impl<'data> PartialEq<StrRef<'data>> for str {
     }
 }
 
+impl<'data> PartialEq<StrRef<'data>> for &str {
+    fn eq(&self, other: &StrRef<'data>) -> bool {
+        *self == other.text()
+    }
+}
+
 impl<'data> Display for StrRef<'data> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut formatter = FmtValueFormatter { output: f };
@@ -1,14 +1,18 @@
 use std::cell::UnsafeCell;
 use std::fs::File;
 use std::io;
-use std::io::{BufReader, Read, StdinLock};
+use std::io::{BufReader, Read, Seek, SeekFrom, StdinLock};
 use std::marker::PhantomData;
 
 use crate::lazy::any_encoding::IonEncoding;
+use crate::lazy::binary::immutable_buffer::ImmutableBuffer;
 use crate::lazy::decoder::{Decoder, LazyRawReader};
 use crate::lazy::expanded::EncodingContextRef;
+use crate::lazy::lob_reader::LobBodySource;
 use crate::lazy::raw_stream_item::LazyRawStreamItem;
-use crate::{IonError, IonResult, LazyRawValue};
+use crate::lazy::text::parse_result::add_line_and_column;
+use crate::result::IonFailure;
+use crate::{IonError, IonResult, IonType, LazyRawValue};
 
 /// Wraps an implementation of [`IonDataSource`] and reads one top level value at a time from the input.
 pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
@@ -24,6 +28,10 @@ pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
     // The absolute position of the reader within the overall stream. This is the index of the first
     // byte that has not yet been read.
     stream_position: usize,
+    // If the reader has handed out the body of a lob to be read incrementally (see
+    // `Self::start_lob`), the number of that body's bytes that have not been read yet. They are
+    // skipped before the next item is read.
+    unread_lob_bytes: usize,
     // XXX: The `UnsafeCell` wrappers around the field below is a workaround for a limitation in
     //      rustc's borrow checker that prevents mutable references from being conditionally
     //      returned in a loop.
@@ -74,6 +82,13 @@ impl<'a> RawReaderState<'a> {
 }
 
 impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
+    /// When a text value fails to parse within this many bytes of the end of the buffer, the
+    /// reader will try again with more data before reporting the error.
+    const MAX_TRUNCATED_TEXT_LENGTH: usize = 1024;
+    /// If fewer than this many bytes follow a text item in the buffer, the reader cannot be
+    /// certain that the item is complete.
+    const MIN_UNAMBIGUOUS_TRAILING_TEXT_LENGTH: usize = 3;
+
     pub fn new(_encoding: Encoding, input: Input) -> StreamingRawReader<Encoding, Input> {
         StreamingRawReader {
             decoder: PhantomData,
@@ -81,6 +96,7 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
             detected_encoding: IonEncoding::default(),
             input: input.into_data_source().into(),
             stream_position: 0,
+            unread_lob_bytes: 0,
         }
     }
 
@@ -123,14 +139,17 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
         context: EncodingContextRef<'top>,
         is_peek: bool,
     ) -> IonResult<LazyRawStreamItem<'top, Encoding>> {
+        if self.unread_lob_bytes > 0 {
+            self.skip_unread_lob_bytes()?;
+        }
         let mut input_source_exhausted = false;
         loop {
             // If the input buffer is empty, try to pull more data from the source before proceeding.
             // It's important that we do this _before_ reading from the buffer; any item returned
             // from a successful `slice_reader.next()` will hold a reference to the buffer. We cannot
             // modify it once we have that item.
-            if self.buffer_is_empty() {
-                self.pull_more_data_from_source()?;
+            if self.buffer_is_empty() && self.pull_more_data_from_source()? == 0 {
+                input_source_exhausted = true;
             }
 
             let available_bytes = unsafe { &*self.input.get() }.buffer();
@@ -166,11 +185,31 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                     continue;
                 }
                 // If there's nothing available, return the result we got.
+            } else if let Err(IonError::Decoding(ref error)) = result {
+                // The text parsers match scalars like timestamps and floats using "complete"
+                // parsers, so a scalar cut off at the end of the buffer (for example: `2024-` or
+                // `1.5e`) is reported as invalid syntax rather than as incomplete data. If the
+                // error was found near the end of the buffer, try again with more data before
+                // reporting it. Errors found further back are genuine; retrying them would only
+                // pull more of the stream into memory.
+                let buffer_end = self.stream_position + available_bytes.len();
+                let is_near_end_of_buffer = error.position().is_some_and(|position| {
+                    position.byte_offset() >= self.stream_position
+                        && buffer_end - position.byte_offset() <= Self::MAX_TRUNCATED_TEXT_LENGTH
+                });
+                if old_encoding.is_text() && is_near_end_of_buffer && !input_source_exhausted {
+                    if input.fill_buffer()? == 0 {
+                        input_source_exhausted = true;
+                    }
+                    continue;
+                }
             } else if let Ok(ref item) = result {
                 // We have successfully read something from the buffer.
                 //
                 // In binary encodings, stream items contain enough data for the reader to tell
-                // whether they are complete.
+                // whether they are complete. However, a binary reader will report the end of the
+                // stream if NOP padding extends to the end of the buffer, so the end of the stream
+                // is never considered complete until the input source is exhausted.
                 //
                 // In text encodings, it's possible for the buffer to end with data that looks like
                 // a complete item but is not. The only way to be certain is to try to read again
@@ -189,13 +228,24 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                 //       └────────┬───────┘ └────────┬───────┘
                 //         buffer contents   remaining in File
                 //
-                // To avoid this, we perform a final check for text readers who have emptied their
-                // buffer: we do not consider the item complete unless the input source is exhausted.
-                if old_encoding.is_text()
-                    && bytes_read == available_bytes.len()
-                    && !input_source_exhausted
-                {
-                    use crate::lazy::raw_stream_item::RawStreamItem::*;
+                // The same is true when only a byte or two follow the item; they may be the
+                // beginning of a `'''` that continues a long string:
+                //
+                //       '''foo''' '        ''bar'''
+                //       └────────┬───────┘ └────────┬───────┘
+                //         buffer contents   remaining in File
+                //
+                // To avoid this, we perform a final check for text readers who have (nearly) emptied
+                // their buffer: we do not consider the item complete unless the input source is
+                // exhausted. The end of the stream is never considered complete until then; the
+                // text reader does not consume whitespace when it reports the end of the stream, so
+                // the buffer may still contain any amount of it.
+                use crate::lazy::raw_stream_item::RawStreamItem::*;
+                let bytes_remaining = available_bytes.len() - bytes_read;
+                let may_be_incomplete = matches!(item, EndOfStream(_))
+                    || (old_encoding.is_text()
+                        && bytes_remaining < Self::MIN_UNAMBIGUOUS_TRAILING_TEXT_LENGTH);
+                if may_be_incomplete && !input_source_exhausted {
                     match item {
                         // Text containers and e-expressions have closing delimiters that allow us
                         // to tell that they're complete.
@@ -229,13 +279,141 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                 }
             }
 
-            return result;
+            return match result {
+                // If the input source has kept the entire stream, add the line and column at
+                // which the text reader found a syntax error.
+                Err(error) if old_encoding.is_text() => match input.complete_stream() {
+                    Some(stream) => Err(add_line_and_column(error, stream)),
+                    None => Err(error),
+                },
+                result => result,
+            };
         }
     }
 
     pub fn encoding(&self) -> IonEncoding {
         self.detected_encoding
     }
+
+    /// Returns the offset of the first byte in the stream that has not yet been read. If the body
+    /// of a lob is being read incrementally, this is the offset of the item that follows the lob.
+    pub fn position(&self) -> usize {
+        self.stream_position + self.unread_lob_bytes
+    }
+
+    /// If the next item in a binary Ion 1.0 stream is an unannotated, non-null blob or clob,
+    /// consumes the lob's header and returns its type. The lob's body can then be read with
+    /// [`Self::read_lob_bytes`] without first pulling all of it into the input buffer; any part of
+    /// the body that has not been read when the next item is requested is skipped.
+    ///
+    /// For any other item or encoding, returns `Ok(None)` without consuming any input.
+    pub fn start_lob(&mut self) -> IonResult<Option<IonType>> {
+        self.skip_unread_lob_bytes()?;
+        if self.detected_encoding != IonEncoding::Binary_1_0 {
+            return Ok(None);
+        }
+        loop {
+            let input = self.input.get_mut();
+            let buffer = ImmutableBuffer::new_with_offset(input.buffer(), self.stream_position);
+            match Self::read_lob_header(buffer) {
+                Ok(Some((ion_type, header_length, body_length))) => {
+                    input.consume(header_length);
+                    self.stream_position += header_length;
+                    self.unread_lob_bytes = body_length;
+                    return Ok(Some(ion_type));
+                }
+                Ok(None) => return Ok(None),
+                // If the header is incomplete, try to pull more data from the source. If there
+                // isn't any, leave it to the next call to `next()` to report the problem.
+                Err(IonError::Incomplete(_)) => {
+                    if input.fill_buffer()? == 0 {
+                        return Ok(None);
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// If `buffer` begins with the header of a non-null blob or clob, returns the lob's type, the
+    /// length of its header, and the length of its body.
+    fn read_lob_header(buffer: ImmutableBuffer<'_>) -> IonResult<Option<(IonType, usize, usize)>> {
+        let Some(header) = buffer.peek_type_descriptor()?.to_header() else {
+            // NOP padding, an IVM, or an annotations wrapper
+            return Ok(None);
+        };
+        if !matches!(header.ion_type, IonType::Blob | IonType::Clob) || header.is_null() {
+            return Ok(None);
+        }
+        let (length, body) = buffer.consume(1).read_value_length(header)?;
+        let header_length = body.offset() - buffer.offset();
+        Ok(Some((header.ion_type, header_length, length.value())))
+    }
+
+    /// Copies the next bytes of the lob body started by [`Self::start_lob`] into `buffer`,
+    /// returning the number of bytes copied. Returns `Ok(0)` once the entire body has been read.
+    ///
+    /// This reads from the data source as needed; it does not grow the input buffer.
+    pub fn read_lob_bytes(&mut self, buffer: &mut [u8]) -> IonResult<usize> {
+        let available = self.next_lob_chunk()?;
+        let num_bytes = available.len().min(buffer.len());
+        buffer[..num_bytes].copy_from_slice(&available[..num_bytes]);
+        self.consume_lob_bytes(num_bytes);
+        Ok(num_bytes)
+    }
+
+    /// Discards any part of the current lob's body that has not been read.
+    fn skip_unread_lob_bytes(&mut self) -> IonResult<()> {
+        while self.unread_lob_bytes > 0 {
+            let num_bytes = self.next_lob_chunk()?.len();
+            self.consume_lob_bytes(num_bytes);
+        }
+        Ok(())
+    }
+
+    /// Returns the unread bytes of the current lob's body that are in the input buffer, pulling
+    /// more data from the source if the buffer is empty. Returns an empty slice if the lob's body
+    /// has been read in full.
+    fn next_lob_chunk(&mut self) -> IonResult<&[u8]> {
+        if self.unread_lob_bytes == 0 {
+            return Ok(&[]);
+        }
+        let input = self.input.get_mut();
+        if input.buffer().is_empty() && input.fill_buffer()? == 0 {
+            return IonResult::incomplete("reading a lob", self.stream_position);
+        }
+        let available = input.buffer();
+        Ok(&available[..available.len().min(self.unread_lob_bytes)])
+    }
+
+    fn consume_lob_bytes(&mut self, num_bytes: usize) {
+        self.input.get_mut().consume(num_bytes);
+        self.stream_position += num_bytes;
+        self.unread_lob_bytes -= num_bytes;
+    }
+
+    /// Repositions the reader so that the next call to [`Self::next`] will read the item that
+    /// begins `offset` bytes from the start of the stream, interpreting it using `encoding`.
+    /// Any data buffered from the previous position is discarded.
+    ///
+    /// The caller is responsible for confirming that `offset` is the beginning of a top-level
+    /// item.
+    pub fn seek_to_offset(&mut self, offset: usize, encoding: IonEncoding) -> IonResult<()>
+    where
+        Input::DataSource: SeekableDataSource,
+    {
+        self.input.get_mut().seek_to(offset)?;
+        self.stream_position = offset;
+        self.unread_lob_bytes = 0;
+        self.detected_encoding = encoding;
+        Ok(())
+    }
+}
+
+impl<Encoding: Decoder, Input: IonInput> LobBodySource for StreamingRawReader<Encoding, Input> {
+    fn read_lob_bytes(&mut self, buffer: &mut [u8]) -> IonResult<usize> {
+        StreamingRawReader::read_lob_bytes(self, buffer)
+    }
 }
 
 /// An input source--typically an implementation of either `AsRef<[u8]>` or `io::Read`--from which
@@ -252,6 +430,22 @@ pub trait IonDataSource {
     /// Marks `number_of_bytes` in the buffer as having been read. The caller is responsible for
     /// confirming that the buffer contains at least `number_of_bytes` bytes.
     fn consume(&mut self, number_of_bytes: usize);
+
+    /// If this data source retains every byte of the stream (including bytes that have already
+    /// been consumed), returns all of them. Text readers use this to report the line and column
+    /// at which a syntax error was found.
+    ///
+    /// Sources that discard consumed bytes return `None`, the default.
+    fn complete_stream(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+/// An [`IonDataSource`] that can be repositioned to an arbitrary offset in its stream.
+pub trait SeekableDataSource: IonDataSource {
+    /// Discards any buffered data and positions the data source so that the next byte it makes
+    /// available is the one `offset` bytes from the beginning of the stream.
+    fn seek_to(&mut self, offset: usize) -> IonResult<()>;
 }
 
 /// A fixed slice of Ion data that does not grow; it wraps an implementation of `AsRef<[u8]>` such
@@ -296,6 +490,10 @@ impl<SliceType: AsRef<[u8]>> IonDataSource for IonSlice<SliceType> {
         Ok(0)
     }
 
+    fn complete_stream(&self) -> Option<&[u8]> {
+        Some(self.stream_bytes())
+    }
+
     fn consume(&mut self, number_of_bytes: usize) {
         self.position += number_of_bytes;
         // In debug/test builds, this will fail noisily if something attempts to consume more data
@@ -310,6 +508,19 @@ impl<SliceType: AsRef<[u8]>> IonDataSource for IonSlice<SliceType> {
     }
 }
 
+impl<SliceType: AsRef<[u8]>> SeekableDataSource for IonSlice<SliceType> {
+    fn seek_to(&mut self, offset: usize) -> IonResult<()> {
+        let stream_length = self.stream_bytes().len();
+        if offset > stream_length {
+            return IonResult::illegal_operation(format!(
+                "cannot seek to offset {offset} in a stream that is {stream_length} bytes long"
+            ));
+        }
+        self.position = offset;
+        Ok(())
+    }
+}
+
 /// A buffered reader for types that don't implement AsRef<[u8]>
 pub struct IonStream<R: Read> {
     // The input source
@@ -327,9 +538,15 @@ impl<R: Read> IonStream<R> {
     const DEFAULT_IO_BUFFER_SIZE: usize = 4 * 1024;
 
     pub fn new(input: R) -> Self {
+        Self::with_buffer_size(input, Self::DEFAULT_IO_BUFFER_SIZE)
+    }
+
+    /// Constructs an `IonStream` whose buffer initially holds `buffer_size` bytes. The buffer
+    /// still grows if a single item does not fit in it.
+    pub(crate) fn with_buffer_size(input: R, buffer_size: usize) -> Self {
         IonStream {
             input,
-            buffer: vec![0u8; Self::DEFAULT_IO_BUFFER_SIZE],
+            buffer: vec![0u8; buffer_size],
             // The index of the first occupied byte in the buffer
             position: 0,
             // The index of the first unoccupied byte in the buffer *at or after* `position`.
@@ -384,6 +601,18 @@ impl<R: Read> IonDataSource for IonStream<R> {
     }
 }
 
+impl<R: Read + Seek> SeekableDataSource for IonStream<R> {
+    fn seek_to(&mut self, offset: usize) -> IonResult<()> {
+        // The reader's offsets count bytes from wherever `input` was positioned when this
+        // `IonStream` was constructed. This assumes that was the beginning of `input`.
+        self.input.seek(SeekFrom::Start(offset as u64))?;
+        // Everything in the buffer came from the previous position.
+        self.position = 0;
+        self.limit = 0;
+        Ok(())
+    }
+}
+
 /// Types that can be used as a source of Ion data.
 ///
 /// In general, this trait is implemented by mapping `Self` to either:
@@ -475,13 +704,16 @@ mod tests {
     use std::io::{BufReader, Cursor, Read};
 
     use crate::lazy::any_encoding::AnyEncoding;
-    use crate::lazy::decoder::{Decoder, LazyRawValue};
+    use crate::lazy::decoder::{Decoder, HasSpan, LazyRawValue};
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_stream_item::LazyRawStreamItem;
     use crate::lazy::raw_value_ref::RawValueRef;
     use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
     use crate::raw_symbol_ref::AsRawSymbolRef;
-    use crate::{v1_0, Decimal, IonError, IonResult, IonStream, RawSymbolRef, RawVersionMarker};
+    use crate::{
+        v1_0, Decimal, Element, ElementReader, IonError, IonResult, IonStream, RawSymbolRef,
+        RawVersionMarker, Reader,
+    };
 
     fn expect_value<'a, D: Decoder>(
         actual: LazyRawStreamItem<'a, D>,
@@ -551,6 +783,26 @@ mod tests {
         read_example_stream(vec)
     }
 
+    #[test]
+    fn syntax_errors_in_slices_report_line_and_column() -> IonResult<()> {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let ion = "\"foo\"\n'''bar\nbaz'''\n  [1, 2 3]";
+        let mut reader = StreamingRawReader::new(AnyEncoding, ion.as_bytes());
+        expect_string(reader.next(context)?, "foo")?;
+        expect_string(reader.next(context)?, "bar\nbaz")?;
+        // The raw reader only sees the unread portion of the input, but the streaming reader has
+        // access to the complete slice.
+        let error = reader.next(context).expect_err("expected an error");
+        let position = error.position().expect("error did not have a position");
+        assert_eq!(position.line_and_column(), Some((4, 9)));
+        assert!(
+            error.to_string().ends_with("  [1, 2 3]\n            ^"),
+            "{error}"
+        );
+        Ok(())
+    }
+
     /// Returns an implementation of io::Read with a buffer small enough to encounter multiple
     /// incomplete values.
     fn tiny_buf_reader(input: &str) -> BufReader<Cursor<&str>> {
@@ -646,4 +898,89 @@ mod tests {
 
         Ok(())
     }
+
+    /// An implementation of `io::Read` that returns at most `chunk_size` bytes from each call
+    /// to `read()`.
+    struct ChunkedReader<'a> {
+        data: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl<'a> Read for ChunkedReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length = self.chunk_size.min(buf.len()).min(self.data.len());
+            buf[..length].copy_from_slice(&self.data[..length]);
+            self.data = &self.data[length..];
+            Ok(length)
+        }
+    }
+
+    /// Reads every item in `input`, returning a description of each one.
+    fn raw_items(input: impl IonInput) -> IonResult<Vec<String>> {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = StreamingRawReader::new(v1_0::Text, input);
+        let mut items = Vec::new();
+        loop {
+            let item = match reader.next(context)? {
+                LazyRawStreamItem::<v1_0::Text>::VersionMarker(marker) => {
+                    format!("IVM {:?}", marker.major_minor())
+                }
+                LazyRawStreamItem::<v1_0::Text>::Value(value) => format!(
+                    "{} {}",
+                    value.ion_type(),
+                    std::str::from_utf8(value.span().bytes()).unwrap()
+                ),
+                LazyRawStreamItem::<v1_0::Text>::EndOfStream(_) => return Ok(items),
+                other => panic!("unexpected item {other:?}"),
+            };
+            items.push(item);
+        }
+    }
+
+    #[test]
+    fn chunked_text_matches_one_shot() -> IonResult<()> {
+        let document = r#"$ion_1_0
+            "a string long enough to span several chunks of input"
+            '''a long string ''' /* a block comment between
+            segments */ '''that also spans several chunks'''
+            // A line comment that straddles a chunk boundary
+            annotation_one::annotation_two::2024-06-01T12:30:05.250-05:00
+            {field_name: [1, 2, 3], another_field: (a b c)}
+            3.14159e0 12345.6789 -98765 $ion_symbol_table
+            123"#;
+        let expected = raw_items(document)?;
+        assert_eq!(expected.len(), 10);
+        // The final value is terminated only by the end of the stream.
+        assert_eq!(expected.last().unwrap(), "int 123");
+        for chunk_size in [1, 2, 3, 7, 64] {
+            let input = IonStream::new(ChunkedReader {
+                data: document.as_bytes(),
+                chunk_size,
+            });
+            assert_eq!(raw_items(input)?, expected, "chunk size {chunk_size}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn chunked_binary_with_nop_padding_matches_one_shot() -> IonResult<()> {
+        let data: &[u8] = &[
+            0xe0, 0x01, 0x00, 0xea, // IVM
+            0x03, 0x00, 0x00, 0x00, // 4-byte NOP
+            0x21, 0x01, // 1
+            0x0e, 0x85, 0x00, 0x00, 0x00, 0x00, 0x00, // 7-byte NOP with a VarUInt length
+            0xb4, 0x00, 0x21, 0x02, 0x00, // [2] with a NOP before and after the value
+            0xd5, 0x84, 0x00, 0x84, 0x21, 0x03, // {name: 3} with a NOP field before it
+            0x01, 0x00, // 2-byte NOP at the end of the stream
+        ];
+        let expected = Element::read_all(data)?;
+        assert_eq!(expected.len(), 3);
+        for chunk_size in [1, 2, 3, 7, 64] {
+            let input = IonStream::new(ChunkedReader { data, chunk_size });
+            let actual = Reader::new(v1_0::Binary, input)?.read_all_elements()?;
+            assert_eq!(actual, expected, "chunk size {chunk_size}");
+        }
+        Ok(())
+    }
 }
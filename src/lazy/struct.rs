@@ -7,10 +7,13 @@ use crate::lazy::expanded::r#struct::{
     ExpandedStructIterator, ExpandedStructSource, LazyExpandedField, LazyExpandedStruct,
 };
 use crate::lazy::expanded::LazyExpandedValue;
-use crate::lazy::value::{AnnotationsIterator, LazyValue};
+use crate::lazy::value::{AnnotationsIterator, LazyValue, Nesting};
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
-use crate::{Annotations, Element, IntoAnnotatedElement, IonError, IonResult, Struct, SymbolRef};
+use crate::{
+    Annotations, Element, IntoAnnotatedElement, IonError, IonResult, IonType, RawSymbolRef, Struct,
+    Symbol, SymbolId, SymbolRef,
+};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 
@@ -49,6 +52,7 @@ use std::fmt::{Debug, Formatter};
 #[derive(Copy, Clone)]
 pub struct LazyStruct<'top, D: Decoder> {
     pub(crate) expanded_struct: LazyExpandedStruct<'top, D>,
+    pub(crate) nesting: Nesting,
 }
 
 pub type LazyBinaryStruct_1_0<'top> = LazyStruct<'top, BinaryEncoding_1_0>;
@@ -72,13 +76,72 @@ impl<'top, D: Decoder> Debug for LazyStruct<'top, D> {
 
 impl<'top, D: Decoder> LazyStruct<'top, D> {
     pub(crate) fn new(expanded_struct: LazyExpandedStruct<'top, D>) -> Self {
-        Self { expanded_struct }
+        Self {
+            expanded_struct,
+            nesting: Nesting::TOP_LEVEL,
+        }
+    }
+
+    /// Returns a copy of this struct that reports the provided position in the container hierarchy.
+    pub(crate) fn with_nesting(mut self, nesting: Nesting) -> Self {
+        self.nesting = nesting;
+        self
+    }
+
+    /// The position in the container hierarchy of this struct's field values.
+    fn field_nesting(&self) -> Nesting {
+        self.nesting.child_of(IonType::Struct)
     }
 
     /// Returns an iterator over this struct's fields. See [`LazyField`].
     pub fn iter(&self) -> StructIterator<'top, D> {
         StructIterator {
             expanded_struct_iter: self.expanded_struct.iter(),
+            nesting: self.field_nesting(),
+            current_field: None,
+        }
+    }
+
+    /// Returns an iterator over this struct's fields that reads each field's name and value as it
+    /// is visited, yielding `(SymbolRef, ValueRef)` pairs. This is convenient when every field
+    /// will be read; use [`iter`](Self::iter) to inspect fields without reading their values.
+    ///
+    /// Nested containers are yielded as lazy container references (for example,
+    /// [`ValueRef::List`]) which can be iterated over in turn. Dropping the iterator before it is
+    /// exhausted leaves the remaining fields unread.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Reader, ValueRef};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "{foo: 1, bar: [2, 3], baz: 4}")?;
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    ///
+    /// let mut sum = 0;
+    /// for field in lazy_struct.fields() {
+    ///     let (name, value) = field?;
+    ///     match value {
+    ///         ValueRef::Int(i) => sum += i.expect_i64()?,
+    ///         ValueRef::List(list) => {
+    ///             assert_eq!(name, "bar");
+    ///             for element in list.elements() {
+    ///                 sum += element?.expect_i64()?;
+    ///             }
+    ///         }
+    ///         other => panic!("unexpected value: {other:?}"),
+    ///     }
+    /// }
+    /// assert_eq!(sum, 10);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn fields(&self) -> FieldsIterator<'top, D> {
+        FieldsIterator {
+            fields: self.iter(),
         }
     }
 
@@ -101,7 +164,7 @@ impl<'top, D: Decoder> LazyStruct<'top, D> {
                 LazyExpandedValue::from_template(self.expanded_struct.context, env, element)
             }
         };
-        LazyValue::new(expanded_value)
+        LazyValue::new(expanded_value).with_nesting(self.nesting)
     }
 
     /// Returns the value of the first field with the specified name, if any. The returned value is
@@ -141,7 +204,7 @@ impl<'top, D: Decoder> LazyStruct<'top, D> {
         let Some(expanded_value) = self.expanded_struct.find(name)? else {
             return Ok(None);
         };
-        let value = LazyValue::new(expanded_value);
+        let value = LazyValue::new(expanded_value).with_nesting(self.field_nesting());
         Ok(Some(value))
     }
 
@@ -228,6 +291,86 @@ impl<'top, D: Decoder> LazyStruct<'top, D> {
         })
     }
 
+    /// Returns an iterator over the values of all fields with the specified name, in the order in
+    /// which they appear in the struct. Field names encoded as symbol IDs are resolved using the
+    /// symbol table before being compared; fields whose names have unknown text never match.
+    ///
+    /// Like [`LazyStruct::find`], this method has linear time complexity.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, ValueRef, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let ion_data = r#"{foo: "hello", bar: quux::5, baz: null, bar: false}"#;
+    /// let ion_bytes: Vec<u8> = Element::read_one(ion_data)?.encode_as(Binary)?;
+    /// let mut reader = Reader::new(Binary, ion_bytes)?;
+    ///
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    ///
+    /// let bars = lazy_struct.find_all("bar").collect::<IonResult<Vec<_>>>()?;
+    /// assert_eq!(bars.len(), 2);
+    /// assert_eq!(bars[0].annotations().next().unwrap()?, "quux");
+    /// assert_eq!(bars[1].read()?, ValueRef::Bool(false));
+    /// assert_eq!(lazy_struct.find_all("Ontario").count(), 0);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn find_all<'a>(
+        &self,
+        name: &'a str,
+    ) -> impl Iterator<Item = IonResult<LazyValue<'top, D>>> + 'a
+    where
+        'top: 'a,
+    {
+        self.iter().filter_map(move |field_result| {
+            let field = match field_result {
+                Ok(field) => field,
+                Err(e) => return Some(Err(e)),
+            };
+            match field.name() {
+                Ok(field_name) if field_name.text() == Some(name) => Some(Ok(field.value())),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            }
+        })
+    }
+
+    /// Like [`LazyStruct::find_all`], but eagerly calls [`LazyValue::read`] on each matching
+    /// field's value.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, ValueRef, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let ion_data = r#"{foo: 1, bar: 2, foo: 3}"#;
+    /// let ion_bytes = Element::read_one(ion_data)?.encode_as(Binary)?;
+    /// let mut reader = Reader::new(Binary, ion_bytes)?;
+    ///
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    ///
+    /// let foos = lazy_struct.get_all("foo").collect::<IonResult<Vec<_>>>()?;
+    /// assert_eq!(foos, vec![ValueRef::Int(1.into()), ValueRef::Int(3.into())]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn get_all<'a>(
+        &self,
+        name: &'a str,
+    ) -> impl Iterator<Item = IonResult<ValueRef<'top, D>>> + 'a
+    where
+        'top: 'a,
+    {
+        self.find_all(name).map(|result| result?.read())
+    }
+
     /// Returns an iterator over the annotations on this value. If this value has no annotations,
     /// the resulting iterator will be empty.
     ///
@@ -261,10 +404,7 @@ impl<'top, D: Decoder> LazyStruct<'top, D> {
     ///# fn main() -> IonResult<()> { Ok(()) }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_struct.annotations(),
-            symbol_table: self.expanded_struct.context.symbol_table(),
-        }
+        self.as_value().annotations()
     }
 }
 
@@ -272,6 +412,8 @@ impl<'top, D: Decoder> LazyStruct<'top, D> {
 #[derive(Copy, Clone)]
 pub struct LazyField<'top, D: Decoder> {
     pub(crate) expanded_field: LazyExpandedField<'top, D>,
+    // The position of this field's value in the container hierarchy.
+    pub(crate) nesting: Nesting,
 }
 
 impl<'top, D: Decoder> Debug for LazyField<'top, D> {
@@ -291,11 +433,23 @@ impl<'top, D: Decoder> LazyField<'top, D> {
         self.expanded_field.name().read()
     }
 
+    /// If this field's name was encoded as a symbol ID, returns `Some(sid)` whether or not the
+    /// symbol ID's text could be resolved. If the name's text was encoded inline, returns `None`.
+    pub fn name_symbol_id(&self) -> IonResult<Option<SymbolId>> {
+        Ok(self.expanded_field.name().read_raw()?.symbol_id())
+    }
+
+    /// Returns the text of this field's name, or `None` if the text is unknown.
+    pub fn name_text(&self) -> IonResult<Option<&'top str>> {
+        Ok(self.name()?.text())
+    }
+
     /// Returns a lazy value representing the value of this field. To access the value's data,
     /// see [`LazyValue::read`].
     pub fn value(&self) -> LazyValue<'top, D> {
         LazyValue {
             expanded_value: self.expanded_field.value(),
+            nesting: self.nesting,
         }
     }
 
@@ -342,6 +496,10 @@ impl<'top, D: Decoder> LazyField<'top, D> {
 
 pub struct StructIterator<'top, D: Decoder> {
     pub(crate) expanded_struct_iter: ExpandedStructIterator<'top, D>,
+    // The position in the container hierarchy of the field values this iterator yields.
+    nesting: Nesting,
+    // The field most recently returned by the iterator, if any.
+    current_field: Option<LazyField<'top, D>>,
 }
 
 impl<'top, D: Decoder> Iterator for StructIterator<'top, D> {
@@ -360,22 +518,175 @@ impl<'top, D: Decoder> StructIterator<'top, D> {
     pub fn next_field(&mut self) -> IonResult<Option<LazyField<'top, D>>> {
         let expanded_field = match self.expanded_struct_iter.next() {
             Some(expanded_field) => expanded_field?,
-            None => return Ok(None),
+            None => {
+                self.current_field = None;
+                return Ok(None);
+            }
         };
 
-        let lazy_field = LazyField { expanded_field };
+        let lazy_field = LazyField {
+            expanded_field,
+            nesting: self.nesting,
+        };
+        self.current_field = Some(lazy_field);
         Ok(Some(lazy_field))
     }
+
+    /// Returns the depth of the field values that this iterator yields; this is one greater than
+    /// the depth of the struct being iterated over.
+    pub fn depth(&self) -> usize {
+        self.nesting.depth
+    }
+
+    /// Returns the type of the container being iterated over: always [`IonType::Struct`].
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.nesting.parent_type
+    }
+
+    /// Returns the name of the field that the iterator most recently yielded. If the iterator has
+    /// not yielded a field yet or has been exhausted, returns `Ok(None)`.
+    ///
+    /// The field name has already been matched by the iterator, so this only resolves its text in
+    /// the symbol table. A name whose text is unknown is returned as a [`Symbol`] with no text.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{IonType, Reader};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "{foo: 1, bar: {baz: 2}}")?;
+    /// let mut fields = reader.expect_next()?.read()?.expect_struct()?.iter();
+    /// assert_eq!(fields.field_name()?, None);
+    /// assert_eq!((fields.depth(), fields.parent_type()), (1, Some(IonType::Struct)));
+    ///
+    /// fields.next_field()?;
+    /// assert_eq!(fields.field_name()?.unwrap(), "foo");
+    /// let bar = fields.next_field()?.unwrap().value();
+    /// assert_eq!(fields.field_name()?.unwrap(), "bar");
+    ///
+    /// let mut nested = bar.read()?.expect_struct()?.iter();
+    /// nested.next_field()?;
+    /// assert_eq!(nested.field_name()?.unwrap(), "baz");
+    /// assert_eq!(nested.depth(), 2);
+    ///
+    /// assert!(fields.next_field()?.is_none());
+    /// assert_eq!(fields.field_name()?, None);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn field_name(&self) -> IonResult<Option<Symbol>> {
+        let Some(field) = self.current_field else {
+            return Ok(None);
+        };
+        let symbol_cache = &field.expanded_field.value().context.symbol_cache;
+        Ok(Some(symbol_cache.materialize(field.name()?)))
+    }
+
+    /// Advances the iterator to the next field with the specified name and returns it. The values
+    /// of any fields skipped along the way are not read. Subsequent calls to
+    /// [`next_field`](Self::next_field) continue with the field that follows the match. If none
+    /// of the remaining fields has the specified name, returns `Ok(None)` and the iterator is
+    /// exhausted.
+    ///
+    /// Field names encoded as symbol IDs are only resolved if the symbol table contains `name`;
+    /// if it doesn't, no such field can match.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let ion_data = r#"{foo: 1, bar: 2, baz: 3, quux: 4}"#;
+    /// let ion_bytes = Element::read_one(ion_data)?.encode_as(Binary)?;
+    /// let mut reader = Reader::new(Binary, ion_bytes)?;
+    ///
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    /// let mut fields = lazy_struct.iter();
+    /// let bar = fields.find_field("bar")?.unwrap();
+    /// assert_eq!(bar.value().read()?.expect_i64()?, 2);
+    /// // Iteration picks up after the field that was found.
+    /// let next = fields.next_field()?.unwrap();
+    /// assert_eq!(next.name()?, "baz");
+    /// assert!(fields.find_field("foo")?.is_none());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn find_field(&mut self, name: &str) -> IonResult<Option<LazyField<'top, D>>> {
+        // Whether `name` is in the symbol table; only checked once a symbol ID field name is seen.
+        let mut name_is_in_symbol_table = None;
+        while let Some(field) = self.next_field()? {
+            let is_match = match field.expanded_field.name().read_raw()? {
+                RawSymbolRef::Text(text) => text == name,
+                RawSymbolRef::SymbolId(_) => {
+                    *name_is_in_symbol_table.get_or_insert_with(|| {
+                        field.value().symbol_table().sid_for(&name).is_some()
+                    }) && field.name()?.text() == Some(name)
+                }
+            };
+            if is_match {
+                return Ok(Some(field));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// An iterator over a struct's fields that reads each field's name and value. See
+/// [`LazyStruct::fields`].
+pub struct FieldsIterator<'top, D: Decoder> {
+    fields: StructIterator<'top, D>,
+}
+
+impl<'top, D: Decoder> FieldsIterator<'top, D> {
+    /// See [`StructIterator::depth`].
+    pub fn depth(&self) -> usize {
+        self.fields.depth()
+    }
+
+    /// See [`StructIterator::parent_type`].
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.fields.parent_type()
+    }
+
+    /// See [`StructIterator::field_name`].
+    pub fn field_name(&self) -> IonResult<Option<Symbol>> {
+        self.fields.field_name()
+    }
+}
+
+impl<'top, D: Decoder> Iterator for FieldsIterator<'top, D> {
+    type Item = IonResult<(SymbolRef<'top>, ValueRef<'top, D>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = match self.fields.next_field() {
+            Ok(Some(field)) => field,
+            Ok(None) => return None,
+            Err(e) => return Some(Err(e)),
+        };
+        let name = match field.name() {
+            Ok(name) => name,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(field.value().read().map(|value| (name, value)))
+    }
 }
 
 impl<'top, D: Decoder> TryFrom<LazyStruct<'top, D>> for Struct {
     type Error = IonError;
 
     fn try_from(lazy_struct: LazyStruct<'top, D>) -> Result<Self, Self::Error> {
+        let symbol_cache = &lazy_struct.expanded_struct.context.symbol_cache;
         let mut builder = StructBuilder::new();
         for field in &lazy_struct {
             let field = field?;
-            builder = builder.with_field(field.name()?, Element::try_from(field.value())?);
+            let name = symbol_cache.materialize(field.name()?);
+            builder = builder.with_field(name, Element::try_from(field.value())?);
         }
         Ok(builder.build())
     }
@@ -411,8 +722,10 @@ impl<'top, 'data: 'top, D: Decoder> IntoIterator for LazyStruct<'top, D> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use crate::lazy::binary::test_utilities::to_binary_ion;
-    use crate::{v1_0, Reader};
+    use crate::{v1_0, AnyEncoding, Decoder, Reader, UnresolvedSymbolIdPolicy};
 
     use super::*;
 
@@ -442,6 +755,44 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::text("{foo: 1, bar: 2, baz: 3, bar: 4}".as_bytes().to_owned())]
+    #[case::binary(to_binary_ion("{foo: 1, bar: 2, baz: 3, bar: 4}").unwrap())]
+    fn find_field(#[case] ion_data: Vec<u8>) -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, ion_data)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+
+        let mut fields = struct_.iter();
+        let bar = fields.find_field("bar")?.expect("field 'bar'");
+        assert_eq!(bar.name()?, "bar");
+        assert_eq!(bar.value().read()?, ValueRef::Int(2.into()));
+        // Iteration resumes after the field that was found.
+        let baz = fields.next_field()?.expect("field 'baz'");
+        assert_eq!(baz.name()?, "baz");
+        // Repeated calls find later fields with the same name.
+        let bar = fields.find_field("bar")?.expect("second field 'bar'");
+        assert_eq!(bar.value().read()?, ValueRef::Int(4.into()));
+        assert!(fields.find_field("bar")?.is_none());
+        assert!(fields.next_field()?.is_none());
+
+        // Fields that aren't in the struct exhaust the iterator.
+        let mut fields = struct_.iter();
+        assert!(fields.find_field("quux")?.is_none());
+        assert!(fields.next_field()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn find_field_not_in_symbol_table() -> IonResult<()> {
+        let ion_data = to_binary_ion("{foo: 1, bar: 2}")?;
+        let mut reader = Reader::new(v1_0::Binary, ion_data)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let mut fields = struct_.iter();
+        assert!(fields.find_field("quux")?.is_none());
+        assert!(fields.next_field()?.is_none());
+        Ok(())
+    }
+
     #[test]
     fn get() -> IonResult<()> {
         let ion_data = to_binary_ion("{foo: 1, bar: 2, baz: 3}")?;
@@ -488,4 +839,184 @@ mod tests {
         assert_eq!(result?, Element::read_one(ion_text)?);
         Ok(())
     }
+
+    #[rstest]
+    #[case::strict(UnresolvedSymbolIdPolicy::Error)]
+    #[case::lenient(UnresolvedSymbolIdPolicy::UnknownText)]
+    fn field_name_and_annotation_symbol_ids(
+        #[case] policy: UnresolvedSymbolIdPolicy,
+    ) -> IonResult<()> {
+        let is_lenient = policy == UnresolvedSymbolIdPolicy::UnknownText;
+        #[rustfmt::skip]
+        let ion_data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0xD9,                   // Struct, length 9
+            0x84, 0x21, 0x01,       //   $4 (name): 1
+            0x80, 0x21, 0x02,       //   $0: 2
+            0xE3, 0x21, 0x03,       //   $99 (out of range): 3
+            0xE6, 0x83,             // Annotations wrapper, length 6; annotations length 3
+            0x84, 0x80, 0xE3,       //   $4::$0::$99::
+            0x21, 0x05,             //   5
+        ];
+        let mut reader = Reader::new(
+            v1_0::Binary.with_unresolved_symbol_id_policy(policy),
+            ion_data,
+        )?;
+
+        let fields: Vec<_> = reader
+            .expect_next()?
+            .read()?
+            .expect_struct()?
+            .iter()
+            .collect::<IonResult<_>>()?;
+
+        // A symbol ID with known text
+        assert_eq!(fields[0].name_symbol_id()?, Some(4));
+        assert_eq!(fields[0].name_text()?, Some("name"));
+        // A symbol ID with unknown text
+        assert_eq!(fields[1].name_symbol_id()?, Some(0));
+        assert_eq!(fields[1].name_text()?, None);
+        assert_eq!(fields[1].name()?, SymbolRef::with_unknown_text());
+        // A symbol ID that is not in the symbol table
+        assert_eq!(fields[2].name_symbol_id()?, Some(99));
+        if is_lenient {
+            assert_eq!(fields[2].name_text()?, None);
+            // The symbol with unknown text retains its symbol ID.
+            assert_eq!(fields[2].name()?, SymbolRef::with_unknown_text_sid(99));
+        } else {
+            assert!(fields[2].name_text().is_err());
+            assert!(fields[2].name().is_err());
+        }
+
+        let value = reader.expect_next()?;
+        let symbol_ids: Vec<_> = value.annotations().symbol_ids().collect::<IonResult<_>>()?;
+        assert_eq!(symbol_ids, vec![Some(4), Some(0), Some(99)]);
+        let texts: IonResult<Vec<_>> = value.annotations().texts().collect();
+        if is_lenient {
+            assert_eq!(texts?, vec![Some("name"), None, None]);
+        } else {
+            assert!(texts.is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn text_field_names_have_no_symbol_id() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "foo::{foo: 1, $4: 2}")?;
+        let value = reader.expect_next()?;
+        let fields: Vec<_> = value
+            .read()?
+            .expect_struct()?
+            .iter()
+            .collect::<IonResult<_>>()?;
+        assert_eq!(fields[0].name_symbol_id()?, None);
+        assert_eq!(fields[0].name_text()?, Some("foo"));
+        assert_eq!(fields[1].name_symbol_id()?, Some(4));
+        assert_eq!(fields[1].name_text()?, Some("name"));
+        let symbol_ids: Vec<_> = value.annotations().symbol_ids().collect::<IonResult<_>>()?;
+        assert_eq!(symbol_ids, vec![None]);
+        Ok(())
+    }
+
+    const DUPLICATE_FIELDS: &str = r#"
+        $ion_symbol_table::{symbols: ["foo"]}
+        {
+            foo: 1,
+            $0: 2,
+            bar: 3,
+            $10: 4,
+            '$0': 5,
+            foo: 6,
+            $0: 7,
+            foo: bar::8,
+        }
+    "#;
+
+    #[rstest]
+    #[case::text(DUPLICATE_FIELDS.as_bytes().to_vec())]
+    #[case::binary(Element::read_one(DUPLICATE_FIELDS)?.encode_as(v1_0::Binary)?)]
+    fn find_all_and_get_all(#[case] ion_data: Vec<u8>) -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, ion_data)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+
+        // Symbol ID field names (`$10`) are resolved through the symbol table before comparison.
+        let foos = struct_.get_all("foo").collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(
+            foos,
+            vec![
+                ValueRef::Int(1.into()),
+                ValueRef::Int(4.into()),
+                ValueRef::Int(6.into()),
+                ValueRef::Int(8.into()),
+            ]
+        );
+        let last_foo = struct_.find_all("foo").last().unwrap()?;
+        assert_eq!(last_foo.annotations().next().unwrap()?, "bar");
+
+        // Fields whose names have unknown text never match, but don't prevent finding the fields
+        // after them. A quoted symbol `'$0'` is text, not a symbol ID.
+        assert_eq!(
+            struct_.get_all("$0").collect::<IonResult<Vec<_>>>()?,
+            vec![ValueRef::Int(5.into())]
+        );
+        assert_eq!(
+            struct_.get_all("bar").collect::<IonResult<Vec<_>>>()?,
+            vec![ValueRef::Int(3.into())]
+        );
+        assert_eq!(struct_.find_all("quux").count(), 0);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text("{a: 1, b: [2, 3], c: {d: 4}, e: five} next")]
+    #[case::binary(to_binary_ion("{a: 1, b: [2, 3], c: {d: 4}, e: five} next").unwrap())]
+    fn fields(#[case] ion_data: impl AsRef<[u8]>) -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, ion_data.as_ref())?;
+        {
+            let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+            let mut fields = struct_.fields();
+            let (name, value) = fields.next().unwrap()?;
+            assert_eq!(name, "a");
+            assert_eq!(value, ValueRef::Int(1.into()));
+            // Nested containers can be iterated over while the outer iterator is in use.
+            let (name, value) = fields.next().unwrap()?;
+            assert_eq!(name, "b");
+            let list = value.expect_list()?;
+            let elements = list.elements().collect::<IonResult<Vec<_>>>()?;
+            assert_eq!(
+                elements,
+                vec![ValueRef::Int(2.into()), ValueRef::Int(3.into())]
+            );
+            let (name, value) = fields.next().unwrap()?;
+            assert_eq!(name, "c");
+            let (nested_name, nested_value) = value.expect_struct()?.fields().next().unwrap()?;
+            assert_eq!(nested_name, "d");
+            assert_eq!(nested_value, ValueRef::Int(4.into()));
+            // `fields` never visits `e`, but the struct can be iterated over again.
+            let names = struct_
+                .fields()
+                .map(|field| Ok(field?.0.text().unwrap().to_owned()))
+                .collect::<IonResult<Vec<_>>>()?;
+            assert_eq!(names, vec!["a", "b", "c", "e"]);
+        }
+        // The reader can still advance past the partially read struct.
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "next");
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn fields_unknown_text_name() -> IonResult<()> {
+        let config =
+            AnyEncoding.with_unresolved_symbol_id_policy(UnresolvedSymbolIdPolicy::UnknownText);
+        let mut reader = Reader::new(config, "{$0: 1, $20: 2}")?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let names = struct_
+            .fields()
+            .map(|field| Ok(field?.0))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(names.len(), 2);
+        assert!(names.iter().all(|name| name.text().is_none()));
+        Ok(())
+    }
 }
@@ -5,10 +5,12 @@ use crate::lazy::decoder::Decoder;
 use crate::lazy::expanded::compiler::TemplateCompiler;
 use crate::lazy::expanded::encoding_module::EncodingModule;
 use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::symbol_cache::SymbolCacheStats;
 use crate::lazy::expanded::template::TemplateMacro;
 use crate::lazy::expanded::{ExpandedStreamItem, ExpandingReader, LazyExpandedValue};
+use crate::lazy::lob_reader::LobReader;
 use crate::lazy::sequence::SExpIterator;
-use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
+use crate::lazy::streaming_raw_reader::{IonInput, SeekableDataSource, StreamingRawReader};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
@@ -16,7 +18,7 @@ use crate::read_config::ReadConfig;
 use crate::result::IonFailure;
 use crate::{
     AnyEncoding, Catalog, Int, IonError, IonResult, IonType, LazyField, LazySExp, LazyStruct,
-    RawSymbolRef, Symbol, SymbolTable, ValueRef,
+    RawSymbolRef, Symbol, SymbolId, SymbolTable, ValueRef,
 };
 use std::ops::Deref;
 use std::sync::Arc;
@@ -26,6 +28,11 @@ const ION_SYMBOL_TABLE: RawSymbolRef = RawSymbolRef::SymbolId(3);
 const IMPORTS: RawSymbolRef = RawSymbolRef::SymbolId(6);
 const SYMBOLS: RawSymbolRef = RawSymbolRef::SymbolId(7);
 
+// The maximum number of symbol IDs that a symbol table's imports may reserve for symbols whose text
+// the catalog cannot provide. Each reserved ID occupies a slot in the symbol table, so without a
+// limit, an import with an enormous `max_id` could exhaust the reader's memory.
+const MAX_UNRESOLVED_IMPORTED_SYMBOLS: usize = 1 << 20;
+
 /// A binary reader that only reads each value that it visits upon request (that is: lazily).
 ///
 /// Unlike [`crate::lazy::reader::Reader`], which only exposes values that are part
@@ -84,6 +91,57 @@ pub struct SystemReader<Encoding: Decoder, Input: IonInput> {
     pub(crate) expanding_reader: ExpandingReader<Encoding, Input>,
 }
 
+/// Describes how a reader's symbol table changed. Passed to the handler registered with
+/// [`SystemReader::on_symbol_table_change`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolTableEvent {
+    /// The symbol table was replaced, either by an Ion version marker, an encoding directive, or a
+    /// local symbol table that did not import the current one. Symbol IDs encountered before
+    /// this event may now map to different text.
+    Reset,
+    /// Symbols were appended to the existing symbol table. The first new symbol has the
+    /// provided symbol ID.
+    Append(SymbolId),
+}
+
+/// A position between top-level expressions in an Ion stream, along with the encoding context
+/// (symbol table, macro table, and Ion encoding) in effect at that position.
+///
+/// Because symbol IDs and macro addresses are resolved using context established earlier in
+/// the stream, a reader cannot simply jump to an offset and begin reading. A checkpoint allows
+/// a reader to resume reading at its position without re-reading the data that precedes it.
+/// See [`SystemReader::checkpoint`] and [`SystemReader::seek_to_checkpoint`].
+///
+/// Checkpoints are cheap to create and to clone. Checkpoints taken while the symbol table is
+/// unchanged share a single copy of it, and restoring a checkpoint does not copy the symbol table
+/// if the reader's table has not changed since the checkpoint was taken. This makes checkpoints
+/// suitable for speculative parsing, in which a reader tries to read ahead and then rewinds if
+/// the attempt fails.
+#[derive(Debug, Clone)]
+pub struct ReaderCheckpoint {
+    pub(crate) offset: usize,
+    pub(crate) encoding: IonEncoding,
+    pub(crate) symbol_table: Arc<SymbolTable>,
+    pub(crate) macro_table: MacroTable,
+}
+
+impl ReaderCheckpoint {
+    /// Returns the offset of the first byte of the expression that follows the checkpoint.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the Ion encoding in use at the checkpoint.
+    pub fn encoding(&self) -> IonEncoding {
+        self.encoding
+    }
+
+    /// Returns the symbol table in effect at the checkpoint.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
+    }
+}
+
 // If the reader encounters a symbol table in the stream, it will store all of the symbols that
 // the table defines in this structure so that they may be applied when the reader next advances.
 #[derive(Default)]
@@ -135,7 +193,14 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
     ) -> SystemReader<Encoding, Input> {
         let config = config.into();
         let raw_reader = StreamingRawReader::new(config.encoding(), input);
-        let expanding_reader = ExpandingReader::new(raw_reader, config.catalog);
+        let mut expanding_reader = ExpandingReader::new(raw_reader, config.catalog);
+        expanding_reader.context_mut().read_options = config.options;
+        if let Some(mut symbol_table) = config.initial_symbol_table {
+            // The caller may install copies of the same table in several readers. Each reader
+            // will modify its copy independently, so it must not share the original's generation.
+            symbol_table.start_new_generation();
+            expanding_reader.context_mut().symbol_table = symbol_table;
+        }
         SystemReader { expanding_reader }
     }
 
@@ -191,10 +256,94 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         self.expanding_reader.context().symbol_table()
     }
 
+    /// Returns statistics describing the reader's cache of materialized symbol text.
+    pub fn symbol_cache_stats(&self) -> SymbolCacheStats {
+        self.expanding_reader.context().symbol_cache.stats()
+    }
+
     pub fn pending_context_changes(&self) -> &PendingContextChanges {
         self.expanding_reader.pending_context_changes()
     }
 
+    /// Registers a handler that will be called each time the reader's symbol table is reset or
+    /// appended to, replacing any handler that was previously registered.
+    ///
+    /// The handler is called once a symbol table, encoding directive, or Ion version marker has
+    /// been fully processed, before any of the values that follow it are surfaced. If a single
+    /// e-expression produces several symbol tables, the handler is called once for their
+    /// combined changes.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-tooling-apis")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, SymbolTableEvent, SystemReader};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let events = Rc::new(RefCell::new(Vec::new()));
+    /// let mut reader = SystemReader::new(
+    ///     AnyEncoding,
+    ///     r#"
+    ///         $ion_symbol_table::{symbols: ["foo"]}
+    ///         $10
+    ///         $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["bar"]}
+    ///         $11
+    ///     "#,
+    /// );
+    /// let handler_events = Rc::clone(&events);
+    /// reader.on_symbol_table_change(move |symbol_table, event| {
+    ///     handler_events.borrow_mut().push((event, symbol_table.len()));
+    /// });
+    /// assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "foo");
+    /// assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "bar");
+    /// assert_eq!(
+    ///     *events.borrow(),
+    ///     [(SymbolTableEvent::Reset, 11), (SymbolTableEvent::Append(11), 12)]
+    /// );
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-tooling-apis"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn on_symbol_table_change(
+        &mut self,
+        handler: impl FnMut(&SymbolTable, SymbolTableEvent) + 'static,
+    ) {
+        self.expanding_reader
+            .set_symbol_table_change_handler(Box::new(handler));
+    }
+
+    /// Returns a [`ReaderCheckpoint`] describing the reader's current position in the stream and
+    /// the encoding context in effect there. Passing it to [`Self::seek_to_checkpoint`] later will
+    /// return the reader to this position.
+    ///
+    /// Returns an error if the reader is in the middle of expanding a macro invocation that has
+    /// more values to produce.
+    pub fn checkpoint(&mut self) -> IonResult<ReaderCheckpoint> {
+        self.expanding_reader.checkpoint()
+    }
+
+    /// Repositions the reader at a [`ReaderCheckpoint`] captured earlier from a reader of the same
+    /// stream and restores the symbol and macro tables that were in effect there. If a symbol
+    /// table change handler is registered, it is notified with [`SymbolTableEvent::Reset`].
+    ///
+    /// This is available when the input is an in-memory slice or a data source that implements
+    /// [`Seek`](std::io::Seek).
+    pub fn seek_to_checkpoint(&mut self, checkpoint: &ReaderCheckpoint) -> IonResult<()>
+    where
+        Input::DataSource: SeekableDataSource,
+    {
+        self.expanding_reader.seek_to_checkpoint(checkpoint)
+    }
+
+    /// If the next application value is a top-level blob or clob, returns a [`LobReader`] over
+    /// its body. Otherwise, returns `Ok(None)` and leaves the value to be read by
+    /// [`Self::next_value`]. See [`Reader::next_lob`](crate::Reader::next_lob).
+    pub fn next_lob(&mut self) -> IonResult<Option<LobReader<'_>>> {
+        self.expanding_reader.next_lob()
+    }
+
     /// Returns the next top-level stream item (IVM, symbol table, encoding directive, Value, or nothing)
     /// as an [`ExpandedStreamItem`].
     ///
@@ -253,7 +402,11 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
             Self::expect_symbol_text("encoding directive operation name", first_value)?;
 
         match step_name_text {
-            "module" => todo!("defining a new named module"),
+            "module" => {
+                return IonResult::decoding_error(
+                    "defining a new named module is not yet supported",
+                )
+            }
             "symbol_table" => {
                 let symbol_table = Self::process_symbol_table_definition(operation_sexp)?;
                 let new_encoding_module = match pending_changes.take_new_active_module() {
@@ -434,9 +587,7 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         let mut imports_field: Option<LazyField<Encoding>> = None;
         let mut symbols_field: Option<LazyField<Encoding>> = None;
 
-        let symbol_table = LazyStruct {
-            expanded_struct: symbol_table,
-        };
+        let symbol_table = LazyStruct::new(symbol_table);
 
         // Iterate through the fields of the symbol table struct, taking note of `imports` and `symbols`
         // if we encounter them.
@@ -546,6 +697,8 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                 pending_lst.is_lst_append = true;
             }
             ValueRef::List(list) => {
+                // The number of symbol IDs reserved so far for symbols with unknown text.
+                let mut num_unresolved_symbols: usize = 0;
                 for value in list.iter() {
                     let ValueRef::Struct(import) = value?.read()? else {
                         // If there's a value in the imports list that isn't a struct, it's malformed.
@@ -553,13 +706,16 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                         continue;
                     };
                     let name = match import.get("name")? {
+                        // Imports of the system symbol table are ignored; its symbols always
+                        // occupy the first symbol IDs.
+                        Some(ValueRef::String(s)) if s == "$ion" => continue,
                         // If `name` is missing, a non-string, or the empty string, ignore this import.
                         Some(ValueRef::String(s)) if !s.is_empty() => s,
                         _ => continue,
                     };
                     let version: usize = match import.get("version")? {
-                        Some(ValueRef::Int(i)) if i > Int::ZERO => usize::try_from(i)
-                            .map_err(|_|
+                        Some(ValueRef::Int(i)) if i > Int::ZERO => i.as_usize()
+                            .ok_or_else(||
                             IonError::decoding_error(format!("found a symbol table import (name='{name}') with a version number too high to support: {i}")),
                         ),
                         // If there's no version, a non-int version, or a version <= 0, we treat it
@@ -567,39 +723,59 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                         _ => Ok(1),
                     }?;
 
-                    let shared_table = match catalog.get_table_with_version(name.as_ref(), version) {
-                        Some(table) => table,
-                        None => return IonResult::decoding_error(
-                            format!("symbol table import failed, could not find table with name='{name}' and version={version}")
-                        ),
-                    };
-
                     let max_id = match import.get("max_id")? {
                         Some(ValueRef::Int(i)) if i >= Int::ZERO => {
-                            usize::try_from(i).map_err(|_| {
-                                IonError::decoding_error(
-                                    "found a `max_id` beyond the range of usize",
-                                )
-                            })?
+                            Some(i.as_usize().ok_or_else(|| {
+                                IonError::decoding_error(format!(
+                                    "found a symbol table import (name='{name}') with a `max_id` beyond the range of usize: {i}"
+                                ))
+                            })?)
                         }
-                        // If the max_id is unspecified, negative, or an invalid data type, we'll import all of the symbols from the requested table.
-                        _ => shared_table.symbols().len(),
+                        // A `max_id` that is missing, null, negative, or not an int is undefined.
+                        _ => None,
                     };
 
-                    let num_symbols_to_import = shared_table.symbols().len().min(max_id);
+                    let exact_match = catalog.get_table_with_version(name.as_ref(), version);
+                    let shared_table = match exact_match {
+                        Some(table) => Some(table),
+                        // Without an exact match, the import can only be resolved if its `max_id`
+                        // says how many symbol IDs it occupies. The latest version of the table
+                        // is used if the catalog has one; otherwise, the IDs are reserved for
+                        // symbols with unknown text.
+                        None if max_id.is_some() => catalog.get_table(name.as_ref()),
+                        None => {
+                            return IonResult::decoding_error(
+                                format!("symbol table import failed, could not find table with name='{name}' and version={version}, and the import did not specify a max_id")
+                            );
+                        }
+                    };
+                    let available_symbols =
+                        shared_table.map(|table| table.symbols()).unwrap_or(&[]);
+                    // If `max_id` is undefined, there is an exact match; all of its symbols are imported.
+                    let max_id = max_id.unwrap_or(available_symbols.len());
 
+                    // If the table has more than `max_id` symbols, the rest are not imported.
+                    let num_symbols_to_import = available_symbols.len().min(max_id);
                     pending_lst
                         .imported_symbols
-                        .extend_from_slice(&shared_table.symbols()[..num_symbols_to_import]);
-
-                    if max_id > shared_table.symbols().len() {
-                        let num_pending_symbols = pending_lst.imported_symbols().len();
-                        let num_placeholders = max_id - shared_table.symbols().len();
-                        pending_lst.imported_symbols.resize(
-                            num_pending_symbols + num_placeholders,
-                            Symbol::unknown_text(),
-                        );
-                    }
+                        .extend_from_slice(&available_symbols[..num_symbols_to_import]);
+
+                    // If the table has fewer than `max_id` symbols, the remaining IDs are reserved
+                    // for symbols with unknown text.
+                    let num_placeholders = max_id - num_symbols_to_import;
+                    num_unresolved_symbols = num_unresolved_symbols
+                        .checked_add(num_placeholders)
+                        .filter(|total| *total <= MAX_UNRESOLVED_IMPORTED_SYMBOLS)
+                        .ok_or_else(|| {
+                            IonError::decoding_error(format!(
+                                "symbol table import (name='{name}', version={version}) has a `max_id` of {max_id}, but only {num_symbols_to_import} of its symbols are available; at most {MAX_UNRESOLVED_IMPORTED_SYMBOLS} unavailable imported symbols are supported"
+                            ))
+                        })?;
+                    let num_pending_symbols = pending_lst.imported_symbols().len();
+                    pending_lst.imported_symbols.resize(
+                        num_pending_symbols + num_placeholders,
+                        Symbol::unknown_text(),
+                    );
                 }
             }
             _ => {
@@ -623,7 +799,8 @@ mod tests {
     use crate::lazy::decoder::RawVersionMarker;
     use crate::lazy::system_stream_item::SystemStreamItem;
     use crate::{
-        v1_0, AnyEncoding, Catalog, IonResult, SequenceWriter, SymbolRef, ValueWriter, Writer,
+        v1_0, AnyEncoding, Catalog, Element, ElementReader, IonResult, Reader, SequenceWriter,
+        SymbolRef, ValueWriter, Writer,
     };
 
     use super::*;
@@ -718,6 +895,7 @@ mod tests {
     use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
     use crate::lazy::encoder::value_writer::AnnotatableWriter;
     use crate::{MapCatalog, SharedSymbolTable};
+    use rstest::rstest;
 
     fn system_reader_for<I: IonInput>(ion: I) -> SystemReader<AnyEncoding, I> {
         SystemReader::new(AnyEncoding, ion)
@@ -894,15 +1072,116 @@ mod tests {
                     imports: [ { name:"shared_table_3", version: 1, max_id: 3 }, { name:"shared_table_2", version: 1 }, { name:"shared_table_4", version: 1, max_id: 1 } ],
                     symbols: [ "local_symbol" ]
                 }
+                $10 // == $0 (shared_table_3)
+                $12 // == $0 (shared_table_3)
                 $13 // "bar"
+                $14 // == $0 (shared_table_4)
+                $15 // "local_symbol"
             "#,
             map_catalog,
         );
-        // We step over the LST...
+        // The tables missing from the catalog occupy the number of symbol IDs given by their `max_id`,
+        // and those symbols have unknown text.
+        for expected in [
+            SymbolRef::with_unknown_text(),
+            SymbolRef::with_unknown_text(),
+            SymbolRef::with_text("bar"),
+            SymbolRef::with_unknown_text(),
+            SymbolRef::with_text("local_symbol"),
+        ] {
+            assert_eq!(
+                reader.expect_next_value()?.read()?.expect_symbol()?,
+                expected
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn non_existent_shared_symbol_table_import_without_max_id() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_symbol_table::{
+                    imports: [ { name:"shared_table", version: 1 } ],
+                    symbols: [ "local_symbol" ]
+                }
+                $10
+            "#,
+            MapCatalog::new(),
+        );
+        // Without a `max_id`, there's no way to know how many symbol IDs the import occupies.
+        let error = reader.next_item().map(|_| ()).unwrap_err();
+        assert!(matches!(error, IonError::Decoding(_)), "{error:?}");
         assert!(
-            matches!(reader.next_item(), Err(IonError::Decoding(_))),
-            "expected a decoding error because shared_table_3 does not exist"
+            error.to_string().contains("did not specify a max_id"),
+            "{error}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn import_with_huge_max_id_is_an_error() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+                $ion_symbol_table::{
+                    imports: [ { name:"missing", version: 1, max_id: 4000000000000 } ]
+                }
+                $10
+            "#,
+            MapCatalog::new(),
         );
+        // Reserving a symbol ID for each of the missing table's symbols would exhaust memory.
+        let error = reader.next_item().map(|_| ()).unwrap_err();
+        assert!(matches!(error, IonError::Decoding(_)), "{error:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn field_names_from_shared_symbol_table_binary() -> IonResult<()> {
+        // A binary stream whose local symbol table imports two symbols from `shared_table`
+        // and then uses them as field names.
+        let binary_ion: &[u8] = &[
+            0xe0, 0x01, 0x00, 0xea, // Ion 1.0 Version Marker
+            0xee, 0x9d, 0x81, 0x83, // '$ion_symbol_table'::
+            0xde, 0x99, // 26 bytes struct
+            0x86, // Field $6 `imports`
+            0xbe, 0x96, // List
+            0xde, 0x94, // 21 bytes struct
+            0x84, // Field $4 `name`
+            0x8c, 0x73, 0x68, 0x61, 0x72, 0x65, 0x64, 0x5f, 0x74, 0x61, 0x62, 0x6c,
+            0x65, // "shared_table"
+            0x85, // Field $5 `version`
+            0x21, 0x01, // INT 1
+            0x88, // $8 `max_id`
+            0x21, 0x02, // INT 2
+            0xd5, // 5 bytes struct
+            0x8a, 0x21, 0x01, // Field $10: 1
+            0x8b, // Field $11
+            0x20, // INT 0 (length 0)
+        ];
+
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new(
+            "shared_table",
+            1,
+            ["width", "height"],
+        )?);
+        let mut reader = system_reader_with_catalog_for(binary_ion, map_catalog);
+        let strukt = reader.expect_next_value()?.read()?.expect_struct()?;
+        let names = strukt
+            .iter()
+            .map(|field| Ok(field?.name()?.text().map(str::to_owned)))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(names, [Some("width".to_owned()), Some("height".to_owned())]);
+
+        // Without a matching catalog entry, the field names have unknown text.
+        let mut reader = system_reader_with_catalog_for(binary_ion, MapCatalog::new());
+        let strukt = reader.expect_next_value()?.read()?.expect_struct()?;
+        let names = strukt
+            .iter()
+            .map(|field| Ok(field?.name()?.text().map(str::to_owned)))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(names, [None, None]);
         Ok(())
     }
 
@@ -973,6 +1252,89 @@ mod tests {
         Ok(())
     }
 
+    /// Reads a local symbol table with a single `import` and the local symbol "local", returning
+    /// the text of each symbol following the system symbols. The catalog contains versions 1 and 2
+    /// of a table named "shared".
+    fn symbols_after_import(import: &str) -> IonResult<Vec<Option<String>>> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(SharedSymbolTable::new("shared", 1, ["foo", "bar"])?);
+        catalog.insert_table(SharedSymbolTable::new("shared", 2, ["foo", "bar", "baz"])?);
+        let ion = format!("$ion_symbol_table::{{imports: [{import}], symbols: [\"local\"]}} 0");
+        let mut reader = system_reader_with_catalog_for(ion.as_str(), catalog);
+        reader.expect_next_value()?;
+        Ok(reader.symbol_table().to_exact_seed())
+    }
+
+    /// Confirms that `import` adds the `expected_imports` to the symbol table, followed by the
+    /// local symbol "local".
+    fn assert_imported_symbols(import: &str, expected_imports: &[Option<&str>]) -> IonResult<()> {
+        let mut expected: Vec<Option<String>> = expected_imports
+            .iter()
+            .map(|text| text.map(String::from))
+            .collect();
+        expected.push(Some("local".to_owned()));
+        assert_eq!(symbols_after_import(import)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::exact_match("version: 1", &[Some("foo"), Some("bar")])]
+    #[case::missing_version("", &[Some("foo"), Some("bar")])]
+    #[case::non_positive_version("version: 0", &[Some("foo"), Some("bar")])]
+    #[case::max_id_zero("version: 1, max_id: 0", &[])]
+    #[case::max_id_equal("version: 1, max_id: 2", &[Some("foo"), Some("bar")])]
+    #[case::max_id_truncates("version: 1, max_id: 1", &[Some("foo")])]
+    #[case::max_id_pads("version: 1, max_id: 4", &[Some("foo"), Some("bar"), None, None])]
+    #[case::max_id_negative("version: 1, max_id: -1", &[Some("foo"), Some("bar")])]
+    #[case::max_id_null("version: 1, max_id: null.int", &[Some("foo"), Some("bar")])]
+    #[case::max_id_not_an_int("version: 1, max_id: 2e0", &[Some("foo"), Some("bar")])]
+    #[case::inexact_match_uses_latest("version: 3, max_id: 2", &[Some("foo"), Some("bar")])]
+    #[case::inexact_match_pads("version: 3, max_id: 4", &[Some("foo"), Some("bar"), Some("baz"), None])]
+    fn import_max_id(
+        #[case] import_fields: &str,
+        #[case] expected_imports: &[Option<&str>],
+    ) -> IonResult<()> {
+        assert_imported_symbols(
+            &format!("{{name: \"shared\", {import_fields}}}"),
+            expected_imports,
+        )
+    }
+
+    #[rstest]
+    #[case::missing_table_with_max_id("{name: \"missing\", max_id: 2}", &[None, None])]
+    #[case::missing_table_max_id_zero("{name: \"missing\", max_id: 0}", &[])]
+    #[case::system_table("{name: \"$ion\", version: 1, max_id: 9}", &[])]
+    #[case::empty_name("{name: \"\", max_id: 3}", &[])]
+    #[case::non_string_name("{name: shared, max_id: 3}", &[])]
+    #[case::not_a_struct("\"shared\"", &[])]
+    fn ignored_or_unresolved_imports(
+        #[case] import: &str,
+        #[case] expected_imports: &[Option<&str>],
+    ) -> IonResult<()> {
+        assert_imported_symbols(import, expected_imports)
+    }
+
+    #[rstest]
+    // Without an exact match in the catalog, the import must have a valid `max_id`.
+    #[case::missing_table_no_max_id("{name: \"missing\", version: 1}")]
+    #[case::missing_table_negative_max_id("{name: \"missing\", version: 1, max_id: -1}")]
+    #[case::missing_table_null_max_id("{name: \"missing\", version: 1, max_id: null}")]
+    #[case::missing_table_non_int_max_id("{name: \"missing\", version: 1, max_id: \"2\"}")]
+    #[case::inexact_match_no_max_id("{name: \"shared\", version: 3}")]
+    #[case::max_id_too_large("{name: \"shared\", version: 1, max_id: 100000000000000000000000}")]
+    // Imports may not reserve an unbounded number of symbol IDs for symbols with unknown text.
+    #[case::missing_table_huge_max_id("{name: \"missing\", version: 1, max_id: 4000000000000}")]
+    #[case::missing_table_usize_max_id("{name: \"missing\", max_id: 18446744073709551615}")]
+    #[case::inexact_match_huge_max_id("{name: \"shared\", version: 3, max_id: 4000000000000}")]
+    #[case::missing_tables_exceed_limit("{name: \"a\", max_id: 1048576}, {name: \"b\", max_id: 1}")]
+    fn invalid_import_max_id(#[case] import: &str) {
+        let result = symbols_after_import(import);
+        assert!(
+            matches!(result, Err(IonError::Decoding(_))),
+            "expected a decoding error for {import}, found {result:?}"
+        );
+    }
+
     #[test]
     fn detect_encoding_directive_text() -> IonResult<()> {
         let text = r#"
@@ -1109,4 +1471,63 @@ mod tests {
         assert_eq!(reader.expect_next_value()?.read()?.expect_i64()?, 12);
         Ok(())
     }
+
+    #[test]
+    fn resolve_symbol_ids_defined_by_encoding_directive_text() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((symbol_table ["foo", "bar", "baz"]))
+            $10
+            $12::{$11: $10}
+        "#;
+        let mut reader = SystemReader::new(AnyEncoding, ion);
+        let _ivm = reader.next_item()?.expect_ivm()?;
+        let _directive = reader.next_item()?.expect_encoding_directive()?;
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "foo");
+        let value = reader.expect_next_value()?;
+        assert!(value.annotations().are(["baz"])?);
+        let field = value.read()?.expect_struct()?.find_expected("bar")?;
+        assert_eq!(field.read()?.expect_symbol()?, "foo");
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_symbol_ids_defined_by_encoding_directive_binary() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_1::new(Vec::new())?;
+        let mut directive = writer
+            .value_writer()
+            .with_annotations("$ion_encoding")?
+            .sexp_writer()?;
+        let mut symbol_table = directive.sexp_writer()?;
+        symbol_table.write_symbol("symbol_table")?;
+        symbol_table.write_list(["foo", "bar", "baz"])?;
+        symbol_table.close()?;
+        directive.close()?;
+        writer.write_symbol(10usize)?;
+        writer.write_symbol(12usize)?;
+        let binary_ion = writer.close()?;
+
+        let mut reader = SystemReader::new(AnyEncoding, binary_ion);
+        let _ivm = reader.next_item()?.expect_ivm()?;
+        let _directive = reader.next_item()?.expect_encoding_directive()?;
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "foo");
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "baz");
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_directives_are_not_user_values() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((symbol_table ["foo", "bar"]))
+            $10
+            $ion_encoding::((symbol_table ["baz"]))
+            [$10]
+        "#;
+        let mut reader = Reader::new(AnyEncoding, ion)?;
+        // The second directive replaces the symbols defined by the first.
+        let expected = Element::read_all("foo [baz]")?;
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use crate::lazy::text::buffer::TextBufferView;
 use crate::position::Position;
-use crate::result::DecodingError;
+use crate::result::{DecodingError, DecodingErrorKind};
 use crate::{IonError, IonResult};
 use smallvec::SmallVec;
 
@@ -32,8 +32,9 @@ impl<'data> AsUtf8 for TextBufferView<'data> {
 
 fn bytes_as_utf8(bytes: &[u8], position: impl Into<Position>) -> IonResult<&str> {
     std::str::from_utf8(bytes).map_err(|_| {
-        let decoding_error =
-            DecodingError::new("encountered invalid UTF-8").with_position(position);
+        let decoding_error = DecodingError::new("encountered invalid UTF-8")
+            .with_kind(DecodingErrorKind::InvalidUtf8)
+            .with_position(position);
         IonError::Decoding(decoding_error)
     })
 }
@@ -44,12 +44,11 @@ use crate::lazy::text::raw::v1_1::reader::{
 use crate::lazy::text::value::{
     LazyRawTextValue, LazyRawTextValue_1_0, LazyRawTextValue_1_1, LazyRawTextVersionMarker,
 };
-use crate::result::DecodingError;
+use crate::result::{DecodingError, DecodingErrorKind};
 use crate::{Encoding, HasRange, IonError, IonResult, IonType, RawSymbolRef, TimestampPrecision};
 
 use crate::lazy::expanded::macro_table::Macro;
 use crate::lazy::expanded::template::{Parameter, RestSyntaxPolicy};
-use crate::lazy::text::as_utf8::AsUtf8;
 use bumpalo::collections::Vec as BumpVec;
 
 impl<'a> Debug for TextBufferView<'a> {
@@ -111,6 +110,9 @@ pub struct TextBufferView<'top> {
     //                          offset: 6
     data: &'top [u8],
     offset: usize,
+    // The number of containers that enclose the values in this buffer. Top-level values have a
+    // depth of zero.
+    depth: usize,
     pub(crate) context: EncodingContextRef<'top>,
 }
 
@@ -140,6 +142,7 @@ impl<'top> TextBufferView<'top> {
             context,
             data,
             offset,
+            depth: 0,
         }
     }
 
@@ -163,6 +166,7 @@ impl<'top> TextBufferView<'top> {
         TextBufferView {
             data: &self.data[offset..offset + length],
             offset: self.offset + offset,
+            depth: self.depth,
             context: self.context,
         }
     }
@@ -176,10 +180,35 @@ impl<'top> TextBufferView<'top> {
         TextBufferView {
             data: &self.data[offset..],
             offset: self.offset + offset,
+            depth: self.depth,
             context: self.context,
         }
     }
 
+    /// Returns the body of the container whose opening delimiter is at the head of this buffer.
+    /// If the buffer does not begin with `opening_delimiter`, returns a recoverable error. If the
+    /// values in the body would be nested more deeply than the reader's configured maximum depth
+    /// allows, returns a fatal error instead.
+    fn container_body(
+        &self,
+        opening_delimiter: u8,
+    ) -> Result<TextBufferView<'top>, nom::Err<IonParseError<'top>>> {
+        if self.bytes().first() != Some(&opening_delimiter) {
+            let error = InvalidInputError::new(*self);
+            return Err(nom::Err::Error(IonParseError::Invalid(error)));
+        }
+        let max_depth = self.context.read_options.max_depth;
+        if self.depth >= max_depth {
+            let error = InvalidInputError::new(*self).with_description(format!(
+                "found a container that exceeds the maximum nesting depth of {max_depth}"
+            ));
+            return Err(nom::Err::Failure(IonParseError::Invalid(error)));
+        }
+        let mut body = self.slice_to_end(1);
+        body.depth += 1;
+        Ok(body)
+    }
+
     /// Returns a slice containing all of the buffer's bytes.
     pub fn bytes(&self) -> &'top [u8] {
         self.data
@@ -213,8 +242,9 @@ impl<'top> TextBufferView<'top> {
         // that the string that's returned lives as long as the data itself, not just the duration
         // of the lifetime introduced by this method call.
         std::str::from_utf8(self.data).map_err(move |_| {
-            let decoding_error =
-                DecodingError::new("encountered invalid UTF-8").with_position(self.offset());
+            let decoding_error = DecodingError::new("encountered invalid UTF-8")
+                .with_kind(DecodingErrorKind::InvalidUtf8)
+                .with_position(self.offset());
             IonError::Decoding(decoding_error)
         })
     }
@@ -254,6 +284,11 @@ impl<'top> TextBufferView<'top> {
     ///        line */
     /// comment
     pub fn match_comment(self) -> IonMatchResult<'top> {
+        // A lone `/` at the end of the buffer may be the beginning of a comment whose second
+        // character has not been read yet.
+        if self.bytes() == b"/" {
+            return Err(nom::Err::Incomplete(Needed::Unknown));
+        }
         alt((
             Self::match_rest_of_line_comment,
             Self::match_multiline_comment,
@@ -327,7 +362,9 @@ impl<'top> TextBufferView<'top> {
             let error = InvalidInputError::new(matched)
                 .with_description("the maximum supported annotations sequence length is 65KB")
                 .with_label("parsing annotations");
-            Err(nom::Err::Error(IonParseError::Invalid(error)))
+            // This is a `Failure` rather than an `Error` so that callers matching an optional
+            // annotations sequence do not quietly discard the annotations and continue.
+            Err(nom::Err::Failure(IonParseError::Invalid(error)))
         } else {
             Ok((remaining, matched))
         }
@@ -355,20 +392,25 @@ impl<'top> TextBufferView<'top> {
 
     /// Matches an optional annotations sequence and a value, including operators.
     pub fn match_sexp_value(self) -> IonParseResult<'top, Option<LazyRawTextValue_1_0<'top>>> {
-        whitespace_and_then(alt((
-            value(None, tag(")")),
-            pair(
-                opt(Self::match_annotations),
-                // We need the s-expression parser to recognize the input `--3` as the operator `--` and the
-                // int `3` while recognizing the input `-3` as the int `-3`. If `match_operator` runs before
-                // `match_value`, it will consume the sign (`-`) of negative number values, treating
-                // `-3` as an operator (`-`) and an int (`3`). Thus, we run `match_value` first.
-                whitespace_and_then(alt((Self::match_value, Self::match_operator))),
-            )
-            .map(|(maybe_annotations, value)| self.apply_annotations(maybe_annotations, value))
-            .map(Some),
-        )))
-        .parse(self)
+        let (input, _ws) = self.match_optional_comments_and_whitespace()?;
+        match input.bytes().first() {
+            None => return Err(nom::Err::Incomplete(Needed::new(1))),
+            Some(b')') => return Ok((input.slice_to_end(1), None)),
+            _ => {}
+        }
+        let (input_after_annotations, maybe_annotations) = input.match_optional_annotations()?;
+        // We need the s-expression parser to recognize the input `--3` as the operator `--` and the
+        // int `3` while recognizing the input `-3` as the int `-3`. If `match_operator` runs before
+        // `match_value`, it will consume the sign (`-`) of negative number values, treating
+        // `-3` as an operator (`-`) and an int (`3`). Thus, we run `match_value` first.
+        let (remaining, value) = match input_after_annotations.match_value() {
+            Err(nom::Err::Error(_)) => input_after_annotations.match_operator()?,
+            result => result?,
+        };
+        Ok((
+            remaining,
+            Some(input.apply_annotations(maybe_annotations, value)),
+        ))
     }
 
     /// Matches either:
@@ -377,22 +419,30 @@ impl<'top> TextBufferView<'top> {
     pub fn match_sexp_value_1_1(
         self,
     ) -> IonParseResult<'top, Option<LazyRawValueExpr<'top, TextEncoding_1_1>>> {
-        whitespace_and_then(alt((
-            Self::match_e_expression.map(|matched| Some(RawValueExpr::EExp(matched))),
-            value(None, peek(tag(")"))),
-            pair(
-                opt(Self::match_annotations),
-                // We need the s-expression parser to recognize the input `--3` as the operator `--` and the
-                // int `3` while recognizing the input `-3` as the int `-3`. If `match_operator` runs before
-                // `match_value`, it will consume the sign (`-`) of negative number values, treating
-                // `-3` as an operator (`-`) and an int (`3`). Thus, we run `match_value` first.
-                whitespace_and_then(alt((Self::match_value_1_1, Self::match_operator))),
-            )
-            .map(|(maybe_annotations, value)| self.apply_annotations(maybe_annotations, value))
-            .map(RawValueExpr::ValueLiteral)
-            .map(Some),
-        )))
-        .parse(self)
+        let (input, _ws) = self.match_optional_comments_and_whitespace()?;
+        match input.match_e_expression() {
+            Err(nom::Err::Error(_)) => {}
+            result => {
+                let (remaining, eexp) = result?;
+                return Ok((remaining, Some(RawValueExpr::EExp(eexp))));
+            }
+        }
+        match input.bytes().first() {
+            None => return Err(nom::Err::Incomplete(Needed::new(1))),
+            Some(b')') => return Ok((input, None)),
+            _ => {}
+        }
+        let (input_after_annotations, maybe_annotations) = input.match_optional_annotations()?;
+        // We need the s-expression parser to recognize the input `--3` as the operator `--` and the
+        // int `3` while recognizing the input `-3` as the int `-3`. If `match_operator` runs before
+        // `match_value`, it will consume the sign (`-`) of negative number values, treating
+        // `-3` as an operator (`-`) and an int (`3`). Thus, we run `match_value` first.
+        let (remaining, value) = match input_after_annotations.match_value_1_1() {
+            Err(nom::Err::Error(_)) => input_after_annotations.match_operator()?,
+            result => result?,
+        };
+        let value = input.apply_annotations(maybe_annotations, value);
+        Ok((remaining, Some(RawValueExpr::ValueLiteral(value))))
     }
 
     fn apply_annotations<E: TextEncoding<'top>>(
@@ -424,43 +474,65 @@ impl<'top> TextBufferView<'top> {
     pub fn match_struct_field(
         self,
     ) -> IonParseResult<'top, Option<LazyRawFieldExpr<'top, TextEncoding_1_0>>> {
+        let (input, maybe_field_name) = self.match_struct_field_name_or_end()?;
+        // If the next thing in the input is a `}`, return `None`.
+        let Some(matched_field_name) = maybe_field_name else {
+            return Ok((input, None));
+        };
+        // Otherwise, match the value and turn the pair into a `LazyRawTextField`.
+        let (input, value) = input.match_annotated_value()?;
+        let (remaining, _delimiter) = input.match_delimiter_after_struct_field()?;
+        let field_name = LazyRawTextFieldName_1_0::new(matched_field_name);
+        Ok((
+            remaining,
+            Some(LazyRawFieldExpr::NameValue(field_name, value)),
+        ))
+    }
+
+    /// Matches either the end of a struct or a field name and the `:` that follows it, allowing
+    /// for leading whitespace and comments in either case.
+    ///
+    /// If the end of the struct is found, returns `Ok(None)`. The closing `}` is not consumed.
+    fn match_struct_field_name_or_end(
+        self,
+    ) -> IonParseResult<'top, Option<MatchedFieldName<'top>>> {
         // A struct field can have leading whitespace, but we want the buffer slice that we match
         // to begin with the field name. Here we skip any whitespace so we have another named
         // slice (`input_including_field_name`) with that property.
         let (input_including_field_name, _ws) = self.match_optional_comments_and_whitespace()?;
-        alt((
-            // If the next thing in the input is a `}`, return `None`.
-            value(None, Self::match_struct_end),
-            // Otherwise, match a name/value pair and turn it into a `LazyRawTextField`.
-            Self::match_struct_field_name_and_value.map(move |(matched_field_name, value)| {
-                let field_name = LazyRawTextFieldName_1_0::new(matched_field_name);
-                Some(LazyRawFieldExpr::<'top, TextEncoding_1_0>::NameValue(
-                    field_name, value,
-                ))
-            }),
-        ))(input_including_field_name)
+        if input_including_field_name.match_struct_end()? {
+            return Ok((input_including_field_name, None));
+        }
+        let (input, field_name) = input_including_field_name.match_struct_field_name_and_colon()?;
+        Ok((input, Some(field_name)))
     }
 
-    /// Matches any amount of whitespace followed by a closing `}`.
-    fn match_struct_end(self) -> IonMatchResult<'top> {
-        whitespace_and_then(peek(tag("}"))).parse(self)
+    /// Returns `true` if the buffer begins with a closing `}`. The `}` is not consumed.
+    fn match_struct_end(self) -> Result<bool, nom::Err<IonParseError<'top>>> {
+        match self.bytes().first() {
+            None => Err(nom::Err::Incomplete(Needed::new(1))),
+            Some(b'}') => Ok(true),
+            _ => Ok(false),
+        }
     }
 
-    /// Matches a field name/value pair. Returns the syntax used for the field name, the range of
-    /// input bytes where the field name is found, and the value.
-    pub fn match_struct_field_name_and_value(
-        self,
-    ) -> IonParseResult<'top, (MatchedFieldName<'top>, LazyRawTextValue_1_0<'top>)> {
+    /// Matches a field name, the `:` that follows it, and any whitespace after that.
+    fn match_struct_field_name_and_colon(self) -> IonParseResult<'top, MatchedFieldName<'top>> {
         terminated(
-            separated_pair(
-                whitespace_and_then(Self::match_struct_field_name),
+            whitespace_and_then(Self::match_struct_field_name),
+            pair(
                 whitespace_and_then(tag(":")),
-                whitespace_and_then(Self::match_annotated_value),
+                Self::match_optional_comments_and_whitespace,
             ),
-            whitespace_and_then(alt((tag(","), peek(tag("}"))))),
         )(self)
     }
 
+    /// Matches syntax that is expected to follow a struct field: any amount of whitespace and/or
+    /// comments followed by either a comma (consumed) or an end-of-struct `}` (not consumed).
+    fn match_delimiter_after_struct_field(self) -> IonMatchResult<'top> {
+        whitespace_and_then(alt((tag(","), peek(tag("}"))))).parse(self)
+    }
+
     /// Matches a struct field (name, value expression) pair.
     ///
     /// If a pair is found, returns `Some(field)` and consumes the following comma if present.
@@ -468,30 +540,54 @@ impl<'top> TextBufferView<'top> {
     pub fn match_struct_field_1_1(
         self,
     ) -> IonParseResult<'top, Option<LazyRawFieldExpr<'top, TextEncoding_1_1>>> {
-        // A struct field can have leading whitespace, but we want the buffer slice that we match
-        // to begin with the field name. Here we skip any whitespace so we have another named
-        // slice (`input_including_field_name`) with that property.
-        let (input_including_field_name, _ws) = self.match_optional_comments_and_whitespace()?;
-        let (input_after_field, field_expr_result) = alt((
-            // If the next thing in the input is a `}`, return `None`.
-            Self::match_struct_end.map(|_| Ok(None)),
+        // If it's an e-expression or a field whose value is an e-expression, return that.
+        match self.match_struct_field_e_expression_1_1() {
+            Ok(Some((remaining, field_expr))) => return Ok((remaining, Some(field_expr))),
+            Ok(None) => {}
+            Err(e) => return Err(e),
+        }
+        let (input, maybe_field_name) = self.match_struct_field_name_or_end()?;
+        // If the next thing in the input is a `}`, return `None`.
+        let Some(matched_field_name) = maybe_field_name else {
+            return Ok((input, None));
+        };
+        // Otherwise, match the value and turn the pair into a `LazyRawTextField`.
+        let (input, value) = input.match_annotated_value_1_1()?;
+        let (remaining, _delimiter) = input.match_delimiter_after_struct_field()?;
+        let field_name = LazyRawTextFieldName_1_1::new(matched_field_name);
+        Ok((
+            remaining,
+            Some(LazyRawFieldExpr::NameValue(field_name, value)),
+        ))
+    }
+
+    /// Matches either an e-expression in field position or a field name whose value is an
+    /// e-expression, allowing for leading whitespace and comments. If the input is neither,
+    /// returns `Ok(None)`.
+    fn match_struct_field_e_expression_1_1(
+        self,
+    ) -> Result<
+        Option<(
+            TextBufferView<'top>,
+            LazyRawFieldExpr<'top, TextEncoding_1_1>,
+        )>,
+        nom::Err<IonParseError<'top>>,
+    > {
+        let (input, _ws) = self.match_optional_comments_and_whitespace()?;
+        let result = alt((
             terminated(
-                Self::match_e_expression.map(|eexp| Ok(Some(LazyRawFieldExpr::EExp(eexp)))),
-                whitespace_and_then(alt((tag(","), peek(tag("}"))))),
+                Self::match_e_expression.map(LazyRawFieldExpr::EExp),
+                Self::match_delimiter_after_struct_field,
             ),
             Self::match_struct_field_name_and_e_expression_1_1.map(|(field_name, invocation)| {
-                Ok(Some(LazyRawFieldExpr::NameEExp(
-                    LazyRawTextFieldName_1_1::new(field_name),
-                    invocation,
-                )))
-            }),
-            // Otherwise, match a name/value pair and turn it into a `LazyRawTextField`.
-            Self::match_struct_field_name_and_value_1_1.map(move |(field_name, value)| {
-                let field_name = LazyRawTextFieldName_1_1::new(field_name);
-                Ok(Some(LazyRawFieldExpr::NameValue(field_name, value)))
+                LazyRawFieldExpr::NameEExp(LazyRawTextFieldName_1_1::new(field_name), invocation)
             }),
-        ))(input_including_field_name)?;
-        Ok((input_after_field, field_expr_result?))
+        ))(input);
+        match result {
+            Ok((remaining, field_expr)) => Ok(Some((remaining, field_expr))),
+            Err(nom::Err::Error(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
     }
 
     /// Matches a field (name, value expression) pair, where the value expression may be either
@@ -501,49 +597,36 @@ impl<'top> TextBufferView<'top> {
         self,
     ) -> IonParseResult<'top, (MatchedFieldName<'top>, TextEExpression_1_1<'top>)> {
         terminated(
-            separated_pair(
-                whitespace_and_then(Self::match_struct_field_name),
-                whitespace_and_then(tag(":")),
-                whitespace_and_then(Self::match_e_expression),
-            ),
-            whitespace_and_then(alt((tag(","), peek(tag("}"))))),
-        )(self)
-    }
-
-    /// Matches a field (name, value expression) pair, where the value expression may be either
-    /// an annotated value or an e-expression. Returns the syntax used for the field name, the
-    /// range of input bytes where the field name is found, and the value.
-    pub fn match_struct_field_name_and_value_1_1(
-        self,
-    ) -> IonParseResult<'top, (MatchedFieldName<'top>, LazyRawTextValue_1_1<'top>)> {
-        terminated(
-            separated_pair(
-                whitespace_and_then(Self::match_struct_field_name),
-                whitespace_and_then(tag(":")),
-                whitespace_and_then(Self::match_annotated_value_1_1),
+            pair(
+                Self::match_struct_field_name_and_colon,
+                Self::match_e_expression,
             ),
-            whitespace_and_then(alt((tag(","), peek(tag("}"))))),
+            Self::match_delimiter_after_struct_field,
         )(self)
     }
 
     /// Matches an optional annotation sequence and a trailing value.
     pub fn match_annotated_value(self) -> IonParseResult<'top, LazyRawTextValue_1_0<'top>> {
+        let (input, maybe_annotations) = self.match_optional_annotations()?;
+        let (remaining, value) = input.match_value()?;
+        Ok((remaining, self.apply_annotations(maybe_annotations, value)))
+    }
+
+    /// Matches an optional annotations sequence and any whitespace that follows it.
+    fn match_optional_annotations(self) -> IonParseResult<'top, Option<TextBufferView<'top>>> {
         pair(
             opt(Self::match_annotations),
-            whitespace_and_then(Self::match_value),
+            Self::match_optional_comments_and_whitespace,
         )
-        .map(|(maybe_annotations, value)| self.apply_annotations(maybe_annotations, value))
+        .map(|(maybe_annotations, _ws)| maybe_annotations)
         .parse(self)
     }
 
     /// Matches an optional annotation sequence and a trailing v1.1 value.
     pub fn match_annotated_value_1_1(self) -> IonParseResult<'top, LazyRawTextValue_1_1<'top>> {
-        pair(
-            opt(Self::match_annotations),
-            whitespace_and_then(Self::match_value_1_1),
-        )
-        .map(|(maybe_annotations, value)| self.apply_annotations(maybe_annotations, value))
-        .parse(self)
+        let (input, maybe_annotations) = self.match_optional_annotations()?;
+        let (remaining, value) = input.match_value_1_1()?;
+        Ok((remaining, self.apply_annotations(maybe_annotations, value)))
     }
 
     /// Matches a struct field name. That is:
@@ -614,6 +697,29 @@ impl<'top> TextBufferView<'top> {
 
     /// Matches a single scalar value or the beginning of a container.
     pub fn match_value(self) -> IonParseResult<'top, LazyRawTextValue_1_0<'top>> {
+        // TODO: Cache child expressions found in 1.0 containers
+        let matched_value = match self.container_kind() {
+            Some(IonType::List) => self
+                .match_list()
+                .map(|(remaining, _matched)| (remaining, MatchedValue::List(&[]))),
+            Some(IonType::SExp) => self
+                .match_sexp()
+                .map(|(remaining, _matched)| (remaining, MatchedValue::SExp(&[]))),
+            Some(_struct) => self
+                .match_struct()
+                .map(|(remaining, _matched)| (remaining, MatchedValue::Struct(&[]))),
+            None => return self.match_scalar_value(),
+        };
+        let (remaining, matched_value) = matched_value?;
+        let value = LazyRawTextValue_1_0 {
+            encoded_value: EncodedTextValue::new(matched_value),
+            input: self.slice(0, remaining.offset() - self.offset()),
+        };
+        Ok((remaining, value))
+    }
+
+    /// Matches a single scalar value.
+    fn match_scalar_value(self) -> IonParseResult<'top, LazyRawTextValue_1_0<'top>> {
         consumed(alt((
             // For `null` and `bool`, we use `read_` instead of `match_` because there's no additional
             // parsing to be done.
@@ -649,24 +755,6 @@ impl<'top> TextBufferView<'top> {
             map(Self::match_clob, |matched_clob| {
                 EncodedTextValue::new(MatchedValue::Clob(matched_clob))
             }),
-            map(Self::match_list, |_matched_list| {
-                // TODO: Cache child expressions found in 1.0 list
-                let not_yet_used_in_1_0 =
-                    bumpalo::collections::Vec::new_in(self.context.allocator()).into_bump_slice();
-                EncodedTextValue::new(MatchedValue::List(not_yet_used_in_1_0))
-            }),
-            map(Self::match_sexp, |_matched_sexp| {
-                // TODO: Cache child expressions found in 1.0 sexp
-                let not_yet_used_in_1_0 =
-                    bumpalo::collections::Vec::new_in(self.context.allocator()).into_bump_slice();
-                EncodedTextValue::new(MatchedValue::SExp(not_yet_used_in_1_0))
-            }),
-            map(Self::match_struct, |_matched_struct| {
-                // TODO: Cache child expressions found in 1.0 struct
-                let not_yet_used_in_1_0 =
-                    bumpalo::collections::Vec::new_in(self.context.allocator()).into_bump_slice();
-                EncodedTextValue::new(MatchedValue::Struct(not_yet_used_in_1_0))
-            }),
         )))
         .map(|(input, encoded_value)| LazyRawTextValue_1_0 {
             encoded_value,
@@ -676,6 +764,28 @@ impl<'top> TextBufferView<'top> {
     }
 
     pub fn match_value_1_1(self) -> IonParseResult<'top, LazyRawTextValue_1_1<'top>> {
+        let matched_value = match self.container_kind() {
+            Some(IonType::List) => self
+                .match_list_1_1()
+                .map(|(remaining, (_matched, cache))| (remaining, MatchedValue::List(cache))),
+            Some(IonType::SExp) => self
+                .match_sexp_1_1()
+                .map(|(remaining, (_matched, cache))| (remaining, MatchedValue::SExp(cache))),
+            Some(_struct) => self
+                .match_struct_1_1()
+                .map(|(remaining, (_matched, cache))| (remaining, MatchedValue::Struct(cache))),
+            None => return self.match_scalar_value_1_1(),
+        };
+        let (remaining, matched_value) = matched_value?;
+        let value = LazyRawTextValue_1_1 {
+            encoded_value: EncodedTextValue::new(matched_value),
+            input: self.slice(0, remaining.offset() - self.offset()),
+        };
+        Ok((remaining, value))
+    }
+
+    /// Matches a single v1.1 scalar value.
+    fn match_scalar_value_1_1(self) -> IonParseResult<'top, LazyRawTextValue_1_1<'top>> {
         consumed(alt((
             // For `null` and `bool`, we use `read_` instead of `match_` because there's no additional
             // parsing to be done.
@@ -711,18 +821,6 @@ impl<'top> TextBufferView<'top> {
             map(Self::match_clob, |matched_clob| {
                 EncodedTextValue::new(MatchedValue::Clob(matched_clob))
             }),
-            map(Self::match_list_1_1, |(_matched_list, child_expr_cache)| {
-                EncodedTextValue::new(MatchedValue::List(child_expr_cache))
-            }),
-            map(Self::match_sexp_1_1, |(_matched_sexp, child_expr_cache)| {
-                EncodedTextValue::new(MatchedValue::SExp(child_expr_cache))
-            }),
-            map(
-                Self::match_struct_1_1,
-                |(_matched_struct, field_expr_cache)| {
-                    EncodedTextValue::new(MatchedValue::Struct(field_expr_cache))
-                },
-            ),
         )))
         .map(|(input, encoded_value)| LazyRawTextValue_1_1 {
             encoded_value,
@@ -731,45 +829,33 @@ impl<'top> TextBufferView<'top> {
         .parse(self)
     }
 
+    /// If the buffer begins with the opening delimiter of a list, s-expression, or struct, returns
+    /// the corresponding container type. Otherwise, returns `None`.
+    fn container_kind(&self) -> Option<IonType> {
+        match self.bytes() {
+            [b'[', ..] => Some(IonType::List),
+            [b'(', ..] => Some(IonType::SExp),
+            // `{{` begins a blob or clob.
+            [b'{', b'{', ..] => None,
+            [b'{', ..] => Some(IonType::Struct),
+            _ => None,
+        }
+    }
+
     /// Matches a list.
     ///
     /// If the input does not contain the entire list, returns `IonError::Incomplete(_)`.
     pub fn match_list(self) -> IonMatchResult<'top> {
-        // If it doesn't start with [, it isn't a list.
-        if self.bytes().first() != Some(&b'[') {
-            let error = InvalidInputError::new(self);
-            return Err(nom::Err::Error(IonParseError::Invalid(error)));
-        }
         // Scan ahead to find the end of this list.
-        let list_body = self.slice_to_end(1);
-        let sequence_iter = RawTextListIterator_1_0::new(list_body);
-        let span = match sequence_iter.find_span() {
-            Ok(span) => span,
-            // If the complete container isn't available, return an incomplete.
-            Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
-            // If invalid syntax was encountered, return a failure to prevent nom from trying
-            // other parser kinds.
-            Err(e) => {
-                return {
-                    let error = InvalidInputError::new(self)
-                        .with_label("matching a list")
-                        .with_description(format!("{}", e));
-                    Err(nom::Err::Failure(IonParseError::Invalid(error)))
-                }
-            }
-        };
-
-        // For the matched span, we use `self` again to include the opening `[`
-        let matched = self.slice(0, span.len());
-        let remaining = self.slice_to_end(span.len());
-        Ok((remaining, matched))
+        let list_body = self.container_body(b'[')?;
+        let span = RawTextListIterator_1_0::new(list_body).find_span();
+        self.match_container_span(span, "matching a list")
     }
 
     /// Matches an Ion v1.1 list, which allows e-expressions (macro invocations) to appear in value
     /// position.
     ///
     /// If the input does not contain the entire list, returns `IonError::Incomplete(_)`.
-    // TODO: DRY with `match_list`
     pub fn match_list_1_1(
         self,
     ) -> IonParseResult<
@@ -779,42 +865,14 @@ impl<'top> TextBufferView<'top> {
             &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
         ),
     > {
-        // If it doesn't start with [, it isn't a list.
-        if self.bytes().first() != Some(&b'[') {
-            let error = InvalidInputError::new(self);
-            return Err(nom::Err::Error(IonParseError::Invalid(error)));
-        }
         // Scan ahead to find the end of this list.
-        let list_body = self.slice_to_end(1);
+        let list_body = self.container_body(b'[')?;
         let sequence_iter = RawTextListIterator_1_1::new(list_body);
-        let (span, child_exprs) = match TextListSpanFinder_1_1::new(
-            self.context.allocator(),
-            sequence_iter,
-        )
-        .find_span()
-        {
-            Ok((span, child_exprs)) => (span, child_exprs),
-            // If the complete container isn't available, return an incomplete.
-            Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
-            // If invalid syntax was encountered, return a failure to prevent nom from trying
-            // other parser kinds.
-            Err(e) => {
-                return {
-                    let error = InvalidInputError::new(self)
-                        .with_label("matching a v1.1 list")
-                        .with_description(format!("{}", e));
-                    Err(nom::Err::Failure(IonParseError::Invalid(error)))
-                }
-            }
-        };
-
-        // For the matched span, we use `self` again to include the opening `[`
-        let matched = self.slice(0, span.len());
-        let remaining = self.slice_to_end(span.len());
-        Ok((remaining, (matched, child_exprs)))
+        let span_and_child_exprs =
+            TextListSpanFinder_1_1::new(self.context.allocator(), sequence_iter).find_span();
+        self.match_container_span_and_child_exprs(span_and_child_exprs, "matching a v1.1 list")
     }
 
-    // TODO: DRY with `match_sexp`
     pub fn match_sexp_1_1(
         self,
     ) -> IonParseResult<
@@ -824,33 +882,54 @@ impl<'top> TextBufferView<'top> {
             &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
         ),
     > {
-        if self.bytes().first() != Some(&b'(') {
-            let error = InvalidInputError::new(self);
-            return Err(nom::Err::Error(IonParseError::Invalid(error)));
-        }
         // Scan ahead to find the end of this sexp
-        let sexp_body = self.slice_to_end(1);
+        let sexp_body = self.container_body(b'(')?;
         let sexp_iter = RawTextSExpIterator_1_1::new(sexp_body);
-        let (span, child_expr_cache) =
-            match TextSExpSpanFinder_1_1::new(self.context.allocator(), sexp_iter).find_span(1) {
-                Ok((span, child_expr_cache)) => (span, child_expr_cache),
-                // If the complete container isn't available, return an incomplete.
-                Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
-                // If invalid syntax was encountered, return a failure to prevent nom from trying
-                // other parser kinds.
-                Err(e) => {
-                    return {
-                        let error = InvalidInputError::new(self)
-                            .with_label("matching a 1.1 sexp")
-                            .with_description(format!("{}", e));
-                        Err(nom::Err::Failure(IonParseError::Invalid(error)))
-                    }
-                }
-            };
-        // For the matched span, we use `self` again to include the opening `(`
+        let span_and_child_exprs =
+            TextSExpSpanFinder_1_1::new(self.context.allocator(), sexp_iter).find_span(1);
+        self.match_container_span_and_child_exprs(span_and_child_exprs, "matching a 1.1 sexp")
+    }
+
+    /// Converts the result of scanning ahead for the end of a container into the result of
+    /// matching that container. The container matchers call themselves (indirectly) once for each
+    /// level of nesting in the input, so this work is done in a separate function to keep their
+    /// stack frames small.
+    #[inline(never)]
+    fn match_container_span_and_child_exprs<T>(
+        self,
+        scan_result: IonResult<(Range<usize>, T)>,
+        label: &'static str,
+    ) -> IonParseResult<'top, (TextBufferView<'top>, T)> {
+        let (span, child_exprs) = match scan_result {
+            Ok((span, child_exprs)) => (span, child_exprs),
+            // If the complete container isn't available, return an incomplete.
+            Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+            // If invalid syntax was encountered, return a failure to prevent nom from trying
+            // other parser kinds.
+            Err(e) => {
+                let error = InvalidInputError::new(self)
+                    .with_label(label)
+                    .with_cause(&e);
+                return Err(nom::Err::Failure(IonParseError::Invalid(error)));
+            }
+        };
+        // For the matched span, we use `self` again to include the opening delimiter.
         let matched = self.slice(0, span.len());
         let remaining = self.slice_to_end(span.len());
-        Ok((remaining, (matched, child_expr_cache)))
+        Ok((remaining, (matched, child_exprs)))
+    }
+
+    /// Like [`Self::match_container_span_and_child_exprs`], but for Ion 1.0 containers, which do
+    /// not cache their child expressions.
+    fn match_container_span(
+        self,
+        scan_result: IonResult<Range<usize>>,
+        label: &'static str,
+    ) -> IonMatchResult<'top> {
+        let scan_result = scan_result.map(|span| (span, ()));
+        let (remaining, (matched, ())) =
+            self.match_container_span_and_child_exprs(scan_result, label)?;
+        Ok((remaining, matched))
     }
 
     /// Matches a single value in a list OR the end of the list, allowing for leading whitespace
@@ -859,20 +938,19 @@ impl<'top> TextBufferView<'top> {
     /// If a value is found, returns `Ok(Some(value))`. If the end of the list is found, returns
     /// `Ok(None)`.
     pub fn match_list_value(self) -> IonParseResult<'top, Option<LazyRawTextValue_1_0<'top>>> {
-        preceded(
-            // Some amount of whitespace/comments...
-            Self::match_optional_comments_and_whitespace,
-            // ...followed by either the end of the list...
-            alt((
-                value(None, tag("]")),
-                // ...or a value...
-                terminated(
-                    Self::match_annotated_value.map(Some),
-                    // ...followed by a comma or end-of-list
-                    Self::match_delimiter_after_list_value,
-                ),
-            )),
-        )(self)
+        // Some amount of whitespace/comments...
+        let (input, _ws) = self.match_optional_comments_and_whitespace()?;
+        // ...followed by either the end of the list...
+        match input.bytes().first() {
+            None => return Err(nom::Err::Incomplete(Needed::new(1))),
+            Some(b']') => return Ok((input.slice_to_end(1), None)),
+            _ => {}
+        }
+        // ...or a value...
+        let (input, value) = input.match_annotated_value()?;
+        // ...followed by a comma or end-of-list
+        let (input, _delimiter) = input.match_delimiter_after_list_value()?;
+        Ok((input, Some(value)))
     }
 
     /// Matches either:
@@ -881,21 +959,27 @@ impl<'top> TextBufferView<'top> {
     pub fn match_list_value_1_1(
         self,
     ) -> IonParseResult<'top, Option<LazyRawValueExpr<'top, TextEncoding_1_1>>> {
-        whitespace_and_then(alt((
-            terminated(
-                Self::match_e_expression,
-                Self::match_delimiter_after_list_value,
-            )
-            .map(|matched| Some(RawValueExpr::EExp(matched))),
-            value(None, tag("]")),
-            terminated(
-                Self::match_annotated_value_1_1.map(Some),
-                // ...followed by a comma or end-of-list
-                Self::match_delimiter_after_list_value,
-            )
-            .map(|maybe_matched| maybe_matched.map(RawValueExpr::ValueLiteral)),
-        )))
-        .parse(self)
+        let (input, _ws) = self.match_optional_comments_and_whitespace()?;
+        match terminated(
+            Self::match_e_expression,
+            Self::match_delimiter_after_list_value,
+        )(input)
+        {
+            Err(nom::Err::Error(_)) => {}
+            result => {
+                let (remaining, eexp) = result?;
+                return Ok((remaining, Some(RawValueExpr::EExp(eexp))));
+            }
+        }
+        match input.bytes().first() {
+            None => return Err(nom::Err::Incomplete(Needed::new(1))),
+            Some(b']') => return Ok((input.slice_to_end(1), None)),
+            _ => {}
+        }
+        let (input, value) = input.match_annotated_value_1_1()?;
+        // ...followed by a comma or end-of-list
+        let (input, _delimiter) = input.match_delimiter_after_list_value()?;
+        Ok((input, Some(RawValueExpr::ValueLiteral(value))))
     }
 
     /// Matches syntax that is expected to follow a value in a list: any amount of whitespace and/or
@@ -911,66 +995,20 @@ impl<'top> TextBufferView<'top> {
     ///
     /// If the input does not contain the entire s-expression, returns `IonError::Incomplete(_)`.
     pub fn match_sexp(self) -> IonMatchResult<'top> {
-        if self.bytes().first() != Some(&b'(') {
-            let error = InvalidInputError::new(self);
-            return Err(nom::Err::Error(IonParseError::Invalid(error)));
-        }
         // Scan ahead to find the end of this sexp
-        let sexp_body = self.slice_to_end(1);
-        let sexp_iter = RawTextSExpIterator_1_0::new(sexp_body);
-        let span = match sexp_iter.find_span(1) {
-            Ok(span) => span,
-            // If the complete container isn't available, return an incomplete.
-            Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
-            // If invalid syntax was encountered, return a failure to prevent nom from trying
-            // other parser kinds.
-            Err(e) => {
-                return {
-                    let error = InvalidInputError::new(self)
-                        .with_label("matching a sexp")
-                        .with_description(format!("{}", e));
-                    Err(nom::Err::Failure(IonParseError::Invalid(error)))
-                }
-            }
-        };
-        // For the matched span, we use `self` again to include the opening `(`
-        let matched = self.slice(0, span.len());
-        let remaining = self.slice_to_end(span.len());
-        Ok((remaining, matched))
+        let sexp_body = self.container_body(b'(')?;
+        let span = RawTextSExpIterator_1_0::new(sexp_body).find_span(1);
+        self.match_container_span(span, "matching a sexp")
     }
 
     /// Matches a struct.
     ///
     /// If the input does not contain the entire struct, returns `IonError::Incomplete(_)`.
     pub fn match_struct(self) -> IonMatchResult<'top> {
-        // If it doesn't start with {, it isn't a struct.
-        if self.bytes().first() != Some(&b'{') {
-            let error = InvalidInputError::new(self);
-            return Err(nom::Err::Error(IonParseError::Invalid(error)));
-        }
         // Scan ahead to find the end of this struct.
-        let struct_body = self.slice_to_end(1);
-        let struct_iter = RawTextStructIterator_1_0::new(struct_body);
-        let span = match struct_iter.find_span() {
-            Ok(span) => span,
-            // If the complete container isn't available, return an incomplete.
-            Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
-            // If invalid syntax was encountered, return a failure to prevent nom from trying
-            // other parser kinds.
-            Err(e) => {
-                return {
-                    let error = InvalidInputError::new(self)
-                        .with_label("matching a struct")
-                        .with_description(format!("{}", e));
-                    Err(nom::Err::Failure(IonParseError::Invalid(error)))
-                }
-            }
-        };
-
-        // For the matched span, we use `self` again to include the opening `{`
-        let matched = self.slice(0, span.len());
-        let remaining = self.slice_to_end(span.len());
-        Ok((remaining, matched))
+        let struct_body = self.container_body(b'{')?;
+        let span = RawTextStructIterator_1_0::new(struct_body).find_span();
+        self.match_container_span(span, "matching a struct")
     }
 
     pub fn match_struct_1_1(
@@ -982,39 +1020,12 @@ impl<'top> TextBufferView<'top> {
             &'top [LazyRawFieldExpr<'top, TextEncoding_1_1>],
         ),
     > {
-        // If it doesn't start with {, it isn't a struct.
-        if self.bytes().first() != Some(&b'{') {
-            let error = InvalidInputError::new(self);
-            return Err(nom::Err::Error(IonParseError::Invalid(error)));
-        }
         // Scan ahead to find the end of this struct.
-        let struct_body = self.slice_to_end(1);
+        let struct_body = self.container_body(b'{')?;
         let struct_iter = RawTextStructIterator_1_1::new(struct_body);
-        let (span, fields) = match TextStructSpanFinder_1_1::new(
-            self.context.allocator(),
-            struct_iter,
-        )
-        .find_span()
-        {
-            Ok((span, fields)) => (span, fields),
-            // If the complete container isn't available, return an incomplete.
-            Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
-            // If invalid syntax was encountered, return a failure to prevent nom from trying
-            // other parser kinds.
-            Err(e) => {
-                return {
-                    let error = InvalidInputError::new(self)
-                        .with_label("matching a v1.1 struct")
-                        .with_description(format!("{}", e));
-                    Err(nom::Err::Failure(IonParseError::Invalid(error)))
-                }
-            }
-        };
-
-        // For the matched span, we use `self` again to include the opening `{`
-        let matched = self.slice(0, span.len());
-        let remaining = self.slice_to_end(span.len());
-        Ok((remaining, (matched, fields)))
+        let span_and_fields =
+            TextStructSpanFinder_1_1::new(self.context.allocator(), struct_iter).find_span();
+        self.match_container_span_and_child_exprs(span_and_fields, "matching a v1.1 struct")
     }
 
     pub fn match_e_expression_arg_group(
@@ -1069,7 +1080,7 @@ impl<'top> TextBufferView<'top> {
                     return {
                         let error = InvalidInputError::new(self)
                             .with_label("matching an e-expression argument group")
-                            .with_description(format!("{}", e));
+                            .with_cause(&e);
                         Err(nom::Err::Failure(IonParseError::Invalid(error)))
                     }
                 }
@@ -1799,7 +1810,12 @@ impl<'top> TextBufferView<'top> {
         Self::match_text_until_unescaped_str(self, "'''")
     }
 
-    /// Matches an operator symbol, which can only legally appear within an s-expression
+    /// Matches an operator symbol, which can only legally appear within an s-expression.
+    ///
+    /// An operator is the longest run of operator characters at the head of the input. Callers
+    /// try to match a value first so that a leading sign is read as part of a number (`-1`,
+    /// `-inf`); a sign that follows another operator character is part of the operator, so
+    /// `--1` is the operator `--` followed by the int `1`.
     fn match_operator<E: TextEncoding<'top>>(
         self,
     ) -> IonParseResult<'top, LazyRawTextValue<'top, E>> {
@@ -1838,12 +1854,17 @@ impl<'top> TextBufferView<'top> {
         // We do this to make sure that input like `$1_02` gets parsed like an identifier;
         // If we didn't check for a trailing underscore, it would be a SID (`$1`) and an
         // identifier (`_02`).
-        terminated(complete_digit1, peek(not(complete_tag("_"))))
-            .map(|buffer: TextBufferView| {
-                // The matched buffer is ascii base 10 digits, parsing must succeed
-                usize::from_str(buffer.as_utf8(self.offset()).unwrap()).unwrap()
-            })
-            .parse(self)
+        let (remaining, matched_digits) =
+            terminated(complete_digit1, peek(not(complete_tag("_"))))(self)?;
+        // The matched buffer is ASCII base 10 digits, so it is valid UTF-8, but the value
+        // it represents may be too large to fit in a `usize`.
+        let address = usize::from_str(matched_digits.as_text().unwrap()).map_err(|_| {
+            let error = InvalidInputError::new(matched_digits)
+                .with_label("parsing an address")
+                .with_description("value did not fit in a usize");
+            nom::Err::Failure(IonParseError::Invalid(error))
+        })?;
+        Ok((remaining, address))
     }
 
     /// Matches an identifier (`foo`).
@@ -2130,27 +2151,21 @@ impl<'top> TextBufferView<'top> {
         recognize(take_while_m_n(4, 4, |c: u8| c.is_ascii_digit()))(self)
     }
 
+    /// Matches two ASCII digits. The ranges of timestamp components are not enforced by the
+    /// parser; they are validated when the timestamp is read so that the resulting error can
+    /// describe the problem.
+    fn match_timestamp_two_digits(self) -> IonMatchResult<'top> {
+        recognize(pair(Self::match_any_digit, Self::match_any_digit))(self)
+    }
+
     /// Matches the month component of a timestamp, including a leading `-`.
     fn match_timestamp_month(self) -> IonMatchResult<'top> {
-        preceded(
-            complete_tag("-"),
-            recognize(alt((
-                pair(complete_char('0'), complete_one_of("123456789")),
-                pair(complete_char('1'), complete_one_of("012")),
-            ))),
-        )(self)
+        preceded(complete_tag("-"), Self::match_timestamp_two_digits)(self)
     }
 
     /// Matches the day component of a timestamp, including a leading `-`.
     fn match_timestamp_day(self) -> IonMatchResult<'top> {
-        preceded(
-            complete_tag("-"),
-            recognize(alt((
-                pair(complete_char('0'), complete_one_of("123456789")),
-                pair(complete_one_of("12"), Self::match_any_digit),
-                pair(complete_char('3'), complete_one_of("01")),
-            ))),
-        )(self)
+        preceded(complete_tag("-"), Self::match_timestamp_two_digits)(self)
     }
 
     /// Matches a leading `T`, a two-digit hour component of a timestamp, a delimiting ':', and a
@@ -2161,25 +2176,16 @@ impl<'top> TextBufferView<'top> {
         preceded(
             tag("T"),
             separated_pair(
-                // Hour
-                recognize(alt((
-                    pair(complete_one_of("01"), Self::match_any_digit),
-                    pair(complete_char('2'), complete_one_of("0123")),
-                ))),
-                // Delimiter
+                Self::match_timestamp_two_digits,
                 complete_tag(":"),
-                // Minutes
-                recognize(pair(complete_one_of("012345"), Self::match_any_digit)),
+                Self::match_timestamp_two_digits,
             ),
         )(self)
     }
 
-    /// Matches a leading `:`, and any two-digit second component from `00` to `59` inclusive.
+    /// Matches a leading `:`, and a two-digit second component.
     fn match_timestamp_seconds(self) -> IonMatchResult<'top> {
-        preceded(
-            complete_tag(":"),
-            recognize(pair(complete_one_of("012345"), Self::match_any_digit)),
-        )(self)
+        preceded(complete_tag(":"), Self::match_timestamp_two_digits)(self)
     }
 
     /// Matches the fractional seconds component of a timestamp, including a leading `.`.
@@ -2213,15 +2219,9 @@ impl<'top> TextBufferView<'top> {
     /// minute.
     fn match_timestamp_offset_hours_and_minutes(self) -> IonParseResult<'top, (Self, Self)> {
         separated_pair(
-            // Hour
-            recognize(alt((
-                pair(complete_one_of("01"), Self::match_any_digit),
-                pair(complete_char('2'), complete_one_of("0123")),
-            ))),
-            // Delimiter
+            Self::match_timestamp_two_digits,
             complete_tag(":"),
-            // Minutes
-            recognize(pair(complete_one_of("012345"), Self::match_any_digit)),
+            Self::match_timestamp_two_digits,
         )(self)
     }
 
@@ -2332,10 +2332,8 @@ impl<'data> nom::InputTake for TextBufferView<'data> {
     }
 
     fn take_split(&self, count: usize) -> (Self, Self) {
-        let (before, after) = self.data.split_at(count);
-        let buffer_before = TextBufferView::new_with_offset(self.context, before, self.offset());
-        let buffer_after =
-            TextBufferView::new_with_offset(self.context, after, self.offset() + count);
+        let buffer_before = self.slice(0, count);
+        let buffer_after = self.slice_to_end(count);
         // Nom's convention is to place the remaining portion of the buffer first, which leads to
         // a potentially surprising reversed tuple order.
         (buffer_after, buffer_before)
@@ -2832,22 +2830,27 @@ mod tests {
             "2023-08-13T14:18-05:00",
             "2023-08-13T14:18:35-05:00",
             "2023-08-13T14:18:35.994-05:00",
-        ],
-        expect_mismatch: [
-            "2023",                          // No 'T'
-            "2023-08",                       // No 'T'
-            "20233T",                        // 5-digit year
+            // Component ranges are validated when the timestamp is read, not when it is matched.
             "2023-13T",                      // Out of bounds month
             "2023-08-41T",                   // Out of bounds day
-            "2023-08+18T",                   // Wrong delimiter
             "2023-08-18T25:00Z",             // Out of bounds hour
-            "2023-08-18T14:00",              // No offset
-            "2023-08-18T14:62",              // Out of bounds minute
-            "2023-08-18T14:35:61",           // Out of bounds second
-            "2023-08-18T14:35:52.Z",         // Dot but no fractional
+            "2023-08-18T14:62Z",             // Out of bounds minute
+            "2023-08-18T14:35:61Z",          // Out of bounds second
             "2023-08-18T14:35:52.000+24:30", // Out of bounds offset hour
             "2023-08-18T14:35:52.000+00:60", // Out of bounds offset minute
         ],
+        expect_mismatch: [
+            "2023",                  // No 'T'
+            "2023-08",               // No 'T'
+            "20233T",                // 5-digit year
+            "2023-1T",               // 1-digit month
+            "2023-08+18T",           // Wrong delimiter
+            "2023-08-18T14:00",      // No offset
+            "2023-08-18T14:62",      // No offset
+            "2023-08-18T14:35:61",   // No offset
+            "2023-08-18T14:35:52.Z", // Dot but no fractional
+            "2023-08-18T14:5Z",      // 1-digit minute
+        ],
     }
 
     matcher_tests! {
@@ -3009,6 +3012,81 @@ mod tests {
         ]
     }
 
+    matcher_tests! {
+        match_struct
+        expect_match: [
+            "{}",
+            "{ }",
+            "{a: 1}",
+            "{a: 1,}",
+            "{a: 1, b: 2}",
+            "{a:1,b:2}",
+            "{ a /*comment*/ : /*comment*/ 1 /*comment*/ , b : 2 }",
+            "{\n  a: 1, // comment\n  b: 2\n}",
+            "{'a b': 1, \"c\": 2, $10: 3}",
+            "{a: foo::{b: bar::[baz::()]}}",
+            "{a: {}, b: [], c: ()}",
+            "{a: {{aGVsbG8=}}, b: {{\"hello\"}}}",
+            "{a: (+ -3 --3)}",
+        ],
+        expect_mismatch: [
+            "foo", "1", "{{aGVsbG8=}}", "{a}", "{a 1}", "{a: 1 b: 2}", "{a: 1,, b: 2}", "{,}",
+        ],
+        expect_incomplete: [
+            "{", "{a", "{a:", "{a: 1", "{a: 1,", "{a: {b: 2}",
+        ]
+    }
+
+    matcher_tests_with_macro! {
+        parsing_structs
+        match_struct_1_1
+        "(macro foo (x*) null)"
+        expect_match: [
+            "{}",
+            "{a: 1, b: 2}",
+            "{ a /*comment*/ : /*comment*/ 1 /*comment*/ , b : 2 }",
+            "{a: (:foo 1 2)}",
+            "{a: (:foo), b: 2}",
+            "{(:foo)}",
+            "{(:foo a 1), b: 2}",
+            "{ (:foo) , }",
+            "{a: foo::{b: bar::[baz::()]}}",
+        ],
+        expect_mismatch: [
+            "foo", "1", "{a}", "{a: 1 b: 2}", "{(:foo) a: 1}",
+        ],
+        expect_incomplete: [
+            "{", "{a: 1", "{(:foo", "{a: (:foo 1",
+        ]
+    }
+
+    matcher_tests! {
+        match_value
+        expect_match: [
+            "[]", "()", "{}", "[{}]", "([{}])", "{a: [()]}", "{{aGVsbG8=}}", "{{\"hello\"}}",
+            "5", "foo",
+        ],
+        expect_mismatch: ["]", ")", "}"],
+        expect_incomplete: ["[", "(", "{", "{{", "[1, (2"],
+    }
+
+    matcher_tests_with_macro! {
+        parsing_sexp_operators
+        match_sexp_1_1
+        "(macro foo (x*) null)"
+        expect_match: [
+            "(-3)",
+            "(- 3)",
+            "(--3)",
+            "(+-())",
+            "(a::+ b::-3)",
+            "(+[1]-{a: 2}*(3))",
+            "(/*comment*/ + /*comment*/)",
+            "(+ (:foo) -)",
+        ],
+        expect_incomplete: ["(+", "(a::"],
+    }
+
     matcher_tests_with_macro! {
         parsing_lists
         match_list_1_1
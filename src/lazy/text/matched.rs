@@ -25,12 +25,12 @@ use std::str::FromStr;
 
 use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
-use ice_code::ice as cold_path;
 use nom::branch::alt;
 use nom::bytes::streaming::tag;
 use nom::character::is_hex_digit;
 use nom::sequence::preceded;
 use nom::{AsBytes, AsChar, Parser};
+use num_bigint::BigInt;
 use num_traits::Zero;
 use smallvec::SmallVec;
 
@@ -42,7 +42,7 @@ use crate::lazy::str_ref::StrRef;
 use crate::lazy::text::as_utf8::AsUtf8;
 use crate::lazy::text::buffer::TextBufferView;
 use crate::lazy::text::parse_result::InvalidInputError;
-use crate::result::{DecodingError, IonFailure};
+use crate::result::{DecodingError, DecodingErrorKind, IonFailure};
 use crate::{
     Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, Timestamp, TimestampPrecision,
 };
@@ -185,6 +185,11 @@ impl MatchedInt {
         let digits = matched_input.slice_to_end(self.digits_offset as usize);
         let mut sanitized: SmallVec<[u8; Self::STACK_ALLOC_BUFFER_CAPACITY]> =
             SmallVec::with_capacity(Self::STACK_ALLOC_BUFFER_CAPACITY);
+        // The sign is parsed along with the digits rather than applied afterward; the magnitude
+        // of i128::MIN does not fit in an i128 on its own.
+        if self.is_negative {
+            sanitized.push(b'-');
+        }
         // Copy the input text over to the sanitization buffer, discarding any underscores. These
         // are legal input, but Rust's integer `from_str_radix` method does not support them.
         sanitized.extend(digits.bytes().iter().copied().filter(|b| *b != b'_'));
@@ -197,21 +202,26 @@ impl MatchedInt {
                 debug_assert!(
                     // `from_str_radix` can fail for a variety of reasons, but our rules for matching an
                     // int rule out most of them (empty str, invalid digit, etc). The only ones that should
-                    // happen are overflow and underflow. In those cases, we fall back to using `BigInt`.
+                    // happen are overflow and underflow.
                     parse_int_error.kind() == &IntErrorKind::NegOverflow
                         || parse_int_error.kind() == &IntErrorKind::PosOverflow
                 );
-                return cold_path!(IonResult::decoding_error(format!(
-                    "encountered an int whose value was exceeded the supported range: '{}'",
-                    std::str::from_utf8(matched_input.bytes()).unwrap_or("invalid UTF-8")
-                )));
+                // The value is too large for an i128; read it as a BigInt instead.
+                Self::read_big_int(text, self.radix())?
             }
         };
+        Ok(int)
+    }
 
-        if self.is_negative {
-            Ok(-int)
-        } else {
-            Ok(int)
+    /// Reads an int whose value does not fit in an `i128`.
+    #[cold]
+    #[inline(never)]
+    fn read_big_int(text: &str, radix: u32) -> IonResult<Int> {
+        match BigInt::parse_bytes(text.as_bytes(), radix) {
+            Some(big_int) => Ok(big_int.into()),
+            None => IonResult::decoding_error(format!(
+                "encountered an int that could not be parsed: '{text}'"
+            )),
         }
     }
 }
@@ -308,11 +318,9 @@ impl MatchedDecimal {
         );
 
         let digits_text = sanitized.as_utf8(digits.offset())?;
-        let magnitude: Int = i128::from_str(digits_text)
-            .map_err(|_| {
-                IonError::decoding_error("decimal magnitude was larger than supported size")
-            })?
-            .into();
+        let magnitude = i128::from_str(digits_text).map_err(|_| {
+            IonError::decoding_error("decimal magnitude was larger than supported size")
+        })?;
 
         let coefficient = if self.is_negative {
             if magnitude.is_zero() {
@@ -435,7 +443,7 @@ impl MatchedString {
             // Support unicode escapes
             true,
         )?;
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized_as_text(sanitized.into_bump_slice(), matched_input)?;
         Ok(StrRef::from(text))
     }
 
@@ -469,7 +477,7 @@ impl MatchedString {
                 true,
             )?;
         }
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized_as_text(sanitized.into_bump_slice(), matched_input)?;
         Ok(StrRef::from(text))
     }
 
@@ -503,11 +511,26 @@ impl MatchedString {
             // Support Unicode escapes
             true,
         )?;
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized_as_text(sanitized.into_bump_slice(), matched_input)?;
         Ok(StrRef::from(text))
     }
 }
 
+/// Validates the UTF-8 of a string or symbol body that has had its escapes replaced. Unescaped
+/// bytes are copied from the input verbatim, so the input may not be valid UTF-8.
+fn sanitized_as_text<'data>(
+    sanitized: &'data [u8],
+    matched_input: TextBufferView<'data>,
+) -> IonResult<&'data str> {
+    std::str::from_utf8(sanitized).map_err(|_| {
+        IonError::Decoding(
+            DecodingError::new("encountered invalid UTF-8")
+                .with_kind(DecodingErrorKind::InvalidUtf8)
+                .with_position(matched_input.offset()),
+        )
+    })
+}
+
 fn replace_escapes_with_byte_values(
     matched_input: TextBufferView,
     sanitized: &mut BumpVec<u8>,
@@ -728,9 +751,16 @@ fn decode_hex_digits_escape<'data>(
     }
 
     // A Rust `char` can represent any Unicode scalar value--a code point that is not part of a
-    // surrogate pair. If the value we found isn't a high surrogate, then it's a complete scalar
-    // value. We can safely convert it to a `char`.
-    let character = char::from_u32(code_point).unwrap();
+    // surrogate pair. If the value we found isn't a high surrogate, it may still be an unpaired
+    // low surrogate or a value beyond the Unicode range, neither of which is a valid scalar.
+    let character = char::from_u32(code_point).ok_or_else(|| {
+        IonError::Decoding(
+            DecodingError::new(format!(
+                "escaped code point U+{hex_digits} is not a valid Unicode scalar value"
+            ))
+            .with_position(input.offset()),
+        )
+    })?;
     let utf8_buffer: &mut [u8; 4] = &mut [0; 4];
     let utf8_encoded = character.encode_utf8(utf8_buffer);
     sanitized.extend_from_slice(utf8_encoded.as_bytes());
@@ -852,9 +882,7 @@ impl MatchedSymbol {
         // Take a slice of the input that ignores the first and last bytes, which are quotes.
         let body = matched_input.slice(1, matched_input.len() - 2);
         // There are no escaped characters, so we can just validate the string in-place.
-        let text = body
-            .as_text()
-            .expect("successfully lexed symbol later found to be invalid UTF-8");
+        let text = body.as_text()?;
         let str_ref = RawSymbolRef::Text(text);
         Ok(str_ref)
     }
@@ -871,7 +899,7 @@ impl MatchedSymbol {
         // that replaces the escaped characters with their corresponding bytes.
         let mut sanitized = BumpVec::with_capacity_in(matched_input.len(), allocator);
         replace_escapes_with_byte_values(body, &mut sanitized, false, true)?;
-        let text = std::str::from_utf8(sanitized.into_bump_slice()).unwrap();
+        let text = sanitized_as_text(sanitized.into_bump_slice(), matched_input)?;
         Ok(RawSymbolRef::Text(text))
     }
 
@@ -891,7 +919,7 @@ impl MatchedSymbol {
         // Skip past the first byte, which has to be a `$`.
         let text = matched_input.slice_to_end(1).as_text()?;
         // It's not possible for the number parsing to fail because the matcher's rules
-        // guarantee that this string contains only decimal digits.
+        // guarantee that this string contains only decimal digits and fits in a `usize`.
         let sid = usize::from_str(text).expect("loading symbol ID as usize");
         Ok(RawSymbolRef::SymbolId(sid))
     }
@@ -918,7 +946,24 @@ impl MatchedTimestamp {
         self
     }
 
+    /// Reads the matched timestamp. The parser only confirms that each component has the
+    /// correct number of digits; the [`TimestampBuilder`](crate::TimestampBuilder) is responsible
+    /// for confirming that each component is within its allowed range. Any problems it finds are
+    /// reported as decoding errors that name the offending component.
     pub(crate) fn read(&self, matched_input: TextBufferView) -> IonResult<Timestamp> {
+        self.read_components(matched_input)
+            .map_err(|error| match error {
+                IonError::IllegalOperation(error) => DecodingError::new(format!(
+                    "found an invalid text timestamp: {}",
+                    error.operation()
+                ))
+                .with_position(matched_input.offset())
+                .into(),
+                error => error,
+            })
+    }
+
+    fn read_components(&self, matched_input: TextBufferView) -> IonResult<Timestamp> {
         // The parser has already confirmed that each subfield is made of ASCII digits,
         // so UTF-8 validation and parsing cannot fail. `unwrap()` is used in such cases
         // throughout.
@@ -973,6 +1018,13 @@ impl MatchedTimestamp {
                 let minutes_start = hours_start + 3;
                 let minutes_text = matched_input.slice(minutes_start, 2).as_text().unwrap();
                 let minutes = i32::from_str(minutes_text).unwrap();
+                // The builder validates the offset as a whole, so it cannot detect an offset
+                // like `+00:60`.
+                if minutes > 59 {
+                    return IonResult::illegal_operation(format!(
+                        "timestamp offset minutes ({minutes}) is outside of the range 0..=59"
+                    ));
+                }
                 let offset_magnitude_minutes = (hours * 60) + minutes;
                 if self.offset == MatchedTimestampOffset::NegativeHoursAndMinutes {
                     Some(-offset_magnitude_minutes)
@@ -1517,6 +1569,11 @@ mod tests {
             ("\"foo\rbar\rbaz\"", "foo\rbar\rbaz"),
             // In long-form strings, all unescaped newlines are converted to `\n`.
             ("'''foo\rbar\r\nbaz'''", "foo\nbar\nbaz"),
+            ("'''foo\r\n\r\nbar\r\r\nbaz'''", "foo\n\nbar\n\nbaz"),
+            ("'''foo\r''' '''\r\nbar'''", "foo\n\nbar"),
+            // An escaped newline of any style is a line continuation and is removed entirely.
+            ("'''foo\\\r\nbar\\\rbaz\\\nquux'''", "foobarbazquux"),
+            ("'''foo\\\r\r\nbar'''", "foo\nbar"),
         ];
 
         for (input, expected) in tests {
@@ -1592,6 +1649,7 @@ mod tests {
             ),
             // In a long-form clob, unescaped `\r` and `\r\n` are normalized into unescaped `\n`
             ("{{'''foo\rbar\r\nbaz'''}}", "foo\nbar\nbaz"),
+            ("{{'''foo\\\r\nbar\\\rbaz\r\n'''}}", "foobarbaz\n"),
             // In a short-form clob, carriage returns are not normalized.
             ("{{\"foo\rbar\rbaz\"}}", "foo\rbar\rbaz"),
         ];
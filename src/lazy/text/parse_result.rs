@@ -10,7 +10,7 @@
 
 use crate::lazy::text::buffer::TextBufferView;
 use crate::position::Position;
-use crate::result::{DecodingError, IonFailure};
+use crate::result::{DecodingError, DecodingErrorKind, IonFailure};
 use crate::{IonError, IonResult};
 use nom::error::{Error as NomError, ErrorKind, ParseError};
 use nom::{Err, IResult};
@@ -71,6 +71,16 @@ pub struct InvalidInputError<'data> {
     // The nom ErrorKind, which indicates which nom-provided parser encountered the error we're
     // bubbling up.
     nom_error_kind: Option<ErrorKind>,
+    // If this error was caused by a problem in a nested value, details of that problem.
+    // This is boxed to keep `IonParseError` (which is created frequently while parsing) small.
+    cause: Option<Box<ErrorCause>>,
+}
+
+/// The position and labels of the problem in a nested value that caused an [`InvalidInputError`].
+#[derive(Debug, PartialEq)]
+struct ErrorCause {
+    position: Option<Position>,
+    labels: Vec<Cow<'static, str>>,
 }
 
 impl<'data> InvalidInputError<'data> {
@@ -81,6 +91,7 @@ impl<'data> InvalidInputError<'data> {
             label: None,
             description: None,
             nom_error_kind: None,
+            cause: None,
         }
     }
 
@@ -102,6 +113,26 @@ impl<'data> InvalidInputError<'data> {
         self
     }
 
+    /// Sets this error's description to that of `cause`, an error encountered while parsing a
+    /// nested value. If `cause` has a position, the resulting `IonError` will report that position
+    /// instead of this error's so that it points to the innermost problem. Any labels `cause` has
+    /// are kept ahead of this error's own label.
+    pub(crate) fn with_cause(mut self, cause: &IonError) -> Self {
+        self.description = Some(cause.to_string().into());
+        let labels = match cause {
+            IonError::Decoding(error) => error
+                .labels()
+                .map(|label| Cow::Owned(label.to_owned()))
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.cause = Some(Box::new(ErrorCause {
+            position: cause.position().cloned(),
+            labels,
+        }));
+        self
+    }
+
     /// Returns a reference to the `description` text, if any.
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
@@ -128,7 +159,7 @@ impl<'data> From<InvalidInputError<'data>> for IonError {
                 .description()
                 .unwrap_or("invalid Ion syntax encountered"),
         );
-        if let Some(label) = invalid_input_error.label {
+        if let Some(ref label) = invalid_input_error.label {
             message.push_str("\n    while ");
             message.push_str(label.as_ref());
         }
@@ -180,9 +211,21 @@ impl<'data> From<InvalidInputError<'data>> for IonError {
             input.len(),
         )
         .unwrap();
-        let position = Position::with_offset(invalid_input_error.input.offset())
+        let input_position = Position::with_offset(invalid_input_error.input.offset())
             .with_length(invalid_input_error.input.len());
-        let decoding_error = DecodingError::new(message).with_position(position);
+        let (position, cause_labels) = match invalid_input_error.cause {
+            Some(cause) => (cause.position.unwrap_or(input_position), cause.labels),
+            None => (input_position, Vec::new()),
+        };
+        let mut decoding_error = DecodingError::new(message)
+            .with_kind(DecodingErrorKind::InvalidSyntax)
+            .with_position(position);
+        for label in cause_labels {
+            decoding_error = decoding_error.with_label(label);
+        }
+        if let Some(label) = invalid_input_error.label {
+            decoding_error = decoding_error.with_label(label);
+        }
         IonError::Decoding(decoding_error)
     }
 }
@@ -317,6 +360,73 @@ impl<'data, T> AddContext<'data, T> for IonParseResult<'data, T> {
     }
 }
 
+/// If `error` is a [`DecodingError`] whose position is known, adds the line and column of that
+/// position to the error along with a snippet of the offending line. `stream` must contain all of
+/// the bytes in the stream, beginning at offset zero; otherwise, the line and column would be
+/// miscounted.
+///
+/// Line and column numbers are computed by scanning `stream` for newlines, so this should only be
+/// called once an error is being surfaced. Successful reads never pay this cost.
+pub(crate) fn add_line_and_column(error: IonError, stream: &[u8]) -> IonError {
+    let IonError::Decoding(decoding_error) = &error else {
+        return error;
+    };
+    let Some(position) = decoding_error.position() else {
+        return error;
+    };
+    let offset = position.byte_offset();
+    if position.has_line_and_column() || offset > stream.len() {
+        return error;
+    }
+
+    // Lines and columns are both numbered starting from 1.
+    let before_error = &stream[..offset];
+    let line = before_error.iter().filter(|&&byte| byte == b'\n').count() + 1;
+    let line_start = before_error
+        .iter()
+        .rposition(|&byte| byte == b'\n')
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let line_end = stream[offset..]
+        .iter()
+        .position(|&byte| byte == b'\n')
+        .map(|index| offset + index)
+        .unwrap_or(stream.len());
+    let text_before_error = String::from_utf8_lossy(&stream[line_start..offset]);
+    let column = text_before_error.chars().count() + 1;
+
+    // Show (up to) the 60 characters of the line that precede the error and the 20 that follow it.
+    const CHARS_BEFORE_ERROR: usize = 60;
+    const CHARS_AFTER_ERROR: usize = 20;
+    let prefix: Vec<char> = text_before_error
+        .chars()
+        .rev()
+        .take(CHARS_BEFORE_ERROR)
+        .collect();
+    let prefix: String = prefix.into_iter().rev().collect();
+    let suffix: String = String::from_utf8_lossy(&stream[offset..line_end])
+        .trim_end_matches('\r')
+        .chars()
+        .take(CHARS_AFTER_ERROR)
+        .collect();
+    // Preserve tabs so the caret lines up with the text above it.
+    let caret_indent: String = prefix
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    let message = format!(
+        "{}\n    at line {line}, column {column}:\n    {prefix}{suffix}\n    {caret_indent}^",
+        decoding_error.description()
+    );
+    let position = position.clone().with_line_and_column(line, column);
+    decoding_error
+        .clone()
+        .with_description(message)
+        .with_position(position)
+        .into()
+}
+
 /// Constructs a `nom::Err::Failure` that contains an `IonParseError` describing the problem
 /// that was encountered.
 pub(crate) fn fatal_parse_error<D: Into<Cow<'static, str>>, O>(
@@ -7,11 +7,12 @@ use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
 use crate::lazy::streaming_raw_reader::RawReaderState;
 use crate::lazy::text::buffer::TextBufferView;
-use crate::lazy::text::parse_result::AddContext;
+use crate::lazy::text::parse_result::{add_line_and_column, AddContext};
 use crate::{Encoding, IonResult};
 
 /// A text Ion 1.0 reader that yields [`LazyRawStreamItem`]s representing the top level values found
 /// in the provided input stream.
+#[derive(Clone)]
 pub struct LazyRawTextReader_1_0<'data> {
     input: &'data [u8],
     // The offset from the beginning of the overall stream at which the `input` slice begins
@@ -45,6 +46,23 @@ impl<'data> LazyRawTextReader_1_0<'data> {
         &'top mut self,
         context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, TextEncoding_1_0>>
+    where
+        'data: 'top,
+    {
+        // Copy these fields out of `self`, which remains mutably borrowed by the item we return.
+        let (stream, stream_offset) = (self.input, self.stream_offset);
+        match self.read_next_item(context) {
+            // If `input` contains the entire stream, we can report the line and column at which
+            // the error occurred.
+            Err(error) if stream_offset == 0 => Err(add_line_and_column(error, stream)),
+            result => result,
+        }
+    }
+
+    fn read_next_item<'top>(
+        &'top mut self,
+        context: EncodingContextRef<'top>,
+    ) -> IonResult<LazyRawStreamItem<'top, TextEncoding_1_0>>
     where
         'data: 'top,
     {
@@ -126,7 +144,8 @@ mod tests {
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_value_ref::RawValueRef;
     use crate::raw_symbol_ref::AsRawSymbolRef;
-    use crate::{Decimal, IonType, RawSymbolRef, RawVersionMarker, Timestamp};
+    use crate::{Decimal, Element, IonData, IonType, RawSymbolRef, RawVersionMarker, Timestamp};
+    use rstest::*;
 
     use super::*;
 
@@ -510,4 +529,85 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case::lf("\n")]
+    #[case::cr_lf("\r\n")]
+    #[case::cr("\r")]
+    fn long_text_line_endings_are_normalized(#[case] newline: &str) -> IonResult<()> {
+        let values = [
+            "'''a\nb\\\nc''' '''\nd'''",
+            "{{'''x\ny\\\nz'''}}",
+            "foo::('''q\n''' '''r''')",
+            "{a: '''1\n\n2'''}",
+        ]
+        .map(|value| value.replace('\n', newline));
+        let data = values.join(newline);
+
+        let expected = Element::read_all(r#""a\nbc\nd" {{"x\nyz"}} foo::("q\nr") {a: "1\n\n2"}"#)?;
+        let actual = Element::read_all(data.as_bytes())?;
+        assert_eq!(IonData::from(&actual), IonData::from(&expected));
+
+        // Normalization only affects the values that are read; spans still refer to the input.
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = LazyRawTextReader_1_0::new(data.as_bytes());
+        let mut offset = 0;
+        for value_text in &values {
+            let value = reader.next(context)?.expect_value()?;
+            assert_eq!(value.span(), value_text.as_bytes());
+            assert_eq!(value.range(), offset..offset + value_text.len());
+            offset += value_text.len() + newline.len();
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::first_line("foo bar 2024-1-01T baz", 1, 9, "foo bar 2024-1-01T baz")]
+    #[case::after_multiline_strings(
+        "'''one\ntwo\nthree'''\n\"four\" '''five\n'''\n  {a: 1, b: 2]\n",
+        6,
+        14,
+        "  {a: 1, b: 2]"
+    )]
+    #[case::nested_container("[1, 2,\n  {\n    a: (3 4 ]\n  }\n]", 3, 13, "    a: (3 4 ]")]
+    fn syntax_errors_report_line_and_column(
+        #[case] input: &str,
+        #[case] expected_line: usize,
+        #[case] expected_column: usize,
+        #[case] expected_line_text: &str,
+    ) {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let mut reader = LazyRawTextReader_1_0::new(input.as_bytes());
+        let error = loop {
+            match reader.next(context) {
+                Ok(RawStreamItem::EndOfStream(_)) => panic!("expected an error"),
+                Ok(_) => {}
+                Err(error) => break error,
+            }
+        };
+        let position = error.position().expect("error did not have a position");
+        assert_eq!(position.line(), Some(expected_line));
+        assert_eq!(position.column(), Some(expected_column));
+        // The message includes the offending line with a caret under the failure position.
+        let caret = format!("{}^", " ".repeat(expected_column - 1));
+        let snippet = format!(
+            "at line {expected_line}, column {expected_column}:\n    {expected_line_text}\n    {caret}"
+        );
+        assert!(error.to_string().ends_with(&snippet), "{error}");
+    }
+
+    #[test]
+    fn line_and_column_are_only_reported_for_complete_streams() {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        // This reader's input begins partway through a larger stream, so it cannot tell which
+        // line the error is on.
+        let mut reader = LazyRawTextReader_1_0::new_with_offset(b"{a: 1]", 100);
+        let error = reader.next(context).expect_err("expected an error");
+        let position = error.position().expect("error did not have a position");
+        assert_eq!(position.byte_offset(), 105);
+        assert!(!position.has_line_and_column());
+    }
 }
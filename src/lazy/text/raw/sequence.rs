@@ -104,11 +104,25 @@ impl<'data> RawTextListIterator_1_0<'data> {
 
 impl<'data> RawTextListIterator_1_0<'data> {
     pub(crate) fn find_span(&self) -> IonResult<Range<usize>> {
+        // Matching each child value may recurse into this method, so the work that follows the
+        // last child value is done in a separate method to keep this stack frame small.
+        let mut last_value = None;
+        for value_result in *self {
+            last_value = Some(value_result?);
+        }
+        self.find_span_after(last_value)
+    }
+
+    #[inline(never)]
+    fn find_span_after(
+        &self,
+        last_value: Option<LazyRawValueExpr<'data, TextEncoding_1_0>>,
+    ) -> IonResult<Range<usize>> {
         // The input has already skipped past the opening delimiter.
         let start = self.input.offset() - 1;
         // We need to find the input slice containing the closing delimiter. It's either...
-        let input_after_last = if let Some(value_result) = self.last() {
-            let value = value_result?.expect_value()?;
+        let input_after_last = if let Some(value_expr) = last_value {
+            let value = value_expr.expect_value()?;
             // ...the input slice that follows the last sequence value...
             self.input
                 .slice_to_end(value.input.offset() + value.total_length() - self.input.offset())
@@ -203,11 +217,26 @@ impl<'top> RawTextSExpIterator_1_0<'top> {
     /// to invoke macros from the data stream), it will always be a minimum of `3`: two bytes for
     /// the opening `(:` and at least one for the macro identifier. (For example: `(:foo`.)
     pub(crate) fn find_span(&self, initial_bytes_skipped: usize) -> IonResult<Range<usize>> {
+        // Matching each child value may recurse into this method, so the work that follows the
+        // last child value is done in a separate method to keep this stack frame small.
+        let mut last_value = None;
+        for value_result in *self {
+            last_value = Some(value_result?);
+        }
+        self.find_span_after(initial_bytes_skipped, last_value)
+    }
+
+    #[inline(never)]
+    fn find_span_after(
+        &self,
+        initial_bytes_skipped: usize,
+        last_value: Option<LazyRawValueExpr<'top, TextEncoding_1_0>>,
+    ) -> IonResult<Range<usize>> {
         // The input has already skipped past the opening delimiter.
         let start = self.input.offset() - initial_bytes_skipped;
         // We need to find the input slice containing the closing delimiter. It's either...
-        let input_after_last = if let Some(value_result) = self.last() {
-            let value = value_result?.expect_value()?;
+        let input_after_last = if let Some(value_expr) = last_value {
+            let value = value_expr.expect_value()?;
             // ...the input slice that follows the last sequence value...
             self.input
                 .slice_to_end(value.input.offset() + value.total_length() - self.input.offset())
@@ -302,9 +331,11 @@ impl<'a> Debug for LazyRawTextSExp_1_0<'a> {
 mod tests {
     use std::ops::Range;
 
+    use rstest::rstest;
+
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::text::raw::reader::LazyRawTextReader_1_0;
-    use crate::IonResult;
+    use crate::{v1_1, Element, IonResult, Reader, Sequence};
 
     fn expect_sequence_range(ion_data: &str, expected: Range<usize>) -> IonResult<()> {
         let empty_context = EncodingContext::empty();
@@ -341,4 +372,53 @@ mod tests {
         }
         Ok(())
     }
+
+    #[rstest]
+    #[case::spaced_dot("(a . b)", "(a '.' b)")]
+    #[case::unspaced_dot("(a.b)", "(a '.' b)")]
+    #[case::operator_then_negative_int("(- -1)", "('-' -1)")]
+    // As in ion-java, an operator is the longest run of operator characters. The `-` that
+    // follows another operator character is not the sign of a number.
+    #[case::operator_run_then_int("(--1)", "('--' 1)")]
+    #[case::trailing_operator("(foo++ 2)", "(foo '++' 2)")]
+    #[case::plus_is_not_a_sign("(+ +1)", "('+' '+' 1)")]
+    #[case::identifier_then_negative_int("(a-1)", "(a -1)")]
+    #[case::negative_int("(-1)", "(-1)")]
+    #[case::operator_then_int("(- 1)", "('-' 1)")]
+    #[case::negative_infinity("(-inf)", "(-inf)")]
+    #[case::operator_then_negative_infinity("(- -inf)", "('-' -inf)")]
+    #[case::positive_infinity("(+inf)", "(+inf)")]
+    #[case::dot_then_int("(.1)", "('.' 1)")]
+    #[case::comparison_run("(x<=-1)", "(x '<=-' 1)")]
+    #[case::operator_between_identifiers("(a+-b)", "(a '+-' b)")]
+    #[case::ints_and_operators("(1 - 1 -1)", "(1 '-' 1 -1)")]
+    #[case::operator_then_negative_float("(- -1.5e3)", "('-' -1.5e3)")]
+    #[case::operator_then_negative_hex("(- -0x10)", "('-' -16)")]
+    // Timestamps cannot be negative
+    #[case::operator_then_timestamp("(- -2024T)", "('-' '-' 2024T)")]
+    #[case::annotated_operator("(a:: /*c*/ - -1)", "(a::'-' -1)")]
+    #[case::commented_annotations("(foo /*c*/ :: bar)", "(foo::bar)")]
+    #[case::line_comments("(a::b:://c\n 1)", "(a::b::1)")]
+    #[case::annotated_operator_run("(foo/**/::/**/++ 1)", "(foo::'++' 1)")]
+    #[case::comment_before_operator("(- //c\n -1)", "('-' -1)")]
+    #[case::comment_before_identifier("(a//c\n-b)", "(a '-' b)")]
+    #[case::comment_before_negative_int("(/*c*/-1)", "(-1)")]
+    #[case::nested_operator("(a(-)b)", "(a ('-') b)")]
+    #[case::top_level_commented_annotations("foo:: /*c*/ bar::42", "foo::bar::42")]
+    #[case::top_level_spaced_annotations("foo /*c*/ :: bar //c\n :: 42", "foo::bar::42")]
+    fn sexp_tokenization(#[case] ion_text: &str, #[case] expected: &str) -> IonResult<()> {
+        let expected = Element::read_all(expected)?;
+        assert_eq!(
+            Element::read_all(ion_text)?,
+            expected,
+            "Ion 1.0: {ion_text}"
+        );
+        let mut reader = Reader::new(v1_1::Text, ion_text)?;
+        let mut actual = Vec::new();
+        while let Some(value) = reader.next()? {
+            actual.push(Element::try_from(value)?);
+        }
+        assert_eq!(Sequence::from(actual), expected, "Ion 1.1: {ion_text}");
+        Ok(())
+    }
 }
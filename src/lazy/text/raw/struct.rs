@@ -32,11 +32,25 @@ impl<'top> RawTextStructIterator_1_0<'top> {
     }
 
     pub(crate) fn find_span(&self) -> IonResult<Range<usize>> {
+        // Matching each field value may recurse into this method, so the work that follows the
+        // last field is done in a separate method to keep this stack frame small.
+        let mut last_field = None;
+        for field_result in *self {
+            last_field = Some(field_result?);
+        }
+        self.find_span_after(last_field)
+    }
+
+    #[inline(never)]
+    fn find_span_after(
+        &self,
+        last_field: Option<LazyRawFieldExpr<'top, TextEncoding_1_0>>,
+    ) -> IonResult<Range<usize>> {
         // The input has already skipped past the opening delimiter.
         let start = self.input.offset() - 1;
         // We need to find the input slice containing the closing delimiter. It's either...
-        let input_after_last = if let Some(field_result) = self.last() {
-            let field = field_result?;
+        let input_after_last = if let Some(field) = last_field {
+            // ...the input slice that follows the last field...
             self.input
                 .slice_to_end(field.range().end - self.input.offset())
         } else {
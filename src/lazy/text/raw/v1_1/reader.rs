@@ -26,6 +26,7 @@ use crate::lazy::text::raw::v1_1::arg_group::{EExpArg, TextEExpArgGroup};
 use crate::lazy::text::value::{LazyRawTextValue_1_1, RawTextAnnotationsIterator};
 use crate::{v1_1, Encoding, IonResult, IonType, RawSymbolRef};
 
+#[derive(Clone)]
 pub struct LazyRawTextReader_1_1<'data> {
     input: &'data [u8],
     // The offset from the beginning of the overall stream at which the `input` slice begins
@@ -256,15 +257,27 @@ impl<'top> TextListSpanFinder_1_1<'top> {
         Range<usize>,
         &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
     )> {
-        // The input has already skipped past the opening delimiter.
-        let start = self.iterator.input.offset() - 1;
+        // Matching each child expression may recurse into this method, so the work that follows
+        // the last child expression is done in a separate method to keep this stack frame small.
         let mut child_expr_cache = BumpVec::new_in(self.allocator);
         for expr_result in self.iterator {
             let expr = expr_result?;
             child_expr_cache.push(expr);
         }
+        self.find_span_after(child_expr_cache.into_bump_slice())
+    }
 
-        let end = child_expr_cache
+    #[inline(never)]
+    fn find_span_after(
+        &self,
+        child_exprs: &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
+    ) -> IonResult<(
+        Range<usize>,
+        &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
+    )> {
+        // The input has already skipped past the opening delimiter.
+        let start = self.iterator.input.offset() - 1;
+        let end = child_exprs
             .last()
             .map(|e| e.range().end)
             .unwrap_or(self.iterator.input.offset());
@@ -288,8 +301,9 @@ impl<'top> TextListSpanFinder_1_1<'top> {
         let end = input_after_end.offset();
 
         let span = start..end;
-        Ok((span, child_expr_cache.into_bump_slice()))
+        Ok((span, child_exprs))
     }
+
     pub fn new(allocator: &'top bumpalo::Bump, iterator: RawTextListIterator_1_1<'top>) -> Self {
         Self {
             allocator,
@@ -418,16 +432,28 @@ impl<'top> TextSExpSpanFinder_1_1<'top> {
         Range<usize>,
         &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
     )> {
-        // The input has already skipped past the opening delimiter.
-        let start = self.iterator.input.offset() - initial_bytes_skipped;
+        // Matching each child expression may recurse into this method, so the work that follows
+        // the last child expression is done in a separate method to keep this stack frame small.
         let mut child_expr_cache = BumpVec::new_in(self.allocator);
-
         for expr_result in self.iterator {
             let expr = expr_result?;
             child_expr_cache.push(expr);
         }
+        self.find_span_after(initial_bytes_skipped, child_expr_cache.into_bump_slice())
+    }
 
-        let end = child_expr_cache
+    #[inline(never)]
+    fn find_span_after(
+        &self,
+        initial_bytes_skipped: usize,
+        child_exprs: &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
+    ) -> IonResult<(
+        Range<usize>,
+        &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
+    )> {
+        // The input has already skipped past the opening delimiter.
+        let start = self.iterator.input.offset() - initial_bytes_skipped;
+        let end = child_exprs
             .last()
             .map(|e| e.range().end)
             .unwrap_or(self.iterator.input.offset());
@@ -444,7 +470,7 @@ impl<'top> TextSExpSpanFinder_1_1<'top> {
         let end = input_after_end.offset();
 
         let range = start..end;
-        Ok((range, child_expr_cache.into_bump_slice()))
+        Ok((range, child_exprs))
     }
 }
 
@@ -731,15 +757,27 @@ impl<'top> TextStructSpanFinder_1_1<'top> {
         Range<usize>,
         &'top [LazyRawFieldExpr<'top, TextEncoding_1_1>],
     )> {
-        // The input has already skipped past the opening delimiter.
-        let start = self.iterator.input.offset() - 1;
+        // Matching each field may recurse into this method, so the work that follows the last
+        // field is done in a separate method to keep this stack frame small.
         let mut child_expr_cache = BumpVec::new_in(self.allocator);
         for expr_result in self.iterator {
             let expr = expr_result?;
             child_expr_cache.push(expr);
         }
+        self.find_span_after(child_expr_cache.into_bump_slice())
+    }
 
-        let end = child_expr_cache
+    #[inline(never)]
+    fn find_span_after(
+        &self,
+        field_exprs: &'top [LazyRawFieldExpr<'top, TextEncoding_1_1>],
+    ) -> IonResult<(
+        Range<usize>,
+        &'top [LazyRawFieldExpr<'top, TextEncoding_1_1>],
+    )> {
+        // The input has already skipped past the opening delimiter.
+        let start = self.iterator.input.offset() - 1;
+        let end = field_exprs
             .last()
             .map(|e| e.range().end)
             .unwrap_or(start + 1);
@@ -761,13 +799,14 @@ impl<'top> TextStructSpanFinder_1_1<'top> {
         let (input_after_end, _end_delimiter) = satisfy(|c| c == b'}' as char)(input_after_ws)
             .with_context("seeking the closing delimiter of a struct", input_after_ws)?;
         let end = input_after_end.offset();
-        Ok((start..end, child_expr_cache.into_bump_slice()))
+        Ok((start..end, field_exprs))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::lazy::any_encoding::IonVersion;
+    use crate::lazy::decoder::RawValueExpr;
     use crate::lazy::expanded::compiler::TemplateCompiler;
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_value_ref::RawValueRef;
@@ -841,4 +880,89 @@ mod tests {
         expect_next(context, reader, RawValueRef::Bool(false));
         Ok(())
     }
+
+    /// Returns the range of the first occurrence of `text` in `data`.
+    fn range_of(data: &str, text: &str) -> Range<usize> {
+        let start = data.find(text).expect("text not found in data");
+        start..start + text.len()
+    }
+
+    fn context_with_templates(templates: &[&str]) -> IonResult<EncodingContext> {
+        let mut context = EncodingContext::for_ion_version(IonVersion::v1_1);
+        for template in templates {
+            let template = TemplateCompiler::compile_from_text(context.get_ref(), template)?;
+            context.macro_table.add_macro(template)?;
+        }
+        Ok(context)
+    }
+
+    #[test]
+    fn nested_e_expressions() -> IonResult<()> {
+        let data = "(:foo (:pair 1 2) 3) (:foo [(:pair 4 5)] (:foo 6 7))";
+        let context = context_with_templates(&[
+            "(macro pair (a b) (values a b))",
+            "(macro foo (x y) [x, y])",
+        ])?;
+        let context = context.get_ref();
+        let reader = &mut LazyRawTextReader_1_1::new(data.as_bytes());
+
+        // (:foo (:pair 1 2) 3)
+        let outer = reader.next(context)?.expect_eexp()?;
+        assert_eq!(outer.range(), range_of(data, "(:foo (:pair 1 2) 3)"));
+        let mut args = outer.raw_arguments();
+        let inner = args.next().unwrap()?.expr().expect_eexp()?;
+        assert_eq!(inner.id, MacroIdRef::LocalName("pair"));
+        assert_eq!(inner.range(), range_of(data, "(:pair 1 2)"));
+        assert_eq!(inner.span().bytes(), b"(:pair 1 2)");
+        let mut inner_args = inner.raw_arguments();
+        for (expected_int, expected_range) in [(1, 13..14), (2, 15..16)] {
+            let value = inner_args.next().unwrap()?.expr().expect_value()?;
+            assert_eq!(value.read()?.expect_int()?, expected_int.into());
+            assert_eq!(value.range(), expected_range);
+        }
+        assert!(inner_args.next().is_none());
+        let value = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(value.read()?.expect_int()?, 3.into());
+        assert!(args.next().is_none());
+
+        // (:foo [(:pair 4 5)] (:foo 6 7))
+        let outer = reader.next(context)?.expect_eexp()?;
+        assert_eq!(
+            outer.range(),
+            range_of(data, "(:foo [(:pair 4 5)] (:foo 6 7))")
+        );
+        let mut args = outer.raw_arguments();
+        let list = args.next().unwrap()?.expr().expect_value()?;
+        assert_eq!(list.range(), range_of(data, "[(:pair 4 5)]"));
+        let list = list.read()?.expect_list()?;
+        let mut children = list.iter();
+        let RawValueExpr::EExp(in_list) = children.next().unwrap()? else {
+            panic!("expected an e-expression in the list");
+        };
+        assert_eq!(in_list.range(), range_of(data, "(:pair 4 5)"));
+        assert!(children.next().is_none());
+        let last = args.next().unwrap()?.expr().expect_eexp()?;
+        assert_eq!(last.id, MacroIdRef::LocalName("foo"));
+        assert_eq!(last.range(), range_of(data, "(:foo 6 7)"));
+        assert!(args.next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn unterminated_nested_e_expression() -> IonResult<()> {
+        let context = context_with_templates(&[
+            "(macro pair (a b) (values a b))",
+            "(macro foo (x y) [x, y])",
+        ])?;
+        let context = context.get_ref();
+        for data in [
+            "(:foo (:pair 1 2 3)",
+            "(:foo (:pair 1 2 3",
+            "(:foo (:pair 1 2",
+        ] {
+            let reader = &mut LazyRawTextReader_1_1::new(data.as_bytes());
+            assert!(reader.next(context).is_err(), "{data}");
+        }
+        Ok(())
+    }
 }
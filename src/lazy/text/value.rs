@@ -276,10 +276,12 @@ impl<'top> Iterator for RawTextAnnotationsIterator<'top> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::text::buffer::TextBufferView;
     use crate::lazy::text::value::RawTextAnnotationsIterator;
-    use crate::{IonResult, RawSymbolRef};
+    use crate::{v1_0, IonResult, RawSymbolRef, Reader};
 
     #[test]
     fn iterate_annotations() -> IonResult<()> {
@@ -298,4 +300,67 @@ mod tests {
         test("foo /*comment*/ :://comment\nbar\n::'baz'::")?;
         Ok(())
     }
+
+    #[rstest]
+    #[case::alert(r"'a\ab'", "a\u{07}b")]
+    #[case::backspace(r"'a\bb'", "a\u{08}b")]
+    #[case::tab(r"'a\tb'", "a\tb")]
+    #[case::newline(r"'a\nb'", "a\nb")]
+    #[case::form_feed(r"'a\fb'", "a\u{0C}b")]
+    #[case::carriage_return(r"'a\rb'", "a\rb")]
+    #[case::vertical_tab(r"'a\vb'", "a\u{0B}b")]
+    #[case::double_quote(r#"'a\"b'"#, "a\"b")]
+    #[case::single_quote(r"'a\'b'", "a'b")]
+    #[case::question_mark(r"'a\?b'", "a?b")]
+    #[case::backslash(r"'a\\b'", "a\\b")]
+    #[case::forward_slash(r"'a\/b'", "a/b")]
+    #[case::null(r"'a\0b'", "a\0b")]
+    #[case::hex(r"'a\x41b'", "aAb")]
+    #[case::unicode_4(r"'a\u00E9b'", "a\u{e9}b")]
+    #[case::unicode_8(r"'a\U0001F600b'", "a\u{1F600}b")]
+    #[case::escaped_newline("'a\\\nb'", "ab")]
+    #[case::every_escape(
+        r#"'\a\b\t\n\f\r\v\"\'\?\\\/\0\x41\u00E9\U0001F600'"#,
+        "\u{07}\u{08}\t\n\u{0C}\r\u{0B}\"'?\\/\0A\u{e9}\u{1F600}"
+    )]
+    fn read_escaped_annotations_and_symbols(
+        #[case] quoted_symbol: &str,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let ion_text = format!("{quoted_symbol}::plain::{quoted_symbol}");
+        let mut reader = Reader::new(v1_0::Text, ion_text.as_bytes())?;
+        let value = reader.expect_next()?;
+        let annotations: Vec<_> = value.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(annotations, vec![expected, "plain"]);
+        assert!(value.annotations().are([expected, "plain"])?);
+        assert_eq!(value.read()?.expect_symbol()?.text(), Some(expected));
+        Ok(())
+    }
+
+    /// Returns `true` if `text` is a slice of `input` rather than a copy of its contents.
+    fn is_borrowed_from(text: &str, input: &[u8]) -> bool {
+        input.as_ptr_range().contains(&text.as_ptr())
+    }
+
+    #[test]
+    fn only_escaped_annotations_are_copied() -> IonResult<()> {
+        let encoding_context = EncodingContext::empty();
+        let context = encoding_context.get_ref();
+        let input = r"foo::'bar baz'::'qu\x75x'::$10::".as_bytes();
+        let annotations: Vec<_> =
+            RawTextAnnotationsIterator::new(TextBufferView::new(context, input))
+                .collect::<IonResult<_>>()?;
+        let [RawSymbolRef::Text(foo), RawSymbolRef::Text(bar_baz), RawSymbolRef::Text(quux), RawSymbolRef::SymbolId(10)] =
+            annotations.as_slice()
+        else {
+            panic!("unexpected annotations: {annotations:?}");
+        };
+        // Annotations without escapes are borrowed from the input...
+        assert!(is_borrowed_from(foo, input));
+        assert!(is_borrowed_from(bar_baz, input));
+        // ...while those with escapes are written to the encoding context's bump allocator.
+        assert_eq!(*quux, "quux");
+        assert!(!is_borrowed_from(quux, input));
+        Ok(())
+    }
 }
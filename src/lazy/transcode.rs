@@ -0,0 +1,208 @@
+use std::io::Write;
+
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoder::writer::Writer;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::IonResult;
+
+/// Copies every remaining top-level value from `reader` to `writer`, returning the number of
+/// values that were copied.
+///
+/// Values are streamed from the reader one at a time and are never materialized as
+/// [`Element`](crate::Element)s. Annotations, field names, and nested containers are preserved.
+/// Each value is re-encoded by the writer, so the output may differ byte-for-byte from the input
+/// even when the input and output encodings are the same.
+///
+/// Symbol tables in the input are consumed by the reader and are not copied. The writer builds
+/// its own symbol table for the symbols it encounters. Symbols whose text is unknown are written
+/// according to the writer's [`UnknownTextSymbolPolicy`](crate::UnknownTextSymbolPolicy).
+///
+/// The writer is not flushed; call [`Writer::flush`] or [`Writer::close`] once transcoding is
+/// complete.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{transcode, v1_0, AnyEncoding, Element, Reader, Writer};
+///
+/// let mut reader = Reader::new(AnyEncoding, "foo::{bar: [1, 2.5, baz]} quux")?;
+/// let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+/// assert_eq!(transcode(&mut reader, &mut writer)?, 2);
+/// let binary = writer.close()?;
+///
+/// assert_eq!(
+///     Element::read_all(binary)?,
+///     Element::read_all("foo::{bar: [1, 2.5, baz]} quux")?
+/// );
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub fn transcode<D: Decoder, Input: IonInput, E: Encoding, Output: Write>(
+    reader: &mut Reader<D, Input>,
+    writer: &mut Writer<E, Output>,
+) -> IonResult<usize> {
+    let mut values_copied = 0;
+    while let Some(value) = reader.next()? {
+        writer.write(value)?;
+        values_copied += 1;
+    }
+    Ok(values_copied)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::lazy::encoder::value_writer::SequenceWriter;
+    use crate::lazy::encoding::BinaryEncoding_1_0;
+    use crate::{
+        v1_0, v1_1, AnyEncoding, Element, IonData, IonResult, Sequence, UnknownTextSymbolPolicy,
+        UnresolvedSymbolIdPolicy, WriteConfig,
+    };
+
+    const CORPUS: &str = r#"
+        null null.bool null.int null.float null.decimal null.timestamp null.string
+        null.symbol null.blob null.clob null.list null.sexp null.struct
+        true false
+        0 -1 9223372036854775807 0x7F -0b1010
+        0e0 -0e0 1.5e3 nan +inf -inf
+        0. -0. 1.23 -4.5d-6 100d10
+        2024T 2024-03-14T 2024-03-14T12:00Z 2024-03-14T12:00:30.125-07:00
+        "" "hello" "tab\tnewline\n" "\u00e9"
+        foo 'hello world' 'quote\'d' $ion_symbol_table
+        {{}} {{aGVsbG8=}} {{"clob text"}}
+        [] [1, [2, [3]], (4 5)]
+        () (+ 1 (* 2 3))
+        {} {a: 1, b: {c: [d, e]}, 'f g': (h i), a: 2}
+        foo::1 foo::bar::"baz" 'a b'::[x::1, y::{z: w::v::2}]
+        {annotated: q::r::{nested: s::t}}
+    "#;
+
+    /// Transcodes `data` (read with `AnyEncoding`) into a fresh writer using `config`.
+    fn transcode_to<E: Encoding>(
+        config: impl Into<WriteConfig<E>>,
+        data: impl AsRef<[u8]>,
+    ) -> IonResult<Vec<u8>> {
+        let mut reader = Reader::new(AnyEncoding, data.as_ref())?;
+        let mut writer = Writer::new(config, Vec::new())?;
+        transcode(&mut reader, &mut writer)?;
+        writer.close()
+    }
+
+    #[rstest]
+    #[case::text_1_0(v1_0::Text)]
+    #[case::binary_1_0(v1_0::Binary)]
+    #[case::text_1_1(v1_1::Text)]
+    #[case::binary_1_1(v1_1::Binary)]
+    fn text_to_encoding_to_text<E: Encoding>(#[case] encoding: E) -> IonResult<()>
+    where
+        WriteConfig<E>: From<E>,
+    {
+        let expected = Element::read_all(CORPUS)?;
+        let intermediate = transcode_to(encoding, CORPUS)?;
+        assert_eq!(
+            IonData::from(&Element::read_all(&intermediate)?),
+            IonData::from(&expected)
+        );
+        let text = transcode_to(v1_0::Text, &intermediate)?;
+        assert_eq!(
+            IonData::from(&Element::read_all(text)?),
+            IonData::from(&expected)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn returns_number_of_values_copied() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, CORPUS)?;
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        let expected = Element::read_all(CORPUS)?.len();
+        assert_eq!(transcode(&mut reader, &mut writer)?, expected);
+        // The reader is exhausted, so transcoding again copies nothing.
+        assert_eq!(transcode(&mut reader, &mut writer)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_tables_are_not_copied() -> IonResult<()> {
+        // A binary stream whose symbol table is replaced partway through.
+        let mut first = Writer::new(v1_0::Binary, Vec::new())?;
+        first.write_symbol("foo")?.write_symbol("bar")?;
+        first.reset_symbol_table()?;
+        first.write_symbol("baz")?;
+        let binary = first.close()?;
+
+        let text = transcode_to(v1_0::Text, &binary)?;
+        let text = String::from_utf8(text).unwrap();
+        assert!(!text.contains("$ion_symbol_table"), "{text}");
+        assert_eq!(Element::read_all(&text)?, Element::read_all("foo bar baz")?);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_text_symbols_follow_the_writer_policy() -> IonResult<()> {
+        #[rustfmt::skip]
+        let ion_data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA,         // IVM; no local symbol table
+            0x70,                           // $0
+            0xD2, 0x8B, 0x70,               // {$11: $0}
+            0xE4, 0x81, 0x8C, 0x71, 0x0A,   // $12::$10
+        ];
+        let transcode_with = |policy: UnknownTextSymbolPolicy| -> IonResult<String> {
+            let read_config =
+                AnyEncoding.with_unresolved_symbol_id_policy(UnresolvedSymbolIdPolicy::UnknownText);
+            let mut reader = Reader::new(read_config, ion_data)?;
+            let write_config =
+                WriteConfig::from(v1_0::Text).with_unknown_text_symbol_policy(policy);
+            let mut writer = Writer::new(write_config, Vec::new())?;
+            transcode(&mut reader, &mut writer)?;
+            Ok(String::from_utf8(writer.close()?).unwrap())
+        };
+
+        let text = transcode_with(UnknownTextSymbolPolicy::WriteSymbolId)?;
+        for expected_text in ["$0", "$11", "$12", "$10"] {
+            assert!(
+                text.contains(expected_text),
+                "{expected_text} not in {text}"
+            );
+        }
+        let text = transcode_with(UnknownTextSymbolPolicy::WriteSidZero)?;
+        assert_eq!(
+            Element::read_all(&text)?,
+            Element::read_all("$0 {$0: $0} $0::$0")?
+        );
+        assert!(transcode_with(UnknownTextSymbolPolicy::Error).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn large_blobs_are_transcoded_unchanged() -> IonResult<()> {
+        let blob: Vec<u8> = (0..64 * 1024).map(|i| (i % 251) as u8).collect();
+        let mut source = Writer::new(v1_0::Binary, Vec::new())?;
+        source.write_blob(&blob)?;
+        let binary = source.close()?;
+
+        // The input was produced by the same kind of writer, so re-encoding it reproduces it.
+        let output = transcode_to(WriteConfig::<BinaryEncoding_1_0>::new(), &binary)?;
+        assert_eq!(output, binary);
+        Ok(())
+    }
+
+    #[test]
+    fn transcodes_into_a_writer_with_existing_values() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        writer.write_symbol("before")?;
+        let mut reader = Reader::new(AnyEncoding, "a::1 {b: c}")?;
+        transcode(&mut reader, &mut writer)?;
+        writer.write_symbol("after")?;
+        let expected: Sequence = Element::read_all("before a::1 {b: c} after")?;
+        assert_eq!(Element::read_all(writer.close()?)?, expected);
+        Ok(())
+    }
+}
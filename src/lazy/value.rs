@@ -1,12 +1,19 @@
-use crate::lazy::decoder::Decoder;
+use std::ops::Range;
+
+use rustc_hash::FxHashSet;
+
+use crate::lazy::decoder::{Decoder, LazyRawValue};
 use crate::lazy::encoding::BinaryEncoding_1_0;
-use crate::lazy::expanded::{ExpandedAnnotationsIterator, LazyExpandedValue};
+use crate::lazy::expanded::{EncodingContextRef, ExpandedAnnotationsIterator, LazyExpandedValue};
+use crate::lazy::r#struct::{LazyStruct, StructIterator};
+use crate::lazy::sequence::{LazyList, LazySExp, ListIterator, SExpIterator};
+use crate::lazy::span::Span;
 use crate::lazy::value_ref::ValueRef;
-use crate::result::IonFailure;
+use crate::result::{type_mismatch_error, DecodingError, DecodingErrorKind, IonFailure};
 use crate::symbol_ref::AsSymbolRef;
 use crate::{
     Annotations, Element, ExpandedValueSource, IntoAnnotatedElement, IonError, IonResult, IonType,
-    RawSymbolRef, SymbolRef, SymbolTable, Value,
+    RawSymbolRef, Symbol, SymbolId, SymbolRef, SymbolTable, Value,
 };
 
 /// A value in a binary Ion stream whose header has been parsed but whose body (i.e. its data) has
@@ -55,13 +62,90 @@ use crate::{
 #[derive(Debug, Copy, Clone)]
 pub struct LazyValue<'top, D: Decoder> {
     pub(crate) expanded_value: LazyExpandedValue<'top, D>,
+    pub(crate) nesting: Nesting,
 }
 
 pub type LazyBinaryValue<'top> = LazyValue<'top, BinaryEncoding_1_0>;
 
+/// Where a value sits in the container hierarchy of the stream that is being read: how many
+/// containers it is nested inside of and the type of the innermost one.
+///
+/// This is tracked by the user-level lazy types as the application steps into containers; it is
+/// not recorded by the underlying raw or expanded values.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Nesting {
+    pub(crate) depth: usize,
+    pub(crate) parent_type: Option<IonType>,
+}
+
+impl Nesting {
+    /// The nesting of a value at the top level of the stream.
+    pub(crate) const TOP_LEVEL: Nesting = Nesting {
+        depth: 0,
+        parent_type: None,
+    };
+
+    /// Returns the nesting of the child values of a container of type `container_type` that has
+    /// this nesting.
+    pub(crate) fn child_of(self, container_type: IonType) -> Nesting {
+        Nesting {
+            depth: self.depth + 1,
+            parent_type: Some(container_type),
+        }
+    }
+}
+
 impl<'top, D: Decoder> LazyValue<'top, D> {
     pub(crate) fn new(expanded_value: LazyExpandedValue<'top, D>) -> LazyValue<'top, D> {
-        LazyValue { expanded_value }
+        LazyValue {
+            expanded_value,
+            nesting: Nesting::TOP_LEVEL,
+        }
+    }
+
+    /// Returns a copy of this value that reports the provided position in the container hierarchy.
+    pub(crate) fn with_nesting(mut self, nesting: Nesting) -> LazyValue<'top, D> {
+        self.nesting = nesting;
+        self
+    }
+
+    /// Returns the number of containers that this value is nested inside of. Values at the top
+    /// level of the stream have a depth of `0`.
+    ///
+    /// The depth is tracked as the application steps into containers, so reading it does not
+    /// require any additional parsing.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{IonType, Reader};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "{foo: [1, 2]}")?;
+    /// let top_level = reader.expect_next()?;
+    /// assert_eq!(top_level.depth(), 0);
+    /// assert_eq!(top_level.parent_type(), None);
+    ///
+    /// let foo = top_level.read()?.expect_struct()?.find_expected("foo")?;
+    /// assert_eq!(foo.depth(), 1);
+    /// assert_eq!(foo.parent_type(), Some(IonType::Struct));
+    ///
+    /// let one = foo.read()?.expect_list()?.iter().next().unwrap()?;
+    /// assert_eq!(one.depth(), 2);
+    /// assert_eq!(one.parent_type(), Some(IonType::List));
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.nesting.depth
+    }
+
+    /// Returns the [`IonType`] of the innermost container that this value is nested inside of,
+    /// or `None` if this value is at the top level of the stream. See [`LazyValue::depth`].
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.nesting.parent_type
     }
 
     #[cfg(feature = "experimental-tooling-apis")]
@@ -152,6 +236,48 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
         }
     }
 
+    /// Returns the range of input bytes from which this value was read. This can be used to build
+    /// an index of the values in a stream or to point to a value's location in an error message.
+    ///
+    /// The range includes the value's annotations, if any. If the value was produced by evaluating
+    /// an e-expression (including a value that came from a macro's template), the range is that of
+    /// the e-expression. Values that were passed to a macro as arguments report their own range.
+    ///
+    /// Returns `None` if the value's origin in the input is not known. Values produced by a
+    /// [`Reader`](crate::Reader) always have a range; every macro evaluation it performs begins
+    /// with an e-expression in the input.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let ion_text = "1 foo::[true, 2.5]";
+    /// let mut reader = Reader::new(Text, ion_text)?;
+    /// assert_eq!(reader.expect_next()?.range(), Some(0..1));
+    /// let list_value = reader.expect_next()?;
+    /// assert_eq!(&ion_text[list_value.range().unwrap()], "foo::[true, 2.5]");
+    ///
+    /// let list = list_value.read()?.expect_list()?;
+    /// let ranges: Vec<_> = list.iter().map(|v| v.map(|v| v.range())).collect::<IonResult<_>>()?;
+    /// assert_eq!(ranges, [Some(8..12), Some(14..17)]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn range(&self) -> Option<Range<usize>> {
+        self.span().map(|span| span.range())
+    }
+
+    /// Returns the input bytes from which this value was read, or `None` if they are not known.
+    /// See [`range`](Self::range) for details.
+    pub fn span(&self) -> Option<Span<'top>> {
+        self.expanded_value.span()
+    }
+
     /// Returns `true` if this value is any form of `null`, including
     /// `null`, `null.string`, `null.int`, etc. Otherwise, returns `false`.
     ///
@@ -217,16 +343,135 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
     ///# fn main() -> IonResult<()> { Ok(()) }
     /// ```
     pub fn annotations(&self) -> AnnotationsIterator<'top, D> {
-        AnnotationsIterator {
-            expanded_annotations: self.expanded_value.annotations(),
-            symbol_table: self.expanded_value.context.symbol_table(),
-        }
+        AnnotationsIterator::new(
+            self.expanded_value.annotations(),
+            self.expanded_value.context,
+            self.expanded_value.range().map(|range| range.start),
+        )
     }
 
+    /// Returns `true` if this value has at least one annotation. This is determined from the
+    /// value's encoding; none of the annotations are read or resolved.
     pub fn has_annotations(&self) -> bool {
         self.expanded_value.has_annotations()
     }
 
+    /// Returns `Ok(true)` if any of this value's annotations has the text `text`. Annotations are
+    /// visited in order and the search stops at the first match; annotations whose text is
+    /// unknown never match. If a decoding error occurs while visiting and resolving the
+    /// annotations, returns an `Err(IonError)`.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    ///
+    /// use ion_rs::{Reader, v1_0};
+    ///
+    /// let mut lazy_reader = Reader::new(v1_0::Text, "foo::bar::baz::99 100")?;
+    ///
+    /// let lazy_value = lazy_reader.expect_next()?;
+    /// assert!(lazy_value.has_annotation("foo")?);
+    /// assert!(lazy_value.has_annotation("baz")?);
+    /// assert!(!lazy_value.has_annotation("quux")?);
+    ///
+    /// let lazy_value = lazy_reader.expect_next()?;
+    /// assert!(!lazy_value.has_annotation("foo")?);
+    ///
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn has_annotation(&self, text: &str) -> IonResult<bool> {
+        if !self.has_annotations() {
+            return Ok(false);
+        }
+        for annotation in self.annotations() {
+            if annotation?.text() == Some(text) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns `Ok(true)` if this value's annotations are exactly the provided sequence of texts.
+    /// Comparison stops at the first annotation that does not match. If a decoding error occurs
+    /// while visiting and resolving the annotations, returns an `Err(IonError)`.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    ///
+    /// use ion_rs::{Reader, v1_0};
+    ///
+    /// let mut lazy_reader = Reader::new(v1_0::Text, "foo::bar::99")?;
+    /// let lazy_value = lazy_reader.expect_next()?;
+    ///
+    /// assert!(lazy_value.annotations_eq(["foo", "bar"])?);
+    /// assert!(!lazy_value.annotations_eq(["foo"])?);
+    /// assert!(!lazy_value.annotations_eq([])?);
+    ///
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn annotations_eq<'a>(&self, texts: impl IntoIterator<Item = &'a str>) -> IonResult<bool> {
+        let mut texts = texts.into_iter();
+        if !self.has_annotations() {
+            return Ok(texts.next().is_none());
+        }
+        let mut annotations = self.annotations();
+        for text in texts {
+            match annotations.next() {
+                Some(Ok(annotation)) if annotation.text() == Some(text) => {}
+                Some(Err(e)) => return Err(e),
+                Some(_) | None => return Ok(false),
+            }
+        }
+        Ok(annotations.next().is_none())
+    }
+
+    /// Like [`Self::has_annotation`], but returns an [`IonError::Decoding`] describing the
+    /// value's actual annotations if none of them has the text `text`.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    ///
+    /// use ion_rs::{Reader, v1_0};
+    ///
+    /// let mut lazy_reader = Reader::new(v1_0::Text, "foo::bar::99")?;
+    /// let lazy_value = lazy_reader.expect_next()?;
+    ///
+    /// assert!(lazy_value.expect_annotation("bar").is_ok());
+    /// assert!(lazy_value.expect_annotation("baz").is_err());
+    ///
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn expect_annotation(&self, text: &str) -> IonResult<()> {
+        if self.has_annotation(text)? {
+            return Ok(());
+        }
+        let found = self
+            .annotations()
+            .map(|annotation| annotation.map(|symbol| format!("{symbol:?}")))
+            .collect::<IonResult<Vec<_>>>()?;
+        let description = format!(
+            "expected a value with the annotation '{text}'; found annotations [{}]",
+            found.join(", ")
+        );
+        let error = DecodingError::new(description);
+        Err(match self.expanded_value.range() {
+            Some(range) => error.with_position(range.start),
+            None => error,
+        }
+        .into())
+    }
+
     /// Reads the body of this value (that is: its data) and returns it as a [`ValueRef`].
     /// ```
     ///# use ion_rs::IonResult;
@@ -258,7 +503,130 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
     ///# fn main() -> IonResult<()> { Ok(()) }
     /// ```
     pub fn read(&self) -> IonResult<ValueRef<'top, D>> {
-        self.expanded_value.read_resolved()
+        Ok(self
+            .expanded_value
+            .read_resolved()?
+            .with_nesting(self.nesting))
+    }
+
+    /// Reads this value and passes it to `expect`, which must only fail if the value is not of
+    /// the `expected` type. The resulting error includes this value's location in the input.
+    fn read_expecting<T>(
+        &self,
+        expected: &str,
+        expect: impl FnOnce(ValueRef<'top, D>) -> IonResult<T>,
+    ) -> IonResult<T> {
+        expect(self.read()?).map_err(|_| {
+            type_mismatch_error(
+                expected,
+                self.ion_type(),
+                self.is_null(),
+                self.expanded_value.range(),
+            )
+        })
+    }
+
+    /// Reads this value as a list. Unlike `read()?.expect_list()`, the error returned if the value
+    /// is not a list includes the value's byte range in the input.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "[1, 2] 3")?;
+    /// assert_eq!(reader.expect_next()?.read_list()?.iter().count(), 2);
+    ///
+    /// let error = reader.expect_next()?.read_list().unwrap_err();
+    /// assert_eq!(error.to_string(), "expected a list, found a(n) int at input bytes 7..8");
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn read_list(&self) -> IonResult<LazyList<'top, D>> {
+        self.read_expecting("a list", |value| value.expect_list())
+    }
+
+    /// Reads this value as an s-expression. See [`LazyValue::read_list`].
+    pub fn read_sexp(&self) -> IonResult<LazySExp<'top, D>> {
+        self.read_expecting("a sexp", |value| value.expect_sexp())
+    }
+
+    /// Reads this value as a struct. See [`LazyValue::read_list`].
+    pub fn read_struct(&self) -> IonResult<LazyStruct<'top, D>> {
+        self.read_expecting("a struct", |value| value.expect_struct())
+    }
+
+    /// Reads this value as an `f32`. Returns an `Err` if the value is not a float or if it cannot
+    /// be represented as an `f32` without losing information.
+    pub fn read_f32(&self) -> IonResult<f32> {
+        self.read()?.expect_f32()
+    }
+
+    /// If this value is a non-null float, returns the number of bytes its encoding used to
+    /// represent the IEEE-754 value; otherwise, returns `None`.
+    ///
+    /// Binary Ion can encode a float using 8 or 4 bytes (Ion 1.1 also supports 2), and can encode
+    /// positive zero using 0 bytes. Text floats, as well as floats produced by macro expansion,
+    /// are always reported as 64-bit (8 bytes). Applications transcribing binary Ion can use this
+    /// to preserve the size of each float.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Reader, Writer};
+    ///
+    /// let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+    /// writer.write(1.5f32)?.write(0.1f64)?;
+    /// let binary_ion = writer.close()?;
+    ///
+    /// let mut reader = Reader::new(v1_0::Binary, binary_ion)?;
+    /// assert_eq!(reader.expect_next()?.float_size(), Some(4));
+    /// assert_eq!(reader.expect_next()?.float_size(), Some(8));
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn float_size(&self) -> Option<usize> {
+        match self.raw() {
+            Some(raw_value) => raw_value.float_size(),
+            None => (self.ion_type() == IonType::Float && !self.is_null()).then_some(8),
+        }
+    }
+
+    /// Reads this value and all of its child values (if any) into an owned [`Element`] that can
+    /// be kept after the reader has advanced. Symbols, field names, and annotations are resolved
+    /// using the symbol table that is active at this value's position in the stream.
+    ///
+    /// Materializing a value does not affect the state of the reader or of any iterator that is
+    /// visiting the value's parent container.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, Reader};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "{id: 1} {id: 2, flagged: true} {id: 3}")?;
+    /// let mut flagged: Vec<Element> = Vec::new();
+    /// while let Some(value) = reader.next()? {
+    ///     if value.read()?.expect_struct()?.get("flagged")?.is_some() {
+    ///         flagged.push(value.materialize()?);
+    ///     }
+    /// }
+    /// assert_eq!(flagged, vec![Element::read_one("{id: 2, flagged: true}")?]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn materialize(&self) -> IonResult<Element> {
+        Element::try_from(*self)
     }
 }
 
@@ -266,24 +634,214 @@ impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for Element {
     type Error = IonError;
 
     fn try_from(lazy_value: LazyValue<'top, D>) -> Result<Self, Self::Error> {
-        let value: Value = lazy_value.read()?.try_into()?;
-        if lazy_value.has_annotations() {
-            let annotations: Annotations = lazy_value.annotations().try_into()?;
-            Ok(value.with_annotations(annotations))
-        } else {
-            Ok(value.into())
+        // Containers are materialized using an explicit stack rather than by recursing so that
+        // deeply nested data cannot exhaust the Rust stack.
+        let context = lazy_value.expanded_value.context;
+        let max_depth = context.read_options.max_depth;
+        let mut containers: Vec<MaterializingContainer<'top, D>> = Vec::new();
+        let mut next_value = lazy_value;
+        loop {
+            let annotations = if next_value.has_annotations() {
+                next_value.annotations().try_into()?
+            } else {
+                Annotations::empty()
+            };
+            // Materialize `next_value` if it's a scalar. If it's a container, start visiting its
+            // child values instead.
+            use MaterializingContainerKind::*;
+            let mut element = match next_value.read()? {
+                ValueRef::List(list) => {
+                    containers.push(MaterializingContainer::new(annotations, List(list.iter())));
+                    None
+                }
+                ValueRef::SExp(sexp) => {
+                    containers.push(MaterializingContainer::new(annotations, SExp(sexp.iter())));
+                    None
+                }
+                ValueRef::Struct(struct_) => {
+                    let kind = Struct(struct_.iter());
+                    containers.push(MaterializingContainer::new(annotations, kind));
+                    None
+                }
+                scalar => Some(Value::try_from(scalar)?.with_annotations(annotations)),
+            };
+            if containers.len() > max_depth {
+                let error = DecodingError::new(format!(
+                    "found a container that exceeds the maximum nesting depth of {max_depth}"
+                ));
+                return Err(match next_value.expanded_value.range() {
+                    Some(range) => error.with_position(range.start),
+                    None => error,
+                }
+                .into());
+            }
+            // Find the next value to materialize, completing any containers that have no
+            // more child values along the way.
+            loop {
+                let Some(container) = containers.last_mut() else {
+                    // The top-level value is complete.
+                    return Ok(element.unwrap());
+                };
+                if let Some(child) = element.take() {
+                    container.children.push(child);
+                }
+                match container.next_child(context)? {
+                    Some(child) => {
+                        next_value = child;
+                        break;
+                    }
+                    None => element = Some(containers.pop().unwrap().into_element()),
+                }
+            }
         }
     }
 }
 
+/// A container whose child values are being materialized by `Element::try_from(LazyValue)`.
+struct MaterializingContainer<'top, D: Decoder> {
+    annotations: Annotations,
+    kind: MaterializingContainerKind<'top, D>,
+    children: Vec<Element>,
+    // For structs, the names of the fields in `children`.
+    field_names: Vec<Symbol>,
+}
+
+enum MaterializingContainerKind<'top, D: Decoder> {
+    List(ListIterator<'top, D>),
+    SExp(SExpIterator<'top, D>),
+    Struct(StructIterator<'top, D>),
+}
+
+impl<'top, D: Decoder> MaterializingContainer<'top, D> {
+    fn new(annotations: Annotations, kind: MaterializingContainerKind<'top, D>) -> Self {
+        Self {
+            annotations,
+            kind,
+            children: Vec::new(),
+            field_names: Vec::new(),
+        }
+    }
+
+    /// Returns the next child value to materialize, or `None` if the container is complete.
+    fn next_child(
+        &mut self,
+        context: EncodingContextRef<'top>,
+    ) -> IonResult<Option<LazyValue<'top, D>>> {
+        use MaterializingContainerKind::*;
+        match &mut self.kind {
+            List(iter) => iter.next().transpose(),
+            SExp(iter) => iter.next().transpose(),
+            Struct(iter) => {
+                let Some(field) = iter.next_field()? else {
+                    return Ok(None);
+                };
+                let name = context.symbol_cache.materialize(field.name()?);
+                self.field_names.push(name);
+                Ok(Some(field.value()))
+            }
+        }
+    }
+
+    fn into_element(self) -> Element {
+        use MaterializingContainerKind::*;
+        let value = match self.kind {
+            List(_) => Value::List(self.children.into()),
+            SExp(_) => Value::SExp(self.children.into()),
+            Struct(_) => Value::Struct(self.field_names.into_iter().zip(self.children).collect()),
+        };
+        value.with_annotations(self.annotations)
+    }
+}
+
 /// Iterates over a slice of bytes, lazily reading them as a sequence of symbol tokens encoded
 /// using the format described by generic type parameter `D`.
 pub struct AnnotationsIterator<'top, D: Decoder> {
     pub(crate) expanded_annotations: ExpandedAnnotationsIterator<'top, D>,
-    pub(crate) symbol_table: &'top SymbolTable,
+    pub(crate) context: EncodingContextRef<'top>,
+    // The offset of the annotated value in the input, if known. Used in error messages.
+    offset: Option<usize>,
+    // The number of annotations visited so far.
+    count: usize,
+    // The annotations visited so far. This is only populated if the reader has been configured
+    // to reject duplicate annotations.
+    visited: VisitedAnnotations<'top>,
+}
+
+/// The annotations an [`AnnotationsIterator`] has already yielded, used to detect duplicates.
+///
+/// Most values have only a handful of annotations, so they are kept in a `Vec` and searched
+/// linearly. Once there are more than [`VisitedAnnotations::MAX_LINEAR_LEN`], they are moved into
+/// a `HashSet` so that a value with a very large number of annotations does not take quadratic
+/// time to check.
+enum VisitedAnnotations<'top> {
+    Linear(Vec<SymbolRef<'top>>),
+    Hashed(FxHashSet<SymbolRef<'top>>),
+}
+
+impl<'top> VisitedAnnotations<'top> {
+    const MAX_LINEAR_LEN: usize = 8;
+
+    /// Records `symbol` as visited. Returns `false` if it had already been visited.
+    fn insert(&mut self, symbol: SymbolRef<'top>) -> bool {
+        match self {
+            VisitedAnnotations::Linear(symbols) => {
+                if symbols.contains(&symbol) {
+                    return false;
+                }
+                if symbols.len() < Self::MAX_LINEAR_LEN {
+                    symbols.push(symbol);
+                } else {
+                    let mut set: FxHashSet<_> = symbols.drain(..).collect();
+                    set.insert(symbol);
+                    *self = VisitedAnnotations::Hashed(set);
+                }
+                true
+            }
+            VisitedAnnotations::Hashed(set) => set.insert(symbol),
+        }
+    }
 }
 
 impl<'top, D: Decoder> AnnotationsIterator<'top, D> {
+    pub(crate) fn new(
+        expanded_annotations: ExpandedAnnotationsIterator<'top, D>,
+        context: EncodingContextRef<'top>,
+        offset: Option<usize>,
+    ) -> Self {
+        Self {
+            expanded_annotations,
+            context,
+            offset,
+            count: 0,
+            visited: VisitedAnnotations::Linear(Vec::new()),
+        }
+    }
+
+    /// Constructs a decoding error, including the annotated value's offset if it is known.
+    fn error(&self, kind: DecodingErrorKind, description: String) -> IonError {
+        let error = DecodingError::new(description).with_kind(kind);
+        match self.offset {
+            Some(offset) => error.with_position(offset),
+            None => error,
+        }
+        .into()
+    }
+
+    /// Returns the next raw annotation, enforcing the reader's configured annotation limit.
+    fn next_raw(&mut self) -> Option<IonResult<RawSymbolRef<'top>>> {
+        let raw_annotation = self.expanded_annotations.next()?;
+        self.count += 1;
+        if let Some(max) = self.context.read_options.max_annotations_per_value {
+            if self.count > max {
+                return Some(Err(self.error(DecodingErrorKind::Other, format!(
+                    "found a value with at least {} annotations, which exceeds the configured maximum of {max}",
+                    self.count
+                ))));
+            }
+        }
+        Some(raw_annotation)
+    }
+
     /// Returns `Ok(true)` if this annotations iterator matches the provided sequence exactly, or
     /// `Ok(false)` if not. If a decoding error occurs while visiting and resolving each annotation,
     /// returns an `Err(IonError)`.
@@ -370,23 +928,51 @@ impl<'top, D: Decoder> AnnotationsIterator<'top, D> {
             IonResult::decoding_error("value annotations did not match expected sequence")
         }
     }
+
+    /// Returns an iterator over the symbol IDs used to encode these annotations. Each item is
+    /// `Some(sid)` if the annotation was encoded as a symbol ID (whether or not its text could be
+    /// resolved) or `None` if its text was encoded inline.
+    pub fn symbol_ids(mut self) -> impl Iterator<Item = IonResult<Option<SymbolId>>> + 'top {
+        std::iter::from_fn(move || {
+            self.next_raw()
+                .map(|annotation| annotation.map(|token| token.symbol_id()))
+        })
+    }
+
+    /// Returns an iterator over the text of these annotations. Each item is `None` if the
+    /// annotation's text is unknown.
+    pub fn texts(self) -> impl Iterator<Item = IonResult<Option<&'top str>>> + 'top {
+        self.map(|annotation| annotation.map(|symbol| symbol.text()))
+    }
 }
 
 impl<'top, D: Decoder> Iterator for AnnotationsIterator<'top, D> {
     type Item = IonResult<SymbolRef<'top>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let raw_annotation = self.expanded_annotations.next()?;
-        match raw_annotation {
-            Ok(RawSymbolRef::SymbolId(sid)) => match self.symbol_table.symbol_for(sid) {
-                None => Some(IonResult::decoding_error(
-                    "found a symbol ID that was not in the symbol table",
-                )),
-                Some(symbol) => Some(Ok(symbol.into())),
+        let symbol = match self.next_raw()? {
+            Ok(RawSymbolRef::SymbolId(sid)) => match self.context.resolve_symbol_id(sid) {
+                None => return Some(Err(self.error(
+                    DecodingErrorKind::SymbolNotFound,
+                    format!(
+                        "found an annotation symbol ID (${sid}) that was not in the symbol table"
+                    ),
+                ))),
+                Some(symbol) => symbol,
             },
-            Ok(RawSymbolRef::Text(text)) => Some(Ok(text.into())),
-            Err(e) => Some(Err(e)),
+            Ok(RawSymbolRef::Text(text)) => text.into(),
+            Err(e) => return Some(Err(e)),
+        };
+        if self.context.read_options.reject_duplicate_annotations && !self.visited.insert(symbol) {
+            return Some(Err(self.error(
+                DecodingErrorKind::Other,
+                format!(
+                    "found a value with duplicate annotation '{}'",
+                    symbol.text().unwrap_or("$0")
+                ),
+            )));
         }
+        Some(Ok(symbol))
     }
 }
 
@@ -394,9 +980,10 @@ impl<'top, D: Decoder> TryFrom<AnnotationsIterator<'top, D>> for Annotations {
     type Error = IonError;
 
     fn try_from(iter: AnnotationsIterator<'top, D>) -> Result<Self, Self::Error> {
+        let context = iter.context;
         let annotations = iter
             .map(|symbol_ref| match symbol_ref {
-                Ok(symbol_ref) => Ok(symbol_ref.to_owned()),
+                Ok(symbol_ref) => Ok(context.symbol_cache.materialize(symbol_ref)),
                 Err(e) => Err(e),
             })
             .collect::<IonResult<Vec<_>>>()?;
@@ -406,15 +993,242 @@ impl<'top, D: Decoder> TryFrom<AnnotationsIterator<'top, D>> for Annotations {
 
 #[cfg(test)]
 mod tests {
+    use num_bigint::BigInt;
     use num_traits::Float;
     use rstest::*;
+    use std::{f32, f64};
 
+    use crate::binary::var_uint::VarUInt;
     use crate::lazy::binary::test_utilities::to_binary_ion;
+    use crate::lazy::decoder::Decoder;
+    use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
+    use crate::lazy::encoding::Encoding;
+    use crate::lazy::value_ref::ValueRef;
+    use crate::position::Position;
+    use crate::read_config::ReadOptions;
+    use crate::write_config::WriteConfig;
     use crate::{
-        ion_list, ion_sexp, ion_struct, v1_0, Decimal, IonResult, IonType, Reader, Symbol,
-        Timestamp,
+        ion_list, ion_sexp, ion_struct, v1_0, v1_1, AnyEncoding, Decimal, ElementReader, IonError,
+        IonResult, IonType, Null, ReadConfig, Reader, Symbol, Timestamp, Writer,
     };
-    use crate::{Element, IntoAnnotatedElement};
+    use crate::{Element, IntoAnnotatedElement, LazyValue};
+
+    fn error_position(error: &IonError) -> Option<&Position> {
+        match error {
+            IonError::Decoding(decoding_error) => decoding_error.position(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn stepping_into_a_binary_scalar_reports_its_position() -> IonResult<()> {
+        #[rustfmt::skip]
+        let ion_data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0xB1, 0x20,             // [0]
+            0x21, 0x05,             // 5, at offset 6
+            0x0F,                   // null, at offset 8
+        ];
+        let mut reader = Reader::new(v1_0::Binary, ion_data)?;
+        assert_eq!(reader.expect_next()?.read_list()?.iter().count(), 1);
+
+        let five = reader.expect_next()?;
+        for error in [
+            five.read_list().map(|_| ()).unwrap_err(),
+            five.read_sexp().map(|_| ()).unwrap_err(),
+            five.read_struct().map(|_| ()).unwrap_err(),
+        ] {
+            assert!(error
+                .to_string()
+                .ends_with("found a(n) int at input bytes 6..8"));
+            let position = error_position(&error).unwrap();
+            assert_eq!(
+                (position.byte_offset(), position.byte_length()),
+                (6, Some(2))
+            );
+        }
+
+        let error = reader.expect_next()?.read_struct().map(|_| ()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a struct, found a null.null at input bytes 8..9"
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text_1_0(v1_0::Text)]
+    #[case::binary_1_0(v1_0::Binary)]
+    #[case::text_1_1(v1_1::Text)]
+    #[case::binary_1_1(v1_1::Binary)]
+    fn type_mismatch_positions_match_value_ranges<E: Encoding + Into<WriteConfig<E>>>(
+        #[case] encoding: E,
+    ) -> IonResult<()> {
+        let ion_data = Element::read_all("foo::1 [2] bar")?.encode_as(encoding)?;
+        let mut reader = Reader::new(AnyEncoding, ion_data.as_ref())?;
+        while let Some(value) = reader.next()? {
+            let range = value.expanded().range().unwrap();
+            let error = value.read_struct().map(|_| ()).unwrap_err();
+            let expected_suffix = format!(" at input bytes {}..{}", range.start, range.end);
+            assert!(error.to_string().ends_with(&expected_suffix), "{error}");
+            assert_eq!(error_position(&error).unwrap().byte_offset(), range.start);
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text(v1_0::Text)]
+    #[case::binary(v1_0::Binary)]
+    fn materialize_every_third_value<E: Encoding + Into<WriteConfig<E>>>(
+        #[case] encoding: E,
+    ) -> IonResult<()> {
+        let ion_text = r#"
+            1 foo::bar {a: 1, b: [2, 3]}
+            $ion_symbol_table::{symbols: ["x", "y"]}
+            $10 $11::"two" [x, {y: (x y)}, 3]
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["z"]}
+            z::{x: $12, nested: {z: [z]}} null.list 2024T
+            [1, 2, 3, 4, [5, 6, 7, [8, 9, 10]], 11, {a: 12, b: 13, c: 14}, 15]
+        "#;
+        let ion_data = Element::read_all(ion_text)?.encode_as(encoding)?;
+        let expected = Element::read_all(&ion_data)?;
+
+        // Materialize every third top-level value.
+        let mut reader = Reader::new(AnyEncoding, ion_data.as_ref())?;
+        let mut materialized = Vec::new();
+        let mut index = 0;
+        while let Some(value) = reader.next()? {
+            if index % 3 == 0 {
+                materialized.push(value.materialize()?);
+            }
+            index += 1;
+        }
+        let expected_every_third: Vec<Element> = expected.iter().step_by(3).cloned().collect();
+        assert_eq!(materialized, expected_every_third);
+
+        // Materialize every third value at every depth while iterating over the containers. Doing
+        // so must not disturb the iterators visiting them.
+        fn visit<D: Decoder>(
+            value: LazyValue<'_, D>,
+            index: &mut usize,
+            materialized: &mut Vec<Element>,
+        ) -> IonResult<()> {
+            if *index % 3 == 0 {
+                materialized.push(value.materialize()?);
+            }
+            *index += 1;
+            if value.is_null() {
+                return Ok(());
+            }
+            match value.read()? {
+                ValueRef::List(list) => {
+                    for child in &list {
+                        visit(child?, index, materialized)?;
+                    }
+                }
+                ValueRef::SExp(sexp) => {
+                    for child in &sexp {
+                        visit(child?, index, materialized)?;
+                    }
+                }
+                ValueRef::Struct(strukt) => {
+                    for field in &strukt {
+                        visit(field?.value(), index, materialized)?;
+                    }
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+        fn flatten(element: &Element, output: &mut Vec<Element>) {
+            output.push(element.clone());
+            if let Some(sequence) = element.as_sequence() {
+                for child in sequence {
+                    flatten(child, output);
+                }
+            } else if let Some(strukt) = element.as_struct() {
+                for (_, child) in strukt {
+                    flatten(child, output);
+                }
+            }
+        }
+
+        let mut reader = Reader::new(AnyEncoding, ion_data.as_ref())?;
+        let mut materialized = Vec::new();
+        let mut index = 0;
+        while let Some(value) = reader.next()? {
+            visit(value, &mut index, &mut materialized)?;
+        }
+        let mut all_values = Vec::new();
+        for element in &expected {
+            flatten(element, &mut all_values);
+        }
+        assert_eq!(index, all_values.len());
+        let expected_every_third: Vec<Element> = all_values.into_iter().step_by(3).collect();
+        assert_eq!(materialized, expected_every_third);
+        Ok(())
+    }
+
+    /// Returns `true` if `text` is a slice of `input` rather than a copy of its contents.
+    fn is_borrowed_from(text: &str, input: &[u8]) -> bool {
+        input.as_ptr_range().contains(&text.as_ptr())
+    }
+
+    #[rstest]
+    #[case::text(v1_0::Text)]
+    #[case::binary(v1_0::Binary)]
+    fn strings_are_borrowed_from_input<E: Encoding + Into<WriteConfig<E>>>(
+        #[case] encoding: E,
+    ) -> IonResult<()> {
+        let ion_data = Element::read_all(r#""hello" {greeting: "hi"}"#)?.encode_as(encoding)?;
+        let input: &[u8] = ion_data.as_ref();
+        let mut reader = Reader::new(AnyEncoding, input)?;
+
+        let hello = reader.expect_next()?.read()?.expect_string()?;
+        assert_eq!(hello, "hello");
+        assert_eq!("hello", hello);
+        assert!(is_borrowed_from(hello.text(), input));
+
+        let greeting = reader.expect_next()?.read()?.expect_struct()?;
+        let hi = greeting.get_expected("greeting")?.expect_string()?;
+        assert_eq!(hi, "hi");
+        assert!(is_borrowed_from(hi.text(), input));
+        Ok(())
+    }
+
+    #[test]
+    fn escaped_text_strings_are_not_borrowed_from_input() -> IonResult<()> {
+        let input = r#""tab\tseparated""#.as_bytes();
+        let mut reader = Reader::new(v1_0::Text, input)?;
+        let text = reader.expect_next()?.read()?.expect_string()?;
+        assert_eq!(text, "tab\tseparated");
+        assert!(!is_borrowed_from(text.text(), input));
+        Ok(())
+    }
+
+    #[test]
+    fn symbols_are_borrowed_from_symbol_table() -> IonResult<()> {
+        let input = to_binary_ion("foo::foo {foo: foo}")?;
+        let mut reader = Reader::new(v1_0::Binary, input.as_slice())?;
+        let value = reader.expect_next()?;
+        let table_text = value.symbol_table().text_for(10).unwrap();
+        let symbol = value.read()?.expect_symbol()?;
+        let annotation = value.annotations().next().unwrap()?;
+        // Each resolved symbol refers to the text stored in the symbol table rather than a copy.
+        for text in [symbol.text().unwrap(), annotation.text().unwrap()] {
+            assert_eq!(text, "foo");
+            assert_eq!(text.as_ptr(), table_text.as_ptr());
+        }
+        let value = reader.expect_next()?;
+        let table_text = value.symbol_table().text_for(10).unwrap();
+        let strukt = value.read()?.expect_struct()?;
+        let field = strukt.iter().next().unwrap()?;
+        let field_name = field.name()?.text().unwrap();
+        let field_value = field.value().read()?.expect_symbol()?.text().unwrap();
+        assert_eq!(field_name.as_ptr(), table_text.as_ptr());
+        assert_eq!(field_value.as_ptr(), table_text.as_ptr());
+        Ok(())
+    }
 
     #[test]
     fn annotations_are() -> IonResult<()> {
@@ -436,6 +1250,106 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::i128_max("170141183460469231731687303715884105727")]
+    #[case::negative_i128_max("-170141183460469231731687303715884105727")]
+    #[case::i128_min("-170141183460469231731687303715884105728")]
+    #[case::u64_max_plus_one("18446744073709551616")]
+    #[case::negative_u64_max("-18446744073709551615")]
+    fn read_wide_ints(#[case] ion_text: &str) -> IonResult<()> {
+        let expected = ion_text.parse::<i128>().unwrap();
+        for ion_data in [ion_text.as_bytes().to_vec(), to_binary_ion(ion_text)?] {
+            let mut reader = Reader::new(AnyEncoding, ion_data)?;
+            let value = reader.expect_next()?;
+            let int = value.read()?.expect_int()?;
+            assert_eq!(int.expect_i128()?, expected);
+            assert!(value.read()?.expect_i64().is_err());
+            assert!(value.read()?.expect_u64().is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_i128_min_from_binary_magnitude() -> IonResult<()> {
+        // A negative int (type code 3) with a 16-byte magnitude of 2^127.
+        let mut ion_data = vec![0xE0, 0x01, 0x00, 0xEA, 0x3E, 0x90, 0x80];
+        ion_data.extend_from_slice(&[0u8; 15]);
+        let mut reader = Reader::new(v1_0::Binary, ion_data)?;
+        let int = reader.expect_next()?.read()?.expect_int()?;
+        assert_eq!(int.expect_i128()?, i128::MIN);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::i128_max_plus_one("170141183460469231731687303715884105728")]
+    #[case::i128_min_minus_one("-170141183460469231731687303715884105729")]
+    #[case::u128_max_plus_one("340282366920938463463374607431768211456")]
+    #[case::hex("-0x1_0000_0000_0000_0000_0000_0000_0000_0000_0000")]
+    #[case::many_digits("123456789012345678901234567890123456789012345678901234567890")]
+    fn read_ints_beyond_i128_from_text(#[case] ion_text: &str) -> IonResult<()> {
+        let digits = ion_text.replace('_', "");
+        let (radix, digits) = match digits.split_once("0x") {
+            Some((sign, hex_digits)) => (16, format!("{sign}{hex_digits}")),
+            None => (10, digits),
+        };
+        let expected = BigInt::parse_bytes(digits.as_bytes(), radix).unwrap();
+        // Read the text and then its binary re-encoding, which exercises the binary writer.
+        for ion_data in [ion_text.as_bytes().to_vec(), to_binary_ion(ion_text)?] {
+            let mut reader = Reader::new(AnyEncoding, ion_data)?;
+            let int = reader.expect_next()?.read()?.expect_int()?;
+            assert_eq!(int.as_i128(), None);
+            assert_eq!(BigInt::from(int), expected);
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::positive_2_pow_127(
+        {
+            // A positive int (type code 2) with a 16-byte magnitude of 2^127.
+            let mut bytes = vec![0xE0, 0x01, 0x00, 0xEA, 0x2E, 0x90, 0x80];
+            bytes.extend_from_slice(&[0u8; 15]);
+            bytes
+        },
+        "170141183460469231731687303715884105728"
+    )]
+    #[case::negative_17_byte_magnitude(
+        {
+            // A negative int (type code 3) with a 17-byte magnitude of 2^128.
+            let mut bytes = vec![0xE0, 0x01, 0x00, 0xEA, 0x3E, 0x91, 0x01];
+            bytes.extend_from_slice(&[0u8; 16]);
+            bytes
+        },
+        "-340282366920938463463374607431768211456"
+    )]
+    fn read_ints_beyond_i128_from_binary(
+        #[case] ion_data: Vec<u8>,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let expected = BigInt::parse_bytes(expected.as_bytes(), 10).unwrap();
+        let mut reader = Reader::new(v1_0::Binary, ion_data)?;
+        let int = reader.expect_next()?.read()?.expect_int()?;
+        assert_eq!(int.as_i128(), None);
+        assert_eq!(BigInt::from(int), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::zero("0", Some(0))]
+    #[case::u64_max("18446744073709551615", Some(u64::MAX))]
+    #[case::u64_max_plus_one("18446744073709551616", None)]
+    #[case::negative("-1", None)]
+    fn read_u64(#[case] ion_text: &str, #[case] expected: Option<u64>) -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Binary, to_binary_ion(ion_text)?)?;
+        let value = reader.expect_next()?;
+        assert_eq!(value.read()?.expect_u64().ok(), expected);
+        assert_eq!(
+            value.read()?.expect_usize().ok(),
+            expected.and_then(|i| usize::try_from(i).ok())
+        );
+        Ok(())
+    }
+
     fn lazy_value_equals(ion_text: &str, expected: impl Into<Element>) -> IonResult<()> {
         let binary_ion = to_binary_ion(ion_text)?;
         let mut reader = Reader::new(v1_0::Binary, binary_ion)?;
@@ -517,4 +1431,750 @@ mod tests {
         assert!(matches!(result, Err(crate::IonError::Incomplete(_))));
         Ok(())
     }
+
+    /// Encodes `1` in binary Ion 1.0 with `count` copies of the annotation `$4` (`name`).
+    /// The binary writer will not produce an annotations sequence longer than 255 bytes, so the
+    /// value is encoded by hand.
+    fn binary_value_with_annotations(count: usize) -> IonResult<Vec<u8>> {
+        let mut annotations_sequence = Vec::new();
+        VarUInt::write_u64(&mut annotations_sequence, count as u64)?;
+        annotations_sequence.extend(std::iter::repeat(0x84u8).take(count));
+        let mut binary_ion = vec![0xE0, 0x01, 0x00, 0xEA, 0xEE];
+        VarUInt::write_u64(&mut binary_ion, annotations_sequence.len() as u64 + 2)?;
+        binary_ion.extend(annotations_sequence);
+        binary_ion.extend([0x21, 0x01]);
+        Ok(binary_ion)
+    }
+
+    #[test]
+    fn max_annotations_per_value() -> IonResult<()> {
+        // The binary reader rejects annotations sequences longer than 255 bytes and the text
+        // reader rejects those longer than 65KB, so these hostile values fail regardless of the
+        // configured limit.
+        let mut reader = Reader::new(AnyEncoding, binary_value_with_annotations(100_000)?)?;
+        assert!(reader.expect_next().and_then(Element::try_from).is_err());
+        let text_ion = format!("{}1", "name::".repeat(100_000));
+        let mut reader = Reader::new(AnyEncoding, text_ion)?;
+        assert!(reader.expect_next().and_then(Element::try_from).is_err());
+
+        // Values with fewer annotations are only rejected if a limit is configured.
+        let binary_ion = binary_value_with_annotations(200)?;
+        let text_ion = format!("{}1", "name::".repeat(10_000));
+        let inputs: [&[u8]; 2] = [binary_ion.as_slice(), text_ion.as_bytes()];
+        for input in inputs {
+            // By default, there is no limit.
+            let mut reader = Reader::new(AnyEncoding, input)?;
+            assert!(Element::try_from(reader.expect_next()?).is_ok());
+
+            let config = ReadConfig::from(AnyEncoding).with_max_annotations_per_value(32);
+            let mut reader = Reader::new(config, input)?;
+            let value = reader.expect_next()?;
+            // The first 32 annotations can be read. Reading the 33rd fails without visiting the rest.
+            let mut annotations = value.annotations();
+            for _ in 0..32 {
+                assert_eq!(annotations.next().unwrap()?, "name");
+            }
+            let error = annotations.next().unwrap().unwrap_err();
+            assert!(error.to_string().contains("maximum of 32"), "{error}");
+            let IonError::Decoding(decoding_error) = error else {
+                panic!("expected a decoding error, found {error:?}");
+            };
+            assert!(decoding_error.position().is_some());
+            // Materializing the value fails in the same way.
+            assert!(Element::try_from(value).is_err());
+        }
+
+        // Values within the limit are unaffected.
+        let config = ReadConfig::from(v1_0::Text).with_max_annotations_per_value(2);
+        let mut reader = Reader::new(config, "foo::bar::1 baz::2 3")?;
+        assert_eq!(
+            reader.read_all_elements()?,
+            Element::read_all("foo::bar::1 baz::2 3")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn value_ranges_text() -> IonResult<()> {
+        let ion = r#"1 foo::bar::"hi" {a: [true, (2 3)], b: baz::null}"#;
+        let text_at = |value: LazyValue<'_, AnyEncoding>| {
+            let range = value.range().unwrap();
+            assert_eq!(value.span().unwrap().bytes(), ion[range.clone()].as_bytes());
+            &ion[range]
+        };
+        let mut reader = Reader::new(AnyEncoding, ion)?;
+        assert_eq!(reader.expect_next()?.range(), Some(0..1));
+        assert_eq!(text_at(reader.expect_next()?), r#"foo::bar::"hi""#);
+
+        let struct_value = reader.expect_next()?;
+        assert_eq!(text_at(struct_value), "{a: [true, (2 3)], b: baz::null}");
+        let strukt = struct_value.read()?.expect_struct()?;
+        let list_value = strukt.find_expected("a")?;
+        assert_eq!(text_at(list_value), "[true, (2 3)]");
+        let children: Vec<_> = list_value
+            .read()?
+            .expect_list()?
+            .iter()
+            .collect::<IonResult<_>>()?;
+        assert_eq!(text_at(children[0]), "true");
+        assert_eq!(text_at(children[1]), "(2 3)");
+        let grandchildren: Vec<_> = children[1]
+            .read()?
+            .expect_sexp()?
+            .iter()
+            .map(|child| child.map(text_at))
+            .collect::<IonResult<_>>()?;
+        assert_eq!(grandchildren, ["2", "3"]);
+        assert_eq!(text_at(strukt.find_expected("b")?), "baz::null");
+        Ok(())
+    }
+
+    #[test]
+    fn value_ranges_binary() -> IonResult<()> {
+        #[rustfmt::skip]
+        let ion: [u8; 16] = [
+            0xE0, 0x01, 0x00, 0xEA,                   // IVM
+            0xB6, 0x21, 0x01, 0x83, 0x74, 0x77, 0x6F, // [1, "two"]
+            0xE4, 0x81, 0x84, 0x21, 0x05,             // $4::5
+        ];
+        let mut reader = Reader::new(AnyEncoding, ion.as_slice())?;
+        let list_value = reader.expect_next()?;
+        assert_eq!(list_value.range(), Some(4..11));
+        assert_eq!(list_value.span().unwrap().bytes(), &ion[4..11]);
+        let ranges: Vec<_> = list_value
+            .read()?
+            .expect_list()?
+            .iter()
+            .map(|child| child.map(|child| child.range().unwrap()))
+            .collect::<IonResult<_>>()?;
+        assert_eq!(ranges, [5..7, 7..11]);
+        // The range of an annotated value includes its annotations wrapper.
+        assert_eq!(reader.expect_next()?.range(), Some(11..16));
+        Ok(())
+    }
+
+    #[test]
+    fn value_ranges_e_expressions() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((macro_table (macro shout (x) [x, (make_string x "!")])))
+            (:shout "hi")
+            (:values 1 (:make_string "a" "b"))
+        "#;
+        let range_of = |text: &str| {
+            let start = ion.find(text).unwrap();
+            start..start + text.len()
+        };
+        let mut reader = Reader::new(AnyEncoding, ion)?;
+
+        // A value produced by a template reports the range of the e-expression...
+        let list_value = reader.expect_next()?;
+        assert_eq!(list_value.range(), Some(range_of(r#"(:shout "hi")"#)));
+        let children: Vec<_> = list_value
+            .read()?
+            .expect_list()?
+            .iter()
+            .collect::<IonResult<_>>()?;
+        // ...but an argument that was passed through reports its own range...
+        assert_eq!(children[0].range(), Some(range_of(r#""hi""#)));
+        // ...and a value constructed by a macro in the template reports the e-expression's range.
+        assert_eq!(children[1].read()?.expect_string()?, "hi!");
+        assert_eq!(children[1].range(), Some(range_of(r#"(:shout "hi")"#)));
+
+        let one = range_of("1 (:make_string").start;
+        assert_eq!(reader.expect_next()?.range(), Some(one..one + 1));
+        let constructed = reader.expect_next()?;
+        assert_eq!(constructed.read()?.expect_string()?, "ab");
+        assert_eq!(
+            constructed.range(),
+            Some(range_of(r#"(:make_string "a" "b")"#))
+        );
+        assert_eq!(
+            constructed.span().unwrap().bytes(),
+            ion[constructed.range().unwrap()].as_bytes()
+        );
+        Ok(())
+    }
+
+    /// Returns a binary Ion 1.0 stream containing a single value: `depth` nested lists.
+    fn binary_nested_lists(depth: usize) -> IonResult<Vec<u8>> {
+        let mut value = vec![0xB0]; // []
+        for _ in 1..depth {
+            let mut list = Vec::new();
+            if value.len() < 14 {
+                list.push(0xB0 | value.len() as u8);
+            } else {
+                list.push(0xBE);
+                VarUInt::write_u64(&mut list, value.len() as u64)?;
+            }
+            list.extend(value);
+            value = list;
+        }
+        let mut binary_ion = vec![0xE0, 0x01, 0x00, 0xEA];
+        binary_ion.extend(value);
+        Ok(binary_ion)
+    }
+
+    fn nesting_depth(element: &Element) -> usize {
+        let mut depth = 0;
+        let mut current = Some(element);
+        while let Some(element) = current {
+            current = if let Some(sequence) = element.as_sequence() {
+                sequence.get(0)
+            } else if let Some(struct_) = element.as_struct() {
+                struct_.fields().next().map(|(_name, value)| value)
+            } else {
+                break;
+            };
+            depth += 1;
+        }
+        depth
+    }
+
+    #[test]
+    fn max_depth() -> IonResult<()> {
+        let deep = binary_nested_lists(2_000)?;
+        // By default, containers may be nested 128 deep.
+        let error = Reader::new(AnyEncoding, deep.as_slice())?
+            .read_one_element()
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("maximum nesting depth of 128"),
+            "{error}"
+        );
+        let IonError::Decoding(decoding_error) = error else {
+            panic!("expected a decoding error, found {error:?}");
+        };
+        // The 129th list begins 128 one-byte-or-longer headers into the stream.
+        assert!(decoding_error.position().unwrap().byte_offset() > 128);
+
+        let element = Reader::new(AnyEncoding, binary_nested_lists(128)?)?.read_one_element()?;
+        assert_eq!(nesting_depth(&element), 128);
+
+        // The limit can be raised.
+        let config = ReadConfig::from(AnyEncoding).with_max_depth(2_000);
+        let element = Reader::new(config, deep.as_slice())?.read_one_element()?;
+        assert_eq!(nesting_depth(&element), 2_000);
+        Ok(())
+    }
+
+    fn binary_1_1_nested_lists(depth: usize) -> IonResult<Vec<u8>> {
+        let mut value = vec![0xB0]; // []
+        for _ in 1..depth {
+            let mut list = Vec::new();
+            if value.len() < 16 {
+                list.push(0xB0 | value.len() as u8);
+            } else {
+                list.push(0xFB);
+                FlexUInt::write(&mut list, value.len() as u64)?;
+            }
+            list.extend(value);
+            value = list;
+        }
+        let mut binary_ion = vec![0xE0, 0x01, 0x01, 0xEA];
+        binary_ion.extend(value);
+        Ok(binary_ion)
+    }
+
+    /// Surrounds `innermost` with `depth` copies of `open` and `close`.
+    fn nested(ivm: &[u8], open: &[u8], innermost: &[u8], close: &[u8], depth: usize) -> Vec<u8> {
+        let mut data = ivm.to_vec();
+        data.extend(open.repeat(depth));
+        data.extend(innermost);
+        data.extend(close.repeat(depth));
+        data
+    }
+
+    #[rstest]
+    #[case::text_1_0_lists(|depth| Ok(nested(b"", b"[", b"0", b"]", depth)))]
+    #[case::text_1_0_sexps(|depth| Ok(nested(b"", b"(", b"0", b")", depth)))]
+    #[case::text_1_0_structs(|depth| Ok(nested(b"", b"{a:", b"0", b"}", depth)))]
+    #[case::text_1_1_lists(|depth| Ok(nested(b"$ion_1_1 ", b"[", b"0", b"]", depth)))]
+    #[case::text_1_1_sexps(|depth| Ok(nested(b"$ion_1_1 ", b"(", b"0", b")", depth)))]
+    #[case::text_1_1_structs(|depth| Ok(nested(b"$ion_1_1 ", b"{a:", b"0", b"}", depth)))]
+    #[case::binary_1_0_lists(binary_nested_lists)]
+    #[case::binary_1_1_lists(binary_1_1_nested_lists)]
+    #[case::binary_1_1_delimited_lists(|depth| Ok(nested(&[0xE0, 0x01, 0x01, 0xEA], &[0xF1], &[0xB0], &[0xF0], depth - 1)))]
+    #[case::binary_1_1_delimited_sexps(|depth| Ok(nested(&[0xE0, 0x01, 0x01, 0xEA], &[0xF2], &[0xC0], &[0xF0], depth - 1)))]
+    fn default_max_depth_fits_on_a_small_stack(
+        #[case] nested_containers: fn(usize) -> IonResult<Vec<u8>>,
+    ) -> IonResult<()> {
+        let max_depth = ReadOptions::DEFAULT_MAX_DEPTH;
+        let at_limit = nested_containers(max_depth)?;
+        let too_deep = nested_containers(max_depth + 1)?;
+        // Text and delimited binary containers are matched recursively, and unoptimized builds
+        // use far more stack per level than release builds do. Reading on a thread with a small
+        // stack catches matchers whose frames have grown too large for the default limit.
+        std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(move || -> IonResult<()> {
+                let element = Reader::new(AnyEncoding, at_limit)?.read_one_element()?;
+                assert_eq!(nesting_depth(&element), max_depth);
+                let error = Reader::new(AnyEncoding, too_deep)?
+                    .read_one_element()
+                    .unwrap_err();
+                assert!(
+                    error.to_string().contains("maximum nesting depth of 128"),
+                    "{error}"
+                );
+                Ok(())
+            })
+            .unwrap()
+            .join()
+            .unwrap()
+    }
+
+    #[test]
+    fn max_depth_binary_1_1_delimited() -> IonResult<()> {
+        // Delimited containers are read eagerly, so the limit is enforced while the reader is
+        // finding the end of the top-level value rather than when it is materialized.
+        let deep = nested(&[0xE0, 0x01, 0x01, 0xEA], &[0xF1], &[], &[0xF0], 1_000_000);
+        let mut reader = Reader::new(AnyEncoding, deep)?;
+        let error = reader.next().unwrap_err();
+        assert!(
+            error.to_string().contains("maximum nesting depth of 128"),
+            "{error}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn max_depth_does_not_limit_width() -> IonResult<()> {
+        let ints = (0..100_000).map(|i| i.to_string()).collect::<Vec<_>>();
+        let ion_text = format!(
+            "[{}] {{a: 1, b: [2, 3], c: {{d: 4}}}} ({})",
+            ints.join(", "),
+            ints.join(" ")
+        );
+        let config = ReadConfig::from(AnyEncoding).with_max_depth(2);
+        let actual = Reader::new(config, ion_text.as_str())?.read_all_elements()?;
+        assert_eq!(actual, Element::read_all(ion_text)?);
+        assert_eq!(actual.get(0).unwrap().as_sequence().unwrap().len(), 100_000);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text_1_0("")]
+    #[case::text_1_1("$ion_1_1 ")]
+    fn max_depth_text(#[case] ivm: &str) -> IonResult<()> {
+        // Each top-level value is checked as soon as the reader matches it.
+        let depth_10 = "[(({a: [{b: ([[[1]]])}]}))]";
+        let depth_11 = "[(({a: [{b: ([[[[1]]]])}]}))]";
+        let config = ReadConfig::from(AnyEncoding).with_max_depth(10);
+        let mut reader = Reader::new(config, format!("{ivm}{depth_10} {depth_11}"))?;
+        let element = Element::try_from(reader.expect_next()?)?;
+        assert_eq!(element, Element::read_one(depth_10)?);
+        let error = reader.next().unwrap_err();
+        assert!(
+            error.to_string().contains("maximum nesting depth of 10"),
+            "{error}"
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::adjacent("foo::foo::1", true)]
+    #[case::separated("foo::bar::foo::1", true)]
+    #[case::unknown_text("$0::$0::1", true)]
+    #[case::container("foo::foo::[1, 2, 3]", true)]
+    #[case::distinct("foo::bar::baz::1", false)]
+    #[case::many_distinct("a::b::c::d::e::f::g::h::i::j::k::l::1", false)]
+    #[case::many_late_duplicate("a::b::c::d::e::f::g::h::i::j::k::a::1", true)]
+    #[case::many_early_duplicate("a::b::c::d::a::f::g::h::i::j::k::l::1", true)]
+    fn reject_duplicate_annotations(
+        #[case] ion_text: &str,
+        #[case] has_duplicates: bool,
+    ) -> IonResult<()> {
+        // Duplicate annotations are permitted by default.
+        let mut reader = Reader::new(v1_0::Text, ion_text)?;
+        assert!(Element::try_from(reader.expect_next()?).is_ok());
+
+        let config = ReadConfig::from(v1_0::Text).with_reject_duplicate_annotations(true);
+        let mut reader = Reader::new(config, ion_text)?;
+        let result = Element::try_from(reader.expect_next()?);
+        assert_eq!(result.is_err(), has_duplicates, "{result:?}");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("1", "foo", false)]
+    #[case::first_match("foo::bar::baz::1", "foo", true)]
+    #[case::late_match("foo::bar::baz::1", "baz", true)]
+    #[case::no_match("foo::bar::baz::1", "quux", false)]
+    #[case::unknown_text("$0::foo::1", "foo", true)]
+    #[case::only_unknown_text("$0::1", "$0", false)]
+    fn has_annotation(
+        #[case] ion_text: &str,
+        #[case] annotation: &str,
+        #[case] expected: bool,
+    ) -> IonResult<()> {
+        let binary_ion = to_binary_ion(ion_text)?;
+        for input in [ion_text.as_bytes(), binary_ion.as_slice()] {
+            let mut reader = Reader::new(AnyEncoding, input)?;
+            let value = reader.expect_next()?;
+            assert_eq!(value.has_annotation(annotation)?, expected, "{ion_text}");
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("1", &[], true)]
+    #[case::empty_vs_some("1", &["foo"], false)]
+    #[case::exact("foo::bar::1", &["foo", "bar"], true)]
+    #[case::prefix("foo::bar::1", &["foo"], false)]
+    #[case::superset("foo::bar::1", &["foo", "bar", "baz"], false)]
+    #[case::reordered("foo::bar::1", &["bar", "foo"], false)]
+    #[case::unknown_text("$0::bar::1", &["$0", "bar"], false)]
+    fn annotations_eq(
+        #[case] ion_text: &str,
+        #[case] annotations: &[&str],
+        #[case] expected: bool,
+    ) -> IonResult<()> {
+        let binary_ion = to_binary_ion(ion_text)?;
+        for input in [ion_text.as_bytes(), binary_ion.as_slice()] {
+            let mut reader = Reader::new(AnyEncoding, input)?;
+            let value = reader.expect_next()?;
+            assert_eq!(
+                value.annotations_eq(annotations.iter().copied())?,
+                expected,
+                "{ion_text}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn annotation_helpers_resolve_symbol_ids() -> IonResult<()> {
+        // Each annotation is encoded as a symbol ID that refers to the local symbol table.
+        let ion_text = r#"
+            $ion_symbol_table::{symbols: ["foo", "bar"]}
+            $10::$11::1
+        "#;
+        let binary_ion = to_binary_ion("foo::bar::1")?;
+        for input in [ion_text.as_bytes(), binary_ion.as_slice()] {
+            let mut reader = Reader::new(AnyEncoding, input)?;
+            let value = reader.expect_next()?;
+            assert!(value
+                .annotations()
+                .symbol_ids()
+                .all(|sid| sid.unwrap().is_some()));
+            assert!(value.has_annotation("bar")?);
+            assert!(value.annotations_eq(["foo", "bar"])?);
+            assert!(!value.annotations_eq(["$10", "$11"])?);
+            value.expect_annotation("foo")?;
+            let error = value.expect_annotation("baz").unwrap_err();
+            assert!(error.to_string().contains("[foo, bar]"), "{error}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn annotation_helpers_reject_undefined_symbol_ids() -> IonResult<()> {
+        // $99 is not defined in the active symbol table, so the helpers cannot say whether the
+        // value has a given annotation.
+        let mut reader = Reader::new(AnyEncoding, "foo::$99::1")?;
+        let value = reader.expect_next()?;
+        assert!(value.has_annotation("foo")?);
+        assert!(value.has_annotation("bar").is_err());
+        assert!(value.annotations_eq(["foo", "bar"]).is_err());
+        assert!(value.expect_annotation("bar").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn has_annotation_short_circuits() -> IonResult<()> {
+        // With a limit of one annotation per value, visiting the second annotation is an error.
+        // Finding a match in the first annotation means the second is never visited.
+        let ion_text = "foo::bar::1";
+        let binary_ion = to_binary_ion(ion_text)?;
+        for input in [ion_text.as_bytes(), binary_ion.as_slice()] {
+            let config = ReadConfig::from(AnyEncoding).with_max_annotations_per_value(1);
+            let mut reader = Reader::new(config, input)?;
+            let value = reader.expect_next()?;
+            assert!(value.has_annotation("foo")?);
+            assert!(!value.annotations_eq(["quux", "bar"])?);
+            assert!(value.has_annotation("bar").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unannotated_values_do_no_annotation_work() -> IonResult<()> {
+        // Materializing an annotation consults the reader's symbol cache. Unannotated scalars
+        // should never reach it.
+        let ion_text = "1 2.5 \"three\" four 2024T null [5, 6] (7 8)";
+        let binary_ion = to_binary_ion(ion_text)?;
+        for input in [ion_text.as_bytes(), binary_ion.as_slice()] {
+            let mut reader = Reader::new(AnyEncoding, input)?;
+            while let Some(value) = reader.next()? {
+                assert!(!value.has_annotations());
+                assert!(!value.has_annotation("four")?);
+                assert!(value.annotations_eq([])?);
+                let element = Element::try_from(value)?;
+                assert!(element.annotations().is_empty());
+            }
+            let stats = reader.symbol_cache_stats();
+            assert_eq!(stats.hits() + stats.misses(), 0, "{stats:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn strings_and_lobs_are_borrowed_from_input() -> IonResult<()> {
+        // Reading a string or lob whose encoding needs no escape processing hands out a slice of
+        // the input buffer, so predicates can inspect the bytes without copying them.
+        let ion_text = r#""hello" {{aGVsbG8=}} {{"hello"}}"#;
+        let binary_ion = to_binary_ion(ion_text)?;
+        for (input, expect_borrowed_lobs) in
+            [(ion_text.as_bytes(), false), (binary_ion.as_slice(), true)]
+        {
+            let input_range = input.as_ptr_range();
+            let mut reader = Reader::new(AnyEncoding, input)?;
+            let string = reader.expect_next()?.read()?.expect_string()?;
+            assert!(input_range.contains(&string.text().as_ptr()));
+            assert_eq!(string.text().as_bytes(), b"hello");
+            for _ in 0..2 {
+                let lob = reader.expect_next()?.read()?;
+                let bytes = lob.expect_lob()?;
+                assert_eq!(bytes.data(), b"hello");
+                assert_eq!(
+                    input_range.contains(&bytes.data().as_ptr()),
+                    expect_borrowed_lobs
+                );
+            }
+        }
+
+        // Text that contains escapes has to be decoded first.
+        let escaped = r#""h\x65llo""#;
+        let mut reader = Reader::new(AnyEncoding, escaped.as_bytes())?;
+        let string = reader.expect_next()?.read()?.expect_string()?;
+        assert_eq!(string.text(), "hello");
+        assert!(!escaped
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&string.text().as_ptr()));
+        Ok(())
+    }
+
+    // The values written are: 0e0, 1.5e0, -0e0, 0.1e0, and nan. The Ion 1.0 binary writer always
+    // uses 8 bytes for nan, while the Ion 1.1 binary writer uses 4.
+    #[rstest]
+    #[case::binary_1_0(v1_0::Binary, &[0, 4, 4, 8, 8])]
+    #[case::text_1_0(v1_0::Text, &[8, 8, 8, 8, 8])]
+    #[case::binary_1_1(v1_1::Binary, &[0, 4, 4, 8, 4])]
+    #[case::text_1_1(v1_1::Text, &[8, 8, 8, 8, 8])]
+    fn float_size<E: Encoding + Into<WriteConfig<E>>>(
+        #[case] encoding: E,
+        #[case] expected_sizes: &[usize],
+    ) -> IonResult<()> {
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        writer
+            .write(0f64)?
+            .write(1.5f64)?
+            .write(-0f64)?
+            .write(0.1f64)?
+            .write(f64::NAN)?
+            .write(Null(IonType::Float))?
+            .write(1)?;
+        let data = writer.close()?;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        for expected_size in expected_sizes {
+            assert_eq!(reader.expect_next()?.float_size(), Some(*expected_size));
+        }
+        // Null floats and non-float values don't have a float size.
+        assert_eq!(reader.expect_next()?.float_size(), None);
+        assert_eq!(reader.expect_next()?.float_size(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn read_f32() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "1.5e0 0.1e0 nan +inf 1e300 null.float 5")?;
+        assert_eq!(reader.expect_next()?.read_f32()?, 1.5f32);
+        // 0.1 can't be represented exactly in an f32
+        assert!(reader.expect_next()?.read_f32().is_err());
+        assert!(reader.expect_next()?.read_f32()?.is_nan());
+        assert_eq!(reader.expect_next()?.read_f32()?, f32::INFINITY);
+        assert!(reader.expect_next()?.read_f32().is_err());
+        assert!(reader.expect_next()?.read_f32().is_err());
+        assert!(reader.expect_next()?.read_f32().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn transcribing_floats_preserves_encoding_sizes() -> IonResult<()> {
+        // Write a mix of 0-, 4-, and 8-byte floats.
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer
+            .write(0.1f32)?
+            .write(0.1f64)?
+            .write(0f64)?
+            .write(-0f32)?
+            .write(f64::consts::PI)?
+            .write(f32::consts::PI)?
+            .write(f64::NEG_INFINITY)?;
+        let original = writer.close()?;
+
+        // Transcribe the stream, writing each float using the size it was read with.
+        let mut reader = Reader::new(v1_0::Binary, original.as_slice())?;
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        while let Some(value) = reader.next()? {
+            let float = value.read()?.expect_float()?;
+            match value.float_size() {
+                Some(8) => writer.write(float)?,
+                _ => writer.write(value.read_f32()?)?,
+            };
+        }
+        let transcribed = writer.close()?;
+        assert_eq!(transcribed, original);
+        Ok(())
+    }
+
+    /// What the lazy reader reported for a value visited by [`walk_by_depth`]: its depth, the type
+    /// of its parent container, the name of the field it was found in (if any), and its own type.
+    type Visit = (usize, Option<IonType>, Option<Symbol>, IonType);
+
+    /// Visits every value in `reader`'s stream depth-first using the lazy iteration handles,
+    /// recording what the depth, parent type, and field name accessors report for each value.
+    fn walk_by_depth<D: Decoder>(reader: &mut Reader<D, &[u8]>) -> IonResult<Vec<Visit>> {
+        fn walk<D: Decoder>(
+            value: LazyValue<'_, D>,
+            field_name: Option<Symbol>,
+            visits: &mut Vec<Visit>,
+        ) -> IonResult<()> {
+            visits.push((
+                value.depth(),
+                value.parent_type(),
+                field_name,
+                value.ion_type(),
+            ));
+            match value.read()? {
+                ValueRef::List(list) => {
+                    let mut values = list.iter();
+                    assert_eq!(values.depth(), value.depth() + 1);
+                    while let Some(child) = values.next().transpose()? {
+                        assert_eq!(child.depth(), values.depth());
+                        assert_eq!(child.parent_type(), values.parent_type());
+                        walk(child, None, visits)?;
+                    }
+                }
+                ValueRef::SExp(sexp) => {
+                    let mut values = sexp.iter();
+                    assert_eq!(values.depth(), value.depth() + 1);
+                    while let Some(child) = values.next().transpose()? {
+                        assert_eq!(child.depth(), values.depth());
+                        assert_eq!(child.parent_type(), values.parent_type());
+                        walk(child, None, visits)?;
+                    }
+                }
+                ValueRef::Struct(struct_) => {
+                    let mut fields = struct_.iter();
+                    assert_eq!(fields.depth(), value.depth() + 1);
+                    while let Some(field) = fields.next_field()? {
+                        let child = field.value();
+                        assert_eq!(child.depth(), fields.depth());
+                        assert_eq!(child.parent_type(), fields.parent_type());
+                        walk(child, fields.field_name()?, visits)?;
+                    }
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+
+        let mut visits = Vec::new();
+        while let Some(value) = reader.next()? {
+            walk(value, None, &mut visits)?;
+        }
+        Ok(visits)
+    }
+
+    /// Returns the number of values found at each depth.
+    fn count_by_depth(visits: &[Visit]) -> Vec<usize> {
+        let mut counts = Vec::new();
+        for (depth, ..) in visits {
+            if counts.len() <= *depth {
+                counts.resize(depth + 1, 0);
+            }
+            counts[*depth] += 1;
+        }
+        counts
+    }
+
+    const NESTED_DATA: &str = r#"
+        1 {a: [b, (c {d: e})], f: {}} [] null
+        $ion_symbol_table::{symbols: ["g"]}
+        foo::[{g: h}, [[i]], {'': j}]
+    "#;
+
+    #[rstest]
+    #[case::text_1_0(v1_0::Text)]
+    #[case::binary_1_0(v1_0::Binary)]
+    #[case::text_1_1(v1_1::Text)]
+    #[case::binary_1_1(v1_1::Binary)]
+    fn depth_parent_type_and_field_name<E: Encoding + Into<WriteConfig<E>>>(
+        #[case] encoding: E,
+    ) -> IonResult<()> {
+        let text = NESTED_DATA.as_bytes();
+        let expected = walk_by_depth(&mut Reader::new(v1_0::Text, text)?)?;
+        assert_eq!(count_by_depth(&expected), vec![5, 5, 5, 3, 1]);
+        let g = (
+            2,
+            Some(IonType::Struct),
+            Some(Symbol::from("g")),
+            IonType::Symbol,
+        );
+        assert!(expected.contains(&g));
+        let d = (
+            4,
+            Some(IonType::Struct),
+            Some(Symbol::from("d")),
+            IonType::Symbol,
+        );
+        assert!(expected.contains(&d));
+
+        let ion_data = Element::read_all(NESTED_DATA)?.encode_as(encoding)?;
+        let actual = walk_by_depth(&mut Reader::new(AnyEncoding, ion_data.as_ref())?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn depth_of_template_expansions() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "$ion_1_1 (:pair 1 (:pair 2 3))".as_bytes())?;
+        reader.register_template_src("(macro pair (first second) [first, {x: second}])")?;
+        let expanded = walk_by_depth(&mut reader)?;
+        let text = "[1, {x: [2, {x: 3}]}]".as_bytes();
+        let expected = walk_by_depth(&mut Reader::new(v1_0::Text, text)?)?;
+        assert_eq!(expanded, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn elements_and_fields_iterators_report_nesting() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "{a: (b [c])}")?;
+        let mut fields = reader.expect_next()?.read()?.expect_struct()?.fields();
+        assert_eq!(
+            (fields.depth(), fields.parent_type()),
+            (1, Some(IonType::Struct))
+        );
+        let (_name, sexp) = fields.next().unwrap()?;
+        assert_eq!(fields.field_name()?.unwrap(), "a");
+
+        let mut elements = sexp.expect_sexp()?.elements();
+        assert_eq!(
+            (elements.depth(), elements.parent_type()),
+            (2, Some(IonType::SExp))
+        );
+        elements.next().unwrap()?;
+        let list = elements.next().unwrap()?.expect_list()?;
+        assert_eq!(list.as_value().depth(), 2);
+        assert_eq!(list.as_value().parent_type(), Some(IonType::SExp));
+        let elements = list.elements();
+        assert_eq!(
+            (elements.depth(), elements.parent_type()),
+            (3, Some(IonType::List))
+        );
+        Ok(())
+    }
 }
@@ -6,12 +6,14 @@ use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::str_ref::StrRef;
-use crate::result::IonFailure;
+use crate::lazy::value::Nesting;
+use crate::result::{type_mismatch_error, IonFailure};
 use crate::{
     Decimal, Element, Environment, ExpandedValueRef, Int, IonError, IonResult, IonType,
     LazyExpandedList, LazyExpandedSExp, LazyExpandedStruct, SymbolRef, Timestamp,
 };
 use std::fmt::{Debug, Formatter};
+use std::ops::Range;
 
 /// A [ValueRef] represents a value that has been read from the input stream. Scalar variants contain
 /// their associated data, while container variants contain a handle to traverse the container. (See
@@ -20,7 +22,7 @@ use std::fmt::{Debug, Formatter};
 /// Unlike a [Value], a `ValueRef` avoids heap allocation whenever possible, choosing to point instead
 /// to existing resources. Numeric values and timestamps are stored within the `ValueRef` itself.
 /// Text values and lobs hold references to either a slice of input data or text in the symbol table.
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub enum ValueRef<'top, D: Decoder> {
     Null(IonType),
     Bool(bool),
@@ -115,11 +117,32 @@ impl<'top, D: Decoder> TryFrom<ValueRef<'top, D>> for Element {
 }
 
 impl<'top, D: Decoder> ValueRef<'top, D> {
+    /// Returns the range of input bytes occupied by this value if it is a container. Scalar
+    /// variants hold only the decoded data, so they return `None`.
+    fn container_range(&self) -> Option<Range<usize>> {
+        use ValueRef::*;
+        match self {
+            SExp(s) => s.as_value().range(),
+            List(l) => l.as_value().range(),
+            Struct(s) => s.as_value().range(),
+            _ => None,
+        }
+    }
+
+    fn expected<T>(&self, expected: &str) -> IonResult<T> {
+        Err(type_mismatch_error(
+            expected,
+            self.ion_type(),
+            matches!(self, ValueRef::Null(_)),
+            self.container_range(),
+        ))
+    }
+
     pub fn expect_null(self) -> IonResult<IonType> {
         if let ValueRef::Null(ion_type) = self {
             Ok(ion_type)
         } else {
-            IonResult::decoding_error("expected a null")
+            self.expected("a null")
         }
     }
 
@@ -127,7 +150,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Bool(b) = self {
             Ok(b)
         } else {
-            IonResult::decoding_error("expected a bool")
+            self.expected("a bool")
         }
     }
 
@@ -135,7 +158,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Int(i) = self {
             Ok(i)
         } else {
-            IonResult::decoding_error(format!("expected an int but found a(n) {self:?}"))
+            self.expected("an int")
         }
     }
 
@@ -143,7 +166,27 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Int(i) = self {
             i.expect_i64()
         } else {
-            IonResult::decoding_error(format!("expected an int (i64) but found a(n) {self:?}"))
+            self.expected("an i64 (int)")
+        }
+    }
+
+    /// Like [`expect_i64`](Self::expect_i64), but returns an `Err` if the int is negative or
+    /// too large to fit in a `u64`.
+    pub fn expect_u64(self) -> IonResult<u64> {
+        if let ValueRef::Int(i) = self {
+            i.expect_u64()
+        } else {
+            self.expected("a u64 (int)")
+        }
+    }
+
+    /// Like [`expect_i64`](Self::expect_i64), but returns an `Err` if the int is negative or
+    /// too large to fit in a `usize`.
+    pub fn expect_usize(self) -> IonResult<usize> {
+        if let ValueRef::Int(i) = self {
+            i.expect_usize()
+        } else {
+            self.expected("a usize (int)")
         }
     }
 
@@ -151,7 +194,22 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Float(f) = self {
             Ok(f)
         } else {
-            IonResult::decoding_error("expected a float")
+            self.expected("a float")
+        }
+    }
+
+    /// Like [`expect_float`](Self::expect_float), but also returns an `Err` if the float cannot
+    /// be represented as an `f32` without losing information. NaN and the infinities are
+    /// representable.
+    pub fn expect_f32(self) -> IonResult<f32> {
+        let value = self.expect_float()?;
+        let value_f32 = value as f32;
+        if value.is_nan() || value_f32 as f64 == value {
+            Ok(value_f32)
+        } else {
+            IonResult::decoding_error(format!(
+                "float {value} cannot be represented losslessly as an f32"
+            ))
         }
     }
 
@@ -159,7 +217,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Decimal(d) = self {
             Ok(d)
         } else {
-            IonResult::decoding_error("expected a decimal")
+            self.expected("a decimal")
         }
     }
 
@@ -167,7 +225,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Timestamp(t) = self {
             Ok(t)
         } else {
-            IonResult::decoding_error("expected a timestamp")
+            self.expected("a timestamp")
         }
     }
 
@@ -175,7 +233,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::String(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error("expected a string")
+            self.expected("a string")
         }
     }
 
@@ -183,7 +241,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Symbol(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error(format!("expected a symbol, found {:?}", self))
+            self.expected("a symbol")
         }
     }
 
@@ -194,7 +252,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
             Symbol(symbol) => symbol.text().ok_or_else(|| {
                 IonError::decoding_error("expected text but found a symbol with undefined text")
             }),
-            _ => IonResult::decoding_error("expected a string or symbol"),
+            _ => self.expected("a string or symbol"),
         }
     }
 
@@ -202,7 +260,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Blob(b) = self {
             Ok(b)
         } else {
-            IonResult::decoding_error("expected a blob")
+            self.expected("a blob")
         }
     }
 
@@ -210,7 +268,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Clob(c) = self {
             Ok(c)
         } else {
-            IonResult::decoding_error("expected a clob")
+            self.expected("a clob")
         }
     }
 
@@ -218,7 +276,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         use ValueRef::*;
         match self {
             Blob(b) | Clob(b) => Ok(b),
-            _ => IonResult::decoding_error("expected a blob or clob"),
+            _ => self.expected("a blob or clob"),
         }
     }
 
@@ -226,7 +284,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::List(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error("expected a list")
+            self.expected("a list")
         }
     }
 
@@ -234,7 +292,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::SExp(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error("expected a sexp")
+            self.expected("a sexp")
         }
     }
 
@@ -242,7 +300,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         if let ValueRef::Struct(s) = self {
             Ok(s)
         } else {
-            IonResult::decoding_error(format!("expected a struct but found a(n) {self:?}"))
+            self.expected("a struct")
         }
     }
 
@@ -264,6 +322,18 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         }
     }
 
+    /// If this is a container, returns a copy that reports the provided position in the container
+    /// hierarchy. Scalars are returned unchanged.
+    #[inline]
+    pub(crate) fn with_nesting(self, nesting: Nesting) -> Self {
+        match self {
+            ValueRef::SExp(sexp) => ValueRef::SExp(sexp.with_nesting(nesting)),
+            ValueRef::List(list) => ValueRef::List(list.with_nesting(nesting)),
+            ValueRef::Struct(struct_) => ValueRef::Struct(struct_.with_nesting(nesting)),
+            scalar => scalar,
+        }
+    }
+
     pub(crate) fn from_template(
         context: EncodingContextRef<'top>,
         environment: Environment<'top, D>,
@@ -273,7 +343,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         match element.value() {
             Null(ion_type) => ValueRef::Null(*ion_type),
             Bool(b) => ValueRef::Bool(*b),
-            Int(i) => ValueRef::Int(*i),
+            Int(i) => ValueRef::Int(i.clone()),
             Float(f) => ValueRef::Float(*f),
             Decimal(d) => ValueRef::Decimal(*d),
             Timestamp(t) => ValueRef::Timestamp(*t),
@@ -308,7 +378,7 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
         match self {
             Null(ion_type) => ExpandedValueRef::Null(*ion_type),
             Bool(b) => ExpandedValueRef::Bool(*b),
-            Int(i) => ExpandedValueRef::Int(*i),
+            Int(i) => ExpandedValueRef::Int(i.clone()),
             Float(f) => ExpandedValueRef::Float(*f),
             Decimal(d) => ExpandedValueRef::Decimal(*d),
             Timestamp(t) => ExpandedValueRef::Timestamp(*t),
@@ -325,9 +395,12 @@ impl<'top, D: Decoder> ValueRef<'top, D> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use crate::lazy::binary::test_utilities::to_binary_ion;
+    use crate::lazy::decoder::LazyRawValue;
     use crate::lazy::value_ref::ValueRef;
-    use crate::{v1_0, Decimal, IonResult, IonType, Reader, SymbolRef, Timestamp};
+    use crate::{v1_0, Decimal, Element, IonResult, IonType, Reader, SymbolRef, Timestamp};
 
     #[test]
     fn expect_type() -> IonResult<()> {
@@ -433,4 +506,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case::int("5", "expected a struct, found a(n) int")]
+    #[case::typed_null("null.int", "expected a struct, found a null.int")]
+    #[case::list("[1, 2, 3]", "expected a struct, found a(n) list")]
+    fn type_mismatch_errors_are_consistent(
+        #[case] ion_data: &str,
+        #[case] expected_message: &str,
+    ) -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, ion_data)?;
+        let value = reader.expect_next()?;
+        let raw_error = value.raw().unwrap().read()?.expect_struct().unwrap_err();
+        let expanded_error = value
+            .expanded()
+            .read()?
+            .expect_struct()
+            .map(|_| ())
+            .unwrap_err();
+        let resolved_error = value.read()?.expect_struct().unwrap_err();
+        // Reading the lazy value directly also reports where the value was found.
+        let lazy_error = value.read_struct().map(|_| ()).unwrap_err();
+        let element_error = Element::read_one(ion_data)?.expect_struct().unwrap_err();
+        for error in [
+            raw_error,
+            expanded_error,
+            resolved_error,
+            lazy_error,
+            element_error,
+        ] {
+            assert!(
+                error.to_string().contains(expected_message),
+                "'{error}' did not contain '{expected_message}'"
+            );
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::list("[1, 2, 3]", 0..9)]
+    #[case::sexp("  (1 2 3)", 2..9)]
+    #[case::struct_("1 {a: 1}", 2..8)]
+    fn container_type_mismatches_report_their_range(
+        #[case] ion_data: &str,
+        #[case] expected_range: std::ops::Range<usize>,
+    ) -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, ion_data)?;
+        let mut value = reader.expect_next()?;
+        if value.ion_type() == IonType::Int {
+            value = reader.expect_next()?;
+        }
+        let raw_error = value.raw().unwrap().read()?.expect_int().unwrap_err();
+        let expanded_error = value.expanded().read()?.expect_int().unwrap_err();
+        let resolved_error = value.read()?.expect_int().unwrap_err();
+        let expected_suffix = format!(
+            " at input bytes {}..{}",
+            expected_range.start, expected_range.end
+        );
+        for error in [raw_error, expanded_error, resolved_error] {
+            assert!(
+                error.to_string().ends_with(&expected_suffix),
+                "'{error}' did not end with '{expected_suffix}'"
+            );
+        }
+        Ok(())
+    }
 }
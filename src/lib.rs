@@ -142,19 +142,20 @@ use rstest_reuse;
 pub use catalog::{Catalog, EmptyCatalog, MapCatalog};
 pub use element::builders::{SequenceBuilder, StructBuilder};
 pub use element::{
-    element_writer::ElementWriter, reader::ElementReader, Annotations, Element,
-    IntoAnnotatedElement, IntoAnnotations, Sequence, Value,
+    element_writer::ElementWriter, reader::ElementReader, reader::OwnedElementIterator,
+    Annotations, Element, IntoAnnotatedElement, IntoAnnotations, Sequence, Value,
 };
 pub use ion_data::IonData;
+pub use position::Position;
 
 #[doc(inline)]
-pub use result::{IonError, IonResult};
+pub use result::{DecodingErrorKind, IonError, IonResult};
 pub use shared_symbol_table::SharedSymbolTable;
 pub use symbol_ref::SymbolRef;
 #[doc(inline)]
 pub use types::{
-    decimal::Decimal, Blob, Bytes, Clob, Int, IonType, List, Null, SExp, Str, Struct, Symbol,
-    SymbolId, Timestamp, TimestampPrecision, UInt,
+    decimal::Decimal, Blob, Bytes, Clob, DuplicateFieldPolicy, FieldRemovalPolicy, Int, IonType,
+    List, Null, SExp, Str, Struct, Symbol, SymbolId, Timestamp, TimestampPrecision, UInt,
 };
 // Allow access to less commonly used types like decimal::coefficient::{Coefficient, Sign}
 pub use types::decimal;
@@ -204,8 +205,11 @@ macro_rules! v1_x_reader_writer {
             lazy::encoder::write_as_ion::WriteAsIon,
             lazy::encoder::writer::Writer,
             lazy::reader::Reader,
+            lazy::lob_reader::LobReader,
+            lazy::transcode::transcode,
+            lazy::system_reader::ReaderCheckpoint,
             raw_symbol_ref::RawSymbolRef,
-            symbol_table::SymbolTable,
+            symbol_table::{SymbolTable, SymbolTableDiff, SymbolTableSnapshot},
             lazy::value::LazyValue,
             lazy::value_ref::ValueRef,
             lazy::r#struct::{LazyStruct, LazyField},
@@ -222,6 +226,7 @@ macro_rules! v1_x_reader_writer {
             lazy::expanded::macro_table::MacroKind,
             lazy::expanded::macro_table::MacroTable,
             lazy::expanded::EncodingContext,
+            lazy::expanded::symbol_cache::SymbolCacheStats,
             lazy::any_encoding::IonVersion,
             lazy::binary::raw::reader::LazyRawBinaryReader_1_0,
             lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1,
@@ -233,7 +238,11 @@ macro_rules! v1_x_reader_writer {
     };
 }
 
-pub use crate::write_config::WriteConfig;
+pub use crate::lazy::encoder::value_writer_config::{
+    NewSymbolPolicy, SymbolInterningPolicy, UnknownTextSymbolPolicy,
+};
+pub use crate::read_config::{ReadConfig, UnresolvedSymbolIdPolicy};
+pub use crate::write_config::{LineEnding, WriteConfig};
 
 macro_rules! v1_0_reader_writer {
     ($visibility:vis) => {
@@ -260,6 +269,7 @@ macro_rules! v1_x_tooling_apis {
         $visibility use crate::{
             lazy::raw_stream_item::RawStreamItem,
             lazy::any_encoding::{
+                LazyRawAnyReader,
                 LazyRawAnyVersionMarker, LazyRawAnyVersionMarkerKind,
                 LazyRawAnyValue, LazyRawValueKind,
                 LazyRawAnyList, LazyRawListKind,
@@ -275,6 +285,7 @@ macro_rules! v1_x_tooling_apis {
                 LazyRawFieldName,
                 LazyRawValue,
                 LazyRawReader,
+                RawValueExpr,
                 RawVersionMarker,
                 LazyRawContainer,
             },
@@ -297,7 +308,7 @@ macro_rules! v1_x_tooling_apis {
             lazy::expanded::sequence::{Environment, ExpandedListSource, ExpandedSExpSource, LazyExpandedList, LazyExpandedSExp},
             lazy::expanded::{ExpandedStreamItem, LazyExpandedValue, ExpandingReader, ExpandedValueSource, ExpandedAnnotationsSource, ExpandedValueRef},
             lazy::system_stream_item::SystemStreamItem,
-            lazy::system_reader::{SystemReader},
+            lazy::system_reader::{SymbolTableEvent, SystemReader},
         };
     };
 }
@@ -312,6 +323,7 @@ macro_rules! v1_0_tooling_apis {
             lazy::binary::immutable_buffer::{ImmutableBuffer, AnnotationsWrapper},
             lazy::binary::raw::type_descriptor::Header,
             lazy::raw_value_ref::RawValueRef,
+            lazy::text::raw::reader::LazyRawTextReader_1_0 as LazyRawTextReader,
             lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0 as RawBinaryWriter,
             lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0 as RawTextWriter,
             lazy::binary::raw::sequence::{
@@ -376,6 +388,7 @@ pub mod v1_0 {
     #[cfg(not(feature = "experimental-reader-writer"))]
     v1_0_reader_writer!(pub(crate));
 
+    pub use crate::lazy::encoder::binary::v1_0::encoded_length::UnresolvedSymbolsError;
     pub use crate::lazy::encoding::{BinaryEncoding_1_0 as Binary, TextEncoding_1_0 as Text};
 }
 
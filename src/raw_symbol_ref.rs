@@ -1,9 +1,9 @@
 use crate::lazy::expanded::EncodingContextRef;
-use crate::result::IonFailure;
+use crate::result::{DecodingError, DecodingErrorKind};
 use crate::{IonError, IonResult, Symbol, SymbolId, SymbolRef};
 
 /// Like RawSymbolToken, but the Text variant holds a borrowed reference instead of a String.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RawSymbolRef<'a> {
     SymbolId(SymbolId),
     Text(&'a str),
@@ -20,30 +20,66 @@ impl<'a> RawSymbolRef<'a> {
         }
     }
 
+    /// If this token is a symbol ID, returns `Some(sid)`. If it is text, returns `None`.
+    pub fn symbol_id(&self) -> Option<SymbolId> {
+        match self {
+            RawSymbolRef::SymbolId(sid) => Some(*sid),
+            RawSymbolRef::Text(_) => None,
+        }
+    }
+
     pub fn resolve(self, context: EncodingContextRef<'a>) -> IonResult<SymbolRef<'a>> {
-        let symbol = match self {
+        match self {
+            RawSymbolRef::SymbolId(sid) => context
+                .resolve_symbol_id(sid)
+                .ok_or_else(|| symbol_not_found_error(sid, None)),
+            RawSymbolRef::Text(text) => Ok(text.into()),
+        }
+    }
+
+    /// Like [`resolve`](Self::resolve), but if this is a symbol ID that is not in the symbol
+    /// table, the resulting error reports `offset` as its position in the input.
+    pub(crate) fn resolve_at(
+        self,
+        context: EncodingContextRef<'a>,
+        offset: usize,
+    ) -> IonResult<SymbolRef<'a>> {
+        match self {
             RawSymbolRef::SymbolId(sid) => context
-                .symbol_table()
-                .symbol_for(sid)
-                .ok_or_else(
-                    #[inline(never)]
-                    || {
-                        IonError::decoding_error(format!(
-                            "found a symbol ID (${}) that was not in the symbol table",
-                            sid
-                        ))
-                    },
-                )?
-                .into(),
-            RawSymbolRef::Text(text) => text.into(),
-        };
-        Ok(symbol)
+                .resolve_symbol_id(sid)
+                .ok_or_else(|| symbol_not_found_error(sid, Some(offset))),
+            RawSymbolRef::Text(text) => Ok(text.into()),
+        }
     }
 }
 
+#[inline(never)]
+fn symbol_not_found_error(sid: SymbolId, offset: Option<usize>) -> IonError {
+    let error = DecodingError::new(format!(
+        "found a symbol ID (${sid}) that was not in the symbol table"
+    ))
+    .with_kind(DecodingErrorKind::SymbolNotFound);
+    match offset {
+        Some(offset) => error.with_position(offset),
+        None => error,
+    }
+    .into()
+}
+
 /// Implemented by types that can be viewed as a [RawSymbolRef] without allocations.
 pub trait AsRawSymbolRef {
     fn as_raw_symbol_token_ref(&self) -> RawSymbolRef;
+
+    /// If this is a symbol with unknown text, returns the symbol ID it was read from (`0` for
+    /// `$0`). Otherwise, returns `None`.
+    ///
+    /// [`as_raw_symbol_token_ref`](Self::as_raw_symbol_token_ref) represents a symbol with
+    /// unknown text as `$0`, since the symbol ID it was read from may mean something else in the
+    /// stream being written. Application writers use this method to apply their
+    /// [`UnknownTextSymbolPolicy`](crate::UnknownTextSymbolPolicy) instead.
+    fn unknown_text_sid(&self) -> Option<SymbolId> {
+        None
+    }
 }
 
 impl<'a> AsRawSymbolRef for RawSymbolRef<'a> {
@@ -64,6 +100,12 @@ impl AsRawSymbolRef for &str {
     }
 }
 
+impl AsRawSymbolRef for String {
+    fn as_raw_symbol_token_ref(&self) -> RawSymbolRef<'_> {
+        RawSymbolRef::Text(self.as_str())
+    }
+}
+
 impl AsRawSymbolRef for Symbol {
     fn as_raw_symbol_token_ref(&self) -> RawSymbolRef {
         match self.text() {
@@ -71,6 +113,10 @@ impl AsRawSymbolRef for Symbol {
             None => RawSymbolRef::SymbolId(0),
         }
     }
+
+    fn unknown_text_sid(&self) -> Option<SymbolId> {
+        Symbol::unknown_text_sid(self)
+    }
 }
 
 impl<T> AsRawSymbolRef for &T
@@ -80,6 +126,10 @@ where
     fn as_raw_symbol_token_ref(&self) -> RawSymbolRef {
         (*self).as_raw_symbol_token_ref()
     }
+
+    fn unknown_text_sid(&self) -> Option<SymbolId> {
+        (*self).unknown_text_sid()
+    }
 }
 
 impl<'a, 'b> From<&'a RawSymbolRef<'b>> for RawSymbolRef<'a> {
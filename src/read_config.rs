@@ -3,26 +3,146 @@ use crate::lazy::any_encoding::AnyEncoding;
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0, TextEncoding_1_1,
 };
+use crate::symbol_table::SymbolTable;
 use crate::{Catalog, Decoder};
 
 /// Provides configuration details for reader construction.
 pub struct ReadConfig<D: Decoder> {
     pub(crate) catalog: Box<dyn Catalog>,
+    pub(crate) options: ReadOptions,
+    pub(crate) initial_symbol_table: Option<SymbolTable>,
     encoding: D,
 }
 
+/// Options that govern how a reader resolves and validates the values it reads. These are
+/// stored in the reader's encoding context so lazy values can consult them.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct ReadOptions {
+    pub(crate) unresolved_symbol_id_policy: UnresolvedSymbolIdPolicy,
+    // If set, reading a value with more annotations than this will return an `Err`.
+    pub(crate) max_annotations_per_value: Option<usize>,
+    // If `true`, reading a value with the same annotation more than once will return an `Err`.
+    pub(crate) reject_duplicate_annotations: bool,
+    // Reading a value nested inside more than this many containers will return an `Err`.
+    pub(crate) max_depth: usize,
+}
+
+impl ReadOptions {
+    /// The default value of [`ReadConfig::with_max_depth`].
+    pub(crate) const DEFAULT_MAX_DEPTH: usize = 128;
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions {
+            unresolved_symbol_id_policy: UnresolvedSymbolIdPolicy::default(),
+            max_annotations_per_value: None,
+            reject_duplicate_annotations: false,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+/// Configuration options for reading a symbol ID that is not defined in the active symbol table.
+///
+/// The policy applies to symbol values, field names, and annotations alike. Symbol IDs that are
+/// defined but map to unknown text (for example, `$0`) are always read as symbols with unknown text.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[non_exhaustive]
+pub enum UnresolvedSymbolIdPolicy {
+    /// Reading the symbol will return an `Err`.
+    #[default]
+    Error,
+    /// The symbol will be read as though it had unknown text. The resulting symbol retains the
+    /// symbol ID; see [`Symbol::unknown_text_sid`](crate::Symbol::unknown_text_sid).
+    UnknownText,
+}
+
 impl<D: Decoder> ReadConfig<D> {
-    fn new(encoding: D) -> Self {
+    pub(crate) fn new(encoding: D) -> Self {
         ReadConfig::new_with_catalog(encoding, EmptyCatalog)
     }
 
     pub(crate) fn new_with_catalog(encoding: D, catalog: impl Catalog + 'static) -> Self {
         ReadConfig {
             catalog: Box::new(catalog),
+            options: ReadOptions::default(),
+            initial_symbol_table: None,
             encoding,
         }
     }
 
+    /// Configures readers built from this configuration to resolve shared symbol table imports
+    /// using `catalog`. Any other options that have already been set are preserved.
+    pub fn with_catalog(mut self, catalog: impl Catalog + 'static) -> Self {
+        self.catalog = Box::new(catalog);
+        self
+    }
+
+    /// Configures how readers built from this configuration will handle symbol IDs that are not
+    /// defined in the active symbol table. By default, reading one will return an `Err`.
+    pub fn with_unresolved_symbol_id_policy(mut self, policy: UnresolvedSymbolIdPolicy) -> Self {
+        self.options.unresolved_symbol_id_policy = policy;
+        self
+    }
+
+    /// Configures readers built from this configuration to return an `Err` when they encounter a
+    /// value with more than `max` annotations. The limit is enforced as the annotations are read,
+    /// so a value with a very large number of annotations is rejected without being fully
+    /// visited. By default, there is no limit.
+    pub fn with_max_annotations_per_value(mut self, max: usize) -> Self {
+        self.options.max_annotations_per_value = Some(max);
+        self
+    }
+
+    /// If `reject` is `true`, readers built from this configuration will return an `Err` when
+    /// they encounter a value with the same annotation more than once (for example: `foo::foo::1`).
+    /// Such values are legal Ion, so this is disabled by default.
+    ///
+    /// Each annotation is compared to those that preceded it, so this is best combined with
+    /// [`Self::with_max_annotations_per_value`] when reading untrusted input.
+    pub fn with_reject_duplicate_annotations(mut self, reject: bool) -> Self {
+        self.options.reject_duplicate_annotations = reject;
+        self
+    }
+
+    /// Configures readers built from this configuration to return an `Err` when they encounter a
+    /// container nested inside `max` other containers.
+    ///
+    /// The default limit is 128. Input nested more deeply than that is rejected unless the limit is
+    /// raised, even though the reader could otherwise read it. The default is deliberately modest:
+    /// reading 1024 levels of nested text containers needs more than the 2 MiB of stack that
+    /// `std::thread::spawn` gives a new thread, even in optimized builds.
+    ///
+    /// Text Ion containers and delimited binary Ion 1.1 containers are checked when their
+    /// enclosing top-level value is read. Values in any encoding are checked when they are
+    /// materialized into an [`Element`](crate::Element), which is done without recursion.
+    ///
+    /// Text containers and delimited binary containers are matched recursively, so each level of
+    /// nesting uses some stack space while the enclosing top-level value is read. Raising the limit
+    /// far beyond the default may allow deeply nested input to exhaust the stack, particularly in
+    /// unoptimized builds or on threads with small stacks. In optimized builds, allow roughly
+    /// 4 KiB of stack for each level of nesting.
+    pub fn with_max_depth(mut self, max: usize) -> Self {
+        self.options.max_depth = max;
+        self
+    }
+
+    /// Configures readers built from this configuration to begin with `symbol_table` as their
+    /// active symbol table instead of the system symbol table.
+    ///
+    /// This allows a reader to decode a fragment of a stream whose symbol table was stored
+    /// elsewhere, such as values that were sliced out of a larger binary stream. Readers with an
+    /// explicit binary encoding (for example, [`v1_0::Binary`](crate::v1_0::Binary)) do not require
+    /// their input to begin with an Ion version marker, so the fragment can start directly with
+    /// a value. Any local symbol tables in the fragment are processed normally; those that append
+    /// will add to the provided table, while an IVM or a non-appending symbol table will replace it.
+    /// The reader works on its own copy, so the same table can be used to build several readers.
+    pub fn with_initial_symbol_table(mut self, symbol_table: SymbolTable) -> Self {
+        self.initial_symbol_table = Some(symbol_table);
+        self
+    }
+
     pub fn encoding(&self) -> D {
         self.encoding
     }
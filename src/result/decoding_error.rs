@@ -6,28 +6,104 @@ use thiserror::Error;
 #[derive(Clone, Debug, Error, PartialEq)]
 #[error("{description}")]
 pub struct DecodingError {
+    kind: DecodingErrorKind,
     description: Cow<'static, str>,
     // This is optional because sometimes data is found to be malformed or invalid but the original
     // data source is not available. For example, consider a deserializer reading a symbol table
     // from an `Element`. If the `symbols` field is missing, it needs to raise a decoding error, but
     // no source position is available. Whenever possible, usages should specify the position.
     position: Option<Position>,
+    // Human-friendly names for what the reader was working on when the error occurred, if known,
+    // from the innermost operation outward. These are already reflected in the description; they
+    // are stored separately so that they can be inspected programmatically.
+    labels: Vec<Cow<'static, str>>,
+}
+
+/// The category of problem that caused a [`DecodingError`].
+///
+/// This allows callers to react to specific kinds of malformed input without matching on the
+/// text of the error's description. Errors that have not (yet) been given a more specific
+/// category are reported as [`DecodingErrorKind::Other`].
+///
+/// Running out of input before a value is complete is not a decoding error; it is reported as
+/// [`IonError::Incomplete`](crate::IonError::Incomplete) so that the reader can try again once
+/// more data is available.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DecodingErrorKind {
+    /// The input was not syntactically valid text Ion.
+    InvalidSyntax,
+    /// Text that was required to be UTF-8 was not.
+    InvalidUtf8,
+    /// A binary type descriptor or opcode was reserved or not legal in its position.
+    InvalidTypeCode,
+    /// A symbol ID was not defined in the active symbol table.
+    SymbolNotFound,
+    /// A numeric value or encoding primitive was too large to be represented.
+    NumericOverflow,
+    /// A value was accessed as a type that it is not.
+    TypeMismatch,
+    /// Any other problem with the input.
+    #[default]
+    Other,
 }
 
 impl DecodingError {
     pub(crate) fn new(description: impl Into<Cow<'static, str>>) -> Self {
         DecodingError {
+            kind: DecodingErrorKind::Other,
             description: description.into(),
             position: None,
+            labels: Vec::new(),
         }
     }
 
+    pub(crate) fn with_kind(mut self, kind: DecodingErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
     pub(crate) fn with_position(mut self, position: impl Into<Position>) -> Self {
         self.position = Some(position.into());
         self
     }
 
+    pub(crate) fn with_description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds `label` to the error's labels. Each label describes an operation that encloses the
+    /// operations described by the labels added before it.
+    pub(crate) fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    pub(crate) fn description(&self) -> &str {
+        self.description.as_ref()
+    }
+
+    /// Returns the category of problem that caused this error.
+    pub fn kind(&self) -> DecodingErrorKind {
+        self.kind
+    }
+
     pub fn position(&self) -> Option<&Position> {
         self.position.as_ref()
     }
+
+    /// If known, returns a description of what the reader was working on when the error
+    /// occurred; for example, `"reading a struct field name"`. This is the innermost of the
+    /// error's [`labels`](Self::labels).
+    pub fn label(&self) -> Option<&str> {
+        self.labels.first().map(|label| label.as_ref())
+    }
+
+    /// Returns descriptions of each of the operations the reader was working on when the error
+    /// occurred, starting with the innermost. For example, an error in a struct field nested in
+    /// a list might have the labels `"reading a struct field"` and then `"matching a list"`.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.labels.iter().map(|label| label.as_ref())
+    }
 }
@@ -1,14 +1,17 @@
 use crate::position::Position;
 use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
 use thiserror::Error;
 
 /// For non-blocking readers, indicates that there was not enough data available in the input buffer
 /// to complete the requested action.
 #[derive(Clone, Debug, Error, PartialEq)]
-#[error("ran out of input while reading {label} at offset {position}")]
 pub struct IncompleteError {
     label: Cow<'static, str>,
     position: Position,
+    // If known, the minimum number of bytes (beginning at `position`) needed to complete the
+    // read and the number of bytes that were actually available.
+    byte_counts: Option<(usize, usize)>,
 }
 
 impl IncompleteError {
@@ -16,10 +19,42 @@ impl IncompleteError {
         IncompleteError {
             label: label.into(),
             position: position.into(),
+            byte_counts: None,
         }
     }
 
+    pub(crate) fn with_byte_counts(mut self, bytes_needed: usize, bytes_available: usize) -> Self {
+        self.byte_counts = Some((bytes_needed, bytes_available));
+        self
+    }
+
     pub fn position(&self) -> &Position {
         &self.position
     }
+
+    /// If known, returns the minimum number of bytes, beginning at [`position`](Self::position),
+    /// that the reader needed in order to complete the read.
+    pub fn bytes_needed(&self) -> Option<usize> {
+        self.byte_counts.map(|(needed, _)| needed)
+    }
+
+    /// If known, returns the number of bytes, beginning at [`position`](Self::position), that
+    /// were available to the reader.
+    pub fn bytes_available(&self) -> Option<usize> {
+        self.byte_counts.map(|(_, available)| available)
+    }
+}
+
+impl Display for IncompleteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ran out of input while reading {} at offset {}",
+            self.label, self.position
+        )?;
+        if let Some((needed, available)) = self.byte_counts {
+            write!(f, " (needed {needed} bytes, {available} available)")?;
+        }
+        Ok(())
+    }
 }
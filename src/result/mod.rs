@@ -3,6 +3,7 @@
 use std::borrow::Cow;
 use std::convert::From;
 use std::fmt::{Debug, Error};
+use std::ops::Range;
 use std::{fmt, io};
 
 use thiserror::Error;
@@ -16,13 +17,14 @@ mod illegal_operation;
 mod incomplete;
 mod io_error;
 
-pub use decoding_error::DecodingError;
+pub use decoding_error::{DecodingError, DecodingErrorKind};
 pub use encoding_error::EncodingError;
 pub use illegal_operation::IllegalOperation;
 pub use incomplete::IncompleteError;
 pub use io_error::IoError;
 
 use crate::position::Position;
+use crate::IonType;
 
 /// A unified Result type representing the outcome of method calls that may fail.
 pub type IonResult<T> = Result<T, IonError>;
@@ -55,6 +57,27 @@ pub enum IonError {
     IllegalOperation(#[from] IllegalOperation),
 }
 
+impl IonError {
+    /// If this error was raised while reading input and its location in the input is known,
+    /// returns that location. Otherwise, returns `None`.
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            IonError::Incomplete(error) => Some(error.position()),
+            IonError::Decoding(error) => error.position(),
+            _ => None,
+        }
+    }
+
+    /// If this is a decoding error, returns the category of problem that caused it. Otherwise,
+    /// returns `None`.
+    pub fn decoding_error_kind(&self) -> Option<DecodingErrorKind> {
+        match self {
+            IonError::Decoding(error) => Some(error.kind()),
+            _ => None,
+        }
+    }
+}
+
 impl From<io::Error> for IonError {
     fn from(io_error: io::Error) -> Self {
         IoError::from(io_error).into()
@@ -104,6 +127,34 @@ impl ser::Error for IonError {
     }
 }
 
+/// Constructs the error returned when a value is accessed as a type that it is not; for example,
+/// calling `expect_struct()` on a value that turns out to be an int. Each of the value types
+/// offering `expect_*` methods uses this function so that their messages are worded identically.
+///
+/// `expected` is a description of the expected type that includes an article, like `"a struct"`.
+/// If the offending value's location in the input is known, `range` should contain its byte
+/// range; it is included in the message and becomes the error's [`Position`].
+pub(crate) fn type_mismatch_error(
+    expected: &str,
+    found: IonType,
+    found_is_null: bool,
+    range: Option<Range<usize>>,
+) -> IonError {
+    let mut description = if found_is_null {
+        format!("expected {expected}, found a null.{found}")
+    } else {
+        format!("expected {expected}, found a(n) {found}")
+    };
+    let Some(range) = range else {
+        return IonError::decoding_error_of_kind(DecodingErrorKind::TypeMismatch, description);
+    };
+    description.push_str(&format!(" at input bytes {}..{}", range.start, range.end));
+    DecodingError::new(description)
+        .with_kind(DecodingErrorKind::TypeMismatch)
+        .with_position(Position::with_offset(range.start).with_length(range.len()))
+        .into()
+}
+
 // Crate-visible convenience methods for constructing error variants and wrapping them in the
 // appropriate type: IonResult<T> or IonError. This is a trait so these methods can be added to
 // `IonResult<T>`, which is just a type alias for `Result<T, IonError>`, whose implementation
@@ -115,6 +166,10 @@ pub(crate) trait IonFailure {
     // the future.
     fn incomplete(label: impl Into<Cow<'static, str>>, position: impl Into<Position>) -> Self;
     fn decoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self;
+    fn decoding_error_of_kind<S: Into<Cow<'static, str>>>(
+        kind: DecodingErrorKind,
+        description: S,
+    ) -> Self;
     fn encoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self;
     fn illegal_operation<S: Into<Cow<'static, str>>>(operation: S) -> Self;
 }
@@ -128,6 +183,13 @@ impl IonFailure for IonError {
         DecodingError::new(description).into()
     }
 
+    fn decoding_error_of_kind<S: Into<Cow<'static, str>>>(
+        kind: DecodingErrorKind,
+        description: S,
+    ) -> Self {
+        DecodingError::new(description).with_kind(kind).into()
+    }
+
     fn encoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self {
         EncodingError::new(description).into()
     }
@@ -146,6 +208,13 @@ impl<T> IonFailure for IonResult<T> {
         Err(IonError::decoding_error(description))
     }
 
+    fn decoding_error_of_kind<S: Into<Cow<'static, str>>>(
+        kind: DecodingErrorKind,
+        description: S,
+    ) -> Self {
+        Err(IonError::decoding_error_of_kind(kind, description))
+    }
+
     fn encoding_error<S: Into<Cow<'static, str>>>(description: S) -> Self {
         Err(IonError::encoding_error(description))
     }
@@ -154,3 +223,121 @@ impl<T> IonFailure for IonResult<T> {
         Err(IonError::illegal_operation(operation))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+    use std::{fmt, io};
+
+    use rstest::rstest;
+
+    use crate::result::{DecodingErrorKind, IonError, IonFailure};
+    use crate::{Element, IonResult};
+
+    const IVM: &[u8] = &[0xE0, 0x01, 0x00, 0xEA];
+
+    fn read_all_error(ion: &[u8]) -> IonError {
+        Element::read_all(ion).expect_err("malformed input was read successfully")
+    }
+
+    fn binary(body: &[u8]) -> Vec<u8> {
+        let mut ion = IVM.to_vec();
+        ion.extend_from_slice(body);
+        ion
+    }
+
+    #[rstest]
+    // A string whose only byte is not valid UTF-8
+    #[case::invalid_utf8(&[0x81, 0xFF], DecodingErrorKind::InvalidUtf8, 4)]
+    // Type code 0xF is reserved
+    #[case::reserved_type_code(&[0x20, 0xF0], DecodingErrorKind::InvalidTypeCode, 5)]
+    // $200 is not in the system symbol table
+    #[case::undefined_symbol_id(&[0x71, 0xC8], DecodingErrorKind::SymbolNotFound, 4)]
+    // $200::0
+    #[case::undefined_annotation(&[0xE3, 0x81, 0xC8, 0x20], DecodingErrorKind::SymbolNotFound, 4)]
+    // {$200: 0}
+    #[case::undefined_field_name(&[0xD2, 0xC8, 0x20], DecodingErrorKind::SymbolNotFound, 5)]
+    // An 11-byte VarInt exponent
+    #[case::oversized_var_int(
+        &[0x5C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x81, 0x01],
+        DecodingErrorKind::NumericOverflow,
+        5
+    )]
+    fn malformed_binary_yields_error_kind(
+        #[case] body: &[u8],
+        #[case] expected_kind: DecodingErrorKind,
+        #[case] expected_offset: usize,
+    ) {
+        let error = read_all_error(&binary(body));
+        let IonError::Decoding(decoding_error) = &error else {
+            panic!("expected a decoding error, found {error:?}");
+        };
+        assert_eq!(decoding_error.kind(), expected_kind, "{error}");
+        assert_eq!(error.decoding_error_kind(), Some(expected_kind));
+        assert_eq!(
+            decoding_error.position().unwrap().byte_offset(),
+            expected_offset
+        );
+    }
+
+    #[rstest]
+    #[case::top_level_struct("foo bar {baz: ]} quux", 14, &["matching a struct"])]
+    #[case::struct_in_list("foo [1, {baz: ]}] quux", 14, &["matching a struct", "matching a list"])]
+    fn malformed_text_yields_invalid_syntax(
+        #[case] ion: &str,
+        #[case] expected_offset: usize,
+        #[case] expected_labels: &[&str],
+    ) {
+        let error = read_all_error(ion.as_bytes());
+        let IonError::Decoding(decoding_error) = &error else {
+            panic!("expected a decoding error, found {error:?}");
+        };
+        assert_eq!(decoding_error.kind(), DecodingErrorKind::InvalidSyntax);
+        let position = decoding_error.position().unwrap();
+        assert_eq!(position.byte_offset(), expected_offset);
+        assert_eq!(position.line_and_column(), Some((1, expected_offset + 1)));
+        let labels: Vec<_> = decoding_error.labels().collect();
+        assert_eq!(labels, expected_labels);
+        assert_eq!(decoding_error.label(), Some(expected_labels[0]));
+    }
+
+    #[test]
+    fn type_mismatch_yields_type_mismatch() -> IonResult<()> {
+        let error = Element::read_one("\"hello\"")?.expect_int().unwrap_err();
+        assert_eq!(
+            error.decoding_error_kind(),
+            Some(DecodingErrorKind::TypeMismatch)
+        );
+        assert_eq!(error.to_string(), "expected an int, found a(n) string");
+        Ok(())
+    }
+
+    #[test]
+    fn untagged_decoding_errors_are_other() {
+        let error = IonError::decoding_error("something went wrong");
+        assert_eq!(error.decoding_error_kind(), Some(DecodingErrorKind::Other));
+        assert_eq!(error.to_string(), "something went wrong");
+        assert_eq!(
+            IonError::illegal_operation("nope").decoding_error_kind(),
+            None
+        );
+    }
+
+    #[test]
+    fn converts_from_io_error() {
+        let io_error = io::Error::new(io::ErrorKind::UnexpectedEof, "stream ended");
+        let error = IonError::from(io_error);
+        let IonError::Io(io_error) = &error else {
+            panic!("expected an I/O error, found {error:?}");
+        };
+        assert_eq!(io_error.source().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(io_error.source().to_string(), "stream ended");
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn converts_from_fmt_error() {
+        let error = IonError::from(fmt::Error);
+        assert!(matches!(error, IonError::Encoding(_)));
+    }
+}
@@ -6,12 +6,12 @@ use crate::lazy::r#struct::{LazyField, StructIterator};
 use crate::lazy::streaming_raw_reader::IonInput;
 use crate::lazy::value::LazyValue;
 use crate::lazy::value_ref::ValueRef;
-use crate::result::IonFailure;
+use crate::result::{DecodingError, IonFailure};
 use crate::serde::decimal::TUNNELED_DECIMAL_TYPE_NAME;
 use crate::serde::timestamp::TUNNELED_TIMESTAMP_TYPE_NAME;
 use crate::{
-    Decimal, IonEncoding, IonError, IonResult, IonType, RawVersionMarker, SystemReader,
-    SystemStreamItem, Timestamp,
+    Decimal, Element, IonEncoding, IonError, IonResult, IonType, RawVersionMarker, Symbol,
+    SystemReader, SystemStreamItem, Timestamp,
 };
 
 /// Generic method that can deserialize an object from any given type
@@ -46,6 +46,47 @@ where
     }
 }
 
+/// Deserializes an object from an in-memory [`Element`].
+///
+/// An `Element` is not tied to an encoding, so types that distinguish between human-readable
+/// and binary representations will see the binary form.
+pub fn from_owned_element<T>(element: &Element) -> IonResult<T>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(ElementDeserializer::new(element))
+}
+
+/// Returns `value` as the visitor's value type `V`.
+///
+/// `Timestamp`s and `Decimal`s are tunneled through serde as newtype structs with reserved names
+/// (see [`TUNNELED_TIMESTAMP_TYPE_NAME`] and [`TUNNELED_DECIMAL_TYPE_NAME`]). When a deserializer
+/// is asked for one of those names, the visitor's value type is the tunneled type itself.
+fn tunnel<T, V>(value: T) -> V {
+    assert_eq!(std::mem::size_of::<V>(), std::mem::size_of::<T>());
+    let value = std::mem::ManuallyDrop::new(value);
+    // # Safety
+    // The compiler doesn't understand that `T` is actually `V` here. The assert statement above
+    // compares the sizes of the two types, and `value` will not be dropped after it is copied.
+    unsafe { std::mem::transmute_copy::<std::mem::ManuallyDrop<T>, V>(&value) }
+}
+
+/// Prefixes a decoding error with the name of the field whose value could not be deserialized.
+/// Errors from nested structs accumulate a prefix for each level.
+fn with_field_name(error: IonError, field_name: &str) -> IonError {
+    match error {
+        IonError::Decoding(e) => {
+            let description = format!("field '{}': {}", field_name, e.description());
+            let mut decoding_error = DecodingError::new(description);
+            if let Some(position) = e.position() {
+                decoding_error = decoding_error.with_position(position.clone());
+            }
+            decoding_error.into()
+        }
+        other => other,
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct ValueDeserializer<'a, 'de> {
     pub(crate) value: &'a LazyValue<'de, AnyEncoding>,
@@ -339,28 +380,9 @@ impl<'a, 'de> de::Deserializer<'de> for ValueDeserializer<'a, 'de> {
     {
         let value = self.value.read()?;
         if name == TUNNELED_TIMESTAMP_TYPE_NAME {
-            let timestamp = value.expect_timestamp()?;
-            assert_eq!(
-                std::mem::size_of::<V::Value>(),
-                std::mem::size_of::<Timestamp>()
-            );
-            // # Safety
-            // compiler doesn't understand that the generic Timestamp here is actually V::Value here
-            // The assert statement above that compares the sizes of the Timestamp and V::Value types
-            let visitor_value =
-                unsafe { std::mem::transmute_copy::<Timestamp, V::Value>(&timestamp) };
-            return Ok(visitor_value);
+            return Ok(tunnel::<Timestamp, V::Value>(value.expect_timestamp()?));
         } else if name == TUNNELED_DECIMAL_TYPE_NAME {
-            let decimal = value.expect_decimal()?;
-            assert_eq!(
-                std::mem::size_of::<V::Value>(),
-                std::mem::size_of::<Decimal>()
-            );
-            // # Safety
-            // compiler doesn't understand that the generic Decimal here is actually V::Value here
-            // The assert statement above that compares the sizes of the Decimal and V::Value types
-            let visitor_value = unsafe { std::mem::transmute_copy::<Decimal, V::Value>(&decimal) };
-            return Ok(visitor_value);
+            return Ok(tunnel::<Decimal, V::Value>(value.expect_decimal()?));
         }
 
         visitor.visit_newtype_struct(self)
@@ -480,6 +502,8 @@ where
 struct StructAsMap<'de> {
     iter: StructIterator<'de, AnyEncoding>,
     current_field: Option<LazyField<'de, AnyEncoding>>,
+    // The text of `current_field`'s name, used to add context to errors raised by its value
+    current_field_name: String,
     is_human_readable: bool,
 }
 
@@ -488,6 +512,7 @@ impl<'de> StructAsMap<'de> {
         Self {
             iter,
             current_field: None,
+            current_field_name: String::new(),
             is_human_readable,
         }
     }
@@ -510,6 +535,7 @@ impl<'de> MapAccess<'de> for StructAsMap<'de> {
             .ok_or_else(|| IonError::decoding_error("found a symbol with unknown text"))?
             .to_owned();
         self.current_field = Some(field);
+        self.current_field_name.clone_from(&name);
 
         let deserializer = MapKeyDeserializer { key: name };
         seed.deserialize(deserializer).map(Some)
@@ -525,21 +551,27 @@ impl<'de> MapAccess<'de> for StructAsMap<'de> {
             &self.current_field.as_ref().unwrap().value(),
             self.is_human_readable,
         ))
+        .map_err(|error| with_field_name(error, &self.current_field_name))
     }
 }
 
+/// Provides access to an enum variant. `D` is a deserializer for the value representing the
+/// variant; it is used once to read the variant's name and again to read its data.
 #[derive(Clone, Copy)]
-struct VariantAccess<'a, 'de> {
-    de: ValueDeserializer<'a, 'de>,
+struct VariantAccess<D> {
+    de: D,
 }
 
-impl<'a, 'de> VariantAccess<'a, 'de> {
-    fn new(de: ValueDeserializer<'a, 'de>) -> Self {
+impl<D> VariantAccess<D> {
+    fn new(de: D) -> Self {
         VariantAccess { de }
     }
 }
 
-impl<'a, 'de> EnumAccess<'de> for VariantAccess<'a, 'de> {
+impl<'de, D> EnumAccess<'de> for VariantAccess<D>
+where
+    D: de::Deserializer<'de, Error = IonError> + Copy,
+{
     type Error = IonError;
     type Variant = Self;
 
@@ -551,7 +583,10 @@ impl<'a, 'de> EnumAccess<'de> for VariantAccess<'a, 'de> {
     }
 }
 
-impl<'a, 'de> de::VariantAccess<'de> for VariantAccess<'a, 'de> {
+impl<'de, D> de::VariantAccess<'de> for VariantAccess<D>
+where
+    D: de::Deserializer<'de, Error = IonError> + Copy,
+{
     type Error = IonError;
 
     fn unit_variant(self) -> Result<(), Self::Error> {
@@ -641,6 +676,393 @@ impl<'a, 'de> de::VariantAccess<'de> for UnitVariantAccess<'a, 'de> {
     }
 }
 
+/// Deserializes an [`Element`] that has already been read into memory.
+#[derive(Clone, Copy)]
+struct ElementDeserializer<'a> {
+    element: &'a Element,
+}
+
+impl<'a> ElementDeserializer<'a> {
+    fn new(element: &'a Element) -> Self {
+        Self { element }
+    }
+
+    /// Reads the element as an `i64` and converts it to the integer type `T`, whose name is
+    /// used in the error reported if the value is out of bounds.
+    fn expect_integer<T: TryFrom<i64>>(&self, type_name: &str) -> IonResult<T> {
+        T::try_from(self.element.expect_i64()?).map_err(|_| {
+            IonError::decoding_error(format!(
+                "found an integer was out of bounds for {type_name}"
+            ))
+        })
+    }
+
+    fn deserialize_as_sequence<'de, V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        let Some(sequence) = self.element.as_sequence() else {
+            return IonResult::decoding_error("expected a list or sexp");
+        };
+        visitor.visit_seq(ElementSequenceIterator(sequence.iter()))
+    }
+
+    fn deserialize_as_map<'de, V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        let strukt = self.element.expect_struct()?;
+        visitor.visit_map(ElementStructAsMap {
+            iter: strukt.iter(),
+            current_field: None,
+        })
+    }
+}
+
+impl<'a, 'de> de::Deserializer<'de> for ElementDeserializer<'a> {
+    type Error = IonError;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        use IonType::*;
+        match self.element.ion_type() {
+            Null => self.deserialize_unit(visitor),
+            Bool => self.deserialize_bool(visitor),
+            Int => self.deserialize_i64(visitor),
+            Float => self.deserialize_f64(visitor),
+            Decimal => self.deserialize_newtype_struct(TUNNELED_DECIMAL_TYPE_NAME, visitor),
+            Timestamp => self.deserialize_newtype_struct(TUNNELED_TIMESTAMP_TYPE_NAME, visitor),
+            String | Symbol => self.deserialize_str(visitor),
+            Blob | Clob => self.deserialize_bytes(visitor),
+            List | SExp => self.deserialize_seq(visitor),
+            Struct => self.deserialize_struct("", &[], visitor),
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_bool(self.element.expect_bool()?)
+    }
+
+    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i8(self.expect_integer("an `i8`")?)
+    }
+
+    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i16(self.expect_integer("an `i16`")?)
+    }
+
+    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i32(self.expect_integer("an `i32`")?)
+    }
+
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i64(self.element.expect_i64()?)
+    }
+
+    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u8(self.expect_integer("a `u8`")?)
+    }
+
+    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u16(self.expect_integer("an `u16`")?)
+    }
+
+    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u32(self.expect_integer("an `u32`")?)
+    }
+
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // There are integer values that fit in a u64 but not an i64, so we use
+        // `expect_int` instead of `expect_i64` to accommodate that case.
+        let value = u64::try_from(self.element.expect_int()?.clone()).map_err(|_| {
+            IonError::decoding_error("found an integer was out of bounds for an `u64`")
+        })?;
+        visitor.visit_u64(value)
+    }
+
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // XXX: This risks loss of fidelity, as it does when deserializing from a reader.
+        visitor.visit_f32(self.element.expect_float()? as f32)
+    }
+
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_f64(self.element.expect_float()?)
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let mut chars = self.element.expect_string()?.chars();
+        let Some(first_char) = chars.next() else {
+            return IonResult::decoding_error("expected a char, found an empty string");
+        };
+        if let Some(_second_char) = chars.next() {
+            return IonResult::decoding_error(
+                "expected a char, found a string with two or more characters",
+            );
+        }
+        visitor.visit_char(first_char)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_str(self.element.expect_text()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.element.expect_text()?.to_owned())
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_bytes(self.element.expect_lob()?)
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_bytes(self.element.expect_lob()?)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.element.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if name == TUNNELED_TIMESTAMP_TYPE_NAME {
+            return Ok(tunnel::<Timestamp, V::Value>(
+                self.element.expect_timestamp()?,
+            ));
+        } else if name == TUNNELED_DECIMAL_TYPE_NAME {
+            return Ok(tunnel::<Decimal, V::Value>(self.element.expect_decimal()?));
+        }
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_as_sequence(visitor)
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_as_sequence(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_as_sequence(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_as_map(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_as_map(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(VariantAccess::new(self))
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.element.annotations().iter().next() {
+            None => {
+                let symbol_text = self.element.expect_symbol()?.text().ok_or_else(|| {
+                    IonError::decoding_error(
+                        "expected a symbol representing an enum's unit struct variant",
+                    )
+                })?;
+                visitor.visit_str(symbol_text)
+            }
+            Some(variant_name) => {
+                let variant_id = variant_name.text().ok_or_else(|| {
+                    IonError::decoding_error("expected an enum variant identifier annotation")
+                })?;
+                visitor.visit_str(variant_id)
+            }
+        }
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // Ignore the value itself, return a `null` that will be also ignored
+        visitor.visit_unit()
+    }
+}
+
+struct ElementSequenceIterator<I>(I);
+
+impl<'a, 'de, I> SeqAccess<'de> for ElementSequenceIterator<I>
+where
+    I: Iterator<Item = &'a Element>,
+{
+    type Error = IonError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some(element) = self.0.next() else {
+            return Ok(None);
+        };
+        seed.deserialize(ElementDeserializer::new(element))
+            .map(Some)
+    }
+}
+
+struct ElementStructAsMap<'a, I> {
+    iter: I,
+    // The name and value of the field whose key was most recently deserialized
+    current_field: Option<(&'a str, &'a Element)>,
+}
+
+impl<'a, 'de, I> MapAccess<'de> for ElementStructAsMap<'a, I>
+where
+    I: Iterator<Item = (&'a Symbol, &'a Element)>,
+{
+    type Error = IonError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((name, value)) = self.iter.next() else {
+            return Ok(None);
+        };
+        let name = name
+            .text()
+            .ok_or_else(|| IonError::decoding_error("found a symbol with unknown text"))?;
+        self.current_field = Some((name, value));
+
+        let deserializer = MapKeyDeserializer {
+            key: name.to_owned(),
+        };
+        seed.deserialize(deserializer).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        // This method will only be called when `next_key_seed` reported another field,
+        // so we can unwrap this safely.
+        let (name, value) = self.current_field.unwrap();
+        seed.deserialize(ElementDeserializer::new(value))
+            .map_err(|error| with_field_name(error, name))
+    }
+}
+
 struct MapKeyDeserializer {
     key: String,
 }
@@ -12,7 +12,12 @@
 //! * `to_pretty`: Serialize an object into pretty formatted Ion text.
 //! * `to_binary`:  Serialize an object into Ion binary format.
 //!
-//! For deserialization `from_ion` API is provided through this module.
+//! There are two different APIs for deserializing Ion data:
+//!
+//! * `from_ion`: Deserialize an object from Ion text or binary data.
+//! * `from_owned_element`: Deserialize an object from an [`Element`](crate::Element).
+//!
+//! Struct fields that do not correspond to a field of the target type are skipped.
 //!
 //! ## Mapping of Ion data types to Rust and serde data types
 //!
@@ -42,7 +47,8 @@
 //!| unit                                                         | null                                        |
 //!| unit_struct                                                  | symbol                                      |
 //!| seq, tuple, tuple_struct                                     | list                                        |
-//!| newtype_struct, map, struct                                  | struct                                      |
+//!| newtype_struct                                               | the wrapped value                           |
+//!| map, struct                                                  | struct                                      |
 //!| newtype_variant                                              | variant value with annotation               |
 //!| struct_variant                                               | struct with annotation                      |
 //!| tuple_variant                                                | list with annotation                        |
@@ -198,16 +204,21 @@ mod decimal;
 pub mod ser;
 mod timestamp;
 
-pub use de::from_ion;
+pub use de::{from_ion, from_owned_element};
 pub use ser::{to_binary, to_pretty, to_string};
 
 #[cfg(test)]
 #[cfg(feature = "experimental-serde")]
 mod tests {
-    use crate::serde::{from_ion, to_binary, to_pretty, to_string};
+    use crate::serde::ser::ValueSerializer;
+    use crate::serde::{from_ion, from_owned_element, to_binary, to_pretty, to_string};
+    use rstest::rstest;
+    use std::collections::{HashMap, HashSet};
     use std::net::IpAddr;
 
-    use crate::{Decimal, Element, Timestamp};
+    use crate::{
+        v1_0, Decimal, Element, IonError, IonResult, SequenceWriter, Timestamp, WriteConfig, Writer,
+    };
     use chrono::{DateTime, FixedOffset, Utc};
     use serde::{Deserialize, Serialize};
     use serde_with::serde_as;
@@ -313,6 +324,10 @@ mod tests {
         let i = r#"Unit"#;
         let expected = E::Unit;
         assert_eq!(expected, from_ion(i).unwrap());
+        assert_eq!(
+            expected,
+            from_owned_element(&Element::read_one(i).unwrap()).unwrap()
+        );
         assert_eq!(
             Element::read_first(i),
             Element::read_first(to_string(&expected).unwrap())
@@ -321,6 +336,10 @@ mod tests {
         let i = r#"Newtype::1"#;
         let expected = E::Newtype(1);
         assert_eq!(expected, from_ion(i).unwrap());
+        assert_eq!(
+            expected,
+            from_owned_element(&Element::read_one(i).unwrap()).unwrap()
+        );
         assert_eq!(
             Element::read_first(i),
             Element::read_first(to_string(&expected).unwrap())
@@ -329,6 +348,10 @@ mod tests {
         let i = r#"Tuple::[1, 2]"#;
         let expected = E::Tuple(1, 2);
         assert_eq!(expected, from_ion(i).unwrap());
+        assert_eq!(
+            expected,
+            from_owned_element(&Element::read_one(i).unwrap()).unwrap()
+        );
         assert_eq!(
             Element::read_first(i),
             Element::read_first(to_string(&expected).unwrap())
@@ -337,6 +360,10 @@ mod tests {
         let i = r#"Struct::{a: 1}"#;
         let expected = E::Struct { a: 1 };
         assert_eq!(expected, from_ion(i).unwrap());
+        assert_eq!(
+            expected,
+            from_owned_element(&Element::read_one(i).unwrap()).unwrap()
+        );
         assert_eq!(
             Element::read_first(i),
             Element::read_first(to_string(&expected).unwrap())
@@ -375,4 +402,322 @@ mod tests {
         assert_eq!(&from_ion::<IpAddr, _>(s).unwrap(), &ip);
         assert_eq!(&from_ion::<IpAddr, _>(binary).unwrap(), &ip);
     }
+
+    #[test]
+    fn deterministic_map_serialization() -> IonResult<()> {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Inventory {
+            name: String,
+            counts: HashMap<String, u32>,
+            locations: HashMap<String, HashMap<String, f64>>,
+        }
+
+        // Each call constructs new `HashMap`s, each with its own iteration order.
+        fn inventory() -> Inventory {
+            let counts = (0..20).map(|i| (format!("item{i:02}"), i)).collect();
+            let locations = (0..5)
+                .map(|i| {
+                    let coordinates = [("x".to_string(), i as f64), ("y".to_string(), 0.5)];
+                    (format!("bin{i}"), coordinates.into_iter().collect())
+                })
+                .collect();
+            Inventory {
+                name: "widgets".to_string(),
+                counts,
+                locations,
+            }
+        }
+
+        fn serialize(value: &Inventory, deterministic: bool) -> IonResult<String> {
+            let config = WriteConfig::from(v1_0::Text).with_deterministic_output(deterministic);
+            let mut writer = Writer::new(config, Vec::new())?;
+            value.serialize(ValueSerializer::new(writer.value_writer(), true))?;
+            Ok(String::from_utf8(writer.close()?).unwrap())
+        }
+
+        let expected = serialize(&inventory(), true)?;
+        for _ in 0..50 {
+            assert_eq!(serialize(&inventory(), true)?, expected);
+        }
+        assert!(
+            expected.contains("{item00: 0, item01: 1, item02: 2, "),
+            "{expected}"
+        );
+        assert!(
+            expected.contains("bin0: {x: 0e0, y: 5e-1, }, bin1: {x: 1e0, y: 5e-1, }"),
+            "{expected}"
+        );
+        assert_eq!(from_ion::<Inventory, _>(&expected)?, inventory());
+
+        // Without deterministic output, each map is written in its own iteration order.
+        let outputs = (0..50)
+            .map(|_| serialize(&inventory(), false))
+            .collect::<IonResult<HashSet<_>>>()?;
+        assert!(outputs.len() > 1);
+        Ok(())
+    }
+
+    #[test]
+    fn deterministic_nested_map_serialization() -> IonResult<()> {
+        type Nested = HashMap<String, HashMap<String, HashMap<String, u8>>>;
+        let value: Nested = ["b", "a"]
+            .into_iter()
+            .map(|outer| {
+                let middle = ["d", "c"]
+                    .into_iter()
+                    .map(|middle| {
+                        let inner = [("f".to_string(), 1), ("e".to_string(), 0)].into();
+                        (middle.to_string(), inner)
+                    })
+                    .collect();
+                (outer.to_string(), middle)
+            })
+            .collect();
+
+        let config = WriteConfig::from(v1_0::Text).with_deterministic_output(true);
+        let mut writer = Writer::new(config, Vec::new())?;
+        value.serialize(ValueSerializer::new(writer.value_writer(), true))?;
+        let output = String::from_utf8(writer.close()?).unwrap();
+        // Maps at every depth are sorted by key.
+        let inner = "{e: 0, f: 1, }";
+        let middle = format!("{{c: {inner}, d: {inner}, }}");
+        assert_eq!(output.trim(), format!("{{a: {middle}, b: {middle}, }}"));
+        assert_eq!(from_ion::<Nested, _>(&output)?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_order_map_calls_are_reported() {
+        use serde::ser::{SerializeMap, Serializer};
+
+        #[derive(Copy, Clone)]
+        enum Misuse {
+            KeyTwice,
+            ValueWithoutKey,
+            KeyWithoutValue,
+        }
+
+        // A `Serialize` implementation that violates the key/value call order.
+        struct Broken(Misuse);
+
+        impl Serialize for Broken {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(None)?;
+                match self.0 {
+                    Misuse::KeyTwice => {
+                        map.serialize_key("a")?;
+                        map.serialize_key("b")?;
+                    }
+                    Misuse::ValueWithoutKey => map.serialize_value(&1)?,
+                    Misuse::KeyWithoutValue => {
+                        map.serialize_entry("a", &1)?;
+                        map.serialize_key("b")?;
+                    }
+                }
+                map.end()
+            }
+        }
+
+        for (misuse, expected_message) in [
+            (
+                Misuse::KeyTwice,
+                "cannot serialize map key 'b'; the previous key ('a') has no value",
+            ),
+            (
+                Misuse::ValueWithoutKey,
+                "cannot serialize a map value without a key",
+            ),
+            (
+                Misuse::KeyWithoutValue,
+                "cannot end a map whose last key ('b') has no value",
+            ),
+        ] {
+            let text_error = to_string(&Broken(misuse)).unwrap_err();
+            let binary_error = to_binary(&Broken(misuse)).unwrap_err();
+            for error in [text_error, binary_error] {
+                assert!(matches!(error, IonError::IllegalOperation(_)), "{error:?}");
+                assert!(error.to_string().contains(expected_message), "{error}");
+            }
+        }
+    }
+
+    #[test]
+    fn timestamp_and_decimal_fields_use_ion_types() -> IonResult<()> {
+        #[derive(Serialize, Deserialize)]
+        struct Reading {
+            taken_at: Timestamp,
+            value: Decimal,
+        }
+
+        let reading = Reading {
+            taken_at: Timestamp::with_ymd(2024, 6, 1)
+                .with_hms(12, 30, 5)
+                .with_milliseconds(250)
+                .with_offset(-300)
+                .build()?,
+            value: Decimal::new(-40125, -3),
+        };
+        let expected =
+            Element::read_one("{taken_at: 2024-06-01T12:30:05.250-05:00, value: -40.125}")?;
+        for serialized in [
+            to_string(&reading)?.into_bytes(),
+            to_pretty(&reading)?.into_bytes(),
+            to_binary(&reading)?,
+        ] {
+            let actual = Element::read_one(&serialized)?;
+            // Each field is written as its Ion type rather than a tunneled blob or struct.
+            assert_eq!(actual, expected);
+            let round_tripped: Reading = from_ion(serialized)?;
+            assert_eq!(round_tripped.taken_at, reading.taken_at);
+            assert_eq!(round_tripped.value, reading.value);
+        }
+        let from_element: Reading = from_owned_element(&expected)?;
+        assert_eq!(from_element.taken_at, reading.taken_at);
+        assert_eq!(from_element.value, reading.value);
+        Ok(())
+    }
+
+    #[test]
+    fn newtype_structs_and_variants() -> IonResult<()> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Meters(f64);
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Point {
+            x: i64,
+            y: i64,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Shape {
+            Circle(Meters),
+            Label(String),
+            Marker(Point),
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Drawing {
+            width: Meters,
+            shapes: Vec<Shape>,
+        }
+
+        let drawing = Drawing {
+            width: Meters(2.5),
+            shapes: vec![
+                Shape::Circle(Meters(0.5)),
+                Shape::Label("origin".to_string()),
+                Shape::Marker(Point { x: 3, y: -4 }),
+            ],
+        };
+        // Newtype structs are written as the value they wrap. Newtype variants are written as
+        // the wrapped value annotated with the variant name.
+        let expected = Element::read_one(
+            r#"{
+                width: 2.5e0,
+                shapes: [Circle::0.5e0, Label::"origin", Marker::{x: 3, y: -4}]
+            }"#,
+        )?;
+        for serialized in [to_string(&drawing)?.into_bytes(), to_binary(&drawing)?] {
+            assert_eq!(Element::read_one(&serialized)?, expected);
+            assert_eq!(from_ion::<Drawing, _>(serialized)?, drawing);
+        }
+        assert_eq!(from_owned_element::<Drawing>(&expected)?, drawing);
+        Ok(())
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Reading {
+        sensor: String,
+        time: Timestamp,
+        values: Vec<i64>,
+        note: Option<String>,
+        location: Location,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Location {
+        lat: f64,
+        lon: f64,
+    }
+
+    fn sample_reading() -> Reading {
+        Reading {
+            sensor: "thermometer".to_string(),
+            time: Timestamp::with_ymd(2024, 6, 1)
+                .with_hms(12, 30, 0)
+                .with_offset(0)
+                .build()
+                .unwrap(),
+            values: vec![20, 21, 19],
+            note: None,
+            location: Location {
+                lat: 47.6,
+                lon: -122.3,
+            },
+        }
+    }
+
+    #[test]
+    fn test_from_owned_element() -> IonResult<()> {
+        let reading = sample_reading();
+        for ion in [to_binary(&reading)?, to_string(&reading)?.into_bytes()] {
+            let element = Element::read_one(ion)?;
+            let back: Reading = from_owned_element(&element)?;
+            assert_eq!(back, reading);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_fields_are_skipped() -> IonResult<()> {
+        let ion = r#"
+            {
+                sensor: "thermometer",
+                firmware: "1.2.3",
+                time: 2024-06-01T12:30:00Z,
+                values: [20, 21, 19],
+                note: null,
+                location: {lat: 47.6e0, lon: -122.3e0, altitude: {meters: 56}},
+                tags: [a, b, c],
+            }
+        "#;
+        let expected = sample_reading();
+        let from_text: Reading = from_ion(ion)?;
+        assert_eq!(from_text, expected);
+        let from_element: Reading = from_owned_element(&Element::read_one(ion)?)?;
+        assert_eq!(from_element, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::top_level_field(
+        r#"{sensor: 5, time: 2024T, values: [], location: {lat: 0e0, lon: 0e0}}"#,
+        "field 'sensor': "
+    )]
+    #[case::sequence_field(
+        r#"{sensor: "s", time: 2024T, values: [1, "two"], location: {lat: 0e0, lon: 0e0}}"#,
+        "field 'values': "
+    )]
+    #[case::nested_field(
+        r#"{sensor: "s", time: 2024T, values: [], location: {lat: 0e0, lon: true}}"#,
+        "field 'location': field 'lon': "
+    )]
+    fn test_type_mismatch_reports_field_name(
+        #[case] ion: &str,
+        #[case] expected_prefix: &str,
+    ) -> IonResult<()> {
+        let expect_error = |result: IonResult<Reading>| match result {
+            Err(IonError::Decoding(e)) => {
+                assert!(
+                    e.description().starts_with(expected_prefix),
+                    "unexpected error description: {}",
+                    e.description()
+                );
+            }
+            other => panic!("expected a decoding error, found {other:?}"),
+        };
+        expect_error(from_ion(ion));
+        expect_error(from_owned_element(&Element::read_one(ion)?));
+        Ok(())
+    }
 }
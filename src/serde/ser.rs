@@ -4,7 +4,7 @@ use std::ops::{Deref, DerefMut};
 use serde::ser::Impossible;
 use serde::{ser, Serialize};
 
-use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
+use crate::lazy::encoder::value_writer::internal::FieldEncoder;
 use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
 use crate::lazy::encoder::writer::Writer;
 use crate::lazy::encoding::{BinaryEncoding_1_0, Encoding, TextEncoding_1_0};
@@ -14,7 +14,7 @@ use crate::serde::timestamp::TUNNELED_TIMESTAMP_TYPE_NAME;
 use crate::symbol_ref::AsSymbolRef;
 use crate::write_config::{WriteConfig, WriteConfigKind};
 use crate::Value::Null;
-use crate::{Decimal, IonError, IonResult, IonType, TextFormat, Timestamp};
+use crate::{Decimal, Element, IonError, IonResult, IonType, TextFormat, Timestamp};
 
 fn write_with_config<T: Serialize, E: Encoding>(
     value: &T,
@@ -283,9 +283,16 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let map_writer = self.value_writer.struct_writer()?;
+        // A map's entries are serialized in its iteration order, which may vary from one process
+        // to the next (as a `HashMap`'s does). If the writer sorts fields, buffer the entries so
+        // they can be sorted by key before they are written.
+        let sorted_entries = map_writer.config().sorts_fields().then(Vec::new);
         Ok(MapWriter {
-            map_writer: self.value_writer.struct_writer()?,
+            map_writer,
             is_human_readable: self.is_human_readable,
+            pending_key: None,
+            sorted_entries,
         })
     }
 
@@ -297,6 +304,8 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
         Ok(MapWriter {
             map_writer: self.value_writer.struct_writer()?,
             is_human_readable: self.is_human_readable,
+            pending_key: None,
+            sorted_entries: None,
         })
     }
 
@@ -313,6 +322,8 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
                 .with_annotations([variant])?
                 .struct_writer()?,
             is_human_readable: self.is_human_readable,
+            pending_key: None,
+            sorted_entries: None,
         })
     }
 }
@@ -407,6 +418,10 @@ impl<V: ValueWriter> ser::SerializeTupleVariant for SeqWriter<V> {
 pub struct MapWriter<V: ValueWriter> {
     map_writer: V::StructWriter,
     is_human_readable: bool,
+    // The most recently serialized key, if its value has not been serialized yet.
+    pending_key: Option<String>,
+    // If the map's entries are being buffered for sorting, the entries serialized so far.
+    sorted_entries: Option<Vec<(String, Element)>>,
 }
 
 impl<V: ValueWriter> Deref for MapWriter<V> {
@@ -434,24 +449,86 @@ impl<V: ValueWriter> ser::SerializeMap for MapWriter<V> {
         // We need to verify that the key is a string type or can be converted
         // to string
         let mk_serializer = MapKeySerializer {};
-        let field_name: String = key.serialize(mk_serializer)?;
-        self.encode_field_name(field_name.as_str())
+        let field_name: String = match key.serialize(mk_serializer) {
+            Ok(field_name) => field_name,
+            Err(e) => {
+                self.map_writer.abandon();
+                return Err(e);
+            }
+        };
+        // The key is held until its value is serialized so that out-of-order calls (which a
+        // hand-written `Serialize` implementation could make) are reported instead of producing
+        // a malformed struct.
+        if let Some(pending_key) = &self.pending_key {
+            self.map_writer.abandon();
+            return IonResult::illegal_operation(format!(
+                "cannot serialize map key '{field_name}'; the previous key ('{pending_key}') has no value"
+            ));
+        }
+        self.pending_key = Some(field_name);
+        Ok(())
     }
 
     fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
+        let Some(field_name) = self.pending_key.take() else {
+            self.map_writer.abandon();
+            return IonResult::illegal_operation("cannot serialize a map value without a key");
+        };
         let is_human_readable = self.is_human_readable;
-        let serializer = ValueSerializer::new(self.make_value_writer(), is_human_readable);
+        if let Some(entries) = self.sorted_entries.as_mut() {
+            match to_element(value, is_human_readable) {
+                Ok(element) => entries.push((field_name, element)),
+                Err(e) => {
+                    self.map_writer.abandon();
+                    return Err(e);
+                }
+            }
+            return Ok(());
+        }
+        let serializer =
+            ValueSerializer::new(self.field_writer(field_name.as_str()), is_human_readable);
         value.serialize(serializer)
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        if let Some(pending_key) = &self.pending_key {
+            self.map_writer.abandon();
+            return IonResult::illegal_operation(format!(
+                "cannot end a map whose last key ('{pending_key}') has no value"
+            ));
+        }
+        if let Some(mut entries) = self.sorted_entries.take() {
+            entries.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+            for (field_name, value) in &entries {
+                self.map_writer.write(field_name, value)?;
+            }
+        }
         self.map_writer.close()
     }
 }
 
+/// Serializes `value` as an [`Element`] so that it can be buffered and written later.
+///
+/// The buffer's writer does not sort fields, so maps nested in `value` are written into it
+/// directly instead of being buffered again at each level. Their fields are sorted when the
+/// resulting `Element` is written to the parent writer, which sorts the fields of every struct
+/// it contains.
+fn to_element<T>(value: &T, is_human_readable: bool) -> IonResult<Element>
+where
+    T: ?Sized + Serialize,
+{
+    let config = WriteConfig::<BinaryEncoding_1_0>::new();
+    let mut writer = Writer::new(config, vec![])?;
+    value.serialize(ValueSerializer::new(
+        writer.value_writer(),
+        is_human_readable,
+    ))?;
+    Element::read_one(writer.close()?)
+}
+
 impl<V: ValueWriter> ser::SerializeStructVariant for MapWriter<V> {
     type Ok = ();
     type Error = IonError;
@@ -1,7 +1,7 @@
 use crate::element::Element;
 use crate::result::IonFailure;
-use crate::{ion_seq, IonResult};
-use crate::{Int, IonError, Symbol};
+use crate::{ion_seq, ion_struct, IonResult};
+use crate::{Int, IntoAnnotatedElement, IonError, IonType, List, Sequence, Symbol};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Stores [`SharedSymbolTable`] with the table name, version and imports
@@ -29,6 +29,11 @@ impl SharedSymbolTable {
                 "shared symbol table with empty name is not allowed",
             );
         }
+        if version < 1 {
+            return IonResult::illegal_operation(format!(
+                "shared symbol table '{name}' has version {version}; versions start at 1"
+            ));
+        }
 
         Ok(Self {
             name,
@@ -117,11 +122,40 @@ impl TryFrom<Element> for SharedSymbolTable {
     }
 }
 
+/// Converts a [`SharedSymbolTable`] into its serialized form: a struct annotated with
+/// `$ion_shared_symbol_table` that has `name`, `version`, and `symbols` fields. Symbols with
+/// unknown text are written as `null` so that the IDs of the symbols that follow them are
+/// unchanged. The resulting [`Element`] can be written to a stream and later read back using
+/// `SharedSymbolTable::try_from`.
+impl From<&SharedSymbolTable> for Element {
+    fn from(sst: &SharedSymbolTable) -> Self {
+        let symbols = sst.symbols().iter().map(|symbol| match symbol.text() {
+            Some(text) => Element::string(text),
+            None => Element::null(IonType::Null),
+        });
+        ion_struct! {
+            "name": sst.name(),
+            "version": sst.version() as i64,
+            "symbols": List::from(symbols.collect::<Sequence>()),
+        }
+        .with_annotations(["$ion_shared_symbol_table"])
+    }
+}
+
+impl From<SharedSymbolTable> for Element {
+    fn from(sst: SharedSymbolTable) -> Self {
+        Element::from(&sst)
+    }
+}
+
 #[cfg(test)]
 mod shared_symbol_table_tests {
     use crate::element::Element;
     use crate::shared_symbol_table::SharedSymbolTable;
-    use crate::{IonResult, Symbol};
+    use crate::{
+        v1_0, AnyEncoding, Decoder, ElementReader, IonResult, MapCatalog, Reader, Sequence, Symbol,
+        UnresolvedSymbolIdPolicy,
+    };
 
     #[test]
     fn shared_symbol_table_read_test() -> IonResult<()> {
@@ -147,4 +181,125 @@ mod shared_symbol_table_tests {
         assert_eq!(sst.symbols()[2], Symbol::owned("foe"));
         Ok(())
     }
+
+    #[test]
+    fn shared_symbol_table_validation() {
+        assert!(SharedSymbolTable::new("", 1, ["foo"]).is_err());
+        assert!(SharedSymbolTable::new("com.example.symbols", 0, ["foo"]).is_err());
+        assert!(SharedSymbolTable::new("com.example.symbols", 1, Vec::<Symbol>::new()).is_ok());
+    }
+
+    #[test]
+    fn shared_symbol_table_to_element() -> IonResult<()> {
+        let sst = SharedSymbolTable::new(
+            "com.example.symbols",
+            2,
+            [
+                Symbol::from("foo"),
+                Symbol::unknown_text(),
+                Symbol::from("bar"),
+            ],
+        )?;
+        let expected = Element::read_one(
+            r#"
+            $ion_shared_symbol_table::{
+                name: "com.example.symbols",
+                version: 2,
+                symbols: ["foo", null, "bar"],
+            }
+        "#,
+        )?;
+        assert_eq!(Element::from(&sst), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_round_trip() -> IonResult<()> {
+        let sst = SharedSymbolTable::new(
+            "syms",
+            1,
+            [
+                Symbol::from("foo"),
+                Symbol::unknown_text(),
+                Symbol::from("bar"),
+            ],
+        )?;
+
+        // Write the shared symbol table document, then read it back.
+        let encoded = Element::from(sst.clone()).encode_as(v1_0::Binary)?;
+        let sst_after: SharedSymbolTable = Element::read_one(encoded)?.try_into()?;
+        assert_eq!(sst_after, sst);
+
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(sst_after);
+
+        // A binary stream that imports the shared symbol table and then uses its symbols.
+        let data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0xEE, 0x93, // Annotation wrapper, length 19
+            0x81, 0x83, // $ion_symbol_table::
+            0xDE, 0x8F, // Struct, length 15
+            0x86, // imports:
+            0xBD, // List, length 13
+            0xDC, // Struct, length 12
+            0x84, 0x84, b's', b'y', b'm', b's', // name: "syms"
+            0x85, 0x21, 0x01, // version: 1
+            0x88, 0x21, 0x03, // max_id: 3
+            0x71, 0x0A, // $10
+            0x71, 0x0B, // $11
+            0x71, 0x0C, // $12
+        ];
+        let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), data)?;
+        assert_eq!(
+            reader.read_all_elements()?,
+            Sequence::from(vec![
+                Element::symbol("foo"),
+                Element::symbol(Symbol::unknown_text()),
+                Element::symbol("bar"),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn catalog_and_unresolved_symbol_id_policy_can_be_combined() -> IonResult<()> {
+        let sst = SharedSymbolTable::new("syms", 1, ["foo", "bar"])?;
+        let catalog = || {
+            let mut catalog = MapCatalog::new();
+            catalog.insert_table(sst.clone());
+            catalog
+        };
+
+        // A binary stream that imports the shared symbol table and then uses one of its symbols
+        // and a symbol ID that is out of range.
+        let data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, // IVM
+            0xEE, 0x93, // Annotation wrapper, length 19
+            0x81, 0x83, // $ion_symbol_table::
+            0xDE, 0x8F, // Struct, length 15
+            0x86, // imports:
+            0xBD, // List, length 13
+            0xDC, // Struct, length 12
+            0x84, 0x84, b's', b'y', b'm', b's', // name: "syms"
+            0x85, 0x21, 0x01, // version: 1
+            0x88, 0x21, 0x02, // max_id: 2
+            0x71, 0x0A, // $10
+            0x71, 0x0C, // $12
+        ];
+        let policy = UnresolvedSymbolIdPolicy::UnknownText;
+        let expected = Sequence::from(vec![
+            Element::symbol("foo"),
+            Element::symbol(Symbol::unknown_text_with_sid(12)),
+        ]);
+        // The options can be configured in either order without discarding one another.
+        let config = AnyEncoding
+            .with_catalog(catalog())
+            .with_unresolved_symbol_id_policy(policy);
+        assert_eq!(Reader::new(config, data)?.read_all_elements()?, expected);
+        let config = AnyEncoding
+            .with_unresolved_symbol_id_policy(policy)
+            .with_catalog(catalog());
+        assert_eq!(Reader::new(config, data)?.read_all_elements()?, expected);
+        Ok(())
+    }
 }
@@ -1,20 +1,26 @@
 use crate::raw_symbol_ref::{AsRawSymbolRef, RawSymbolRef};
 use crate::result::IonFailure;
-use crate::{IonResult, Str, Symbol};
+use crate::{IonResult, Str, Symbol, SymbolId};
 use std::borrow::Borrow;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 
 /// A reference to a fully resolved symbol. Like `Symbol` (a fully resolved symbol with a
-/// static lifetime), a `SymbolRef` may have known or undefined text (i.e. `$0`).
+/// static lifetime), a `SymbolRef` may have known or undefined text (i.e. `$0`). A `SymbolRef`
+/// with undefined text retains its symbol ID; see [`Symbol`] for details.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct SymbolRef<'a> {
     text: Option<&'a str>,
+    // If `text` is `None`, the symbol ID the symbol was read from. Otherwise, `0`.
+    unknown_text_sid: SymbolId,
 }
 
 impl<'a> Debug for SymbolRef<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.text().unwrap_or("$0"))
+        match self.text {
+            Some(text) => write!(f, "{text}"),
+            None => write!(f, "${}", self.unknown_text_sid),
+        }
     }
 }
 
@@ -24,19 +30,39 @@ impl<'a> SymbolRef<'a> {
         self.text
     }
 
+    /// If this symbol has unknown text, returns `Some(sid)` where `sid` is the symbol ID it was
+    /// read from (`0` for `$0`). If the symbol has known text, returns `None`.
+    pub fn unknown_text_sid(&self) -> Option<SymbolId> {
+        match self.text {
+            None => Some(self.unknown_text_sid),
+            Some(_) => None,
+        }
+    }
+
     /// Constructs a `SymbolRef` with unknown text.
     pub fn with_unknown_text() -> Self {
-        SymbolRef { text: None }
+        Self::with_unknown_text_sid(0)
+    }
+
+    /// Constructs a `SymbolRef` with unknown text that was read from symbol ID `sid`.
+    pub fn with_unknown_text_sid(sid: SymbolId) -> Self {
+        SymbolRef {
+            text: None,
+            unknown_text_sid: sid,
+        }
     }
 
     /// Constructs a `SymbolRef` with the specified text.
     pub fn with_text(text: &'a str) -> SymbolRef<'a> {
-        SymbolRef { text: Some(text) }
+        SymbolRef {
+            text: Some(text),
+            unknown_text_sid: 0,
+        }
     }
 
     pub fn to_owned(self) -> Symbol {
         match self.text {
-            None => Symbol::unknown_text(),
+            None => Symbol::unknown_text_with_sid(self.unknown_text_sid),
             Some(text) => Symbol::owned(Str::from(text)),
         }
     }
@@ -68,16 +94,14 @@ pub trait AsSymbolRef {
 // All text types can be viewed as a `SymbolRef`.
 impl<'a, A: AsRef<str> + 'a> AsSymbolRef for A {
     fn as_symbol_ref(&self) -> SymbolRef {
-        SymbolRef {
-            text: Some(self.as_ref()),
-        }
+        SymbolRef::with_text(self.as_ref())
     }
 }
 
 impl<'a> Hash for SymbolRef<'a> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self.text() {
-            None => 0.hash(state),
+            None => self.unknown_text_sid.hash(state),
             Some(text) => text.hash(state),
         }
     }
@@ -85,14 +109,15 @@ impl<'a> Hash for SymbolRef<'a> {
 
 impl<'a> From<&'a str> for SymbolRef<'a> {
     fn from(text: &'a str) -> Self {
-        Self { text: Some(text) }
+        Self::with_text(text)
     }
 }
 
 impl<'a> From<&'a Symbol> for SymbolRef<'a> {
     fn from(symbol: &'a Symbol) -> Self {
-        Self {
-            text: symbol.text(),
+        match symbol.text() {
+            Some(text) => Self::with_text(text),
+            None => Self::with_unknown_text_sid(symbol.unknown_text_sid().unwrap_or_default()),
         }
     }
 }
@@ -110,17 +135,13 @@ impl<'a> Borrow<str> for SymbolRef<'a> {
 // trait definitions, this cannot be achieved with `AsRef` or `Borrow`.
 impl AsSymbolRef for Symbol {
     fn as_symbol_ref(&self) -> SymbolRef {
-        self.text()
-            .map(SymbolRef::with_text)
-            .unwrap_or_else(SymbolRef::with_unknown_text)
+        self.into()
     }
 }
 
 impl AsSymbolRef for &Symbol {
     fn as_symbol_ref(&self) -> SymbolRef {
-        self.text()
-            .map(SymbolRef::with_text)
-            .unwrap_or_else(SymbolRef::with_unknown_text)
+        (*self).into()
     }
 }
 
@@ -131,6 +152,10 @@ impl<'a> AsRawSymbolRef for SymbolRef<'a> {
             Some(text) => RawSymbolRef::Text(text),
         }
     }
+
+    fn unknown_text_sid(&self) -> Option<SymbolId> {
+        SymbolRef::unknown_text_sid(self)
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use rustc_hash::FxHashMap;
@@ -14,6 +16,21 @@ pub struct SymbolTable {
     ion_version: IonVersion,
     symbols_by_id: Vec<Symbol>,
     ids_by_text: FxHashMap<Symbol, SymbolId>,
+    // The number of symbols that `initialize` added to the beginning of the table.
+    system_len: usize,
+    // Replaced each time the table is reset. Used to detect resets between snapshots and to tell
+    // whether a reader checkpoint's copy of the table is still current. See `next_generation`.
+    generation: usize,
+}
+
+/// Returns a generation number that has not been assigned to any other symbol table.
+///
+/// A table's symbols can only change by being appended to or by being reset, and each reset
+/// assigns a new generation. As a result, if a table's generation and length match those of a
+/// read-only copy that was taken from it earlier, the two tables still have the same contents.
+fn next_generation() -> usize {
+    static NEXT_GENERATION: AtomicUsize = AtomicUsize::new(0);
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
 }
 
 impl Default for SymbolTable {
@@ -31,6 +48,8 @@ impl SymbolTable {
             ion_version,
             symbols_by_id: Vec::with_capacity(INITIAL_SYMBOLS_CAPACITY),
             ids_by_text: FxHashMap::default(),
+            system_len: 0,
+            generation: next_generation(),
         };
         symbol_table.initialize();
         symbol_table
@@ -56,14 +75,31 @@ impl SymbolTable {
             .for_each(|text| {
                 let _sid = self.add_symbol_for_text(text);
             });
+        self.system_len = self.symbols_by_id.len();
     }
 
     pub(crate) fn reset(&mut self) {
         self.symbols_by_id.clear();
         self.ids_by_text.clear();
+        self.generation = next_generation();
         self.initialize();
     }
 
+    /// Returns `true` if `self` and `other` have the same generation and length. If `other` is a
+    /// read-only copy that was taken from `self` (or vice versa), this means that their contents
+    /// are still the same. If this returns `false`, the tables may or may not have the same
+    /// contents.
+    pub(crate) fn is_same_state_as(&self, other: &SymbolTable) -> bool {
+        self.generation == other.generation && self.symbols_by_id.len() == other.len()
+    }
+
+    /// Assigns the table a new generation without changing its contents. A modifiable table that
+    /// was cloned from a read-only copy must call this before it is modified; otherwise, it could
+    /// be mistaken for other copies of the same table. See [`Self::is_same_state_as`].
+    pub(crate) fn start_new_generation(&mut self) {
+        self.generation = next_generation();
+    }
+
     pub(crate) fn reset_to_version(&mut self, new_version: IonVersion) {
         self.ion_version = new_version;
         self.reset();
@@ -103,11 +139,30 @@ impl SymbolTable {
         }
     }
 
-    /// If defined, returns the Symbol ID associated with the provided text.
+    /// If defined, returns the Symbol ID associated with the provided text. Unlike
+    /// [`intern`](Self::intern), this never modifies the symbol table.
     pub fn sid_for<A: AsRef<str>>(&self, text: &A) -> Option<SymbolId> {
         self.ids_by_text.get(text.as_ref()).copied()
     }
 
+    /// Returns the Symbol ID associated with the provided text, adding the text to the symbol
+    /// table first if it is not already present.
+    pub fn intern<A: AsRef<str>>(&mut self, text: A) -> SymbolId {
+        match self.sid_for(&text) {
+            Some(sid) => sid,
+            None => self.add_symbol_for_text(text),
+        }
+    }
+
+    /// Calls [`intern`](Self::intern) on each of the provided texts, returning the Symbol IDs
+    /// associated with them in the same order.
+    pub fn intern_all<A: AsRef<str>, I: IntoIterator<Item = A>>(
+        &mut self,
+        texts: I,
+    ) -> Vec<SymbolId> {
+        texts.into_iter().map(|text| self.intern(text)).collect()
+    }
+
     /// If defined, returns the text associated with the provided Symbol ID.
     pub fn text_for(&self, sid: SymbolId) -> Option<&str> {
         self.symbols_by_id
@@ -154,4 +209,442 @@ impl SymbolTable {
     pub fn len(&self) -> usize {
         self.symbols_by_id.len()
     }
+
+    /// Returns the largest symbol ID defined in the table.
+    pub fn max_id(&self) -> SymbolId {
+        // The table always contains at least `$0`, so this cannot underflow.
+        self.symbols_by_id.len() - 1
+    }
+
+    /// Returns the number of system symbols at the beginning of the table, including `$0`.
+    /// Symbol IDs greater than or equal to this value were added by imports or local symbol
+    /// table definitions.
+    pub fn system_len(&self) -> usize {
+        self.system_len
+    }
+
+    /// Returns an iterator over each symbol ID in the table and its associated text, if known.
+    pub fn iter(&self) -> impl Iterator<Item = (SymbolId, Option<&str>)> {
+        self.symbols_by_id
+            .iter()
+            .enumerate()
+            .map(|(sid, symbol)| (sid, symbol.text()))
+    }
+
+    /// Returns the text of each symbol following the system symbols, in symbol ID order. Symbols
+    /// whose text is unknown are represented as `None`.
+    ///
+    /// The result can be passed to `Writer::with_exact_symbol_table` so that a writer will assign
+    /// the same symbol IDs that this table does.
+    pub fn to_exact_seed(&self) -> Vec<Option<String>> {
+        self.symbols_by_id[self.system_len()..]
+            .iter()
+            .map(|symbol| symbol.text().map(String::from))
+            .collect()
+    }
+
+    /// Captures the current contents of the symbol table. The resulting [`SymbolTableSnapshot`]
+    /// is independent of the table and is cheap to clone, making it suitable for comparing the
+    /// table's state at different points in a stream. See [`SymbolTableSnapshot::diff`].
+    pub fn snapshot(&self) -> SymbolTableSnapshot {
+        SymbolTableSnapshot {
+            generation: self.generation,
+            system_len: self.system_len(),
+            symbols: self.symbols_by_id.as_slice().into(),
+        }
+    }
+}
+
+/// An immutable copy of a [`SymbolTable`]'s contents at a point in time.
+///
+/// Its [`Display`] implementation prints one `$sid: "text"` line per symbol, which is useful
+/// when debugging symbol resolution.
+#[derive(Debug, Clone)]
+pub struct SymbolTableSnapshot {
+    generation: usize,
+    system_len: usize,
+    symbols: Arc<[Symbol]>,
+}
+
+impl SymbolTableSnapshot {
+    /// Returns the number of symbols in the snapshot.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns `true` if the snapshot contains no symbols. Snapshots of a [`SymbolTable`] always
+    /// contain at least the system symbols.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Returns the largest symbol ID in the snapshot.
+    pub fn max_id(&self) -> SymbolId {
+        self.symbols.len().saturating_sub(1)
+    }
+
+    /// Returns the number of system symbols at the beginning of the snapshot, including `$0`.
+    pub fn system_len(&self) -> usize {
+        self.system_len
+    }
+
+    /// Returns an iterator over each symbol ID in the snapshot and its associated text, if known.
+    pub fn iter(&self) -> impl Iterator<Item = (SymbolId, Option<&str>)> {
+        self.symbols
+            .iter()
+            .enumerate()
+            .map(|(sid, symbol)| (sid, symbol.text()))
+    }
+
+    /// Describes how the symbol table changed between this snapshot and a `later` snapshot of
+    /// the same table.
+    ///
+    /// If the table was reset in the interim (for example, by an IVM or by a local symbol table
+    /// that did not append to the active one), the diff reports the reset and treats every
+    /// non-system symbol in `later` as appended.
+    pub fn diff(&self, later: &SymbolTableSnapshot) -> SymbolTableDiff {
+        let was_reset =
+            later.generation != self.generation || later.symbols.len() < self.symbols.len();
+        let first_appended_id = if was_reset {
+            later.system_len
+        } else {
+            self.symbols.len()
+        };
+        SymbolTableDiff {
+            was_reset,
+            first_appended_id,
+            symbols: Arc::clone(&later.symbols),
+        }
+    }
+}
+
+impl Display for SymbolTableSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (sid, text) in self.iter() {
+            match text {
+                Some(text) => writeln!(f, "${sid}: {text:?}")?,
+                None => writeln!(f, "${sid}: <unknown text>")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The changes made to a symbol table between two [`SymbolTableSnapshot`]s.
+/// See [`SymbolTableSnapshot::diff`].
+#[derive(Debug, Clone)]
+pub struct SymbolTableDiff {
+    was_reset: bool,
+    first_appended_id: SymbolId,
+    symbols: Arc<[Symbol]>,
+}
+
+impl SymbolTableDiff {
+    /// Returns `true` if the symbol table was reset between the two snapshots.
+    pub fn was_reset(&self) -> bool {
+        self.was_reset
+    }
+
+    /// Returns `true` if the table was not reset and no symbols were appended.
+    pub fn is_empty(&self) -> bool {
+        !self.was_reset && self.first_appended_id >= self.symbols.len()
+    }
+
+    /// Returns an iterator over the symbol IDs and text of the symbols that were added.
+    pub fn appended(&self) -> impl Iterator<Item = (SymbolId, Option<&str>)> {
+        self.symbols[self.first_appended_id..]
+            .iter()
+            .enumerate()
+            .map(|(index, symbol)| (self.first_appended_id + index, symbol.text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::system_reader::SystemReader;
+    use crate::{
+        v1_0, AnyEncoding, IonResult, NewSymbolPolicy, Reader, SequenceWriter, WriteConfig, Writer,
+    };
+
+    const EXAMPLE_STREAM: &str = r#"
+        $ion_symbol_table::{symbols: ["foo", "bar", "baz"]}
+        foo
+        $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["quux"]}
+        quux
+        $ion_symbol_table::{symbols: ["hello"]}
+        hello
+    "#;
+
+    #[test]
+    fn iterate_symbol_ids_and_text() -> IonResult<()> {
+        let mut reader = SystemReader::new(AnyEncoding, EXAMPLE_STREAM);
+        let _lst = reader.next_item()?.expect_symbol_table()?;
+        let _foo = reader.next_item()?.expect_value()?;
+        let symbol_table = reader.symbol_table();
+        assert_eq!(symbol_table.system_len(), 10);
+        assert_eq!(symbol_table.len(), 13);
+        assert_eq!(symbol_table.max_id(), 12);
+        assert_eq!((0, None), symbol_table.iter().next().unwrap());
+        let local_symbols: Vec<_> = symbol_table
+            .iter()
+            .skip(symbol_table.system_len())
+            .collect();
+        assert_eq!(
+            local_symbols,
+            vec![(10, Some("foo")), (11, Some("bar")), (12, Some("baz"))]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_symbol_ids_and_text_1_1() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((symbol_table ["foo", "bar", "baz"]))
+            foo
+        "#;
+        let mut reader = SystemReader::new(AnyEncoding, ion);
+        let _ivm = reader.next_item()?.expect_ivm()?;
+        let _directive = reader.next_item()?.expect_encoding_directive()?;
+        let _foo = reader.next_item()?.expect_value()?;
+        let symbol_table = reader.symbol_table();
+        // The system symbols are the ones the table was initialized with, however many there are.
+        let system_len = SymbolTable::new(IonVersion::v1_1).len();
+        assert_eq!(symbol_table.system_len(), system_len);
+        assert_eq!(symbol_table.snapshot().system_len(), system_len);
+        assert_eq!(symbol_table.len(), system_len + 3);
+        let local_symbols: Vec<_> = symbol_table
+            .iter()
+            .skip(symbol_table.system_len())
+            .map(|(_sid, text)| text)
+            .collect();
+        assert_eq!(local_symbols, vec![Some("foo"), Some("bar"), Some("baz")]);
+        assert_eq!(
+            symbol_table.to_exact_seed(),
+            vec![
+                Some("foo".to_owned()),
+                Some("bar".to_owned()),
+                Some("baz".to_owned())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn diff_snapshots() -> IonResult<()> {
+        let mut reader = SystemReader::new(AnyEncoding, EXAMPLE_STREAM);
+        let _lst = reader.next_item()?.expect_symbol_table()?;
+        let _foo = reader.next_item()?.expect_value()?;
+        let first = reader.symbol_table().snapshot();
+        assert!(first.diff(&first).is_empty());
+
+        // An LST that appends to the active table
+        let _lst = reader.next_item()?.expect_symbol_table()?;
+        let _quux = reader.next_item()?.expect_value()?;
+        let second = reader.symbol_table().snapshot();
+        let diff = first.diff(&second);
+        assert!(!diff.was_reset());
+        assert_eq!(
+            diff.appended().collect::<Vec<_>>(),
+            vec![(13, Some("quux"))]
+        );
+
+        // An LST that replaces the active table
+        let _lst = reader.next_item()?.expect_symbol_table()?;
+        let _hello = reader.next_item()?.expect_value()?;
+        let third = reader.symbol_table().snapshot();
+        let diff = second.diff(&third);
+        assert!(diff.was_reset());
+        assert_eq!(
+            diff.appended().collect::<Vec<_>>(),
+            vec![(10, Some("hello"))]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn diff_detects_reset_to_same_length() {
+        let mut symbol_table = SymbolTable::new(IonVersion::v1_0);
+        symbol_table.add_symbol_for_text("foo");
+        let before = symbol_table.snapshot();
+        symbol_table.reset();
+        symbol_table.add_symbol_for_text("bar");
+        let diff = before.diff(&symbol_table.snapshot());
+        assert!(diff.was_reset());
+        assert_eq!(diff.appended().collect::<Vec<_>>(), vec![(10, Some("bar"))]);
+    }
+
+    #[test]
+    fn display_snapshot() {
+        let mut symbol_table = SymbolTable::new(IonVersion::v1_0);
+        symbol_table.add_symbol_for_text("foo");
+        symbol_table.add_placeholder();
+        symbol_table.add_symbol_for_text("quoted \"text\"");
+        let expected = r#"$0: <unknown text>
+$1: "$ion"
+$2: "$ion_1_0"
+$3: "$ion_symbol_table"
+$4: "name"
+$5: "version"
+$6: "imports"
+$7: "symbols"
+$8: "max_id"
+$9: "$ion_shared_symbol_table"
+$10: "foo"
+$11: <unknown text>
+$12: "quoted \"text\""
+"#;
+        assert_eq!(symbol_table.snapshot().to_string(), expected);
+    }
+
+    // A binary stream whose local symbol table includes a slot with unknown text ($11) and
+    // does not list its symbols in order of first use.
+    //     $ion_symbol_table::{symbols: ["quux", null, "foo", "bar", "baz"]}
+    //     foo bar::baz {quux: foo}
+    #[rustfmt::skip]
+    const EXAMPLE_BINARY_STREAM: &[u8] = &[
+        0xE0, 0x01, 0x00, 0xEA, // IVM
+        0xEE, 0x99, 0x81, 0x83, // $ion_symbol_table::
+        0xDE, 0x95, 0x87, // {symbols:
+        0xBE, 0x92, // [
+        0x84, b'q', b'u', b'u', b'x', // "quux"
+        0x0F, // null
+        0x83, b'f', b'o', b'o', // "foo"
+        0x83, b'b', b'a', b'r', // "bar"
+        0x83, b'b', b'a', b'z', // "baz"]}
+        0x71, 0x0C, // foo
+        0xE4, 0x81, 0x8D, 0x71, 0x0E, // bar::baz
+        0xD3, 0x8A, 0x71, 0x0C, // {quux: foo}
+    ];
+
+    #[test]
+    fn transcode_with_exact_seed() -> IonResult<()> {
+        // Binary to text, capturing the source stream's symbol table.
+        let mut reader = Reader::new(AnyEncoding, EXAMPLE_BINARY_STREAM)?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        while let Some(value) = reader.next()? {
+            writer.write(value)?;
+        }
+        let text = writer.close()?;
+        let seed = reader.symbol_table().to_exact_seed();
+        assert_eq!(
+            seed,
+            vec![
+                Some("quux".to_string()),
+                None,
+                Some("foo".to_string()),
+                Some("bar".to_string()),
+                Some("baz".to_string())
+            ]
+        );
+
+        // Text back to binary, reusing the source stream's symbol IDs.
+        let config = WriteConfig::<v1_0::Binary>::new()
+            .with_new_symbol_policy(NewSymbolPolicy::ErrorOnNewSymbols);
+        let mut writer = Writer::new(config, Vec::new())?.with_exact_symbol_table(seed.clone())?;
+        let mut reader = Reader::new(AnyEncoding, text)?;
+        while let Some(value) = reader.next()? {
+            writer.write(value)?;
+        }
+        assert_eq!(writer.close()?, EXAMPLE_BINARY_STREAM);
+
+        // Writing text that isn't in the seed is an error under `ErrorOnNewSymbols`...
+        let config = WriteConfig::<v1_0::Binary>::new()
+            .with_new_symbol_policy(NewSymbolPolicy::ErrorOnNewSymbols);
+        let mut writer = Writer::new(config, Vec::new())?.with_exact_symbol_table(seed.clone())?;
+        writer.write_symbol("foo")?;
+        let Err(error) = writer.write_symbol("hello") else {
+            panic!("writing a new symbol succeeded");
+        };
+        assert!(error.to_string().contains("symbol value"), "{error}");
+
+        // ...but is added to the table after the seeded symbols otherwise.
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?.with_exact_symbol_table(seed)?;
+        writer.write_symbol("hello")?;
+        let output = writer.close()?;
+        let mut reader = Reader::new(AnyEncoding, output)?;
+        assert_eq!(
+            reader.expect_next()?.read()?.expect_symbol()?.text(),
+            Some("hello")
+        );
+        assert_eq!(reader.symbol_table().sid_for(&"hello"), Some(15));
+        Ok(())
+    }
+
+    #[test]
+    fn intern_returns_existing_symbol_ids() {
+        let mut symbol_table = SymbolTable::default();
+        let foo = symbol_table.intern("foo");
+        assert_eq!(foo, 10);
+        assert_eq!(symbol_table.intern("foo"), foo);
+        assert_eq!(symbol_table.intern(String::from("foo")), foo);
+        assert_eq!(symbol_table.len(), 11);
+
+        let sids = symbol_table.intern_all(["bar", "foo", "baz", "bar"]);
+        assert_eq!(sids, vec![11, foo, 12, 11]);
+        assert_eq!(symbol_table.len(), 13);
+        assert_eq!(symbol_table.text_for(12), Some("baz"));
+    }
+
+    #[test]
+    fn sid_for_does_not_modify_the_table() {
+        let mut symbol_table = SymbolTable::default();
+        symbol_table.intern("foo");
+        let len = symbol_table.len();
+        assert_eq!(symbol_table.sid_for(&"foo"), Some(10));
+        assert_eq!(symbol_table.sid_for(&"bar"), None);
+        assert_eq!(symbol_table.sid_for(&"bar"), None);
+        assert_eq!(symbol_table.len(), len);
+    }
+
+    #[test]
+    fn system_symbols_have_fixed_ids() {
+        let mut symbol_table = SymbolTable::default();
+        let system_symbols = [
+            "$ion",
+            "$ion_1_0",
+            "$ion_symbol_table",
+            "name",
+            "version",
+            "imports",
+            "symbols",
+            "max_id",
+            "$ion_shared_symbol_table",
+        ];
+        for (sid, text) in (1..).zip(system_symbols) {
+            assert_eq!(symbol_table.sid_for(&text), Some(sid), "{text}");
+        }
+        // Interning system symbol text doesn't add to the table
+        let sids = symbol_table.intern_all(system_symbols);
+        assert_eq!(sids, (1..=9).collect::<Vec<SymbolId>>());
+        assert_eq!(symbol_table.len(), symbol_table.system_len());
+    }
+
+    #[test]
+    fn is_same_state_as() {
+        let mut table = SymbolTable::default();
+        table.add_symbol_for_text("foo");
+        let copy = table.clone();
+        assert!(table.is_same_state_as(&copy));
+
+        table.add_symbol_for_text("bar");
+        assert!(!table.is_same_state_as(&copy));
+
+        // A reset changes the generation even if the table ends up the same length.
+        let mut reset = copy.clone();
+        reset.reset();
+        reset.add_symbol_for_text("foo");
+        assert_eq!(reset.len(), copy.len());
+        assert!(!reset.is_same_state_as(&copy));
+
+        // Tables don't share a generation unless one is a copy of the other.
+        let mut other = SymbolTable::default();
+        other.add_symbol_for_text("foo");
+        assert!(!other.is_same_state_as(&copy));
+
+        let mut restored = copy.clone();
+        restored.start_new_generation();
+        assert!(!restored.is_same_state_as(&copy));
+    }
 }
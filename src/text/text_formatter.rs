@@ -267,21 +267,35 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
 
     /// Returns `true` if this token's text resembles a symbol ID literal. For example: `'$99'` is a
     /// symbol with the text `$99`. However, `$99` (without quotes) is a symbol ID that maps to
-    /// different text.
+    /// different text. The text `$` (with no digits) is also quoted.
     fn token_resembles_symbol_id(token: &str) -> bool {
-        if token.is_empty() {
-            return false;
+        match token.strip_prefix('$') {
+            Some(digits) => digits.chars().all(|c| c.is_ascii_digit()),
+            None => false,
         }
-        let mut chars = token.chars();
-        let first = chars.next().unwrap();
-        first == '$' && chars.all(|c| c.is_numeric())
+    }
+
+    /// Returns `true` if this token's text resembles an Ion version marker. For example: `'$ion_1_0'`
+    /// is a symbol with the text `$ion_1_0`. However, `$ion_1_0` (without quotes) at the top level
+    /// is a version marker rather than a value.
+    fn token_resembles_version_marker(token: &str) -> bool {
+        let Some(version) = token.strip_prefix("$ion_") else {
+            return false;
+        };
+        let Some((major, minor)) = version.split_once('_') else {
+            return false;
+        };
+        let is_number = |text: &str| !text.is_empty() && text.chars().all(|c| c.is_ascii_digit());
+        is_number(major) && is_number(minor)
     }
 
     pub(crate) fn format_symbol_token<A: AsRawSymbolRef>(&mut self, token: A) -> IonResult<()> {
         match token.as_raw_symbol_token_ref() {
             RawSymbolRef::SymbolId(sid) => write!(self.output, "${sid}")?,
             RawSymbolRef::Text(text)
-                if Self::token_is_keyword(text) || Self::token_resembles_symbol_id(text) =>
+                if Self::token_is_keyword(text)
+                    || Self::token_resembles_symbol_id(text)
+                    || Self::token_resembles_version_marker(text) =>
             {
                 // Write the symbol text in single quotes
                 write!(self.output, "'{text}'")?;
@@ -505,6 +519,7 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
 mod formatter_test {
     use crate::text::text_formatter::FmtValueFormatter;
     use crate::{ion_list, ion_sexp, ion_struct, IonResult, IonType, Timestamp};
+    use rstest::*;
 
     fn formatter<F>(mut f: F, expected: &str)
     where
@@ -570,6 +585,27 @@ mod formatter_test {
         Ok(())
     }
 
+    #[rstest]
+    #[case::symbol_id_lookalike("$99", "'$99'")]
+    #[case::symbol_id_zero_lookalike("$0", "'$0'")]
+    #[case::bare_dollar_sign("$", "'$'")]
+    #[case::ivm_lookalike("$ion_1_0", "'$ion_1_0'")]
+    #[case::future_ivm_lookalike("$ion_12_345", "'$ion_12_345'")]
+    #[case::keyword("null", "'null'")]
+    #[case::dollar_identifier("$foo", "$foo")]
+    #[case::dollar_digits_and_letters("$99a", "$99a")]
+    #[case::system_symbol("$ion_symbol_table", "$ion_symbol_table")]
+    #[case::incomplete_ivm("$ion_1", "$ion_1")]
+    #[case::ivm_with_suffix("$ion_1_0_x", "$ion_1_0_x")]
+    fn test_format_symbol_text_quoting(#[case] text: &str, #[case] expected: &str) {
+        formatter(|ivf| ivf.format_symbol(text), expected);
+    }
+
+    #[test]
+    fn test_format_symbol_id() {
+        formatter(|ivf| ivf.format_symbol(99usize), "$99");
+    }
+
     #[test]
     fn test_format_string() -> IonResult<()> {
         formatter(|ivf| ivf.format_string("bar"), "\"bar\"");
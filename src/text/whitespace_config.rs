@@ -1,46 +1,106 @@
-#[derive(Clone, Copy)]
+use std::borrow::Cow;
+
+use crate::LineEnding;
+
+#[derive(Clone, Debug)]
 pub(crate) struct WhitespaceConfig {
     // Top-level values are independent of other values in the stream, we may separate differently
-    pub(crate) space_between_top_level_values: &'static str,
+    pub(crate) space_between_top_level_values: Cow<'static, str>,
     // Non-top-level values are within a container
-    pub(crate) space_between_nested_values: &'static str,
+    pub(crate) space_between_nested_values: Cow<'static, str>,
     // Indentation is repeated before nested values, corresponding to the level of nesting
-    pub(crate) indentation: &'static str,
+    pub(crate) indentation: Cow<'static, str>,
     // e.g. after 'foo:' in "{foo: bar}"
-    pub(crate) space_after_field_name: &'static str,
+    pub(crate) space_after_field_name: Cow<'static, str>,
     // Between the container open and any value in it
-    pub(crate) space_after_container_start: &'static str,
+    pub(crate) space_after_container_start: Cow<'static, str>,
+    // Between the last value in a container and the container close when there is no trailing
+    // delimiter
+    pub(crate) space_before_container_end: Cow<'static, str>,
+    // Whether the last value in a container is followed by a delimiter (e.g. `[1, 2, ]`)
+    pub(crate) trailing_delimiters: bool,
+}
+
+impl WhitespaceConfig {
+    /// Replaces the indentation written for each level of depth. Formats that do not indent
+    /// nested values (i.e. those that do not write each nested value on its own line) are
+    /// unaffected.
+    pub(crate) fn with_indentation(mut self, indentation: &str) -> Self {
+        if !self.indentation.is_empty() {
+            self.indentation = Cow::Owned(indentation.to_owned());
+        }
+        self
+    }
+
+    pub(crate) fn with_space_after_field_name(mut self, space_after_field_name: bool) -> Self {
+        if !space_after_field_name {
+            self.space_after_field_name = Cow::Borrowed("");
+        }
+        self
+    }
+
+    pub(crate) fn with_trailing_delimiters(mut self, trailing_delimiters: bool) -> Self {
+        self.trailing_delimiters = trailing_delimiters;
+        self
+    }
+
+    /// Rewrites each newline in the configured whitespace to use the specified line ending.
+    pub(crate) fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        if line_ending == LineEnding::Lf {
+            return self;
+        }
+        let line_ending = line_ending.as_str();
+        for whitespace in [
+            &mut self.space_between_top_level_values,
+            &mut self.space_between_nested_values,
+            &mut self.space_after_container_start,
+            &mut self.space_before_container_end,
+        ] {
+            if whitespace.contains('\n') {
+                *whitespace = Cow::Owned(whitespace.replace('\n', line_ending));
+            }
+        }
+        self
+    }
 }
 
-pub(crate) static COMPACT_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
+pub(crate) const COMPACT_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
     // Single space between top level values
-    space_between_top_level_values: " ",
+    space_between_top_level_values: Cow::Borrowed(" "),
     // Single space between values
-    space_between_nested_values: " ",
+    space_between_nested_values: Cow::Borrowed(" "),
     // No indentation
-    indentation: "",
+    indentation: Cow::Borrowed(""),
     // Single space between field names and values
-    space_after_field_name: " ",
+    space_after_field_name: Cow::Borrowed(" "),
     // The first value in a container appears next to the opening delimiter
-    space_after_container_start: "",
+    space_after_container_start: Cow::Borrowed(""),
+    // The last value in a container appears next to the closing delimiter
+    space_before_container_end: Cow::Borrowed(""),
+    // Each value in a list or struct is followed by a comma
+    trailing_delimiters: true,
 };
 
-pub(crate) static LINES_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
+pub(crate) const LINES_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
     // Each value appears on its own line
-    space_between_top_level_values: "\n",
+    space_between_top_level_values: Cow::Borrowed("\n"),
     // Otherwise use the compact/default layout from `DEFAULT_WS_CONFIG`
     ..COMPACT_WHITESPACE_CONFIG
 };
 
-pub(crate) static PRETTY_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
+pub(crate) const PRETTY_WHITESPACE_CONFIG: WhitespaceConfig = WhitespaceConfig {
     // Each top-level value starts on its own line
-    space_between_top_level_values: "\n",
+    space_between_top_level_values: Cow::Borrowed("\n"),
     // Each value appears on its own line
-    space_between_nested_values: "\n",
+    space_between_nested_values: Cow::Borrowed("\n"),
     // Values get two spaces of indentation per level of depth
-    indentation: "  ",
+    indentation: Cow::Borrowed("  "),
     // Field names and values are separated by a single space
-    space_after_field_name: " ",
+    space_after_field_name: Cow::Borrowed(" "),
     // The first value in a container appears on a line by itself
-    space_after_container_start: "\n",
+    space_after_container_start: Cow::Borrowed("\n"),
+    // The closing delimiter appears on a line by itself
+    space_before_container_end: Cow::Borrowed("\n"),
+    // Each value in a list or struct is followed by a comma
+    trailing_delimiters: true,
 };
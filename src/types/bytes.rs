@@ -18,7 +18,7 @@ use std::cmp::Ordering;
 /// let bytes: Bytes = b"world".into();
 /// assert_eq!(&bytes, b"world".as_slice());
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub struct Bytes {
     data: Vec<u8>,
 }
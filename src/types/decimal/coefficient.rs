@@ -4,16 +4,14 @@ use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::ops::Neg;
 
-use num_traits::Zero;
-
-use crate::result::{IonError, IonFailure};
+use crate::result::{DecodingErrorKind, IonError, IonFailure};
 use crate::types::CountDecimalDigits;
 use crate::IonResult;
 use crate::{Int, UInt};
 
 /// Indicates whether the `Coefficient`'s magnitude is less than 0 (negative) or not (positive).
 /// When the magnitude is zero, the `Sign` can be used to distinguish between -0 and 0.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum Sign {
     Negative,
     Positive,
@@ -27,29 +25,30 @@ pub enum Sign {
 /// they will be considered unequal.
 ///
 /// While the Ion specification allows this type to be of arbitrary size, this implementation currently
-/// supports coefficients in the integer range supported by `i128`.
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+/// supports coefficients in the integer range supported by `i128`. Converting an [`Int`] outside of
+/// that range to a `Coefficient` will fail with a
+/// [`NumericOverflow`](crate::DecodingErrorKind::NumericOverflow) error.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Coefficient {
     /// This field exists solely to preserve the distinction between `0` and `-0`.
     /// It will agree with the sign information in the `magnitude` field in all cases *except*
     /// when the coefficient is `-0`.
     sign: Sign,
-    magnitude: Int,
+    magnitude: i128,
 }
 
 impl Coefficient {
     pub const ZERO: Coefficient = Coefficient {
         sign: Sign::Positive,
-        magnitude: Int::ZERO,
+        magnitude: 0,
     };
 
     pub const NEGATIVE_ZERO: Coefficient = Coefficient {
         sign: Sign::Negative,
-        magnitude: Int::ZERO,
+        magnitude: 0,
     };
 
-    pub(crate) fn new<I: Into<Int>>(value: I) -> Self {
-        let value: Int = value.into();
+    pub(crate) fn new(value: i128) -> Self {
         let sign = if value.is_negative() {
             Sign::Negative
         } else {
@@ -66,7 +65,7 @@ impl Coefficient {
     }
 
     pub fn magnitude(&self) -> UInt {
-        self.magnitude.unsigned_abs()
+        self.magnitude.unsigned_abs().into()
     }
 
     pub fn is_negative(&self) -> bool {
@@ -82,7 +81,7 @@ impl Coefficient {
     pub(crate) fn negative_zero() -> Self {
         Coefficient {
             sign: Sign::Negative,
-            magnitude: 0u64.into(),
+            magnitude: 0,
         }
     }
 
@@ -97,12 +96,12 @@ impl Coefficient {
     }
 
     pub(crate) fn is_zero_with_sign(&self, test_sign: Sign) -> bool {
-        self.sign == test_sign && self.magnitude.is_zero()
+        self.sign == test_sign && self.magnitude == 0
     }
 
     /// Returns true if the Coefficient represents a zero of any sign.
     pub fn is_zero(&self) -> bool {
-        self.magnitude().is_zero()
+        self.magnitude == 0
     }
 
     /// If the value can fit in an i64, return it as such. This is useful for
@@ -112,7 +111,7 @@ impl Coefficient {
             // Returning an unsigned zero would be lossy.
             return None;
         }
-        match i64::try_from(self.magnitude.data) {
+        match i64::try_from(self.magnitude) {
             Ok(signed) => match self.sign {
                 Sign::Negative => Some(signed.neg()),
                 Sign::Positive => Some(signed),
@@ -124,12 +123,22 @@ impl Coefficient {
     /// If the value can be represented as an `i128`, return it as such.
     /// If the coefficient is negative zero or outside the range of an `i128`, returns `None`.
     pub(crate) fn as_int(&self) -> Option<Int> {
+        self.as_i128().map(Int::from)
+    }
+
+    /// If the coefficient is not negative zero, returns it as an `i128`. Otherwise, returns `None`.
+    pub(crate) fn as_i128(&self) -> Option<i128> {
         if self.is_negative_zero() {
             // Returning an unsigned zero would be lossy.
             return None;
         }
         Some(self.magnitude)
     }
+
+    /// Returns the unsigned magnitude of this coefficient as a `u128`.
+    pub(crate) fn unsigned_magnitude(&self) -> u128 {
+        self.magnitude.unsigned_abs()
+    }
 }
 
 // This macro makes it possible to turn unsigned integers into a Coefficient using `.into()`.
@@ -137,7 +146,7 @@ macro_rules! impl_coefficient_from_unsigned_int_types {
     ($($t:ty),*) => ($(
         impl From<$t> for Coefficient {
             fn from(value: $t) -> Coefficient {
-                Coefficient::new(value)
+                Coefficient::new(value as i128)
             }
         }
     )*)
@@ -149,12 +158,34 @@ macro_rules! impl_coefficient_from_signed_int_types {
     ($($t:ty),*) => ($(
         impl From<$t> for Coefficient {
             fn from(value: $t) -> Coefficient {
-                Coefficient::new(value)
+                Coefficient::new(value as i128)
             }
         }
     )*)
 }
-impl_coefficient_from_signed_int_types!(i8, i16, i32, i64, i128, isize, Int);
+impl_coefficient_from_signed_int_types!(i8, i16, i32, i64, i128, isize);
+
+impl TryFrom<Int> for Coefficient {
+    type Error = IonError;
+
+    fn try_from(value: Int) -> Result<Self, Self::Error> {
+        Coefficient::try_from(&value)
+    }
+}
+
+impl TryFrom<&Int> for Coefficient {
+    type Error = IonError;
+
+    fn try_from(value: &Int) -> Result<Self, Self::Error> {
+        match value.as_i128() {
+            Some(value) => Ok(Coefficient::new(value)),
+            None => IonResult::decoding_error_of_kind(
+                DecodingErrorKind::NumericOverflow,
+                format!("Int {value} is outside the supported range of a decimal coefficient"),
+            ),
+        }
+    }
+}
 
 impl TryFrom<Coefficient> for Int {
     type Error = IonError;
@@ -163,7 +194,7 @@ impl TryFrom<Coefficient> for Int {
         if value.is_negative_zero() {
             return IonResult::illegal_operation("cannot convert negative zero Coefficient to Int");
         }
-        Ok(value.magnitude)
+        Ok(value.magnitude.into())
     }
 }
 
@@ -182,7 +213,7 @@ impl TryFrom<Coefficient> for UInt {
         if value.is_negative() {
             return IonResult::illegal_operation("cannot convert a negative Coefficient to a UInt");
         }
-        Ok(value.magnitude.unsigned_abs())
+        Ok(value.magnitude.unsigned_abs().into())
     }
 }
 
@@ -296,18 +327,33 @@ mod coefficient_tests {
         assert_eq!(Int::try_from(Coefficient::new(5)), Ok(Int::from(5)));
         assert_eq!(Int::try_from(Coefficient::new(-5)), Ok(Int::from(-5)));
 
-        let enormous_int = Int::try_from(12345678901234567890123456789u128).unwrap();
+        let enormous_int = 12345678901234567890123456789i128;
         assert_eq!(
             Int::try_from(Coefficient::new(enormous_int)),
-            Ok(enormous_int)
+            Ok(Int::from(enormous_int))
         );
         assert_eq!(
             Int::try_from(Coefficient::new(enormous_int.neg())),
-            Ok(enormous_int.neg())
+            Ok(Int::from(enormous_int.neg()))
         );
 
         // Zeros
         assert_eq!(Int::try_from(Coefficient::new(0)), Ok(Int::from(0)));
         assert!(Int::try_from(Coefficient::negative_zero()).is_err());
     }
+
+    #[test]
+    fn convert_from_int() {
+        assert_eq!(
+            Coefficient::try_from(Int::from(i128::MIN)),
+            Ok(Coefficient::new(i128::MIN))
+        );
+        let too_large = Int::from(u128::MAX);
+        assert_eq!(
+            Coefficient::try_from(too_large)
+                .unwrap_err()
+                .decoding_error_kind(),
+            Some(DecodingErrorKind::NumericOverflow)
+        );
+    }
 }
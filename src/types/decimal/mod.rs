@@ -6,6 +6,7 @@ use crate::decimal::coefficient::{Coefficient, Sign};
 use crate::ion_data::{IonEq, IonOrd};
 use crate::result::{IonError, IonFailure};
 use crate::{Int, IonResult, UInt};
+use num_bigint::BigInt;
 use num_traits::Zero;
 use std::convert::{TryFrom, TryInto};
 use std::fmt::{Display, Formatter};
@@ -36,9 +37,46 @@ pub mod coefficient;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// ## Equality and ordering
+///
+/// `Decimal` supports two different notions of equality:
+///
+/// * [`PartialEq`], [`PartialOrd`], and [`Ord`] compare decimals _numerically_. `1.5` and `1.50`
+///   are equal, as are `0d0` and `-0d0`. [`Decimal::numerically_eq`] is an explicit spelling of
+///   this relation.
+/// * Ion equivalence (as used by [`IonData`](crate::IonData) and by [`Element`](crate::Element)'s
+///   `PartialEq` implementation) follows the Ion data model, in which the coefficient and exponent
+///   must both match. `1.5` and `1.50` have different precisions and are _not_ equivalent, nor
+///   are `0d0` and `-0d0`.
+///
+/// ```
+/// use ion_rs::{Decimal, Element, IonData};
+/// let d1 = Decimal::new(15, -1); // 1.5
+/// let d2 = Decimal::new(150, -2); // 1.50
+/// // Numerically equal...
+/// assert_eq!(d1, d2);
+/// assert!(d1.numerically_eq(&d2));
+/// // ...but not equivalent in the Ion data model.
+/// assert_ne!(IonData::from(d1), IonData::from(d2));
+/// assert_ne!(Element::from(d1), Element::from(d2));
+/// ```
+///
+/// Callers that need some other policy can build it from [`Decimal::coefficient`],
+/// [`Decimal::exponent`], and [`Decimal::precision`].
+///
+/// ## Arithmetic
+///
+/// [`Decimal::checked_add`], [`Decimal::checked_sub`], and [`Decimal::checked_mul`] compute exact
+/// results. Because a `Decimal`'s [`Coefficient`] is limited to the range of an `i128` (which
+/// allows `Decimal`, and the [`Timestamp`](crate::Timestamp)s that may contain one, to be `Copy`),
+/// a result whose coefficient does not fit in that range is not promoted to a larger integer
+/// representation. Instead, the operation returns an [`IonError`]. Intermediate values are not
+/// subject to this limit; `(2 * 10^38) + -(10^38)` succeeds even though rewriting `2 * 10^38`
+/// with an exponent of `0` would not fit in an `i128`.
 #[derive(Copy, Clone, Debug)]
 pub struct Decimal {
-    // A Coefficient is a `(Sign, UInt)` pair supporting integers of arbitrary size
+    // A Coefficient is a `(Sign, i128)` pair that can represent negative zero
     pub(crate) coefficient: Coefficient,
     pub(crate) exponent: i64,
 }
@@ -105,6 +143,17 @@ impl Decimal {
         }
     }
 
+    /// Returns `true` if this `Decimal` and `other` represent the same number, regardless of their
+    /// precision or the sign of a zero. For example, `1.5`, `1.50`, and `15d-1` are all
+    /// numerically equal, as are `0d0` and `-0d3`.
+    ///
+    /// This is the same relation used by `Decimal`'s [`PartialEq`] implementation. To test whether
+    /// two decimals are equivalent in the Ion data model (same coefficient and exponent), wrap them
+    /// in [`IonData`](crate::IonData).
+    pub fn numerically_eq(&self, other: &Decimal) -> bool {
+        Decimal::compare(self, other) == Ordering::Equal
+    }
+
     /// Returns `true` if this Decimal is a zero of any sign or exponent.
     pub fn is_zero(&self) -> bool {
         self.coefficient.magnitude().is_zero()
@@ -113,7 +162,144 @@ impl Decimal {
     /// Returns true if this Decimal's coefficient has a negative sign AND a magnitude greater than
     /// zero. Otherwise, returns false. (Negative zero returns false.)
     pub fn is_less_than_zero(&self) -> bool {
-        self.coefficient.sign() == Sign::Negative && self.coefficient.magnitude().data > 0
+        self.coefficient.sign() == Sign::Negative && self.coefficient.unsigned_magnitude() > 0
+    }
+
+    /// Returns the sum of this `Decimal` and `other`.
+    ///
+    /// The result's exponent is the smaller of the two operands' exponents, so no precision is
+    /// lost: `1.5 + 2.25` is `3.75`, and `1.50 + 2` is `3.50`. The sum of two zeros is negative
+    /// zero only if both operands are negative zero; any other sum that is zero (like `1.5 + -1.5`)
+    /// is positive zero.
+    ///
+    /// If the result's coefficient would not fit in an `i128`, returns an [`IonError`]. See
+    /// [Arithmetic](Decimal#arithmetic).
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::{Decimal, IonData};
+    /// let sum = Decimal::new(150, -2).checked_add(&Decimal::new(2, 0))?;
+    /// assert_eq!(IonData::from(sum), IonData::from(Decimal::new(350, -2)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_add(&self, other: &Decimal) -> IonResult<Decimal> {
+        self.checked_sum(other, false)
+    }
+
+    /// Returns the result of subtracting `other` from this `Decimal`. This is equivalent to
+    /// adding the negation of `other`; see [`Decimal::checked_add`] for details. In particular,
+    /// `-0 - 0` is negative zero.
+    pub fn checked_sub(&self, other: &Decimal) -> IonResult<Decimal> {
+        self.checked_sum(other, true)
+    }
+
+    /// Adds `other` (or, if `subtract` is true, its negation) to this `Decimal`.
+    fn checked_sum(&self, other: &Decimal, subtract: bool) -> IonResult<Decimal> {
+        let exponent = self.exponent.min(other.exponent);
+        let sum = self
+            .coefficient_with_exponent(exponent)
+            .zip(other.coefficient_with_exponent(exponent))
+            .and_then(|(c1, c2)| {
+                if subtract {
+                    c1.checked_sub(c2)
+                } else {
+                    c1.checked_add(c2)
+                }
+            })
+            // If an operand or the sum overflowed an i128, the operands may still cancel out.
+            .or_else(|| self.big_checked_sum(other, exponent, subtract))
+            .ok_or_else(|| {
+                Self::overflow_error(if subtract { "subtraction" } else { "addition" })
+            })?;
+        // The sum is only negative zero if both operands (after any negation) are negative zero.
+        let other_is_negative_zero = if subtract {
+            other.coefficient.is_positive_zero()
+        } else {
+            other.coefficient.is_negative_zero()
+        };
+        if sum == 0 && self.coefficient.is_negative_zero() && other_is_negative_zero {
+            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        }
+        Ok(Decimal::new(sum, exponent))
+    }
+
+    /// Returns the product of this `Decimal` and `other`.
+    ///
+    /// The result's exponent is the sum of the two operands' exponents: `1.5 * 2.25` is `3.375`,
+    /// and `1.50 * 2` is `3.00`. As with integer multiplication, the result is negative (including
+    /// negative zero) if exactly one of the operands is negative.
+    ///
+    /// If the result's coefficient would not fit in an `i128`, returns an [`IonError`]. See
+    /// [Arithmetic](Decimal#arithmetic).
+    pub fn checked_mul(&self, other: &Decimal) -> IonResult<Decimal> {
+        let overflow = || Self::overflow_error("multiplication");
+        let exponent = self
+            .exponent
+            .checked_add(other.exponent)
+            .ok_or_else(overflow)?;
+        let product = self
+            .coefficient_value()
+            .checked_mul(other.coefficient_value())
+            .ok_or_else(overflow)?;
+        if product == 0 && self.coefficient.is_negative() != other.coefficient.is_negative() {
+            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        }
+        Ok(Decimal::new(product, exponent))
+    }
+
+    /// Returns the coefficient as an `i128`. Negative zero is returned as `0`.
+    fn coefficient_value(&self) -> i128 {
+        self.coefficient.as_i128().unwrap_or(0)
+    }
+
+    /// Returns the coefficient that this `Decimal` would have if it were rewritten using the
+    /// specified exponent, which must be less than or equal to its current exponent. Returns
+    /// `None` if that coefficient would not fit in an `i128`.
+    fn coefficient_with_exponent(&self, exponent: i64) -> Option<i128> {
+        let coefficient = self.coefficient_value();
+        if coefficient == 0 {
+            return Some(0);
+        }
+        let exponent_delta = u32::try_from(self.exponent.checked_sub(exponent)?).ok()?;
+        coefficient.checked_mul(10i128.checked_pow(exponent_delta)?)
+    }
+
+    /// Like the `i128` arithmetic in [`Self::checked_sum`], but aligns the operands' coefficients
+    /// using `BigInt`s. Returns `None` if the sum does not fit in an `i128`.
+    #[cold]
+    #[inline(never)]
+    fn big_checked_sum(&self, other: &Decimal, exponent: i64, subtract: bool) -> Option<i128> {
+        let c1 = self.big_coefficient_with_exponent(exponent)?;
+        let c2 = other.big_coefficient_with_exponent(exponent)?;
+        let sum = if subtract { c1 - c2 } else { c1 + c2 };
+        i128::try_from(sum).ok()
+    }
+
+    /// Like [`Self::coefficient_with_exponent`], but returns a `BigInt`. Returns `None` if
+    /// rewriting the coefficient requires so many additional digits that no `i128` could cancel
+    /// it out.
+    fn big_coefficient_with_exponent(&self, exponent: i64) -> Option<BigInt> {
+        // The other operand of a sum already has the smaller exponent, so its coefficient is an
+        // `i128` with at most 39 digits. A non-zero coefficient shifted left by 40 or more digits
+        // is too large to be brought back into the range of an `i128` by adding it.
+        const MAX_EXPONENT_DELTA: u32 = 39;
+        let coefficient = self.coefficient_value();
+        if coefficient == 0 {
+            return Some(BigInt::zero());
+        }
+        let exponent_delta = u32::try_from(self.exponent.checked_sub(exponent)?).ok()?;
+        if exponent_delta > MAX_EXPONENT_DELTA {
+            return None;
+        }
+        Some(BigInt::from(coefficient) * BigInt::from(10).pow(exponent_delta))
+    }
+
+    fn overflow_error(operation: &str) -> IonError {
+        IonError::illegal_operation(format!(
+            "decimal {operation} overflowed; the result's coefficient must fit in an i128 and its exponent in an i64"
+        ))
     }
 
     /// Semantically identical to `self >= Decimal::new(1, 0)`, but much cheaper to compute.
@@ -194,15 +380,17 @@ impl Decimal {
         // d1 has the larger exponent (3). We need to scale its coefficient up to d2's 10^2 scale.
         // We do this by multiplying it times 10^exponent_delta, which is 1 in this case.
         // This lets us compare 80 and 80, determining that the decimals are equal.
-        let mut scaled_coefficient: u128 = d1.coefficient.magnitude().data;
+        let mut scaled_coefficient: u128 = d1.coefficient.unsigned_magnitude();
         scaled_coefficient *= 10u128.pow(exponent_delta as u32);
         UInt::from(scaled_coefficient).cmp(&d2.coefficient.magnitude())
     }
 }
 
+/// Two Decimals are considered equal (though not necessarily IonEq) if they represent the same
+/// number. Precision and the sign of zero are ignored. See [`Decimal::numerically_eq`].
 impl PartialEq for Decimal {
     fn eq(&self, other: &Self) -> bool {
-        self.cmp(other) == Ordering::Equal
+        self.numerically_eq(other)
     }
 }
 
@@ -263,16 +451,20 @@ macro_rules! impl_decimal_from_signed_primitive_integer {
     ($($t:ty),*) => ($(
         impl From<$t> for Decimal {
             fn from(value: $t) -> Self {
-                Decimal::new(Coefficient::new(value), 0)
+                Decimal::new(Coefficient::new(value as i128), 0)
             }
         }
     )*)
 }
 impl_decimal_from_signed_primitive_integer!(i8, i16, i32, i64, isize);
 
-impl From<Int> for Decimal {
-    fn from(value: Int) -> Self {
-        Decimal::new(value, 0)
+impl TryFrom<Int> for Decimal {
+    type Error = IonError;
+
+    /// Attempts to create a Decimal from an `Int`. Returns an Error if the `Int` is outside the
+    /// supported range of a [`Coefficient`].
+    fn try_from(value: Int) -> Result<Self, Self::Error> {
+        Ok(Decimal::new(Coefficient::try_from(value)?, 0))
     }
 }
 
@@ -443,7 +635,7 @@ impl Display for Decimal {
 mod decimal_tests {
     use crate::decimal::coefficient::Coefficient;
     use crate::result::IonResult;
-    use crate::{Decimal, Int};
+    use crate::{ion_list, ion_struct, Decimal, Element, Int, IonData};
 
     use num_traits::Float;
     use std::cmp::Ordering;
@@ -533,6 +725,71 @@ mod decimal_tests {
         assert_eq!(decimal1.ion_eq(&decimal2), ion_eq_expected);
     }
 
+    #[rstest]
+    // Each case lists two decimals followed by whether they are expected to be:
+    //   * numerically equal (`==` and `numerically_eq`)
+    //   * Ion equivalent (`IonEq`, `IonData`, and `Element` equality)
+    // and their expected numeric ordering.
+    #[case::different_precision(Decimal::new(15, -1), Decimal::new(150, -2), true, false, Ordering::Equal)]
+    #[case::same_precision(Decimal::new(15, -1), Decimal::new(15, -1), true, true, Ordering::Equal)]
+    #[case::zero_and_negative_zero(
+        Decimal::ZERO,
+        Decimal::NEGATIVE_ZERO,
+        true,
+        false,
+        Ordering::Equal
+    )]
+    #[case::negative_zeros(
+        Decimal::NEGATIVE_ZERO,
+        Decimal::negative_zero(),
+        true,
+        true,
+        Ordering::Equal
+    )]
+    #[case::positive_exponent(
+        Decimal::new(1, 2),
+        Decimal::new(100, 0),
+        true,
+        false,
+        Ordering::Equal
+    )]
+    #[case::trailing_zeros(Decimal::new(100, -2), Decimal::new(1000, -3), true, false, Ordering::Equal)]
+    #[case::different_values(Decimal::new(15, -1), Decimal::new(151, -2), false, false, Ordering::Less)]
+    fn test_decimal_equality_relations(
+        #[case] d1: Decimal,
+        #[case] d2: Decimal,
+        #[case] numerically_equal: bool,
+        #[case] ion_equivalent: bool,
+        #[case] ordering: Ordering,
+    ) {
+        // Numeric equality is symmetric
+        assert_eq!(d1 == d2, numerically_equal);
+        assert_eq!(d2 == d1, numerically_equal);
+        assert_eq!(d1.numerically_eq(&d2), numerically_equal);
+        assert_eq!(d2.numerically_eq(&d1), numerically_equal);
+        // The numeric ordering must be consistent with `==`
+        assert_eq!(d1.cmp(&d2), ordering);
+        assert_eq!(d1.partial_cmp(&d2), Some(ordering));
+        assert_eq!(d1.cmp(&d2) == Ordering::Equal, d1 == d2);
+        // Ion equivalence is symmetric
+        assert_eq!(d1.ion_eq(&d2), ion_equivalent);
+        assert_eq!(d2.ion_eq(&d1), ion_equivalent);
+        assert_eq!(IonData::from(d1) == IonData::from(d2), ion_equivalent);
+        // Element equality follows the Ion data model for decimals, both at the top level...
+        assert_eq!(Element::from(d1) == Element::from(d2), ion_equivalent);
+        // ...and when nested in containers.
+        assert_eq!(
+            ion_list![d1] == ion_list![d2],
+            ion_equivalent,
+            "list equality"
+        );
+        assert_eq!(
+            ion_struct! {"a": d1} == ion_struct! {"a": d2},
+            ion_equivalent,
+            "struct equality"
+        );
+    }
+
     #[rstest]
     // Each tuple is a coefficient/exponent pair that will be used to construct a Decimal
     // Positive numbers
@@ -697,12 +954,186 @@ mod decimal_tests {
     #[case(8675309u32, Decimal::new(8675309u32, 0))]
     // mixed coefficient representations
     #[case(8675309i64, Decimal::new(8675309u32, 0))]
-    #[case(Int::from(-8675309i64), Decimal::new(-8675309i64, 0))]
-    #[case(Int::from(-8675309i128), Decimal::new(-8675309i64, 0))]
+    #[case(Coefficient::try_from(Int::from(-8675309i64)).unwrap(), Decimal::new(-8675309i64, 0))]
+    #[case(Coefficient::try_from(Int::from(-8675309i128)).unwrap(), Decimal::new(-8675309i64, 0))]
     fn decimal_from_integers(
         #[case] coefficient: impl Into<Coefficient>,
         #[case] expected: Decimal,
     ) {
         assert_eq!(Decimal::new(coefficient, 0), expected);
     }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), Decimal::new(225, -2), Decimal::new(375, -2))]
+    #[case(Decimal::new(150, -2), Decimal::new(2, 0), Decimal::new(350, -2))]
+    #[case(Decimal::new(1, 3), Decimal::new(1, -3), Decimal::new(1000001, -3))]
+    #[case(Decimal::new(-15, -1), Decimal::new(5, -1), Decimal::new(-10, -1))]
+    #[case(Decimal::new(15, -1), Decimal::new(-15, -1), Decimal::new(0, -1))]
+    #[case(Decimal::new(-15, -1), Decimal::new(15, -1), Decimal::new(0, -1))]
+    #[case(Decimal::new(0, 100), Decimal::new(7, -100), Decimal::new(7, -100))]
+    #[case(
+        Decimal::new(i128::MAX, 0),
+        Decimal::new(0, 5),
+        Decimal::new(i128::MAX, 0)
+    )]
+    #[case(Decimal::ZERO, Decimal::ZERO, Decimal::ZERO)]
+    #[case(Decimal::NEGATIVE_ZERO, Decimal::ZERO, Decimal::ZERO)]
+    #[case(Decimal::ZERO, Decimal::NEGATIVE_ZERO, Decimal::ZERO)]
+    #[case(Decimal::negative_zero_with_exponent(-1), Decimal::NEGATIVE_ZERO, Decimal::negative_zero_with_exponent(-1))]
+    fn test_checked_add(#[case] d1: Decimal, #[case] d2: Decimal, #[case] expected: Decimal) {
+        let sum = d1.checked_add(&d2).unwrap();
+        assert_eq!(IonData::from(sum), IonData::from(expected));
+        // Addition is commutative
+        let sum = d2.checked_add(&d1).unwrap();
+        assert_eq!(IonData::from(sum), IonData::from(expected));
+    }
+
+    #[rstest]
+    #[case(Decimal::new(375, -2), Decimal::new(15, -1), Decimal::new(225, -2))]
+    #[case(Decimal::new(2, 0), Decimal::new(350, -2), Decimal::new(-150, -2))]
+    #[case(Decimal::new(15, -1), Decimal::new(15, -1), Decimal::new(0, -1))]
+    #[case(Decimal::ZERO, Decimal::ZERO, Decimal::ZERO)]
+    #[case(Decimal::ZERO, Decimal::NEGATIVE_ZERO, Decimal::ZERO)]
+    #[case(Decimal::NEGATIVE_ZERO, Decimal::NEGATIVE_ZERO, Decimal::ZERO)]
+    #[case(Decimal::NEGATIVE_ZERO, Decimal::ZERO, Decimal::NEGATIVE_ZERO)]
+    fn test_checked_sub(#[case] d1: Decimal, #[case] d2: Decimal, #[case] expected: Decimal) {
+        let difference = d1.checked_sub(&d2).unwrap();
+        assert_eq!(IonData::from(difference), IonData::from(expected));
+    }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), Decimal::new(225, -2), Decimal::new(3375, -3))]
+    #[case(Decimal::new(150, -2), Decimal::new(2, 0), Decimal::new(300, -2))]
+    #[case(Decimal::new(-4, 2), Decimal::new(25, -1), Decimal::new(-100, 1))]
+    #[case(Decimal::new(-4, 2), Decimal::new(-25, -1), Decimal::new(100, 1))]
+    #[case(Decimal::new(0, 2), Decimal::new(25, -1), Decimal::new(0, 1))]
+    #[case(Decimal::new(0, 2), Decimal::new(-25, -1), Decimal::negative_zero_with_exponent(1))]
+    #[case(Decimal::NEGATIVE_ZERO, Decimal::new(3, 0), Decimal::NEGATIVE_ZERO)]
+    #[case(Decimal::NEGATIVE_ZERO, Decimal::NEGATIVE_ZERO, Decimal::ZERO)]
+    fn test_checked_mul(#[case] d1: Decimal, #[case] d2: Decimal, #[case] expected: Decimal) {
+        let product = d1.checked_mul(&d2).unwrap();
+        assert_eq!(IonData::from(product), IonData::from(expected));
+        // Multiplication is commutative
+        let product = d2.checked_mul(&d1).unwrap();
+        assert_eq!(IonData::from(product), IonData::from(expected));
+    }
+
+    #[test]
+    fn test_checked_arithmetic_overflow() {
+        let max = Decimal::new(i128::MAX, 0);
+        let min = Decimal::new(i128::MIN, 0);
+        let one = Decimal::new(1, 0);
+        assert!(max.checked_add(&one).is_err());
+        assert!(min.checked_sub(&one).is_err());
+        assert!(max.checked_mul(&Decimal::new(2, 0)).is_err());
+        // Aligning the exponents requires a coefficient that is too large
+        assert!(one.checked_add(&Decimal::new(1, -40)).is_err());
+        assert!(one.checked_add(&Decimal::new(1, i64::MIN)).is_err());
+        assert!(Decimal::new(1, i64::MAX)
+            .checked_mul(&Decimal::new(1, 1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_checked_add_with_cancelling_operands() {
+        // Rewriting 2 * 10^38 with an exponent of 0 would overflow an i128, but the sum does not.
+        let large = Decimal::new(2 * 10i128.pow(37), 1);
+        let sum = large
+            .checked_add(&Decimal::new(-(10i128.pow(38)), 0))
+            .unwrap();
+        assert_eq!(
+            IonData::from(sum),
+            IonData::from(Decimal::new(10i128.pow(38), 0))
+        );
+        let difference = large
+            .checked_sub(&Decimal::new(10i128.pow(38), 0))
+            .unwrap();
+        assert_eq!(IonData::from(difference), IonData::from(sum));
+    }
+
+    /// Compares `checked_add`, `checked_sub`, and `checked_mul` against `BigDecimal` arithmetic
+    /// for random decimals. The coefficients range from a single digit to the full width of an
+    /// `i128`, so many of the exact results do not fit in a `Decimal`; those must be reported as
+    /// errors rather than wrapping or truncating.
+    #[test]
+    fn test_checked_arithmetic_random_inputs() {
+        use bigdecimal::BigDecimal;
+        use num_bigint::BigInt;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        fn random_decimal(rng: &mut StdRng) -> Decimal {
+            let digits = rng.gen_range(0..=38);
+            let magnitude = rng.gen_range(0..10i128.pow(digits));
+            let coefficient = if rng.gen_bool(0.5) {
+                -magnitude
+            } else {
+                magnitude
+            };
+            Decimal::new(coefficient, rng.gen_range(-45..=45))
+        }
+
+        fn to_big_decimal(decimal: &Decimal) -> BigDecimal {
+            BigDecimal::new(
+                BigInt::from(decimal.coefficient_value()),
+                -decimal.exponent(),
+            )
+        }
+
+        /// Checks `actual` against the exact result, which is rewritten with `exponent`.
+        fn check(
+            description: String,
+            actual: IonResult<Decimal>,
+            expected: BigDecimal,
+            exponent: i64,
+            outcomes: &mut [usize; 2],
+        ) {
+            let (coefficient, scale) = expected.with_scale(-exponent).into_bigint_and_exponent();
+            assert_eq!(scale, -exponent);
+            match i128::try_from(coefficient) {
+                Ok(coefficient) => {
+                    let actual = actual.unwrap_or_else(|e| panic!("{description}: {e}"));
+                    assert_eq!(actual.exponent(), exponent, "{description}");
+                    assert_eq!(actual.coefficient_value(), coefficient, "{description}");
+                    outcomes[0] += 1;
+                }
+                Err(_) => {
+                    assert!(actual.is_err(), "{description} should overflow");
+                    outcomes[1] += 1;
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(0x10_4E);
+        // The number of results that fit in a `Decimal` and the number that overflowed.
+        let mut outcomes = [0usize; 2];
+        for _ in 0..20_000 {
+            let (d1, d2) = (random_decimal(&mut rng), random_decimal(&mut rng));
+            let (b1, b2) = (to_big_decimal(&d1), to_big_decimal(&d2));
+            let exponent = d1.exponent().min(d2.exponent());
+            check(
+                format!("{d1} + {d2}"),
+                d1.checked_add(&d2),
+                &b1 + &b2,
+                exponent,
+                &mut outcomes,
+            );
+            check(
+                format!("{d1} - {d2}"),
+                d1.checked_sub(&d2),
+                &b1 - &b2,
+                exponent,
+                &mut outcomes,
+            );
+            check(
+                format!("{d1} * {d2}"),
+                d1.checked_mul(&d2),
+                &b1 * &b2,
+                d1.exponent() + d2.exponent(),
+                &mut outcomes,
+            );
+        }
+        // Both outcomes are well represented.
+        assert!(outcomes.iter().all(|&count| count > 5_000), "{outcomes:?}");
+    }
 }
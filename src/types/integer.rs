@@ -1,25 +1,42 @@
 use crate::ion_data::{IonEq, IonOrd};
-use crate::result::IonFailure;
+use crate::result::{DecodingErrorKind, IonFailure};
 use crate::types::CountDecimalDigits;
 use crate::{IonError, IonResult};
-use num_traits::Zero;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{ToPrimitive, Zero};
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::mem;
 use std::ops::{Add, Neg};
 
-/// Represents an unsigned integer of any size.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Represents an unsigned integer of arbitrary size.
+///
+/// Values that fit in a `u128` are stored inline; larger values are stored in a heap-allocated
+/// [`BigUint`]. Because it may own a heap allocation, `UInt` is `Clone` but not `Copy`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct UInt {
-    pub(crate) data: u128,
+    pub(crate) data: UIntData,
+}
+
+/// The storage for a [`UInt`]. The `BigUInt` variant is only used for values that do not fit in a
+/// `u128`, so each value has exactly one representation. This allows `Eq`, `Ord`, and `Hash` to be
+/// derived: every `BigUInt` value is greater than every `U128` value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum UIntData {
+    U128(u128),
+    BigUInt(BigUint),
 }
 
 impl UInt {
-    pub const ZERO: UInt = UInt { data: 0u128 };
+    pub const ZERO: UInt = UInt {
+        data: UIntData::U128(0),
+    };
 
     #[inline]
     pub(crate) fn new(data: impl Into<u128>) -> Self {
-        Self { data: data.into() }
+        Self {
+            data: UIntData::U128(data.into()),
+        }
     }
 
     /// Attempts to convert this `UInt` to a `usize`. If the value is too large to fit,
@@ -37,7 +54,10 @@ impl UInt {
     /// Attempts to convert this `UInt` to a `u128`. If the value is too large to fit,
     /// returns `None`.
     pub fn as_u128(&self) -> Option<u128> {
-        Some(self.data)
+        match self.data {
+            UIntData::U128(value) => Some(value),
+            UIntData::BigUInt(_) => None,
+        }
     }
 
     /// Attempts to convert this `UInt` to a `usize`. If the value is too large to fit,
@@ -63,7 +83,10 @@ impl UInt {
 
     /// Returns the number of digits in the base-10 representation of the UInteger.
     pub(crate) fn number_of_decimal_digits(&self) -> u32 {
-        self.data.count_decimal_digits()
+        match &self.data {
+            UIntData::U128(value) => value.count_decimal_digits(),
+            UIntData::BigUInt(value) => value.to_string().len() as u32,
+        }
     }
 }
 
@@ -94,6 +117,32 @@ impl From<usize> for UInt {
     }
 }
 
+impl From<BigUint> for UInt {
+    fn from(value: BigUint) -> Self {
+        match value.to_u128() {
+            Some(value) => UInt::new(value),
+            None => UInt {
+                data: UIntData::BigUInt(value),
+            },
+        }
+    }
+}
+
+impl From<UInt> for BigUint {
+    fn from(value: UInt) -> Self {
+        match value.data {
+            UIntData::U128(value) => BigUint::from(value),
+            UIntData::BigUInt(value) => value,
+        }
+    }
+}
+
+impl From<&UInt> for BigUint {
+    fn from(value: &UInt) -> Self {
+        value.clone().into()
+    }
+}
+
 macro_rules! impl_uint_try_from_signed_int_types {
     ($($t:ty),*) => ($(
         impl TryFrom<$t> for UInt {
@@ -116,7 +165,7 @@ macro_rules! impl_int_types_try_from_uint {
             type Error = IonError;
 
             fn try_from(value: &UInt) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                value.as_u128().and_then(|value| <$t>::try_from(value).ok()).ok_or_else(|| {
                     IonError::decoding_error(
                             concat!("UInt was too large to fit in a ", stringify!($t))
                         )
@@ -133,10 +182,10 @@ impl TryFrom<Int> for UInt {
     type Error = IonError;
 
     fn try_from(value: Int) -> Result<Self, Self::Error> {
-        if value.data.is_negative() {
+        if value.is_negative() {
             return IonResult::decoding_error("cannot convert negative Int to a UInt");
         }
-        Ok(value.data.unsigned_abs().into())
+        Ok(value.unsigned_abs())
     }
 }
 
@@ -144,19 +193,19 @@ impl TryFrom<&Int> for UInt {
     type Error = IonError;
 
     fn try_from(value: &Int) -> Result<Self, Self::Error> {
-        (*value).try_into()
+        value.clone().try_into()
     }
 }
 
 impl From<&UInt> for UInt {
     fn from(value: &UInt) -> Self {
-        *value
+        value.clone()
     }
 }
 
 impl From<&Int> for Int {
     fn from(value: &Int) -> Self {
-        *value
+        value.clone()
     }
 }
 
@@ -166,7 +215,7 @@ macro_rules! impl_small_int_try_from_int {
             type Error = IonError;
 
             fn try_from(value: Int) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                value.as_i128().and_then(|value| <$t>::try_from(value).ok()).ok_or_else(|| {
                     IonError::decoding_error(concat!("Int was outside the range of a(n) ", stringify!($t)))
                 })
             }
@@ -183,7 +232,7 @@ macro_rules! impl_small_unsigned_int_try_from_uint {
             type Error = IonError;
 
             fn try_from(value: UInt) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                <$t>::try_from(&value).map_err(|_| {
                     IonError::decoding_error(concat!("UInt was outside the range of a(n) ", stringify!($t)))
                 })
             }
@@ -193,8 +242,14 @@ macro_rules! impl_small_unsigned_int_try_from_uint {
 
 impl_small_unsigned_int_try_from_uint!(u8, u16, u32, u64, u128, usize);
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// A signed integer of arbitrary size.
+///
+/// Values that fit in an `i128` are stored inline; larger values are stored in a heap-allocated
+/// [`BigInt`]. Use [`as_i64`](Self::as_i64), [`as_i128`](Self::as_i128), or a `TryFrom`
+/// conversion to get a primitive integer, or convert the `Int` into a `BigInt` to access values
+/// of any size. Because it may own a heap allocation, `Int` is `Clone` but not `Copy`.
+///
 /// ```
 /// # use ion_rs::IonResult;
 /// # fn main() -> IonResult<()> {
@@ -215,25 +270,69 @@ impl_small_unsigned_int_try_from_uint!(u8, u16, u32, u64, u128, usize);
 /// # }
 /// ```
 pub struct Int {
-    pub(crate) data: i128,
+    pub(crate) data: IntData,
+}
+
+/// The storage for an [`Int`]. The `BigInt` variant is only used for values that do not fit in an
+/// `i128`, so each value has exactly one representation and `Eq` and `Hash` can be derived.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum IntData {
+    I128(i128),
+    BigInt(BigInt),
 }
 
 impl Int {
-    pub const ZERO: Int = Int { data: 0i128 };
+    pub const ZERO: Int = Int {
+        data: IntData::I128(0),
+    };
 
     pub(crate) fn new(data: impl Into<i128>) -> Self {
-        Self { data: data.into() }
+        Self {
+            data: IntData::I128(data.into()),
+        }
+    }
+
+    /// Constructs an `Int` from its sign and the big-endian bytes of its magnitude, as found in
+    /// Ion's binary encodings. Magnitudes that fit in a `u128` are read without allocating.
+    pub(crate) fn from_sign_and_magnitude_be_bytes(is_negative: bool, magnitude: &[u8]) -> Self {
+        // Leading zero bytes do not contribute to the magnitude.
+        let first_non_zero = magnitude
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(magnitude.len());
+        let magnitude = &magnitude[first_non_zero..];
+        if magnitude.len() <= mem::size_of::<u128>() {
+            let mut buffer = [0u8; mem::size_of::<u128>()];
+            buffer[mem::size_of::<u128>() - magnitude.len()..].copy_from_slice(magnitude);
+            let magnitude = u128::from_be_bytes(buffer);
+            let value = if is_negative {
+                0i128.checked_sub_unsigned(magnitude)
+            } else {
+                i128::try_from(magnitude).ok()
+            };
+            if let Some(value) = value {
+                return Int::new(value);
+            }
+        }
+        let sign = if is_negative { Sign::Minus } else { Sign::Plus };
+        BigInt::from_bytes_be(sign, magnitude).into()
     }
 
     /// Returns a [`UInt`] representing the unsigned magnitude of this `Int`.
     pub fn unsigned_abs(&self) -> UInt {
-        self.data.unsigned_abs().into()
+        match &self.data {
+            IntData::I128(value) => value.unsigned_abs().into(),
+            IntData::BigInt(value) => value.magnitude().clone().into(),
+        }
     }
 
     /// Returns `true` if this value is less than zero.
     /// If this value is greater than or equal to zero, returns `false`.
     pub fn is_negative(&self) -> bool {
-        self.data.is_negative()
+        match &self.data {
+            IntData::I128(value) => value.is_negative(),
+            IntData::BigInt(value) => value.sign() == Sign::Minus,
+        }
     }
 
     /// If this value is small enough to fit in an `i64`, returns `Ok(i64)`. Otherwise,
@@ -248,7 +347,7 @@ impl Int {
 
     #[inline(always)]
     pub fn as_u32(&self) -> Option<u32> {
-        u32::try_from(self.data).ok()
+        self.as_i128().and_then(|value| u32::try_from(value).ok())
     }
 
     #[inline]
@@ -261,37 +360,98 @@ impl Int {
 
     #[inline(always)]
     pub fn as_usize(&self) -> Option<usize> {
-        usize::try_from(self.data).ok()
+        self.as_i128().and_then(|value| usize::try_from(value).ok())
     }
 
     #[inline]
     pub fn expect_usize(&self) -> IonResult<usize> {
         self.as_usize().ok_or_else(
             #[inline(never)]
-            || IonError::decoding_error(format!("Int {self} was not in the range of a usize.")),
+            || {
+                IonError::decoding_error_of_kind(
+                    DecodingErrorKind::NumericOverflow,
+                    format!("Int {self} was not in the range of a usize."),
+                )
+            },
+        )
+    }
+
+    /// If this value is non-negative and small enough to fit in a `u64`, returns `Some(u64)`.
+    /// Otherwise, returns `None`.
+    #[inline(always)]
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_i128().and_then(|value| u64::try_from(value).ok())
+    }
+
+    /// If this value is non-negative and small enough to fit in a `u64`, returns `Ok(u64)`.
+    /// Otherwise, returns a [`DecodingError`](IonError::Decoding) of kind
+    /// [`NumericOverflow`](DecodingErrorKind::NumericOverflow).
+    #[inline]
+    pub fn expect_u64(&self) -> IonResult<u64> {
+        self.as_u64().ok_or_else(
+            #[inline(never)]
+            || {
+                IonError::decoding_error_of_kind(
+                    DecodingErrorKind::NumericOverflow,
+                    format!("Int {self} was not in the range of a u64."),
+                )
+            },
         )
     }
 
     /// If this value is small enough to fit in an `i128`, returns `Ok(i128)`. Otherwise,
-    /// returns a [`DecodingError`](IonError::Decoding).
+    /// returns a [`DecodingError`](IonError::Decoding) of kind
+    /// [`NumericOverflow`](DecodingErrorKind::NumericOverflow).
     pub fn expect_i128(&self) -> IonResult<i128> {
         self.as_i128().ok_or_else(|| {
-            IonError::decoding_error(format!("Int {self} was not in the range of an i128."))
+            IonError::decoding_error_of_kind(
+                DecodingErrorKind::NumericOverflow,
+                format!("Int {self} was not in the range of an i128."),
+            )
         })
     }
 
     /// If this value is small enough to fit in an `i64`, returns `Some(i64)`. Otherwise, returns
     /// `None`.
     pub fn as_i64(&self) -> Option<i64> {
-        i64::try_from(self.data).ok()
+        self.as_i128().and_then(|value| i64::try_from(value).ok())
     }
 
     /// If this value is small enough to fit in an `i128`, returns `Some(i128)`. Otherwise, returns
     /// `None`.
+    #[inline(always)]
     pub fn as_i128(&self) -> Option<i128> {
-        Some(self.data)
+        match self.data {
+            IntData::I128(value) => Some(value),
+            IntData::BigInt(_) => None,
+        }
+    }
+}
+
+impl PartialOrd for Int {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Int {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.data, &other.data) {
+            (IntData::I128(this), IntData::I128(other)) => this.cmp(other),
+            // A `BigInt` is always outside the range of an `i128`, so its sign decides the order.
+            (IntData::I128(_), IntData::BigInt(other)) => match other.sign() {
+                Sign::Minus => Ordering::Greater,
+                _ => Ordering::Less,
+            },
+            (IntData::BigInt(this), IntData::I128(_)) => match this.sign() {
+                Sign::Minus => Ordering::Less,
+                _ => Ordering::Greater,
+            },
+            (IntData::BigInt(this), IntData::BigInt(other)) => this.cmp(other),
+        }
     }
 }
+
 impl IonEq for Int {
     fn ion_eq(&self, other: &Self) -> bool {
         self == other
@@ -308,7 +468,13 @@ impl Neg for Int {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        self.data.neg().into()
+        match self.data {
+            IntData::I128(value) => match value.checked_neg() {
+                Some(negated) => negated.into(),
+                None => BigInt::from(value).neg().into(),
+            },
+            IntData::BigInt(value) => value.neg().into(),
+        }
     }
 }
 
@@ -316,17 +482,22 @@ impl Add<Self> for Int {
     type Output = Int;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.data.add(rhs.data).into()
+        if let (IntData::I128(this), IntData::I128(other)) = (&self.data, &rhs.data) {
+            if let Some(sum) = this.checked_add(*other) {
+                return sum.into();
+            }
+        }
+        (BigInt::from(self) + BigInt::from(rhs)).into()
     }
 }
 
 impl Zero for Int {
     fn zero() -> Self {
-        Int { data: 0i128 }
+        Int::ZERO
     }
 
     fn is_zero(&self) -> bool {
-        self.data == 0
+        self.data == IntData::I128(0)
     }
 }
 
@@ -334,35 +505,43 @@ impl Add<Self> for UInt {
     type Output = UInt;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.data.add(rhs.data).into()
+        if let (UIntData::U128(this), UIntData::U128(other)) = (&self.data, &rhs.data) {
+            if let Some(sum) = this.checked_add(*other) {
+                return sum.into();
+            }
+        }
+        (BigUint::from(self) + BigUint::from(rhs)).into()
     }
 }
 
 impl Zero for UInt {
     fn zero() -> Self {
-        UInt { data: 0u128 }
+        UInt::ZERO
     }
 
     fn is_zero(&self) -> bool {
-        self.data == 0
+        self.data == UIntData::U128(0)
     }
 }
 
-impl CountDecimalDigits for Int {
+impl CountDecimalDigits for &Int {
     fn count_decimal_digits(self) -> u32 {
-        self.data.count_decimal_digits()
+        self.unsigned_abs().number_of_decimal_digits()
     }
 }
 
-impl CountDecimalDigits for UInt {
+impl CountDecimalDigits for &UInt {
     fn count_decimal_digits(self) -> u32 {
-        self.data.count_decimal_digits()
+        self.number_of_decimal_digits()
     }
 }
 
 impl Display for UInt {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.data)
+        match &self.data {
+            UIntData::U128(value) => write!(f, "{value}"),
+            UIntData::BigUInt(value) => write!(f, "{value}"),
+        }
     }
 }
 
@@ -371,51 +550,69 @@ macro_rules! impl_int_i128_from {
     ($($t:ty),*) => ($(
         impl From<$t> for Int {
             fn from(value: $t) -> Int {
-                Int {data: value as i128 }
+                Int::new(value as i128)
             }
         }
     )*)
 }
 impl_int_i128_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
 
-// Conversion to Integer from integer types that may or may not fit in an i128
-macro_rules! impl_int_try_from {
-    ($($t:ty),*) => ($(
-        impl TryFrom<$t> for Int {
-            type Error = IonError;
-            fn try_from(value: $t) -> Result<Self, Self::Error> {
-                match i128::try_from(value) {
-                    Ok(i128_value) => Ok(i128_value.into()),
-                    Err(e) => IonResult::decoding_error(format!("could not safely convert value {} of type '{}' to Int: {e:?}", value, stringify!($t))),
-                }
-            }
-        }
-    )*)
+impl From<u128> for Int {
+    fn from(value: u128) -> Self {
+        UInt::from(value).into()
+    }
 }
 
-impl_int_try_from!(u128);
+impl From<BigInt> for Int {
+    fn from(value: BigInt) -> Self {
+        match value.to_i128() {
+            Some(value) => Int::new(value),
+            None => Int {
+                data: IntData::BigInt(value),
+            },
+        }
+    }
+}
 
-impl TryFrom<UInt> for Int {
-    type Error = IonError;
+impl From<Int> for BigInt {
+    fn from(value: Int) -> Self {
+        match value.data {
+            IntData::I128(value) => BigInt::from(value),
+            IntData::BigInt(value) => value,
+        }
+    }
+}
 
-    fn try_from(value: UInt) -> Result<Self, Self::Error> {
-        i128::try_from(value.data)
-            .map_err(|_| IonError::decoding_error("UInt was outside the supported Int range"))
-            .map(Int::new)
+impl From<&Int> for BigInt {
+    fn from(value: &Int) -> Self {
+        value.clone().into()
     }
 }
 
-impl TryFrom<&UInt> for Int {
-    type Error = IonError;
+impl From<UInt> for Int {
+    fn from(value: UInt) -> Self {
+        match value.data {
+            UIntData::U128(value) => match i128::try_from(value) {
+                Ok(value) => Int::new(value),
+                Err(_) => BigInt::from(value).into(),
+            },
+            UIntData::BigUInt(value) => BigInt::from(value).into(),
+        }
+    }
+}
 
-    fn try_from(value: &UInt) -> Result<Self, Self::Error> {
-        value.data.try_into()
+impl From<&UInt> for Int {
+    fn from(value: &UInt) -> Self {
+        value.clone().into()
     }
 }
 
 impl Display for Int {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.data)
+        match &self.data {
+            IntData::I128(value) => write!(f, "{value}"),
+            IntData::BigInt(value) => write!(f, "{value}"),
+        }
     }
 }
 
@@ -473,6 +670,9 @@ mod integer_tests {
     #[case::big_int_gt_i64(Int::from(9223372036854775809i128), Int::from(0), Ordering::Greater)]
     #[case::i64_gt_big_int_i128(Int::from(0), Int::from(9223372036854775809i128), Ordering::Less)]
     #[case::i64_lt_big_int_i128(Int::from(0), Int::from(-9223372036854775809i128),  Ordering::Greater)]
+    #[case::i128_lt_beyond_i128(Int::from(i128::MAX), Int::from(u128::MAX), Ordering::Less)]
+    #[case::i128_gt_beyond_i128(Int::from(i128::MIN), -Int::from(u128::MAX), Ordering::Greater)]
+    #[case::beyond_i128(-Int::from(u128::MAX), Int::from(u128::MAX), Ordering::Less)]
     fn integer_ordering_tests(#[case] this: Int, #[case] other: Int, #[case] expected: Ordering) {
         assert_eq!(this.cmp(&other), expected)
     }
@@ -600,4 +800,48 @@ mod integer_tests {
         assert_eq!(UInt::from(128_000u128).expect_u64(), Ok(128_000u64));
         assert!(UInt::from(u128::MAX).expect_u64().is_err())
     }
+
+    #[test]
+    fn int_as_unsigned() {
+        assert_eq!(Int::from(128_000).as_u64(), Some(128_000u64));
+        assert_eq!(Int::from(u64::MAX).expect_u64(), Ok(u64::MAX));
+        assert_eq!(
+            Int::from(u64::MAX).expect_usize().ok(),
+            usize::try_from(u64::MAX).ok()
+        );
+        for out_of_range in [Int::from(-1), Int::from(u64::MAX as i128 + 1)] {
+            assert!(out_of_range.as_u64().is_none());
+            assert_eq!(
+                out_of_range.expect_u64().unwrap_err().decoding_error_kind(),
+                Some(DecodingErrorKind::NumericOverflow)
+            );
+        }
+        assert_eq!(
+            Int::from(-1)
+                .expect_usize()
+                .unwrap_err()
+                .decoding_error_kind(),
+            Some(DecodingErrorKind::NumericOverflow)
+        );
+    }
+
+    #[rstest]
+    #[case::zero(false, &[], Int::ZERO)]
+    #[case::leading_zeros(true, &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5], Int::from(-5))]
+    #[case::i128_min(true, &[0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], Int::from(i128::MIN))]
+    #[case::i128_max_plus_one(false, &[0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], Int::from(i128::MAX) + Int::from(1))]
+    #[case::u128_max_plus_one(false, &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], Int::from(u128::MAX) + Int::from(1))]
+    fn int_from_sign_and_magnitude(
+        #[case] is_negative: bool,
+        #[case] magnitude: &[u8],
+        #[case] expected: Int,
+    ) {
+        let int = Int::from_sign_and_magnitude_be_bytes(is_negative, magnitude);
+        assert_eq!(int, expected);
+        // Values in the range of an i128 are always stored inline.
+        assert_eq!(
+            int.as_i128().is_some(),
+            i128::try_from(BigInt::from(&int)).is_ok()
+        );
+    }
 }
@@ -2,7 +2,7 @@ use crate::element::builders::SequenceBuilder;
 use crate::element::iterators::SequenceIterator;
 use crate::ion_data::IonEq;
 use crate::text::text_formatter::FmtValueFormatter;
-use crate::{Element, Sequence};
+use crate::{Element, IonError, IonResult, Sequence};
 use delegate::delegate;
 use std::fmt::{Display, Formatter};
 
@@ -35,6 +35,8 @@ impl List {
             pub fn get(&self, index: usize) -> Option<&Element>;
             pub fn len(&self) -> usize;
             pub fn is_empty(&self) -> bool;
+            pub fn try_into_vec<T>(&self) -> IonResult<Vec<T>>
+                where T: for<'a> TryFrom<&'a Element, Error = IonError>;
         }
     }
 }
@@ -23,7 +23,7 @@ pub use integer::{Int, UInt};
 pub use list::List;
 pub use lob::{Blob, Clob};
 pub use null::Null;
-pub use r#struct::Struct;
+pub use r#struct::{DuplicateFieldPolicy, FieldRemovalPolicy, Struct};
 pub use sexp::SExp;
 pub use string::Str;
 pub use symbol::Symbol;
@@ -36,7 +36,7 @@ use std::fmt;
 /// Represents the Ion data type of a given value. To learn more about each data type,
 /// read [the Ion Data Model](https://amazon-ion.github.io/ion-docs/docs/spec.html#the-ion-data-model)
 /// section of the spec.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub enum IonType {
     Null,
     Bool,
@@ -1,13 +1,18 @@
 use crate::element::builders::StructBuilder;
 use crate::element::Element;
 use crate::ion_data::{IonEq, IonOrd};
+use crate::result::IonFailure;
 use crate::symbol_ref::AsSymbolRef;
 use crate::text::text_formatter::FmtValueFormatter;
-use crate::Symbol;
+use crate::{IonError, IonResult, Symbol};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use smallvec::SmallVec;
 use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 // A convenient type alias for a vector capable of storing a single `usize` inline
 // without heap allocation. This type should not be used in public interfaces directly.
@@ -25,8 +30,8 @@ struct Fields {
 impl Fields {
     /// Gets all of the indexes that contain a value associated with the given field name.
     fn get_indexes<A: AsSymbolRef>(&self, field_name: A) -> Option<&IndexVec> {
+        let field_name = field_name.as_symbol_ref();
         field_name
-            .as_symbol_ref()
             .text()
             .map(|text| {
                 // If the symbol has defined text, look it up by &str
@@ -34,7 +39,7 @@ impl Fields {
             })
             .unwrap_or_else(|| {
                 // Otherwise, construct a (cheap, stack-allocated) Symbol with unknown text...
-                let symbol = Symbol::unknown_text();
+                let symbol = field_name.to_owned();
                 // ...and use the unknown text symbol to look up matching field values
                 self.by_name.get(&symbol)
             })
@@ -64,6 +69,12 @@ impl Fields {
             .map(|(_name, value)| value)
     }
 
+    /// Like [`get_last`](Self::get_last), but returns a mutable reference to the value.
+    fn get_last_mut<A: AsSymbolRef>(&mut self, field_name: A) -> Option<&mut Element> {
+        let index = *self.get_indexes(field_name)?.last()?;
+        self.by_index.get_mut(index).map(|(_name, value)| value)
+    }
+
     /// Iterates over all of the values associated with the given field name.
     fn get_all<A: AsSymbolRef>(&self, field_name: A) -> FieldValuesIterator {
         let indexes = self.get_indexes(field_name);
@@ -106,8 +117,15 @@ impl<'a> Iterator for FieldIterator<'a> {
             // Get the next &(name, value) and convert it to (&name, &value)
             .and_then(|iter| iter.next().map(|field| (&field.0, &field.1)))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.values.as_ref().map(|iter| iter.len()).unwrap_or(0);
+        (remaining, Some(remaining))
+    }
 }
 
+impl<'a> ExactSizeIterator for FieldIterator<'a> {}
+
 /// Iterates over the values associated with a given field name in a Struct.
 pub(crate) struct FieldValuesIterator<'a> {
     current: usize,
@@ -129,6 +147,27 @@ impl<'a> Iterator for FieldValuesIterator<'a> {
     }
 }
 
+/// Determines how [`Struct::to_map`] handles a field name that appears more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateFieldPolicy {
+    /// Return an error naming the repeated field.
+    Error,
+    /// Keep the value of the first field with the repeated name.
+    FirstWins,
+    /// Keep the value of the last field with the repeated name.
+    LastWins,
+}
+
+/// Determines which fields [`Struct::with_field_removed`] removes when more than one field has
+/// the requested name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldRemovalPolicy {
+    /// Remove only the first field with the requested name.
+    FirstOccurrence,
+    /// Remove every field with the requested name.
+    AllOccurrences,
+}
+
 /// An in-memory representation of an Ion Struct
 /// ```
 /// use ion_rs::{Element, ion_struct};
@@ -162,6 +201,11 @@ impl Struct {
         StructBuilder::new()
     }
 
+    /// Returns a [`StructBuilder`] with space preallocated for `capacity` fields.
+    pub fn builder_with_capacity(capacity: usize) -> StructBuilder {
+        StructBuilder::with_capacity(capacity)
+    }
+
     pub fn clone_builder(&self) -> StructBuilder {
         StructBuilder::with_initial_fields(&self.fields.by_index)
     }
@@ -223,6 +267,14 @@ impl Struct {
         FieldIterator::new(&self.fields.by_index)
     }
 
+    /// Returns this struct's fields sorted by name. Fields with the same name are ordered by
+    /// their values.
+    pub(crate) fn sorted_fields(&self) -> Vec<&(Symbol, Element)> {
+        let mut fields: Vec<_> = self.fields.by_index.iter().collect();
+        fields.sort_by(ion_cmp_field);
+        fields
+    }
+
     /// Returns the value associated with the specified field name.
     ///
     /// If more than one field in this struct has that name, this method will return the value of
@@ -232,11 +284,187 @@ impl Struct {
         self.fields.get_last(field_name)
     }
 
+    /// Returns a mutable reference to the value associated with the specified field name. As with
+    /// [`get`](Self::get), if more than one field has that name, the _last_ one is returned.
+    pub fn get_mut<A: AsSymbolRef>(&mut self, field_name: A) -> Option<&mut Element> {
+        self.fields.get_last_mut(field_name)
+    }
+
     /// Returns an iterator over all of the values associated with the specified field name.
     pub fn get_all<A: AsSymbolRef>(&self, field_name: A) -> impl Iterator<Item = &Element> {
         self.fields.get_all(field_name)
     }
 
+    /// Returns a copy of this struct without the field(s) with the specified name. Whether only
+    /// the first such field or all of them are removed is determined by `removal_policy`. The
+    /// remaining fields keep their order and annotations. If no field has the specified name,
+    /// the copy is identical to this struct.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, FieldRemovalPolicy};
+    /// let struct_ = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+    /// assert_eq!(
+    ///     struct_.with_field_removed("foo", FieldRemovalPolicy::FirstOccurrence),
+    ///     ion_struct! { "bar": 2, "foo": 3 }
+    /// );
+    /// assert_eq!(
+    ///     struct_.with_field_removed("foo", FieldRemovalPolicy::AllOccurrences),
+    ///     ion_struct! { "bar": 2 }
+    /// );
+    /// ```
+    pub fn with_field_removed<A: AsSymbolRef>(
+        &self,
+        field_name: A,
+        removal_policy: FieldRemovalPolicy,
+    ) -> Struct {
+        let indexes = self.fields.get_indexes(field_name);
+        let indexes_to_remove = match (indexes, removal_policy) {
+            (None, _) => return self.clone(),
+            (Some(indexes), FieldRemovalPolicy::FirstOccurrence) => &indexes[..1],
+            (Some(indexes), FieldRemovalPolicy::AllOccurrences) => &indexes[..],
+        };
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !indexes_to_remove.contains(index))
+            .map(|(_, field)| field.clone())
+            .collect()
+    }
+
+    /// Returns a copy of this struct in which the value of the first field with the specified name
+    /// has been replaced by `value`. Other fields with the same name are unaffected. If no field
+    /// has the specified name, the copy is identical to this struct.
+    ///
+    /// ```
+    /// use ion_rs::ion_struct;
+    /// let struct_ = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+    /// assert_eq!(
+    ///     struct_.with_field_replaced("foo", "hello"),
+    ///     ion_struct! { "foo": "hello", "bar": 2, "foo": 3 }
+    /// );
+    /// ```
+    pub fn with_field_replaced<A: AsSymbolRef, E: Into<Element>>(
+        &self,
+        field_name: A,
+        value: E,
+    ) -> Struct {
+        let mut fields = self.fields.clone();
+        if let Some(&index) = self.fields.get_indexes(field_name).and_then(|i| i.first()) {
+            fields.by_index[index].1 = value.into();
+        }
+        Struct { fields }
+    }
+
+    /// Returns a copy of this struct in which every field named `old_name` has been renamed to
+    /// `new_name`. The renamed fields keep their values, annotations, and positions.
+    ///
+    /// ```
+    /// use ion_rs::ion_struct;
+    /// let struct_ = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+    /// assert_eq!(
+    ///     struct_.with_field_renamed("foo", "baz"),
+    ///     ion_struct! { "baz": 1, "bar": 2, "baz": 3 }
+    /// );
+    /// ```
+    pub fn with_field_renamed<A: AsSymbolRef, S: Into<Symbol>>(
+        &self,
+        old_name: A,
+        new_name: S,
+    ) -> Struct {
+        let Some(indexes) = self.fields.get_indexes(old_name) else {
+            return self.clone();
+        };
+        let new_name = new_name.into();
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(index, (name, value))| {
+                let name = if indexes.contains(&index) {
+                    new_name.clone()
+                } else {
+                    name.clone()
+                };
+                (name, value.clone())
+            })
+            .collect()
+    }
+
+    /// Converts this struct into a `HashMap` keyed by field name. Field names that appear more
+    /// than once are handled according to `duplicate_policy`.
+    ///
+    /// Returns an error if any field name has unknown text or, under
+    /// [`DuplicateFieldPolicy::Error`], if a field name is repeated.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, DuplicateFieldPolicy, Element};
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// let struct_ = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+    /// let map = struct_.to_map(DuplicateFieldPolicy::LastWins)?;
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map["foo"], Element::int(3));
+    /// assert!(struct_.to_map(DuplicateFieldPolicy::Error).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_map(
+        &self,
+        duplicate_policy: DuplicateFieldPolicy,
+    ) -> IonResult<HashMap<String, Element>> {
+        let mut map = HashMap::with_capacity(self.len());
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            let text = Self::field_name_text(index, name)?;
+            if !map.contains_key(text) {
+                map.insert(text.to_owned(), value.clone());
+                continue;
+            }
+            match duplicate_policy {
+                DuplicateFieldPolicy::Error => {
+                    return IonResult::decoding_error(format!(
+                        "struct contains more than one field named '{text}' (repeated at field index {index})"
+                    ))
+                }
+                DuplicateFieldPolicy::FirstWins => {}
+                DuplicateFieldPolicy::LastWins => {
+                    map.insert(text.to_owned(), value.clone());
+                }
+            }
+        }
+        Ok(map)
+    }
+
+    /// Converts this struct into a `HashMap` that maps each field name to all of the values
+    /// associated with it, in the order they appear in the struct.
+    ///
+    /// The map is keyed by [`Symbol`], so field names with unknown text are kept apart from
+    /// field names with known text. A field read from an unknown symbol ID such as `$10` does
+    /// not share an entry with a field whose name is the text `"$10"`. Entries for names with
+    /// known text can be looked up with a `&str`.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, Element};
+    /// let struct_ = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+    /// let map = struct_.to_multimap();
+    /// assert_eq!(map["foo"], vec![Element::int(1), Element::int(3)]);
+    /// assert_eq!(map["bar"], vec![Element::int(2)]);
+    /// ```
+    pub fn to_multimap(&self) -> HashMap<Symbol, Vec<Element>> {
+        let mut map: HashMap<Symbol, Vec<Element>> = HashMap::with_capacity(self.len());
+        for (name, value) in self.fields.iter() {
+            map.entry(name.clone()).or_default().push(value.clone());
+        }
+        map
+    }
+
+    fn field_name_text(index: usize, name: &Symbol) -> IonResult<&str> {
+        name.text().ok_or_else(|| {
+            IonError::decoding_error(format!(
+                "field name at field index {index} has unknown text (${})",
+                name.unknown_text_sid().unwrap_or_default()
+            ))
+        })
+    }
+
     #[allow(clippy::map_identity)]
     // ^-- This is a temporary workaround for a bug in Clippy that should be fixed in the next release.
     // See: https://github.com/rust-lang/rust-clippy/issues/9280
@@ -258,6 +486,24 @@ impl<'a> IntoIterator for &'a Struct {
     }
 }
 
+/// Allows `struct_.par_iter()` to visit the struct's (field name, field value) pairs in parallel.
+#[cfg(feature = "rayon")]
+impl<'a> IntoParallelIterator for &'a Struct {
+    type Iter = rayon::iter::Map<
+        rayon::slice::Iter<'a, (Symbol, Element)>,
+        fn(&'a (Symbol, Element)) -> (&'a Symbol, &'a Element),
+    >;
+    type Item = (&'a Symbol, &'a Element);
+
+    fn into_par_iter(self) -> Self::Iter {
+        // Like `FieldIterator`, this converts each &(name, value) into (&name, &value).
+        self.fields
+            .by_index
+            .par_iter()
+            .map(|field| (&field.0, &field.1))
+    }
+}
+
 impl<K, V> FromIterator<(K, V)> for Struct
 where
     K: Into<Symbol>,
@@ -297,6 +543,25 @@ impl PartialEq for Struct {
 
 impl Eq for Struct {}
 
+impl Hash for Struct {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Struct equality does not depend on the order of the fields, so neither can the hash.
+        // Each field is hashed on its own and the results are combined with a commutative sum.
+        let fields_hash = self
+            .fields
+            .by_index
+            .iter()
+            .map(|field| {
+                let mut field_hasher = DefaultHasher::new();
+                field.hash(&mut field_hasher);
+                field_hasher.finish()
+            })
+            .fold(0u64, u64::wrapping_add);
+        self.len().hash(state);
+        fields_hash.hash(state);
+    }
+}
+
 impl IonEq for Struct {
     fn ion_eq(&self, other: &Self) -> bool {
         self == other
@@ -338,8 +603,11 @@ fn ion_cmp_field(this: &&(Symbol, Element), that: &&(Symbol, Element)) -> Orderi
 
 #[cfg(test)]
 mod tests {
+    use super::{DuplicateFieldPolicy, FieldRemovalPolicy};
     use crate::element::Element;
-    use crate::ion_struct;
+    use crate::ion_data::IonEq;
+    use crate::{ion_struct, Decimal, IonResult, Struct, Symbol};
+    use rstest::rstest;
 
     #[test]
     fn for_field_in_struct() {
@@ -353,4 +621,234 @@ mod tests {
         }
         assert_eq!(baz_value, Some(&Element::int(3)));
     }
+
+    #[test]
+    fn field_iterator_len() {
+        let s = ion_struct! { "foo": 1, "bar": 2, "baz": 3};
+        let mut fields = (&s).into_iter();
+        assert_eq!(fields.len(), 3);
+        fields.next();
+        assert_eq!(fields.len(), 2);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_matches_sequential_iteration() {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+        let s: Struct = (0..10_000i64)
+            .map(|i| (format!("field{}", i % 7), Element::int(i)))
+            .collect();
+        let sum = |(name, value): (&Symbol, &Element)| {
+            name.text().unwrap().len() as i64 + value.expect_i64().unwrap()
+        };
+        let sequential: i64 = s.iter().map(sum).sum();
+        let parallel: i64 = s.par_iter().map(sum).sum();
+        assert_eq!(parallel, sequential);
+    }
+
+    #[rstest]
+    #[case::first_wins(DuplicateFieldPolicy::FirstWins, 1)]
+    #[case::last_wins(DuplicateFieldPolicy::LastWins, 3)]
+    fn to_map_with_duplicate_fields(
+        #[case] policy: DuplicateFieldPolicy,
+        #[case] expected_foo: i64,
+    ) -> IonResult<()> {
+        let s = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+        let map = s.to_map(policy)?;
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["foo"], Element::int(expected_foo));
+        assert_eq!(map["bar"], Element::int(2));
+        Ok(())
+    }
+
+    #[test]
+    fn to_map_rejects_duplicate_fields() {
+        let s = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+        let error = s.to_map(DuplicateFieldPolicy::Error).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("'foo'"), "{message}");
+        assert!(message.contains("field index 2"), "{message}");
+    }
+
+    #[test]
+    fn to_map_rejects_unknown_field_names() {
+        let s: Struct = [
+            (Symbol::from("foo"), Element::int(1)),
+            (Symbol::unknown_text(), Element::int(2)),
+        ]
+        .into_iter()
+        .collect();
+        let error = s.to_map(DuplicateFieldPolicy::LastWins).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("$0"), "{message}");
+        assert!(message.contains("field index 1"), "{message}");
+    }
+
+    #[test]
+    fn to_multimap_groups_values() {
+        let s: Struct = [
+            (Symbol::from("foo"), Element::int(1)),
+            (Symbol::from("bar"), Element::int(2)),
+            (Symbol::from("foo"), Element::int(3)),
+            (Symbol::unknown_text(), Element::int(4)),
+            (Symbol::unknown_text_with_sid(10), Element::int(5)),
+            (Symbol::unknown_text(), Element::int(6)),
+        ]
+        .into_iter()
+        .collect();
+        let map = s.to_multimap();
+        assert_eq!(map.len(), 4);
+        assert_eq!(map["foo"], vec![Element::int(1), Element::int(3)]);
+        assert_eq!(map["bar"], vec![Element::int(2)]);
+        // Unknown-text field names keep their symbol IDs rather than all collapsing into `$0`.
+        assert_eq!(
+            map[&Symbol::unknown_text()],
+            vec![Element::int(4), Element::int(6)]
+        );
+        assert_eq!(
+            map[&Symbol::unknown_text_with_sid(10)],
+            vec![Element::int(5)]
+        );
+    }
+
+    #[test]
+    fn to_multimap_keeps_unknown_text_apart_from_sid_like_text() {
+        let s: Struct = [
+            (Symbol::unknown_text(), Element::int(1)),
+            (Symbol::from("$0"), Element::int(2)),
+            (Symbol::unknown_text_with_sid(10), Element::int(3)),
+            (Symbol::from("$10"), Element::int(4)),
+        ]
+        .into_iter()
+        .collect();
+        let map = s.to_multimap();
+        assert_eq!(map.len(), 4);
+        assert_eq!(map[&Symbol::unknown_text()], vec![Element::int(1)]);
+        assert_eq!(map["$0"], vec![Element::int(2)]);
+        assert_eq!(
+            map[&Symbol::unknown_text_with_sid(10)],
+            vec![Element::int(3)]
+        );
+        assert_eq!(map["$10"], vec![Element::int(4)]);
+    }
+
+    #[rstest]
+    #[case::first(FieldRemovalPolicy::FirstOccurrence, "{bar: 2, foo: b::3, baz: 4}")]
+    #[case::all(FieldRemovalPolicy::AllOccurrences, "{bar: 2, baz: 4}")]
+    fn with_field_removed(
+        #[case] policy: FieldRemovalPolicy,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let s = Element::read_one("{foo: a::1, bar: 2, foo: b::3, baz: 4}")?;
+        let s = s.as_struct().unwrap();
+        let expected = Element::read_one(expected)?;
+        let actual = s.with_field_removed("foo", policy);
+        assert_eq!(&actual, expected.as_struct().unwrap());
+        // Unaffected fields keep their order.
+        let names: Vec<_> = actual
+            .fields()
+            .map(|(name, _)| name.text().unwrap())
+            .collect();
+        let expected_names: Vec<_> = expected
+            .as_struct()
+            .unwrap()
+            .fields()
+            .map(|(name, _)| name.text().unwrap())
+            .collect();
+        assert_eq!(names, expected_names);
+        // Removing a field that doesn't exist leaves the struct unchanged.
+        assert_eq!(&s.with_field_removed("quux", policy), s);
+        Ok(())
+    }
+
+    #[test]
+    fn with_field_replaced() -> IonResult<()> {
+        let s = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+        let replaced = s.with_field_replaced("foo", Element::read_one("a::4")?);
+        assert_eq!(
+            replaced,
+            Element::read_one("{foo: a::4, bar: 2, foo: 3}")?
+                .expect_struct()?
+                .clone()
+        );
+        assert_eq!(replaced.get_all("foo").count(), 2);
+        // The original is unchanged.
+        assert_eq!(s, ion_struct! { "foo": 1, "bar": 2, "foo": 3 });
+        // Replacing a field that doesn't exist leaves the struct unchanged.
+        assert_eq!(s.with_field_replaced("quux", 5), s);
+        Ok(())
+    }
+
+    #[test]
+    fn with_field_renamed() -> IonResult<()> {
+        let s = Element::read_one("{foo: a::1, bar: 2, foo: 3}")?;
+        let renamed = s.expect_struct()?.with_field_renamed("foo", "baz");
+        assert_eq!(
+            &renamed,
+            Element::read_one("{baz: a::1, bar: 2, baz: 3}")?.expect_struct()?
+        );
+        assert_eq!(renamed.get("foo"), None);
+        let names: Vec<_> = renamed
+            .fields()
+            .map(|(name, _)| name.text().unwrap())
+            .collect();
+        assert_eq!(names, ["baz", "bar", "baz"]);
+        // Renaming a field that doesn't exist leaves the struct unchanged.
+        assert_eq!(
+            &s.expect_struct()?.with_field_renamed("quux", "x"),
+            s.expect_struct()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn modifying_fields_with_unknown_text() {
+        let s: Struct = [
+            (Symbol::from("foo"), Element::int(1)),
+            (Symbol::unknown_text(), Element::int(2)),
+            (Symbol::unknown_text(), Element::int(3)),
+        ]
+        .into_iter()
+        .collect();
+        let unknown = Symbol::unknown_text();
+
+        let removed = s.with_field_removed(&unknown, FieldRemovalPolicy::FirstOccurrence);
+        assert_eq!(removed.len(), 2);
+        assert_eq!(
+            removed.get_all(&unknown).collect::<Vec<_>>(),
+            [&Element::int(3)]
+        );
+
+        let replaced = s.with_field_replaced(&unknown, 4);
+        assert_eq!(
+            replaced.get_all(&unknown).collect::<Vec<_>>(),
+            [&Element::int(4), &Element::int(3)]
+        );
+
+        let renamed = s.with_field_renamed(&unknown, "bar");
+        assert_eq!(renamed, ion_struct! { "foo": 1, "bar": 2, "bar": 3 });
+        assert_eq!(renamed.get(&unknown), None);
+    }
+
+    #[test]
+    fn modified_structs_are_compared_as_ion_data() {
+        let s = ion_struct! { "foo": 1, "bar": 2.5f64, "foo": 3 };
+        // Struct equivalence ignores field order.
+        let removed = s.with_field_removed("foo", FieldRemovalPolicy::FirstOccurrence);
+        assert!(removed.ion_eq(&ion_struct! { "foo": 3, "bar": 2.5f64 }));
+        assert!(!removed.ion_eq(&s));
+        // Replacing the first `foo` with the value of the second one leaves a duplicate field,
+        // which is not equivalent to a single field with that value.
+        let replaced = s.with_field_replaced("foo", 3);
+        assert!(replaced.ion_eq(&ion_struct! { "bar": 2.5f64, "foo": 3, "foo": 3 }));
+        assert!(!replaced.ion_eq(&removed));
+        // Ion equivalence distinguishes values that are numerically equal but have different types.
+        assert!(!s
+            .with_field_replaced("bar", Decimal::new(25, -1))
+            .ion_eq(&s));
+        assert!(s.with_field_replaced("bar", 2.5f64).ion_eq(&s));
+        // Renaming a field to its own name has no effect.
+        assert!(s.with_field_renamed("foo", "foo").ion_eq(&s));
+    }
 }
@@ -1,6 +1,6 @@
 use crate::ion_data::{IonEq, IonOrd};
 use crate::result::IonFailure;
-use crate::{IonResult, SymbolRef};
+use crate::{IonResult, SymbolId, SymbolRef};
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
@@ -17,8 +17,10 @@ pub(crate) enum SymbolText {
     //       Symbols are read-only, so there's no chance we'll add data to the `String`. Using
     //       a `Box<str>` shrinks this value from 24 bytes to 8 bytes.
     Owned(String),
-    // This Symbol is equivalent to SID zero (`$0`)
-    Unknown,
+    // This Symbol has unknown text. The symbol ID is `0` for `$0` and for symbol IDs that a
+    // symbol table defines without text. Otherwise, it is the symbol ID that the reader found
+    // beyond the end of its symbol table.
+    Unknown(SymbolId),
 }
 
 impl SymbolText {
@@ -26,7 +28,7 @@ impl SymbolText {
         let text = match self {
             SymbolText::Shared(s) => s.as_ref(),
             SymbolText::Owned(s) => s.as_str(),
-            SymbolText::Unknown => return None,
+            SymbolText::Unknown(_) => return None,
         };
         Some(text)
     }
@@ -37,7 +39,7 @@ impl Hash for SymbolText {
         match self {
             SymbolText::Shared(text) => text.hash(state),
             SymbolText::Owned(text) => text.hash(state),
-            SymbolText::Unknown => "".hash(state),
+            SymbolText::Unknown(_) => "".hash(state),
         }
     }
 }
@@ -47,7 +49,7 @@ impl Clone for SymbolText {
         match self {
             SymbolText::Owned(text) => SymbolText::Owned(text.to_owned()),
             SymbolText::Shared(text) => SymbolText::Shared(Arc::clone(text)),
-            SymbolText::Unknown => SymbolText::Unknown,
+            SymbolText::Unknown(sid) => SymbolText::Unknown(*sid),
         }
     }
 }
@@ -66,13 +68,14 @@ impl PartialOrd<Self> for SymbolText {
 
 impl Ord for SymbolText {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (self.text(), other.text()) {
+        match (self, other) {
+            // If both Symbols have unknown text, compare their symbol IDs.
+            (SymbolText::Unknown(sid1), SymbolText::Unknown(sid2)) => sid1.cmp(sid2),
+            // Unknown text is treated as 'less than' known text
+            (SymbolText::Unknown(_), _) => Ordering::Less,
+            (_, SymbolText::Unknown(_)) => Ordering::Greater,
             // If both Symbols have known text, delegate the comparison to their text.
-            (Some(s1), Some(s2)) => s1.cmp(s2),
-            // Otherwise, $0 (unknown text) is treated as 'less than' known text
-            (Some(_), None) => Ordering::Greater,
-            (None, Some(_)) => Ordering::Less,
-            (None, None) => Ordering::Equal,
+            _ => self.text().cmp(&other.text()),
         }
     }
 }
@@ -80,6 +83,17 @@ impl Ord for SymbolText {
 /// The text of a fully resolved field name, annotation, or symbol value. If the symbol has known
 /// text (that is: the symbol is not `$0`), it will be stored as either a `String` or a shared
 /// reference to text in a symbol table.
+///
+/// A symbol with unknown text retains the symbol ID it was read from when that ID was beyond the
+/// end of the reader's symbol table (see
+/// [`UnresolvedSymbolIdPolicy::UnknownText`](crate::UnresolvedSymbolIdPolicy::UnknownText)).
+/// `$0` and symbol IDs that the symbol table defines without text are all equivalent to
+/// symbol ID `0`.
+///
+/// Symbols with known text are equal if their text is equal. Symbols with unknown text are equal
+/// if their symbol IDs are equal, and are never equal to a symbol with known text. Comparing
+/// symbols does not raise an error, but note that the symbol IDs of unknown-text symbols are only
+/// meaningful relative to the symbol table of the stream they were read from.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Symbol {
     pub(crate) text: SymbolText,
@@ -100,7 +114,15 @@ impl Symbol {
 
     pub fn unknown_text() -> Symbol {
         Symbol {
-            text: SymbolText::Unknown,
+            text: SymbolText::Unknown(0),
+        }
+    }
+
+    /// Constructs a symbol with unknown text that was read from symbol ID `sid`.
+    /// `Symbol::unknown_text_with_sid(0)` is equivalent to [`Symbol::unknown_text()`].
+    pub fn unknown_text_with_sid(sid: SymbolId) -> Symbol {
+        Symbol {
+            text: SymbolText::Unknown(sid),
         }
     }
 
@@ -109,7 +131,7 @@ impl Symbol {
         match self.text {
             SymbolText::Shared(text) => Symbol::shared(text),
             SymbolText::Owned(text) => Symbol::shared(text.into()),
-            SymbolText::Unknown => Symbol::unknown_text(),
+            SymbolText::Unknown(sid) => Symbol::unknown_text_with_sid(sid),
         }
     }
 
@@ -117,6 +139,15 @@ impl Symbol {
         self.text.text()
     }
 
+    /// If this symbol has unknown text, returns `Some(sid)` where `sid` is the symbol ID it was
+    /// read from (`0` for `$0`). If the symbol has known text, returns `None`.
+    pub fn unknown_text_sid(&self) -> Option<SymbolId> {
+        match self.text {
+            SymbolText::Unknown(sid) => Some(sid),
+            _ => None,
+        }
+    }
+
     pub fn expect_text(&self) -> IonResult<&str> {
         match self.text() {
             Some(text) => Ok(text),
@@ -174,7 +205,7 @@ impl<'a> From<SymbolRef<'a>> for Symbol {
 impl Display for Symbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self.text() {
-            None => write!(f, "$0"),
+            None => write!(f, "${}", self.unknown_text_sid().unwrap_or_default()),
             Some(text) => write!(f, "'{text}'"),
         }
     }
@@ -250,4 +281,37 @@ mod symbol_tests {
         let expected = vec!["bar", "baz", "foo", "quux"];
         assert_eq!(symbols, expected)
     }
+
+    #[test]
+    fn unknown_text_ordering_and_eq() {
+        let mut symbols = vec![
+            Symbol::owned("foo"),
+            Symbol::unknown_text_with_sid(10),
+            Symbol::unknown_text(),
+            Symbol::unknown_text_with_sid(2),
+        ];
+        symbols.sort();
+        // Symbols with unknown text sort before those with known text, ordered by symbol ID.
+        let expected = vec![
+            Symbol::unknown_text(),
+            Symbol::unknown_text_with_sid(2),
+            Symbol::unknown_text_with_sid(10),
+            Symbol::owned("foo"),
+        ];
+        assert_eq!(symbols, expected);
+
+        assert_eq!(Symbol::unknown_text_with_sid(0), Symbol::unknown_text());
+        assert_eq!(
+            Symbol::unknown_text_with_sid(10),
+            Symbol::unknown_text_with_sid(10).into_shared()
+        );
+        assert_ne!(Symbol::unknown_text_with_sid(10), Symbol::unknown_text());
+        assert_ne!(Symbol::unknown_text_with_sid(10), Symbol::owned("$10"));
+        assert_eq!(
+            Symbol::unknown_text_with_sid(10).unknown_text_sid(),
+            Some(10)
+        );
+        assert_eq!(Symbol::owned("foo").unknown_text_sid(), None);
+        assert_eq!(Symbol::unknown_text_with_sid(10).to_string(), "$10");
+    }
 }
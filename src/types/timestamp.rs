@@ -10,10 +10,10 @@ use std::cmp::Ordering;
 use std::convert::TryInto;
 use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
-use std::ops::Div;
+use std::ops::RangeInclusive;
 
 /// Indicates the most precise time unit that has been specified in the accompanying [Timestamp].
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
 pub enum TimestampPrecision {
     /// Year-level precision (e.g. `2020T`)
     #[default]
@@ -131,6 +131,34 @@ fn first_n_digits_of(num_digits: u32, value: u32) -> u32 {
     value / 10u32.pow(total_digits - num_digits)
 }
 
+/// The largest offset from UTC, in minutes, that a [Timestamp] may have: `+/-23:59`.
+pub(crate) const MAX_OFFSET_MINUTES: i32 = 23 * 60 + 59;
+
+/// Returns `Err` if the specified offset is outside of the range `-23:59` to `+23:59`.
+fn validate_offset_minutes(offset_minutes: i32) -> IonResult<()> {
+    validate_component(
+        "offset",
+        offset_minutes,
+        -MAX_OFFSET_MINUTES..=MAX_OFFSET_MINUTES,
+    )
+}
+
+/// Returns an `Err` naming the timestamp `component` and its allowed `range` if `value` falls
+/// outside of that range.
+fn validate_component<V>(component: &str, value: V, range: RangeInclusive<V>) -> IonResult<()>
+where
+    V: PartialOrd + Display,
+{
+    if !range.contains(&value) {
+        return IonResult::illegal_operation(format!(
+            "timestamp {component} ({value}) is outside of the range {}..={}",
+            range.start(),
+            range.end()
+        ));
+    }
+    Ok(())
+}
+
 /// Constructs a [FixedOffset] at the specified offset seconds from UTC. If the specified offset
 /// is out of bounds, this method will panic.
 fn offset_east(seconds_east: i32) -> FixedOffset {
@@ -240,21 +268,23 @@ impl Timestamp {
             // representing the number of nanoseconds.
             Some(Arbitrary(decimal)) => {
                 const NANOSECONDS_EXPONENT: i64 = -9;
-                const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+                let magnitude = decimal.coefficient.unsigned_magnitude();
                 let exponent_delta = decimal.exponent - NANOSECONDS_EXPONENT;
-                let magnitude = match &decimal.coefficient.magnitude().data {
-                    m if *m >= NANOSECONDS_PER_SECOND => {
-                        // The coefficient is more precise than nanoseconds. We need to truncate a
-                        // copy of it.
-                        m.div(10f64.powi(exponent_delta.abs() as i32) as u128)
-                            .to_u32()
-                            .expect("failed to convert coefficient magnitude to u32 nanos")
-                    }
-                    m => *m as u32,
+                // The scaling factor may not fit in a u128 if the decimal has a very large
+                // (negative) exponent. Integer math is used throughout so that unusual values
+                // produce a result instead of a panic.
+                let scale = u32::try_from(exponent_delta.unsigned_abs())
+                    .ok()
+                    .and_then(|power| 10u128.checked_pow(power));
+                let nanoseconds = if exponent_delta < 0 {
+                    // The coefficient is more precise than nanoseconds. Truncate the extra digits.
+                    scale.map_or(0, |scale| magnitude / scale)
+                } else {
+                    scale
+                        .and_then(|scale| magnitude.checked_mul(scale))
+                        .unwrap_or(0)
                 };
-                // Adjust for the exponent
-                let nanoseconds = (magnitude as f64 * 10f64.powi(exponent_delta as i32)) as u32;
-                Some(nanoseconds)
+                Some(nanoseconds.to_u32().unwrap_or(0))
             }
             // This Timestamp's precision is too low to have a fractional seconds field.
             None => None,
@@ -505,6 +535,51 @@ impl Timestamp {
         self.offset.map(|offset| offset.local_minus_utc() / 60)
     }
 
+    /// Returns the offset from UTC in minutes, or `None` if the offset is unknown.
+    /// This is equivalent to [`offset`](Self::offset), but names its unit explicitly.
+    pub fn offset_minutes(&self) -> Option<i32> {
+        self.offset()
+    }
+
+    /// Returns a new [Timestamp] representing the same instant as this one at the specified offset
+    /// from UTC. The date and time fields are recomputed as needed; the precision and fractional
+    /// seconds are preserved exactly.
+    ///
+    /// Returns `Err` if this timestamp has no time component (for example: `2024-03-01T`), if its
+    /// offset is unknown (`-00:00`), or if `offset_minutes` is outside the range `+/-23:59`.
+    ///
+    /// ```
+    /// use ion_rs::Timestamp;
+    /// # fn main() -> ion_rs::IonResult<()> {
+    /// let timestamp = Timestamp::with_ymd(2024, 1, 1)
+    ///     .with_hms(0, 30, 0)
+    ///     .with_offset(2 * 60)
+    ///     .build()?;
+    /// let pacific = timestamp.to_offset(-8 * 60)?;
+    /// assert_eq!(pacific.to_string(), "2023-12-31T14:30:00-08:00");
+    /// assert_eq!(pacific, timestamp.to_offset(-8 * 60)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_offset(&self, offset_minutes: i32) -> IonResult<Timestamp> {
+        if self.precision < TimestampPrecision::HourAndMinute {
+            return IonResult::illegal_operation(format!(
+                "cannot change the offset of a timestamp with {:?} precision",
+                self.precision
+            ));
+        }
+        if self.offset.is_none() {
+            return IonResult::illegal_operation(
+                "cannot change the offset of a timestamp whose offset is unknown",
+            );
+        }
+        validate_offset_minutes(offset_minutes)?;
+        // `date_time` always holds the UTC fields, so only the offset needs to change.
+        let mut timestamp = *self;
+        timestamp.offset = Some(offset_east(offset_minutes * 60));
+        Ok(timestamp)
+    }
+
     /// Returns the precision that has been specified in the [Timestamp].
     pub fn precision(&self) -> TimestampPrecision {
         self.precision
@@ -587,9 +662,147 @@ impl Timestamp {
         self.date_time.second()
     }
 
-    /// Return a UTC timestamp for this [Timestamp]
+    /// Returns a new [Timestamp] representing the same instant as this one with an offset of
+    /// `+00:00`. The precision and fractional seconds are preserved exactly.
+    ///
+    /// If this timestamp's offset is unknown (`-00:00`) or it has no time component, it is
+    /// returned unchanged; its fields are already understood to be in UTC.
     pub fn to_utc(&self) -> Timestamp {
-        self.date_time.into()
+        self.to_offset(0).unwrap_or(*self)
+    }
+
+    /// Returns a new [Timestamp] that is `duration` later than this one.
+    ///
+    /// The result has the same precision, number of fractional second digits, and offset (known
+    /// or unknown) as this timestamp. Any part of the result that this precision cannot represent
+    /// is truncated; for example, adding 36 hours to `2024-06-01T` produces `2024-06-02T`.
+    ///
+    /// Returns `Err` if the result would be outside the range of years supported by Ion (1-9999).
+    ///
+    /// ```
+    /// use ion_rs::Timestamp;
+    /// use std::time::Duration;
+    /// # fn main() -> ion_rs::IonResult<()> {
+    /// let timestamp = Timestamp::with_ymd(2024, 2, 20)
+    ///     .with_hms(12, 0, 0)
+    ///     .with_milliseconds(500)
+    ///     .with_offset(-5 * 60)
+    ///     .build()?;
+    /// let expiration = timestamp.add_duration(Duration::from_secs(30 * 24 * 60 * 60))?;
+    /// assert_eq!(expiration.to_string(), "2024-03-21T12:00:00.500-05:00");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_duration(&self, duration: std::time::Duration) -> IonResult<Timestamp> {
+        self.add_signed_duration(self.checked_time_delta(duration)?)
+    }
+
+    /// Returns a new [Timestamp] that is `duration` earlier than this one. See
+    /// [`add_duration`](Self::add_duration) for details.
+    pub fn sub_duration(&self, duration: std::time::Duration) -> IonResult<Timestamp> {
+        self.add_signed_duration(-self.checked_time_delta(duration)?)
+    }
+
+    /// Returns a new [Timestamp] that is offset from this one by the specified (possibly negative)
+    /// [`chrono::Duration`]. See [`add_duration`](Self::add_duration) for details.
+    pub fn add_signed_duration(&self, duration: chrono::Duration) -> IonResult<Timestamp> {
+        let out_of_range = || {
+            IonError::illegal_operation(format!(
+                "adding {duration} to {self} produces a timestamp outside the supported range (years 1-9999)"
+            ))
+        };
+        let mut timestamp = *self;
+        match self.fractional_seconds {
+            // The fractional seconds are stored in `date_time`, which can add the duration directly.
+            None | Some(Mantissa::Digits(_)) => {
+                timestamp.date_time = self
+                    .date_time
+                    .checked_add_signed(duration)
+                    .ok_or_else(out_of_range)?;
+            }
+            // The fractional seconds are stored in a Decimal. Add the duration's sub-second
+            // portion to it, carrying into (or borrowing from) the duration's whole seconds.
+            Some(Mantissa::Arbitrary(ref fraction)) => {
+                let (carry_seconds, fraction) =
+                    Self::add_nanoseconds_to_fraction(fraction, duration.subsec_nanos())
+                        .ok_or_else(out_of_range)?;
+                let seconds = duration
+                    .num_seconds()
+                    .checked_add(carry_seconds)
+                    .ok_or_else(out_of_range)?;
+                timestamp.date_time = chrono::Duration::try_seconds(seconds)
+                    .and_then(|seconds| self.date_time.checked_add_signed(seconds))
+                    .ok_or_else(out_of_range)?;
+                timestamp.fractional_seconds = Some(Mantissa::Arbitrary(fraction));
+            }
+        }
+        timestamp.truncate_to_precision();
+        if !(1..=9999).contains(&timestamp.year()) {
+            return Err(out_of_range());
+        }
+        Ok(timestamp)
+    }
+
+    fn checked_time_delta(&self, duration: std::time::Duration) -> IonResult<chrono::Duration> {
+        chrono::Duration::from_std(duration).map_err(|_| {
+            IonError::illegal_operation(format!(
+                "duration {duration:?} is too large to add to timestamp {self}"
+            ))
+        })
+    }
+
+    /// Adds `nanoseconds` (which may be negative) to `fraction`, a number of seconds in the range
+    /// `[0, 1)`. Returns the number of whole seconds to carry and the new fraction, which has the
+    /// same scale as the original. Digits beyond that scale are truncated.
+    ///
+    /// Returns `None` if the computation would overflow.
+    fn add_nanoseconds_to_fraction(fraction: &Decimal, nanoseconds: i32) -> Option<(i64, Decimal)> {
+        const NANOSECOND_DIGITS: u32 = 9;
+        let fraction_digits = u32::try_from(fraction.scale().max(0)).ok()?;
+        // Perform the addition at a scale that can represent both values exactly.
+        let digits = fraction_digits.max(NANOSECOND_DIGITS);
+        let one_second = 10i128.checked_pow(digits)?;
+        let fraction_value = i128::try_from(fraction.coefficient().magnitude().as_u128()?)
+            .ok()?
+            .checked_mul(10i128.checked_pow(digits - fraction_digits)?)?;
+        let nanoseconds =
+            i128::from(nanoseconds).checked_mul(10i128.pow(digits - NANOSECOND_DIGITS))?;
+        let sum = fraction_value.checked_add(nanoseconds)?;
+        let carry_seconds = i64::try_from(sum.div_euclid(one_second)).ok()?;
+        let remainder = sum.rem_euclid(one_second) / 10i128.pow(digits - fraction_digits);
+        Some((
+            carry_seconds,
+            Decimal::new(remainder, -i64::from(fraction_digits)),
+        ))
+    }
+
+    /// Discards any part of `date_time` that this timestamp's precision cannot represent.
+    fn truncate_to_precision(&mut self) {
+        use TimestampPrecision::*;
+        let date = self.date_time.date();
+        let (year, month) = (date.year(), date.month());
+        // Each of these operations produces a valid datetime, so they cannot fail.
+        self.date_time = match self.precision {
+            Year => NaiveDate::from_ymd_opt(year, 1, 1).unwrap().into(),
+            Month => NaiveDate::from_ymd_opt(year, month, 1).unwrap().into(),
+            Day => date.into(),
+            HourAndMinute => self
+                .date_time
+                .with_second(0)
+                .and_then(|dt| dt.with_nanosecond(0))
+                .unwrap(),
+            Second => {
+                let nanoseconds = self.date_time.nanosecond();
+                let truncated = match self.fractional_seconds {
+                    Some(Mantissa::Digits(digits)) if digits < 9 => {
+                        nanoseconds - nanoseconds % 10u32.pow(9 - digits)
+                    }
+                    Some(Mantissa::Digits(_)) => nanoseconds,
+                    None | Some(Mantissa::Arbitrary(_)) => 0,
+                };
+                self.date_time.with_nanosecond(truncated).unwrap()
+            }
+        };
     }
 
     /// Returns this Timestamp's fractional seconds in nanoseconds
@@ -833,12 +1046,6 @@ impl<T> TimestampBuilder<T> {
     where
         D: Datelike + Timelike + Debug,
     {
-        if self.year == 0 || self.year > 9999 {
-            return IonResult::illegal_operation(format!(
-                "Timestamp year '{}' out of range (1-9999)",
-                self.year
-            ));
-        }
         datetime = datetime.with_year(self.year as i32).ok_or_else(|| {
             IonError::illegal_operation(format!("specified year ('{}') is invalid", self.year))
         })?;
@@ -897,6 +1104,48 @@ impl<T> TimestampBuilder<T> {
         Ok(datetime)
     }
 
+    /// Confirms that each of the components required by the builder's precision is within its
+    /// allowed range. Both the text and binary readers construct timestamps using the builder,
+    /// so these rules are applied consistently regardless of the input's encoding:
+    ///
+    /// * The year must be in the range `1..=9999`.
+    /// * The month must be in the range `1..=12`.
+    /// * The day must exist in the specified month. February 29th is only allowed in leap years.
+    /// * The hour must be in the range `0..=23` and the minute in the range `0..=59`.
+    /// * The second must be in the range `0..=59`. Ion timestamps cannot represent leap seconds.
+    ///
+    /// The offset, if any, is validated separately by `validate_offset_minutes`.
+    fn validate_components(&self) -> IonResult<()> {
+        validate_component("year", self.year, 1..=9999)?;
+        if self.precision == TimestampPrecision::Year {
+            return Ok(());
+        }
+        validate_component("month", self.month, 1..=12)?;
+        if self.precision == TimestampPrecision::Month {
+            return Ok(());
+        }
+        validate_component("day", self.day, 1..=31)?;
+        if NaiveDate::from_ymd_opt(self.year as i32, self.month, self.day).is_none() {
+            return IonResult::illegal_operation(format!(
+                "timestamp day {} does not exist in month {} of year {}",
+                self.day, self.month, self.year
+            ));
+        }
+        if self.precision == TimestampPrecision::Day {
+            return Ok(());
+        }
+        validate_component("hour", self.hour, 0..=23)?;
+        validate_component("minute", self.minute, 0..=59)?;
+        if self.precision == TimestampPrecision::HourAndMinute {
+            return Ok(());
+        }
+        validate_component("second", self.second, 0..=59)?;
+        if let Some(nanoseconds) = self.nanoseconds {
+            validate_component("nanoseconds", nanoseconds, 0..=999_999_999)?;
+        }
+        Ok(())
+    }
+
     // A [NaiveDateTime] has no offset. This function attempts to apply the provided offset to the
     // NaiveDateTime, producing a DateTime<FixedOffset>. If the offset is invalid or the combination
     // of offset and datetime would produce an invalid Timestamp, this function will return Err.
@@ -907,12 +1156,14 @@ impl<T> TimestampBuilder<T> {
     ) -> IonResult<DateTime<FixedOffset>> {
         // The chrono APIs express their DateTime offsets in seconds, but the Ion APIs use minutes.
         const SECONDS_PER_MINUTE: i32 = 60;
-        let offset_seconds = offset_minutes * SECONDS_PER_MINUTE;
-        let offset = FixedOffset::east_opt(offset_seconds).ok_or_else(|| {
-            IonError::illegal_operation(format!(
-                "specified offset ({offset_minutes} minutes) is invalid"
-            ))
-        })?;
+        let offset = offset_minutes
+            .checked_mul(SECONDS_PER_MINUTE)
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| {
+                IonError::illegal_operation(format!(
+                    "specified offset ({offset_minutes} minutes) is invalid"
+                ))
+            })?;
 
         // If the fields of the datetime are UTC, constructing a DateTime<FixedOffset> is guaranteed
         // to succeed. Return it directly.
@@ -943,9 +1194,12 @@ impl<T> TimestampBuilder<T> {
 
     /// Attempt to construct a [Timestamp] using the values configured on the [TimestampBuilder].
     /// If any of the individual fields are invalid (for example, a `month` value that is greater
-    /// than `12`) or if the resulting timestamp would represent a non-existent point in time
-    /// (like those bypassed by daylight saving time), this method will return an `Err(IonError)`.
+    /// than `12`, a `second` value of `60`, or a `day` that does not exist in the specified month)
+    /// or if the resulting timestamp would represent a non-existent point in time (like those
+    /// bypassed by daylight saving time), this method will return an `Err(IonError)` that names
+    /// the offending component and its allowed range.
     pub fn build(mut self) -> IonResult<Timestamp> {
+        self.validate_components()?;
         // Start with a clean slate NaiveDateTime that we can configure. (These are cheap to copy.)
         let mut datetime: NaiveDateTime = NaiveDate::from_ymd_opt(0, 1, 1)
             .unwrap()
@@ -953,6 +1207,15 @@ impl<T> TimestampBuilder<T> {
             .unwrap();
         // Set all of the time fields on the datetime using the data from our TimestampBuilder
         datetime = self.configure_datetime(datetime)?;
+        if let Some(offset_minutes) = self.offset {
+            if self.precision < TimestampPrecision::HourAndMinute {
+                return IonResult::illegal_operation(format!(
+                    "cannot specify an offset for a timestamp with {:?} precision",
+                    self.precision
+                ));
+            }
+            validate_offset_minutes(offset_minutes)?;
+        }
         // If the timestamp we're building has a known offset...
         let mut timestamp: Timestamp = if let Some(offset_minutes) = self.offset {
             // ...apply the offset to our NaiveDateTime, producing a DateTime<FixedOffset>
@@ -1088,6 +1351,7 @@ macro_rules! with_offset {
     () => {
         /// Sets the difference, in minutes, from UTC. A positive value indicates
         /// Eastern Hemisphere, while a negative value indicates Western Hemisphere.
+        /// The offset must be in the range `+/-23:59`; if it isn't, `build()` will return `Err`.
         // The unit (minutes) could be seconds (which is what the chrono crate uses
         // internally), but Ion uses minutes in its binary representation, so it
         // makes sense to be consistent.
@@ -1258,7 +1522,7 @@ mod timestamp_tests {
     use crate::ion_data::IonEq;
     use crate::result::IonResult;
     use crate::types::Mantissa;
-    use crate::{Decimal, Timestamp, TimestampPrecision};
+    use crate::{Decimal, Element, IonType, Timestamp, TimestampPrecision};
     use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
     use rstest::*;
     use std::cmp::Ordering;
@@ -2030,6 +2294,166 @@ mod timestamp_tests {
         assert!(t1.ion_eq(&t2));
     }
 
+    #[rstest]
+    #[case::utc_across_year_boundary("2024-01-01T00:30+02:00", 0, "2023-12-31T22:30+00:00")]
+    #[case::west_across_year_boundary("2023-12-31T23:30-02:00", 60, "2024-01-01T02:30+01:00")]
+    #[case::into_leap_day("2024-03-01T03:00:00Z", -5 * 60, "2024-02-29T22:00:00-05:00")]
+    #[case::out_of_leap_day("2024-02-29T23:30:00-05:00", 0, "2024-03-01T04:30:00+00:00")]
+    #[case::past_end_of_february("2023-02-28T23:30:00-05:00", 0, "2023-03-01T04:30:00+00:00")]
+    #[case::nanoseconds("2024-06-01T12:00:00.123456789+01:00", -8 * 60, "2024-06-01T03:00:00.123456789-08:00")]
+    #[case::picoseconds("2024-06-01T12:00:00.123456789012+01:00", 23 * 60 + 59, "2024-06-02T10:59:00.123456789012+23:59")]
+    fn test_to_offset(
+        #[case] input: &str,
+        #[case] offset_minutes: i32,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Element::read_one(input)?.expect_timestamp()?;
+        let converted = timestamp.to_offset(offset_minutes)?;
+        assert_eq!(converted.to_string(), expected);
+        assert_eq!(converted.offset_minutes(), Some(offset_minutes));
+        assert_eq!(converted.precision(), timestamp.precision());
+        // The instant is unchanged, so converting back produces the original timestamp.
+        let round_tripped = converted.to_offset(timestamp.offset_minutes().unwrap())?;
+        assert!(round_tripped.ion_eq(&timestamp));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unknown_offset("2024-06-01T12:00-00:00", 0)]
+    #[case::date_only("2024-06-01T", 0)]
+    #[case::offset_too_large("2024-06-01T12:00Z", 24 * 60)]
+    #[case::offset_too_small("2024-06-01T12:00Z", -24 * 60)]
+    fn test_to_offset_errors(#[case] input: &str, #[case] offset_minutes: i32) -> IonResult<()> {
+        let timestamp = Element::read_one(input)?.expect_timestamp()?;
+        assert!(timestamp.to_offset(offset_minutes).is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unknown_offset("2024-06-01T12:00:00.5-00:00")]
+    #[case::date_only("2024-06-01T")]
+    fn test_to_utc_returns_timestamps_without_known_offsets_unchanged(
+        #[case] input: &str,
+    ) -> IonResult<()> {
+        let timestamp = Element::read_one(input)?.expect_timestamp()?;
+        let utc = timestamp.to_utc();
+        assert!(utc.ion_eq(&timestamp));
+        assert_eq!(utc.offset_minutes(), None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::across_year_boundary("2023-12-31T23:30+05:30", 3600, "2024-01-01T00:30+05:30")]
+    #[case::unknown_offset("2023-12-31T23:30:00-00:00", 3600, "2024-01-01T00:30:00-00:00")]
+    #[case::into_leap_day("2024-02-28T", 24 * 3600, "2024-02-29T")]
+    #[case::truncated_to_day("2024-02-29T", 36 * 3600, "2024-03-01T")]
+    #[case::truncated_to_month("2024-01T", 40 * 24 * 3600, "2024-02T")]
+    #[case::truncated_to_year("2024T", 400 * 24 * 3600, "2025T")]
+    #[case::truncated_to_minute("2024-06-01T12:00+00:00", 90, "2024-06-01T12:01+00:00")]
+    #[case::truncated_to_second("2024-06-01T12:00:00+00:00", 0, "2024-06-01T12:00:00+00:00")]
+    #[case::nanoseconds(
+        "2024-06-01T12:00:00.999999999+00:00",
+        0,
+        "2024-06-01T12:00:00.999999999+00:00"
+    )]
+    fn test_add_duration(
+        #[case] input: &str,
+        #[case] seconds: u64,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Element::read_one(input)?.expect_timestamp()?;
+        let later = timestamp.add_duration(std::time::Duration::from_secs(seconds))?;
+        assert_eq!(later.to_string(), expected);
+        assert_eq!(later.precision(), timestamp.precision());
+        assert_eq!(later.offset_minutes(), timestamp.offset_minutes());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::nanosecond_carry(
+        "2023-12-31T23:59:59.999999999+00:00",
+        1,
+        "2024-01-01T00:00:00.000000000+00:00"
+    )]
+    #[case::truncated_to_millis(
+        "2024-06-01T12:00:00.500+00:00",
+        1_999_999,
+        "2024-06-01T12:00:00.501+00:00"
+    )]
+    #[case::no_fraction("2024-06-01T12:00:00+00:00", 999_999_999, "2024-06-01T12:00:00+00:00")]
+    #[case::arbitrary_carry(
+        "2024-06-01T12:00:59.999999999999+00:00",
+        1,
+        "2024-06-01T12:01:00.000000000999+00:00"
+    )]
+    #[case::arbitrary_borrow("2024-06-01T12:01:00.000000000999+00:00", -1, "2024-06-01T12:00:59.999999999999+00:00")]
+    #[case::arbitrary_seconds("2024-06-01T12:00:00.123456789012+00:00", -61_000_000_000, "2024-06-01T11:58:59.123456789012+00:00")]
+    fn test_add_signed_duration(
+        #[case] input: &str,
+        #[case] nanoseconds: i64,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Element::read_one(input)?.expect_timestamp()?;
+        let result = timestamp.add_signed_duration(chrono::Duration::nanoseconds(nanoseconds))?;
+        assert_eq!(result.to_string(), expected);
+        assert_eq!(result.precision(), timestamp.precision());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::after_year_9999("9999-12-31T23:00+00:00", 3600)]
+    #[case::after_year_9999_at_year_precision("9999T", 366 * 24 * 3600)]
+    #[case::before_year_1("0001-01-01T00:30+00:00", -3600)]
+    #[case::before_year_1_with_offset("0001-01-01T00:30+01:00", -3600)]
+    #[case::duration_overflow("2024-06-01T12:00+00:00", i64::MAX / 1000)]
+    fn test_add_signed_duration_out_of_range(
+        #[case] input: &str,
+        #[case] seconds: i64,
+    ) -> IonResult<()> {
+        let timestamp = Element::read_one(input)?.expect_timestamp()?;
+        assert!(timestamp
+            .add_signed_duration(chrono::Duration::seconds(seconds))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sub_duration() -> IonResult<()> {
+        let timestamp = Element::read_one("2024-03-01T01:00:00.25-08:00")?.expect_timestamp()?;
+        let earlier =
+            timestamp.sub_duration(std::time::Duration::from_millis(2 * 3600 * 1000 + 500))?;
+        assert_eq!(earlier.to_string(), "2024-02-29T22:59:59.75-08:00");
+        assert!(Timestamp::with_year(1)
+            .build()?
+            .sub_duration(std::time::Duration::from_secs(1))
+            .is_err());
+        assert!(timestamp.sub_duration(std::time::Duration::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_rejects_out_of_range_offsets() {
+        let builder = TimestampBuilder::with_ymd(2024, 6, 1).with_hour_and_minute(12, 0);
+        assert!(builder
+            .clone()
+            .with_offset(MAX_OFFSET_MINUTES)
+            .build()
+            .is_ok());
+        assert!(builder
+            .clone()
+            .with_offset(-MAX_OFFSET_MINUTES)
+            .build()
+            .is_ok());
+        assert!(builder.clone().with_offset(24 * 60).build().is_err());
+        assert!(builder.with_offset(-24 * 60).build().is_err());
+    }
+
+    #[test]
+    fn test_builder_rejects_offsets_without_a_time_component() {
+        let result = TimestampBuilder::with_ymd(2024, 6, 1).build_utc_fields_at_offset(60);
+        assert!(result.is_err());
+    }
+
     #[rstest]
     #[case(TimestampBuilder::with_year(3030).build().unwrap(), "3030T")]
     #[case(TimestampBuilder::with_year(3030).with_month(11).build().unwrap(), "3030-11T")]
@@ -2047,4 +2471,107 @@ mod timestamp_tests {
         write!(&mut buf, "{ts}").unwrap();
         assert_eq!(expect, String::from_utf8(buf).unwrap());
     }
+
+    #[rstest]
+    #[case::year_zero("0000T", "year (0)")]
+    #[case::month_zero("2023-00T", "month (0)")]
+    #[case::month_13("2023-13T", "month (13)")]
+    #[case::day_zero("2023-01-00T", "day (0)")]
+    #[case::day_32("2023-01-32T", "day (32)")]
+    #[case::april_31("2023-04-31T", "day 31 does not exist in month 4")]
+    #[case::february_29_non_leap("2023-02-29T", "day 29 does not exist in month 2")]
+    #[case::february_29_century("2100-02-29T", "day 29 does not exist in month 2")]
+    #[case::hour_24("2023-01-01T24:00Z", "hour (24)")]
+    #[case::minute_60("2023-01-01T00:60Z", "minute (60)")]
+    #[case::leap_second("2016-12-31T23:59:60Z", "second (60)")]
+    #[case::offset_24_hours("2023-01-01T00:00+24:00", "offset (1440)")]
+    #[case::offset_too_negative("2023-01-01T00:00-99:00", "offset (-5940)")]
+    #[case::offset_minute_60("2023-01-01T00:00-23:60", "offset minutes (60)")]
+    fn invalid_text_timestamp(#[case] text: &str, #[case] expected_message: &str) {
+        let error = Element::read_one(text).expect_err("invalid timestamp was accepted");
+        let IonError::Decoding(ref decoding_error) = error else {
+            panic!("expected a decoding error, found {error:?}");
+        };
+        assert!(
+            error.to_string().contains(expected_message),
+            "'{error}' did not contain '{expected_message}'"
+        );
+        assert_eq!(decoding_error.position().map(|p| p.byte_offset()), Some(0));
+    }
+
+    // Each of these is a binary Ion 1.0 stream holding a single timestamp.
+    #[rstest]
+    #[case::year_zero(&[0xE0, 0x01, 0x00, 0xEA, 0x62, 0xC0, 0x80], "year (0)")]
+    #[case::month_13(&[0xE0, 0x01, 0x00, 0xEA, 0x64, 0xC0, 0x0F, 0xE7, 0x8D], "month (13)")]
+    #[case::day_zero(&[0xE0, 0x01, 0x00, 0xEA, 0x65, 0xC0, 0x0F, 0xE7, 0x81, 0x80], "day (0)")]
+    #[case::february_30(
+        &[0xE0, 0x01, 0x00, 0xEA, 0x65, 0xC0, 0x0F, 0xE7, 0x82, 0x9E],
+        "day 30 does not exist in month 2"
+    )]
+    #[case::hour_24(
+        &[0xE0, 0x01, 0x00, 0xEA, 0x67, 0x80, 0x0F, 0xE7, 0x81, 0x81, 0x98, 0x80],
+        "hour (24)"
+    )]
+    #[case::leap_second(
+        &[0xE0, 0x01, 0x00, 0xEA, 0x68, 0x80, 0x0F, 0xE0, 0x8C, 0x9F, 0x97, 0xBB, 0xBC],
+        "second (60)"
+    )]
+    #[case::offset_24_hours(
+        &[0xE0, 0x01, 0x00, 0xEA, 0x68, 0x0B, 0xA0, 0x0F, 0xE7, 0x81, 0x81, 0x80, 0x80],
+        "offset (1440)"
+    )]
+    fn invalid_binary_timestamp(#[case] ion_data: &[u8], #[case] expected_message: &str) {
+        let error = Element::read_one(ion_data).expect_err("invalid timestamp was accepted");
+        assert!(
+            matches!(error, IonError::Decoding(_)),
+            "expected a decoding error, found {error:?}"
+        );
+        assert!(
+            error.to_string().contains(expected_message),
+            "'{error}' did not contain '{expected_message}'"
+        );
+    }
+
+    #[rstest]
+    #[case::builder_second_60(Timestamp::with_ymd(2023, 1, 1).with_hms(0, 0, 60).build(), "second (60)")]
+    #[case::builder_leap_nanoseconds(
+        Timestamp::with_ymd(2023, 1, 1).with_hms(23, 59, 59).with_nanoseconds(1_500_000_000).build(),
+        "nanoseconds (1500000000)"
+    )]
+    #[case::builder_month_13(Timestamp::with_year(2023).with_month(13).build(), "month (13)")]
+    #[case::builder_year_10000(Timestamp::with_year(10_000).build(), "year (10000)")]
+    fn invalid_builder_timestamp(
+        #[case] result: IonResult<Timestamp>,
+        #[case] expected_message: &str,
+    ) {
+        let error = result.expect_err("invalid timestamp was accepted");
+        assert!(matches!(error, IonError::IllegalOperation(_)));
+        assert!(
+            error.to_string().contains(expected_message),
+            "'{error}' did not contain '{expected_message}'"
+        );
+    }
+
+    #[rstest]
+    #[case::year_one("0001T")]
+    #[case::year_9999("9999T")]
+    #[case::first_day("0001-01-01T")]
+    #[case::last_day("9999-12-31T")]
+    #[case::leap_day("2024-02-29T")]
+    #[case::leap_day_400_year("2000-02-29T")]
+    #[case::april_30("2023-04-30T")]
+    #[case::last_minute("2023-12-31T23:59Z")]
+    #[case::last_second("2023-12-31T23:59:59Z")]
+    #[case::last_nanosecond("2023-12-31T23:59:59.999999999Z")]
+    #[case::maximum_offset("2023-01-01T00:00+23:59")]
+    #[case::minimum_offset("2023-01-01T00:00-23:59")]
+    #[case::unknown_offset("9999-12-31T23:59:59.9999999999-00:00")]
+    fn valid_timestamp_edge_cases(#[case] text: &str) -> IonResult<()> {
+        let from_text = Element::read_one(text)?;
+        assert_eq!(from_text.ion_type(), IonType::Timestamp);
+        // The binary reader must accept everything that the text reader accepts.
+        let binary = from_text.encode_as(crate::v1_0::Binary)?;
+        assert_eq!(Element::read_one(binary)?, from_text);
+        Ok(())
+    }
 }
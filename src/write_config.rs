@@ -2,12 +2,19 @@ use std::io;
 use std::marker::PhantomData;
 
 use crate::lazy::encoder::value_writer::SequenceWriter;
+use crate::lazy::encoder::value_writer_config::{
+    NewSymbolPolicy, SymbolInterningPolicy, UnknownTextSymbolPolicy,
+};
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::writer::Writer;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::{
-    BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, OutputFromBytes, TextEncoding_1_0,
-    TextEncoding_1_1,
+    BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, OutputFromBytes, TextEncoding,
+    TextEncoding_1_0, TextEncoding_1_1,
+};
+use crate::result::IonFailure;
+use crate::text::whitespace_config::{
+    WhitespaceConfig, COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
 };
 use crate::{IonResult, TextFormat};
 
@@ -16,10 +23,90 @@ use crate::{IonResult, TextFormat};
 #[derive(Clone, Debug)]
 pub struct WriteConfig<E: Encoding> {
     pub(crate) kind: WriteConfigKind,
+    pub(crate) unknown_text_symbol_policy: UnknownTextSymbolPolicy,
+    pub(crate) deterministic: bool,
+    pub(crate) new_symbol_policy: NewSymbolPolicy,
+    pub(crate) symbol_interning_policy: SymbolInterningPolicy,
+    pub(crate) flush_every_n_values: Option<usize>,
+    pub(crate) flush_threshold_bytes: Option<usize>,
     phantom_data: PhantomData<E>,
 }
 
 impl<E: Encoding> WriteConfig<E> {
+    /// Configures how writers built from this configuration will handle symbol values, field names,
+    /// and annotations whose text is unknown (for example, `$0`). By default, they are written as
+    /// symbol ID `0`.
+    pub fn with_unknown_text_symbol_policy(mut self, policy: UnknownTextSymbolPolicy) -> Self {
+        self.unknown_text_symbol_policy = policy;
+        self
+    }
+
+    /// Configures how writers built from this configuration will handle symbol values, field
+    /// names, and annotations whose text is not already in the symbol table. By default, the
+    /// text is added to the symbol table.
+    pub fn with_new_symbol_policy(mut self, policy: NewSymbolPolicy) -> Self {
+        self.new_symbol_policy = policy;
+        self
+    }
+
+    /// Configures when Ion 1.1 writers built from this configuration add new symbol text to the
+    /// symbol table instead of writing it inline. By default, new text is always added to the
+    /// symbol table.
+    ///
+    /// A local symbol table is only written when text has actually been added to the symbol table,
+    /// so a stream written with [`SymbolInterningPolicy::NeverIntern`] contains no symbol tables.
+    pub fn with_symbol_interning_policy(mut self, policy: SymbolInterningPolicy) -> Self {
+        self.symbol_interning_policy = policy;
+        self
+    }
+
+    /// Configures whether writers built from this configuration produce deterministic output.
+    ///
+    /// By default, the fields of a `HashMap` (whether written directly or serialized via serde)
+    /// are written in the map's iteration order, which can vary from one process to the next.
+    /// In deterministic mode, the fields of a `HashMap`, a serde map, or an [`Element`](crate::Element)
+    /// struct are sorted by name before they are written. Because new symbols are added to the
+    /// symbol table in the order in which they are first written, writing the same logical data
+    /// will then always produce the same bytes. This is useful when the output will be cached
+    /// or signed.
+    pub fn with_deterministic_output(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Configures writers built from this configuration to flush automatically after every `n`
+    /// top-level values. By default, writers only flush when [`Writer::flush`] or
+    /// [`Writer::close`] is called. Setting `n` to `0` disables automatic flushing by value count.
+    ///
+    /// Automatic flushes only happen between top-level values and, like a manual flush, first
+    /// write any symbol table entries that the flushed values require.
+    ///
+    /// The limits are only checked by the [`SequenceWriter`](crate::SequenceWriter) methods that
+    /// write a complete top-level value: `write`, the scalar `write_*` methods, `write_list`,
+    /// `write_sexp`, and `write_struct`. Closing a container writer (for example, one returned by
+    /// `list_writer()`) or finishing a value started with `value_writer()` does not check them.
+    /// Such a value is counted when it is started, and is flushed once a later top-level value
+    /// has been written using one of the methods above.
+    pub fn with_flush_every_n_values(mut self, n: usize) -> Self {
+        self.flush_every_n_values = (n > 0).then_some(n);
+        self
+    }
+
+    /// Configures writers built from this configuration to flush automatically once the encoded
+    /// top-level values they are holding reach `threshold` bytes. By default, writers only flush
+    /// when [`Writer::flush`] or [`Writer::close`] is called. Setting `threshold` to `0` disables
+    /// automatic flushing by size.
+    ///
+    /// The threshold is checked after each top-level value is written, so a value that crosses it
+    /// is flushed in its entirety; values are never split across flushes. This can be combined
+    /// with [`with_flush_every_n_values`](Self::with_flush_every_n_values), in which case the
+    /// writer flushes when either limit is reached. The threshold is checked by the same methods
+    /// as that limit; see its documentation for the values that are not checked immediately.
+    pub fn with_flush_threshold_bytes(mut self, threshold: usize) -> Self {
+        self.flush_threshold_bytes = (threshold > 0).then_some(threshold);
+        self
+    }
+
     pub(crate) fn encode<V: WriteAsIon>(&self, value: V) -> IonResult<E::Output> {
         let bytes = self.encode_to(value, Vec::new())?;
         Ok(E::Output::from_bytes(bytes))
@@ -79,10 +166,71 @@ impl<E: Encoding> WriteConfig<E> {
     }
 }
 
+impl<E: Encoding + for<'a> TextEncoding<'a>> WriteConfig<E> {
+    /// Sets the string written once per level of depth before each nested value, such as `"\t"`
+    /// or `"    "`. By default, [`TextFormat::Pretty`] indents nested values with two spaces.
+    ///
+    /// Only formats that write each nested value on its own line indent them; for other
+    /// formats, this setting has no effect.
+    ///
+    /// The indentation is written verbatim, so it may only contain spaces and tabs. Returns an
+    /// error if it contains any other character.
+    pub fn with_indentation(mut self, indentation: impl Into<String>) -> IonResult<Self> {
+        let indentation = indentation.into();
+        if let Some(c) = indentation.chars().find(|c| !matches!(c, ' ' | '\t')) {
+            return IonResult::illegal_operation(format!(
+                "indentation may only contain spaces and tabs, but found {c:?}"
+            ));
+        }
+        self.text_config_mut().indentation = Some(indentation);
+        Ok(self)
+    }
+
+    /// Configures whether the last value in each list and struct is followed by a comma
+    /// (for example: `[1, 2, ]`). By default, trailing commas are written.
+    ///
+    /// When trailing commas are disabled, the last value in each list, s-expression, and struct
+    /// is followed directly by the container's closing delimiter (or, in formats that write each
+    /// nested value on its own line, by a newline).
+    pub fn with_trailing_commas(mut self, trailing_commas: bool) -> Self {
+        self.text_config_mut().trailing_commas = trailing_commas;
+        self
+    }
+
+    /// Configures whether a space is written after the `:` that follows each field name
+    /// (for example: `{foo: 1}` vs `{foo:1}`). By default, the space is written.
+    pub fn with_space_after_field_name(mut self, space_after_field_name: bool) -> Self {
+        self.text_config_mut().space_after_field_name = space_after_field_name;
+        self
+    }
+
+    /// Sets the character sequence written at the end of each line. By default, lines end
+    /// with [`LineEnding::Lf`].
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.text_config_mut().line_ending = line_ending;
+        self
+    }
+
+    fn text_config_mut(&mut self) -> &mut TextWriteConfig {
+        match &mut self.kind {
+            WriteConfigKind::Text(text_config) => text_config,
+            WriteConfigKind::Binary(_) => {
+                unreachable!("text encodings always have a text writer configuration")
+            }
+        }
+    }
+}
+
 impl WriteConfig<TextEncoding_1_0> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig::new(text_kind)),
+            unknown_text_symbol_policy: UnknownTextSymbolPolicy::default(),
+            deterministic: false,
+            new_symbol_policy: NewSymbolPolicy::default(),
+            symbol_interning_policy: SymbolInterningPolicy::default(),
+            flush_every_n_values: None,
+            flush_threshold_bytes: None,
             phantom_data: Default::default(),
         }
     }
@@ -91,7 +239,13 @@ impl WriteConfig<TextEncoding_1_0> {
 impl WriteConfig<TextEncoding_1_1> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig::new(text_kind)),
+            unknown_text_symbol_policy: UnknownTextSymbolPolicy::default(),
+            deterministic: false,
+            new_symbol_policy: NewSymbolPolicy::default(),
+            symbol_interning_policy: SymbolInterningPolicy::default(),
+            flush_every_n_values: None,
+            flush_threshold_bytes: None,
             phantom_data: Default::default(),
         }
     }
@@ -101,6 +255,12 @@ impl WriteConfig<BinaryEncoding_1_0> {
     pub fn new() -> Self {
         Self {
             kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            unknown_text_symbol_policy: UnknownTextSymbolPolicy::default(),
+            deterministic: false,
+            new_symbol_policy: NewSymbolPolicy::default(),
+            symbol_interning_policy: SymbolInterningPolicy::default(),
+            flush_every_n_values: None,
+            flush_threshold_bytes: None,
             phantom_data: Default::default(),
         }
     }
@@ -110,6 +270,12 @@ impl WriteConfig<BinaryEncoding_1_1> {
     pub fn new() -> Self {
         Self {
             kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            unknown_text_symbol_policy: UnknownTextSymbolPolicy::default(),
+            deterministic: false,
+            new_symbol_policy: NewSymbolPolicy::default(),
+            symbol_interning_policy: SymbolInterningPolicy::default(),
+            flush_every_n_values: None,
+            flush_threshold_bytes: None,
             phantom_data: Default::default(),
         }
     }
@@ -150,6 +316,59 @@ pub(crate) enum WriteConfigKind {
 #[derive(Clone, Debug)]
 pub(crate) struct TextWriteConfig {
     pub(crate) text_kind: TextFormat,
+    // Overrides the text kind's indentation, if set
+    indentation: Option<String>,
+    trailing_commas: bool,
+    space_after_field_name: bool,
+    line_ending: LineEnding,
+}
+
+impl TextWriteConfig {
+    pub(crate) fn new(text_kind: TextFormat) -> Self {
+        Self {
+            text_kind,
+            indentation: None,
+            trailing_commas: true,
+            space_after_field_name: true,
+            line_ending: LineEnding::default(),
+        }
+    }
+
+    /// Returns the whitespace configuration for the text kind with any customizations applied.
+    pub(crate) fn whitespace_config(&self) -> WhitespaceConfig {
+        let mut whitespace_config = match self.text_kind {
+            TextFormat::Compact => COMPACT_WHITESPACE_CONFIG,
+            TextFormat::Lines => LINES_WHITESPACE_CONFIG,
+            TextFormat::Pretty => PRETTY_WHITESPACE_CONFIG,
+        };
+        if let Some(indentation) = &self.indentation {
+            whitespace_config = whitespace_config.with_indentation(indentation);
+        }
+        whitespace_config
+            .with_trailing_delimiters(self.trailing_commas)
+            .with_space_after_field_name(self.space_after_field_name)
+            .with_line_ending(self.line_ending)
+    }
+}
+
+/// The character sequence that text writers write at the end of each line.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum LineEnding {
+    /// A line feed (`\n`), as used on Unix-like systems.
+    #[default]
+    Lf,
+    /// A carriage return followed by a line feed (`\r\n`), as used on Windows.
+    CrLf,
+}
+
+impl LineEnding {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
 }
 
 /// Binary writer configuration to be used to create a writer
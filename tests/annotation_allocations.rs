@@ -0,0 +1,64 @@
+#![cfg(feature = "experimental-reader-writer")]
+//! Confirms that iterating over the annotations of values in a text stream does not allocate on
+//! the heap, even when the annotations contain escape sequences.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ion_rs::{v1_0, IonResult, Reader};
+
+/// Wraps the system allocator, counting the number of allocations it performs.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const NUM_VALUES: usize = 20_000;
+
+#[test]
+fn annotation_iteration_does_not_allocate() -> IonResult<()> {
+    let ion_text = (0..NUM_VALUES)
+        .map(|i| format!(r"'escap\x65d'::'no escapes'::plain::'\u00E9t\u00E9'::{i} "))
+        .collect::<String>();
+    let mut reader = Reader::new(v1_0::Text, ion_text.as_bytes())?;
+
+    let mut num_values = 0;
+    let mut annotation_allocations = 0;
+    while let Some(value) = reader.next()? {
+        // Only count the allocations made while the annotations are being read.
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        assert!(value
+            .annotations()
+            .are(["escaped", "no escapes", "plain", "\u{e9}t\u{e9}"])?);
+        assert!(value.has_annotation("escaped")?);
+        annotation_allocations += ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+        num_values += 1;
+    }
+    assert_eq!(num_values, NUM_VALUES);
+    // Escaped text is written to the reader's bump allocator, which reuses its memory for each
+    // value. Growing it may require a handful of allocations early on, but the number must not
+    // grow with the number of values read.
+    assert!(
+        annotation_allocations < 16,
+        "reading annotations made {annotation_allocations} allocations"
+    );
+    Ok(())
+}
@@ -361,3 +361,78 @@ mod unknown_symbol_text_tests {
         assert_eq!(expected_string, actual_string)
     }
 }
+
+/// Test vectors that do not depend on the `ion-hash-test` submodule. Each expected value is the
+/// output of the identity digest, as in the spec's `ion_hash_tests.ion`.
+mod inline_vector_tests {
+    use super::*;
+
+    fn identity_hash(element: &Element) -> String {
+        let digest = IdentityDigest::hash_element(element).unwrap();
+        format!("{:02x?}", without_trailing_zeros(&digest[..]))
+    }
+
+    #[test]
+    fn test_nulls_of_every_type() {
+        for (ion, type_qualifier) in [
+            ("null", 0x0f),
+            ("null.bool", 0x1f),
+            ("null.int", 0x2f),
+            ("null.float", 0x4f),
+            ("null.decimal", 0x5f),
+            ("null.timestamp", 0x6f),
+            ("null.symbol", 0x7f),
+            ("null.string", 0x8f),
+            ("null.clob", 0x9f),
+            ("null.blob", 0xaf),
+            ("null.list", 0xbf),
+            ("null.sexp", 0xcf),
+            ("null.struct", 0xdf),
+        ] {
+            let element = Element::read_one(ion).unwrap();
+            let expected = format!("{:02x?}", &[0x0b, type_qualifier, 0x0e]);
+            assert_eq!(identity_hash(&element), expected, "{ion}");
+        }
+    }
+
+    #[test]
+    fn test_scalars_annotations_and_containers() {
+        for (ion, expected) in [
+            ("true", &[0x0b, 0x11, 0x0e][..]),
+            ("5", &[0x0b, 0x20, 0x05, 0x0e]),
+            ("-5", &[0x0b, 0x30, 0x05, 0x0e]),
+            // The representation of 11 (0x0b) collides with the begin marker and is escaped.
+            ("11", &[0x0b, 0x20, 0x0c, 0x0b, 0x0e]),
+            ("\"hi\"", &[0x0b, 0x80, 0x68, 0x69, 0x0e]),
+            (
+                "a::1",
+                &[
+                    0x0b, 0xe0, 0x0b, 0x70, 0x61, 0x0e, 0x0b, 0x20, 0x01, 0x0e, 0x0e,
+                ],
+            ),
+            ("[1]", &[0x0b, 0xb0, 0x0b, 0x20, 0x01, 0x0e, 0x0e]),
+            ("(1)", &[0x0b, 0xc0, 0x0b, 0x20, 0x01, 0x0e, 0x0e]),
+        ] {
+            let element = Element::read_one(ion).unwrap();
+            assert_eq!(
+                identity_hash(&element),
+                format!("{:02x?}", expected),
+                "{ion}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_is_independent_of_encoding_and_field_order() -> IonResult<()> {
+        let text = "annotated::{a: 1, b: [2.5, \"three\"], c: {{Zm91cg==}}, d: 2024-01-01T}";
+        let element = Element::read_one(text)?;
+        let binary = element.encode_as(ion_rs::v1_0::Binary)?;
+        let reordered = Element::read_one(
+            "annotated::{d: 2024-01-01T, c: {{Zm91cg==}}, b: [2.5, \"three\"], a: 1}",
+        )?;
+        let expected = identity_hash(&element);
+        assert_eq!(identity_hash(&Element::read_one(binary)?), expected);
+        assert_eq!(identity_hash(&reordered), expected);
+        Ok(())
+    }
+}
@@ -2,8 +2,8 @@
 
 use crate::good_round_trip;
 use crate::ion_tests::{
-    bad, equivs, non_equivs, ElementApi, SkipList, ELEMENT_EQUIVS_SKIP_LIST,
-    ELEMENT_GLOBAL_SKIP_LIST, ELEMENT_ROUND_TRIP_SKIP_LIST,
+    bad, equivs, non_equivs, transcode_to_each_encoding, ElementApi, SkipList,
+    ELEMENT_EQUIVS_SKIP_LIST, ELEMENT_GLOBAL_SKIP_LIST, ELEMENT_ROUND_TRIP_SKIP_LIST,
 };
 use ion_rs::Reader;
 use ion_rs::{AnyEncoding, IonResult};
@@ -75,3 +75,9 @@ fn lazy_equivs(file_name: &str) {
 fn lazy_non_equivs(file_name: &str) {
     non_equivs(LazyReaderElementApi, file_name)
 }
+
+#[test_resources("ion-tests/iontestdata_1_0/good/**/*.ion")]
+#[test_resources("ion-tests/iontestdata_1_0/good/**/*.10n")]
+fn lazy_transcode(file_name: &str) {
+    transcode_to_each_encoding(LazyReaderElementApi, AnyEncoding, file_name)
+}
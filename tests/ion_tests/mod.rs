@@ -5,12 +5,12 @@
 use std::fs::read;
 use std::path::MAIN_SEPARATOR_STR as PATH_SEPARATOR;
 
-use ion_rs::v1_0;
-use ion_rs::Writer;
+use ion_rs::{transcode, v1_0, v1_1, AnyEncoding, Decoder, Encoding, ReadConfig, Reader};
 use ion_rs::{
     Element, ElementReader, ElementWriter, Format, IonData, IonError, IonResult, SExp, Sequence,
     Symbol, Value,
 };
+use ion_rs::{WriteConfig, Writer};
 
 pub mod lazy_element_ion_tests;
 
@@ -376,6 +376,49 @@ pub fn non_equivs<E: ElementApi>(_element_api: E, file_name: &str) {
     });
 }
 
+/// Uses [`transcode`] to copy the values in `file_name` into each Ion encoding, then asserts that
+/// the values read back from each output are equivalent to those in the file.
+pub fn transcode_to_each_encoding<E: ElementApi, D: Decoder>(
+    _element_api: E,
+    decoder: D,
+    file_name: &str,
+) where
+    ReadConfig<D>: From<D>,
+{
+    let skip_list = concat(E::global_skip_list(), E::round_trip_skip_list());
+    E::assert_file(&skip_list[..], file_name, || {
+        let data = read(file_name)?;
+        let expected = Reader::new(decoder, data.as_slice())?.read_all_elements()?;
+        assert_transcodes_to::<E, _, _>(decoder, v1_0::Text, &data, &expected)?;
+        assert_transcodes_to::<E, _, _>(decoder, v1_0::Binary, &data, &expected)?;
+        assert_transcodes_to::<E, _, _>(decoder, v1_1::Text, &data, &expected)?;
+        assert_transcodes_to::<E, _, _>(decoder, v1_1::Binary, &data, &expected)
+    });
+}
+
+fn assert_transcodes_to<E: ElementApi, D: Decoder, O: Encoding>(
+    decoder: D,
+    encoding: O,
+    data: &[u8],
+    expected: &Sequence,
+) -> IonResult<()>
+where
+    ReadConfig<D>: From<D>,
+    WriteConfig<O>: From<O>,
+{
+    let mut reader = Reader::new(decoder, data)?;
+    let mut writer = Writer::new(encoding, Vec::new())?;
+    transcode(&mut reader, &mut writer)?;
+    let output = writer.close()?;
+    let actual = Reader::new(AnyEncoding, output.as_slice())?.read_all_elements()?;
+    assert!(
+        IonData::eq(expected, &actual),
+        "Transcoding to {encoding:?} failed: {}",
+        E::not_eq_error_message(expected, &actual)
+    );
+    Ok(())
+}
+
 pub const ELEMENT_GLOBAL_SKIP_LIST: SkipList = &[
     // The binary reader does not check whether nested values are longer than their
     // parent container.
@@ -3,7 +3,7 @@
 /// TODO: When the Ion 1.1 binary reader is complete, update this module to include binary tests
 mod ion_tests;
 
-use crate::ion_tests::{bad, equivs, non_equivs, ElementApi, SkipList};
+use crate::ion_tests::{bad, equivs, non_equivs, transcode_to_each_encoding, ElementApi, SkipList};
 use ion_rs::IonResult;
 use ion_rs::{v1_1, Reader};
 use test_generator::test_resources;
@@ -103,3 +103,8 @@ fn lazy_equivs(file_name: &str) {
 fn lazy_non_equivs(file_name: &str) {
     non_equivs(LazyReaderElementApi, file_name)
 }
+
+#[test_resources("ion-tests/iontestdata_1_1/good/**/*.ion")]
+fn lazy_transcode(file_name: &str) {
+    transcode_to_each_encoding(LazyReaderElementApi, v1_1::Text, file_name)
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "experimental-tooling-apis")]
+//! Uses [`SystemReader::on_symbol_table_change`] to find the values in a binary stream that contain
+//! symbols matching a pattern without resolving the text of each symbol ID the stream uses.
+
+use ion_rs::v1_0::{Binary, RawValueRef};
+use ion_rs::{
+    Annotatable, AnyEncoding, IonResult, LazyRawValue, LazyValue, RawSymbolRef, Symbol, SymbolId,
+    SymbolTable, SymbolTableEvent, SystemReader, ValueRef, Writer,
+};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Tracks which of the symbol IDs in the reader's current symbol table have text that contains
+/// `pattern`. Rather than inspecting every symbol it encounters, the matcher scans each symbol
+/// table once as it is defined.
+struct SymbolPatternMatcher {
+    pattern: String,
+    matching_ids: HashSet<SymbolId>,
+}
+
+impl SymbolPatternMatcher {
+    fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_owned(),
+            matching_ids: HashSet::new(),
+        }
+    }
+
+    fn symbol_table_changed(&mut self, symbol_table: &SymbolTable, event: SymbolTableEvent) {
+        let first_new_id = match event {
+            SymbolTableEvent::Reset => {
+                self.matching_ids.clear();
+                0
+            }
+            SymbolTableEvent::Append(first_new_id) => first_new_id,
+        };
+        for (id, symbol) in symbol_table.symbols().iter().enumerate().skip(first_new_id) {
+            if symbol
+                .text()
+                .is_some_and(|text| text.contains(&self.pattern))
+            {
+                self.matching_ids.insert(id);
+            }
+        }
+    }
+
+    /// Returns `true` if `value`'s annotations or symbol value use a matching symbol ID.
+    fn is_match(&self, value: &LazyValue<AnyEncoding>) -> IonResult<bool> {
+        for symbol_id in value.annotations().symbol_ids() {
+            if symbol_id?.is_some_and(|sid| self.matching_ids.contains(&sid)) {
+                return Ok(true);
+            }
+        }
+        let raw_value = value
+            .raw()
+            .expect("binary streams do not contain e-expressions");
+        if let RawValueRef::Symbol(RawSymbolRef::SymbolId(sid)) = raw_value.read()? {
+            return Ok(self.matching_ids.contains(&sid));
+        }
+        Ok(false)
+    }
+}
+
+/// The reference implementation: resolves the text of each annotation and symbol value.
+fn is_text_match(value: &LazyValue<AnyEncoding>, pattern: &str) -> IonResult<bool> {
+    for annotation in value.annotations() {
+        if annotation?
+            .text()
+            .is_some_and(|text| text.contains(pattern))
+        {
+            return Ok(true);
+        }
+    }
+    if let ValueRef::Symbol(symbol) = value.read()? {
+        return Ok(symbol.text().is_some_and(|text| text.contains(pattern)));
+    }
+    Ok(false)
+}
+
+/// Writes a binary Ion 1.0 stream of log-like values. Each flush appends any newly encountered
+/// symbols to the stream's local symbol table.
+fn write_stream(batches: &[&[(&str, &str)]]) -> IonResult<Vec<u8>> {
+    let mut writer = Writer::new(Binary, Vec::new())?;
+    for batch in batches {
+        for (level, event) in batch.iter() {
+            writer.write(Symbol::from(*event).annotated_with(*level))?;
+            writer.write(*event)?;
+        }
+        writer.flush()?;
+    }
+    writer.close()
+}
+
+#[test]
+fn symbol_pattern_matcher() -> IonResult<()> {
+    let mut ion = write_stream(&[
+        &[("info", "user_login"), ("debug", "cache_miss")],
+        &[("warn", "user_lockout"), ("info", "user_login")],
+        &[("error", "disk_full"), ("info", "cache_hit")],
+    ])?;
+    // The second stream begins with an Ion version marker, which resets the symbol table.
+    // The same symbol IDs are reused for different text.
+    ion.extend(write_stream(&[
+        &[("info", "disk_usage"), ("warn", "user_logout")],
+        &[("error", "user_login_failed")],
+    ])?);
+
+    for pattern in ["user", "cache", "disk", "error", "info", "nothing"] {
+        let matcher = Rc::new(RefCell::new(SymbolPatternMatcher::new(pattern)));
+        let events = Rc::new(RefCell::new(Vec::new()));
+
+        let mut reader = SystemReader::new(AnyEncoding, ion.as_slice());
+        let handler_matcher = Rc::clone(&matcher);
+        let handler_events = Rc::clone(&events);
+        reader.on_symbol_table_change(move |symbol_table, event| {
+            handler_events.borrow_mut().push(event);
+            handler_matcher
+                .borrow_mut()
+                .symbol_table_changed(symbol_table, event);
+        });
+
+        let mut matches_by_id = 0;
+        let mut matches_by_text = 0;
+        while let Some(value) = reader.next_value()? {
+            matches_by_id += matcher.borrow().is_match(&value)? as usize;
+            matches_by_text += is_text_match(&value, pattern)? as usize;
+        }
+        assert_eq!(matches_by_id, matches_by_text, "pattern: {pattern}");
+
+        // Each stream's IVM reset the symbol table. The writer appended to it each time it
+        // flushed new symbols.
+        use SymbolTableEvent::*;
+        assert_eq!(
+            *events.borrow(),
+            [
+                Reset,
+                Append(10),
+                Append(14),
+                Append(16),
+                Reset,
+                Append(10),
+                Append(14)
+            ]
+        );
+    }
+    Ok(())
+}